@@ -0,0 +1,1440 @@
+//! Attitude Ephemeris Message (AEM)
+//!
+//! Reading and writing of CCSDS AEMs in KVN and XML, and interpolation of
+//! their attitudes.
+//!
+//! An AEM is a header followed by one or more segments, each with its own
+//! metadata (object, the two frames and the direction of the rotation between
+//! them, time system, span, attitude type and interpolation settings) and a
+//! time-ordered list of attitudes. Quaternion attitudes are supported, alone
+//! (QUATERNION), with their derivative (QUATERNION/DERIVATIVE) or with
+//! angular rates (QUATERNION/RATE); the Euler angle and spin attitude types
+//! are not. Quaternions are read into [`UnitQuaternion`]s and angular rates
+//! into rad/s; the message itself is in deg/s.
+//!
+//! [`Aem::rotation`] interpolates the rotation from frame A to frame B
+//! spherically between the attitudes of the segment covering an epoch, so
+//! that pointing profiles can be exchanged as messages and evaluated back.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use astrora_core::ccsds::parse_aem;
+//!
+//! let aem = parse_aem(&std::fs::read_to_string("sat.aem")?)?;
+//! let boresight = aem.rotation(&epoch)?.inverse() * Vector3::z();
+//! ```
+//!
+//! # References
+//!
+//! - CCSDS 504.0-B-1: Attitude Data Messages, section 4
+
+use hifitime::TimeScale;
+
+use super::kvn::{self, KvnLine, KvnWriter};
+use super::xml::{XmlElement, XmlWriter};
+use super::{
+    format_epoch, format_number, parse_epoch, quaternion_components, AttitudeDirection, Encoding,
+    Fields, QUATERNION_KEYS, QUATERNION_RATE_KEYS,
+};
+use crate::core::linalg::{UnitQuaternion, Vector3, Vector4};
+use crate::core::time::Epoch;
+use crate::core::{PoliastroError, PoliastroResult};
+
+/// Version of CCSDS 504.0 written in KVN and XML messages
+pub const CCSDS_AEM_VERSION: &str = "1.0";
+
+const MESSAGE_TYPE: &str = "AEM";
+
+const RATE_KEYS: [&str; 3] = ["X_RATE", "Y_RATE", "Z_RATE"];
+
+/// Attitude representation of a segment (ATTITUDE_TYPE)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttitudeType {
+    /// Quaternions
+    Quaternion,
+    /// Quaternions and their derivatives
+    QuaternionDerivative,
+    /// Quaternions and angular rates about the axes of RATE_FRAME
+    QuaternionRate,
+}
+
+impl AttitudeType {
+    /// Type from its CCSDS name ("QUATERNION", "QUATERNION/DERIVATIVE" or
+    /// "QUATERNION/RATE", any case)
+    pub fn from_name(name: &str) -> PoliastroResult<Self> {
+        match name.trim().to_ascii_uppercase().as_str() {
+            "QUATERNION" => Ok(AttitudeType::Quaternion),
+            "QUATERNION/DERIVATIVE" => Ok(AttitudeType::QuaternionDerivative),
+            "QUATERNION/RATE" => Ok(AttitudeType::QuaternionRate),
+            _ => Err(invalid(format!(
+                "unsupported ATTITUDE_TYPE '{name}' (expected QUATERNION, \
+                 QUATERNION/DERIVATIVE or QUATERNION/RATE)"
+            ))),
+        }
+    }
+
+    /// CCSDS name
+    pub fn name(&self) -> &'static str {
+        match self {
+            AttitudeType::Quaternion => "QUATERNION",
+            AttitudeType::QuaternionDerivative => "QUATERNION/DERIVATIVE",
+            AttitudeType::QuaternionRate => "QUATERNION/RATE",
+        }
+    }
+
+    /// XML element of an attitude of this type
+    fn xml_tag(&self) -> &'static str {
+        match self {
+            AttitudeType::Quaternion => "quaternionState",
+            AttitudeType::QuaternionDerivative => "quaternionDerivative",
+            AttitudeType::QuaternionRate => "quaternionEulerRate",
+        }
+    }
+
+    /// Number of values of a KVN data line, without the epoch
+    fn values(&self) -> usize {
+        match self {
+            AttitudeType::Quaternion => 4,
+            AttitudeType::QuaternionDerivative => 8,
+            AttitudeType::QuaternionRate => 7,
+        }
+    }
+}
+
+/// Rate of change of an attitude
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AttitudeRate {
+    /// Derivative of the quaternion in 1/s, in the order Q1, Q2, Q3, QC
+    Derivative(Vector4),
+    /// Angular rates about the X, Y and Z axes of RATE_FRAME, in rad/s
+    Angular(Vector3),
+}
+
+/// Metadata of an AEM segment
+#[derive(Debug, Clone, PartialEq)]
+pub struct AemMetadata {
+    /// Spacecraft name (OBJECT_NAME)
+    pub object_name: String,
+    /// International designator (OBJECT_ID)
+    pub object_id: String,
+    /// Origin of the frames, if relevant (CENTER_NAME)
+    pub center_name: Option<String>,
+    /// First frame of the rotation (REF_FRAME_A)
+    pub frame_a: String,
+    /// Second frame of the rotation (REF_FRAME_B)
+    pub frame_b: String,
+    /// Direction of the rotation (ATTITUDE_DIR)
+    pub direction: AttitudeDirection,
+    /// Time system of all epochs of the segment (TIME_SYSTEM)
+    pub time_system: String,
+    /// Start of the span of the segment (START_TIME)
+    pub start_time: Epoch,
+    /// Start of the span recommended for use (USEABLE_START_TIME)
+    pub useable_start_time: Option<Epoch>,
+    /// End of the span recommended for use (USEABLE_STOP_TIME)
+    pub useable_stop_time: Option<Epoch>,
+    /// End of the span of the segment (STOP_TIME)
+    pub stop_time: Epoch,
+    /// Attitude representation (ATTITUDE_TYPE)
+    pub attitude_type: AttitudeType,
+    /// Whether KVN data lines give QC before Q1, Q2 and Q3
+    /// (QUATERNION_TYPE = FIRST) rather than after them (LAST)
+    pub scalar_first: bool,
+    /// Frame of the angular rates (RATE_FRAME), for QUATERNION/RATE
+    pub rate_frame: Option<String>,
+    /// Recommended interpolation method (INTERPOLATION_METHOD)
+    pub interpolation_method: Option<String>,
+    /// Recommended interpolation degree (INTERPOLATION_DEGREE)
+    pub interpolation_degree: Option<usize>,
+    /// COMMENT lines of the metadata block
+    pub comments: Vec<String>,
+}
+
+impl AemMetadata {
+    fn time_scale(&self) -> PoliastroResult<TimeScale> {
+        super::time_scale(&self.time_system, MESSAGE_TYPE)
+    }
+}
+
+/// An attitude of an AEM segment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AemAttitude {
+    /// Epoch of the attitude
+    pub epoch: Epoch,
+    /// Rotation in the direction of the segment
+    pub quaternion: UnitQuaternion,
+    /// Rate of change, for the QUATERNION/DERIVATIVE and QUATERNION/RATE
+    /// types
+    pub rate: Option<AttitudeRate>,
+}
+
+impl AemAttitude {
+    /// Attitude `quaternion` at `epoch`, without rate
+    pub fn new(epoch: Epoch, quaternion: UnitQuaternion) -> Self {
+        Self {
+            epoch,
+            quaternion,
+            rate: None,
+        }
+    }
+
+    /// Attitude type the rate calls for
+    fn attitude_type(&self) -> AttitudeType {
+        match self.rate {
+            None => AttitudeType::Quaternion,
+            Some(AttitudeRate::Derivative(_)) => AttitudeType::QuaternionDerivative,
+            Some(AttitudeRate::Angular(_)) => AttitudeType::QuaternionRate,
+        }
+    }
+
+    /// Quaternion components Q1, Q2, Q3, QC and the rate, in XML keyword
+    /// order and message units
+    fn fields(&self) -> Vec<(&'static str, String)> {
+        let mut fields: Vec<_> = QUATERNION_KEYS
+            .iter()
+            .zip(quaternion_components(&self.quaternion))
+            .map(|(&key, value)| (key, format_number(value)))
+            .collect();
+        match &self.rate {
+            Some(AttitudeRate::Derivative(rate)) => fields.extend(
+                QUATERNION_RATE_KEYS
+                    .iter()
+                    .zip(rate.iter())
+                    .map(|(&key, value)| (key, format_number(*value))),
+            ),
+            Some(AttitudeRate::Angular(rate)) => fields.extend(
+                RATE_KEYS
+                    .iter()
+                    .zip(rate.iter())
+                    .map(|(&key, value)| (key, format_number(value.to_degrees()))),
+            ),
+            None => {}
+        }
+        fields
+    }
+
+    /// Values of a KVN data line, without the epoch
+    fn values(&self, scalar_first: bool) -> Vec<String> {
+        let mut components = quaternion_components(&self.quaternion);
+        let mut values = Vec::new();
+        if scalar_first {
+            components.rotate_right(1);
+        }
+        values.extend(components.iter().map(|&value| format_number(value)));
+        match &self.rate {
+            Some(AttitudeRate::Derivative(rate)) => {
+                let mut rate: [f64; 4] = (*rate).into();
+                if scalar_first {
+                    rate.rotate_right(1);
+                }
+                values.extend(rate.iter().map(|&value| format_number(value)));
+            }
+            Some(AttitudeRate::Angular(rate)) => {
+                values.extend(rate.iter().map(|value| format_number(value.to_degrees())));
+            }
+            None => {}
+        }
+        values
+    }
+
+    /// Attitude from the values of a KVN data line
+    fn from_values(values: &[String], metadata: &AemMetadata) -> PoliastroResult<Self> {
+        let attitude_type = metadata.attitude_type;
+        let Some((epoch, values)) = values.split_first() else {
+            return Err(invalid("empty data line"));
+        };
+        if values.len() != attitude_type.values() {
+            return Err(invalid(format!(
+                "{} values where {} has {}",
+                values.len(),
+                attitude_type.name(),
+                attitude_type.values()
+            )));
+        }
+        let numbers = values
+            .iter()
+            .map(|value| {
+                value
+                    .parse::<f64>()
+                    .map_err(|_| invalid(format!("invalid attitude value '{value}'")))
+            })
+            .collect::<PoliastroResult<Vec<_>>>()?;
+        // Q1, Q2, Q3, QC order
+        let components = |offset: usize| {
+            let mut components = [0.0; 4];
+            components.copy_from_slice(&numbers[offset..offset + 4]);
+            if metadata.scalar_first {
+                components.rotate_left(1);
+            }
+            components
+        };
+        let rate = match attitude_type {
+            AttitudeType::Quaternion => None,
+            AttitudeType::QuaternionDerivative => {
+                Some(AttitudeRate::Derivative(Vector4::from(components(4))))
+            }
+            AttitudeType::QuaternionRate => Some(AttitudeRate::Angular(
+                Vector3::new(numbers[4], numbers[5], numbers[6]).map(f64::to_radians),
+            )),
+        };
+        Ok(Self {
+            epoch: parse_epoch(epoch, metadata.time_scale()?, MESSAGE_TYPE)?,
+            quaternion: super::quaternion(components(0), MESSAGE_TYPE)?,
+            rate,
+        })
+    }
+
+    /// Attitude from the fields of an XML attitudeState
+    fn from_fields(fields: &Fields, metadata: &AemMetadata) -> PoliastroResult<Self> {
+        let parse_all = |keys: &[&str]| {
+            keys.iter()
+                .map(|key| fields.require_parse::<f64>(key))
+                .collect::<PoliastroResult<Vec<_>>>()
+        };
+        let quaternion = parse_all(&QUATERNION_KEYS)?;
+        let rate = match metadata.attitude_type {
+            AttitudeType::Quaternion => None,
+            AttitudeType::QuaternionDerivative => Some(AttitudeRate::Derivative(
+                Vector4::from_vec(parse_all(&QUATERNION_RATE_KEYS)?),
+            )),
+            AttitudeType::QuaternionRate => Some(AttitudeRate::Angular(
+                Vector3::from_vec(parse_all(&RATE_KEYS)?).map(f64::to_radians),
+            )),
+        };
+        Ok(Self {
+            epoch: parse_epoch(
+                fields.require("EPOCH")?,
+                metadata.time_scale()?,
+                MESSAGE_TYPE,
+            )?,
+            quaternion: super::quaternion(
+                [quaternion[0], quaternion[1], quaternion[2], quaternion[3]],
+                MESSAGE_TYPE,
+            )?,
+            rate,
+        })
+    }
+}
+
+/// A segment of an AEM: metadata and attitudes
+#[derive(Debug, Clone, PartialEq)]
+pub struct AemSegment {
+    /// Metadata block
+    pub metadata: AemMetadata,
+    /// COMMENT lines of the data block
+    pub comments: Vec<String>,
+    /// Attitudes, in increasing time order
+    pub attitudes: Vec<AemAttitude>,
+}
+
+impl AemSegment {
+    /// Segment of the rotations `attitudes` from `frame_a` to `frame_b`,
+    /// spanning from the first to the last attitude, in UTC
+    ///
+    /// The attitude type follows the rate of the first attitude; angular
+    /// rates are taken about the axes of frame B.
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` if there are no attitudes, they are not in increasing
+    /// time order or they do not all have the same kind of rate
+    pub fn new(
+        object_name: impl Into<String>,
+        object_id: impl Into<String>,
+        frame_a: impl Into<String>,
+        frame_b: impl Into<String>,
+        attitudes: Vec<AemAttitude>,
+    ) -> PoliastroResult<Self> {
+        let (Some(first), Some(last)) = (attitudes.first(), attitudes.last()) else {
+            return Err(invalid("a segment needs at least one attitude"));
+        };
+        let attitude_type = first.attitude_type();
+        let frame_b = frame_b.into();
+        let metadata = AemMetadata {
+            object_name: object_name.into(),
+            object_id: object_id.into(),
+            center_name: None,
+            frame_a: frame_a.into(),
+            rate_frame: (attitude_type == AttitudeType::QuaternionRate)
+                .then(|| "REF_FRAME_B".to_string()),
+            frame_b,
+            direction: AttitudeDirection::AToB,
+            time_system: "UTC".to_string(),
+            start_time: first.epoch,
+            useable_start_time: None,
+            useable_stop_time: None,
+            stop_time: last.epoch,
+            attitude_type,
+            scalar_first: false,
+            interpolation_method: None,
+            interpolation_degree: None,
+            comments: Vec::new(),
+        };
+        let segment = Self {
+            metadata,
+            comments: Vec::new(),
+            attitudes,
+        };
+        segment.validate()?;
+        Ok(segment)
+    }
+
+    /// Check the time system, that the attitudes are in increasing time
+    /// order within START_TIME and STOP_TIME, as are the useable times, and
+    /// that their rates match ATTITUDE_TYPE
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` describing the first problem found
+    pub fn validate(&self) -> PoliastroResult<()> {
+        let metadata = &self.metadata;
+        let time_scale = metadata.time_scale()?;
+        let text = |epoch: &Epoch| format_epoch(epoch, time_scale);
+        let before = |a: &Epoch, b: &Epoch| b.duration_since(a).to_seconds() > 0.0;
+
+        let (Some(first), Some(last)) = (self.attitudes.first(), self.attitudes.last()) else {
+            return Err(invalid(format!(
+                "segment of {} has no attitudes",
+                metadata.object_name
+            )));
+        };
+        if before(&metadata.stop_time, &metadata.start_time) {
+            return Err(invalid("STOP_TIME is before START_TIME"));
+        }
+        for (key, epoch) in [
+            ("USEABLE_START_TIME", metadata.useable_start_time),
+            ("USEABLE_STOP_TIME", metadata.useable_stop_time),
+        ] {
+            if let Some(epoch) = epoch {
+                if before(&epoch, &metadata.start_time) || before(&metadata.stop_time, &epoch) {
+                    return Err(invalid(format!(
+                        "{key} {} is outside START_TIME and STOP_TIME",
+                        text(&epoch)
+                    )));
+                }
+            }
+        }
+        if metadata.attitude_type == AttitudeType::QuaternionRate && metadata.rate_frame.is_none() {
+            return Err(invalid("QUATERNION/RATE without RATE_FRAME"));
+        }
+
+        for pair in self.attitudes.windows(2) {
+            if !before(&pair[0].epoch, &pair[1].epoch) {
+                return Err(invalid(format!(
+                    "attitude at {} is not after the attitude at {}",
+                    text(&pair[1].epoch),
+                    text(&pair[0].epoch)
+                )));
+            }
+        }
+        for attitude in [first, last] {
+            if before(&attitude.epoch, &metadata.start_time)
+                || before(&metadata.stop_time, &attitude.epoch)
+            {
+                return Err(invalid(format!(
+                    "attitude at {} is outside START_TIME and STOP_TIME",
+                    text(&attitude.epoch)
+                )));
+            }
+        }
+        if let Some(attitude) = self
+            .attitudes
+            .iter()
+            .find(|attitude| attitude.attitude_type() != metadata.attitude_type)
+        {
+            return Err(invalid(format!(
+                "attitude at {} does not match ATTITUDE_TYPE {}",
+                text(&attitude.epoch),
+                metadata.attitude_type.name()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether the attitudes of the segment cover `epoch`
+    fn covers(&self, epoch: &Epoch) -> bool {
+        match (self.attitudes.first(), self.attitudes.last()) {
+            (Some(first), Some(last)) => {
+                epoch.duration_since(&first.epoch).to_seconds() >= 0.0
+                    && last.epoch.duration_since(epoch).to_seconds() >= 0.0
+            }
+            _ => false,
+        }
+    }
+
+    /// Rotation from frame A to frame B at `epoch`, interpolated spherically
+    /// between the bracketing attitudes
+    ///
+    /// # Errors
+    ///
+    /// `OutOfRange` if the attitudes do not cover `epoch`, `InvalidMessage`
+    /// if there are none
+    pub fn rotation(&self, epoch: &Epoch) -> PoliastroResult<UnitQuaternion> {
+        let (Some(first), Some(last)) = (self.attitudes.first(), self.attitudes.last()) else {
+            return Err(invalid("segment has no attitudes"));
+        };
+        if !self.covers(epoch) {
+            let (first, last) = (&first.epoch, &last.epoch);
+            return Err(PoliastroError::out_of_range(
+                "epoch (s from the first attitude)",
+                epoch.duration_since(first).to_seconds(),
+                0.0,
+                last.duration_since(first).to_seconds(),
+            ));
+        }
+        let index = self
+            .attitudes
+            .partition_point(|attitude| epoch.duration_since(&attitude.epoch).to_seconds() > 0.0);
+        let after = &self.attitudes[index];
+        let rotation = if index == 0 {
+            after.quaternion
+        } else {
+            let before = &self.attitudes[index - 1];
+            let span = after.epoch.duration_since(&before.epoch).to_seconds();
+            let t = epoch.duration_since(&before.epoch).to_seconds() / span;
+            // q and -q are the same rotation: take the shorter arc
+            let mut end = after.quaternion;
+            if before.quaternion.coords.dot(&end.coords) < 0.0 {
+                end = UnitQuaternion::new_unchecked(-end.into_inner());
+            }
+            before.quaternion.slerp(&end, t)
+        };
+        Ok(self.metadata.direction.a_to_b(&rotation))
+    }
+}
+
+/// An attitude ephemeris message: header and segments
+#[derive(Debug, Clone, PartialEq)]
+pub struct Aem {
+    /// Agency or operator creating the message (ORIGINATOR)
+    pub originator: String,
+    /// Creation time, UTC (CREATION_DATE)
+    pub creation_date: String,
+    /// COMMENT lines of the header
+    pub comments: Vec<String>,
+    /// Segments, in message order
+    pub segments: Vec<AemSegment>,
+}
+
+impl Aem {
+    /// AEM of `segments` from `originator`, created now
+    pub fn new(originator: impl Into<String>, segments: Vec<AemSegment>) -> Self {
+        Self {
+            originator: originator.into(),
+            creation_date: format_epoch(&Epoch::now(), TimeScale::UTC),
+            comments: Vec::new(),
+            segments,
+        }
+    }
+
+    /// Rotation from frame A to frame B at `epoch`, from the last segment
+    /// whose attitudes cover it
+    ///
+    /// # Errors
+    ///
+    /// `OutOfRange` if no segment covers `epoch`
+    pub fn rotation(&self, epoch: &Epoch) -> PoliastroResult<UnitQuaternion> {
+        match self
+            .segments
+            .iter()
+            .rev()
+            .find(|segment| segment.covers(epoch))
+        {
+            Some(segment) => segment.rotation(epoch),
+            None => {
+                let segment = self
+                    .segments
+                    .first()
+                    .ok_or_else(|| invalid("no segments"))?;
+                segment.rotation(epoch)
+            }
+        }
+    }
+
+    /// Write the message in CCSDS KVN
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` if a segment is invalid
+    pub fn to_kvn(&self) -> PoliastroResult<String> {
+        let mut kvn = KvnWriter::new();
+        kvn.field("CCSDS_AEM_VERS", CCSDS_AEM_VERSION);
+        kvn.comments(&self.comments);
+        kvn.field("CREATION_DATE", &self.creation_date);
+        kvn.field("ORIGINATOR", &self.originator);
+
+        for segment in &self.segments {
+            segment.validate()?;
+            let time_scale = segment.metadata.time_scale()?;
+
+            kvn.blank();
+            kvn.keyword("META_START");
+            kvn.comments(&segment.metadata.comments);
+            for (key, value) in metadata_fields(&segment.metadata, time_scale, true) {
+                kvn.field(key, value);
+            }
+            kvn.keyword("META_STOP");
+
+            kvn.blank();
+            kvn.keyword("DATA_START");
+            kvn.comments(&segment.comments);
+            for attitude in &segment.attitudes {
+                let values: Vec<String> =
+                    std::iter::once(format_epoch(&attitude.epoch, time_scale))
+                        .chain(attitude.values(segment.metadata.scalar_first))
+                        .collect();
+                kvn.values(&values);
+            }
+            kvn.keyword("DATA_STOP");
+        }
+        Ok(kvn.finish())
+    }
+
+    /// Write the message in CCSDS NDM/XML
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` if a segment is invalid
+    pub fn to_xml(&self) -> PoliastroResult<String> {
+        let mut xml = XmlWriter::new("aem", CCSDS_AEM_VERSION);
+        xml.open("header");
+        xml.comments(&self.comments);
+        xml.leaf("CREATION_DATE", &self.creation_date, None);
+        xml.leaf("ORIGINATOR", &self.originator, None);
+        xml.close("header");
+
+        xml.open("body");
+        for segment in &self.segments {
+            segment.validate()?;
+            let time_scale = segment.metadata.time_scale()?;
+
+            xml.open("segment");
+            xml.open("metadata");
+            xml.comments(&segment.metadata.comments);
+            for (key, value) in metadata_fields(&segment.metadata, time_scale, false) {
+                xml.leaf(key, &value, None);
+            }
+            xml.close("metadata");
+
+            xml.open("data");
+            xml.comments(&segment.comments);
+            let tag = segment.metadata.attitude_type.xml_tag();
+            for attitude in &segment.attitudes {
+                xml.open("attitudeState");
+                xml.open(tag);
+                xml.leaf("EPOCH", &format_epoch(&attitude.epoch, time_scale), None);
+                let mut quaternion = attitude.fields();
+                let rate = quaternion.split_off(4);
+                xml.block(
+                    "quaternion",
+                    quaternion
+                        .into_iter()
+                        .map(|(key, value)| (key, value, None)),
+                );
+                match attitude.rate {
+                    Some(AttitudeRate::Derivative(_)) => xml.block(
+                        "quaternionRate",
+                        rate.into_iter()
+                            .map(|(key, value)| (key, value, Some("1/s"))),
+                    ),
+                    Some(AttitudeRate::Angular(_)) => xml.block(
+                        "rotationRates",
+                        rate.into_iter()
+                            .map(|(key, value)| (key, value, Some("deg/s"))),
+                    ),
+                    None => {}
+                }
+                xml.close(tag);
+                xml.close("attitudeState");
+            }
+            xml.close("data");
+            xml.close("segment");
+        }
+        xml.close("body");
+        Ok(xml.finish("aem"))
+    }
+}
+
+/// Parse an AEM in CCSDS KVN or XML (detected from the text)
+///
+/// # Errors
+///
+/// `InvalidMessage` if the message is malformed, misses a mandatory
+/// keyword, has an unsupported attitude type, a quaternion that is not of
+/// unit norm, no segments, or a segment fails [`AemSegment::validate`]
+///
+/// # Example
+///
+/// ```ignore
+/// let aem = parse_aem(&std::fs::read_to_string("sat.aem")?)?;
+/// for segment in &aem.segments {
+///     println!("{}: {} attitudes", segment.metadata.object_name, segment.attitudes.len());
+/// }
+/// ```
+pub fn parse_aem(text: &str) -> PoliastroResult<Aem> {
+    match Encoding::detect(text) {
+        Encoding::Kvn => aem_from_kvn(text),
+        Encoding::Xml => aem_from_xml(text),
+        Encoding::Json => Err(invalid("JSON is not a CCSDS AEM encoding")),
+    }
+}
+
+/// Write an AEM in `encoding` (KVN or XML)
+///
+/// # Errors
+///
+/// `InvalidMessage` for the JSON encoding or if a segment is invalid
+pub fn write_aem(aem: &Aem, encoding: Encoding) -> PoliastroResult<String> {
+    match encoding {
+        Encoding::Kvn => aem.to_kvn(),
+        Encoding::Xml => aem.to_xml(),
+        Encoding::Json => Err(invalid("JSON is not a CCSDS AEM encoding")),
+    }
+}
+
+fn invalid(reason: impl Into<String>) -> PoliastroError {
+    PoliastroError::invalid_message(MESSAGE_TYPE, reason)
+}
+
+/// Prefix the reason of a message error with a KVN line number
+fn at_line(line: usize, error: PoliastroError) -> PoliastroError {
+    match error {
+        PoliastroError::InvalidMessage {
+            message_type,
+            reason,
+        } => PoliastroError::invalid_message(message_type, format!("line {line}: {reason}")),
+        other => other,
+    }
+}
+
+/// Metadata keywords and values, in CCSDS order; QUATERNION_TYPE only
+/// matters to KVN data lines
+fn metadata_fields(
+    metadata: &AemMetadata,
+    time_scale: TimeScale,
+    kvn: bool,
+) -> Vec<(&'static str, String)> {
+    let epoch = |epoch: &Epoch| format_epoch(epoch, time_scale);
+    let mut fields = vec![
+        ("OBJECT_NAME", metadata.object_name.clone()),
+        ("OBJECT_ID", metadata.object_id.clone()),
+    ];
+    if let Some(center_name) = &metadata.center_name {
+        fields.push(("CENTER_NAME", center_name.clone()));
+    }
+    fields.push(("REF_FRAME_A", metadata.frame_a.clone()));
+    fields.push(("REF_FRAME_B", metadata.frame_b.clone()));
+    fields.push(("ATTITUDE_DIR", metadata.direction.name().to_string()));
+    fields.push(("TIME_SYSTEM", metadata.time_system.clone()));
+    fields.push(("START_TIME", epoch(&metadata.start_time)));
+    if let Some(useable_start) = &metadata.useable_start_time {
+        fields.push(("USEABLE_START_TIME", epoch(useable_start)));
+    }
+    if let Some(useable_stop) = &metadata.useable_stop_time {
+        fields.push(("USEABLE_STOP_TIME", epoch(useable_stop)));
+    }
+    fields.push(("STOP_TIME", epoch(&metadata.stop_time)));
+    fields.push(("ATTITUDE_TYPE", metadata.attitude_type.name().to_string()));
+    if kvn {
+        let order = if metadata.scalar_first {
+            "FIRST"
+        } else {
+            "LAST"
+        };
+        fields.push(("QUATERNION_TYPE", order.to_string()));
+    }
+    if let Some(rate_frame) = &metadata.rate_frame {
+        fields.push(("RATE_FRAME", rate_frame.clone()));
+    }
+    if let Some(method) = &metadata.interpolation_method {
+        fields.push(("INTERPOLATION_METHOD", method.clone()));
+    }
+    if let Some(degree) = metadata.interpolation_degree {
+        fields.push(("INTERPOLATION_DEGREE", degree.to_string()));
+    }
+    fields
+}
+
+fn metadata_from_fields(fields: &Fields) -> PoliastroResult<AemMetadata> {
+    const KEYS: [&str; 17] = [
+        "OBJECT_NAME",
+        "OBJECT_ID",
+        "CENTER_NAME",
+        "REF_FRAME_A",
+        "REF_FRAME_B",
+        "ATTITUDE_DIR",
+        "TIME_SYSTEM",
+        "START_TIME",
+        "USEABLE_START_TIME",
+        "USEABLE_STOP_TIME",
+        "STOP_TIME",
+        "ATTITUDE_TYPE",
+        "QUATERNION_TYPE",
+        "EULER_ROT_SEQ",
+        "RATE_FRAME",
+        "INTERPOLATION_METHOD",
+        "INTERPOLATION_DEGREE",
+    ];
+    if let Some((key, _)) = fields.iter().find(|(key, _)| !KEYS.contains(key)) {
+        return Err(invalid(format!("unexpected metadata keyword {key}")));
+    }
+
+    let time_system = fields.require("TIME_SYSTEM")?.to_string();
+    let time_scale = super::time_scale(&time_system, MESSAGE_TYPE)?;
+    let epoch = |key: &str| parse_epoch(fields.require(key)?, time_scale, MESSAGE_TYPE);
+    let optional_epoch = |key: &str| {
+        fields
+            .get(key)
+            .map(|value| parse_epoch(value, time_scale, MESSAGE_TYPE))
+            .transpose()
+    };
+    let scalar_first = match fields.get("QUATERNION_TYPE").map(str::to_ascii_uppercase) {
+        Some(order) if order == "FIRST" => true,
+        Some(order) if order == "LAST" => false,
+        None => false,
+        Some(order) => {
+            return Err(invalid(format!(
+                "unknown QUATERNION_TYPE '{order}' (expected FIRST or LAST)"
+            )))
+        }
+    };
+
+    Ok(AemMetadata {
+        object_name: fields.require("OBJECT_NAME")?.to_string(),
+        object_id: fields.require("OBJECT_ID")?.to_string(),
+        center_name: fields.get("CENTER_NAME").map(str::to_string),
+        frame_a: fields.require("REF_FRAME_A")?.to_string(),
+        frame_b: fields.require("REF_FRAME_B")?.to_string(),
+        direction: AttitudeDirection::from_name(fields.require("ATTITUDE_DIR")?, MESSAGE_TYPE)?,
+        start_time: epoch("START_TIME")?,
+        useable_start_time: optional_epoch("USEABLE_START_TIME")?,
+        useable_stop_time: optional_epoch("USEABLE_STOP_TIME")?,
+        stop_time: epoch("STOP_TIME")?,
+        attitude_type: AttitudeType::from_name(fields.require("ATTITUDE_TYPE")?)?,
+        scalar_first,
+        rate_frame: fields.get("RATE_FRAME").map(str::to_string),
+        interpolation_method: fields.get("INTERPOLATION_METHOD").map(str::to_string),
+        interpolation_degree: fields.parse("INTERPOLATION_DEGREE")?,
+        comments: fields.comments.clone(),
+        time_system,
+    })
+}
+
+/// Header and segments into a message
+fn complete_aem(header: &Fields, segments: Vec<AemSegment>) -> PoliastroResult<Aem> {
+    if segments.is_empty() {
+        return Err(invalid("no segments"));
+    }
+    for segment in &segments {
+        segment.validate()?;
+    }
+    Ok(Aem {
+        originator: header.require("ORIGINATOR")?.to_string(),
+        creation_date: header.require("CREATION_DATE")?.to_string(),
+        comments: header.comments.clone(),
+        segments,
+    })
+}
+
+/// Block of a KVN message being read
+enum KvnBlock {
+    Header,
+    Metadata(Fields),
+    /// Between META_STOP and DATA_START
+    BeforeData,
+    Data,
+    /// After DATA_STOP
+    AfterData,
+}
+
+fn aem_from_kvn(text: &str) -> PoliastroResult<Aem> {
+    let mut header = Fields::new(MESSAGE_TYPE);
+    let mut segments: Vec<AemSegment> = Vec::new();
+    let mut block = KvnBlock::Header;
+
+    for (line_no, line) in kvn::lines(text, MESSAGE_TYPE)? {
+        block = match (block, line) {
+            (KvnBlock::Header, KvnLine::Field { key, value }) => {
+                header.push(key, value);
+                KvnBlock::Header
+            }
+            (KvnBlock::Header, KvnLine::Comment(comment)) => {
+                header.comments.push(comment);
+                KvnBlock::Header
+            }
+            (KvnBlock::Header | KvnBlock::AfterData, KvnLine::Keyword(keyword))
+                if keyword == "META_START" =>
+            {
+                KvnBlock::Metadata(Fields::new(MESSAGE_TYPE))
+            }
+            (KvnBlock::Metadata(mut fields), KvnLine::Field { key, value }) => {
+                fields.push(key, value);
+                KvnBlock::Metadata(fields)
+            }
+            (KvnBlock::Metadata(mut fields), KvnLine::Comment(comment)) => {
+                fields.comments.push(comment);
+                KvnBlock::Metadata(fields)
+            }
+            (KvnBlock::Metadata(fields), KvnLine::Keyword(keyword)) if keyword == "META_STOP" => {
+                segments.push(AemSegment {
+                    metadata: metadata_from_fields(&fields).map_err(|e| at_line(line_no, e))?,
+                    comments: Vec::new(),
+                    attitudes: Vec::new(),
+                });
+                KvnBlock::BeforeData
+            }
+            (KvnBlock::BeforeData, KvnLine::Keyword(keyword)) if keyword == "DATA_START" => {
+                KvnBlock::Data
+            }
+            (block @ (KvnBlock::BeforeData | KvnBlock::Data), KvnLine::Comment(comment)) => {
+                if let Some(segment) = segments.last_mut() {
+                    segment.comments.push(comment);
+                }
+                block
+            }
+            (KvnBlock::Data, KvnLine::Values(values)) => {
+                if let Some(segment) = segments.last_mut() {
+                    let attitude = AemAttitude::from_values(&values, &segment.metadata)
+                        .map_err(|e| at_line(line_no, e))?;
+                    segment.attitudes.push(attitude);
+                }
+                KvnBlock::Data
+            }
+            (KvnBlock::Data, KvnLine::Keyword(keyword)) if keyword == "DATA_STOP" => {
+                KvnBlock::AfterData
+            }
+            (_, other) => {
+                let reason = match other {
+                    KvnLine::Field { key, .. } => format!("unexpected keyword {key}"),
+                    KvnLine::Comment(_) => "unexpected COMMENT".to_string(),
+                    KvnLine::Keyword(keyword) => format!("unexpected {keyword}"),
+                    KvnLine::Values(_) => "unexpected data line".to_string(),
+                };
+                return Err(at_line(line_no, invalid(reason)));
+            }
+        };
+    }
+
+    match block {
+        KvnBlock::Header | KvnBlock::AfterData => {}
+        KvnBlock::Metadata(_) => return Err(invalid("missing META_STOP")),
+        KvnBlock::BeforeData => return Err(invalid("missing DATA_START")),
+        KvnBlock::Data => return Err(invalid("missing DATA_STOP")),
+    }
+    header.require("CCSDS_AEM_VERS")?;
+    complete_aem(&header, segments)
+}
+
+fn aem_from_xml(text: &str) -> PoliastroResult<Aem> {
+    let root = XmlElement::parse(text, MESSAGE_TYPE)?;
+    if root.name != "aem" {
+        return Err(invalid(format!(
+            "root element is <{}>, not <aem>",
+            root.name
+        )));
+    }
+    let header = root
+        .child("header")
+        .map(|header| header.fields(MESSAGE_TYPE))
+        .unwrap_or_else(|| Fields::new(MESSAGE_TYPE));
+    let body = root
+        .child("body")
+        .ok_or_else(|| invalid("missing <body>"))?;
+
+    let mut segments = Vec::new();
+    for segment in body.children_named("segment") {
+        let metadata = metadata_from_fields(
+            &segment
+                .child("metadata")
+                .ok_or_else(|| invalid("segment without <metadata>"))?
+                .fields(MESSAGE_TYPE),
+        )?;
+        let data = segment
+            .child("data")
+            .ok_or_else(|| invalid("segment without <data>"))?;
+        let attitudes = data
+            .children_named("attitudeState")
+            .map(|state| AemAttitude::from_fields(&state.flat_fields(MESSAGE_TYPE), &metadata))
+            .collect::<PoliastroResult<Vec<_>>>()?;
+        segments.push(AemSegment {
+            metadata,
+            comments: data.fields(MESSAGE_TYPE).comments,
+            attitudes,
+        });
+    }
+    complete_aem(&header, segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::time::Duration;
+
+    /// Example 4-2 of CCSDS 504.0-B-1, shortened
+    const AEM_KVN: &str = "CCSDS_AEM_VERS = 1.0
+COMMENT This file is a dummy example of attitude data
+CREATION_DATE = 2002-11-04T17:22:31
+ORIGINATOR = NASA/JPL
+
+META_START
+COMMENT This example shows an AEM with a rotation
+OBJECT_NAME = MARS GLOBAL SURVEYOR
+OBJECT_ID = 1996-062A
+CENTER_NAME = mars barycenter
+REF_FRAME_A = EME2000
+REF_FRAME_B = SC_BODY_1
+ATTITUDE_DIR = A2B
+TIME_SYSTEM = UTC
+START_TIME = 1996-11-28T22:08:02.5555
+USEABLE_START_TIME = 1996-11-28T22:08:03.5555
+USEABLE_STOP_TIME = 1996-11-30T01:18:02.5555
+STOP_TIME = 1996-11-30T01:28:02.5555
+ATTITUDE_TYPE = QUATERNION
+QUATERNION_TYPE = LAST
+INTERPOLATION_METHOD = hermite
+INTERPOLATION_DEGREE = 7
+META_STOP
+
+DATA_START
+1996-11-28T22:08:02.5555 0.56748 0.03146 0.45689 0.68427
+1996-11-28T22:08:03.5555 0.71934 0.05805 0.29677 0.62420
+1996-11-30T01:28:02.5555 -0.64585 0.14644 -0.26070 0.70294
+DATA_STOP
+
+META_START
+OBJECT_NAME = MARS GLOBAL SURVEYOR
+OBJECT_ID = 1996-062A
+REF_FRAME_A = EME2000
+REF_FRAME_B = SC_BODY_1
+ATTITUDE_DIR = B2A
+TIME_SYSTEM = UTC
+START_TIME = 1996-12-18T12:10:00.5555
+STOP_TIME = 1996-12-18T12:10:10.5555
+ATTITUDE_TYPE = QUATERNION/RATE
+QUATERNION_TYPE = FIRST
+RATE_FRAME = REF_FRAME_B
+META_STOP
+
+DATA_START
+COMMENT Spinning about the body Z axis
+1996-12-18T12:10:00.5555 1.0 0.0 0.0 0.0 0.0 0.0 0.6
+1996-12-18T12:10:10.5555 0.99619470 0.0 0.0 0.08715574 0.0 0.0 0.6
+DATA_STOP
+";
+
+    #[test]
+    fn test_parse_aem_kvn() {
+        let aem = parse_aem(AEM_KVN).unwrap();
+        assert_eq!(aem.originator, "NASA/JPL");
+        assert_eq!(aem.segments.len(), 2);
+
+        let first = &aem.segments[0];
+        assert_eq!(first.metadata.object_name, "MARS GLOBAL SURVEYOR");
+        assert_eq!(first.metadata.direction, AttitudeDirection::AToB);
+        assert_eq!(first.metadata.attitude_type, AttitudeType::Quaternion);
+        assert_eq!(first.metadata.interpolation_degree, Some(7));
+        assert_eq!(first.attitudes.len(), 3);
+        let [q1, _, _, qc] = quaternion_components(&first.attitudes[0].quaternion);
+        assert!((q1 - 0.56748).abs() < 1e-4 && (qc - 0.68427).abs() < 1e-4);
+
+        let second = &aem.segments[1];
+        assert!(second.metadata.scalar_first);
+        assert_eq!(second.comments, vec!["Spinning about the body Z axis"]);
+        // Scalar first: identity, then 10° about Z
+        assert!(second.attitudes[0].quaternion.angle() < 1e-12);
+        let angle = second.attitudes[1].quaternion.angle();
+        assert!((angle - 10.0_f64.to_radians()).abs() < 1e-6);
+        assert_eq!(
+            second.attitudes[1].rate,
+            Some(AttitudeRate::Angular(Vector3::new(
+                0.0,
+                0.0,
+                0.6_f64.to_radians()
+            )))
+        );
+    }
+
+    #[test]
+    fn test_aem_rotation_interpolation() {
+        let aem = parse_aem(AEM_KVN).unwrap();
+        let segment = &aem.segments[1];
+        let start = segment.attitudes[0].epoch;
+        let middle = start.add_duration(Duration::from_seconds(5.0));
+
+        // B2A: the rotation from A to B is the inverse of the message one
+        let rotation = aem.rotation(&middle).unwrap();
+        let expected = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 5.0_f64.to_radians());
+        assert!(rotation.angle_to(&expected.inverse()) < 1e-6);
+
+        // Ends of the segment give the message attitudes
+        let end = segment.attitudes[1].epoch;
+        let at_end = segment.rotation(&end).unwrap();
+        assert!(at_end.angle_to(&segment.attitudes[1].quaternion.inverse()) < 1e-12);
+
+        // The first segment covers its own attitudes
+        let first = &aem.segments[0];
+        let at_start = aem.rotation(&first.attitudes[0].epoch).unwrap();
+        assert!(at_start.angle_to(&first.attitudes[0].quaternion) < 1e-12);
+
+        let outside = start.add_duration(Duration::from_seconds(60.0));
+        assert!(matches!(
+            aem.rotation(&outside),
+            Err(PoliastroError::OutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_aem_kvn_xml_round_trip() {
+        let aem = parse_aem(AEM_KVN).unwrap();
+        for encoding in [Encoding::Kvn, Encoding::Xml] {
+            let text = write_aem(&aem, encoding).unwrap();
+            let read = parse_aem(&text).unwrap();
+            assert_eq!(read.segments.len(), 2);
+            for (read, expected) in read.segments.iter().zip(&aem.segments) {
+                let mut metadata = read.metadata.clone();
+                // XML does not carry QUATERNION_TYPE
+                metadata.scalar_first = expected.metadata.scalar_first;
+                assert_eq!(metadata, expected.metadata);
+                assert_eq!(read.comments, expected.comments);
+                for (read, expected) in read.attitudes.iter().zip(&expected.attitudes) {
+                    assert_eq!(read.epoch, expected.epoch);
+                    assert!(read.quaternion.angle_to(&expected.quaternion) < 1e-12);
+                    match (read.rate, expected.rate) {
+                        (Some(AttitudeRate::Angular(a)), Some(AttitudeRate::Angular(b))) => {
+                            assert!((a - b).norm() < 1e-15)
+                        }
+                        (a, b) => assert_eq!(a, b),
+                    }
+                }
+            }
+        }
+
+        // A pointing profile built from scratch
+        let epoch = Epoch::from_gregorian_utc(2024, 5, 1, 0, 0, 0, 0);
+        let attitudes = (0..5)
+            .map(|i| {
+                let mut attitude = AemAttitude::new(
+                    epoch.add_duration(Duration::from_seconds(60.0 * i as f64)),
+                    UnitQuaternion::from_axis_angle(&Vector3::x_axis(), 0.1 * i as f64),
+                );
+                attitude.rate = Some(AttitudeRate::Derivative(Vector4::new(
+                    0.05, 0.0, 0.0, -0.0025,
+                )));
+                attitude
+            })
+            .collect();
+        let segment =
+            AemSegment::new("SAT", "2024-001A", "EME2000", "SC_BODY_1", attitudes).unwrap();
+        assert_eq!(
+            segment.metadata.attitude_type,
+            AttitudeType::QuaternionDerivative
+        );
+        let aem = Aem::new("ASTRORA", vec![segment]);
+        let xml = write_aem(&aem, Encoding::Xml).unwrap();
+        assert!(xml.contains("<quaternionDerivative>"));
+        let read = parse_aem(&xml).unwrap();
+        for (read, expected) in read.segments[0]
+            .attitudes
+            .iter()
+            .zip(&aem.segments[0].attitudes)
+        {
+            assert_eq!(read.epoch, expected.epoch);
+            assert!(read.quaternion.angle_to(&expected.quaternion) < 1e-12);
+            assert_eq!(read.rate, expected.rate);
+        }
+    }
+
+    #[test]
+    fn test_aem_attitude_type_round_trips() {
+        for attitude_type in [
+            AttitudeType::Quaternion,
+            AttitudeType::QuaternionDerivative,
+            AttitudeType::QuaternionRate,
+        ] {
+            assert_eq!(
+                AttitudeType::from_name(attitude_type.name()).unwrap(),
+                attitude_type
+            );
+            let lower = attitude_type.name().to_ascii_lowercase();
+            assert_eq!(AttitudeType::from_name(&lower).unwrap(), attitude_type);
+        }
+
+        let epoch = Epoch::from_gregorian_utc(2024, 5, 1, 0, 0, 0, 0);
+        let axis = Vector3::new(1.0, -2.0, 0.5).normalize();
+        let rates = [
+            None,
+            Some(AttitudeRate::Derivative(Vector4::new(
+                0.01, -0.02, 0.005, -0.001,
+            ))),
+            Some(AttitudeRate::Angular(Vector3::new(0.001, -0.25, 0.5))),
+        ];
+        for rate in rates {
+            let attitudes: Vec<_> = (0..4)
+                .map(|i| AemAttitude {
+                    epoch: epoch.add_duration(Duration::from_seconds(10.0 * i as f64)),
+                    quaternion: UnitQuaternion::from_axis_angle(
+                        &nalgebra::Unit::new_normalize(axis),
+                        0.3 * i as f64 - 0.4,
+                    ),
+                    rate,
+                })
+                .collect();
+            let segment =
+                AemSegment::new("SAT", "2024-001A", "EME2000", "SC_BODY_1", attitudes).unwrap();
+            for (scalar_first, direction) in [
+                (false, AttitudeDirection::AToB),
+                (true, AttitudeDirection::BToA),
+            ] {
+                let mut segment = segment.clone();
+                segment.metadata.scalar_first = scalar_first;
+                segment.metadata.direction = direction;
+                segment.metadata.useable_start_time =
+                    Some(epoch.add_duration(Duration::from_seconds(5.0)));
+                segment.comments.push("Slew".to_string());
+                // Two segments, the second one later
+                let mut later = segment.clone();
+                for attitude in &mut later.attitudes {
+                    attitude.epoch = attitude.epoch.add_duration(Duration::from_seconds(3600.0));
+                }
+                later.metadata.start_time = later.attitudes[0].epoch;
+                later.metadata.stop_time = later.attitudes[3].epoch;
+                later.metadata.useable_start_time = None;
+                let aem = Aem::new("ASTRORA", vec![segment, later]);
+
+                for encoding in [Encoding::Kvn, Encoding::Xml] {
+                    let text = write_aem(&aem, encoding).unwrap();
+                    let read = parse_aem(&text).unwrap();
+                    assert_eq!(read.creation_date, aem.creation_date);
+                    assert_eq!(read.segments.len(), 2);
+                    for (read, expected) in read.segments.iter().zip(&aem.segments) {
+                        let mut metadata = read.metadata.clone();
+                        if encoding == Encoding::Xml {
+                            assert!(!metadata.scalar_first);
+                            metadata.scalar_first = scalar_first;
+                        }
+                        assert_eq!(metadata, expected.metadata);
+                        assert_eq!(read.comments, expected.comments);
+                        for (read, expected) in read.attitudes.iter().zip(&expected.attitudes) {
+                            assert_eq!(read.epoch, expected.epoch);
+                            assert!(read.quaternion.angle_to(&expected.quaternion) < 1e-12);
+                            match (read.rate, expected.rate) {
+                                (
+                                    Some(AttitudeRate::Angular(a)),
+                                    Some(AttitudeRate::Angular(b)),
+                                ) => {
+                                    assert!((a - b).norm() < 1e-15)
+                                }
+                                (a, b) => assert_eq!(a, b),
+                            }
+                        }
+                    }
+                    // The interpolated rotations are those of the message
+                    let middle = epoch.add_duration(Duration::from_seconds(3625.0));
+                    let rotation = read.rotation(&middle).unwrap();
+                    assert!(rotation.angle_to(&aem.rotation(&middle).unwrap()) < 1e-12);
+                }
+
+                // KVN data lines in the QUATERNION_TYPE order, with the
+                // angular rates in deg/s
+                let kvn = aem.to_kvn().unwrap();
+                let line = kvn
+                    .lines()
+                    .find(|line| line.starts_with("2024-05-01T00:00:10"))
+                    .unwrap();
+                let values: Vec<f64> = line
+                    .split_whitespace()
+                    .skip(1)
+                    .map(|v| v.parse().unwrap())
+                    .collect();
+                assert_eq!(
+                    values.len(),
+                    aem.segments[0].metadata.attitude_type.values()
+                );
+                let components = quaternion_components(&aem.segments[0].attitudes[1].quaternion);
+                let scalar = if scalar_first { values[0] } else { values[3] };
+                assert_eq!(scalar, components[3]);
+                if let Some(AttitudeRate::Angular(_)) = rate {
+                    assert_eq!(
+                        &values[4..],
+                        &[
+                            0.001_f64.to_degrees(),
+                            -0.25_f64.to_degrees(),
+                            0.5_f64.to_degrees()
+                        ]
+                    );
+                }
+                let tag = aem.segments[0].metadata.attitude_type.xml_tag();
+                assert!(aem.to_xml().unwrap().contains(&format!("<{tag}>")));
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_aem_invalid_input() {
+        let is_invalid =
+            |text: &str| matches!(parse_aem(text), Err(PoliastroError::InvalidMessage { .. }));
+
+        // Mandatory keywords, of the header and of the second segment
+        let second = AEM_KVN.find("META_START\nOBJECT_NAME").unwrap();
+        for key in ["CCSDS_AEM_VERS", "CREATION_DATE", "ORIGINATOR"] {
+            let at = AEM_KVN.find(key).unwrap();
+            let end = at + AEM_KVN[at..].find('\n').unwrap() + 1;
+            let text = format!("{}{}", &AEM_KVN[..at], &AEM_KVN[end..]);
+            assert!(is_invalid(&text), "without {key}");
+        }
+        for key in [
+            "OBJECT_NAME",
+            "OBJECT_ID",
+            "REF_FRAME_A",
+            "REF_FRAME_B",
+            "ATTITUDE_DIR",
+            "TIME_SYSTEM",
+            "START_TIME",
+            "STOP_TIME",
+            "ATTITUDE_TYPE",
+        ] {
+            let at = second + AEM_KVN[second..].find(&format!("{key} = ")).unwrap();
+            let end = at + AEM_KVN[at..].find('\n').unwrap() + 1;
+            let text = format!("{}{}", &AEM_KVN[..at], &AEM_KVN[end..]);
+            assert!(is_invalid(&text), "without {key}");
+        }
+
+        // Malformed values, spans and structure
+        for (from, to) in [
+            ("TIME_SYSTEM = UTC", "TIME_SYSTEM = SCLK"),
+            ("START_TIME = 1996-11-28T22:08:02.5555", "START_TIME = noon"),
+            ("INTERPOLATION_DEGREE = 7", "INTERPOLATION_DEGREE = seven"),
+            (
+                "USEABLE_START_TIME = 1996-11-28T22:08:03.5555",
+                "USEABLE_START_TIME = 1996-11-28T22:08:01",
+            ),
+            (
+                "USEABLE_STOP_TIME = 1996-11-30T01:18:02.5555",
+                "USEABLE_STOP_TIME = 1996-12-01T00:00:00",
+            ),
+            (
+                "STOP_TIME = 1996-11-30T01:28:02.5555",
+                "STOP_TIME = 1996-11-28T22:08:00",
+            ),
+            (
+                "STOP_TIME = 1996-11-30T01:28:02.5555",
+                "STOP_TIME = 1996-11-30T01:28:01",
+            ),
+            ("0.56748 0.03146", "0.56748 x0.03146"),
+            ("1996-11-28T22:08:02.5555 0.56748", "1996-11-28 0.56748"),
+            ("0.0 0.0 0.6\n1996", "0.0 0.6\n1996"),
+            ("0.0 0.0 0.6\n1996", "0.0 0.0 0.6 0.0\n1996"),
+            (
+                "ATTITUDE_TYPE = QUATERNION/RATE",
+                "ATTITUDE_TYPE = QUATERNION/DERIVATIVE",
+            ),
+            (
+                "ATTITUDE_TYPE = QUATERNION/RATE",
+                "ATTITUDE_TYPE = EULER_ANGLE",
+            ),
+            ("ATTITUDE_DIR = B2A", "ATTITUDE_DIR = BOTH"),
+            (
+                "DATA_START\n1996",
+                "1996-11-28T22:08:02.5555 1 0 0 0\nDATA_START\n1996",
+            ),
+            (
+                "DATA_START\nCOMMENT",
+                "DATA_START\nOBJECT_NAME = MGS\nCOMMENT",
+            ),
+        ] {
+            assert!(AEM_KVN.contains(from), "{from}");
+            assert!(is_invalid(&AEM_KVN.replacen(from, to, 1)), "{from} -> {to}");
+        }
+        let end = AEM_KVN.rfind("DATA_STOP").unwrap();
+        assert!(is_invalid(&AEM_KVN[..end]));
+        let meta = AEM_KVN.rfind("META_STOP").unwrap();
+        assert!(is_invalid(&AEM_KVN[..meta]));
+        assert!(is_invalid(
+            "CCSDS_AEM_VERS = 1.0\nCREATION_DATE = 2002-11-04T17:22:31\nORIGINATOR = JPL\n"
+        ));
+
+        // XML attitudes with missing or unexpected components
+        let xml = parse_aem(AEM_KVN).unwrap().to_xml().unwrap();
+        assert!(parse_aem(&xml).is_ok());
+        for (from, to) in [
+            ("<aem ", "<oem "),
+            ("<metadata>", "<meta>"),
+            ("<data>", "<records>"),
+            ("<Q2>0</Q2>", ""),
+            ("<Q2>0</Q2>", "<Q2>0.9</Q2>"),
+            ("<Z_RATE units=\"deg/s\">0.6</Z_RATE>", ""),
+            ("<ATTITUDE_DIR>B2A</ATTITUDE_DIR>", ""),
+            (
+                "<ATTITUDE_TYPE>QUATERNION/RATE</ATTITUDE_TYPE>",
+                "<ATTITUDE_TYPE>QUATERNION/DERIVATIVE</ATTITUDE_TYPE>",
+            ),
+        ] {
+            assert!(xml.contains(from), "{from}");
+            assert!(
+                parse_aem(&xml.replacen(from, to, 1)).is_err(),
+                "{from} -> {to}"
+            );
+        }
+
+        // Segments that are not written
+        let epoch = Epoch::from_gregorian_utc(2024, 5, 1, 0, 0, 0, 0);
+        let later = epoch.add_duration(Duration::from_seconds(60.0));
+        let identity = UnitQuaternion::identity();
+        assert!(AemSegment::new("SAT", "X", "EME2000", "SC_BODY_1", vec![]).is_err());
+        let backwards = vec![
+            AemAttitude::new(later, identity),
+            AemAttitude::new(epoch, identity),
+        ];
+        assert!(AemSegment::new("SAT", "X", "EME2000", "SC_BODY_1", backwards).is_err());
+        let mut spinning = AemAttitude::new(later, identity);
+        spinning.rate = Some(AttitudeRate::Angular(Vector3::z()));
+        let mixed = vec![AemAttitude::new(epoch, identity), spinning];
+        assert!(AemSegment::new("SAT", "X", "EME2000", "SC_BODY_1", mixed).is_err());
+        let mut aem = parse_aem(AEM_KVN).unwrap();
+        aem.segments[1].metadata.rate_frame = None;
+        assert!(aem.to_kvn().is_err() && aem.to_xml().is_err());
+        assert!(Aem::new("ASTRORA", vec![])
+            .to_kvn()
+            .map(|text| is_invalid(&text))
+            .unwrap());
+    }
+
+    #[test]
+    fn test_parse_aem_errors() {
+        let replace = |from: &str, to: &str| parse_aem(&AEM_KVN.replacen(from, to, 1));
+        let is_invalid = |result: PoliastroResult<Aem>| {
+            matches!(result, Err(PoliastroError::InvalidMessage { .. }))
+        };
+
+        assert!(is_invalid(replace(
+            "ATTITUDE_TYPE = QUATERNION\n",
+            "ATTITUDE_TYPE = EULER_ANGLE\n"
+        )));
+        assert!(is_invalid(replace(
+            "QUATERNION_TYPE = LAST",
+            "QUATERNION_TYPE = MIDDLE"
+        )));
+        assert!(is_invalid(replace(
+            "ATTITUDE_DIR = A2B",
+            "ATTITUDE_DIR = AB"
+        )));
+        assert!(is_invalid(replace("0.45689 0.68427", "0.45689")));
+        assert!(is_invalid(replace("0.45689 0.68427", "0.45689 0.9")));
+        assert!(is_invalid(replace("RATE_FRAME = REF_FRAME_B\n", "")));
+        assert!(is_invalid(replace(
+            "1996-11-28T22:08:03.5555 0.71934",
+            "1996-11-28T22:08:01.5555 0.71934"
+        )));
+        assert!(is_invalid(replace(
+            "DATA_STOP\n\nMETA_START",
+            "\nMETA_START"
+        )));
+        assert!(is_invalid(replace(
+            "META_STOP\n\nDATA_START",
+            "META_STOP\n"
+        )));
+        assert!(is_invalid(replace("EME2000", "EME2000\nREF_FRAME = X")));
+        assert!(is_invalid(parse_aem("<aem><body/></aem>")));
+    }
+}
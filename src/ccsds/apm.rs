@@ -0,0 +1,1139 @@
+//! Attitude Parameter Message (APM)
+//!
+//! Reading and writing of CCSDS APMs in KVN and XML.
+//!
+//! An APM gives the attitude of one object at one epoch: a header, metadata
+//! (object, center and time system), the quaternion between two frames with
+//! optionally its rate of change, and optionally Euler angles, spin
+//! parameters, the inertia tensor and planned attitude maneuvers.
+//! Quaternions are read into [`UnitQuaternion`]s and torques into N·m; the
+//! Euler angle and spin keywords are kept verbatim and written back in CCSDS
+//! order.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use astrora_core::ccsds::parse_apm;
+//!
+//! let apm = parse_apm(&std::fs::read_to_string("sat.apm")?)?;
+//! let body_axis = apm.rotation() * Vector3::x();
+//! ```
+//!
+//! # References
+//!
+//! - CCSDS 504.0-B-1: Attitude Data Messages, section 3
+
+use hifitime::TimeScale;
+
+use super::kvn::{self, KvnLine, KvnWriter};
+use super::xml::{XmlElement, XmlWriter};
+use super::{
+    format_epoch, format_number, parse_epoch, quaternion_components, AttitudeDirection, Encoding,
+    Fields, QUATERNION_KEYS, QUATERNION_RATE_KEYS,
+};
+use crate::core::linalg::{Matrix3, UnitQuaternion, Vector3, Vector4};
+use crate::core::time::{Duration, Epoch};
+use crate::core::{PoliastroError, PoliastroResult};
+
+/// Version of CCSDS 504.0 written in KVN and XML messages
+pub const CCSDS_APM_VERSION: &str = "1.0";
+
+const MESSAGE_TYPE: &str = "APM";
+
+const HEADER_KEYS: [&str; 2] = ["CREATION_DATE", "ORIGINATOR"];
+
+const METADATA_KEYS: [&str; 4] = ["OBJECT_NAME", "OBJECT_ID", "CENTER_NAME", "TIME_SYSTEM"];
+
+const QUATERNION_FRAME_KEYS: [&str; 4] = ["EPOCH", "Q_FRAME_A", "Q_FRAME_B", "Q_DIR"];
+
+/// Euler angle keywords, kept verbatim, in CCSDS order
+const EULER_KEYS: [&str; 11] = [
+    "EULER_FRAME_A",
+    "EULER_FRAME_B",
+    "EULER_DIR",
+    "EULER_ROT_SEQ",
+    "RATE_FRAME",
+    "X_ANGLE",
+    "Y_ANGLE",
+    "Z_ANGLE",
+    "X_RATE",
+    "Y_RATE",
+    "Z_RATE",
+];
+
+/// Spin keywords, kept verbatim, in CCSDS order
+const SPIN_KEYS: [&str; 10] = [
+    "SPIN_FRAME_A",
+    "SPIN_FRAME_B",
+    "SPIN_DIR",
+    "SPIN_ALPHA",
+    "SPIN_DELTA",
+    "SPIN_ANGLE",
+    "SPIN_ANGLE_VEL",
+    "NUTATION",
+    "NUTATION_PER",
+    "NUTATION_PHASE",
+];
+
+/// Inertia tensor keywords, diagonal first
+const INERTIA_KEYS: [&str; 6] = ["I11", "I22", "I33", "I12", "I13", "I23"];
+
+/// Row and column of each of [`INERTIA_KEYS`]
+const INERTIA_INDICES: [(usize, usize); 6] = [(0, 0), (1, 1), (2, 2), (0, 1), (0, 2), (1, 2)];
+
+const MANEUVER_KEYS: [&str; 6] = [
+    "MAN_EPOCH_START",
+    "MAN_DURATION",
+    "MAN_REF_FRAME",
+    "MAN_TOR_1",
+    "MAN_TOR_2",
+    "MAN_TOR_3",
+];
+
+/// Metadata of an APM
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApmMetadata {
+    /// Spacecraft name (OBJECT_NAME)
+    pub object_name: String,
+    /// International designator (OBJECT_ID)
+    pub object_id: String,
+    /// Origin of the frames, if relevant (CENTER_NAME)
+    pub center_name: Option<String>,
+    /// Time system of all epochs of the message (TIME_SYSTEM)
+    pub time_system: String,
+    /// COMMENT lines of the metadata block
+    pub comments: Vec<String>,
+}
+
+impl ApmMetadata {
+    fn time_scale(&self) -> PoliastroResult<TimeScale> {
+        super::time_scale(&self.time_system, MESSAGE_TYPE)
+    }
+}
+
+/// A planned attitude maneuver of an APM
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttitudeManeuver {
+    /// Start of the maneuver (MAN_EPOCH_START)
+    pub epoch_start: Epoch,
+    /// Duration in s (MAN_DURATION)
+    pub duration: f64,
+    /// Frame of the torque (MAN_REF_FRAME)
+    pub ref_frame: String,
+    /// Torque in N·m (MAN_TOR_1, MAN_TOR_2, MAN_TOR_3)
+    pub torque: Vector3,
+    /// COMMENT lines of the maneuver
+    pub comments: Vec<String>,
+}
+
+impl AttitudeManeuver {
+    fn validate(&self) -> PoliastroResult<()> {
+        if self.duration < 0.0 || !self.duration.is_finite() {
+            return Err(invalid(format!(
+                "MAN_DURATION {} is not a non-negative duration",
+                self.duration
+            )));
+        }
+        if self.ref_frame.trim().is_empty() {
+            return Err(invalid("maneuver without MAN_REF_FRAME"));
+        }
+        if !self.torque.iter().all(|value| value.is_finite()) {
+            return Err(invalid("maneuver torque is not finite"));
+        }
+        Ok(())
+    }
+
+    /// Keywords and values, in CCSDS order
+    fn fields(&self, time_scale: TimeScale) -> Vec<(&'static str, String)> {
+        let mut fields = vec![
+            (
+                MANEUVER_KEYS[0],
+                format_epoch(&self.epoch_start, time_scale),
+            ),
+            (MANEUVER_KEYS[1], format_number(self.duration)),
+            (MANEUVER_KEYS[2], self.ref_frame.clone()),
+        ];
+        for (key, value) in MANEUVER_KEYS[3..].iter().zip(self.torque.iter()) {
+            fields.push((key, format_number(*value)));
+        }
+        fields
+    }
+
+    fn from_fields(fields: &Fields, time_scale: TimeScale) -> PoliastroResult<Self> {
+        if let Some((key, _)) = fields.iter().find(|(key, _)| !MANEUVER_KEYS.contains(key)) {
+            return Err(invalid(format!("unexpected maneuver keyword {key}")));
+        }
+        let maneuver = Self {
+            epoch_start: parse_epoch(fields.require("MAN_EPOCH_START")?, time_scale, MESSAGE_TYPE)?,
+            duration: fields.require_parse("MAN_DURATION")?,
+            ref_frame: fields.require("MAN_REF_FRAME")?.to_string(),
+            torque: fields
+                .vector(&["MAN_TOR_1", "MAN_TOR_2", "MAN_TOR_3"], 1.0)?
+                .ok_or_else(|| invalid("maneuver without MAN_TOR_1, MAN_TOR_2 and MAN_TOR_3"))?,
+            comments: fields.comments.clone(),
+        };
+        maneuver.validate()?;
+        Ok(maneuver)
+    }
+}
+
+/// An attitude parameter message
+#[derive(Debug, Clone, PartialEq)]
+pub struct Apm {
+    /// Agency or operator creating the message (ORIGINATOR)
+    pub originator: String,
+    /// Creation time, UTC (CREATION_DATE)
+    pub creation_date: String,
+    /// COMMENT lines of the header
+    pub comments: Vec<String>,
+    /// Metadata block
+    pub metadata: ApmMetadata,
+    /// COMMENT lines of the quaternion, Euler, spin and inertia blocks
+    pub data_comments: Vec<String>,
+    /// Epoch of the attitude (EPOCH)
+    pub epoch: Epoch,
+    /// First frame of the quaternion (Q_FRAME_A)
+    pub frame_a: String,
+    /// Second frame of the quaternion (Q_FRAME_B)
+    pub frame_b: String,
+    /// Direction of the quaternion (Q_DIR)
+    pub direction: AttitudeDirection,
+    /// Rotation in `direction` (Q1, Q2, Q3, QC)
+    pub quaternion: UnitQuaternion,
+    /// Derivative of the quaternion in 1/s, in the order Q1, Q2, Q3, QC
+    /// (Q1_DOT … QC_DOT)
+    pub quaternion_rate: Option<Vector4>,
+    /// Euler angle and spin keywords, verbatim
+    pub parameters: Vec<(String, String)>,
+    /// Frame of the inertia tensor (INERTIA_REF_FRAME)
+    pub inertia_frame: Option<String>,
+    /// Inertia tensor in kg·m² (I11 … I23)
+    pub inertia: Option<Matrix3>,
+    /// Attitude maneuvers, in order of start
+    pub maneuvers: Vec<AttitudeManeuver>,
+}
+
+impl Apm {
+    /// APM of the rotation `quaternion` from `frame_a` to `frame_b` at
+    /// `epoch`, in UTC and created now
+    pub fn new(
+        originator: impl Into<String>,
+        object_name: impl Into<String>,
+        object_id: impl Into<String>,
+        epoch: Epoch,
+        frame_a: impl Into<String>,
+        frame_b: impl Into<String>,
+        quaternion: UnitQuaternion,
+    ) -> Self {
+        Self {
+            originator: originator.into(),
+            creation_date: format_epoch(&Epoch::now(), TimeScale::UTC),
+            comments: Vec::new(),
+            metadata: ApmMetadata {
+                object_name: object_name.into(),
+                object_id: object_id.into(),
+                center_name: None,
+                time_system: "UTC".to_string(),
+                comments: Vec::new(),
+            },
+            data_comments: Vec::new(),
+            epoch,
+            frame_a: frame_a.into(),
+            frame_b: frame_b.into(),
+            direction: AttitudeDirection::AToB,
+            quaternion,
+            quaternion_rate: None,
+            parameters: Vec::new(),
+            inertia_frame: None,
+            inertia: None,
+            maneuvers: Vec::new(),
+        }
+    }
+
+    /// Rotation from frame A to frame B, whatever the direction of the
+    /// message
+    pub fn rotation(&self) -> UnitQuaternion {
+        self.direction.a_to_b(&self.quaternion)
+    }
+
+    /// Value of a verbatim Euler angle or spin keyword
+    pub fn parameter(&self, key: &str) -> Option<&str> {
+        self.parameters
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Check the time system, the inertia tensor and the maneuvers
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` describing the first problem found
+    pub fn validate(&self) -> PoliastroResult<()> {
+        let time_scale = self.metadata.time_scale()?;
+        if let Some(rate) = &self.quaternion_rate {
+            if !rate.iter().all(|value| value.is_finite()) {
+                return Err(invalid("quaternion rate is not finite"));
+            }
+        }
+        if let Some((key, _)) = self.parameters.iter().find(|(key, _)| {
+            !EULER_KEYS.contains(&key.as_str()) && !SPIN_KEYS.contains(&key.as_str())
+        }) {
+            return Err(invalid(format!("unexpected keyword {key}")));
+        }
+        if let Some(inertia) = &self.inertia {
+            if self.inertia_frame.is_none() {
+                return Err(invalid("inertia tensor without INERTIA_REF_FRAME"));
+            }
+            if (0..3).any(|i| inertia[(i, i)] <= 0.0) || inertia != &inertia.transpose() {
+                return Err(invalid(
+                    "inertia tensor is not symmetric with positive moments",
+                ));
+            }
+        }
+
+        for maneuver in &self.maneuvers {
+            maneuver.validate()?;
+        }
+        for pair in self.maneuvers.windows(2) {
+            let end = pair[0]
+                .epoch_start
+                .add_duration(Duration::from_seconds(pair[0].duration));
+            if pair[1].epoch_start.duration_since(&end).to_seconds() < 0.0 {
+                return Err(invalid(format!(
+                    "maneuver at {} starts before the end of the maneuver at {}",
+                    format_epoch(&pair[1].epoch_start, time_scale),
+                    format_epoch(&pair[0].epoch_start, time_scale)
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn metadata_fields(&self) -> Vec<(&'static str, String)> {
+        let metadata = &self.metadata;
+        let mut fields = vec![
+            ("OBJECT_NAME", metadata.object_name.clone()),
+            ("OBJECT_ID", metadata.object_id.clone()),
+        ];
+        if let Some(center_name) = &metadata.center_name {
+            fields.push(("CENTER_NAME", center_name.clone()));
+        }
+        fields.push(("TIME_SYSTEM", metadata.time_system.clone()));
+        fields
+    }
+
+    /// EPOCH, frames and direction of the quaternion
+    fn frame_fields(&self, time_scale: TimeScale) -> Vec<(&'static str, String)> {
+        vec![
+            ("EPOCH", format_epoch(&self.epoch, time_scale)),
+            ("Q_FRAME_A", self.frame_a.clone()),
+            ("Q_FRAME_B", self.frame_b.clone()),
+            ("Q_DIR", self.direction.name().to_string()),
+        ]
+    }
+
+    fn quaternion_fields(&self) -> Vec<(&'static str, String)> {
+        QUATERNION_KEYS
+            .iter()
+            .zip(quaternion_components(&self.quaternion))
+            .map(|(&key, value)| (key, format_number(value)))
+            .collect()
+    }
+
+    fn rate_fields(&self) -> Vec<(&'static str, String)> {
+        self.quaternion_rate
+            .iter()
+            .flat_map(|rate| {
+                QUATERNION_RATE_KEYS
+                    .iter()
+                    .zip(rate.iter())
+                    .map(|(&key, value)| (key, format_number(*value)))
+            })
+            .collect()
+    }
+
+    /// Verbatim keywords of `keys`, in CCSDS order
+    fn group_fields(&self, keys: &[&'static str]) -> Vec<(&'static str, String)> {
+        keys.iter()
+            .filter_map(|&key| self.parameter(key).map(|value| (key, value.to_string())))
+            .collect()
+    }
+
+    fn inertia_fields(&self) -> Vec<(&'static str, String)> {
+        let (Some(frame), Some(inertia)) = (&self.inertia_frame, &self.inertia) else {
+            return Vec::new();
+        };
+        std::iter::once(("INERTIA_REF_FRAME", frame.clone()))
+            .chain(
+                INERTIA_KEYS
+                    .iter()
+                    .zip(INERTIA_INDICES)
+                    .map(|(&key, index)| (key, format_number(inertia[index]))),
+            )
+            .collect()
+    }
+
+    /// Write the message in CCSDS KVN
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` if the message is invalid
+    pub fn to_kvn(&self) -> PoliastroResult<String> {
+        self.validate()?;
+        let time_scale = self.metadata.time_scale()?;
+        let mut kvn = KvnWriter::new();
+        kvn.field("CCSDS_APM_VERS", CCSDS_APM_VERSION);
+        kvn.comments(&self.comments);
+        kvn.field("CREATION_DATE", &self.creation_date);
+        kvn.field("ORIGINATOR", &self.originator);
+
+        kvn.blank();
+        kvn.comments(&self.metadata.comments);
+        for (key, value) in self.metadata_fields() {
+            kvn.field(key, value);
+        }
+
+        kvn.blank();
+        kvn.comments(&self.data_comments);
+        for (key, value) in self
+            .frame_fields(time_scale)
+            .into_iter()
+            .chain(self.quaternion_fields())
+            .chain(self.rate_fields())
+            .chain(self.group_fields(&EULER_KEYS))
+            .chain(self.group_fields(&SPIN_KEYS))
+            .chain(self.inertia_fields())
+        {
+            kvn.field(key, value);
+        }
+
+        for maneuver in &self.maneuvers {
+            kvn.blank();
+            kvn.comments(&maneuver.comments);
+            for (key, value) in maneuver.fields(time_scale) {
+                kvn.field(key, value);
+            }
+        }
+        Ok(kvn.finish())
+    }
+
+    /// Write the message in CCSDS NDM/XML
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` if the message is invalid
+    pub fn to_xml(&self) -> PoliastroResult<String> {
+        self.validate()?;
+        let time_scale = self.metadata.time_scale()?;
+        let no_units = |fields: Vec<(&'static str, String)>| {
+            fields.into_iter().map(|(key, value)| (key, value, None))
+        };
+        let mut xml = XmlWriter::new("apm", CCSDS_APM_VERSION);
+        xml.open("header");
+        xml.comments(&self.comments);
+        xml.leaf("CREATION_DATE", &self.creation_date, None);
+        xml.leaf("ORIGINATOR", &self.originator, None);
+        xml.close("header");
+
+        xml.open("body");
+        xml.open("segment");
+        xml.open("metadata");
+        xml.comments(&self.metadata.comments);
+        for (key, value) in self.metadata_fields() {
+            xml.leaf(key, &value, None);
+        }
+        xml.close("metadata");
+
+        xml.open("data");
+        xml.comments(&self.data_comments);
+        xml.open("quaternionState");
+        for (key, value) in self.frame_fields(time_scale) {
+            xml.leaf(key, &value, None);
+        }
+        xml.block("quaternion", no_units(self.quaternion_fields()));
+        let rate = self.rate_fields();
+        if !rate.is_empty() {
+            xml.block(
+                "quaternionRate",
+                rate.into_iter()
+                    .map(|(key, value)| (key, value, Some("1/s"))),
+            );
+        }
+        xml.close("quaternionState");
+
+        let euler = self.group_fields(&EULER_KEYS);
+        if !euler.is_empty() {
+            xml.open("eulerElementsThree");
+            let (frames, values): (Vec<_>, Vec<_>) = euler
+                .into_iter()
+                .partition(|(key, _)| !key.ends_with("_ANGLE") && !key.ends_with("_RATE"));
+            for (key, value) in frames {
+                xml.leaf(key, &value, None);
+            }
+            let (angles, rates): (Vec<_>, Vec<_>) = values
+                .into_iter()
+                .partition(|(key, _)| key.ends_with("_ANGLE"));
+            for (tag, fields, units) in [
+                ("rotationAngles", angles, "deg"),
+                ("rotationRates", rates, "deg/s"),
+            ] {
+                if !fields.is_empty() {
+                    xml.block(
+                        tag,
+                        fields
+                            .into_iter()
+                            .map(|(key, value)| (key, value, Some(units))),
+                    );
+                }
+            }
+            xml.close("eulerElementsThree");
+        }
+        let spin = self.group_fields(&SPIN_KEYS);
+        if !spin.is_empty() {
+            xml.block("eulerElementsSpin", no_units(spin));
+        }
+        let inertia = self.inertia_fields();
+        if !inertia.is_empty() {
+            xml.block(
+                "spacecraftParameters",
+                inertia.into_iter().map(|(key, value)| {
+                    let units = (key != "INERTIA_REF_FRAME").then_some("kg*m**2");
+                    (key, value, units)
+                }),
+            );
+        }
+        for maneuver in &self.maneuvers {
+            xml.open("maneuverParameters");
+            xml.comments(&maneuver.comments);
+            for (index, (key, value)) in maneuver.fields(time_scale).into_iter().enumerate() {
+                let units = match index {
+                    1 => Some("s"),
+                    3.. => Some("N*m"),
+                    _ => None,
+                };
+                xml.leaf(key, &value, units);
+            }
+            xml.close("maneuverParameters");
+        }
+        xml.close("data");
+        xml.close("segment");
+        xml.close("body");
+        Ok(xml.finish("apm"))
+    }
+}
+
+/// Parse an APM in CCSDS KVN or XML (detected from the text)
+///
+/// # Errors
+///
+/// `InvalidMessage` if the message is malformed, misses a mandatory
+/// keyword, has an unknown keyword, a quaternion that is not of unit norm,
+/// or fails [`Apm::validate`]
+///
+/// # Example
+///
+/// ```ignore
+/// let apm = parse_apm(&std::fs::read_to_string("sat.apm")?)?;
+/// println!("{} to {}: {:?}", apm.frame_a, apm.frame_b, apm.rotation());
+/// ```
+pub fn parse_apm(text: &str) -> PoliastroResult<Apm> {
+    match Encoding::detect(text) {
+        Encoding::Kvn => apm_from_kvn(text),
+        Encoding::Xml => apm_from_xml(text),
+        Encoding::Json => Err(invalid("JSON is not a CCSDS APM encoding")),
+    }
+}
+
+/// Write an APM in `encoding` (KVN or XML)
+///
+/// # Errors
+///
+/// `InvalidMessage` for the JSON encoding or if the message is invalid
+pub fn write_apm(apm: &Apm, encoding: Encoding) -> PoliastroResult<String> {
+    match encoding {
+        Encoding::Kvn => apm.to_kvn(),
+        Encoding::Xml => apm.to_xml(),
+        Encoding::Json => Err(invalid("JSON is not a CCSDS APM encoding")),
+    }
+}
+
+fn invalid(reason: impl Into<String>) -> PoliastroError {
+    PoliastroError::invalid_message(MESSAGE_TYPE, reason)
+}
+
+/// Block a KVN keyword belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum KvnBlock {
+    Header,
+    Metadata,
+    Data,
+    /// The n-th maneuver
+    Maneuver(usize),
+}
+
+fn apm_from_kvn(text: &str) -> PoliastroResult<Apm> {
+    let mut header = Fields::new(MESSAGE_TYPE);
+    let mut metadata = Fields::new(MESSAGE_TYPE);
+    let mut data = Fields::new(MESSAGE_TYPE);
+    let mut maneuvers: Vec<Fields> = Vec::new();
+    let mut block = KvnBlock::Header;
+    // Comments belong to the block of the keyword that follows them
+    let mut comments = Vec::new();
+
+    for (line_no, line) in kvn::lines(text, MESSAGE_TYPE)? {
+        let (key, value) = match line {
+            KvnLine::Comment(comment) => {
+                comments.push(comment);
+                continue;
+            }
+            KvnLine::Field { key, value } => (key, value),
+            KvnLine::Keyword(keyword) => {
+                return Err(invalid(format!("line {line_no}: unexpected {keyword}")))
+            }
+            KvnLine::Values(_) => {
+                return Err(invalid(format!("line {line_no}: unexpected data line")))
+            }
+        };
+
+        let target = if key == "CCSDS_APM_VERS" || HEADER_KEYS.contains(&key.as_str()) {
+            KvnBlock::Header
+        } else if METADATA_KEYS.contains(&key.as_str()) {
+            KvnBlock::Metadata
+        } else if key == "MAN_EPOCH_START" {
+            KvnBlock::Maneuver(maneuvers.len())
+        } else if key.starts_with("MAN_") {
+            match block {
+                KvnBlock::Maneuver(index) => KvnBlock::Maneuver(index),
+                _ => {
+                    return Err(invalid(format!(
+                        "line {line_no}: {key} before MAN_EPOCH_START"
+                    )))
+                }
+            }
+        } else {
+            KvnBlock::Data
+        };
+        if target < block {
+            return Err(invalid(format!("line {line_no}: unexpected keyword {key}")));
+        }
+        block = target;
+
+        let fields = match block {
+            KvnBlock::Header => &mut header,
+            KvnBlock::Metadata => &mut metadata,
+            KvnBlock::Data => &mut data,
+            KvnBlock::Maneuver(index) => {
+                if index == maneuvers.len() {
+                    maneuvers.push(Fields::new(MESSAGE_TYPE));
+                }
+                &mut maneuvers[index]
+            }
+        };
+        fields.comments.append(&mut comments);
+        fields.push(key, value);
+    }
+
+    // Trailing comments stay with the last block
+    match maneuvers.last_mut() {
+        Some(maneuver) => maneuver.comments.append(&mut comments),
+        None => data.comments.append(&mut comments),
+    }
+    header.require("CCSDS_APM_VERS")?;
+    complete_apm(&header, &metadata, &data, &maneuvers)
+}
+
+fn apm_from_xml(text: &str) -> PoliastroResult<Apm> {
+    let root = XmlElement::parse(text, MESSAGE_TYPE)?;
+    if root.name != "apm" {
+        return Err(invalid(format!(
+            "root element is <{}>, not <apm>",
+            root.name
+        )));
+    }
+    let header = root
+        .child("header")
+        .map(|header| header.fields(MESSAGE_TYPE))
+        .unwrap_or_else(|| Fields::new(MESSAGE_TYPE));
+    let segment = root
+        .child("body")
+        .and_then(|body| body.child("segment"))
+        .ok_or_else(|| invalid("missing <body> <segment>"))?;
+    let metadata = segment
+        .child("metadata")
+        .ok_or_else(|| invalid("segment without <metadata>"))?
+        .fields(MESSAGE_TYPE);
+    let data = segment
+        .child("data")
+        .ok_or_else(|| invalid("segment without <data>"))?;
+    let maneuvers: Vec<Fields> = data
+        .children_named("maneuverParameters")
+        .map(|maneuver| maneuver.fields(MESSAGE_TYPE))
+        .collect();
+    let mut attitude = data.clone();
+    attitude
+        .children
+        .retain(|child| child.name != "maneuverParameters");
+    complete_apm(
+        &header,
+        &metadata,
+        &attitude.flat_fields(MESSAGE_TYPE),
+        &maneuvers,
+    )
+}
+
+/// Header, metadata, data and maneuver fields into a message
+fn complete_apm(
+    header: &Fields,
+    metadata: &Fields,
+    data: &Fields,
+    maneuvers: &[Fields],
+) -> PoliastroResult<Apm> {
+    if let Some((key, _)) = metadata
+        .iter()
+        .find(|(key, _)| !METADATA_KEYS.contains(key))
+    {
+        return Err(invalid(format!("unexpected metadata keyword {key}")));
+    }
+    let known = |key: &str| {
+        key == "INERTIA_REF_FRAME"
+            || QUATERNION_FRAME_KEYS.contains(&key)
+            || QUATERNION_KEYS.contains(&key)
+            || QUATERNION_RATE_KEYS.contains(&key)
+            || EULER_KEYS.contains(&key)
+            || SPIN_KEYS.contains(&key)
+            || INERTIA_KEYS.contains(&key)
+    };
+    if let Some((key, _)) = data.iter().find(|(key, _)| !known(key)) {
+        return Err(invalid(format!("unexpected keyword {key}")));
+    }
+
+    let metadata = ApmMetadata {
+        object_name: metadata.require("OBJECT_NAME")?.to_string(),
+        object_id: metadata.require("OBJECT_ID")?.to_string(),
+        center_name: metadata.get("CENTER_NAME").map(str::to_string),
+        time_system: metadata.require("TIME_SYSTEM")?.to_string(),
+        comments: metadata.comments.clone(),
+    };
+    let time_scale = metadata.time_scale()?;
+
+    let components = |keys: &[&str; 4]| -> PoliastroResult<Option<[f64; 4]>> {
+        if keys.iter().all(|key| data.get(key).is_none()) {
+            return Ok(None);
+        }
+        let mut values = [0.0; 4];
+        for (value, key) in values.iter_mut().zip(keys) {
+            *value = data.require_parse(key)?;
+        }
+        Ok(Some(values))
+    };
+    let quaternion = components(&QUATERNION_KEYS)?
+        .ok_or_else(|| invalid("missing quaternion (Q1, Q2, Q3, QC)"))?;
+    let inertia = if INERTIA_KEYS.iter().any(|key| data.get(key).is_some()) {
+        let mut inertia = Matrix3::zeros();
+        for (key, (i, j)) in INERTIA_KEYS.iter().zip(INERTIA_INDICES) {
+            inertia[(i, j)] = data.require_parse(key)?;
+            inertia[(j, i)] = inertia[(i, j)];
+        }
+        Some(inertia)
+    } else {
+        None
+    };
+
+    let apm = Apm {
+        originator: header.require("ORIGINATOR")?.to_string(),
+        creation_date: header.require("CREATION_DATE")?.to_string(),
+        comments: header.comments.clone(),
+        data_comments: data.comments.clone(),
+        epoch: parse_epoch(data.require("EPOCH")?, time_scale, MESSAGE_TYPE)?,
+        frame_a: data.require("Q_FRAME_A")?.to_string(),
+        frame_b: data.require("Q_FRAME_B")?.to_string(),
+        direction: AttitudeDirection::from_name(data.require("Q_DIR")?, MESSAGE_TYPE)?,
+        quaternion: super::quaternion(quaternion, MESSAGE_TYPE)?,
+        quaternion_rate: components(&QUATERNION_RATE_KEYS)?.map(Vector4::from),
+        parameters: data
+            .iter()
+            .filter(|(key, _)| EULER_KEYS.contains(key) || SPIN_KEYS.contains(key))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect(),
+        inertia_frame: data.get("INERTIA_REF_FRAME").map(str::to_string),
+        inertia,
+        maneuvers: maneuvers
+            .iter()
+            .map(|fields| AttitudeManeuver::from_fields(fields, time_scale))
+            .collect::<PoliastroResult<_>>()?,
+        metadata,
+    };
+    apm.validate()?;
+    Ok(apm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Example 3-3 of CCSDS 504.0-B-1, with Euler angles and a maneuver
+    const APM_KVN: &str = "CCSDS_APM_VERS = 1.0
+CREATION_DATE = 2003-09-30T19:23:57
+ORIGINATOR = GSFC
+COMMENT GEOCENTRIC, CARTESIAN, EARTH FIXED
+OBJECT_NAME = TRMM
+OBJECT_ID = 1997-009A
+CENTER_NAME = EARTH
+TIME_SYSTEM = UTC
+
+COMMENT Attitude quaternion
+EPOCH = 2003-09-30T14:28:15.1172
+Q_FRAME_A = SC_BODY_1
+Q_FRAME_B = ITRF-97
+Q_DIR = A2B
+Q1 = 0.00005
+Q2 = 0.87543
+Q3 = 0.40949
+QC = 0.25678
+Q1_DOT = 0.00000000
+Q2_DOT = 0.00000000
+Q3_DOT = 0.00000000
+QC_DOT = 0.00000000
+
+EULER_FRAME_A = SC_BODY_1
+EULER_FRAME_B = ITRF-97
+EULER_DIR = A2B
+EULER_ROT_SEQ = 312
+RATE_FRAME = EULER_FRAME_A
+X_ANGLE = -26.78 [deg]
+Y_ANGLE = 46.26 [deg]
+Z_ANGLE = 144.10 [deg]
+
+INERTIA_REF_FRAME = SC_BODY_1
+I11 = 6080.0 [kg*m**2]
+I22 = 5245.5 [kg*m**2]
+I33 = 8067.3 [kg*m**2]
+I12 = -135.9 [kg*m**2]
+I13 = 89.3 [kg*m**2]
+I23 = -90.7 [kg*m**2]
+
+COMMENT Slew to target
+MAN_EPOCH_START = 2003-09-30T15:00:00
+MAN_DURATION = 120.0 [s]
+MAN_REF_FRAME = SC_BODY_1
+MAN_TOR_1 = -1.25 [N*m]
+MAN_TOR_2 = 0.0 [N*m]
+MAN_TOR_3 = 0.5 [N*m]
+";
+
+    #[test]
+    fn test_parse_apm_kvn() {
+        let apm = parse_apm(APM_KVN).unwrap();
+        assert_eq!(apm.originator, "GSFC");
+        assert_eq!(apm.metadata.object_name, "TRMM");
+        assert_eq!(
+            apm.metadata.comments,
+            vec!["GEOCENTRIC, CARTESIAN, EARTH FIXED"]
+        );
+        assert_eq!(apm.metadata.center_name.as_deref(), Some("EARTH"));
+        assert_eq!(
+            apm.epoch,
+            Epoch::from_gregorian_utc(2003, 9, 30, 14, 28, 15, 117_200_000)
+        );
+        assert_eq!(apm.frame_b, "ITRF-97");
+        assert_eq!(apm.direction, AttitudeDirection::AToB);
+        // Normalized from components of norm 0.99999...
+        let [q1, q2, q3, qc] = quaternion_components(&apm.quaternion);
+        assert!((q2 - 0.87543).abs() < 1e-4 && (qc - 0.25678).abs() < 1e-4 && q1 > 0.0);
+        assert!((apm.quaternion.quaternion().norm() - 1.0).abs() < 1e-12);
+        assert!((q3 - 0.40949).abs() < 1e-4);
+        assert_eq!(apm.quaternion_rate, Some(Vector4::zeros()));
+        assert_eq!(apm.parameter("EULER_ROT_SEQ"), Some("312"));
+        assert_eq!(apm.parameter("Z_ANGLE"), Some("144.10"));
+
+        let inertia = apm.inertia.unwrap();
+        assert_eq!(inertia[(1, 0)], -135.9);
+        assert_eq!(inertia[(2, 2)], 8067.3);
+        assert_eq!(apm.maneuvers.len(), 1);
+        assert_eq!(apm.maneuvers[0].comments, vec!["Slew to target"]);
+        assert_eq!(apm.maneuvers[0].torque, Vector3::new(-1.25, 0.0, 0.5));
+
+        let mut reversed = apm.clone();
+        reversed.direction = AttitudeDirection::BToA;
+        let axis = Vector3::new(0.3, -0.2, 0.9);
+        let back = reversed.rotation() * (apm.rotation() * axis);
+        assert!((back - axis).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_apm_kvn_xml_round_trip() {
+        let apm = parse_apm(APM_KVN).unwrap();
+        for encoding in [Encoding::Kvn, Encoding::Xml] {
+            let text = write_apm(&apm, encoding).unwrap();
+            let read = parse_apm(&text).unwrap();
+            assert_eq!(read.metadata, apm.metadata);
+            assert_eq!(read.epoch, apm.epoch);
+            assert!(read.quaternion.angle_to(&apm.quaternion) < 1e-12);
+            assert_eq!(read.quaternion_rate, apm.quaternion_rate);
+            assert_eq!(read.parameters, apm.parameters);
+            assert_eq!(read.inertia, apm.inertia);
+            assert_eq!(read.maneuvers, apm.maneuvers);
+        }
+        let xml = write_apm(&apm, Encoding::Xml).unwrap();
+        assert!(xml.contains("<rotationAngles>"));
+        assert!(xml.contains("<Q_DIR>A2B</Q_DIR>"));
+    }
+
+    #[test]
+    fn test_apm_attitude_type_round_trips() {
+        let epoch = Epoch::from_gregorian_utc(2024, 5, 1, 12, 0, 0, 0);
+        let quaternion = UnitQuaternion::from_euler_angles(0.1, -0.7, 2.5);
+        let minimal = Apm::new(
+            "OPS",
+            "SAT",
+            "2024-001A",
+            epoch,
+            "EME2000",
+            "SC_BODY_1",
+            quaternion,
+        );
+
+        // Quaternion with its derivative, seen from frame B
+        let mut derivative = minimal.clone();
+        derivative.direction = AttitudeDirection::BToA;
+        derivative.quaternion_rate = Some(Vector4::new(1.5e-3, -2.5e-4, 0.0, 7.25e-5));
+
+        // Euler angles and rates, spin and inertia
+        let mut euler = minimal.clone();
+        euler.parameters = [
+            ("EULER_FRAME_A", "EME2000"),
+            ("EULER_FRAME_B", "SC_BODY_1"),
+            ("EULER_DIR", "A2B"),
+            ("EULER_ROT_SEQ", "321"),
+            ("RATE_FRAME", "EULER_FRAME_B"),
+            ("X_ANGLE", "5.73"),
+            ("Y_ANGLE", "-40.1"),
+            ("Z_ANGLE", "143.24"),
+            ("X_RATE", "0.1"),
+            ("Y_RATE", "0.0"),
+            ("Z_RATE", "-0.25"),
+            ("SPIN_FRAME_A", "EME2000"),
+            ("SPIN_FRAME_B", "SC_BODY_1"),
+            ("SPIN_DIR", "A2B"),
+            ("SPIN_ALPHA", "26.78"),
+            ("SPIN_DELTA", "46.26"),
+            ("SPIN_ANGLE", "144.1"),
+            ("SPIN_ANGLE_VEL", "0.5"),
+        ]
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+        euler.inertia_frame = Some("SC_BODY_1".to_string());
+        euler.inertia = Some(Matrix3::new(
+            1200.0, -12.5, 3.0, -12.5, 980.0, 0.25, 3.0, 0.25, 1500.0,
+        ));
+        // Back-to-back maneuvers
+        let slew = |start: f64, torque: Vector3| AttitudeManeuver {
+            epoch_start: epoch.add_duration(Duration::from_seconds(start)),
+            duration: 60.0,
+            ref_frame: "SC_BODY_1".to_string(),
+            torque,
+            comments: vec![format!("Slew at {start} s")],
+        };
+        euler.maneuvers = vec![
+            slew(600.0, Vector3::new(0.125, -0.5, 0.0)),
+            slew(660.0, Vector3::new(-0.125, 0.5, 0.0)),
+        ];
+
+        for apm in [&minimal, &derivative, &euler] {
+            for encoding in [Encoding::Kvn, Encoding::Xml] {
+                let text = write_apm(apm, encoding).unwrap();
+                let read = parse_apm(&text).unwrap();
+                assert_eq!(read.metadata, apm.metadata);
+                assert_eq!(read.creation_date, apm.creation_date);
+                assert_eq!(read.epoch, epoch);
+                assert_eq!((&read.frame_a, &read.frame_b), (&apm.frame_a, &apm.frame_b));
+                assert_eq!(read.direction, apm.direction);
+                assert!(read.quaternion.angle_to(&apm.quaternion) < 1e-12);
+                assert!(read.rotation().angle_to(&apm.rotation()) < 1e-12);
+                assert_eq!(read.quaternion_rate, apm.quaternion_rate);
+                assert_eq!(read.parameters, apm.parameters);
+                assert_eq!(read.inertia_frame, apm.inertia_frame);
+                assert_eq!(read.inertia, apm.inertia);
+                assert_eq!(read.maneuvers, apm.maneuvers);
+                // Written again, the text is unchanged
+                assert_eq!(write_apm(&read, encoding).unwrap(), text);
+            }
+        }
+        assert!(derivative.rotation().angle_to(&quaternion.inverse()) < 1e-12);
+
+        // XML blocks and units of each attitude type
+        let xml = minimal.to_xml().unwrap();
+        assert!(xml.contains("<quaternionState>") && !xml.contains("<quaternionRate>"));
+        assert!(!xml.contains("eulerElements") && !xml.contains("<maneuverParameters>"));
+        let xml = derivative.to_xml().unwrap();
+        assert!(
+            xml.contains("<QC_DOT units=\"1/s\">7.25e-5</QC_DOT>"),
+            "{xml}"
+        );
+        assert!(xml.contains("<Q_DIR>B2A</Q_DIR>"));
+        let xml = euler.to_xml().unwrap();
+        for expected in [
+            "<eulerElementsThree>",
+            "<EULER_ROT_SEQ>321</EULER_ROT_SEQ>",
+            "<RATE_FRAME>EULER_FRAME_B</RATE_FRAME>",
+            "<Y_ANGLE units=\"deg\">-40.1</Y_ANGLE>",
+            "<Z_RATE units=\"deg/s\">-0.25</Z_RATE>",
+            "<eulerElementsSpin>",
+            "<SPIN_ANGLE_VEL>0.5</SPIN_ANGLE_VEL>",
+            "<I12 units=\"kg*m**2\">-12.5</I12>",
+            "<MAN_TOR_2 units=\"N*m\">-0.5</MAN_TOR_2>",
+        ] {
+            assert!(xml.contains(expected), "{expected}");
+        }
+        let kvn = euler.to_kvn().unwrap();
+        let position = |key: &str| kvn.find(&format!("\n{key} = ")).unwrap();
+        assert!(position("QC") < position("EULER_FRAME_A"));
+        assert!(position("Z_RATE") < position("SPIN_FRAME_A"));
+        assert!(position("SPIN_ANGLE_VEL") < position("INERTIA_REF_FRAME"));
+        assert!(position("I23") < position("MAN_EPOCH_START"));
+    }
+
+    #[test]
+    fn test_parse_apm_invalid_input() {
+        let is_invalid =
+            |text: &str| matches!(parse_apm(text), Err(PoliastroError::InvalidMessage { .. }));
+
+        // Mandatory keywords
+        for key in [
+            "CCSDS_APM_VERS",
+            "CREATION_DATE",
+            "ORIGINATOR",
+            "OBJECT_NAME",
+            "OBJECT_ID",
+            "TIME_SYSTEM",
+            "EPOCH",
+            "Q_FRAME_A",
+            "Q_FRAME_B",
+            "Q_DIR",
+            "Q1",
+            "QC",
+            "QC_DOT",
+            "I23",
+            "MAN_DURATION",
+            "MAN_REF_FRAME",
+            "MAN_TOR_1",
+        ] {
+            let at = APM_KVN.find(&format!("{key} = ")).unwrap();
+            let end = at + APM_KVN[at..].find('\n').unwrap() + 1;
+            let text = format!("{}{}", &APM_KVN[..at], &APM_KVN[end..]);
+            assert!(is_invalid(&text), "without {key}");
+        }
+
+        // Malformed values, unknown keywords and keywords out of place
+        for (from, to) in [
+            ("TIME_SYSTEM = UTC", "TIME_SYSTEM = MET"),
+            ("EPOCH = 2003-09-30T14:28:15.1172", "EPOCH = today"),
+            ("Q2 = 0.87543", "Q2 = 0.87543.0"),
+            ("Q2_DOT = 0.00000000", "Q2_DOT = inf"),
+            ("I12 = -135.9", "I12 = heavy"),
+            ("MAN_TOR_2 = 0.0", "MAN_TOR_2 = none"),
+            (
+                "MAN_EPOCH_START = 2003-09-30T15:00:00",
+                "MAN_EPOCH_START = soon",
+            ),
+            ("MAN_REF_FRAME = SC_BODY_1", "MAN_REF_FRAME = "),
+            ("MAN_REF_FRAME", "MAN_MOMENTUM = 1\nMAN_REF_FRAME"),
+            ("Y_ANGLE", "EULER_ANGLE = 3\nY_ANGLE"),
+            ("INERTIA_REF_FRAME", "SPIN_RATE = 0.5\nINERTIA_REF_FRAME"),
+            ("EULER_FRAME_A", "CENTER_NAME = MOON\nEULER_FRAME_A"),
+            ("COMMENT Slew", "MAN_DURATION = 1\nCOMMENT Slew"),
+            ("CENTER_NAME", "Q_DIR = A2B\nCENTER_NAME"),
+        ] {
+            assert!(APM_KVN.contains(from), "{from}");
+            assert!(is_invalid(&APM_KVN.replacen(from, to, 1)), "{from} -> {to}");
+        }
+        assert!(is_invalid(&format!("{APM_KVN}META_START\n")));
+        assert!(is_invalid(&format!(
+            "{APM_KVN}2003-09-30T15:00:00 1 0 0 0\n"
+        )));
+        // A second maneuver before the end of the first
+        let overlapping = format!(
+            "{APM_KVN}\nMAN_EPOCH_START = 2003-09-30T15:01:00\nMAN_DURATION = 10 [s]\n\
+             MAN_REF_FRAME = SC_BODY_1\nMAN_TOR_1 = 0\nMAN_TOR_2 = 0\nMAN_TOR_3 = 0\n"
+        );
+        assert!(is_invalid(&overlapping));
+        assert!(parse_apm(&overlapping.replace("15:01:00", "15:02:00")).is_ok());
+
+        // XML structure and blocks
+        let xml = parse_apm(APM_KVN).unwrap().to_xml().unwrap();
+        assert!(parse_apm(&xml).is_ok());
+        for (from, to) in [
+            ("<apm ", "<opm "),
+            ("<segment>", "<part>"),
+            ("<metadata>", "<meta>"),
+            ("<data>", "<records>"),
+            ("<Q_DIR>A2B</Q_DIR>", ""),
+            ("<Q_FRAME_B>ITRF-97</Q_FRAME_B>", ""),
+            ("<QC_DOT units=\"1/s\">0</QC_DOT>", ""),
+            (
+                "<Z_ANGLE units=\"deg\">144.10</Z_ANGLE>",
+                "<W_ANGLE units=\"deg\">144.10</W_ANGLE>",
+            ),
+            (
+                "<I33 units=\"kg*m**2\">8067.3</I33>",
+                "<I33 units=\"kg*m**2\">0</I33>",
+            ),
+            ("<INERTIA_REF_FRAME>SC_BODY_1</INERTIA_REF_FRAME>", ""),
+            (
+                "<MAN_DURATION units=\"s\">120</MAN_DURATION>",
+                "<MAN_DURATION units=\"s\">-120</MAN_DURATION>",
+            ),
+        ] {
+            assert!(xml.contains(from), "{from}");
+            assert!(
+                parse_apm(&xml.replacen(from, to, 1)).is_err(),
+                "{from} -> {to}"
+            );
+        }
+
+        // Invalid messages are not written
+        let apm = parse_apm(APM_KVN).unwrap();
+        let mut invalid = apm.clone();
+        invalid
+            .parameters
+            .push(("NUTATION_RATE".to_string(), "1".to_string()));
+        assert!(invalid.to_kvn().is_err() && invalid.to_xml().is_err());
+        let mut invalid = apm.clone();
+        invalid.inertia.as_mut().unwrap()[(0, 1)] = 1.0;
+        assert!(invalid.to_kvn().is_err() && invalid.to_xml().is_err());
+        let mut invalid = apm.clone();
+        invalid.inertia_frame = None;
+        assert!(write_apm(&invalid, Encoding::Kvn).is_err());
+        let mut invalid = apm.clone();
+        invalid.quaternion_rate = Some(Vector4::new(f64::NAN, 0.0, 0.0, 0.0));
+        assert!(write_apm(&invalid, Encoding::Xml).is_err());
+        let mut invalid = apm;
+        invalid.maneuvers[0].torque.z = f64::INFINITY;
+        assert!(write_apm(&invalid, Encoding::Kvn).is_err());
+        assert!(write_apm(&invalid, Encoding::Json).is_err());
+    }
+
+    #[test]
+    fn test_parse_apm_errors() {
+        let replace = |from: &str, to: &str| parse_apm(&APM_KVN.replacen(from, to, 1));
+        let is_invalid = |result: PoliastroResult<Apm>| {
+            matches!(result, Err(PoliastroError::InvalidMessage { .. }))
+        };
+
+        assert!(is_invalid(replace("QC = 0.25678", "QC = 0.5")));
+        assert!(is_invalid(replace("Q3 = 0.40949\n", "")));
+        assert!(is_invalid(replace("Q_DIR = A2B", "Q_DIR = A2C")));
+        assert!(is_invalid(replace("I33 = 8067.3", "I33 = -8067.3")));
+        assert!(is_invalid(replace("INERTIA_REF_FRAME = SC_BODY_1\n", "")));
+        assert!(is_invalid(replace(
+            "MAN_DURATION = 120.0",
+            "MAN_DURATION = -1"
+        )));
+        assert!(is_invalid(replace("MAN_TOR_3 = 0.5 [N*m]\n", "")));
+        assert!(is_invalid(replace("Z_ANGLE", "W_ANGLE")));
+        assert!(is_invalid(replace("I11 =", "OBJECT_ID = X\nI11 =")));
+        assert!(is_invalid(parse_apm("{}")));
+    }
+}
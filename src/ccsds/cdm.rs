@@ -0,0 +1,1671 @@
+//! Conjunction Data Message (CDM)
+//!
+//! Reading and writing of CCSDS CDMs in KVN and XML.
+//!
+//! A CDM describes one close approach between two objects: a header, the
+//! relative metadata and data (time of closest approach, miss distance,
+//! relative state in the RTN frame of the first object, screening volume and
+//! the originator's probability of collision), then for each object its
+//! metadata, orbit determination and additional parameters, state vector at
+//! TCA and RTN covariance.
+//!
+//! States are read into [`CartesianState`]s in m and m/s (the message is in
+//! km and km/s) and covariances into [`Covariance6`]s in the RTN frame of
+//! their object, in m², m²/s and m²/s² as in the message. Keywords without a
+//! typed field (operator contact, force models, OD and additional parameters,
+//! the drag, SRP and thrust covariance rows, the screening volume) are kept
+//! verbatim and written back in CCSDS order.
+//!
+//! [`Cdm::new`] builds a message from the states of both objects at TCA, for
+//! reporting screening results.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use astrora_core::ccsds::parse_cdm;
+//!
+//! let cdm = parse_cdm(&std::fs::read_to_string("event.cdm")?)?;
+//! println!("{} m at {}", cdm.miss_distance, cdm.tca);
+//! let covariance = cdm.object1.inertial_covariance()?;
+//! ```
+//!
+//! # References
+//!
+//! - CCSDS 508.0-B-1: Conjunction Data Message
+
+use hifitime::TimeScale;
+use pyo3::prelude::*;
+
+use super::kvn::{self, KvnLine, KvnWriter};
+use super::xml::{XmlElement, XmlWriter};
+use super::{format_epoch, format_number, parse_epoch, Encoding, Fields};
+use crate::coordinates::FrameType;
+use crate::core::covariance::{ric_rotation, Covariance6};
+use crate::core::linalg::Vector3;
+use crate::core::state::CartesianState;
+use crate::core::time::Epoch;
+use crate::core::{PoliastroError, PoliastroResult};
+
+/// Version of CCSDS 508.0 written in KVN and XML messages
+pub const CCSDS_CDM_VERSION: &str = "1.0";
+
+const MESSAGE_TYPE: &str = "CDM";
+
+/// Km to m (km/s to m/s)
+const KM_TO_M: f64 = 1e3;
+
+/// Designations of the two objects (OBJECT)
+const OBJECTS: [&str; 2] = ["OBJECT1", "OBJECT2"];
+
+const HEADER_KEYS: [&str; 4] = ["CREATION_DATE", "ORIGINATOR", "MESSAGE_FOR", "MESSAGE_ID"];
+
+const RELATIVE_POSITION_KEYS: [&str; 3] = [
+    "RELATIVE_POSITION_R",
+    "RELATIVE_POSITION_T",
+    "RELATIVE_POSITION_N",
+];
+
+const RELATIVE_VELOCITY_KEYS: [&str; 3] = [
+    "RELATIVE_VELOCITY_R",
+    "RELATIVE_VELOCITY_T",
+    "RELATIVE_VELOCITY_N",
+];
+
+/// Screening period and volume keywords, kept verbatim, in CCSDS order
+const SCREENING_KEYS: [&str; 9] = [
+    "START_SCREEN_PERIOD",
+    "STOP_SCREEN_PERIOD",
+    "SCREEN_VOLUME_FRAME",
+    "SCREEN_VOLUME_SHAPE",
+    "SCREEN_VOLUME_X",
+    "SCREEN_VOLUME_Y",
+    "SCREEN_VOLUME_Z",
+    "SCREEN_ENTRY_TIME",
+    "SCREEN_EXIT_TIME",
+];
+
+/// Object metadata keywords, in CCSDS order
+const METADATA_KEYS: [&str; 21] = [
+    "OBJECT",
+    "OBJECT_DESIGNATOR",
+    "CATALOG_NAME",
+    "OBJECT_NAME",
+    "INTERNATIONAL_DESIGNATOR",
+    "OBJECT_TYPE",
+    "OPERATOR_CONTACT_POSITION",
+    "OPERATOR_ORGANIZATION",
+    "OPERATOR_PHONE",
+    "OPERATOR_EMAIL",
+    "EPHEMERIS_NAME",
+    "COVARIANCE_METHOD",
+    "MANEUVERABLE",
+    "ORBIT_CENTER",
+    "REF_FRAME",
+    "GRAVITY_MODEL",
+    "ATMOSPHERIC_MODEL",
+    "N_BODY_PERTURBATIONS",
+    "SOLAR_RAD_PRESSURE",
+    "EARTH_TIDES",
+    "INTRACK_THRUST",
+];
+
+const OD_PARAMETER_KEYS: [&str; 10] = [
+    "TIME_LASTOB_START",
+    "TIME_LASTOB_END",
+    "RECOMMENDED_OD_SPAN",
+    "ACTUAL_OD_SPAN",
+    "OBS_AVAILABLE",
+    "OBS_USED",
+    "TRACKS_AVAILABLE",
+    "TRACKS_USED",
+    "RESIDUALS_ACCEPTED",
+    "WEIGHTED_RMS",
+];
+
+const ADDITIONAL_PARAMETER_KEYS: [&str; 8] = [
+    "AREA_PC",
+    "AREA_DRG",
+    "AREA_SRP",
+    "MASS",
+    "CD_AREA_OVER_MASS",
+    "CR_AREA_OVER_MASS",
+    "THRUST_ACCELERATION",
+    "SEDR",
+];
+
+const STATE_KEYS: [&str; 6] = ["X", "Y", "Z", "X_DOT", "Y_DOT", "Z_DOT"];
+
+const STATE_UNITS: [&str; 2] = ["km", "km/s"];
+
+/// Lower-triangle keywords of the RTN position and velocity covariance, row
+/// by row
+const RTN_COVARIANCE: [&str; 21] = [
+    "CR_R",
+    "CT_R",
+    "CT_T",
+    "CN_R",
+    "CN_T",
+    "CN_N",
+    "CRDOT_R",
+    "CRDOT_T",
+    "CRDOT_N",
+    "CRDOT_RDOT",
+    "CTDOT_R",
+    "CTDOT_T",
+    "CTDOT_N",
+    "CTDOT_RDOT",
+    "CTDOT_TDOT",
+    "CNDOT_R",
+    "CNDOT_T",
+    "CNDOT_N",
+    "CNDOT_RDOT",
+    "CNDOT_TDOT",
+    "CNDOT_NDOT",
+];
+
+/// Drag, SRP and thrust rows of the covariance, kept verbatim
+const EXTENDED_COVARIANCE: [&str; 24] = [
+    "CDRG_R",
+    "CDRG_T",
+    "CDRG_N",
+    "CDRG_RDOT",
+    "CDRG_TDOT",
+    "CDRG_NDOT",
+    "CDRG_DRG",
+    "CSRP_R",
+    "CSRP_T",
+    "CSRP_N",
+    "CSRP_RDOT",
+    "CSRP_TDOT",
+    "CSRP_NDOT",
+    "CSRP_DRG",
+    "CSRP_SRP",
+    "CTHR_R",
+    "CTHR_T",
+    "CTHR_N",
+    "CTHR_RDOT",
+    "CTHR_TDOT",
+    "CTHR_NDOT",
+    "CTHR_DRG",
+    "CTHR_SRP",
+    "CTHR_THR",
+];
+
+/// REF_FRAME values of CCSDS 508.0-B-1
+const REF_FRAMES: [&str; 3] = ["EME2000", "GCRF", "ITRF"];
+
+/// One of the two objects of a conjunction
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CdmObject {
+    /// Satellite catalog designator (OBJECT_DESIGNATOR)
+    pub object_designator: String,
+    /// Satellite catalog of the designator (CATALOG_NAME)
+    pub catalog_name: String,
+    /// Spacecraft name (OBJECT_NAME)
+    pub object_name: String,
+    /// International designator, YYYY-NNNP{PP} (INTERNATIONAL_DESIGNATOR)
+    pub international_designator: String,
+    /// Ephemeris the state comes from, or NONE (EPHEMERIS_NAME)
+    pub ephemeris_name: String,
+    /// CALCULATED or DEFAULT (COVARIANCE_METHOD)
+    pub covariance_method: String,
+    /// YES, NO or N/A (MANEUVERABLE)
+    pub maneuverable: String,
+    /// Frame of the state: EME2000, GCRF or ITRF (REF_FRAME)
+    pub ref_frame: String,
+    /// State at TCA in `ref_frame` (m, m/s)
+    pub state: CartesianState,
+    /// Position and velocity covariance in the RTN frame of `state`
+    pub covariance: Covariance6,
+    /// Other metadata, OD parameter, additional parameter and covariance
+    /// keywords with their values, in message order
+    pub parameters: Vec<(String, String)>,
+    /// COMMENT lines of the metadata
+    pub comments: Vec<String>,
+    /// COMMENT lines of the data
+    pub data_comments: Vec<String>,
+}
+
+impl CdmObject {
+    /// Object in `frame` (GCRS, J2000 or ITRS) with its state and RTN
+    /// covariance at TCA, from the SATCAT, without ephemeris name and with
+    /// a calculated covariance
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` for frames a CDM cannot carry
+    pub fn new(
+        object_designator: impl Into<String>,
+        object_name: impl Into<String>,
+        international_designator: impl Into<String>,
+        frame: FrameType,
+        state: CartesianState,
+        covariance: Covariance6,
+    ) -> PoliastroResult<Self> {
+        let ref_frame = match frame {
+            FrameType::J2000 => "EME2000",
+            FrameType::GCRS => "GCRF",
+            FrameType::ITRS => "ITRF",
+            other => {
+                return Err(invalid(format!(
+                    "{other} states cannot be written in a CDM (use GCRS, J2000 or ITRS)"
+                )))
+            }
+        };
+        Ok(Self {
+            object_designator: object_designator.into(),
+            catalog_name: "SATCAT".to_string(),
+            object_name: object_name.into(),
+            international_designator: international_designator.into(),
+            ephemeris_name: "NONE".to_string(),
+            covariance_method: "CALCULATED".to_string(),
+            maneuverable: "N/A".to_string(),
+            ref_frame: ref_frame.to_string(),
+            state,
+            covariance,
+            parameters: Vec::new(),
+            comments: Vec::new(),
+            data_comments: Vec::new(),
+        })
+    }
+
+    /// Value of a keyword kept in `parameters`, such as `MASS` or
+    /// `ORBIT_CENTER`
+    pub fn parameter(&self, key: &str) -> Option<&str> {
+        self.parameters
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Frame of the state
+    ///
+    /// # Errors
+    ///
+    /// `UnknownFrame` if REF_FRAME or ORBIT_CENTER is not supported
+    pub fn frame(&self) -> PoliastroResult<FrameType> {
+        super::frame_type(
+            &self.ref_frame,
+            self.parameter("ORBIT_CENTER").unwrap_or("EARTH"),
+        )
+    }
+
+    /// Covariance rotated from RTN to the frame of the state
+    ///
+    /// # Errors
+    ///
+    /// `InvalidState` if the RTN frame is undefined (zero position or
+    /// rectilinear motion)
+    pub fn inertial_covariance(&self) -> PoliastroResult<Covariance6> {
+        self.covariance
+            .from_ric(&self.state.position, &self.state.velocity)
+    }
+
+    fn validate(&self) -> PoliastroResult<()> {
+        if !REF_FRAMES.contains(&self.ref_frame.as_str()) {
+            return Err(invalid(format!(
+                "REF_FRAME '{}' (expected EME2000, GCRF or ITRF)",
+                self.ref_frame
+            )));
+        }
+        if let Some((key, _)) = self
+            .parameters
+            .iter()
+            .find(|(key, _)| parameter_group(key).is_none())
+        {
+            return Err(invalid(format!("unexpected keyword {key}")));
+        }
+        Ok(())
+    }
+
+    /// State components in km and km/s
+    fn values(&self) -> [f64; 6] {
+        let (r, v) = (&self.state.position, &self.state.velocity);
+        [r.x, r.y, r.z, v.x, v.y, v.z].map(|value| value / KM_TO_M)
+    }
+
+    /// Metadata keywords and values, in CCSDS order
+    fn metadata_fields(&self, designation: &str) -> Vec<(&'static str, String)> {
+        METADATA_KEYS
+            .iter()
+            .filter_map(|&key| {
+                let value = match key {
+                    "OBJECT" => Some(designation),
+                    "OBJECT_DESIGNATOR" => Some(self.object_designator.as_str()),
+                    "CATALOG_NAME" => Some(self.catalog_name.as_str()),
+                    "OBJECT_NAME" => Some(self.object_name.as_str()),
+                    "INTERNATIONAL_DESIGNATOR" => Some(self.international_designator.as_str()),
+                    "EPHEMERIS_NAME" => Some(self.ephemeris_name.as_str()),
+                    "COVARIANCE_METHOD" => Some(self.covariance_method.as_str()),
+                    "MANEUVERABLE" => Some(self.maneuverable.as_str()),
+                    "REF_FRAME" => Some(self.ref_frame.as_str()),
+                    _ => self.parameter(key),
+                };
+                value.map(|value| (key, value.to_string()))
+            })
+            .collect()
+    }
+
+    /// Parameters of a group, in CCSDS order
+    fn group_fields(&self, keys: &[&'static str]) -> Vec<(&'static str, String)> {
+        keys.iter()
+            .filter_map(|&key| self.parameter(key).map(|value| (key, value.to_string())))
+            .collect()
+    }
+
+    /// The 21 RTN covariance keywords and values
+    fn covariance_fields(&self) -> Vec<(&'static str, String)> {
+        let values = super::lower_triangle(&self.covariance, 1.0);
+        RTN_COVARIANCE
+            .iter()
+            .zip(values)
+            .map(|(&key, value)| (key, format_number(value)))
+            .collect()
+    }
+}
+
+/// Keyword groups of the parameters of an object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParameterGroup {
+    Metadata,
+    OdParameters,
+    AdditionalParameters,
+    Covariance,
+}
+
+fn parameter_group(key: &str) -> Option<ParameterGroup> {
+    if METADATA_KEYS.contains(&key) {
+        Some(ParameterGroup::Metadata)
+    } else if OD_PARAMETER_KEYS.contains(&key) {
+        Some(ParameterGroup::OdParameters)
+    } else if ADDITIONAL_PARAMETER_KEYS.contains(&key) {
+        Some(ParameterGroup::AdditionalParameters)
+    } else if EXTENDED_COVARIANCE.contains(&key) {
+        Some(ParameterGroup::Covariance)
+    } else {
+        None
+    }
+}
+
+/// A conjunction data message
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cdm {
+    /// Agency or operator creating the message (ORIGINATOR)
+    pub originator: String,
+    /// Creation time, UTC (CREATION_DATE)
+    pub creation_date: String,
+    /// Spacecraft the message is for (MESSAGE_FOR)
+    pub message_for: Option<String>,
+    /// Identifier of the message, unique for the originator (MESSAGE_ID)
+    pub message_id: String,
+    /// COMMENT lines of the header
+    pub comments: Vec<String>,
+    /// Time of closest approach (TCA)
+    pub tca: Epoch,
+    /// Distance between the objects at TCA, m (MISS_DISTANCE)
+    pub miss_distance: f64,
+    /// Relative speed at TCA, m/s (RELATIVE_SPEED)
+    pub relative_speed: Option<f64>,
+    /// Position of object 2 relative to object 1 in the RTN frame of object
+    /// 1, m (RELATIVE_POSITION_R, _T, _N)
+    pub relative_position: Option<Vector3>,
+    /// Velocity of object 2 relative to object 1 in the RTN frame of object
+    /// 1, m/s (RELATIVE_VELOCITY_R, _T, _N)
+    pub relative_velocity: Option<Vector3>,
+    /// Screening period and volume keywords with their values
+    pub screening: Vec<(String, String)>,
+    /// Probability of collision computed by the originator
+    /// (COLLISION_PROBABILITY)
+    pub collision_probability: Option<f64>,
+    /// Method of `collision_probability` (COLLISION_PROBABILITY_METHOD)
+    pub collision_probability_method: Option<String>,
+    /// COMMENT lines of the relative metadata and data
+    pub relative_comments: Vec<String>,
+    /// The first object, whose RTN frame the relative state is in
+    pub object1: CdmObject,
+    /// The second object
+    pub object2: CdmObject,
+}
+
+impl Cdm {
+    /// CDM of the conjunction of two objects at `tca`, from `originator`,
+    /// created now
+    ///
+    /// The miss distance, relative speed and relative state come from the
+    /// object states.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidMessage`: If the objects are in different frames or an
+    ///   object is invalid
+    /// - `InvalidState`: If the RTN frame of object 1 is undefined
+    pub fn new(
+        originator: impl Into<String>,
+        message_id: impl Into<String>,
+        tca: Epoch,
+        object1: CdmObject,
+        object2: CdmObject,
+    ) -> PoliastroResult<Self> {
+        if object1.ref_frame != object2.ref_frame {
+            return Err(invalid(format!(
+                "objects in {} and {}; the relative state needs a single frame",
+                object1.ref_frame, object2.ref_frame
+            )));
+        }
+        let (state1, state2) = (&object1.state, &object2.state);
+        let rotation = ric_rotation(&state1.position, &state1.velocity)?;
+        let position = state2.position - state1.position;
+        let velocity = state2.velocity - state1.velocity;
+
+        let cdm = Self {
+            originator: originator.into(),
+            creation_date: format_epoch(&Epoch::now(), TimeScale::UTC),
+            message_for: None,
+            message_id: message_id.into(),
+            comments: Vec::new(),
+            tca,
+            miss_distance: position.norm(),
+            relative_speed: Some(velocity.norm()),
+            relative_position: Some(rotation * position),
+            relative_velocity: Some(rotation * velocity),
+            screening: Vec::new(),
+            collision_probability: None,
+            collision_probability_method: None,
+            relative_comments: Vec::new(),
+            object1,
+            object2,
+        };
+        cdm.validate()?;
+        Ok(cdm)
+    }
+
+    /// Check the objects, the miss distance and the probability of
+    /// collision
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` describing the first problem found
+    pub fn validate(&self) -> PoliastroResult<()> {
+        if self.miss_distance < 0.0 || !self.miss_distance.is_finite() {
+            return Err(invalid(format!(
+                "MISS_DISTANCE {} is not a distance",
+                self.miss_distance
+            )));
+        }
+        if let Some(probability) = self.collision_probability {
+            if !(0.0..=1.0).contains(&probability) {
+                return Err(invalid(format!(
+                    "COLLISION_PROBABILITY {probability} is not within [0, 1]"
+                )));
+            }
+        }
+        if let Some((key, _)) = self
+            .screening
+            .iter()
+            .find(|(key, _)| !SCREENING_KEYS.contains(&key.as_str()))
+        {
+            return Err(invalid(format!("unexpected keyword {key}")));
+        }
+        self.object1.validate()?;
+        self.object2.validate()
+    }
+
+    /// The objects with their OBJECT designations
+    fn objects(&self) -> [(&'static str, &CdmObject); 2] {
+        [(OBJECTS[0], &self.object1), (OBJECTS[1], &self.object2)]
+    }
+
+    /// Relative metadata and data keywords, values and XML units, in CCSDS
+    /// order, without the relative state vector
+    fn relative_fields(&self) -> (Vec<RelativeField>, Vec<RelativeField>) {
+        let mut before = vec![
+            ("TCA", format_epoch(&self.tca, TimeScale::UTC), None),
+            (
+                "MISS_DISTANCE",
+                format_number(self.miss_distance),
+                Some("m"),
+            ),
+        ];
+        if let Some(speed) = self.relative_speed {
+            before.push(("RELATIVE_SPEED", format_number(speed), Some("m/s")));
+        }
+
+        let mut after: Vec<RelativeField> = SCREENING_KEYS
+            .iter()
+            .filter_map(|&key| {
+                self.screening
+                    .iter()
+                    .find(|(k, _)| k == key)
+                    .map(|(_, value)| (key, value.clone(), None))
+            })
+            .collect();
+        if let Some(probability) = self.collision_probability {
+            after.push(("COLLISION_PROBABILITY", format_number(probability), None));
+        }
+        if let Some(method) = &self.collision_probability_method {
+            after.push(("COLLISION_PROBABILITY_METHOD", method.clone(), None));
+        }
+        (before, after)
+    }
+
+    /// Relative position and velocity keywords, values and XML units
+    fn relative_state_fields(&self) -> Vec<RelativeField> {
+        let mut fields = Vec::new();
+        for (keys, vector, units) in [
+            (RELATIVE_POSITION_KEYS, &self.relative_position, "m"),
+            (RELATIVE_VELOCITY_KEYS, &self.relative_velocity, "m/s"),
+        ] {
+            if let Some(vector) = vector {
+                for (key, value) in keys.iter().zip(vector.iter()) {
+                    fields.push((*key, format_number(*value), Some(units)));
+                }
+            }
+        }
+        fields
+    }
+
+    /// Write the message in CCSDS KVN
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` if the message is invalid
+    pub fn to_kvn(&self) -> PoliastroResult<String> {
+        self.validate()?;
+        let mut kvn = KvnWriter::new();
+        kvn.field("CCSDS_CDM_VERS", CCSDS_CDM_VERSION);
+        kvn.comments(&self.comments);
+        kvn.field("CREATION_DATE", &self.creation_date);
+        kvn.field("ORIGINATOR", &self.originator);
+        if let Some(message_for) = &self.message_for {
+            kvn.field("MESSAGE_FOR", message_for);
+        }
+        kvn.field("MESSAGE_ID", &self.message_id);
+
+        kvn.blank();
+        kvn.comments(&self.relative_comments);
+        let (before, after) = self.relative_fields();
+        for (key, value, _) in before
+            .into_iter()
+            .chain(self.relative_state_fields())
+            .chain(after)
+        {
+            kvn.field(key, value);
+        }
+
+        for (designation, object) in self.objects() {
+            kvn.blank();
+            kvn.comments(&object.comments);
+            for (key, value) in object.metadata_fields(designation) {
+                kvn.field(key, value);
+            }
+            kvn.comments(&object.data_comments);
+            let state = STATE_KEYS
+                .iter()
+                .zip(object.values())
+                .map(|(&key, value)| (key, format_number(value)));
+            for (key, value) in object
+                .group_fields(&OD_PARAMETER_KEYS)
+                .into_iter()
+                .chain(object.group_fields(&ADDITIONAL_PARAMETER_KEYS))
+                .chain(state)
+                .chain(object.covariance_fields())
+                .chain(object.group_fields(&EXTENDED_COVARIANCE))
+            {
+                kvn.field(key, value);
+            }
+        }
+        Ok(kvn.finish())
+    }
+
+    /// Write the message in CCSDS NDM/XML, with units attributes as in the
+    /// CCSDS schema
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` if the message is invalid
+    pub fn to_xml(&self) -> PoliastroResult<String> {
+        self.validate()?;
+        let mut xml = XmlWriter::new("cdm", CCSDS_CDM_VERSION);
+        xml.open("header");
+        xml.comments(&self.comments);
+        xml.leaf("CREATION_DATE", &self.creation_date, None);
+        xml.leaf("ORIGINATOR", &self.originator, None);
+        if let Some(message_for) = &self.message_for {
+            xml.leaf("MESSAGE_FOR", message_for, None);
+        }
+        xml.leaf("MESSAGE_ID", &self.message_id, None);
+        xml.close("header");
+
+        xml.open("body");
+        xml.open("relativeMetadataData");
+        xml.comments(&self.relative_comments);
+        let (before, after) = self.relative_fields();
+        for (key, value, units) in before {
+            xml.leaf(key, &value, units);
+        }
+        let relative_state = self.relative_state_fields();
+        if !relative_state.is_empty() {
+            xml.block("relativeStateVector", relative_state);
+        }
+        for (key, value, units) in after {
+            xml.leaf(key, &value, units);
+        }
+        xml.close("relativeMetadataData");
+
+        for (designation, object) in self.objects() {
+            xml.open("segment");
+            xml.open("metadata");
+            xml.comments(&object.comments);
+            for (key, value) in object.metadata_fields(designation) {
+                xml.leaf(key, &value, None);
+            }
+            xml.close("metadata");
+
+            xml.open("data");
+            xml.comments(&object.data_comments);
+            for (tag, keys) in [
+                ("odParameters", &OD_PARAMETER_KEYS[..]),
+                ("additionalParameters", &ADDITIONAL_PARAMETER_KEYS[..]),
+            ] {
+                let fields = object.group_fields(keys);
+                if !fields.is_empty() {
+                    xml.block(
+                        tag,
+                        fields.into_iter().map(|(key, value)| (key, value, None)),
+                    );
+                }
+            }
+            let state =
+                STATE_KEYS
+                    .iter()
+                    .zip(object.values())
+                    .enumerate()
+                    .map(|(index, (&key, value))| {
+                        (key, format_number(value), Some(STATE_UNITS[index / 3]))
+                    });
+            xml.block("stateVector", state);
+            let covariance = object
+                .covariance_fields()
+                .into_iter()
+                .enumerate()
+                .map(|(index, (key, value))| (key, value, Some(rtn_covariance_units(index))))
+                .chain(
+                    object
+                        .group_fields(&EXTENDED_COVARIANCE)
+                        .into_iter()
+                        .map(|(key, value)| (key, value, None)),
+                );
+            xml.block("covarianceMatrix", covariance);
+            xml.close("data");
+            xml.close("segment");
+        }
+        xml.close("body");
+        Ok(xml.finish("cdm"))
+    }
+}
+
+/// Keyword, value and XML units of a relative metadata or data field
+type RelativeField = (&'static str, String, Option<&'static str>);
+
+/// Units of the RTN covariance keywords, in the order of [`RTN_COVARIANCE`]
+fn rtn_covariance_units(index: usize) -> &'static str {
+    match index {
+        0..=5 => "m**2",
+        6..=8 | 10..=12 | 15..=17 => "m**2/s",
+        _ => "m**2/s**2",
+    }
+}
+
+/// Parse a CDM in CCSDS KVN or XML (detected from the text)
+///
+/// # Errors
+///
+/// `InvalidMessage` if the message is malformed, misses a mandatory
+/// keyword, has an unknown keyword, or does not describe exactly two objects
+///
+/// # Example
+///
+/// ```ignore
+/// let cdm = parse_cdm(&std::fs::read_to_string("event.cdm")?)?;
+/// println!("Pc = {:?}", cdm.collision_probability);
+/// ```
+pub fn parse_cdm(text: &str) -> PoliastroResult<Cdm> {
+    match Encoding::detect(text) {
+        Encoding::Kvn => cdm_from_kvn(text),
+        Encoding::Xml => cdm_from_xml(text),
+        Encoding::Json => Err(invalid("JSON is not a CCSDS CDM encoding")),
+    }
+}
+
+/// Write a CDM in `encoding` (KVN or XML)
+///
+/// # Errors
+///
+/// `InvalidMessage` for the JSON encoding or if the message is invalid
+pub fn write_cdm(cdm: &Cdm, encoding: Encoding) -> PoliastroResult<String> {
+    match encoding {
+        Encoding::Kvn => cdm.to_kvn(),
+        Encoding::Xml => cdm.to_xml(),
+        Encoding::Json => Err(invalid("JSON is not a CCSDS CDM encoding")),
+    }
+}
+
+fn invalid(reason: impl Into<String>) -> PoliastroError {
+    PoliastroError::invalid_message(MESSAGE_TYPE, reason)
+}
+
+/// Metadata and data fields of an object
+type ObjectFields = (Fields, Fields);
+
+/// Block a KVN keyword belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum KvnBlock {
+    Header,
+    Relative,
+    /// Metadata or data of the n-th object
+    Object(usize),
+}
+
+fn cdm_from_kvn(text: &str) -> PoliastroResult<Cdm> {
+    let mut header = Fields::new(MESSAGE_TYPE);
+    let mut relative = Fields::new(MESSAGE_TYPE);
+    let mut objects: Vec<ObjectFields> = Vec::new();
+    let mut block = KvnBlock::Header;
+    // Comments belong to the block of the keyword that follows them
+    let mut comments = Vec::new();
+
+    for (line_no, line) in kvn::lines(text, MESSAGE_TYPE)? {
+        let (key, value) = match line {
+            KvnLine::Comment(comment) => {
+                comments.push(comment);
+                continue;
+            }
+            KvnLine::Field { key, value } => (key, value),
+            KvnLine::Keyword(keyword) => {
+                return Err(invalid(format!("line {line_no}: unexpected {keyword}")))
+            }
+            KvnLine::Values(_) => {
+                return Err(invalid(format!("line {line_no}: unexpected data line")))
+            }
+        };
+
+        let target = if key == "CCSDS_CDM_VERS" || HEADER_KEYS.contains(&key.as_str()) {
+            KvnBlock::Header
+        } else if key == "OBJECT" {
+            if objects.len() == OBJECTS.len() || value != OBJECTS[objects.len()] {
+                return Err(invalid(format!(
+                    "line {line_no}: unexpected OBJECT = {value}"
+                )));
+            }
+            objects.push((Fields::new(MESSAGE_TYPE), Fields::new(MESSAGE_TYPE)));
+            KvnBlock::Object(objects.len() - 1)
+        } else if objects.is_empty() {
+            KvnBlock::Relative
+        } else {
+            KvnBlock::Object(objects.len() - 1)
+        };
+        if target < block {
+            return Err(invalid(format!("line {line_no}: unexpected keyword {key}")));
+        }
+        block = target;
+
+        let fields = match block {
+            KvnBlock::Header => &mut header,
+            KvnBlock::Relative => &mut relative,
+            KvnBlock::Object(index) => {
+                let (metadata, data) = &mut objects[index];
+                if !METADATA_KEYS.contains(&key.as_str()) {
+                    data
+                } else if data.iter().next().is_none() {
+                    metadata
+                } else {
+                    return Err(invalid(format!("line {line_no}: unexpected keyword {key}")));
+                }
+            }
+        };
+        fields.comments.append(&mut comments);
+        fields.push(key, value);
+    }
+
+    // Trailing comments stay with the last data block
+    if let Some((_, data)) = objects.last_mut() {
+        data.comments.append(&mut comments);
+    }
+    header.require("CCSDS_CDM_VERS")?;
+    complete_cdm(&header, &relative, objects)
+}
+
+fn cdm_from_xml(text: &str) -> PoliastroResult<Cdm> {
+    let root = XmlElement::parse(text, MESSAGE_TYPE)?;
+    if root.name != "cdm" {
+        return Err(invalid(format!(
+            "root element is <{}>, not <cdm>",
+            root.name
+        )));
+    }
+    let header = root
+        .child("header")
+        .map(|header| header.fields(MESSAGE_TYPE))
+        .unwrap_or_else(|| Fields::new(MESSAGE_TYPE));
+    let body = root
+        .child("body")
+        .ok_or_else(|| invalid("missing <body>"))?;
+    let relative = body
+        .child("relativeMetadataData")
+        .ok_or_else(|| invalid("missing <relativeMetadataData>"))?
+        .flat_fields(MESSAGE_TYPE);
+    let objects = body
+        .children_named("segment")
+        .map(|segment| {
+            let metadata = segment
+                .child("metadata")
+                .ok_or_else(|| invalid("segment without <metadata>"))?;
+            let data = segment
+                .child("data")
+                .ok_or_else(|| invalid("segment without <data>"))?;
+            Ok((
+                metadata.fields(MESSAGE_TYPE),
+                data.flat_fields(MESSAGE_TYPE),
+            ))
+        })
+        .collect::<PoliastroResult<Vec<_>>>()?;
+    complete_cdm(&header, &relative, objects)
+}
+
+/// Header, relative and object fields into a message
+fn complete_cdm(
+    header: &Fields,
+    relative: &Fields,
+    objects: Vec<ObjectFields>,
+) -> PoliastroResult<Cdm> {
+    let Ok([object1, object2]) = <[ObjectFields; 2]>::try_from(objects) else {
+        return Err(invalid("a CDM describes exactly two objects"));
+    };
+
+    let known = |key: &str| {
+        matches!(
+            key,
+            "TCA"
+                | "MISS_DISTANCE"
+                | "RELATIVE_SPEED"
+                | "COLLISION_PROBABILITY"
+                | "COLLISION_PROBABILITY_METHOD"
+        ) || RELATIVE_POSITION_KEYS.contains(&key)
+            || RELATIVE_VELOCITY_KEYS.contains(&key)
+            || SCREENING_KEYS.contains(&key)
+    };
+    if let Some((key, _)) = relative.iter().find(|(key, _)| !known(key)) {
+        return Err(invalid(format!("unexpected keyword {key}")));
+    }
+
+    let cdm = Cdm {
+        originator: header.require("ORIGINATOR")?.to_string(),
+        creation_date: header.require("CREATION_DATE")?.to_string(),
+        message_for: header.get("MESSAGE_FOR").map(str::to_string),
+        message_id: header.require("MESSAGE_ID")?.to_string(),
+        comments: header.comments.clone(),
+        tca: parse_epoch(relative.require("TCA")?, TimeScale::UTC, MESSAGE_TYPE)?,
+        miss_distance: relative.require_parse("MISS_DISTANCE")?,
+        relative_speed: relative.parse("RELATIVE_SPEED")?,
+        relative_position: relative.vector(&RELATIVE_POSITION_KEYS, 1.0)?,
+        relative_velocity: relative.vector(&RELATIVE_VELOCITY_KEYS, 1.0)?,
+        screening: relative
+            .iter()
+            .filter(|(key, _)| SCREENING_KEYS.contains(key))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect(),
+        collision_probability: relative.parse("COLLISION_PROBABILITY")?,
+        collision_probability_method: relative
+            .get("COLLISION_PROBABILITY_METHOD")
+            .map(str::to_string),
+        relative_comments: relative.comments.clone(),
+        object1: object_from_fields(OBJECTS[0], object1)?,
+        object2: object_from_fields(OBJECTS[1], object2)?,
+    };
+    cdm.validate()?;
+    Ok(cdm)
+}
+
+fn object_from_fields(
+    designation: &str,
+    (metadata, data): ObjectFields,
+) -> PoliastroResult<CdmObject> {
+    let object = metadata.require("OBJECT")?;
+    if object != designation {
+        return Err(invalid(format!(
+            "OBJECT = {object} where {designation} was expected"
+        )));
+    }
+
+    let typed = [
+        "OBJECT",
+        "OBJECT_DESIGNATOR",
+        "CATALOG_NAME",
+        "OBJECT_NAME",
+        "INTERNATIONAL_DESIGNATOR",
+        "EPHEMERIS_NAME",
+        "COVARIANCE_METHOD",
+        "MANEUVERABLE",
+        "REF_FRAME",
+    ];
+    let mut parameters = Vec::new();
+    for (key, value) in metadata.iter() {
+        if !METADATA_KEYS.contains(&key) {
+            return Err(invalid(format!("unexpected metadata keyword {key}")));
+        }
+        if !typed.contains(&key) {
+            parameters.push((key.to_string(), value.to_string()));
+        }
+    }
+    for (key, value) in data.iter() {
+        if STATE_KEYS.contains(&key) || RTN_COVARIANCE.contains(&key) {
+            continue;
+        }
+        match parameter_group(key) {
+            Some(ParameterGroup::Metadata) | None => {
+                return Err(invalid(format!("unexpected data keyword {key}")))
+            }
+            Some(_) => parameters.push((key.to_string(), value.to_string())),
+        }
+    }
+
+    let position = data.vector(&[STATE_KEYS[0], STATE_KEYS[1], STATE_KEYS[2]], KM_TO_M)?;
+    let velocity = data.vector(&[STATE_KEYS[3], STATE_KEYS[4], STATE_KEYS[5]], KM_TO_M)?;
+    let (Some(position), Some(velocity)) = (position, velocity) else {
+        return Err(invalid(format!("{designation} without a state vector")));
+    };
+    let covariance = data
+        .covariance(&RTN_COVARIANCE, 1.0)?
+        .ok_or_else(|| invalid(format!("{designation} without a covariance")))?;
+
+    Ok(CdmObject {
+        object_designator: metadata.require("OBJECT_DESIGNATOR")?.to_string(),
+        catalog_name: metadata.require("CATALOG_NAME")?.to_string(),
+        object_name: metadata.require("OBJECT_NAME")?.to_string(),
+        international_designator: metadata.require("INTERNATIONAL_DESIGNATOR")?.to_string(),
+        ephemeris_name: metadata.require("EPHEMERIS_NAME")?.to_string(),
+        covariance_method: metadata.require("COVARIANCE_METHOD")?.to_string(),
+        maneuverable: metadata.require("MANEUVERABLE")?.to_string(),
+        ref_frame: metadata.require("REF_FRAME")?.to_string(),
+        state: CartesianState::new(position, velocity),
+        covariance,
+        parameters,
+        comments: metadata.comments,
+        data_comments: data.comments,
+    })
+}
+
+#[pymethods]
+impl CdmObject {
+    /// Create a CDM object
+    ///
+    /// # Arguments
+    /// - `object_designator`: Satellite catalog designator
+    /// - `object_name`: Spacecraft name
+    /// - `international_designator`: International designator (YYYY-NNNP{PP})
+    /// - `state`: State at TCA (m, m/s)
+    /// - `covariance`: Position and velocity covariance in the RTN frame of
+    ///   the state
+    /// - `frame`: Frame of the state ("GCRS", "J2000" or "ITRS")
+    #[new]
+    #[pyo3(signature = (object_designator, object_name, international_designator, state, covariance, frame="GCRS"))]
+    fn py_new(
+        object_designator: String,
+        object_name: String,
+        international_designator: String,
+        state: CartesianState,
+        covariance: Covariance6,
+        frame: &str,
+    ) -> PyResult<Self> {
+        Ok(Self::new(
+            object_designator,
+            object_name,
+            international_designator,
+            frame.parse()?,
+            state,
+            covariance,
+        )?)
+    }
+
+    /// Satellite catalog designator
+    #[getter]
+    fn get_object_designator(&self) -> String {
+        self.object_designator.clone()
+    }
+
+    /// Satellite catalog of the designator
+    #[getter]
+    fn get_catalog_name(&self) -> String {
+        self.catalog_name.clone()
+    }
+
+    /// Spacecraft name
+    #[getter]
+    fn get_object_name(&self) -> String {
+        self.object_name.clone()
+    }
+
+    /// International designator
+    #[getter]
+    fn get_international_designator(&self) -> String {
+        self.international_designator.clone()
+    }
+
+    /// REF_FRAME of the state (EME2000, GCRF or ITRF)
+    #[getter]
+    fn get_ref_frame(&self) -> String {
+        self.ref_frame.clone()
+    }
+
+    /// State at TCA (m, m/s)
+    #[getter]
+    fn get_state(&self) -> CartesianState {
+        self.state
+    }
+
+    /// Position and velocity covariance in the RTN frame of the state
+    #[getter]
+    fn get_covariance(&self) -> Covariance6 {
+        self.covariance
+    }
+
+    /// Other keywords (operator, force models, OD and additional
+    /// parameters, extended covariance) as (keyword, value) pairs
+    #[getter]
+    fn get_parameters(&self) -> Vec<(String, String)> {
+        self.parameters.clone()
+    }
+
+    /// Covariance in the frame of the state
+    #[pyo3(name = "inertial_covariance")]
+    fn py_inertial_covariance(&self) -> PyResult<Covariance6> {
+        Ok(self.inertial_covariance()?)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "CdmObject('{}', '{}', {})",
+            self.object_designator, self.object_name, self.ref_frame
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::linalg::Matrix6;
+
+    /// After the example of CCSDS 508.0-B-1 annex C
+    const CDM_KVN: &str = "CCSDS_CDM_VERS = 1.0
+COMMENT Screening of the day
+CREATION_DATE = 2010-03-12T22:31:12.000
+ORIGINATOR = JSPOC
+MESSAGE_FOR = SATELLITE A
+MESSAGE_ID = 201113719185
+
+COMMENT Relative metadata
+TCA = 2010-03-13T22:37:52.618
+MISS_DISTANCE = 715 [m]
+RELATIVE_SPEED = 14762 [m/s]
+RELATIVE_POSITION_R = 27.4 [m]
+RELATIVE_POSITION_T = -70.2 [m]
+RELATIVE_POSITION_N = 711.8 [m]
+RELATIVE_VELOCITY_R = -7.2 [m/s]
+RELATIVE_VELOCITY_T = -14692.0 [m/s]
+RELATIVE_VELOCITY_N = -1437.2 [m/s]
+START_SCREEN_PERIOD = 2010-03-12T18:29:32.212
+STOP_SCREEN_PERIOD = 2010-03-15T18:29:32.212
+SCREEN_VOLUME_FRAME = RTN
+SCREEN_VOLUME_SHAPE = ELLIPSOID
+SCREEN_VOLUME_X = 200
+SCREEN_VOLUME_Y = 1000
+SCREEN_VOLUME_Z = 1000
+SCREEN_ENTRY_TIME = 2010-03-13T22:37:52.222
+SCREEN_EXIT_TIME = 2010-03-13T22:37:52.824
+COLLISION_PROBABILITY = 4.835E-05
+COLLISION_PROBABILITY_METHOD = FOSTER-1992
+
+COMMENT Object1 metadata
+OBJECT = OBJECT1
+OBJECT_DESIGNATOR = 12345
+CATALOG_NAME = SATCAT
+OBJECT_NAME = SATELLITE A
+INTERNATIONAL_DESIGNATOR = 1997-030E
+EPHEMERIS_NAME = EPHEMERIS SATELLITE A
+COVARIANCE_METHOD = CALCULATED
+MANEUVERABLE = YES
+REF_FRAME = EME2000
+GRAVITY_MODEL = EGM-96: 36D 36O
+ATMOSPHERIC_MODEL = JACCHIA 70 DCA
+COMMENT Object1 data
+TIME_LASTOB_START = 2010-03-12T02:14:12.746
+TIME_LASTOB_END = 2010-03-12T02:14:12.746
+OBS_USED = 1026
+MASS = 251.6 [kg]
+X = 2570.097065 [km]
+Y = 2244.654904 [km]
+Z = 6281.497978 [km]
+X_DOT = 4.418769571 [km/s]
+Y_DOT = 4.833547743 [km/s]
+Z_DOT = -3.526774282 [km/s]
+CR_R = 4.142E+01
+CT_R = -8.579E+00
+CT_T = 2.533E+03
+CN_R = -2.313E+01
+CN_T = 1.336E+01
+CN_N = 7.098E+01
+CRDOT_R = 2.520E-03
+CRDOT_T = -5.476E+00
+CRDOT_N = 8.626E-04
+CRDOT_RDOT = 5.744E-03
+CTDOT_R = -1.006E-02
+CTDOT_T = 4.041E-03
+CTDOT_N = -1.359E-03
+CTDOT_RDOT = -1.502E-05
+CTDOT_TDOT = 1.049E-05
+CNDOT_R = 1.053E-03
+CNDOT_T = -3.412E-03
+CNDOT_N = 1.213E-02
+CNDOT_RDOT = -3.004E-06
+CNDOT_TDOT = -1.091E-06
+CNDOT_NDOT = 5.529E-05
+CDRG_R = -2.3E-03
+CDRG_T = 1.5E-01
+
+OBJECT = OBJECT2
+OBJECT_DESIGNATOR = 30337
+CATALOG_NAME = SATCAT
+OBJECT_NAME = FENGYUN 1C DEB
+INTERNATIONAL_DESIGNATOR = 1999-025AA
+EPHEMERIS_NAME = NONE
+COVARIANCE_METHOD = CALCULATED
+MANEUVERABLE = NO
+REF_FRAME = EME2000
+X = 2569.540800 [km]
+Y = 2245.093614 [km]
+Z = 6281.599946 [km]
+X_DOT = -2.888612500 [km/s]
+Y_DOT = -6.007247516 [km/s]
+Z_DOT = 3.328770172 [km/s]
+CR_R = 1.337E+03
+CT_R = -4.806E+04
+CT_T = 2.492E+06
+CN_R = -3.298E+01
+CN_T = -7.5888E+02
+CN_N = 7.105E+01
+CRDOT_R = 2.591E-03
+CRDOT_T = -4.152E-02
+CRDOT_N = -1.784E-06
+CRDOT_RDOT = 6.886E-05
+CTDOT_R = -1.016E-02
+CTDOT_T = -1.506E-04
+CTDOT_N = 1.637E-03
+CTDOT_RDOT = -2.987E-06
+CTDOT_TDOT = 1.059E-05
+CNDOT_R = 4.400E-03
+CNDOT_T = 8.482E-03
+CNDOT_N = 8.633E-05
+CNDOT_RDOT = -1.903E-06
+CNDOT_TDOT = -4.594E-06
+CNDOT_NDOT = 5.178E-05
+COMMENT End of message
+";
+
+    #[test]
+    fn test_parse_cdm_kvn() {
+        let cdm = parse_cdm(CDM_KVN).unwrap();
+        assert_eq!(cdm.originator, "JSPOC");
+        assert_eq!(cdm.message_for.as_deref(), Some("SATELLITE A"));
+        assert_eq!(cdm.message_id, "201113719185");
+        assert_eq!(cdm.comments, vec!["Screening of the day".to_string()]);
+        assert_eq!(cdm.relative_comments, vec!["Relative metadata".to_string()]);
+
+        assert_eq!(
+            cdm.tca,
+            Epoch::from_gregorian_utc(2010, 3, 13, 22, 37, 52, 618_000_000)
+        );
+        assert_eq!(cdm.miss_distance, 715.0);
+        assert_eq!(cdm.relative_speed, Some(14762.0));
+        assert_eq!(
+            cdm.relative_position,
+            Some(Vector3::new(27.4, -70.2, 711.8))
+        );
+        assert_eq!(cdm.relative_velocity.unwrap().y, -14692.0);
+        assert_eq!(cdm.screening.len(), 9);
+        assert_eq!(
+            cdm.screening[3],
+            ("SCREEN_VOLUME_SHAPE".to_string(), "ELLIPSOID".to_string())
+        );
+        assert_eq!(cdm.collision_probability, Some(4.835e-5));
+        assert_eq!(
+            cdm.collision_probability_method.as_deref(),
+            Some("FOSTER-1992")
+        );
+
+        let object1 = &cdm.object1;
+        assert_eq!(object1.object_designator, "12345");
+        assert_eq!(object1.object_name, "SATELLITE A");
+        assert_eq!(object1.maneuverable, "YES");
+        assert_eq!(object1.frame().unwrap(), FrameType::J2000);
+        assert_eq!(object1.comments, vec!["Object1 metadata".to_string()]);
+        assert_eq!(object1.data_comments, vec!["Object1 data".to_string()]);
+        assert_eq!(object1.parameter("GRAVITY_MODEL"), Some("EGM-96: 36D 36O"));
+        assert_eq!(object1.parameter("MASS"), Some("251.6"));
+        assert_eq!(object1.parameter("CDRG_T"), Some("1.5E-01"));
+        assert_eq!(object1.parameters.len(), 8);
+        assert_eq!(
+            object1.state.position,
+            Vector3::new(2570.097065e3, 2244.654904e3, 6281.497978e3)
+        );
+        assert!((object1.state.velocity.x - 4418.769571).abs() < 1e-9);
+        let matrix = object1.covariance.matrix();
+        assert_eq!(matrix[(1, 1)], 2.533e3);
+        assert_eq!(matrix[(0, 1)], -8.579);
+        assert_eq!(matrix[(5, 4)], -1.091e-6);
+
+        let object2 = &cdm.object2;
+        assert_eq!(object2.object_name, "FENGYUN 1C DEB");
+        assert!(object2.parameters.is_empty());
+        assert!(object2.comments.is_empty());
+        assert_eq!(object2.data_comments, vec!["End of message".to_string()]);
+
+        // The message is consistent with its states
+        let separation = object2.state.position - object1.state.position;
+        assert!((separation.norm() - cdm.miss_distance).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_cdm_kvn_xml_round_trip() {
+        let cdm = parse_cdm(CDM_KVN).unwrap();
+
+        let kvn = cdm.to_kvn().unwrap();
+        assert!(kvn
+            .contains("\nCOMMENT Object1 metadata\nOBJECT = OBJECT1\nOBJECT_DESIGNATOR = 12345\n"));
+        assert!(kvn.contains(
+            "\nMANEUVERABLE = YES\nREF_FRAME = EME2000\nGRAVITY_MODEL = EGM-96: 36D 36O\n"
+        ));
+        assert!(kvn.contains("\nOBS_USED = 1026\nMASS = 251.6\nX = 2570.097065\n"));
+        assert!(kvn.contains("\nCNDOT_NDOT = 5.529e-5\nCDRG_R = -2.3E-03\n"));
+        assert_eq!(parse_cdm(&kvn).unwrap(), cdm);
+
+        let xml = write_cdm(&cdm, Encoding::Xml).unwrap();
+        assert!(xml.contains("<MISS_DISTANCE units=\"m\">715</MISS_DISTANCE>"));
+        assert!(xml.contains("<relativeStateVector>"));
+        assert!(xml.contains("<odParameters>"));
+        assert!(xml.contains("<MASS>251.6</MASS>"));
+        assert!(xml.contains("<X units=\"km\">2570.097065</X>"));
+        assert!(xml.contains("<CT_T units=\"m**2\">2533</CT_T>"));
+        assert!(xml.contains("<CRDOT_R units=\"m**2/s\">"));
+        let from_xml = parse_cdm(&xml).unwrap();
+        assert_eq!(from_xml, cdm);
+        assert_eq!(parse_cdm(&from_xml.to_kvn().unwrap()).unwrap(), cdm);
+
+        assert!(write_cdm(&cdm, Encoding::Json).is_err());
+    }
+
+    #[test]
+    fn test_cdm_from_states() {
+        let tca = Epoch::from_gregorian_utc(2024, 6, 1, 3, 4, 5, 0);
+        let state1 = CartesianState::new(
+            Vector3::new(7000e3, 0.0, 0.0),
+            Vector3::new(0.0, 7500.0, 0.0),
+        );
+        // 100 m above, 200 m across the track and crossing at 1 km/s
+        let state2 = CartesianState::new(
+            Vector3::new(7000.1e3, 0.0, 200.0),
+            Vector3::new(0.0, 7500.0, 1000.0),
+        );
+        let covariance = Covariance6::new(Matrix6::from_diagonal(
+            &crate::core::linalg::Vector6::new(100.0, 400.0, 25.0, 1e-4, 4e-4, 1e-4),
+        ))
+        .unwrap();
+        let object1 = CdmObject::new(
+            "12345",
+            "SAT A",
+            "2020-001A",
+            FrameType::GCRS,
+            state1,
+            covariance,
+        )
+        .unwrap();
+        let object2 = CdmObject::new(
+            "67890",
+            "DEBRIS",
+            "1999-025AA",
+            FrameType::GCRS,
+            state2,
+            covariance,
+        )
+        .unwrap();
+
+        let mut cdm = Cdm::new("ASTRORA", "SCREEN-1", tca, object1, object2).unwrap();
+        assert!((cdm.miss_distance - (100f64.powi(2) + 200f64.powi(2)).sqrt()).abs() < 1e-9);
+        assert!((cdm.relative_speed.unwrap() - 1000.0).abs() < 1e-9);
+        let relative = cdm.relative_position.unwrap();
+        assert!((relative - Vector3::new(100.0, 0.0, 200.0)).norm() < 1e-9);
+        let velocity = cdm.relative_velocity.unwrap();
+        assert!((velocity - Vector3::new(0.0, 0.0, 1000.0)).norm() < 1e-9);
+        assert_eq!(cdm.object1.ref_frame, "GCRF");
+        assert_eq!(cdm.object1.frame().unwrap(), FrameType::GCRS);
+
+        // Along-track in RTN is y here, so the inertial covariance is the same
+        let inertial = cdm.object1.inertial_covariance().unwrap();
+        assert!((inertial.matrix() - covariance.matrix()).amax() < 1e-9);
+
+        cdm.collision_probability = Some(1.5e-4);
+        cdm.collision_probability_method = Some("FOSTER-1992".to_string());
+        for encoding in [Encoding::Kvn, Encoding::Xml] {
+            let parsed = parse_cdm(&write_cdm(&cdm, encoding).unwrap()).unwrap();
+            assert_eq!(parsed.tca, tca);
+            assert_eq!(parsed.collision_probability, Some(1.5e-4));
+            assert_eq!(parsed.object2.state, cdm.object2.state);
+            let error = (parsed.object1.covariance.matrix() - covariance.matrix()).amax();
+            assert!(error < 1e-12);
+        }
+
+        assert!(
+            CdmObject::new("1", "A", "2020-001A", FrameType::TEME, state1, covariance).is_err()
+        );
+        let itrs =
+            CdmObject::new("1", "A", "2020-001A", FrameType::ITRS, state1, covariance).unwrap();
+        assert!(Cdm::new("ASTRORA", "X", tca, itrs, cdm.object2.clone()).is_err());
+    }
+
+    /// KVN keyword values, of OBJECT2 when `second` and of the header,
+    /// relative block or OBJECT1 otherwise
+    fn kvn_values(kvn: &str, second: bool) -> Vec<(String, String)> {
+        let start = if second {
+            kvn.find("OBJECT = OBJECT2").unwrap()
+        } else {
+            0
+        };
+        let end = if second {
+            kvn.len()
+        } else {
+            kvn.find("OBJECT = OBJECT2").unwrap()
+        };
+        kvn[start..end]
+            .lines()
+            .filter_map(|line| line.split_once(" = "))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_cdm_keyword_errors() {
+        let object2 = CDM_KVN.find("OBJECT = OBJECT2").unwrap();
+        // The message with the line of `key` in the first block that has it
+        // changed by `edit` (removed when `None`)
+        let edited = |key: &str, second: bool, edit: Option<&str>| {
+            let from = if second { object2 } else { 0 };
+            let at = from + CDM_KVN[from..].find(&format!("\n{key} = ")).unwrap() + 1;
+            let end = at + CDM_KVN[at..].find('\n').unwrap() + 1;
+            let line = edit.map_or(String::new(), |value| format!("{key} = {value}\n"));
+            format!("{}{line}{}", &CDM_KVN[..at], &CDM_KVN[end..])
+        };
+        let assert_rejected = |text: &str, key: &str| match parse_cdm(text) {
+            Err(PoliastroError::InvalidMessage { reason, .. }) => {
+                assert!(reason.contains(key), "{key}: {reason}")
+            }
+            other => panic!("{key}: {other:?}"),
+        };
+
+        // Every mandatory keyword, of the header, relative block and both
+        // objects
+        let header = [
+            "CREATION_DATE",
+            "ORIGINATOR",
+            "MESSAGE_ID",
+            "TCA",
+            "MISS_DISTANCE",
+        ];
+        let object = [
+            "OBJECT_DESIGNATOR",
+            "CATALOG_NAME",
+            "OBJECT_NAME",
+            "INTERNATIONAL_DESIGNATOR",
+            "EPHEMERIS_NAME",
+            "COVARIANCE_METHOD",
+            "MANEUVERABLE",
+            "REF_FRAME",
+        ];
+        for key in header {
+            assert_rejected(&edited(key, false, None), key);
+        }
+        for key in object.iter().chain(&STATE_KEYS).chain(&RTN_COVARIANCE) {
+            for second in [false, true] {
+                let text = edited(key, second, None);
+                assert!(
+                    matches!(parse_cdm(&text), Err(PoliastroError::InvalidMessage { .. })),
+                    "{key} of object {}",
+                    if second { 2 } else { 1 }
+                );
+            }
+        }
+        // Named in the error where one of a group is missing
+        assert_rejected(&edited("CN_T", true, None), "CN_T");
+        assert_rejected(&edited("Y_DOT", false, None), "Y_DOT");
+        assert_rejected(
+            &edited("RELATIVE_VELOCITY_N", false, None),
+            "RELATIVE_VELOCITY_N",
+        );
+
+        // Malformed values
+        for (key, second, value) in [
+            ("TCA", false, "2010-03-13 22:37"),
+            ("MISS_DISTANCE", false, "far [m]"),
+            ("RELATIVE_SPEED", false, "14762 m/s"),
+            ("RELATIVE_POSITION_R", false, "27.4.1 [m]"),
+            ("COLLISION_PROBABILITY", false, "low"),
+            ("X", false, "2570,097065 [km]"),
+            ("Z_DOT", true, "NaN?"),
+            ("CT_R", false, "-8.579E+"),
+            ("CNDOT_NDOT", true, ""),
+        ] {
+            assert!(
+                matches!(
+                    parse_cdm(&edited(key, second, Some(value))),
+                    Err(PoliastroError::InvalidMessage { .. })
+                ),
+                "{key} = {value}"
+            );
+        }
+        assert_rejected(
+            &edited("MISS_DISTANCE", false, Some("far [m]")),
+            "MISS_DISTANCE",
+        );
+        assert_rejected(&edited("CR_R", true, Some("big")), "CR_R");
+
+        // Optional keywords may be left out
+        for key in [
+            "MESSAGE_FOR",
+            "RELATIVE_SPEED",
+            "COLLISION_PROBABILITY",
+            "MASS",
+        ] {
+            assert!(parse_cdm(&edited(key, false, None)).is_ok(), "{key}");
+        }
+        let without_relative_state = RELATIVE_POSITION_KEYS
+            .iter()
+            .chain(&RELATIVE_VELOCITY_KEYS)
+            .fold(CDM_KVN.to_string(), |text, key| {
+                let at = text.find(&format!("\n{key} = ")).unwrap() + 1;
+                let end = at + text[at..].find('\n').unwrap() + 1;
+                format!("{}{}", &text[..at], &text[end..])
+            });
+        let cdm = parse_cdm(&without_relative_state).unwrap();
+        assert_eq!((cdm.relative_position, cdm.relative_velocity), (None, None));
+    }
+
+    #[test]
+    fn test_cdm_rtn_covariance_layout() {
+        // Distinct elements: Cᵢⱼ = 0.01 (i + 1)(j + 1) + δᵢⱼ (i + 1)
+        let matrix = Matrix6::from_fn(|i, j| {
+            0.01 * ((i + 1) * (j + 1)) as f64 + if i == j { (i + 1) as f64 } else { 0.0 }
+        });
+        let covariance = Covariance6::new(matrix).unwrap();
+        let mut cdm = parse_cdm(CDM_KVN).unwrap();
+        cdm.object2.covariance = covariance;
+
+        // Lower triangle row by row, R T N RDOT TDOT NDOT, in m², m²/s and m²/s²
+        let kvn = cdm.to_kvn().unwrap();
+        let values = kvn_values(&kvn, true);
+        let mut keys = RTN_COVARIANCE.iter();
+        for i in 0..6 {
+            for j in 0..=i {
+                let key = keys.next().unwrap();
+                let (_, value) = values.iter().find(|(k, _)| k == key).unwrap();
+                let value: f64 = value.parse().unwrap();
+                assert!((value - matrix[(i, j)]).abs() < 1e-12, "{key} = {value}");
+            }
+        }
+        assert_eq!(RTN_COVARIANCE[9], "CRDOT_RDOT");
+        assert_eq!(RTN_COVARIANCE[17], "CNDOT_N");
+        for (index, units) in [
+            (0, "m**2"),
+            (5, "m**2"),
+            (6, "m**2/s"),
+            (9, "m**2/s**2"),
+            (17, "m**2/s"),
+            (20, "m**2/s**2"),
+        ] {
+            assert_eq!(
+                rtn_covariance_units(index),
+                units,
+                "{}",
+                RTN_COVARIANCE[index]
+            );
+        }
+        let xml = cdm.to_xml().unwrap();
+        assert!(xml.contains("<CN_N units=\"m**2\">3.09</CN_N>"), "{xml}");
+        assert!(xml.contains("<CTDOT_T units=\"m**2/s\">0.1</CTDOT_T>"));
+        assert!(xml.contains("<CTDOT_TDOT units=\"m**2/s**2\">5.25</CTDOT_TDOT>"));
+
+        // Both encodings give back the full symmetric matrix
+        for text in [kvn, xml] {
+            let parsed = parse_cdm(&text).unwrap().object2.covariance.matrix();
+            assert!((parsed - matrix).amax() < 1e-12);
+            assert_eq!(parsed, parsed.transpose());
+        }
+
+        // An upper-triangle keyword is not part of the layout
+        let upper = CDM_KVN.replacen("CT_R = -8.579E+00", "CR_T = -8.579E+00", 1);
+        assert!(matches!(
+            parse_cdm(&upper),
+            Err(PoliastroError::InvalidMessage { .. })
+        ));
+    }
+
+    #[test]
+    fn test_cdm_round_trips() {
+        let cdm = parse_cdm(CDM_KVN).unwrap();
+
+        // Writing is stable: KVN → XML → KVN and XML → KVN → XML give the
+        // same text
+        let kvn = write_cdm(&cdm, Encoding::Kvn).unwrap();
+        let xml = write_cdm(&cdm, Encoding::Xml).unwrap();
+        assert_eq!(parse_cdm(&xml).unwrap().to_kvn().unwrap(), kvn);
+        assert_eq!(parse_cdm(&kvn).unwrap().to_xml().unwrap(), xml);
+
+        // Without the optional keywords
+        let mut minimal = cdm.clone();
+        minimal.message_for = None;
+        minimal.comments.clear();
+        minimal.relative_comments.clear();
+        minimal.relative_speed = None;
+        minimal.relative_position = None;
+        minimal.relative_velocity = None;
+        minimal.screening.clear();
+        minimal.collision_probability = None;
+        minimal.collision_probability_method = None;
+        for object in [&mut minimal.object1, &mut minimal.object2] {
+            object.parameters.clear();
+            object.comments.clear();
+            object.data_comments.clear();
+        }
+        for encoding in [Encoding::Kvn, Encoding::Xml] {
+            let text = write_cdm(&minimal, encoding).unwrap();
+            assert!(!text.contains("MESSAGE_FOR") && !text.contains("COMMENT"));
+            let parsed = parse_cdm(&text).unwrap();
+            assert_eq!(parsed, minimal);
+            let other = match encoding {
+                Encoding::Kvn => Encoding::Xml,
+                _ => Encoding::Kvn,
+            };
+            assert_eq!(
+                parse_cdm(&write_cdm(&parsed, other).unwrap()).unwrap(),
+                minimal
+            );
+        }
+
+        // Comments, screening data and parameters with unusual values survive
+        // both encodings
+        let mut unusual = cdm;
+        unusual.object1.comments = vec!["Values with = and < & >".to_string()];
+        unusual
+            .object1
+            .parameters
+            .push(("ORBIT_CENTER".to_string(), "EARTH".to_string()));
+        unusual.screening[0].1 = "2010-03-12T18:29:32.212".to_string();
+        for encoding in [Encoding::Kvn, Encoding::Xml] {
+            let parsed = parse_cdm(&write_cdm(&unusual, encoding).unwrap()).unwrap();
+            assert_eq!(parsed.object1.comments, unusual.object1.comments);
+            assert_eq!(parsed.object1.parameter("ORBIT_CENTER"), Some("EARTH"));
+            assert_eq!(parsed.object1.frame().unwrap(), FrameType::J2000);
+        }
+
+        // Invalid messages are not written
+        let mut invalid = parse_cdm(CDM_KVN).unwrap();
+        invalid.object2.ref_frame = "TEME".to_string();
+        assert!(write_cdm(&invalid, Encoding::Kvn).is_err());
+        assert!(write_cdm(&invalid, Encoding::Xml).is_err());
+    }
+
+    #[test]
+    fn test_parse_cdm_errors() {
+        let replace = |from: &str, to: &str| {
+            assert!(CDM_KVN.contains(from), "{from}");
+            CDM_KVN.replacen(from, to, 1)
+        };
+        let cases = [
+            // Missing mandatory keywords
+            replace("MESSAGE_ID = 201113719185\n", ""),
+            replace("TCA = 2010-03-13T22:37:52.618\n", ""),
+            replace("CCSDS_CDM_VERS = 1.0\n", ""),
+            replace("MANEUVERABLE = NO\n", ""),
+            replace("Z_DOT = 3.328770172 [km/s]\n", ""),
+            replace("CNDOT_NDOT = 5.178E-05\n", ""),
+            replace("RELATIVE_POSITION_T = -70.2 [m]\n", ""),
+            // Objects out of order, or a third one
+            replace("OBJECT = OBJECT1", "OBJECT = OBJECT2"),
+            format!("{CDM_KVN}OBJECT = OBJECT3\n"),
+            CDM_KVN[..CDM_KVN.find("OBJECT = OBJECT2").unwrap()].to_string(),
+            // Keywords in the wrong block, unknown or malformed
+            replace("MESSAGE_FOR = SATELLITE A\n", "").replace(
+                "COMMENT Object1 data\n",
+                "COMMENT Object1 data\nMESSAGE_FOR = A\n",
+            ),
+            replace("SCREEN_VOLUME_X = 200", "SCREEN_VOLUME_A = 200"),
+            replace("MASS = 251.6 [kg]", "COLOUR = blue"),
+            replace("OBS_USED = 1026\n", "OBS_USED = 1026\nREF_FRAME = GCRF\n"),
+            replace("MISS_DISTANCE = 715 [m]", "MISS_DISTANCE = -715 [m]"),
+            replace(
+                "COLLISION_PROBABILITY = 4.835E-05",
+                "COLLISION_PROBABILITY = 1.5",
+            ),
+            replace("REF_FRAME = EME2000", "REF_FRAME = TEME"),
+            replace("CT_T = 2.533E+03", "CT_T = -2.533E+03"),
+            replace("X = 2570.097065 [km]", "2570.097065"),
+        ];
+        for text in cases {
+            assert!(
+                matches!(parse_cdm(&text), Err(PoliastroError::InvalidMessage { .. })),
+                "{text}"
+            );
+        }
+
+        assert!(parse_cdm("{\"TCA\": \"2010-03-13T22:37:52\"}").is_err());
+        assert!(parse_cdm("<oem/>").is_err());
+    }
+}
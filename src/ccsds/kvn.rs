@@ -0,0 +1,177 @@
+//! KVN (keyword = value notation) reading and writing
+
+use std::fmt::Display;
+
+use crate::core::{PoliastroError, PoliastroResult};
+
+/// One non-blank line of a KVN message
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum KvnLine {
+    /// `KEYWORD = value [units]`, without the units
+    Field { key: String, value: String },
+    /// `COMMENT text`
+    Comment(String),
+    /// A keyword alone on its line (`META_START`, `COVARIANCE_STOP`, ...)
+    Keyword(String),
+    /// Whitespace-separated values (ephemeris and covariance data lines)
+    Values(Vec<String>),
+}
+
+/// Split a KVN message into lines, each with its 1-based line number
+pub(crate) fn lines(text: &str, message_type: &str) -> PoliastroResult<Vec<(usize, KvnLine)>> {
+    let mut lines = Vec::new();
+    for (index, raw) in text.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let parsed = if line == "COMMENT" || line.starts_with("COMMENT ") {
+            KvnLine::Comment(line["COMMENT".len()..].trim().to_string())
+        } else if let Some((key, value)) = line.split_once('=') {
+            let key = key.trim();
+            if !is_keyword(key) {
+                return Err(PoliastroError::invalid_message(
+                    message_type,
+                    format!("line {}: invalid keyword '{key}'", index + 1),
+                ));
+            }
+            KvnLine::Field {
+                key: key.to_string(),
+                value: strip_units(value.trim()).to_string(),
+            }
+        } else if is_keyword(line) && (line.ends_with("_START") || line.ends_with("_STOP")) {
+            KvnLine::Keyword(line.to_string())
+        } else {
+            KvnLine::Values(line.split_whitespace().map(str::to_string).collect())
+        };
+        lines.push((index + 1, parsed));
+    }
+    Ok(lines)
+}
+
+fn is_keyword(text: &str) -> bool {
+    !text.is_empty()
+        && text
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Drop a trailing `[units]`
+fn strip_units(value: &str) -> &str {
+    match value
+        .strip_suffix(']')
+        .and_then(|rest| rest.rsplit_once('['))
+    {
+        Some((value, units)) if !units.contains(char::is_whitespace) => value.trim_end(),
+        _ => value,
+    }
+}
+
+/// Builds a KVN message line by line
+#[derive(Debug, Default)]
+pub(crate) struct KvnWriter {
+    text: String,
+}
+
+impl KvnWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field(&mut self, key: &str, value: impl Display) {
+        self.text += &format!("{key} = {value}\n");
+    }
+
+    /// A keyword alone on its line, such as `META_START`
+    pub fn keyword(&mut self, keyword: &str) {
+        self.text += keyword;
+        self.text.push('\n');
+    }
+
+    /// A data line of whitespace-separated values
+    pub fn values(&mut self, values: &[String]) {
+        self.text += &values.join(" ");
+        self.text.push('\n');
+    }
+
+    pub fn comments(&mut self, comments: &[String]) {
+        for comment in comments {
+            self.text += &format!("COMMENT {comment}\n");
+        }
+    }
+
+    pub fn blank(&mut self) {
+        self.text.push('\n');
+    }
+
+    pub fn finish(self) -> String {
+        self.text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kvn_lines() {
+        let text = "CCSDS_OEM_VERS = 2.0\n\
+                    COMMENT  generated for testing\n\
+                    \n\
+                    META_START\n\
+                    OBJECT_NAME = ISS (ZARYA)\n\
+                    SEMI_MAJOR_AXIS = 6800.0 [km]\n\
+                    META_STOP\n\
+                    2024-01-01T00:00:00 1.0 2.0 3.0\n\
+                    COMMENT\n";
+        let parsed = lines(text, "OEM").unwrap();
+        assert_eq!(parsed.len(), 8);
+        assert_eq!(
+            parsed[1],
+            (2, KvnLine::Comment("generated for testing".to_string()))
+        );
+        assert_eq!(parsed[2], (4, KvnLine::Keyword("META_START".to_string())));
+        assert_eq!(
+            parsed[3].1,
+            KvnLine::Field {
+                key: "OBJECT_NAME".to_string(),
+                value: "ISS (ZARYA)".to_string()
+            }
+        );
+        assert_eq!(
+            parsed[4].1,
+            KvnLine::Field {
+                key: "SEMI_MAJOR_AXIS".to_string(),
+                value: "6800.0".to_string()
+            }
+        );
+        assert!(matches!(&parsed[6].1, KvnLine::Values(values) if values.len() == 4));
+        assert_eq!(parsed[7].1, KvnLine::Comment(String::new()));
+
+        assert!(matches!(
+            lines("bad key = 1", "OEM"),
+            Err(PoliastroError::InvalidMessage { .. })
+        ));
+    }
+
+    #[test]
+    fn test_kvn_writer() {
+        let mut kvn = KvnWriter::new();
+        kvn.field("CCSDS_OEM_VERS", "2.0");
+        kvn.comments(&["first".to_string()]);
+        kvn.blank();
+        kvn.keyword("META_START");
+        kvn.field("OBJECT_NAME", "ISS");
+        kvn.keyword("META_STOP");
+        kvn.values(&["1.5".to_string(), "-2".to_string()]);
+        let text = kvn.finish();
+        assert_eq!(
+            text,
+            "CCSDS_OEM_VERS = 2.0\nCOMMENT first\n\nMETA_START\nOBJECT_NAME = ISS\nMETA_STOP\n1.5 -2\n"
+        );
+        let parsed = lines(&text, "OEM").unwrap();
+        assert_eq!(parsed.len(), 6);
+        assert_eq!(parsed[2].1, KvnLine::Keyword("META_START".to_string()));
+        assert!(matches!(&parsed[5].1, KvnLine::Values(values) if values.len() == 2));
+    }
+}
@@ -0,0 +1,579 @@
+//! CCSDS Navigation Data Messages
+//!
+//! Shared support for the two encodings of the CCSDS navigation data
+//! messages (NDM):
+//! - KVN: `KEYWORD = value [units]` lines, `COMMENT` lines, `*_START` /
+//!   `*_STOP` block keywords and whitespace-separated data lines
+//! - XML: the NDM/XML schema, in which keywords become leaf elements grouped
+//!   into `header`, `metadata` and `data` blocks
+//!
+//! Both encodings are read into keyword/value [`Fields`] from which the
+//! message parsers build their typed structures, so a message parses to the
+//! same value whichever encoding it arrives in. Epochs are CCSDS ASCII time
+//! codes (`YYYY-MM-DDThh:mm:ss.d…` or `YYYY-DDDThh:mm:ss.d…`), and Cartesian
+//! covariances are the 21 lower-triangle keywords `CX_X` … `CZ_DOT_Z_DOT`.
+//!
+//! Epochs map onto [`Epoch`] in the message TIME_SYSTEM, and REF_FRAME /
+//! CENTER_NAME pairs onto the supported [`FrameType`]s (see [`frame_type`]).
+//!
+//! Messages:
+//! - [`opm`]: orbit parameter messages, with maneuvers
+//! - [`oem`]: orbit ephemeris messages, with an interpolating ephemeris
+//! - [`cdm`]: conjunction data messages
+//! - [`apm`]: attitude parameter messages
+//! - [`aem`]: attitude ephemeris messages, with attitude interpolation
+//!
+//! The orbit mean-elements message (OMM) itself lives with the SGP4 elements
+//! in [`crate::satellite::omm`].
+//!
+//! # References
+//!
+//! - CCSDS 502.0-B-3: Orbit Data Messages
+//! - CCSDS 504.0-B-1: Attitude Data Messages
+//! - CCSDS 505.0-B-3: XML Specification for Navigation Data Messages
+//! - CCSDS 508.0-B-1: Conjunction Data Message
+
+pub mod aem;
+pub mod apm;
+pub mod cdm;
+pub(crate) mod kvn;
+pub mod oem;
+pub mod opm;
+pub(crate) mod xml;
+
+pub use aem::{
+    parse_aem, write_aem, Aem, AemAttitude, AemMetadata, AemSegment, AttitudeRate, AttitudeType,
+};
+pub use apm::{parse_apm, write_apm, Apm, ApmMetadata, AttitudeManeuver};
+pub use cdm::{parse_cdm, write_cdm, Cdm, CdmObject};
+pub use oem::{
+    parse_oem, write_oem, Interpolation, Oem, OemCovariance, OemEphemeris, OemMetadata, OemSegment,
+    OemStateVector,
+};
+pub use opm::{parse_opm, write_opm, Opm, OpmManeuver, OpmMetadata, SpacecraftParameters};
+
+use std::str::FromStr;
+
+use hifitime::{Epoch as HifiEpoch, TimeScale};
+use sgp4::chrono::{Datelike, NaiveDateTime, Timelike};
+
+use crate::coordinates::FrameType;
+use crate::core::covariance::Covariance6;
+use crate::core::linalg::{Matrix6, Quaternion, UnitQuaternion, Vector3};
+use crate::core::time::Epoch;
+use crate::core::{PoliastroError, PoliastroResult};
+
+/// Encoding of a navigation data message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Keyword = value notation
+    Kvn,
+    /// NDM/XML
+    Xml,
+    /// JSON objects with the KVN keywords (OMM only, as distributed by
+    /// CelesTrak and Space-Track)
+    Json,
+}
+
+impl Encoding {
+    /// Encoding of a message, from its first non-blank character
+    pub fn detect(text: &str) -> Self {
+        match text.trim_start().chars().next() {
+            Some('<') => Encoding::Xml,
+            Some('{') | Some('[') => Encoding::Json,
+            _ => Encoding::Kvn,
+        }
+    }
+
+    /// Encoding from its name ("kvn", "xml" or "json", any case)
+    pub fn from_name(name: &str) -> PoliastroResult<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "kvn" => Ok(Encoding::Kvn),
+            "xml" => Ok(Encoding::Xml),
+            "json" => Ok(Encoding::Json),
+            _ => Err(PoliastroError::invalid_message(
+                "NDM",
+                format!("unknown encoding '{name}' (expected kvn, xml or json)"),
+            )),
+        }
+    }
+}
+
+/// Keyword/value pairs of a message block, in message order
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Fields {
+    message_type: &'static str,
+    entries: Vec<(String, String)>,
+    /// COMMENT lines, in order
+    pub comments: Vec<String>,
+}
+
+impl Fields {
+    pub fn new(message_type: &'static str) -> Self {
+        Self {
+            message_type,
+            entries: Vec::new(),
+            comments: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.entries.push((key.into(), value.into()));
+    }
+
+    /// Keyword/value pairs, in message order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Value of the first occurrence of `key`
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn require(&self, key: &str) -> PoliastroResult<&str> {
+        self.get(key)
+            .ok_or_else(|| self.error(format!("missing {key}")))
+    }
+
+    /// Parsed value of an optional keyword
+    pub fn parse<T: FromStr>(&self, key: &str) -> PoliastroResult<Option<T>> {
+        self.get(key)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| self.error(format!("invalid {key} '{value}'")))
+            })
+            .transpose()
+    }
+
+    pub fn require_parse<T: FromStr>(&self, key: &str) -> PoliastroResult<T> {
+        self.parse(key)?
+            .ok_or_else(|| self.error(format!("missing {key}")))
+    }
+
+    pub fn datetime(&self, key: &str) -> PoliastroResult<NaiveDateTime> {
+        parse_datetime(self.require(key)?, self.message_type)
+    }
+
+    /// Covariance from the 21 lower-triangle `keys`, scaled by `scale`
+    ///
+    /// `None` when none of the keys is present; an error when only some are.
+    pub fn covariance(
+        &self,
+        keys: &[&str; 21],
+        scale: f64,
+    ) -> PoliastroResult<Option<Covariance6>> {
+        if keys.iter().all(|key| self.get(key).is_none()) {
+            return Ok(None);
+        }
+        let values = keys
+            .iter()
+            .map(|key| self.require_parse::<f64>(key))
+            .collect::<PoliastroResult<Vec<_>>>()?;
+        from_lower_triangle(&values, scale, self.message_type).map(Some)
+    }
+
+    /// Vector from three keywords, scaled by `scale`
+    ///
+    /// `None` when none of the keywords is present; an error when only some are.
+    pub fn vector(&self, keys: &[&str; 3], scale: f64) -> PoliastroResult<Option<Vector3>> {
+        if keys.iter().all(|key| self.get(key).is_none()) {
+            return Ok(None);
+        }
+        let mut vector = Vector3::zeros();
+        for (component, key) in vector.iter_mut().zip(keys) {
+            *component = self.require_parse::<f64>(key)? * scale;
+        }
+        Ok(Some(vector))
+    }
+
+    pub fn error(&self, reason: impl Into<String>) -> PoliastroError {
+        PoliastroError::invalid_message(self.message_type, reason)
+    }
+}
+
+/// Parse a CCSDS ASCII time code, calendar (`YYYY-MM-DDThh:mm:ss.d…`) or
+/// day-of-year (`YYYY-DDDThh:mm:ss.d…`), with an optional trailing `Z`
+pub(crate) fn parse_datetime(value: &str, message_type: &str) -> PoliastroResult<NaiveDateTime> {
+    let trimmed = value.trim().trim_end_matches('Z');
+    ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%jT%H:%M:%S%.f"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(trimmed, format).ok())
+        .ok_or_else(|| {
+            PoliastroError::invalid_message(message_type, format!("invalid epoch '{value}'"))
+        })
+}
+
+/// Format a time as a calendar CCSDS ASCII time code, with as many decimals
+/// as needed
+pub(crate) fn format_datetime(datetime: &NaiveDateTime) -> String {
+    datetime.format("%Y-%m-%dT%H:%M:%S%.f").to_string()
+}
+
+/// Time scale of a TIME_SYSTEM value
+pub(crate) fn time_scale(time_system: &str, message_type: &str) -> PoliastroResult<TimeScale> {
+    match time_system {
+        "UTC" => Ok(TimeScale::UTC),
+        "TAI" => Ok(TimeScale::TAI),
+        "TT" => Ok(TimeScale::TT),
+        "TDB" => Ok(TimeScale::TDB),
+        "GPS" => Ok(TimeScale::GPST),
+        _ => Err(PoliastroError::invalid_message(
+            message_type,
+            format!("unsupported TIME_SYSTEM '{time_system}' (expected UTC, TAI, TT, TDB or GPS)"),
+        )),
+    }
+}
+
+/// Parse a CCSDS ASCII time code in `time_scale`
+pub(crate) fn parse_epoch(
+    value: &str,
+    time_scale: TimeScale,
+    message_type: &str,
+) -> PoliastroResult<Epoch> {
+    let datetime = parse_datetime(value, message_type)?;
+    // A leap second is a nanosecond count past one second
+    let nanos = datetime.nanosecond();
+    HifiEpoch::maybe_from_gregorian(
+        datetime.year(),
+        datetime.month() as u8,
+        datetime.day() as u8,
+        datetime.hour() as u8,
+        datetime.minute() as u8,
+        (datetime.second() + nanos / 1_000_000_000) as u8,
+        nanos % 1_000_000_000,
+        time_scale,
+    )
+    .map(Epoch::new)
+    .map_err(|_| PoliastroError::invalid_message(message_type, format!("invalid epoch '{value}'")))
+}
+
+/// Format an epoch as a calendar CCSDS ASCII time code in `time_scale`, with
+/// as many decimals as needed
+pub(crate) fn format_epoch(epoch: &Epoch, time_scale: TimeScale) -> String {
+    let (year, month, day, hour, minute, second, nanos) = epoch.inner().to_gregorian(time_scale);
+    let mut text = format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}");
+    if nanos > 0 {
+        text += format!(".{nanos:09}").trim_end_matches('0');
+    }
+    text
+}
+
+/// Frame of a REF_FRAME and CENTER_NAME pair
+///
+/// ICRF is the ICRS about the solar system barycenter and the GCRS about the
+/// Earth; EME2000 is J2000, any ITRF realization the ITRS, and CIRF / TIRF
+/// the CIRS / TIRS. The other frames are geocentric.
+///
+/// # Errors
+///
+/// `UnknownFrame` for other frames or centers
+pub fn frame_type(ref_frame: &str, center_name: &str) -> PoliastroResult<FrameType> {
+    let center = center_name.trim().to_ascii_uppercase();
+    let barycentric = matches!(center.as_str(), "SOLAR SYSTEM BARYCENTER" | "SSB");
+    let frame = match ref_frame.trim().to_ascii_uppercase().as_str() {
+        "ICRF" | "ICRF2" | "ICRF3" if barycentric => FrameType::ICRS,
+        "ICRF" | "ICRF2" | "ICRF3" | "GCRF" => FrameType::GCRS,
+        "EME2000" => FrameType::J2000,
+        "TEME" => FrameType::TEME,
+        "MOD" => FrameType::MOD,
+        "TOD" => FrameType::TOD,
+        "CIRF" => FrameType::CIRS,
+        "TIRF" => FrameType::TIRS,
+        name if name.starts_with("ITRF") => FrameType::ITRS,
+        _ => return Err(PoliastroError::unknown_frame(ref_frame)),
+    };
+    if center != "EARTH" && frame != FrameType::ICRS {
+        return Err(PoliastroError::unknown_frame(format!(
+            "{ref_frame} about {center_name}"
+        )));
+    }
+    Ok(frame)
+}
+
+/// REF_FRAME and CENTER_NAME of a frame, the inverse of [`frame_type`]
+pub fn frame_names(frame: FrameType) -> (&'static str, &'static str) {
+    match frame {
+        FrameType::ICRS => ("ICRF", "SOLAR SYSTEM BARYCENTER"),
+        FrameType::GCRS => ("GCRF", "EARTH"),
+        FrameType::J2000 => ("EME2000", "EARTH"),
+        FrameType::ITRS => ("ITRF2014", "EARTH"),
+        FrameType::TEME => ("TEME", "EARTH"),
+        FrameType::MOD => ("MOD", "EARTH"),
+        FrameType::TOD => ("TOD", "EARTH"),
+        FrameType::CIRS => ("CIRF", "EARTH"),
+        FrameType::TIRS => ("TIRF", "EARTH"),
+    }
+}
+
+/// Lower-triangle keywords of a Cartesian covariance, row by row
+pub(crate) const CARTESIAN_COVARIANCE: [&str; 21] = [
+    "CX_X",
+    "CY_X",
+    "CY_Y",
+    "CZ_X",
+    "CZ_Y",
+    "CZ_Z",
+    "CX_DOT_X",
+    "CX_DOT_Y",
+    "CX_DOT_Z",
+    "CX_DOT_X_DOT",
+    "CY_DOT_X",
+    "CY_DOT_Y",
+    "CY_DOT_Z",
+    "CY_DOT_X_DOT",
+    "CY_DOT_Y_DOT",
+    "CZ_DOT_X",
+    "CZ_DOT_Y",
+    "CZ_DOT_Z",
+    "CZ_DOT_X_DOT",
+    "CZ_DOT_Y_DOT",
+    "CZ_DOT_Z_DOT",
+];
+
+/// Units of the Cartesian covariance keywords, in the order of
+/// [`CARTESIAN_COVARIANCE`]
+pub(crate) fn cartesian_covariance_units(index: usize) -> &'static str {
+    match index {
+        0..=5 => "km**2",
+        6..=8 | 10..=12 | 15..=17 => "km**2/s",
+        _ => "km**2/s**2",
+    }
+}
+
+/// Km² to m² (km²/s to m²/s, km²/s² to m²/s²)
+pub(crate) const KM2_TO_M2: f64 = 1e6;
+
+/// Covariance from its 21 lower-triangle values, scaled by `scale`
+pub(crate) fn from_lower_triangle(
+    values: &[f64],
+    scale: f64,
+    message_type: &str,
+) -> PoliastroResult<Covariance6> {
+    if values.len() != 21 {
+        return Err(PoliastroError::invalid_message(
+            message_type,
+            format!("covariance has {} values (expected 21)", values.len()),
+        ));
+    }
+    let mut matrix = Matrix6::zeros();
+    let mut k = 0;
+    for i in 0..6 {
+        for j in 0..=i {
+            matrix[(i, j)] = values[k] * scale;
+            matrix[(j, i)] = values[k] * scale;
+            k += 1;
+        }
+    }
+    Covariance6::new(matrix)
+        .map_err(|e| PoliastroError::invalid_message(message_type, format!("covariance: {e}")))
+}
+
+/// The 21 lower-triangle values of a covariance, divided by `scale`
+pub(crate) fn lower_triangle(covariance: &Covariance6, scale: f64) -> Vec<f64> {
+    let matrix = covariance.matrix();
+    (0..6)
+        .flat_map(|i| (0..=i).map(move |j| (i, j)))
+        .map(|index| matrix[index] / scale)
+        .collect()
+}
+
+/// Direction of the rotation of an attitude message (Q_DIR, ATTITUDE_DIR)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttitudeDirection {
+    /// From frame A to frame B
+    AToB,
+    /// From frame B to frame A
+    BToA,
+}
+
+impl AttitudeDirection {
+    /// Direction from its CCSDS name ("A2B" or "B2A", any case)
+    pub fn from_name(name: &str, message_type: &str) -> PoliastroResult<Self> {
+        match name.trim().to_ascii_uppercase().as_str() {
+            "A2B" => Ok(AttitudeDirection::AToB),
+            "B2A" => Ok(AttitudeDirection::BToA),
+            _ => Err(PoliastroError::invalid_message(
+                message_type,
+                format!("unknown attitude direction '{name}' (expected A2B or B2A)"),
+            )),
+        }
+    }
+
+    /// CCSDS name
+    pub fn name(&self) -> &'static str {
+        match self {
+            AttitudeDirection::AToB => "A2B",
+            AttitudeDirection::BToA => "B2A",
+        }
+    }
+
+    /// Rotation from frame A to frame B, given the rotation of a message in
+    /// this direction
+    pub fn a_to_b(&self, rotation: &UnitQuaternion) -> UnitQuaternion {
+        match self {
+            AttitudeDirection::AToB => *rotation,
+            AttitudeDirection::BToA => rotation.inverse(),
+        }
+    }
+}
+
+/// Keywords of the quaternion components, vector part first
+pub(crate) const QUATERNION_KEYS: [&str; 4] = ["Q1", "Q2", "Q3", "QC"];
+
+/// Keywords of the quaternion derivative components
+pub(crate) const QUATERNION_RATE_KEYS: [&str; 4] = ["Q1_DOT", "Q2_DOT", "Q3_DOT", "QC_DOT"];
+
+/// Largest departure from unit norm of a quaternion read from a message,
+/// which is then normalized
+const QUATERNION_NORM_TOLERANCE: f64 = 1e-3;
+
+/// Rotation from its components Q1, Q2, Q3 (vector part) and QC (scalar
+/// part)
+pub(crate) fn quaternion(
+    components: [f64; 4],
+    message_type: &str,
+) -> PoliastroResult<UnitQuaternion> {
+    let [q1, q2, q3, qc] = components;
+    let quaternion = Quaternion::new(qc, q1, q2, q3);
+    let norm = quaternion.norm();
+    if !norm.is_finite() || (norm - 1.0).abs() > QUATERNION_NORM_TOLERANCE {
+        return Err(PoliastroError::invalid_message(
+            message_type,
+            format!("quaternion {components:?} is not a unit quaternion (norm {norm})"),
+        ));
+    }
+    Ok(UnitQuaternion::from_quaternion(quaternion))
+}
+
+/// Components Q1, Q2, Q3 and QC of a rotation
+pub(crate) fn quaternion_components(rotation: &UnitQuaternion) -> [f64; 4] {
+    let coords = rotation.quaternion().coords;
+    [coords.x, coords.y, coords.z, coords.w]
+}
+
+/// Format a number so that parsing it gives back the same value
+pub(crate) fn format_number(value: f64) -> String {
+    if value != 0.0 && (value.abs() < 1e-4 || value.abs() >= 1e15) {
+        format!("{value:e}")
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_encoding() {
+        assert_eq!(
+            Encoding::detect("  <?xml version=\"1.0\"?><omm/>"),
+            Encoding::Xml
+        );
+        assert_eq!(Encoding::detect("\n{\"EPOCH\": \"\"}"), Encoding::Json);
+        assert_eq!(Encoding::detect("CCSDS_OMM_VERS = 2.0"), Encoding::Kvn);
+        assert_eq!(Encoding::from_name("XML").unwrap(), Encoding::Xml);
+        assert!(Encoding::from_name("yaml").is_err());
+    }
+
+    #[test]
+    fn test_parse_datetime() {
+        let calendar = parse_datetime("2008-09-20T12:25:40.104", "OMM").unwrap();
+        let day_of_year = parse_datetime("2008-264T12:25:40.104Z", "OMM").unwrap();
+        assert_eq!(calendar, day_of_year);
+        assert_eq!(format_datetime(&calendar), "2008-09-20T12:25:40.104");
+        assert_eq!(
+            format_datetime(&parse_datetime("2024-01-01T00:00:00", "OEM").unwrap()),
+            "2024-01-01T00:00:00"
+        );
+        assert!(matches!(
+            parse_datetime("2024-13-01T00:00:00", "OEM"),
+            Err(PoliastroError::InvalidMessage { .. })
+        ));
+    }
+
+    #[test]
+    fn test_epoch_time_systems() {
+        let scale = time_scale("TAI", "OEM").unwrap();
+        let epoch = parse_epoch("2024-01-01T00:00:37.25", scale, "OEM").unwrap();
+        assert_eq!(
+            epoch,
+            Epoch::from_gregorian_utc(2024, 1, 1, 0, 0, 0, 250_000_000)
+        );
+        assert_eq!(format_epoch(&epoch, scale), "2024-01-01T00:00:37.25");
+        assert_eq!(
+            format_epoch(&epoch, TimeScale::UTC),
+            "2024-01-01T00:00:00.25"
+        );
+
+        // Leap second
+        let leap = parse_epoch("2016-12-31T23:59:60.5", TimeScale::UTC, "OEM").unwrap();
+        let after = Epoch::from_gregorian_utc(2017, 1, 1, 0, 0, 0, 0);
+        assert!((after.duration_since(&leap).to_seconds() - 0.5).abs() < 1e-9);
+
+        assert!(time_scale("UT1", "OEM").is_err());
+    }
+
+    #[test]
+    fn test_frame_names() {
+        for frame in [
+            FrameType::ICRS,
+            FrameType::GCRS,
+            FrameType::J2000,
+            FrameType::ITRS,
+            FrameType::TEME,
+            FrameType::MOD,
+            FrameType::TOD,
+            FrameType::CIRS,
+            FrameType::TIRS,
+        ] {
+            let (ref_frame, center_name) = frame_names(frame);
+            assert_eq!(frame_type(ref_frame, center_name).unwrap(), frame);
+        }
+        assert_eq!(frame_type("ICRF", "EARTH").unwrap(), FrameType::GCRS);
+        assert_eq!(frame_type("ITRF-93", "EARTH").unwrap(), FrameType::ITRS);
+        assert!(matches!(
+            frame_type("EME2000", "MARS"),
+            Err(PoliastroError::UnknownFrame { .. })
+        ));
+        assert!(frame_type("RTN", "EARTH").is_err());
+    }
+
+    #[test]
+    fn test_covariance_lower_triangle() {
+        let values: Vec<f64> = (1..=21).map(|k| k as f64 * 1e-3).collect();
+        let covariance = from_lower_triangle(&values, KM2_TO_M2, "OPM").unwrap();
+        assert_eq!(covariance.matrix()[(1, 0)], 2e-3 * KM2_TO_M2);
+        assert_eq!(covariance.matrix()[(0, 1)], 2e-3 * KM2_TO_M2);
+        assert_eq!(covariance.matrix()[(5, 5)], 21e-3 * KM2_TO_M2);
+
+        let back = lower_triangle(&covariance, KM2_TO_M2);
+        for (a, b) in back.iter().zip(&values) {
+            assert!((a - b).abs() <= 1e-15 * b.abs());
+        }
+
+        let mut fields = Fields::new("OPM");
+        assert!(fields
+            .covariance(&CARTESIAN_COVARIANCE, 1.0)
+            .unwrap()
+            .is_none());
+        fields.push("CX_X", "1.0");
+        assert!(fields.covariance(&CARTESIAN_COVARIANCE, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_format_number() {
+        for value in [0.0, 1.0, -0.5, 51.6416, 1.2345e-9, -3.3e-7, 2.5e20, 7e-5] {
+            assert_eq!(format_number(value).parse::<f64>().unwrap(), value);
+        }
+        assert_eq!(format_number(1.2345e-9), "1.2345e-9");
+        assert_eq!(format_number(15.72125391), "15.72125391");
+    }
+}
@@ -0,0 +1,2026 @@
+//! Orbit Ephemeris Message (OEM)
+//!
+//! Reading and writing of CCSDS OEMs in KVN and XML, and an interpolating
+//! ephemeris built from their state vectors.
+//!
+//! An OEM is a header followed by one or more segments, each with its own
+//! metadata (object, center, frame, time system, span and interpolation
+//! settings), a time-ordered list of state vectors and optional covariances.
+//! States are read into [`CartesianState`]s in m and m/s at [`Epoch`]s in the
+//! segment TIME_SYSTEM; the message itself is in km and km/s.
+//!
+//! [`OemEphemeris`] interpolates the states with the method and degree of the
+//! segment metadata, and never across segment boundaries, which usually fall
+//! on maneuvers. It evaluates in any of the supported frames, and feeds
+//! satellite pass prediction over a ground observer and closest approach
+//! search against another ephemeris.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use astrora_core::ccsds::parse_oem;
+//! use astrora_core::coordinates::FrameType;
+//!
+//! let oem = parse_oem(&std::fs::read_to_string("sat.oem")?)?;
+//! let ephemeris = oem.ephemeris()?;
+//! let state = ephemeris.state_in(&epoch, FrameType::ITRS)?;
+//! ```
+//!
+//! # References
+//!
+//! - CCSDS 502.0-B-3: Orbit Data Messages, section 5
+
+use hifitime::TimeScale;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use super::kvn::{self, KvnLine, KvnWriter};
+use super::xml::{XmlElement, XmlWriter};
+use super::{
+    format_epoch, format_number, parse_epoch, Encoding, Fields, CARTESIAN_COVARIANCE, KM2_TO_M2,
+};
+use crate::coordinates::{transform_state, FrameType};
+use crate::core::covariance::Covariance6;
+use crate::core::linalg::Vector3;
+use crate::core::state::CartesianState;
+use crate::core::time::{Duration, Epoch};
+use crate::core::{PoliastroError, PoliastroResult};
+use crate::satellite::conjunction::{compute_conjunction_between, ConjunctionResult};
+use crate::satellite::visibility::{find_all_passes, Observer, SatellitePass};
+
+/// Version of CCSDS 502.0 written in KVN and XML messages
+pub const CCSDS_OEM_VERSION: &str = "2.0";
+
+const MESSAGE_TYPE: &str = "OEM";
+
+/// Interpolation degree when a segment does not give one
+const DEFAULT_INTERPOLATION_DEGREE: usize = 7;
+
+/// Km to m (km/s to m/s, km/s² to m/s²)
+const KM_TO_M: f64 = 1e3;
+
+/// Keywords of the state vector components in XML, in KVN data line order
+const STATE_KEYS: [&str; 9] = [
+    "X", "Y", "Z", "X_DOT", "Y_DOT", "Z_DOT", "X_DDOT", "Y_DDOT", "Z_DDOT",
+];
+
+const STATE_UNITS: [&str; 3] = ["km", "km/s", "km/s**2"];
+
+/// Interpolation method of a segment (INTERPOLATION)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Lagrange polynomials through the positions, and separately the
+    /// velocities
+    Lagrange,
+    /// Hermite polynomials through the positions and their velocities
+    Hermite,
+    /// Straight lines between consecutive states
+    Linear,
+}
+
+impl Interpolation {
+    /// Method from its CCSDS name ("LAGRANGE", "HERMITE" or "LINEAR", any case)
+    pub fn from_name(name: &str) -> PoliastroResult<Self> {
+        match name.trim().to_ascii_uppercase().as_str() {
+            "LAGRANGE" => Ok(Interpolation::Lagrange),
+            "HERMITE" => Ok(Interpolation::Hermite),
+            "LINEAR" => Ok(Interpolation::Linear),
+            _ => Err(PoliastroError::invalid_message(
+                MESSAGE_TYPE,
+                format!("unknown INTERPOLATION '{name}' (expected LAGRANGE, HERMITE or LINEAR)"),
+            )),
+        }
+    }
+
+    /// CCSDS name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Interpolation::Lagrange => "LAGRANGE",
+            Interpolation::Hermite => "HERMITE",
+            Interpolation::Linear => "LINEAR",
+        }
+    }
+
+    /// Number of states the interpolant of `degree` passes through
+    fn nodes(&self, degree: usize) -> usize {
+        match self {
+            Interpolation::Lagrange => degree + 1,
+            // 2n - 1 >= degree with n states and their velocities
+            Interpolation::Hermite => (degree + 2) / 2,
+            Interpolation::Linear => 2,
+        }
+    }
+}
+
+/// Metadata of an OEM segment
+#[derive(Debug, Clone, PartialEq)]
+pub struct OemMetadata {
+    /// Spacecraft name (OBJECT_NAME)
+    pub object_name: String,
+    /// International designator (OBJECT_ID)
+    pub object_id: String,
+    /// Origin of the frame (CENTER_NAME)
+    pub center_name: String,
+    /// Reference frame of the states (REF_FRAME)
+    pub ref_frame: String,
+    /// Epoch of the frame, when not implied by its name (REF_FRAME_EPOCH)
+    pub ref_frame_epoch: Option<String>,
+    /// Time system of all epochs of the segment (TIME_SYSTEM)
+    pub time_system: String,
+    /// Start of the span of the segment (START_TIME)
+    pub start_time: Epoch,
+    /// Start of the span recommended for use (USEABLE_START_TIME)
+    pub useable_start_time: Option<Epoch>,
+    /// End of the span recommended for use (USEABLE_STOP_TIME)
+    pub useable_stop_time: Option<Epoch>,
+    /// End of the span of the segment (STOP_TIME)
+    pub stop_time: Epoch,
+    /// Recommended interpolation method (INTERPOLATION)
+    pub interpolation: Option<Interpolation>,
+    /// Recommended interpolation degree (INTERPOLATION_DEGREE)
+    pub interpolation_degree: Option<usize>,
+    /// COMMENT lines of the metadata block
+    pub comments: Vec<String>,
+}
+
+impl OemMetadata {
+    /// Frame of the states, from REF_FRAME and CENTER_NAME
+    ///
+    /// # Errors
+    ///
+    /// `UnknownFrame` if the pair is not one of the supported frames
+    pub fn frame(&self) -> PoliastroResult<FrameType> {
+        super::frame_type(&self.ref_frame, &self.center_name)
+    }
+
+    fn time_scale(&self) -> PoliastroResult<TimeScale> {
+        super::time_scale(&self.time_system, MESSAGE_TYPE)
+    }
+}
+
+/// A state vector of an OEM segment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OemStateVector {
+    /// Epoch of the state
+    pub epoch: Epoch,
+    /// Position (m) and velocity (m/s)
+    pub state: CartesianState,
+    /// Acceleration (m/s²), when the message gives one
+    pub acceleration: Option<Vector3>,
+}
+
+impl OemStateVector {
+    pub fn new(epoch: Epoch, state: CartesianState) -> Self {
+        Self {
+            epoch,
+            state,
+            acceleration: None,
+        }
+    }
+
+    /// Components in km, km/s and km/s², in data line order
+    fn values(&self) -> Vec<f64> {
+        let vectors = [
+            Some(self.state.position),
+            Some(self.state.velocity),
+            self.acceleration,
+        ];
+        vectors
+            .iter()
+            .flatten()
+            .flat_map(|vector| vector.iter().map(|value| value / KM_TO_M))
+            .collect()
+    }
+
+    /// State from its 6 or 9 components in km, km/s and km/s²
+    fn from_values(epoch: Epoch, values: &[f64]) -> Self {
+        let vector = |i: usize| Vector3::new(values[i], values[i + 1], values[i + 2]) * KM_TO_M;
+        Self {
+            epoch,
+            state: CartesianState::new(vector(0), vector(3)),
+            acceleration: (values.len() == 9).then(|| vector(6)),
+        }
+    }
+}
+
+/// A covariance of an OEM segment
+#[derive(Debug, Clone, PartialEq)]
+pub struct OemCovariance {
+    /// Epoch of the covariance
+    pub epoch: Epoch,
+    /// Frame of the covariance (COV_REF_FRAME), when it is not REF_FRAME
+    pub frame: Option<String>,
+    /// Position and velocity covariance, in m and m/s
+    pub covariance: Covariance6,
+}
+
+/// A segment of an OEM: metadata, states and covariances
+#[derive(Debug, Clone, PartialEq)]
+pub struct OemSegment {
+    /// Metadata block
+    pub metadata: OemMetadata,
+    /// COMMENT lines of the data block
+    pub comments: Vec<String>,
+    /// State vectors, in increasing time order
+    pub states: Vec<OemStateVector>,
+    /// Covariances, if any
+    pub covariances: Vec<OemCovariance>,
+}
+
+impl OemSegment {
+    /// Segment of `states` in `frame`, spanning from the first to the last
+    /// state, in UTC and with Lagrange interpolation of degree 7 (or less
+    /// when there are fewer states)
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` if there are no states or they are not in increasing
+    /// time order
+    pub fn new(
+        object_name: impl Into<String>,
+        object_id: impl Into<String>,
+        frame: FrameType,
+        states: Vec<OemStateVector>,
+    ) -> PoliastroResult<Self> {
+        let (Some(first), Some(last)) = (states.first(), states.last()) else {
+            return Err(invalid("a segment needs at least one state"));
+        };
+        let (ref_frame, center_name) = super::frame_names(frame);
+        let metadata = OemMetadata {
+            object_name: object_name.into(),
+            object_id: object_id.into(),
+            center_name: center_name.to_string(),
+            ref_frame: ref_frame.to_string(),
+            ref_frame_epoch: None,
+            time_system: "UTC".to_string(),
+            start_time: first.epoch,
+            useable_start_time: None,
+            useable_stop_time: None,
+            stop_time: last.epoch,
+            interpolation: Some(Interpolation::Lagrange),
+            interpolation_degree: Some(DEFAULT_INTERPOLATION_DEGREE.min(states.len() - 1)),
+            comments: Vec::new(),
+        };
+        let segment = Self {
+            metadata,
+            comments: Vec::new(),
+            states,
+            covariances: Vec::new(),
+        };
+        segment.validate()?;
+        Ok(segment)
+    }
+
+    /// Check the time system, and that the states are in increasing time
+    /// order within START_TIME and STOP_TIME, as are the useable times
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` describing the first problem found
+    pub fn validate(&self) -> PoliastroResult<()> {
+        let metadata = &self.metadata;
+        let time_scale = metadata.time_scale()?;
+        let text = |epoch: &Epoch| format_epoch(epoch, time_scale);
+        let before = |a: &Epoch, b: &Epoch| b.duration_since(a).to_seconds() > 0.0;
+
+        if self.states.is_empty() {
+            return Err(invalid(format!(
+                "segment of {} has no states",
+                metadata.object_name
+            )));
+        }
+        if before(&metadata.stop_time, &metadata.start_time) {
+            return Err(invalid("STOP_TIME is before START_TIME"));
+        }
+        for (key, epoch) in [
+            ("USEABLE_START_TIME", metadata.useable_start_time),
+            ("USEABLE_STOP_TIME", metadata.useable_stop_time),
+        ] {
+            if let Some(epoch) = epoch {
+                if before(&epoch, &metadata.start_time) || before(&metadata.stop_time, &epoch) {
+                    return Err(invalid(format!(
+                        "{key} {} is outside START_TIME and STOP_TIME",
+                        text(&epoch)
+                    )));
+                }
+            }
+        }
+        if let (Some(start), Some(stop)) = (metadata.useable_start_time, metadata.useable_stop_time)
+        {
+            if before(&stop, &start) {
+                return Err(invalid("USEABLE_STOP_TIME is before USEABLE_START_TIME"));
+            }
+        }
+
+        for pair in self.states.windows(2) {
+            if !before(&pair[0].epoch, &pair[1].epoch) {
+                return Err(invalid(format!(
+                    "state at {} is not after the state at {}",
+                    text(&pair[1].epoch),
+                    text(&pair[0].epoch)
+                )));
+            }
+        }
+        for state in [&self.states[0], &self.states[self.states.len() - 1]] {
+            if before(&state.epoch, &metadata.start_time)
+                || before(&metadata.stop_time, &state.epoch)
+            {
+                return Err(invalid(format!(
+                    "state at {} is outside START_TIME and STOP_TIME",
+                    text(&state.epoch)
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An orbit ephemeris message: header and segments
+#[derive(Debug, Clone, PartialEq)]
+pub struct Oem {
+    /// Agency or operator creating the message (ORIGINATOR)
+    pub originator: String,
+    /// Creation time, UTC (CREATION_DATE)
+    pub creation_date: String,
+    /// COMMENT lines of the header
+    pub comments: Vec<String>,
+    /// Segments, in message order
+    pub segments: Vec<OemSegment>,
+}
+
+impl Oem {
+    /// OEM of `segments` from `originator`, created now
+    pub fn new(originator: impl Into<String>, segments: Vec<OemSegment>) -> Self {
+        Self {
+            originator: originator.into(),
+            creation_date: format_epoch(&Epoch::now(), TimeScale::UTC),
+            comments: Vec::new(),
+            segments,
+        }
+    }
+
+    /// Interpolating ephemeris of all segments
+    ///
+    /// # Errors
+    ///
+    /// - `UnknownFrame`: If a segment frame is not supported
+    /// - `InvalidMessage`: If the segments are in different frames or a
+    ///   segment is invalid
+    pub fn ephemeris(&self) -> PoliastroResult<OemEphemeris> {
+        OemEphemeris::from_segments(&self.segments)
+    }
+
+    /// Write the message in CCSDS KVN
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` if a segment is invalid
+    pub fn to_kvn(&self) -> PoliastroResult<String> {
+        let mut kvn = KvnWriter::new();
+        kvn.field("CCSDS_OEM_VERS", CCSDS_OEM_VERSION);
+        kvn.comments(&self.comments);
+        kvn.field("CREATION_DATE", &self.creation_date);
+        kvn.field("ORIGINATOR", &self.originator);
+
+        for segment in &self.segments {
+            segment.validate()?;
+            let time_scale = segment.metadata.time_scale()?;
+
+            kvn.blank();
+            kvn.keyword("META_START");
+            kvn.comments(&segment.metadata.comments);
+            for (key, value) in metadata_fields(&segment.metadata, time_scale) {
+                kvn.field(key, value);
+            }
+            kvn.keyword("META_STOP");
+
+            kvn.blank();
+            kvn.comments(&segment.comments);
+            for state in &segment.states {
+                let values: Vec<String> = std::iter::once(format_epoch(&state.epoch, time_scale))
+                    .chain(state.values().into_iter().map(format_number))
+                    .collect();
+                kvn.values(&values);
+            }
+
+            if !segment.covariances.is_empty() {
+                kvn.blank();
+                kvn.keyword("COVARIANCE_START");
+                for covariance in &segment.covariances {
+                    kvn.field("EPOCH", format_epoch(&covariance.epoch, time_scale));
+                    if let Some(frame) = &covariance.frame {
+                        kvn.field("COV_REF_FRAME", frame);
+                    }
+                    let values = super::lower_triangle(&covariance.covariance, KM2_TO_M2);
+                    for row in 0..6 {
+                        let start = row * (row + 1) / 2;
+                        let row_values: Vec<String> = values[start..=start + row]
+                            .iter()
+                            .map(|&value| format_number(value))
+                            .collect();
+                        kvn.values(&row_values);
+                    }
+                }
+                kvn.keyword("COVARIANCE_STOP");
+            }
+        }
+        Ok(kvn.finish())
+    }
+
+    /// Write the message in CCSDS NDM/XML, with units attributes as in the
+    /// CCSDS schema
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` if a segment is invalid
+    pub fn to_xml(&self) -> PoliastroResult<String> {
+        let mut xml = XmlWriter::new("oem", CCSDS_OEM_VERSION);
+        xml.open("header");
+        xml.comments(&self.comments);
+        xml.leaf("CREATION_DATE", &self.creation_date, None);
+        xml.leaf("ORIGINATOR", &self.originator, None);
+        xml.close("header");
+
+        xml.open("body");
+        for segment in &self.segments {
+            segment.validate()?;
+            let time_scale = segment.metadata.time_scale()?;
+
+            xml.open("segment");
+            xml.open("metadata");
+            xml.comments(&segment.metadata.comments);
+            for (key, value) in metadata_fields(&segment.metadata, time_scale) {
+                xml.leaf(key, &value, None);
+            }
+            xml.close("metadata");
+
+            xml.open("data");
+            xml.comments(&segment.comments);
+            for state in &segment.states {
+                let epoch = ("EPOCH", format_epoch(&state.epoch, time_scale), None);
+                let components = state
+                    .values()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, value)| {
+                        (
+                            STATE_KEYS[index],
+                            format_number(value),
+                            Some(STATE_UNITS[index / 3]),
+                        )
+                    });
+                xml.block("stateVector", std::iter::once(epoch).chain(components));
+            }
+            for covariance in &segment.covariances {
+                let mut fields = vec![("EPOCH", format_epoch(&covariance.epoch, time_scale), None)];
+                if let Some(frame) = &covariance.frame {
+                    fields.push(("COV_REF_FRAME", frame.clone(), None));
+                }
+                let values = super::lower_triangle(&covariance.covariance, KM2_TO_M2);
+                for (index, (key, value)) in CARTESIAN_COVARIANCE.iter().zip(values).enumerate() {
+                    let units = super::cartesian_covariance_units(index);
+                    fields.push((key, format_number(value), Some(units)));
+                }
+                xml.block("covarianceMatrix", fields);
+            }
+            xml.close("data");
+            xml.close("segment");
+        }
+        xml.close("body");
+        Ok(xml.finish("oem"))
+    }
+}
+
+/// Parse an OEM in CCSDS KVN or XML (detected from the text)
+///
+/// # Errors
+///
+/// `InvalidMessage` if the message is malformed, misses a mandatory
+/// keyword, has no segments, or a segment fails [`OemSegment::validate`]
+///
+/// # Example
+///
+/// ```ignore
+/// let oem = parse_oem(&std::fs::read_to_string("sat.oem")?)?;
+/// for segment in &oem.segments {
+///     println!("{}: {} states", segment.metadata.object_name, segment.states.len());
+/// }
+/// ```
+pub fn parse_oem(text: &str) -> PoliastroResult<Oem> {
+    match Encoding::detect(text) {
+        Encoding::Kvn => oem_from_kvn(text),
+        Encoding::Xml => oem_from_xml(text),
+        Encoding::Json => Err(invalid("JSON is not a CCSDS OEM encoding")),
+    }
+}
+
+/// Write an OEM in `encoding` (KVN or XML)
+///
+/// # Errors
+///
+/// `InvalidMessage` for the JSON encoding or if a segment is invalid
+pub fn write_oem(oem: &Oem, encoding: Encoding) -> PoliastroResult<String> {
+    match encoding {
+        Encoding::Kvn => oem.to_kvn(),
+        Encoding::Xml => oem.to_xml(),
+        Encoding::Json => Err(invalid("JSON is not a CCSDS OEM encoding")),
+    }
+}
+
+fn invalid(reason: impl Into<String>) -> PoliastroError {
+    PoliastroError::invalid_message(MESSAGE_TYPE, reason)
+}
+
+/// Prefix the reason of a message error with a KVN line number
+fn at_line(line: usize, error: PoliastroError) -> PoliastroError {
+    match error {
+        PoliastroError::InvalidMessage {
+            message_type,
+            reason,
+        } => PoliastroError::invalid_message(message_type, format!("line {line}: {reason}")),
+        other => other,
+    }
+}
+
+/// Metadata keywords and values, in CCSDS order
+fn metadata_fields(metadata: &OemMetadata, time_scale: TimeScale) -> Vec<(&'static str, String)> {
+    let epoch = |epoch: &Epoch| format_epoch(epoch, time_scale);
+    let mut fields = vec![
+        ("OBJECT_NAME", metadata.object_name.clone()),
+        ("OBJECT_ID", metadata.object_id.clone()),
+        ("CENTER_NAME", metadata.center_name.clone()),
+        ("REF_FRAME", metadata.ref_frame.clone()),
+    ];
+    if let Some(ref_frame_epoch) = &metadata.ref_frame_epoch {
+        fields.push(("REF_FRAME_EPOCH", ref_frame_epoch.clone()));
+    }
+    fields.push(("TIME_SYSTEM", metadata.time_system.clone()));
+    fields.push(("START_TIME", epoch(&metadata.start_time)));
+    if let Some(useable_start) = &metadata.useable_start_time {
+        fields.push(("USEABLE_START_TIME", epoch(useable_start)));
+    }
+    if let Some(useable_stop) = &metadata.useable_stop_time {
+        fields.push(("USEABLE_STOP_TIME", epoch(useable_stop)));
+    }
+    fields.push(("STOP_TIME", epoch(&metadata.stop_time)));
+    if let Some(interpolation) = metadata.interpolation {
+        fields.push(("INTERPOLATION", interpolation.name().to_string()));
+    }
+    if let Some(degree) = metadata.interpolation_degree {
+        fields.push(("INTERPOLATION_DEGREE", degree.to_string()));
+    }
+    fields
+}
+
+fn metadata_from_fields(fields: &Fields) -> PoliastroResult<OemMetadata> {
+    let time_system = fields.require("TIME_SYSTEM")?.to_string();
+    let time_scale = super::time_scale(&time_system, MESSAGE_TYPE)?;
+    let epoch = |key: &str| parse_epoch(fields.require(key)?, time_scale, MESSAGE_TYPE);
+    let optional_epoch = |key: &str| {
+        fields
+            .get(key)
+            .map(|value| parse_epoch(value, time_scale, MESSAGE_TYPE))
+            .transpose()
+    };
+
+    Ok(OemMetadata {
+        object_name: fields.require("OBJECT_NAME")?.to_string(),
+        object_id: fields.require("OBJECT_ID")?.to_string(),
+        center_name: fields.require("CENTER_NAME")?.to_string(),
+        ref_frame: fields.require("REF_FRAME")?.to_string(),
+        ref_frame_epoch: fields.get("REF_FRAME_EPOCH").map(str::to_string),
+        start_time: epoch("START_TIME")?,
+        useable_start_time: optional_epoch("USEABLE_START_TIME")?,
+        useable_stop_time: optional_epoch("USEABLE_STOP_TIME")?,
+        stop_time: epoch("STOP_TIME")?,
+        interpolation: fields
+            .get("INTERPOLATION")
+            .map(Interpolation::from_name)
+            .transpose()?,
+        interpolation_degree: fields.parse("INTERPOLATION_DEGREE")?,
+        comments: fields.comments.clone(),
+        time_system,
+    })
+}
+
+/// Header and segments into a message
+fn complete_oem(header: &Fields, segments: Vec<OemSegment>) -> PoliastroResult<Oem> {
+    if segments.is_empty() {
+        return Err(invalid("no segments"));
+    }
+    for segment in &segments {
+        segment.validate()?;
+    }
+    Ok(Oem {
+        originator: header.require("ORIGINATOR")?.to_string(),
+        creation_date: header.require("CREATION_DATE")?.to_string(),
+        comments: header.comments.clone(),
+        segments,
+    })
+}
+
+/// Block of a KVN message being read
+enum KvnBlock {
+    Header,
+    Metadata(Fields),
+    Data,
+    Covariance(Option<PendingCovariance>),
+}
+
+/// Covariance whose values are still being read
+struct PendingCovariance {
+    epoch: Epoch,
+    frame: Option<String>,
+    values: Vec<f64>,
+}
+
+impl PendingCovariance {
+    fn finish(self) -> PoliastroResult<OemCovariance> {
+        Ok(OemCovariance {
+            epoch: self.epoch,
+            frame: self.frame,
+            covariance: super::from_lower_triangle(&self.values, KM2_TO_M2, MESSAGE_TYPE)?,
+        })
+    }
+}
+
+fn oem_from_kvn(text: &str) -> PoliastroResult<Oem> {
+    let mut header = Fields::new(MESSAGE_TYPE);
+    let mut segments: Vec<OemSegment> = Vec::new();
+    let mut block = KvnBlock::Header;
+
+    for (line_no, line) in kvn::lines(text, MESSAGE_TYPE)? {
+        block = match (block, line) {
+            (KvnBlock::Header, KvnLine::Field { key, value }) => {
+                header.push(key, value);
+                KvnBlock::Header
+            }
+            (KvnBlock::Header, KvnLine::Comment(comment)) => {
+                header.comments.push(comment);
+                KvnBlock::Header
+            }
+            (KvnBlock::Header | KvnBlock::Data, KvnLine::Keyword(keyword))
+                if keyword == "META_START" =>
+            {
+                KvnBlock::Metadata(Fields::new(MESSAGE_TYPE))
+            }
+            (KvnBlock::Metadata(mut fields), KvnLine::Field { key, value }) => {
+                fields.push(key, value);
+                KvnBlock::Metadata(fields)
+            }
+            (KvnBlock::Metadata(mut fields), KvnLine::Comment(comment)) => {
+                fields.comments.push(comment);
+                KvnBlock::Metadata(fields)
+            }
+            (KvnBlock::Metadata(fields), KvnLine::Keyword(keyword)) if keyword == "META_STOP" => {
+                segments.push(OemSegment {
+                    metadata: metadata_from_fields(&fields).map_err(|e| at_line(line_no, e))?,
+                    comments: Vec::new(),
+                    states: Vec::new(),
+                    covariances: Vec::new(),
+                });
+                KvnBlock::Data
+            }
+            (KvnBlock::Data, KvnLine::Keyword(keyword)) if keyword == "COVARIANCE_START" => {
+                KvnBlock::Covariance(None)
+            }
+            (KvnBlock::Data, KvnLine::Comment(comment)) => {
+                if let Some(segment) = segments.last_mut() {
+                    segment.comments.push(comment);
+                }
+                KvnBlock::Data
+            }
+            (KvnBlock::Data, KvnLine::Values(values)) => {
+                if let Some(segment) = segments.last_mut() {
+                    let state = state_from_kvn(&values, &segment.metadata)
+                        .map_err(|e| at_line(line_no, e))?;
+                    segment.states.push(state);
+                }
+                KvnBlock::Data
+            }
+            (KvnBlock::Covariance(current), line) => {
+                covariance_line(current, line, &mut segments).map_err(|e| at_line(line_no, e))?
+            }
+            (_, other) => return Err(at_line(line_no, unexpected(&other))),
+        };
+    }
+
+    match block {
+        KvnBlock::Header | KvnBlock::Data => {}
+        KvnBlock::Metadata(_) => return Err(invalid("missing META_STOP")),
+        KvnBlock::Covariance(_) => return Err(invalid("missing COVARIANCE_STOP")),
+    }
+    header.require("CCSDS_OEM_VERS")?;
+    complete_oem(&header, segments)
+}
+
+/// Read one line of a KVN covariance block, into the last segment
+fn covariance_line(
+    current: Option<PendingCovariance>,
+    line: KvnLine,
+    segments: &mut [OemSegment],
+) -> PoliastroResult<KvnBlock> {
+    // Covariance blocks only follow META_STOP, so there is a segment
+    let Some(segment) = segments.last_mut() else {
+        return Err(unexpected(&line));
+    };
+    let mut finish = |current: Option<PendingCovariance>| -> PoliastroResult<()> {
+        if let Some(pending) = current {
+            segment.covariances.push(pending.finish()?);
+        }
+        Ok(())
+    };
+
+    match (current, line) {
+        (current, KvnLine::Field { key, value }) if key == "EPOCH" => {
+            finish(current)?;
+            let time_scale = segment.metadata.time_scale()?;
+            Ok(KvnBlock::Covariance(Some(PendingCovariance {
+                epoch: parse_epoch(&value, time_scale, MESSAGE_TYPE)?,
+                frame: None,
+                values: Vec::new(),
+            })))
+        }
+        (Some(mut pending), KvnLine::Field { key, value }) if key == "COV_REF_FRAME" => {
+            pending.frame = Some(value);
+            Ok(KvnBlock::Covariance(Some(pending)))
+        }
+        (Some(mut pending), KvnLine::Values(values)) => {
+            for value in values {
+                let number = value
+                    .parse()
+                    .map_err(|_| invalid(format!("invalid covariance value '{value}'")))?;
+                pending.values.push(number);
+            }
+            Ok(KvnBlock::Covariance(Some(pending)))
+        }
+        (current, KvnLine::Comment(comment)) => {
+            segment.comments.push(comment);
+            Ok(KvnBlock::Covariance(current))
+        }
+        (Some(pending), KvnLine::Keyword(keyword)) if keyword == "COVARIANCE_STOP" => {
+            finish(Some(pending))?;
+            Ok(KvnBlock::Data)
+        }
+        (_, other) => Err(unexpected(&other)),
+    }
+}
+
+fn unexpected(line: &KvnLine) -> PoliastroError {
+    invalid(match line {
+        KvnLine::Field { key, .. } => format!("unexpected keyword {key}"),
+        KvnLine::Comment(_) => "unexpected COMMENT".to_string(),
+        KvnLine::Keyword(keyword) => format!("unexpected {keyword}"),
+        KvnLine::Values(_) => "unexpected data line".to_string(),
+    })
+}
+
+/// State from a KVN data line: the epoch and 6 or 9 components
+fn state_from_kvn(values: &[String], metadata: &OemMetadata) -> PoliastroResult<OemStateVector> {
+    if values.len() != 7 && values.len() != 10 {
+        return Err(invalid(format!(
+            "a data line has an epoch and 6 or 9 components, found {} values",
+            values.len()
+        )));
+    }
+    let epoch = parse_epoch(&values[0], metadata.time_scale()?, MESSAGE_TYPE)?;
+    let components = values[1..]
+        .iter()
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| invalid(format!("invalid state component '{value}'")))
+        })
+        .collect::<PoliastroResult<Vec<f64>>>()?;
+    Ok(OemStateVector::from_values(epoch, &components))
+}
+
+fn oem_from_xml(text: &str) -> PoliastroResult<Oem> {
+    let root = XmlElement::parse(text, MESSAGE_TYPE)?;
+    if root.name != "oem" {
+        return Err(invalid(format!(
+            "root element is <{}>, not <oem>",
+            root.name
+        )));
+    }
+    let header = root
+        .child("header")
+        .map(|header| header.fields(MESSAGE_TYPE))
+        .unwrap_or_else(|| Fields::new(MESSAGE_TYPE));
+    let body = root
+        .child("body")
+        .ok_or_else(|| invalid("missing <body>"))?;
+    let segments = body
+        .children_named("segment")
+        .map(segment_from_xml)
+        .collect::<PoliastroResult<Vec<_>>>()?;
+    complete_oem(&header, segments)
+}
+
+fn segment_from_xml(element: &XmlElement) -> PoliastroResult<OemSegment> {
+    let metadata = element
+        .child("metadata")
+        .ok_or_else(|| invalid("segment without <metadata>"))?;
+    let metadata = metadata_from_fields(&metadata.fields(MESSAGE_TYPE))?;
+    let time_scale = metadata.time_scale()?;
+    let data = element
+        .child("data")
+        .ok_or_else(|| invalid("segment without <data>"))?;
+
+    let states = data
+        .children_named("stateVector")
+        .map(|state| {
+            let fields = state.fields(MESSAGE_TYPE);
+            let epoch = parse_epoch(fields.require("EPOCH")?, time_scale, MESSAGE_TYPE)?;
+            let has_acceleration = STATE_KEYS[6..].iter().any(|key| fields.get(key).is_some());
+            let count = if has_acceleration { 9 } else { 6 };
+            let components = STATE_KEYS[..count]
+                .iter()
+                .map(|key| fields.require_parse(key))
+                .collect::<PoliastroResult<Vec<f64>>>()?;
+            Ok(OemStateVector::from_values(epoch, &components))
+        })
+        .collect::<PoliastroResult<Vec<_>>>()?;
+
+    let covariances = data
+        .children_named("covarianceMatrix")
+        .map(|matrix| {
+            let fields = matrix.fields(MESSAGE_TYPE);
+            Ok(OemCovariance {
+                epoch: parse_epoch(fields.require("EPOCH")?, time_scale, MESSAGE_TYPE)?,
+                frame: fields.get("COV_REF_FRAME").map(str::to_string),
+                covariance: fields
+                    .covariance(&CARTESIAN_COVARIANCE, KM2_TO_M2)?
+                    .ok_or_else(|| fields.error("covarianceMatrix without values"))?,
+            })
+        })
+        .collect::<PoliastroResult<Vec<_>>>()?;
+
+    Ok(OemSegment {
+        metadata,
+        comments: data.fields(MESSAGE_TYPE).comments,
+        states,
+        covariances,
+    })
+}
+
+/// Interpolating ephemeris of the states of one or more OEM segments
+///
+/// Each segment is interpolated on its own over its useable span, with its
+/// INTERPOLATION and INTERPOLATION_DEGREE (Lagrange of degree 7 when absent),
+/// through the states nearest the requested time. Lagrange interpolates the
+/// positions and the velocities separately; Hermite interpolates the
+/// positions with their velocities as derivatives and differentiates for the
+/// velocity. Where segments meet, the later one is used.
+///
+/// # Example
+///
+/// ```ignore
+/// let ephemeris = parse_oem(&text)?.ephemeris()?;
+/// let passes = ephemeris.find_passes(&observer, &start, &end, 10f64.to_radians(), 1.0)?;
+/// ```
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OemEphemeris {
+    frame: FrameType,
+    /// Epoch the piece times count from
+    reference: Epoch,
+    pieces: Vec<Piece>,
+}
+
+/// The interpolant of one segment
+#[derive(Debug, Clone, PartialEq)]
+struct Piece {
+    interpolation: Interpolation,
+    /// Number of states each interpolation passes through
+    nodes: usize,
+    /// Useable span (s from the reference epoch)
+    start: f64,
+    stop: f64,
+    times: Vec<f64>,
+    positions: Vec<Vector3>,
+    velocities: Vec<Vector3>,
+}
+
+impl Piece {
+    fn new(segment: &OemSegment, reference: &Epoch) -> Self {
+        let seconds = |epoch: &Epoch| epoch.duration_since(reference).to_seconds();
+        let metadata = &segment.metadata;
+        let times: Vec<f64> = segment
+            .states
+            .iter()
+            .map(|state| seconds(&state.epoch))
+            .collect();
+        let (first, last) = (times[0], times[times.len() - 1]);
+        let interpolation = metadata.interpolation.unwrap_or(Interpolation::Lagrange);
+        let degree = metadata
+            .interpolation_degree
+            .unwrap_or(DEFAULT_INTERPOLATION_DEGREE);
+
+        Self {
+            interpolation,
+            nodes: interpolation.nodes(degree).max(2).min(times.len()),
+            start: metadata
+                .useable_start_time
+                .map_or(first, |epoch| seconds(&epoch).max(first)),
+            stop: metadata
+                .useable_stop_time
+                .map_or(last, |epoch| seconds(&epoch).min(last)),
+            positions: segment
+                .states
+                .iter()
+                .map(|state| state.state.position)
+                .collect(),
+            velocities: segment
+                .states
+                .iter()
+                .map(|state| state.state.velocity)
+                .collect(),
+            times,
+        }
+    }
+
+    fn contains(&self, t: f64) -> bool {
+        t >= self.start && t <= self.stop
+    }
+
+    fn evaluate(&self, t: f64) -> (Vector3, Vector3) {
+        // The states around t, shifted inwards at the ends
+        let after = self.times.partition_point(|&time| time < t);
+        let first = after
+            .saturating_sub(self.nodes / 2)
+            .min(self.times.len() - self.nodes);
+        let window = first..first + self.nodes;
+        let times = &self.times[window.clone()];
+
+        match self.interpolation {
+            Interpolation::Lagrange | Interpolation::Linear => (
+                lagrange(times, &self.positions[window.clone()], t),
+                lagrange(times, &self.velocities[window], t),
+            ),
+            Interpolation::Hermite => hermite(
+                times,
+                &self.positions[window.clone()],
+                &self.velocities[window],
+                t,
+            ),
+        }
+    }
+}
+
+/// Value at `t` of the Lagrange polynomial through `values` at `times`
+fn lagrange(times: &[f64], values: &[Vector3], t: f64) -> Vector3 {
+    let mut result = Vector3::zeros();
+    for (j, (&t_j, value)) in times.iter().zip(values).enumerate() {
+        let weight: f64 = times
+            .iter()
+            .enumerate()
+            .filter(|&(k, _)| k != j)
+            .map(|(_, &t_k)| (t - t_k) / (t_j - t_k))
+            .product();
+        result += value * weight;
+    }
+    result
+}
+
+/// Value and derivative at `t` of the Hermite polynomial through `values`
+/// with `derivatives` at `times`, from its divided differences
+fn hermite(
+    times: &[f64],
+    values: &[Vector3],
+    derivatives: &[Vector3],
+    t: f64,
+) -> (Vector3, Vector3) {
+    // Every node twice, and the differences over each repeated node are the
+    // derivatives
+    let size = 2 * times.len();
+    let z: Vec<f64> = times.iter().flat_map(|&time| [time, time]).collect();
+    let mut coefficients: Vec<Vector3> = values.iter().flat_map(|&value| [value, value]).collect();
+    for order in 1..size {
+        for i in (order..size).rev() {
+            coefficients[i] = if order == 1 && i % 2 == 1 {
+                derivatives[i / 2]
+            } else {
+                (coefficients[i] - coefficients[i - 1]) / (z[i] - z[i - order])
+            };
+        }
+    }
+
+    // Newton form by Horner's scheme, with its derivative
+    let mut value = coefficients[size - 1];
+    let mut derivative = Vector3::zeros();
+    for i in (0..size - 1).rev() {
+        derivative = derivative * (t - z[i]) + value;
+        value = value * (t - z[i]) + coefficients[i];
+    }
+    (value, derivative)
+}
+
+impl OemEphemeris {
+    /// Ephemeris of `segments`, which must all be in the same frame
+    ///
+    /// # Errors
+    ///
+    /// - `UnknownFrame`: If the segment frame is not supported
+    /// - `InvalidMessage`: If there are no segments, they are in different
+    ///   frames, or a segment is invalid
+    pub fn from_segments(segments: &[OemSegment]) -> PoliastroResult<Self> {
+        let first = segments
+            .first()
+            .ok_or_else(|| invalid("an ephemeris needs at least one segment"))?;
+        first.validate()?;
+        let frame = first.metadata.frame()?;
+        let reference = first.states[0].epoch;
+
+        let pieces = segments
+            .iter()
+            .map(|segment| {
+                segment.validate()?;
+                let segment_frame = segment.metadata.frame()?;
+                if segment_frame != frame {
+                    return Err(invalid(format!(
+                        "segments in different frames ({frame} and {segment_frame})"
+                    )));
+                }
+                Ok(Piece::new(segment, &reference))
+            })
+            .collect::<PoliastroResult<Vec<_>>>()?;
+
+        Ok(Self {
+            frame,
+            reference,
+            pieces,
+        })
+    }
+
+    /// Frame of the states
+    pub fn frame(&self) -> FrameType {
+        self.frame
+    }
+
+    /// Start of the span
+    pub fn start(&self) -> Epoch {
+        self.epoch_at(self.span().0)
+    }
+
+    /// End of the span
+    pub fn stop(&self) -> Epoch {
+        self.epoch_at(self.span().1)
+    }
+
+    /// Interpolated state at `epoch`, in the frame of the ephemeris
+    ///
+    /// # Errors
+    ///
+    /// - `OutOfRange`: If `epoch` is outside the span
+    /// - `InvalidParameter`: If `epoch` falls in a gap between segments
+    pub fn state(&self, epoch: &Epoch) -> PoliastroResult<CartesianState> {
+        let (position, velocity) = self.interpolate(self.seconds(epoch))?;
+        Ok(CartesianState::new(position, velocity))
+    }
+
+    /// Interpolated state at `epoch`, transformed to `frame`
+    ///
+    /// # Errors
+    ///
+    /// As [`OemEphemeris::state`], or if the frame transformation fails
+    pub fn state_in(&self, epoch: &Epoch, frame: FrameType) -> PoliastroResult<CartesianState> {
+        let state = self.state(epoch)?;
+        let (position, velocity) =
+            transform_state(&state.position, &state.velocity, epoch, self.frame, frame)?;
+        Ok(CartesianState::new(position, velocity))
+    }
+
+    /// The same ephemeris with every state transformed to `frame`
+    ///
+    /// Interpolating in the new frame is equivalent to transforming the
+    /// interpolated states as long as the states are dense compared to the
+    /// frame motion, as they are for frames rotating with the Earth.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a frame transformation fails
+    pub fn in_frame(&self, frame: FrameType) -> PoliastroResult<Self> {
+        let mut ephemeris = self.clone();
+        ephemeris.frame = frame;
+        for piece in &mut ephemeris.pieces {
+            for ((&t, position), velocity) in piece
+                .times
+                .iter()
+                .zip(&mut piece.positions)
+                .zip(&mut piece.velocities)
+            {
+                let epoch = self.epoch_at(t);
+                (*position, *velocity) =
+                    transform_state(position, velocity, &epoch, self.frame, frame)?;
+            }
+        }
+        Ok(ephemeris)
+    }
+
+    /// Passes over a ground observer between `start` and `end`
+    ///
+    /// The states are transformed to the ITRS once, then interpolated for
+    /// [`find_all_passes`].
+    ///
+    /// # Arguments
+    ///
+    /// * `observer` - Ground observer
+    /// * `start`, `end` - Search window, within the span
+    /// * `min_elevation` - Minimum elevation of a pass (radians)
+    /// * `time_step` - Search step (minutes)
+    ///
+    /// # Returns
+    ///
+    /// The passes, with times in minutes from `start`
+    ///
+    /// # Errors
+    ///
+    /// - `OutOfRange` / `InvalidParameter`: If the ephemeris does not cover
+    ///   the window
+    /// - Frame transformation errors
+    pub fn find_passes(
+        &self,
+        observer: &Observer,
+        start: &Epoch,
+        end: &Epoch,
+        min_elevation: f64,
+        time_step: f64,
+    ) -> PoliastroResult<Vec<SatellitePass>> {
+        let (t_start, t_end) = (self.seconds(start), self.seconds(end));
+        self.check_coverage(t_start, t_end)?;
+        let itrs = self.in_frame(FrameType::ITRS)?;
+
+        let propagate = |minutes: f64| -> [f64; 3] {
+            let t = (t_start + minutes * 60.0).clamp(t_start, t_end);
+            let (position, _) = itrs.interpolate_clamped(t);
+            [
+                position.x / KM_TO_M,
+                position.y / KM_TO_M,
+                position.z / KM_TO_M,
+            ]
+        };
+        Ok(find_all_passes(
+            &propagate,
+            observer,
+            0.0,
+            (t_end - t_start) / 60.0,
+            min_elevation,
+            time_step,
+        ))
+    }
+
+    /// Closest approach to `other` between `start` and `end`
+    ///
+    /// `other` is transformed to the frame of this ephemeris if needed, and
+    /// the search is [`compute_conjunction_between`].
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Ephemeris of the second object
+    /// * `start`, `end` - Search window, within both spans
+    /// * `step` - Sampling step of the search (seconds)
+    /// * `collision_threshold` - Distance threshold for collision risk (meters)
+    ///
+    /// # Returns
+    ///
+    /// `ConjunctionResult` with the TCA in seconds from `start`
+    ///
+    /// # Errors
+    ///
+    /// - `OutOfRange` / `InvalidParameter`: If an ephemeris does not cover
+    ///   the window, or for invalid search parameters
+    /// - Frame transformation errors
+    pub fn conjunction(
+        &self,
+        other: &OemEphemeris,
+        start: &Epoch,
+        end: &Epoch,
+        step: f64,
+        collision_threshold: f64,
+    ) -> PoliastroResult<ConjunctionResult> {
+        let other = other.in_frame(self.frame)?;
+        let (t_start, t_end) = (self.seconds(start), self.seconds(end));
+        let (u_start, u_end) = (other.seconds(start), other.seconds(end));
+        self.check_coverage(t_start, t_end)?;
+        other.check_coverage(u_start, u_end)?;
+
+        compute_conjunction_between(
+            |t| self.interpolate_clamped((t_start + t).min(t_end)),
+            |t| other.interpolate_clamped((u_start + t).min(u_end)),
+            0.0,
+            t_end - t_start,
+            step,
+            collision_threshold,
+        )
+    }
+
+    fn seconds(&self, epoch: &Epoch) -> f64 {
+        epoch.duration_since(&self.reference).to_seconds()
+    }
+
+    fn epoch_at(&self, t: f64) -> Epoch {
+        self.reference.add_duration(Duration::from_seconds(t))
+    }
+
+    /// Earliest start and latest stop of the pieces
+    fn span(&self) -> (f64, f64) {
+        self.pieces.iter().fold(
+            (f64::INFINITY, f64::NEG_INFINITY),
+            |(start, stop), piece| (start.min(piece.start), stop.max(piece.stop)),
+        )
+    }
+
+    fn interpolate(&self, t: f64) -> PoliastroResult<(Vector3, Vector3)> {
+        if let Some(piece) = self.pieces.iter().rev().find(|piece| piece.contains(t)) {
+            return Ok(piece.evaluate(t));
+        }
+        let (start, stop) = self.span();
+        if t < start || t > stop {
+            Err(PoliastroError::out_of_range(
+                "epoch (s from the ephemeris start)",
+                t - start,
+                0.0,
+                stop - start,
+            ))
+        } else {
+            Err(PoliastroError::invalid_parameter(
+                "epoch (s from the ephemeris start)",
+                t - start,
+                "falls between ephemeris segments",
+            ))
+        }
+    }
+
+    /// Interpolated state, at the nearest end of the nearest piece when `t`
+    /// is not covered
+    fn interpolate_clamped(&self, t: f64) -> (Vector3, Vector3) {
+        let distance = |piece: &Piece| (piece.start - t).max(t - piece.stop).max(0.0);
+        let piece = self
+            .pieces
+            .iter()
+            .rev()
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+            .unwrap_or(&self.pieces[0]);
+        piece.evaluate(t.clamp(piece.start, piece.stop))
+    }
+
+    /// Check that the pieces cover [t_start, t_end] without gaps
+    fn check_coverage(&self, t_start: f64, t_end: f64) -> PoliastroResult<()> {
+        self.interpolate(t_start)?;
+        self.interpolate(t_end)?;
+        let mut spans: Vec<(f64, f64)> = self
+            .pieces
+            .iter()
+            .map(|piece| (piece.start, piece.stop))
+            .collect();
+        spans.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut covered = t_start;
+        for (start, stop) in spans {
+            if start > covered {
+                break;
+            }
+            covered = covered.max(stop);
+        }
+        if covered < t_end {
+            // Just past the end of the covered part
+            self.interpolate(covered + 1e-6)?;
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl OemEphemeris {
+    /// Ephemeris of all segments of an OEM in CCSDS KVN or XML
+    ///
+    /// Raises ValueError if the message is invalid, or its segments are in
+    /// different or unsupported frames.
+    #[staticmethod]
+    #[pyo3(name = "from_oem")]
+    fn py_from_oem(oem: &str) -> PyResult<Self> {
+        Ok(parse_oem(oem)?.ephemeris()?)
+    }
+
+    /// Frame of the states ("GCRS", "ITRS", ...)
+    #[getter]
+    fn get_frame(&self) -> String {
+        self.frame.to_string()
+    }
+
+    /// Start of the span
+    #[getter]
+    fn get_start(&self) -> Epoch {
+        self.start()
+    }
+
+    /// End of the span
+    #[getter]
+    fn get_stop(&self) -> Epoch {
+        self.stop()
+    }
+
+    /// Interpolated state at `epoch`, in m and m/s, in the frame of the
+    /// message or in `frame` ("GCRS", "ITRS", "TEME", ...)
+    ///
+    /// Raises ValueError if `epoch` is outside the span or between segments.
+    #[pyo3(name = "state", signature = (epoch, frame=None))]
+    fn py_state(&self, epoch: &Epoch, frame: Option<&str>) -> PyResult<CartesianState> {
+        Ok(match frame {
+            Some(frame) => self.state_in(epoch, frame.parse()?)?,
+            None => self.state(epoch)?,
+        })
+    }
+
+    /// Passes over a ground observer between `start` and `end`
+    ///
+    /// Returns a list of dictionaries as `find_satellite_passes`, with times
+    /// in minutes from `start`.
+    #[pyo3(
+        name = "find_passes",
+        signature = (latitude_deg, longitude_deg, altitude_km, start, end, min_elevation_deg=10.0, time_step_minutes=1.0)
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn py_find_passes(
+        &self,
+        py: Python<'_>,
+        latitude_deg: f64,
+        longitude_deg: f64,
+        altitude_km: f64,
+        start: &Epoch,
+        end: &Epoch,
+        min_elevation_deg: f64,
+        time_step_minutes: f64,
+    ) -> PyResult<PyObject> {
+        let observer = Observer::new(
+            latitude_deg.to_radians(),
+            longitude_deg.to_radians(),
+            altitude_km,
+        );
+        let passes = self.find_passes(
+            &observer,
+            start,
+            end,
+            min_elevation_deg.to_radians(),
+            time_step_minutes,
+        )?;
+
+        let list = PyList::empty_bound(py);
+        for pass in passes {
+            let dict = PyDict::new_bound(py);
+            dict.set_item("rise_time_minutes", pass.rise_time)?;
+            dict.set_item("set_time_minutes", pass.set_time)?;
+            dict.set_item("max_elevation_time_minutes", pass.max_elevation_time)?;
+            dict.set_item("max_elevation_deg", pass.max_elevation.to_degrees())?;
+            dict.set_item("rise_azimuth_deg", pass.rise_azimuth.to_degrees())?;
+            dict.set_item("set_azimuth_deg", pass.set_azimuth.to_degrees())?;
+            dict.set_item("duration_minutes", pass.duration)?;
+            list.append(dict)?;
+        }
+        Ok(list.into())
+    }
+
+    /// Closest approach to `other` between `start` and `end`
+    ///
+    /// Returns a dictionary with `tca` (Epoch), `miss_distance` (m),
+    /// `relative_position` (m), `relative_velocity` (m/s) and
+    /// `collision_risk`.
+    #[pyo3(
+        name = "conjunction",
+        signature = (other, start, end, step=60.0, collision_threshold=5000.0)
+    )]
+    fn py_conjunction(
+        &self,
+        py: Python<'_>,
+        other: &OemEphemeris,
+        start: &Epoch,
+        end: &Epoch,
+        step: f64,
+        collision_threshold: f64,
+    ) -> PyResult<PyObject> {
+        let result = self.conjunction(other, start, end, step, collision_threshold)?;
+        let dict = PyDict::new_bound(py);
+        dict.set_item(
+            "tca",
+            start
+                .add_duration(Duration::from_seconds(result.tca))
+                .into_py(py),
+        )?;
+        dict.set_item("miss_distance", result.miss_distance)?;
+        dict.set_item(
+            "relative_position",
+            result.relative_position.as_slice().to_vec(),
+        )?;
+        dict.set_item(
+            "relative_velocity",
+            result.relative_velocity.as_slice().to_vec(),
+        )?;
+        dict.set_item("collision_risk", result.collision_risk)?;
+        Ok(dict.into())
+    }
+
+    fn __len__(&self) -> usize {
+        self.pieces.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "OemEphemeris(frame={}, start={}, stop={}, segments={})",
+            self.frame,
+            self.start().to_iso_string(),
+            self.stop().to_iso_string(),
+            self.pieces.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::GM_EARTH;
+
+    /// Two segments either side of a maneuver, in the layout of the CCSDS
+    /// 502.0-B-3 examples
+    const OEM_KVN: &str = "CCSDS_OEM_VERS = 2.0
+COMMENT Test ephemeris
+CREATION_DATE = 2024-03-01T10:00:00
+ORIGINATOR = ASTRORA
+
+META_START
+COMMENT Before the maneuver
+OBJECT_NAME = SAT-A
+OBJECT_ID = 2024-001A
+CENTER_NAME = EARTH
+REF_FRAME = EME2000
+TIME_SYSTEM = UTC
+START_TIME = 2024-03-01T12:00:00
+USEABLE_START_TIME = 2024-03-01T12:00:00
+USEABLE_STOP_TIME = 2024-03-01T12:02:00
+STOP_TIME = 2024-03-01T12:02:00
+INTERPOLATION = HERMITE
+INTERPOLATION_DEGREE = 3
+META_STOP
+
+COMMENT Produced by a unit test
+2024-03-01T12:00:00.000 7000.0 0.0 0.0 0.0 7.546 0.0
+2024-03-01T12:01:00.000 6984.1 452.6 0.0 -0.488 7.530 0.0
+2024-03-01T12:02:00.000 6936.6 903.2 0.0 -0.974 7.480 0.0
+
+COVARIANCE_START
+EPOCH = 2024-03-01T12:00:00
+COV_REF_FRAME = RTN
+1.0e-3
+1.0e-5 2.0e-3
+1.0e-5 1.0e-5 3.0e-3
+1.0e-7 1.0e-7 1.0e-7 4.0e-6
+1.0e-7 1.0e-7 1.0e-7 1.0e-8 5.0e-6
+1.0e-7 1.0e-7 1.0e-7 1.0e-8 1.0e-8 6.0e-6
+EPOCH = 2024-03-01T12:02:00
+1
+0 1
+0 0 1
+0 0 0 1
+0 0 0 0 1
+0 0 0 0 0 1
+COVARIANCE_STOP
+
+META_START
+OBJECT_NAME = SAT-A
+OBJECT_ID = 2024-001A
+CENTER_NAME = EARTH
+REF_FRAME = EME2000
+TIME_SYSTEM = TAI
+START_TIME = 2024-03-01T12:02:37
+STOP_TIME = 2024-03-01T12:03:37
+META_STOP
+2024-03-01T12:02:37 6936.6 903.2 0.0 -0.974 7.490 0.0 -0.008 -0.001 0.0
+2024-03-01T12:03:37 6860.0 1350.0 0.0 -1.458 7.390 0.0 -0.008 -0.0015 0.0
+";
+
+    /// Circular orbit with a 51.6° inclination, exactly
+    fn circular_state(t: f64) -> CartesianState {
+        let radius: f64 = 7000e3;
+        let rate = (GM_EARTH / radius.powi(3)).sqrt();
+        let (sin_i, cos_i) = 51.6_f64.to_radians().sin_cos();
+        let (sin, cos) = (rate * t).sin_cos();
+        CartesianState::new(
+            Vector3::new(cos, sin * cos_i, sin * sin_i) * radius,
+            Vector3::new(-sin, cos * cos_i, cos * sin_i) * radius * rate,
+        )
+    }
+
+    fn circular_segment(
+        start: &Epoch,
+        step: f64,
+        count: usize,
+        interpolation: Interpolation,
+        degree: usize,
+    ) -> OemSegment {
+        let states = (0..count)
+            .map(|k| {
+                let t = k as f64 * step;
+                OemStateVector::new(
+                    start.add_duration(Duration::from_seconds(t)),
+                    circular_state(t),
+                )
+            })
+            .collect();
+        let mut segment = OemSegment::new("SAT", "2024-001A", FrameType::GCRS, states).unwrap();
+        segment.metadata.interpolation = Some(interpolation);
+        segment.metadata.interpolation_degree = Some(degree);
+        segment
+    }
+
+    fn assert_close(a: &Vector3, b: &Vector3) {
+        assert!((a - b).norm() <= 1e-12 * b.norm().max(1.0), "{a} != {b}");
+    }
+
+    fn assert_same_oem(a: &Oem, b: &Oem) {
+        assert_eq!(a.originator, b.originator);
+        assert_eq!(a.creation_date, b.creation_date);
+        assert_eq!(a.comments, b.comments);
+        assert_eq!(a.segments.len(), b.segments.len());
+        for (x, y) in a.segments.iter().zip(&b.segments) {
+            assert_eq!(x.metadata, y.metadata);
+            assert_eq!(x.comments, y.comments);
+            assert_eq!(x.states.len(), y.states.len());
+            for (s, t) in x.states.iter().zip(&y.states) {
+                assert_eq!(s.epoch, t.epoch);
+                assert_close(&s.state.position, &t.state.position);
+                assert_close(&s.state.velocity, &t.state.velocity);
+                assert_eq!(s.acceleration.is_some(), t.acceleration.is_some());
+            }
+            assert_eq!(x.covariances.len(), y.covariances.len());
+            for (c, d) in x.covariances.iter().zip(&y.covariances) {
+                assert_eq!(c.epoch, d.epoch);
+                assert_eq!(c.frame, d.frame);
+                let (m, n) = (c.covariance.matrix(), d.covariance.matrix());
+                assert!((m - n).abs().max() <= 1e-12 * m.abs().max());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_oem_kvn() {
+        let oem = parse_oem(OEM_KVN).unwrap();
+        assert_eq!(oem.originator, "ASTRORA");
+        assert_eq!(oem.creation_date, "2024-03-01T10:00:00");
+        assert_eq!(oem.comments, vec!["Test ephemeris".to_string()]);
+        assert_eq!(oem.segments.len(), 2);
+
+        let first = &oem.segments[0];
+        assert_eq!(first.metadata.object_name, "SAT-A");
+        assert_eq!(first.metadata.frame().unwrap(), FrameType::J2000);
+        assert_eq!(first.metadata.interpolation, Some(Interpolation::Hermite));
+        assert_eq!(first.metadata.interpolation_degree, Some(3));
+        assert_eq!(
+            first.metadata.comments,
+            vec!["Before the maneuver".to_string()]
+        );
+        assert_eq!(first.comments, vec!["Produced by a unit test".to_string()]);
+        assert_eq!(first.states.len(), 3);
+        assert_eq!(
+            first.states[1].epoch,
+            Epoch::from_gregorian_utc(2024, 3, 1, 12, 1, 0, 0)
+        );
+        assert_eq!(
+            first.states[1].state.position,
+            Vector3::new(6984.1e3, 452.6e3, 0.0)
+        );
+        assert_eq!(
+            first.states[1].state.velocity,
+            Vector3::new(-488.0, 7530.0, 0.0)
+        );
+        assert!(first.states[1].acceleration.is_none());
+
+        assert_eq!(first.covariances.len(), 2);
+        let covariance = &first.covariances[0];
+        assert_eq!(covariance.frame.as_deref(), Some("RTN"));
+        assert!((covariance.covariance.matrix()[(2, 2)] - 3e3).abs() < 1e-9);
+        assert!((covariance.covariance.matrix()[(0, 1)] - 10.0).abs() < 1e-12);
+        assert_eq!(first.covariances[1].frame, None);
+        assert_eq!(first.covariances[1].covariance.matrix()[(5, 5)], 1e6);
+
+        // TAI epochs, and accelerations
+        let second = &oem.segments[1];
+        assert_eq!(second.metadata.time_system, "TAI");
+        assert_eq!(
+            second.states[0].epoch,
+            Epoch::from_gregorian_utc(2024, 3, 1, 12, 2, 0, 0)
+        );
+        assert_eq!(
+            second.states[0].acceleration,
+            Some(Vector3::new(-8.0, -1.0, 0.0))
+        );
+        assert_eq!(second.metadata.interpolation, None);
+    }
+
+    #[test]
+    fn test_oem_kvn_xml_round_trip() {
+        let oem = parse_oem(OEM_KVN).unwrap();
+
+        let kvn = oem.to_kvn().unwrap();
+        assert!(kvn.contains("\nMETA_START\nCOMMENT Before the maneuver\nOBJECT_NAME = SAT-A\n"));
+        assert!(kvn.contains("\n2024-03-01T12:01:00 6984.1 452.6 0 -0.488 7.53 0\n"));
+        assert!(
+            kvn.contains("\nCOVARIANCE_START\nEPOCH = 2024-03-01T12:00:00\nCOV_REF_FRAME = RTN\n")
+        );
+        assert_same_oem(&parse_oem(&kvn).unwrap(), &oem);
+
+        let xml = write_oem(&oem, Encoding::Xml).unwrap();
+        assert!(xml.contains("<X units=\"km\">6984.1</X>"));
+        assert!(xml.contains("<X_DDOT units=\"km/s**2\">-0.008</X_DDOT>"));
+        assert!(xml.contains("<CX_DOT_X units=\"km**2/s\">"));
+        let from_xml = parse_oem(&xml).unwrap();
+        assert_same_oem(&from_xml, &oem);
+        assert_same_oem(&parse_oem(&from_xml.to_kvn().unwrap()).unwrap(), &oem);
+
+        assert!(write_oem(&oem, Encoding::Json).is_err());
+    }
+
+    #[test]
+    fn test_parse_oem_errors() {
+        let replace = |from: &str, to: &str| {
+            assert!(OEM_KVN.contains(from), "{from}");
+            OEM_KVN.replacen(from, to, 1)
+        };
+        let cases = [
+            // Missing block ends and mandatory keywords
+            OEM_KVN.replace("META_STOP\n2024-03-01T12:02:37", "2024-03-01T12:02:37"),
+            replace("COVARIANCE_STOP\n", ""),
+            replace("ORIGINATOR = ASTRORA\n", ""),
+            replace("OBJECT_ID = 2024-001A\n", ""),
+            replace("CCSDS_OEM_VERS = 2.0\n", ""),
+            // States out of order or outside the span
+            replace("2024-03-01T12:01:00.000", "2024-03-01T12:03:00.000"),
+            replace(
+                "STOP_TIME = 2024-03-01T12:03:37",
+                "STOP_TIME = 2024-03-01T12:03:00",
+            ),
+            replace(
+                "USEABLE_STOP_TIME = 2024-03-01T12:02:00",
+                "USEABLE_STOP_TIME = 2024-03-01T12:05:00",
+            ),
+            // Malformed data
+            replace(" 7.530 0.0\n", " 7.530\n"),
+            replace("-0.488", "fast"),
+            replace("0 0 0 0 0 1\nCOVARIANCE_STOP", "0 0 0 0 1\nCOVARIANCE_STOP"),
+            replace("TIME_SYSTEM = TAI", "TIME_SYSTEM = UT1"),
+            replace("INTERPOLATION = HERMITE", "INTERPOLATION = SPLINE"),
+            replace("COMMENT Produced by a unit test\n", "OBJECT_NAME = SAT-B\n"),
+        ];
+        for text in cases {
+            assert!(
+                matches!(parse_oem(&text), Err(PoliastroError::InvalidMessage { .. })),
+                "{text}"
+            );
+        }
+
+        // Line numbers in KVN errors
+        let error = parse_oem(&replace("-0.488", "fast")).unwrap_err();
+        assert!(error.to_string().contains("line 23:"), "{error}");
+
+        assert!(parse_oem("{\"OBJECT_NAME\": \"SAT\"}").is_err());
+        assert!(parse_oem("<omm/>").is_err());
+    }
+
+    #[test]
+    fn test_interpolation_accuracy() {
+        let start = Epoch::from_gregorian_utc(2024, 3, 1, 12, 0, 0, 0);
+        for (interpolation, degree, tolerance) in [
+            (Interpolation::Lagrange, 7, 1e-3),
+            (Interpolation::Hermite, 5, 1e-3),
+            (Interpolation::Linear, 1, 5e3),
+        ] {
+            let segment = circular_segment(&start, 60.0, 121, interpolation, degree);
+            let ephemeris = OemEphemeris::from_segments(&[segment]).unwrap();
+            assert_eq!(ephemeris.start(), start);
+
+            // Exact at the states, close in between, including near the ends
+            for t in [0.0, 30.0, 90.0, 3615.0, 7170.0, 7200.0] {
+                let epoch = start.add_duration(Duration::from_seconds(t));
+                let state = ephemeris.state(&epoch).unwrap();
+                let exact = circular_state(t);
+                let error = (state.position - exact.position).norm();
+                assert!(error < tolerance, "{interpolation:?} at {t} s: {error} m");
+                if t % 60.0 == 0.0 {
+                    assert!(error < 1e-6, "{interpolation:?} at {t} s: {error} m");
+                }
+                if interpolation != Interpolation::Linear {
+                    let velocity_error = (state.velocity - exact.velocity).norm();
+                    assert!(
+                        velocity_error < 1e-5,
+                        "{interpolation:?} at {t} s: {velocity_error} m/s"
+                    );
+                }
+            }
+        }
+
+        let segment = circular_segment(&start, 60.0, 11, Interpolation::Hermite, 5);
+        let ephemeris = OemEphemeris::from_segments(&[segment]).unwrap();
+        let after = start.add_duration(Duration::from_seconds(601.0));
+        assert!(matches!(
+            ephemeris.state(&after),
+            Err(PoliastroError::OutOfRange { .. })
+        ));
+
+        // Transformed on request
+        let epoch = start.add_duration(Duration::from_seconds(200.0));
+        let itrs = ephemeris.state_in(&epoch, FrameType::ITRS).unwrap();
+        let gcrs = ephemeris.state(&epoch).unwrap();
+        assert!((itrs.position.norm() - gcrs.position.norm()).abs() < 1e-3);
+        assert!((itrs.position - gcrs.position).norm() > 1e3);
+        let in_itrs = ephemeris.in_frame(FrameType::ITRS).unwrap();
+        assert_eq!(in_itrs.frame(), FrameType::ITRS);
+        assert!((in_itrs.state(&epoch).unwrap().position - itrs.position).norm() < 1e-2);
+    }
+
+    #[test]
+    fn test_ephemeris_segments() {
+        let start = Epoch::from_gregorian_utc(2024, 3, 1, 12, 0, 0, 0);
+        let at = |t: f64| start.add_duration(Duration::from_seconds(t));
+        let first = circular_segment(&start, 60.0, 11, Interpolation::Lagrange, 5);
+        let mut second = circular_segment(&at(600.0), 60.0, 11, Interpolation::Lagrange, 5);
+        // A 10 m/s shift in velocity shows which segment is used
+        for state in &mut second.states {
+            state.state.velocity.x += 10.0;
+        }
+        let mut third = circular_segment(&at(1800.0), 60.0, 11, Interpolation::Lagrange, 5);
+        third.metadata.useable_start_time = Some(at(1860.0));
+
+        let ephemeris = OemEphemeris::from_segments(&[first, second, third.clone()]).unwrap();
+        assert_eq!(ephemeris.start(), start);
+        assert_eq!(ephemeris.stop(), at(2400.0));
+
+        let vx = |t: f64| ephemeris.state(&at(t)).unwrap().velocity.x;
+        assert!((vx(300.0) - circular_state(300.0).velocity.x).abs() < 1e-6);
+        // The later segment wins at the boundary
+        let shifted = circular_state(0.0).velocity.x + 10.0;
+        assert!((vx(600.0) - shifted).abs() < 1e-6);
+
+        // Gaps and useable spans
+        assert!(matches!(
+            ephemeris.state(&at(1500.0)),
+            Err(PoliastroError::InvalidParameter { .. })
+        ));
+        assert!(ephemeris.state(&at(1830.0)).is_err());
+        assert!(ephemeris.state(&at(1860.0)).is_ok());
+        assert!(ephemeris.check_coverage(0.0, 1200.0).is_ok());
+        assert!(ephemeris.check_coverage(0.0, 1900.0).is_err());
+
+        // Segments must share a frame
+        third.metadata.ref_frame = "TEME".to_string();
+        let mixed = [
+            circular_segment(&start, 60.0, 11, Interpolation::Lagrange, 5),
+            third,
+        ];
+        assert!(OemEphemeris::from_segments(&mixed).is_err());
+        assert!(OemEphemeris::from_segments(&[]).is_err());
+    }
+
+    #[test]
+    fn test_interpolation_near_segment_boundaries() {
+        let start = Epoch::from_gregorian_utc(2024, 3, 1, 12, 0, 0, 0);
+        let at = |t: f64| start.add_duration(Duration::from_seconds(t));
+        // Adjacent segments sharing the state at 600 s
+        let first = circular_segment(&start, 60.0, 11, Interpolation::Lagrange, 7);
+        let second = circular_segment(&at(600.0), 60.0, 11, Interpolation::Hermite, 5);
+        let ephemeris = OemEphemeris::from_segments(&[first, second]).unwrap();
+
+        // The window shifts inwards at the ends of each segment, so the
+        // accuracy holds up to the boundary on either side
+        for t in [
+            0.5, 5.0, 595.0, 599.999, 600.0, 600.001, 605.0, 1195.0, 1199.5,
+        ] {
+            let state = ephemeris.state(&at(t)).unwrap();
+            let exact = circular_state(t - if t >= 600.0 { 600.0 } else { 0.0 });
+            let error = (state.position - exact.position).norm();
+            assert!(error < 1e-3, "at {t} s: {error} m");
+            assert!((state.velocity - exact.velocity).norm() < 1e-5, "at {t} s");
+        }
+
+        // Exact at the ends of the segments
+        assert_close(
+            &ephemeris.state(&at(0.0)).unwrap().position,
+            &circular_state(0.0).position,
+        );
+        assert_close(
+            &ephemeris.state(&at(1200.0)).unwrap().position,
+            &circular_state(600.0).position,
+        );
+    }
+
+    #[test]
+    fn test_interpolation_out_of_span() {
+        let start = Epoch::from_gregorian_utc(2024, 3, 1, 12, 0, 0, 0);
+        let at = |t: f64| start.add_duration(Duration::from_seconds(t));
+        let mut segment = circular_segment(&start, 60.0, 11, Interpolation::Lagrange, 5);
+        segment.metadata.useable_start_time = Some(at(120.0));
+        segment.metadata.useable_stop_time = Some(at(480.0));
+        let ephemeris = OemEphemeris::from_segments(&[segment]).unwrap();
+        assert_eq!(ephemeris.start(), at(120.0));
+        assert_eq!(ephemeris.stop(), at(480.0));
+
+        // States outside the useable span still serve as nodes inside it
+        let state = ephemeris.state(&at(125.0)).unwrap();
+        assert!((state.position - circular_state(125.0).position).norm() < 1e-3);
+
+        for t in [-60.0, 0.0, 119.999, 480.001, 600.0, 1e6] {
+            match ephemeris.state(&at(t)) {
+                Err(PoliastroError::OutOfRange {
+                    value, min, max, ..
+                }) => {
+                    assert_eq!((min, max), (0.0, 360.0));
+                    assert!((value - (t - 120.0)).abs() < 1e-6, "{value} at {t} s");
+                }
+                other => panic!("at {t} s: {other:?}"),
+            }
+        }
+        assert!(ephemeris.state(&at(120.0)).is_ok());
+        assert!(ephemeris.state(&at(480.0)).is_ok());
+        assert!(ephemeris.state_in(&at(500.0), FrameType::ITRS).is_err());
+    }
+
+    #[test]
+    fn test_interpolation_degree_edge_cases() {
+        let start = Epoch::from_gregorian_utc(2024, 3, 1, 12, 0, 0, 0);
+        let at = |t: f64| start.add_duration(Duration::from_seconds(t));
+        // Cubic trajectory, which interpolants of degree 3 and up reproduce
+        let cubic = |t: f64| {
+            let s = t / 100.0;
+            CartesianState::new(
+                Vector3::new(
+                    7e6 + 1e3 * s - 20.0 * s * s + 3.0 * s * s * s,
+                    5e5 * s,
+                    -2e3 * s * s,
+                ),
+                Vector3::new(
+                    (1e3 - 40.0 * s + 9.0 * s * s) / 100.0,
+                    5e5 / 100.0,
+                    -4e3 * s / 100.0,
+                ),
+            )
+        };
+        let segment = |count: usize, interpolation: Interpolation, degree: usize| {
+            let states = (0..count)
+                .map(|k| OemStateVector::new(at(60.0 * k as f64), cubic(60.0 * k as f64)))
+                .collect();
+            let mut segment = OemSegment::new("SAT", "2024-001A", FrameType::GCRS, states).unwrap();
+            segment.metadata.interpolation = Some(interpolation);
+            segment.metadata.interpolation_degree = Some(degree);
+            OemEphemeris::from_segments(&[segment]).unwrap()
+        };
+        let error = |ephemeris: &OemEphemeris, t: f64| {
+            (ephemeris.state(&at(t)).unwrap().position - cubic(t).position).norm()
+        };
+
+        // Degrees above what the states allow fall back to all the states
+        for (interpolation, degree) in [
+            (Interpolation::Lagrange, 3),
+            (Interpolation::Lagrange, 12),
+            (Interpolation::Hermite, 3),
+            (Interpolation::Hermite, 15),
+        ] {
+            let ephemeris = segment(6, interpolation, degree);
+            for t in [10.0, 150.0, 290.0] {
+                assert!(
+                    error(&ephemeris, t) < 1e-6,
+                    "{interpolation:?} {degree} at {t} s"
+                );
+            }
+        }
+
+        // Degrees too low for the trajectory: still exact at the states, and
+        // at least two nodes even for degree 0
+        for (interpolation, degree) in [
+            (Interpolation::Lagrange, 0),
+            (Interpolation::Lagrange, 1),
+            (Interpolation::Linear, 5),
+        ] {
+            let ephemeris = segment(6, interpolation, degree);
+            assert!(
+                error(&ephemeris, 120.0) < 1e-6,
+                "{interpolation:?} {degree}"
+            );
+            assert!(error(&ephemeris, 150.0) > 1.0, "{interpolation:?} {degree}");
+            assert!(error(&ephemeris, 150.0) < 1e3, "{interpolation:?} {degree}");
+        }
+        // Hermite through two states is already cubic
+        assert!(error(&segment(6, Interpolation::Hermite, 0), 150.0) < 1e-6);
+        // Linear interpolation is the midpoint between two states
+        let linear = segment(6, Interpolation::Linear, 1);
+        let midpoint = (cubic(120.0).position + cubic(180.0).position) / 2.0;
+        assert_close(&linear.state(&at(150.0)).unwrap().position, &midpoint);
+
+        // Two states and a single state
+        let two = segment(2, Interpolation::Hermite, 7);
+        assert!(error(&two, 30.0) < 1e-6);
+        let one = segment(1, Interpolation::Lagrange, 7);
+        assert_close(&one.state(&at(0.0)).unwrap().position, &cubic(0.0).position);
+        assert!(matches!(
+            one.state(&at(1.0)),
+            Err(PoliastroError::OutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_ephemeris_conjunction() {
+        let start = Epoch::from_gregorian_utc(2024, 3, 1, 12, 0, 0, 0);
+        let radius: f64 = 7000e3;
+        let rate = (GM_EARTH / radius.powi(3)).sqrt();
+        let period = std::f64::consts::TAU / rate;
+
+        // Equatorial and polar circular orbits 500 m apart in radius, over
+        // the same point at half a period
+        let states = |radius: f64, polar: bool| {
+            (0..=120)
+                .map(|k| {
+                    let t = k as f64 * period / 120.0;
+                    let (sin, cos) = (rate * t).sin_cos();
+                    let (position, velocity) = if polar {
+                        (Vector3::new(cos, 0.0, sin), Vector3::new(-sin, 0.0, cos))
+                    } else {
+                        (Vector3::new(cos, sin, 0.0), Vector3::new(-sin, cos, 0.0))
+                    };
+                    OemStateVector::new(
+                        start.add_duration(Duration::from_seconds(t)),
+                        CartesianState::new(position * radius, velocity * radius * rate),
+                    )
+                })
+                .collect()
+        };
+        let ephemeris = |radius: f64, polar: bool, frame: FrameType| {
+            let segment =
+                OemSegment::new("SAT", "2024-001A", frame, states(radius, polar)).unwrap();
+            OemEphemeris::from_segments(&[segment]).unwrap()
+        };
+        let equatorial = ephemeris(radius, false, FrameType::GCRS);
+        let polar = ephemeris(radius + 500.0, true, FrameType::J2000);
+
+        let window_start = start.add_duration(Duration::from_seconds(600.0));
+        let window_end = start.add_duration(Duration::from_seconds(period - 600.0));
+        let result = equatorial
+            .conjunction(&polar, &window_start, &window_end, 60.0, 1000.0)
+            .unwrap();
+        assert!(
+            (result.tca - (period / 2.0 - 600.0)).abs() < 0.01,
+            "{}",
+            result.tca
+        );
+        assert!(
+            (result.miss_distance - 500.0).abs() < 0.1,
+            "{}",
+            result.miss_distance
+        );
+        assert!(result.collision_risk);
+
+        let too_late = start.add_duration(Duration::from_seconds(period + 60.0));
+        assert!(equatorial
+            .conjunction(&polar, &window_start, &too_late, 60.0, 1000.0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_ephemeris_passes() {
+        // Equatorial orbit seen from the equator: passes reach the zenith
+        let start = Epoch::from_gregorian_utc(2024, 3, 1, 12, 0, 0, 0);
+        let radius: f64 = 7000e3;
+        let rate = (GM_EARTH / radius.powi(3)).sqrt();
+        let states = (0..=180)
+            .map(|k| {
+                let t = k as f64 * 60.0;
+                let (sin, cos) = (rate * t).sin_cos();
+                OemStateVector::new(
+                    start.add_duration(Duration::from_seconds(t)),
+                    CartesianState::new(
+                        Vector3::new(cos, sin, 0.0) * radius,
+                        Vector3::new(-sin, cos, 0.0) * radius * rate,
+                    ),
+                )
+            })
+            .collect();
+        let segment = OemSegment::new("SAT", "2024-001A", FrameType::GCRS, states).unwrap();
+        let ephemeris = OemEphemeris::from_segments(&[segment]).unwrap();
+
+        let observer = Observer::new(0.0, 0.0, 0.0);
+        let end = start.add_duration(Duration::from_hours(3.0));
+        let passes = ephemeris
+            .find_passes(&observer, &start, &end, 10f64.to_radians(), 1.0)
+            .unwrap();
+        assert!(!passes.is_empty());
+        for pass in &passes {
+            assert!(
+                pass.max_elevation > 80f64.to_radians(),
+                "{}",
+                pass.max_elevation
+            );
+            assert!(pass.rise_time >= 0.0 && pass.set_time <= 180.0);
+        }
+
+        let beyond = start.add_duration(Duration::from_hours(4.0));
+        assert!(ephemeris
+            .find_passes(&observer, &start, &beyond, 10f64.to_radians(), 1.0)
+            .is_err());
+    }
+}
@@ -0,0 +1,1588 @@
+//! Orbit Parameter Message (OPM)
+//!
+//! Reading and writing of CCSDS OPMs in KVN and XML.
+//!
+//! An OPM gives the state of one object at one epoch: a header, metadata
+//! (object, center, frame and time system), the Cartesian state vector and
+//! optionally the osculating Keplerian elements, spacecraft parameters, a
+//! position and velocity covariance, planned maneuvers and user-defined
+//! parameters. States are read into [`CartesianState`]s in m and m/s,
+//! elements into [`OrbitalElements`] in m and rad and maneuver Δv in m/s; the
+//! message itself is in km, km/s and degrees.
+//!
+//! Maneuvers are burns given by their ignition epoch, duration, mass change
+//! and Δv in MAN_REF_FRAME, usually RTN or the frame of the state.
+//! [`OpmManeuver`] builds them from the entries of a
+//! [`DeltaVBudget`](crate::maneuvers::budget::DeltaVBudget) and from the
+//! Hohmann, bi-elliptic and Lambert transfer calculators, so that planned
+//! burns can be handed to operations, and [`Opm::apply_propellant`] fills in
+//! their mass changes with the rocket equation.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use astrora_core::ccsds::{write_opm, Encoding, Opm, OpmManeuver};
+//! use astrora_core::maneuvers::HohmannTransfer;
+//!
+//! let transfer = HohmannTransfer::calculate(r_leo, r_geo, GM_EARTH)?;
+//! let mut opm = Opm::new("OPS", "SAT-1", "2024-001A", FrameType::GCRS, epoch, state);
+//! opm.maneuvers = OpmManeuver::hohmann(&transfer, ignition);
+//! let text = write_opm(&opm, Encoding::Kvn)?;
+//! ```
+//!
+//! # References
+//!
+//! - CCSDS 502.0-B-2: Orbit Data Messages, section 3
+
+use hifitime::TimeScale;
+use pyo3::prelude::*;
+
+use super::kvn::{self, KvnLine, KvnWriter};
+use super::xml::{XmlElement, XmlWriter};
+use super::{
+    format_epoch, format_number, parse_epoch, Encoding, Fields, CARTESIAN_COVARIANCE, KM2_TO_M2,
+};
+use crate::coordinates::FrameType;
+use crate::core::anomaly::{mean_to_true_anomaly, mean_to_true_anomaly_hyperbolic};
+use crate::core::covariance::{ric_rotation, Covariance6};
+use crate::core::elements::{rv_to_coe, OrbitalElements};
+use crate::core::linalg::Vector3;
+use crate::core::state::CartesianState;
+use crate::core::time::{Duration, Epoch};
+use crate::core::{PoliastroError, PoliastroResult};
+use crate::maneuvers::bielliptic::BiellipticTransferResult;
+use crate::maneuvers::budget::DeltaVManeuver;
+use crate::maneuvers::hohmann::HohmannTransferResult;
+use crate::maneuvers::lambert::LambertSolution;
+
+/// Version of CCSDS 502.0 written in KVN and XML messages
+pub const CCSDS_OPM_VERSION: &str = "2.0";
+
+const MESSAGE_TYPE: &str = "OPM";
+
+/// Km to m (km/s to m/s)
+const KM_TO_M: f64 = 1e3;
+
+/// Km³/s² to m³/s²
+const KM3_TO_M3: f64 = 1e9;
+
+/// Standard gravity for the rocket equation (m/s²)
+const STANDARD_GRAVITY: f64 = 9.80665;
+
+const HEADER_KEYS: [&str; 2] = ["CREATION_DATE", "ORIGINATOR"];
+
+const METADATA_KEYS: [&str; 6] = [
+    "OBJECT_NAME",
+    "OBJECT_ID",
+    "CENTER_NAME",
+    "REF_FRAME",
+    "REF_FRAME_EPOCH",
+    "TIME_SYSTEM",
+];
+
+const STATE_KEYS: [&str; 6] = ["X", "Y", "Z", "X_DOT", "Y_DOT", "Z_DOT"];
+
+const STATE_UNITS: [&str; 2] = ["km", "km/s"];
+
+/// Keplerian element keywords, with TRUE_ANOMALY and MEAN_ANOMALY
+/// alternatives
+const KEPLERIAN_KEYS: [&str; 8] = [
+    "SEMI_MAJOR_AXIS",
+    "ECCENTRICITY",
+    "INCLINATION",
+    "RA_OF_ASC_NODE",
+    "ARG_OF_PERICENTER",
+    "TRUE_ANOMALY",
+    "MEAN_ANOMALY",
+    "GM",
+];
+
+const SPACECRAFT_KEYS: [&str; 5] = [
+    "MASS",
+    "SOLAR_RAD_AREA",
+    "SOLAR_RAD_COEFF",
+    "DRAG_AREA",
+    "DRAG_COEFF",
+];
+
+const SPACECRAFT_UNITS: [Option<&str>; 5] = [Some("kg"), Some("m**2"), None, Some("m**2"), None];
+
+const MANEUVER_KEYS: [&str; 7] = [
+    "MAN_EPOCH_IGNITION",
+    "MAN_DURATION",
+    "MAN_DELTA_MASS",
+    "MAN_REF_FRAME",
+    "MAN_DV_1",
+    "MAN_DV_2",
+    "MAN_DV_3",
+];
+
+const USER_DEFINED_PREFIX: &str = "USER_DEFINED_";
+
+/// Metadata of an OPM
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpmMetadata {
+    /// Spacecraft name (OBJECT_NAME)
+    pub object_name: String,
+    /// International designator (OBJECT_ID)
+    pub object_id: String,
+    /// Origin of the frame (CENTER_NAME)
+    pub center_name: String,
+    /// Reference frame of the state (REF_FRAME)
+    pub ref_frame: String,
+    /// Epoch of the frame, when not implied by its name (REF_FRAME_EPOCH)
+    pub ref_frame_epoch: Option<String>,
+    /// Time system of all epochs of the message (TIME_SYSTEM)
+    pub time_system: String,
+    /// COMMENT lines of the metadata block
+    pub comments: Vec<String>,
+}
+
+impl OpmMetadata {
+    /// Frame of the state, from REF_FRAME and CENTER_NAME
+    ///
+    /// # Errors
+    ///
+    /// `UnknownFrame` if the pair is not one of the supported frames
+    pub fn frame(&self) -> PoliastroResult<FrameType> {
+        super::frame_type(&self.ref_frame, &self.center_name)
+    }
+
+    fn time_scale(&self) -> PoliastroResult<TimeScale> {
+        super::time_scale(&self.time_system, MESSAGE_TYPE)
+    }
+}
+
+/// Spacecraft parameters of an OPM, each optional
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SpacecraftParameters {
+    /// Mass in kg (MASS)
+    pub mass: Option<f64>,
+    /// Solar radiation pressure area in m² (SOLAR_RAD_AREA)
+    pub solar_rad_area: Option<f64>,
+    /// Solar radiation pressure coefficient (SOLAR_RAD_COEFF)
+    pub solar_rad_coeff: Option<f64>,
+    /// Drag area in m² (DRAG_AREA)
+    pub drag_area: Option<f64>,
+    /// Drag coefficient (DRAG_COEFF)
+    pub drag_coeff: Option<f64>,
+}
+
+impl SpacecraftParameters {
+    /// Values in the order of [`SPACECRAFT_KEYS`]
+    fn values(&self) -> [Option<f64>; 5] {
+        [
+            self.mass,
+            self.solar_rad_area,
+            self.solar_rad_coeff,
+            self.drag_area,
+            self.drag_coeff,
+        ]
+    }
+}
+
+/// A maneuver of an OPM
+///
+/// An impulsive maneuver has a zero duration. The Δv is in MAN_REF_FRAME:
+/// RTN (radial, transverse, normal, also written RSW or RIC) of the state at
+/// ignition, or an inertial frame.
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpmManeuver {
+    /// Ignition epoch (MAN_EPOCH_IGNITION)
+    pub epoch_ignition: Epoch,
+    /// Burn duration in s (MAN_DURATION)
+    pub duration: f64,
+    /// Mass change in kg, zero or negative (MAN_DELTA_MASS)
+    pub delta_mass: f64,
+    /// Frame of the Δv (MAN_REF_FRAME)
+    pub ref_frame: String,
+    /// Δv in m/s (MAN_DV_1, MAN_DV_2, MAN_DV_3)
+    pub delta_v: Vector3,
+    /// COMMENT lines of the maneuver
+    pub comments: Vec<String>,
+}
+
+impl OpmManeuver {
+    /// Impulsive maneuver of `delta_v` (m/s) in `ref_frame`, without mass
+    /// change
+    pub fn impulsive(
+        epoch_ignition: Epoch,
+        ref_frame: impl Into<String>,
+        delta_v: Vector3,
+    ) -> Self {
+        Self {
+            epoch_ignition,
+            duration: 0.0,
+            delta_mass: 0.0,
+            ref_frame: ref_frame.into(),
+            delta_v,
+            comments: Vec::new(),
+        }
+    }
+
+    /// Impulsive along-track (RTN transverse) maneuver of a delta-v budget
+    /// entry, with its name and notes as comments
+    pub fn from_budget(maneuver: &DeltaVManeuver, epoch_ignition: Epoch) -> Self {
+        let mut burn = Self::impulsive(
+            epoch_ignition,
+            "RTN",
+            Vector3::new(0.0, maneuver.delta_v, 0.0),
+        );
+        burn.comments.push(maneuver.name.clone());
+        burn.comments.extend(maneuver.notes.clone());
+        burn
+    }
+
+    /// The two tangential RTN burns of a Hohmann transfer between circular
+    /// orbits, the first at `epoch_ignition` and the second half a transfer
+    /// orbit later, both retrograde when lowering the orbit
+    pub fn hohmann(transfer: &HohmannTransferResult, epoch_ignition: Epoch) -> Vec<Self> {
+        let sign = if transfer.r_final > transfer.r_initial {
+            1.0
+        } else {
+            -1.0
+        };
+        tangential_burns(
+            "Hohmann transfer",
+            epoch_ignition,
+            &[
+                (0.0, sign * transfer.delta_v1),
+                (transfer.transfer_time, sign * transfer.delta_v2),
+            ],
+        )
+    }
+
+    /// The three tangential RTN burns of a bi-elliptic transfer between
+    /// circular orbits: at `epoch_ignition`, at the apoapsis of the first
+    /// transfer orbit and at the periapsis of the second
+    pub fn bielliptic(transfer: &BiellipticTransferResult, epoch_ignition: Epoch) -> Vec<Self> {
+        let half_period =
+            std::f64::consts::PI * (transfer.transfer1_sma.powi(3) / transfer.mu).sqrt();
+        // The second burn raises the periapsis when the final orbit is
+        // higher, and the third always circularizes at a periapsis
+        let sign = if transfer.r_final > transfer.r_initial {
+            1.0
+        } else {
+            -1.0
+        };
+        tangential_burns(
+            "Bi-elliptic transfer",
+            epoch_ignition,
+            &[
+                (0.0, transfer.delta_v1),
+                (half_period, sign * transfer.delta_v2),
+                (transfer.transfer_time, -transfer.delta_v3),
+            ],
+        )
+    }
+
+    /// The departure and arrival burns of a Lambert transfer, in the
+    /// inertial `frame` of the solution, from the velocity `v_departure`
+    /// before departure to `v_arrival` after arrival (m/s)
+    pub fn lambert(
+        solution: &LambertSolution,
+        v_departure: &Vector3,
+        v_arrival: &Vector3,
+        epoch_departure: Epoch,
+        frame: FrameType,
+    ) -> Vec<Self> {
+        let (ref_frame, _) = super::frame_names(frame);
+        let epoch_arrival = epoch_departure.add_duration(Duration::from_seconds(solution.tof));
+        let mut departure = Self::impulsive(epoch_departure, ref_frame, solution.v1 - v_departure);
+        departure
+            .comments
+            .push("Lambert transfer departure".to_string());
+        let mut arrival = Self::impulsive(epoch_arrival, ref_frame, v_arrival - solution.v2);
+        arrival
+            .comments
+            .push("Lambert transfer arrival".to_string());
+        vec![departure, arrival]
+    }
+
+    /// Δv in the inertial frame of `state`, the state at ignition
+    ///
+    /// An RTN Δv is rotated into the frame of the state; a Δv in an inertial
+    /// frame is taken to be in the frame of the state.
+    ///
+    /// # Errors
+    ///
+    /// - `UnknownFrame`: If MAN_REF_FRAME is neither RTN nor a supported
+    ///   frame
+    /// - `InvalidState`: If RTN is undefined for the state
+    pub fn inertial_delta_v(&self, state: &CartesianState) -> PoliastroResult<Vector3> {
+        match self.ref_frame.to_ascii_uppercase().as_str() {
+            "RTN" | "RSW" | "RIC" => {
+                Ok(ric_rotation(&state.position, &state.velocity)?.transpose() * self.delta_v)
+            }
+            name => super::frame_type(name, "EARTH").map(|_| self.delta_v),
+        }
+    }
+
+    /// Check that the duration is not negative, the mass change not positive
+    /// and the Δv finite
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` describing the first problem found
+    pub fn validate(&self) -> PoliastroResult<()> {
+        if self.duration < 0.0 || !self.duration.is_finite() {
+            return Err(invalid(format!(
+                "MAN_DURATION {} is not a non-negative duration",
+                self.duration
+            )));
+        }
+        if self.delta_mass > 0.0 || !self.delta_mass.is_finite() {
+            return Err(invalid(format!(
+                "MAN_DELTA_MASS {} is not zero or negative",
+                self.delta_mass
+            )));
+        }
+        if self.ref_frame.trim().is_empty() {
+            return Err(invalid("maneuver without MAN_REF_FRAME"));
+        }
+        if !self.delta_v.iter().all(|value| value.is_finite()) {
+            return Err(invalid("maneuver Δv is not finite"));
+        }
+        Ok(())
+    }
+
+    /// Keywords and values, in CCSDS order
+    fn fields(&self, time_scale: TimeScale) -> Vec<(&'static str, String)> {
+        let mut fields = vec![
+            (
+                MANEUVER_KEYS[0],
+                format_epoch(&self.epoch_ignition, time_scale),
+            ),
+            (MANEUVER_KEYS[1], format_number(self.duration)),
+            (MANEUVER_KEYS[2], format_number(self.delta_mass)),
+            (MANEUVER_KEYS[3], self.ref_frame.clone()),
+        ];
+        for (key, value) in MANEUVER_KEYS[4..].iter().zip(self.delta_v.iter()) {
+            fields.push((key, format_number(value / KM_TO_M)));
+        }
+        fields
+    }
+
+    fn from_fields(fields: &Fields, time_scale: TimeScale) -> PoliastroResult<Self> {
+        if let Some((key, _)) = fields.iter().find(|(key, _)| !MANEUVER_KEYS.contains(key)) {
+            return Err(invalid(format!("unexpected maneuver keyword {key}")));
+        }
+        let delta_v = fields
+            .vector(&["MAN_DV_1", "MAN_DV_2", "MAN_DV_3"], KM_TO_M)?
+            .ok_or_else(|| invalid("maneuver without MAN_DV_1, MAN_DV_2 and MAN_DV_3"))?;
+        let maneuver = Self {
+            epoch_ignition: parse_epoch(
+                fields.require("MAN_EPOCH_IGNITION")?,
+                time_scale,
+                MESSAGE_TYPE,
+            )?,
+            duration: fields.require_parse("MAN_DURATION")?,
+            delta_mass: fields.require_parse("MAN_DELTA_MASS")?,
+            ref_frame: fields.require("MAN_REF_FRAME")?.to_string(),
+            delta_v,
+            comments: fields.comments.clone(),
+        };
+        maneuver.validate()?;
+        Ok(maneuver)
+    }
+}
+
+/// Tangential RTN burns of a transfer, at offsets (s) from `epoch_ignition`
+/// and with signed Δv (m/s)
+fn tangential_burns(
+    transfer: &str,
+    epoch_ignition: Epoch,
+    burns: &[(f64, f64)],
+) -> Vec<OpmManeuver> {
+    burns
+        .iter()
+        .enumerate()
+        .map(|(index, &(offset, delta_v))| {
+            let mut burn = OpmManeuver::impulsive(
+                epoch_ignition.add_duration(Duration::from_seconds(offset)),
+                "RTN",
+                Vector3::new(0.0, delta_v, 0.0),
+            );
+            burn.comments
+                .push(format!("{transfer} burn {} of {}", index + 1, burns.len()));
+            burn
+        })
+        .collect()
+}
+
+/// An orbit parameter message
+#[derive(Debug, Clone, PartialEq)]
+pub struct Opm {
+    /// Agency or operator creating the message (ORIGINATOR)
+    pub originator: String,
+    /// Creation time, UTC (CREATION_DATE)
+    pub creation_date: String,
+    /// COMMENT lines of the header
+    pub comments: Vec<String>,
+    /// Metadata block
+    pub metadata: OpmMetadata,
+    /// COMMENT lines of the state vector, elements, spacecraft parameters and
+    /// covariance
+    pub data_comments: Vec<String>,
+    /// Epoch of the state and elements (EPOCH)
+    pub epoch: Epoch,
+    /// Position (m) and velocity (m/s)
+    pub state: CartesianState,
+    /// Osculating Keplerian elements (m, rad), if given
+    pub keplerian: Option<OrbitalElements>,
+    /// Gravitational parameter of the elements in m³/s² (GM)
+    pub gm: Option<f64>,
+    /// Mass, areas and coefficients
+    pub spacecraft: SpacecraftParameters,
+    /// Position and velocity covariance (m², m²/s, m²/s²), if given
+    pub covariance: Option<Covariance6>,
+    /// Frame of the covariance (COV_REF_FRAME), when it is not REF_FRAME
+    pub covariance_frame: Option<String>,
+    /// Maneuvers, in order of ignition
+    pub maneuvers: Vec<OpmManeuver>,
+    /// USER_DEFINED_* parameters, without the prefix
+    pub user_defined: Vec<(String, String)>,
+}
+
+impl Opm {
+    /// OPM of `state` at `epoch` in `frame`, in UTC and created now
+    pub fn new(
+        originator: impl Into<String>,
+        object_name: impl Into<String>,
+        object_id: impl Into<String>,
+        frame: FrameType,
+        epoch: Epoch,
+        state: CartesianState,
+    ) -> Self {
+        let (ref_frame, center_name) = super::frame_names(frame);
+        Self {
+            originator: originator.into(),
+            creation_date: format_epoch(&Epoch::now(), TimeScale::UTC),
+            comments: Vec::new(),
+            metadata: OpmMetadata {
+                object_name: object_name.into(),
+                object_id: object_id.into(),
+                center_name: center_name.to_string(),
+                ref_frame: ref_frame.to_string(),
+                ref_frame_epoch: None,
+                time_system: "UTC".to_string(),
+                comments: Vec::new(),
+            },
+            data_comments: Vec::new(),
+            epoch,
+            state,
+            keplerian: None,
+            gm: None,
+            spacecraft: SpacecraftParameters::default(),
+            covariance: None,
+            covariance_frame: None,
+            maneuvers: Vec::new(),
+            user_defined: Vec::new(),
+        }
+    }
+
+    /// The message with the osculating elements of its state about a body
+    /// of gravitational parameter `mu` (m³/s²)
+    ///
+    /// # Errors
+    ///
+    /// If the elements are undefined for the state
+    pub fn with_keplerian(mut self, mu: f64) -> PoliastroResult<Self> {
+        self.keplerian = Some(rv_to_coe(
+            &self.state.position,
+            &self.state.velocity,
+            mu,
+            1e-8,
+        )?);
+        self.gm = Some(mu);
+        Ok(self)
+    }
+
+    /// Set the mass change of each maneuver from the rocket equation, burning
+    /// the maneuvers in order from MASS with `specific_impulse` (s)
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidParameter`: If the specific impulse is not positive
+    /// - `InvalidMessage`: If the message has no MASS
+    pub fn apply_propellant(&mut self, specific_impulse: f64) -> PoliastroResult<()> {
+        if specific_impulse <= 0.0 || !specific_impulse.is_finite() {
+            return Err(PoliastroError::invalid_parameter(
+                "specific_impulse",
+                specific_impulse,
+                "must be positive",
+            ));
+        }
+        let mut mass = self
+            .spacecraft
+            .mass
+            .ok_or_else(|| invalid("MASS is needed to compute MAN_DELTA_MASS"))?;
+        let exhaust_velocity = specific_impulse * STANDARD_GRAVITY;
+        for maneuver in &mut self.maneuvers {
+            // m₁ = m₀ exp(-Δv / (Isp g₀))
+            let burnt = mass * (1.0 - (-maneuver.delta_v.norm() / exhaust_velocity).exp());
+            maneuver.delta_mass = -burnt;
+            mass -= burnt;
+        }
+        Ok(())
+    }
+
+    /// Check the time system, elements, spacecraft parameters and maneuvers
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` describing the first problem found
+    pub fn validate(&self) -> PoliastroResult<()> {
+        let time_scale = self.metadata.time_scale()?;
+        let state = [self.state.position, self.state.velocity];
+        if !state.iter().flat_map(|v| v.iter()).all(|v| v.is_finite()) {
+            return Err(invalid("state vector is not finite"));
+        }
+
+        match (&self.keplerian, self.gm) {
+            (None, None) => {}
+            (Some(elements), Some(gm)) => {
+                if gm <= 0.0 || !gm.is_finite() {
+                    return Err(invalid(format!("GM {gm} is not positive")));
+                }
+                if elements.e < 0.0 || (elements.e < 1.0) != (elements.a > 0.0) {
+                    return Err(invalid(format!(
+                        "SEMI_MAJOR_AXIS {} m and ECCENTRICITY {} are inconsistent",
+                        elements.a, elements.e
+                    )));
+                }
+            }
+            _ => return Err(invalid("Keplerian elements need GM")),
+        }
+
+        for (key, value) in SPACECRAFT_KEYS.iter().zip(self.spacecraft.values()) {
+            if let Some(value) = value {
+                if value < 0.0 || !value.is_finite() {
+                    return Err(invalid(format!("{key} {value} is negative")));
+                }
+            }
+        }
+
+        for maneuver in &self.maneuvers {
+            maneuver.validate()?;
+        }
+        for pair in self.maneuvers.windows(2) {
+            if pair[1]
+                .epoch_ignition
+                .duration_since(&pair[0].epoch_ignition)
+                .to_seconds()
+                < 0.0
+            {
+                return Err(invalid(format!(
+                    "maneuver at {} is before the maneuver at {}",
+                    format_epoch(&pair[1].epoch_ignition, time_scale),
+                    format_epoch(&pair[0].epoch_ignition, time_scale)
+                )));
+            }
+        }
+        if let Some(mass) = self.spacecraft.mass {
+            let burnt: f64 = self.maneuvers.iter().map(|m| -m.delta_mass).sum();
+            if burnt >= mass {
+                return Err(invalid(format!(
+                    "maneuvers use {burnt} kg of a {mass} kg MASS"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Metadata keywords and values, in CCSDS order
+    fn metadata_fields(&self) -> Vec<(&'static str, String)> {
+        let metadata = &self.metadata;
+        let mut fields = vec![
+            ("OBJECT_NAME", metadata.object_name.clone()),
+            ("OBJECT_ID", metadata.object_id.clone()),
+            ("CENTER_NAME", metadata.center_name.clone()),
+            ("REF_FRAME", metadata.ref_frame.clone()),
+        ];
+        if let Some(ref_frame_epoch) = &metadata.ref_frame_epoch {
+            fields.push(("REF_FRAME_EPOCH", ref_frame_epoch.clone()));
+        }
+        fields.push(("TIME_SYSTEM", metadata.time_system.clone()));
+        fields
+    }
+
+    /// Data blocks before the maneuvers: XML tag and keyword, value and
+    /// units of each field, in CCSDS order
+    fn data_blocks(&self, time_scale: TimeScale) -> Vec<(&'static str, Vec<DataField>)> {
+        let state = std::iter::once(("EPOCH", format_epoch(&self.epoch, time_scale), None))
+            .chain(
+                STATE_KEYS
+                    .iter()
+                    .zip(self.state.position.iter().chain(self.state.velocity.iter()))
+                    .enumerate()
+                    .map(|(index, (&key, value))| {
+                        (
+                            key,
+                            format_number(value / KM_TO_M),
+                            Some(STATE_UNITS[index / 3]),
+                        )
+                    }),
+            )
+            .collect();
+        let mut blocks = vec![("stateVector", state)];
+
+        if let (Some(elements), Some(gm)) = (&self.keplerian, self.gm) {
+            let angle = |value: f64| format_number(value.to_degrees());
+            blocks.push((
+                "keplerianElements",
+                vec![
+                    (
+                        "SEMI_MAJOR_AXIS",
+                        format_number(elements.a / KM_TO_M),
+                        Some("km"),
+                    ),
+                    ("ECCENTRICITY", format_number(elements.e), None),
+                    ("INCLINATION", angle(elements.i), Some("deg")),
+                    ("RA_OF_ASC_NODE", angle(elements.raan), Some("deg")),
+                    ("ARG_OF_PERICENTER", angle(elements.argp), Some("deg")),
+                    ("TRUE_ANOMALY", angle(elements.nu), Some("deg")),
+                    ("GM", format_number(gm / KM3_TO_M3), Some("km**3/s**2")),
+                ],
+            ));
+        }
+
+        let spacecraft: Vec<DataField> = SPACECRAFT_KEYS
+            .iter()
+            .zip(self.spacecraft.values())
+            .zip(SPACECRAFT_UNITS)
+            .filter_map(|((&key, value), units)| value.map(|v| (key, format_number(v), units)))
+            .collect();
+        if !spacecraft.is_empty() {
+            blocks.push(("spacecraftParameters", spacecraft));
+        }
+
+        if let Some(covariance) = &self.covariance {
+            let mut fields = Vec::new();
+            if let Some(frame) = &self.covariance_frame {
+                fields.push(("COV_REF_FRAME", frame.clone(), None));
+            }
+            let values = super::lower_triangle(covariance, KM2_TO_M2);
+            for (index, (key, value)) in CARTESIAN_COVARIANCE.iter().zip(values).enumerate() {
+                let units = super::cartesian_covariance_units(index);
+                fields.push((key, format_number(value), Some(units)));
+            }
+            blocks.push(("covarianceMatrix", fields));
+        }
+        blocks
+    }
+
+    /// Write the message in CCSDS KVN
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` if the message is invalid
+    pub fn to_kvn(&self) -> PoliastroResult<String> {
+        self.validate()?;
+        let time_scale = self.metadata.time_scale()?;
+        let mut kvn = KvnWriter::new();
+        kvn.field("CCSDS_OPM_VERS", CCSDS_OPM_VERSION);
+        kvn.comments(&self.comments);
+        kvn.field("CREATION_DATE", &self.creation_date);
+        kvn.field("ORIGINATOR", &self.originator);
+
+        kvn.blank();
+        kvn.comments(&self.metadata.comments);
+        for (key, value) in self.metadata_fields() {
+            kvn.field(key, value);
+        }
+
+        kvn.blank();
+        kvn.comments(&self.data_comments);
+        for (_, fields) in self.data_blocks(time_scale) {
+            for (key, value, _) in fields {
+                kvn.field(key, value);
+            }
+        }
+
+        for maneuver in &self.maneuvers {
+            kvn.blank();
+            kvn.comments(&maneuver.comments);
+            for (key, value) in maneuver.fields(time_scale) {
+                kvn.field(key, value);
+            }
+        }
+
+        if !self.user_defined.is_empty() {
+            kvn.blank();
+            for (parameter, value) in &self.user_defined {
+                kvn.field(&format!("{USER_DEFINED_PREFIX}{parameter}"), value);
+            }
+        }
+        Ok(kvn.finish())
+    }
+
+    /// Write the message in CCSDS NDM/XML, with units attributes as in the
+    /// CCSDS schema
+    ///
+    /// # Errors
+    ///
+    /// `InvalidMessage` if the message is invalid
+    pub fn to_xml(&self) -> PoliastroResult<String> {
+        self.validate()?;
+        let time_scale = self.metadata.time_scale()?;
+        let mut xml = XmlWriter::new("opm", CCSDS_OPM_VERSION);
+        xml.open("header");
+        xml.comments(&self.comments);
+        xml.leaf("CREATION_DATE", &self.creation_date, None);
+        xml.leaf("ORIGINATOR", &self.originator, None);
+        xml.close("header");
+
+        xml.open("body");
+        xml.open("segment");
+        xml.open("metadata");
+        xml.comments(&self.metadata.comments);
+        for (key, value) in self.metadata_fields() {
+            xml.leaf(key, &value, None);
+        }
+        xml.close("metadata");
+
+        xml.open("data");
+        xml.comments(&self.data_comments);
+        for (tag, fields) in self.data_blocks(time_scale) {
+            xml.block(tag, fields);
+        }
+        for maneuver in &self.maneuvers {
+            xml.open("maneuverParameters");
+            xml.comments(&maneuver.comments);
+            for (index, (key, value)) in maneuver.fields(time_scale).into_iter().enumerate() {
+                let units = match index {
+                    1 => Some("s"),
+                    2 => Some("kg"),
+                    4.. => Some("km/s"),
+                    _ => None,
+                };
+                xml.leaf(key, &value, units);
+            }
+            xml.close("maneuverParameters");
+        }
+        if !self.user_defined.is_empty() {
+            xml.open("userDefinedParameters");
+            for (parameter, value) in &self.user_defined {
+                xml.leaf_with("USER_DEFINED", &[("parameter", parameter)], value);
+            }
+            xml.close("userDefinedParameters");
+        }
+        xml.close("data");
+        xml.close("segment");
+        xml.close("body");
+        Ok(xml.finish("opm"))
+    }
+}
+
+/// Keyword, value and XML units of a data field
+type DataField = (&'static str, String, Option<&'static str>);
+
+/// Parse an OPM in CCSDS KVN or XML (detected from the text)
+///
+/// # Errors
+///
+/// `InvalidMessage` if the message is malformed, misses a mandatory
+/// keyword, has an unknown keyword or fails [`Opm::validate`]
+///
+/// # Example
+///
+/// ```ignore
+/// let opm = parse_opm(&std::fs::read_to_string("sat.opm")?)?;
+/// for maneuver in &opm.maneuvers {
+///     println!("{:?} m/s in {}", maneuver.delta_v, maneuver.ref_frame);
+/// }
+/// ```
+pub fn parse_opm(text: &str) -> PoliastroResult<Opm> {
+    match Encoding::detect(text) {
+        Encoding::Kvn => opm_from_kvn(text),
+        Encoding::Xml => opm_from_xml(text),
+        Encoding::Json => Err(invalid("JSON is not a CCSDS OPM encoding")),
+    }
+}
+
+/// Write an OPM in `encoding` (KVN or XML)
+///
+/// # Errors
+///
+/// `InvalidMessage` for the JSON encoding or if the message is invalid
+pub fn write_opm(opm: &Opm, encoding: Encoding) -> PoliastroResult<String> {
+    match encoding {
+        Encoding::Kvn => opm.to_kvn(),
+        Encoding::Xml => opm.to_xml(),
+        Encoding::Json => Err(invalid("JSON is not a CCSDS OPM encoding")),
+    }
+}
+
+fn invalid(reason: impl Into<String>) -> PoliastroError {
+    PoliastroError::invalid_message(MESSAGE_TYPE, reason)
+}
+
+/// Block a KVN keyword belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum KvnBlock {
+    Header,
+    Metadata,
+    Data,
+    /// The n-th maneuver
+    Maneuver(usize),
+}
+
+fn opm_from_kvn(text: &str) -> PoliastroResult<Opm> {
+    let mut header = Fields::new(MESSAGE_TYPE);
+    let mut metadata = Fields::new(MESSAGE_TYPE);
+    let mut data = Fields::new(MESSAGE_TYPE);
+    let mut maneuvers: Vec<Fields> = Vec::new();
+    let mut block = KvnBlock::Header;
+    // Comments belong to the block of the keyword that follows them
+    let mut comments = Vec::new();
+
+    for (line_no, line) in kvn::lines(text, MESSAGE_TYPE)? {
+        let (key, value) = match line {
+            KvnLine::Comment(comment) => {
+                comments.push(comment);
+                continue;
+            }
+            KvnLine::Field { key, value } => (key, value),
+            KvnLine::Keyword(keyword) => {
+                return Err(invalid(format!("line {line_no}: unexpected {keyword}")))
+            }
+            KvnLine::Values(_) => {
+                return Err(invalid(format!("line {line_no}: unexpected data line")))
+            }
+        };
+
+        // User-defined parameters follow the maneuvers
+        let target = if key.starts_with(USER_DEFINED_PREFIX) {
+            block.max(KvnBlock::Data)
+        } else if key == "CCSDS_OPM_VERS" || HEADER_KEYS.contains(&key.as_str()) {
+            KvnBlock::Header
+        } else if METADATA_KEYS.contains(&key.as_str()) {
+            KvnBlock::Metadata
+        } else if key == "MAN_EPOCH_IGNITION" {
+            KvnBlock::Maneuver(maneuvers.len())
+        } else if key.starts_with("MAN_") {
+            match block {
+                KvnBlock::Maneuver(index) => KvnBlock::Maneuver(index),
+                _ => {
+                    return Err(invalid(format!(
+                        "line {line_no}: {key} before MAN_EPOCH_IGNITION"
+                    )))
+                }
+            }
+        } else {
+            KvnBlock::Data
+        };
+        if target < block {
+            return Err(invalid(format!("line {line_no}: unexpected keyword {key}")));
+        }
+        block = target;
+
+        let fields = if key.starts_with(USER_DEFINED_PREFIX) {
+            &mut data
+        } else {
+            match block {
+                KvnBlock::Header => &mut header,
+                KvnBlock::Metadata => &mut metadata,
+                KvnBlock::Data => &mut data,
+                KvnBlock::Maneuver(index) => {
+                    if index == maneuvers.len() {
+                        maneuvers.push(Fields::new(MESSAGE_TYPE));
+                    }
+                    &mut maneuvers[index]
+                }
+            }
+        };
+        fields.comments.append(&mut comments);
+        fields.push(key, value);
+    }
+
+    // Trailing comments stay with the last block
+    match maneuvers.last_mut() {
+        Some(maneuver) => maneuver.comments.append(&mut comments),
+        None => data.comments.append(&mut comments),
+    }
+    header.require("CCSDS_OPM_VERS")?;
+    complete_opm(&header, &metadata, &data, &maneuvers)
+}
+
+fn opm_from_xml(text: &str) -> PoliastroResult<Opm> {
+    let root = XmlElement::parse(text, MESSAGE_TYPE)?;
+    if root.name != "opm" {
+        return Err(invalid(format!(
+            "root element is <{}>, not <opm>",
+            root.name
+        )));
+    }
+    let header = root
+        .child("header")
+        .map(|header| header.fields(MESSAGE_TYPE))
+        .unwrap_or_else(|| Fields::new(MESSAGE_TYPE));
+    let segment = root
+        .child("body")
+        .and_then(|body| body.child("segment"))
+        .ok_or_else(|| invalid("missing <body> <segment>"))?;
+    let metadata = segment
+        .child("metadata")
+        .ok_or_else(|| invalid("segment without <metadata>"))?
+        .fields(MESSAGE_TYPE);
+    let data = segment
+        .child("data")
+        .ok_or_else(|| invalid("segment without <data>"))?;
+    let maneuvers: Vec<Fields> = data
+        .children_named("maneuverParameters")
+        .map(|maneuver| maneuver.fields(MESSAGE_TYPE))
+        .collect();
+    let mut orbit = data.clone();
+    orbit
+        .children
+        .retain(|child| child.name != "maneuverParameters");
+    complete_opm(
+        &header,
+        &metadata,
+        &orbit.flat_fields(MESSAGE_TYPE),
+        &maneuvers,
+    )
+}
+
+/// Header, metadata, data and maneuver fields into a message
+fn complete_opm(
+    header: &Fields,
+    metadata: &Fields,
+    data: &Fields,
+    maneuvers: &[Fields],
+) -> PoliastroResult<Opm> {
+    if let Some((key, _)) = metadata
+        .iter()
+        .find(|(key, _)| !METADATA_KEYS.contains(key))
+    {
+        return Err(invalid(format!("unexpected metadata keyword {key}")));
+    }
+    let known = |key: &str| {
+        key == "EPOCH"
+            || key == "COV_REF_FRAME"
+            || key.starts_with(USER_DEFINED_PREFIX)
+            || STATE_KEYS.contains(&key)
+            || KEPLERIAN_KEYS.contains(&key)
+            || SPACECRAFT_KEYS.contains(&key)
+            || CARTESIAN_COVARIANCE.contains(&key)
+    };
+    if let Some((key, _)) = data.iter().find(|(key, _)| !known(key)) {
+        return Err(invalid(format!("unexpected keyword {key}")));
+    }
+
+    let metadata = OpmMetadata {
+        object_name: metadata.require("OBJECT_NAME")?.to_string(),
+        object_id: metadata.require("OBJECT_ID")?.to_string(),
+        center_name: metadata.require("CENTER_NAME")?.to_string(),
+        ref_frame: metadata.require("REF_FRAME")?.to_string(),
+        ref_frame_epoch: metadata.get("REF_FRAME_EPOCH").map(str::to_string),
+        time_system: metadata.require("TIME_SYSTEM")?.to_string(),
+        comments: metadata.comments.clone(),
+    };
+    let time_scale = metadata.time_scale()?;
+
+    let position = data.vector(&[STATE_KEYS[0], STATE_KEYS[1], STATE_KEYS[2]], KM_TO_M)?;
+    let velocity = data.vector(&[STATE_KEYS[3], STATE_KEYS[4], STATE_KEYS[5]], KM_TO_M)?;
+    let (Some(position), Some(velocity)) = (position, velocity) else {
+        return Err(invalid("missing state vector"));
+    };
+    let (keplerian, gm) = match keplerian_from_fields(data)? {
+        Some((elements, gm)) => (Some(elements), Some(gm)),
+        None => (None, None),
+    };
+
+    let opm = Opm {
+        originator: header.require("ORIGINATOR")?.to_string(),
+        creation_date: header.require("CREATION_DATE")?.to_string(),
+        comments: header.comments.clone(),
+        data_comments: data.comments.clone(),
+        epoch: parse_epoch(data.require("EPOCH")?, time_scale, MESSAGE_TYPE)?,
+        state: CartesianState::new(position, velocity),
+        keplerian,
+        gm,
+        spacecraft: SpacecraftParameters {
+            mass: data.parse("MASS")?,
+            solar_rad_area: data.parse("SOLAR_RAD_AREA")?,
+            solar_rad_coeff: data.parse("SOLAR_RAD_COEFF")?,
+            drag_area: data.parse("DRAG_AREA")?,
+            drag_coeff: data.parse("DRAG_COEFF")?,
+        },
+        covariance: data.covariance(&CARTESIAN_COVARIANCE, KM2_TO_M2)?,
+        covariance_frame: data.get("COV_REF_FRAME").map(str::to_string),
+        maneuvers: maneuvers
+            .iter()
+            .map(|fields| OpmManeuver::from_fields(fields, time_scale))
+            .collect::<PoliastroResult<_>>()?,
+        user_defined: data
+            .iter()
+            .filter_map(|(key, value)| {
+                key.strip_prefix(USER_DEFINED_PREFIX)
+                    .map(|parameter| (parameter.to_string(), value.to_string()))
+            })
+            .collect(),
+        metadata,
+    };
+    opm.validate()?;
+    Ok(opm)
+}
+
+/// Keplerian elements (m, rad) and GM (m³/s²), with a MEAN_ANOMALY
+/// converted to the true anomaly
+///
+/// `None` when no element is given; an error when only some are.
+fn keplerian_from_fields(data: &Fields) -> PoliastroResult<Option<(OrbitalElements, f64)>> {
+    if KEPLERIAN_KEYS.iter().all(|key| data.get(key).is_none()) {
+        return Ok(None);
+    }
+    let angle = |key: &str| data.require_parse::<f64>(key).map(f64::to_radians);
+    let e: f64 = data.require_parse("ECCENTRICITY")?;
+    let nu = match (
+        data.parse::<f64>("TRUE_ANOMALY")?,
+        data.parse::<f64>("MEAN_ANOMALY")?,
+    ) {
+        (Some(nu), None) => nu.to_radians(),
+        (None, Some(mean)) if e < 1.0 => mean_to_true_anomaly(mean.to_radians(), e, None, None)
+            .map_err(|err| invalid(format!("MEAN_ANOMALY: {err}")))?,
+        (None, Some(mean)) => mean_to_true_anomaly_hyperbolic(mean.to_radians(), e, None, None)
+            .map_err(|err| invalid(format!("MEAN_ANOMALY: {err}")))?,
+        _ => return Err(invalid("give one of TRUE_ANOMALY and MEAN_ANOMALY")),
+    };
+    let elements = OrbitalElements::new(
+        data.require_parse::<f64>("SEMI_MAJOR_AXIS")? * KM_TO_M,
+        e,
+        angle("INCLINATION")?,
+        angle("RA_OF_ASC_NODE")?,
+        angle("ARG_OF_PERICENTER")?,
+        nu,
+    );
+    let gm = data.require_parse::<f64>("GM")? * KM3_TO_M3;
+    Ok(Some((elements, gm)))
+}
+
+#[pymethods]
+impl OpmManeuver {
+    /// Create an OPM maneuver
+    ///
+    /// # Arguments
+    /// - `epoch_ignition`: Ignition epoch
+    /// - `delta_v`: Δv in `ref_frame` (m/s)
+    /// - `ref_frame`: Frame of the Δv ("RTN", or an inertial frame such as
+    ///   "EME2000")
+    /// - `duration`: Burn duration (s), 0 for an impulsive maneuver
+    /// - `delta_mass`: Mass change (kg), zero or negative
+    #[new]
+    #[pyo3(signature = (epoch_ignition, delta_v, ref_frame="RTN", duration=0.0, delta_mass=0.0))]
+    fn py_new(
+        epoch_ignition: Epoch,
+        delta_v: [f64; 3],
+        ref_frame: &str,
+        duration: f64,
+        delta_mass: f64,
+    ) -> PyResult<Self> {
+        let mut maneuver = Self::impulsive(epoch_ignition, ref_frame, Vector3::from(delta_v));
+        maneuver.duration = duration;
+        maneuver.delta_mass = delta_mass;
+        maneuver.validate()?;
+        Ok(maneuver)
+    }
+
+    /// Ignition epoch
+    #[getter]
+    fn get_epoch_ignition(&self) -> Epoch {
+        self.epoch_ignition
+    }
+
+    /// Burn duration (s)
+    #[getter]
+    fn get_duration(&self) -> f64 {
+        self.duration
+    }
+
+    /// Mass change (kg)
+    #[getter]
+    fn get_delta_mass(&self) -> f64 {
+        self.delta_mass
+    }
+
+    /// Frame of the Δv
+    #[getter]
+    fn get_ref_frame(&self) -> String {
+        self.ref_frame.clone()
+    }
+
+    /// Δv in the maneuver frame (m/s)
+    #[getter]
+    fn get_delta_v(&self) -> [f64; 3] {
+        [self.delta_v.x, self.delta_v.y, self.delta_v.z]
+    }
+
+    /// COMMENT lines
+    #[getter]
+    fn get_comments(&self) -> Vec<String> {
+        self.comments.clone()
+    }
+
+    /// Δv in the inertial frame of `state`, the state at ignition (m/s)
+    #[pyo3(name = "inertial_delta_v")]
+    fn py_inertial_delta_v(&self, state: CartesianState) -> PyResult<[f64; 3]> {
+        let delta_v = self.inertial_delta_v(&state)?;
+        Ok([delta_v.x, delta_v.y, delta_v.z])
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "OpmManeuver({}, [{}, {}, {}] m/s, {})",
+            format_epoch(&self.epoch_ignition, TimeScale::UTC),
+            self.delta_v.x,
+            self.delta_v.y,
+            self.delta_v.z,
+            self.ref_frame
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::GM_EARTH;
+    use crate::core::linalg::Matrix6;
+    use crate::maneuvers::{BiellipticTransfer, HohmannTransfer};
+
+    /// Example 3-2 of CCSDS 502.0-B-2, with covariance and user-defined
+    /// parameters added
+    const OPM_KVN: &str = "CCSDS_OPM_VERS = 2.0
+COMMENT Generated by GSOC, R. Kiehling
+COMMENT Current intermediate orbit IO2 and maneuver planning data
+CREATION_DATE = 2000-06-03T05:33:00.000
+ORIGINATOR = GSOC
+
+OBJECT_NAME = EUTELSAT W4
+OBJECT_ID = 2000-028A
+CENTER_NAME = EARTH
+REF_FRAME = TOD
+TIME_SYSTEM = UTC
+
+COMMENT State Vector
+EPOCH = 2006-06-03T00:00:00.000
+X = 6655.9942 [km]
+Y = -40218.5751 [km]
+Z = -82.9177 [km]
+X_DOT = 3.11548208 [km/s]
+Y_DOT = 0.47042605 [km/s]
+Z_DOT = -0.00101495 [km/s]
+
+COMMENT Keplerian elements
+SEMI_MAJOR_AXIS = 41399.5123 [km]
+ECCENTRICITY = 0.020842611
+INCLINATION = 0.117746 [deg]
+RA_OF_ASC_NODE = 17.604721 [deg]
+ARG_OF_PERICENTER = 218.242943 [deg]
+MEAN_ANOMALY = 41.922339 [deg]
+GM = 398600.4415 [km**3/s**2]
+
+COMMENT Spacecraft parameters
+MASS = 1913.000 [kg]
+SOLAR_RAD_AREA = 10.000 [m**2]
+SOLAR_RAD_COEFF = 1.300
+DRAG_AREA = 10.000 [m**2]
+DRAG_COEFF = 2.300
+
+COV_REF_FRAME = RTN
+CX_X = 3.331349476038534e-04
+CY_X = 4.618927349220216e-04
+CY_Y = 6.782421679971363e-04
+CZ_X = -3.070007847730449e-04
+CZ_Y = -4.221234189514228e-04
+CZ_Z = 3.231931992380369e-04
+CX_DOT_X = -3.349365033922630e-07
+CX_DOT_Y = -4.686084221046758e-07
+CX_DOT_Z = 2.484949578400095e-07
+CX_DOT_X_DOT = 4.296022805587290e-10
+CY_DOT_X = -2.211832501084875e-07
+CY_DOT_Y = -2.864186892102733e-07
+CY_DOT_Z = 1.798098699846038e-07
+CY_DOT_X_DOT = 2.608899201686016e-10
+CY_DOT_Y_DOT = 1.767514756338532e-10
+CZ_DOT_X = -3.041346050686871e-07
+CZ_DOT_Y = -4.989496988610662e-07
+CZ_DOT_Z = 3.540310904497689e-07
+CZ_DOT_X_DOT = 1.869263192954590e-10
+CZ_DOT_Y_DOT = 1.008862586240695e-10
+CZ_DOT_Z_DOT = 6.224444338635500e-10
+
+COMMENT First maneuver: AMF-3
+COMMENT Non-impulsive, thrust direction fixed in inertial frame
+MAN_EPOCH_IGNITION = 2000-06-03T09:00:34.1
+MAN_DURATION = 132.60 [s]
+MAN_DELTA_MASS = -18.418 [kg]
+MAN_REF_FRAME = EME2000
+MAN_DV_1 = -0.02325700 [km/s]
+MAN_DV_2 = 0.01683160 [km/s]
+MAN_DV_3 = -0.00893444 [km/s]
+
+COMMENT Second maneuver: first station acquisition maneuver
+COMMENT impulsive, thrust direction fixed in RTN frame
+MAN_EPOCH_IGNITION = 2000-06-05T18:59:21.0
+MAN_DURATION = 0.00 [s]
+MAN_DELTA_MASS = -1.469 [kg]
+MAN_REF_FRAME = RTN
+MAN_DV_1 = 0.00101500 [km/s]
+MAN_DV_2 = -0.00187300 [km/s]
+MAN_DV_3 = 0.00000000 [km/s]
+
+USER_DEFINED_EARTH_MODEL = WGS-84
+";
+
+    #[test]
+    fn test_parse_opm_kvn() {
+        let opm = parse_opm(OPM_KVN).unwrap();
+        assert_eq!(opm.originator, "GSOC");
+        assert_eq!(opm.comments.len(), 2);
+        assert_eq!(opm.metadata.object_name, "EUTELSAT W4");
+        assert_eq!(opm.metadata.frame().unwrap(), FrameType::TOD);
+        assert_eq!(opm.data_comments.len(), 3);
+        assert_eq!(opm.epoch, Epoch::from_gregorian_utc(2006, 6, 3, 0, 0, 0, 0));
+        assert!((opm.state.position.x - 6_655_994.2).abs() < 1e-6);
+        assert!((opm.state.velocity.x - 3_115.48208).abs() < 1e-9);
+
+        let elements = opm.keplerian.unwrap();
+        assert!((elements.a - 41_399_512.3).abs() < 1e-6);
+        assert!((elements.i.to_degrees() - 0.117746).abs() < 1e-12);
+        // The true anomaly is ahead of the mean anomaly after periapsis
+        let mean = 41.922339_f64.to_radians();
+        assert!(elements.nu > mean && elements.nu < mean + 2.0 * elements.e + 1e-3);
+        assert!((opm.gm.unwrap() - 3.986004415e14).abs() < 1.0);
+
+        assert_eq!(opm.spacecraft.mass, Some(1913.0));
+        assert_eq!(opm.spacecraft.drag_coeff, Some(2.3));
+        assert_eq!(opm.covariance_frame.as_deref(), Some("RTN"));
+        let covariance = opm.covariance.unwrap();
+        assert!((covariance.matrix()[(0, 0)] - 333.1349476038534).abs() < 1e-9);
+
+        assert_eq!(opm.maneuvers.len(), 2);
+        let first = &opm.maneuvers[0];
+        assert_eq!(first.comments.len(), 2);
+        assert_eq!(first.ref_frame, "EME2000");
+        assert!((first.duration - 132.6).abs() < 1e-12);
+        assert!((first.delta_mass + 18.418).abs() < 1e-12);
+        assert!((first.delta_v - Vector3::new(-23.257, 16.8316, -8.93444)).norm() < 1e-9);
+        assert_eq!(opm.maneuvers[1].ref_frame, "RTN");
+        assert_eq!(
+            opm.user_defined,
+            vec![("EARTH_MODEL".to_string(), "WGS-84".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_opm_kvn_xml_round_trip() {
+        let opm = parse_opm(OPM_KVN).unwrap();
+        for encoding in [Encoding::Kvn, Encoding::Xml] {
+            let text = write_opm(&opm, encoding).unwrap();
+            let read = parse_opm(&text).unwrap();
+            assert_eq!(read.metadata, opm.metadata);
+            assert_eq!(read.epoch, opm.epoch);
+            assert!((read.state.position - opm.state.position).norm() < 1e-6);
+            assert!((read.state.velocity - opm.state.velocity).norm() < 1e-9);
+            let (elements, expected) = (read.keplerian.unwrap(), opm.keplerian.unwrap());
+            assert!((elements.nu - expected.nu).abs() < 1e-12);
+            assert_eq!(read.spacecraft, opm.spacecraft);
+            assert!(
+                (read.covariance.unwrap().matrix() - opm.covariance.unwrap().matrix()).norm()
+                    < 1e-9
+            );
+            assert_eq!(read.maneuvers.len(), 2);
+            for (read, expected) in read.maneuvers.iter().zip(&opm.maneuvers) {
+                assert_eq!(read.epoch_ignition, expected.epoch_ignition);
+                assert_eq!(read.comments, expected.comments);
+                assert!((read.delta_v - expected.delta_v).norm() < 1e-9);
+            }
+            assert_eq!(read.user_defined, opm.user_defined);
+        }
+
+        let xml = write_opm(&opm, Encoding::Xml).unwrap();
+        assert!(xml.contains("<maneuverParameters>"));
+        assert!(xml.contains("<USER_DEFINED parameter=\"EARTH_MODEL\">WGS-84</USER_DEFINED>"));
+        assert!(write_opm(&opm, Encoding::Json).is_err());
+    }
+
+    #[test]
+    fn test_opm_transfer_maneuvers() {
+        let epoch = Epoch::from_gregorian_utc(2024, 3, 1, 12, 0, 0, 0);
+        let (r_leo, r_geo) = (6_778_000.0, 42_164_000.0);
+        let state = CartesianState::new(
+            Vector3::new(r_leo, 0.0, 0.0),
+            Vector3::new(0.0, (GM_EARTH / r_leo).sqrt(), 0.0),
+        );
+
+        let transfer = HohmannTransfer::calculate(r_leo, r_geo, GM_EARTH).unwrap();
+        let mut opm = Opm::new("OPS", "SAT", "2024-001A", FrameType::GCRS, epoch, state)
+            .with_keplerian(GM_EARTH)
+            .unwrap();
+        opm.spacecraft.mass = Some(2000.0);
+        opm.maneuvers = OpmManeuver::hohmann(&transfer, epoch);
+        assert_eq!(opm.maneuvers.len(), 2);
+        assert!((opm.maneuvers[0].delta_v.y - transfer.delta_v1).abs() < 1e-12);
+        let coast = opm.maneuvers[1]
+            .epoch_ignition
+            .duration_since(&epoch)
+            .to_seconds();
+        assert!((coast - transfer.transfer_time).abs() < 1e-6);
+        // Prograde along the velocity of a circular orbit
+        let inertial = opm.maneuvers[0].inertial_delta_v(&state).unwrap();
+        assert!((inertial - Vector3::new(0.0, transfer.delta_v1, 0.0)).norm() < 1e-9);
+
+        opm.apply_propellant(300.0).unwrap();
+        let mass_ratio = (transfer.delta_v_total / (300.0 * STANDARD_GRAVITY)).exp();
+        let burnt: f64 = opm.maneuvers.iter().map(|m| -m.delta_mass).sum();
+        assert!((burnt - 2000.0 * (1.0 - 1.0 / mass_ratio)).abs() < 1e-9);
+        let read = parse_opm(&opm.to_kvn().unwrap()).unwrap();
+        assert!((read.maneuvers[1].delta_mass - opm.maneuvers[1].delta_mass).abs() < 1e-9);
+
+        // Lowering burns are retrograde
+        let lowering = HohmannTransfer::calculate(r_geo, r_leo, GM_EARTH).unwrap();
+        assert!(OpmManeuver::hohmann(&lowering, epoch)
+            .iter()
+            .all(|m| m.delta_v.y < 0.0));
+
+        let bielliptic =
+            BiellipticTransfer::calculate(r_leo, 10.0 * r_leo, 20.0 * r_leo, GM_EARTH).unwrap();
+        let burns = OpmManeuver::bielliptic(&bielliptic, epoch);
+        let signs: Vec<f64> = burns.iter().map(|m| m.delta_v.y.signum()).collect();
+        assert_eq!(signs, vec![1.0, 1.0, -1.0]);
+        let end = burns[2].epoch_ignition.duration_since(&epoch).to_seconds();
+        assert!((end - bielliptic.transfer_time).abs() < 1e-6);
+
+        let budget = DeltaVManeuver::with_notes("Station keeping", 1.5, "East-west").unwrap();
+        let burn = OpmManeuver::from_budget(&budget, epoch);
+        assert_eq!(burn.comments, vec!["Station keeping", "East-west"]);
+        assert_eq!(burn.delta_v, Vector3::new(0.0, 1.5, 0.0));
+    }
+
+    #[test]
+    fn test_opm_maneuver_round_trips() {
+        let epoch = Epoch::from_gregorian_utc(2024, 3, 1, 12, 0, 0, 0);
+        let state = CartesianState::new(
+            Vector3::new(7_000_000.0, -12_345.5, 1_000.25),
+            Vector3::new(1.5, 7_546.25, -0.125),
+        );
+        let minimal = Opm::new("OPS", "SAT", "2024-001A", FrameType::GCRS, epoch, state);
+        let mut opm = minimal.clone();
+        opm.spacecraft.mass = Some(1200.0);
+        // Finite inertial burn, impulsive RTN burn and Lambert burns in EME2000
+        let mut finite = OpmManeuver::impulsive(
+            epoch.add_duration(Duration::from_seconds(600.0)),
+            "EME2000",
+            Vector3::new(-1.25, 2.5, 0.0),
+        );
+        finite.duration = 30.5;
+        finite.delta_mass = -2.75;
+        finite.comments.push("Finite burn".to_string());
+        opm.maneuvers.push(finite);
+        opm.maneuvers.push(OpmManeuver::impulsive(
+            epoch.add_duration(Duration::from_seconds(3600.0)),
+            "RTN",
+            Vector3::new(0.0, 0.5, -0.25),
+        ));
+
+        for message in [&minimal, &opm] {
+            for encoding in [Encoding::Kvn, Encoding::Xml] {
+                let text = write_opm(message, encoding).unwrap();
+                let read = parse_opm(&text).unwrap();
+                assert_eq!(read.metadata, message.metadata);
+                assert_eq!(read.creation_date, message.creation_date);
+                assert_eq!(read.epoch, epoch);
+                assert!((read.state.position - state.position).norm() < 1e-6);
+                assert!((read.state.velocity - state.velocity).norm() < 1e-9);
+                assert_eq!((read.keplerian, read.gm), (None, None));
+                assert_eq!(read.covariance, None);
+                assert_eq!(read.spacecraft, message.spacecraft);
+                assert_eq!(read.maneuvers.len(), message.maneuvers.len());
+                for (read, expected) in read.maneuvers.iter().zip(&message.maneuvers) {
+                    assert_eq!(read.epoch_ignition, expected.epoch_ignition);
+                    assert_eq!(read.ref_frame, expected.ref_frame);
+                    assert_eq!(read.duration, expected.duration);
+                    assert_eq!(read.delta_mass, expected.delta_mass);
+                    assert_eq!(read.comments, expected.comments);
+                    assert!((read.delta_v - expected.delta_v).norm() < 1e-9);
+                }
+                // Written again, the text is unchanged
+                assert_eq!(write_opm(&read, encoding).unwrap(), text);
+            }
+        }
+
+        let xml = opm.to_xml().unwrap();
+        assert_eq!(xml.matches("<maneuverParameters>").count(), 2);
+        assert!(xml.contains("<MAN_DURATION units=\"s\">30.5</MAN_DURATION>"));
+        assert!(xml.contains("<MAN_DELTA_MASS units=\"kg\">-2.75</MAN_DELTA_MASS>"));
+        assert!(xml.contains("<MAN_DV_1 units=\"km/s\">-0.00125</MAN_DV_1>"));
+        assert!(xml.contains("<MAN_REF_FRAME>RTN</MAN_REF_FRAME>"));
+        assert!(!minimal.to_kvn().unwrap().contains("MAN_"));
+
+        // The full example goes KVN → XML → KVN unchanged once written (the
+        // mean anomaly is written as a true anomaly)
+        let kvn = parse_opm(OPM_KVN).unwrap().to_kvn().unwrap();
+        let xml = parse_opm(&kvn).unwrap().to_xml().unwrap();
+        assert_eq!(parse_opm(&xml).unwrap().to_kvn().unwrap(), kvn);
+        assert_eq!(parse_opm(&kvn).unwrap().to_xml().unwrap(), xml);
+        assert!(kvn.contains("TRUE_ANOMALY") && !kvn.contains("MEAN_ANOMALY"));
+
+        // Δv in the frame of the state
+        let rtn = &opm.maneuvers[1];
+        let inertial = rtn.inertial_delta_v(&state).unwrap();
+        assert!((inertial.norm() - rtn.delta_v.norm()).abs() < 1e-12);
+        assert_eq!(
+            opm.maneuvers[0].inertial_delta_v(&state).unwrap(),
+            opm.maneuvers[0].delta_v
+        );
+        let mut unknown = rtn.clone();
+        unknown.ref_frame = "LVLH_ROTATING".to_string();
+        assert!(unknown.inertial_delta_v(&state).is_err());
+    }
+
+    #[test]
+    fn test_parse_opm_invalid_input() {
+        let is_invalid =
+            |text: &str| matches!(parse_opm(text), Err(PoliastroError::InvalidMessage { .. }));
+        let remove = |line: &str| OPM_KVN.replacen(line, "", 1);
+
+        // Mandatory keywords
+        for line in [
+            "CCSDS_OPM_VERS = 2.0\n",
+            "CREATION_DATE = 2000-06-03T05:33:00.000\n",
+            "ORIGINATOR = GSOC\n",
+            "OBJECT_NAME = EUTELSAT W4\n",
+            "OBJECT_ID = 2000-028A\n",
+            "CENTER_NAME = EARTH\n",
+            "REF_FRAME = TOD\n",
+            "TIME_SYSTEM = UTC\n",
+            "EPOCH = 2006-06-03T00:00:00.000\n",
+            "Z_DOT = -0.00101495 [km/s]\n",
+            "ECCENTRICITY = 0.020842611\n",
+            "MEAN_ANOMALY = 41.922339 [deg]\n",
+            "MAN_DURATION = 0.00 [s]\n",
+            "MAN_REF_FRAME = EME2000\n",
+            "MAN_DV_3 = 0.00000000 [km/s]\n",
+            "CZ_DOT_Z_DOT = 6.224444338635500e-10\n",
+        ] {
+            assert!(is_invalid(&remove(line)), "without {line}");
+        }
+
+        // Malformed values and unknown keywords
+        for (from, to) in [
+            ("EPOCH = 2006-06-03T00:00:00.000", "EPOCH = 3 June 2006"),
+            ("Y = -40218.5751", "Y = -40218,5751"),
+            ("ECCENTRICITY = 0.020842611", "ECCENTRICITY = 1.5"),
+            ("GM = 398600.4415", "GM = -398600.4415"),
+            ("SOLAR_RAD_AREA = 10.000", "SOLAR_RAD_AREA = -10"),
+            ("MAN_DV_2 = 0.01683160", "MAN_DV_2 = fast"),
+            (
+                "MAN_EPOCH_IGNITION = 2000-06-03T09:00:34.1",
+                "MAN_EPOCH_IGNITION = later",
+            ),
+            ("MAN_DELTA_MASS = -1.469", "MAN_DELTA_MASS = -2000"),
+            ("MAN_REF_FRAME = RTN", "MAN_REF_FRAME = "),
+            ("MAN_REF_FRAME = RTN", "MAN_REF_FRAME = RTN\nMAN_THRUST = 1"),
+            ("CX_X = ", "CX_Y = 1\nCX_X = "),
+            ("USER_DEFINED_EARTH_MODEL", "EARTH_MODEL"),
+            ("OBJECT_ID", "OBJECT_TYPE = PAYLOAD\nOBJECT_ID"),
+        ] {
+            assert!(is_invalid(&OPM_KVN.replacen(from, to, 1)), "{from} -> {to}");
+        }
+        // A maneuver keyword before its ignition epoch
+        assert!(is_invalid(&OPM_KVN.replacen(
+            "MAN_EPOCH_IGNITION = 2000-06-03T09:00:34.1\nMAN_DURATION = 132.60 [s]",
+            "MAN_DURATION = 132.60 [s]\nMAN_EPOCH_IGNITION = 2000-06-03T09:00:34.1",
+            1
+        )));
+        assert!(is_invalid("CCSDS_OPM_VERS = 2.0\nMETA_START\n"));
+
+        // XML structure and maneuver blocks
+        let xml = parse_opm(OPM_KVN).unwrap().to_xml().unwrap();
+        assert!(parse_opm(&xml).is_ok());
+        for (from, to) in [
+            ("<opm ", "<oem "),
+            ("<segment>", "<part>"),
+            ("<metadata>", "<header>"),
+            ("<OBJECT_NAME>EUTELSAT W4</OBJECT_NAME>", ""),
+            ("<MAN_DV_3 units=\"km/s\">-0.00893444</MAN_DV_3>", ""),
+            (
+                "<MAN_DURATION units=\"s\">132.6</MAN_DURATION>",
+                "<MAN_DURATION>-1</MAN_DURATION>",
+            ),
+            (
+                "<MASS units=\"kg\">1913</MASS>",
+                "<MASS units=\"kg\">heavy</MASS>",
+            ),
+            (
+                "<TIME_SYSTEM>UTC</TIME_SYSTEM>",
+                "<TIME_SYSTEM>MET</TIME_SYSTEM>",
+            ),
+        ] {
+            assert!(xml.contains(from), "{from}");
+            let text = xml.replacen(from, to, 1);
+            assert!(parse_opm(&text).is_err(), "{from} -> {to}");
+        }
+
+        // Invalid messages are not written
+        let mut opm = parse_opm(OPM_KVN).unwrap();
+        opm.maneuvers.swap(0, 1);
+        assert!(opm.to_kvn().is_err() && opm.to_xml().is_err());
+        let mut opm = parse_opm(OPM_KVN).unwrap();
+        opm.gm = None;
+        assert!(opm.to_kvn().is_err() && opm.to_xml().is_err());
+        let mut opm = parse_opm(OPM_KVN).unwrap();
+        opm.state.velocity.x = f64::NAN;
+        assert!(write_opm(&opm, Encoding::Kvn).is_err());
+        let mut burn = OpmManeuver::impulsive(opm.epoch, "RTN", Vector3::new(0.0, 1.0, 0.0));
+        burn.duration = -1.0;
+        assert!(burn.validate().is_err());
+    }
+
+    #[test]
+    fn test_parse_opm_errors() {
+        let replace = |from: &str, to: &str| parse_opm(&OPM_KVN.replacen(from, to, 1));
+        let is_invalid = |result: PoliastroResult<Opm>| {
+            matches!(result, Err(PoliastroError::InvalidMessage { .. }))
+        };
+
+        assert!(is_invalid(replace(
+            "MEAN_ANOMALY",
+            "TRUE_ANOMALY = 1.0\nMEAN_ANOMALY"
+        )));
+        assert!(is_invalid(replace("GM = 398600.4415 [km**3/s**2]\n", "")));
+        assert!(is_invalid(replace(
+            "MAN_DELTA_MASS = -18.418",
+            "MAN_DELTA_MASS = 18.418"
+        )));
+        assert!(is_invalid(replace(
+            "MAN_DURATION = 132.60",
+            "MAN_DURATION = -1"
+        )));
+        assert!(is_invalid(replace("MASS = 1913.000", "MASS = 10")));
+        assert!(is_invalid(replace("X = 6655.9942 [km]\n", "")));
+        assert!(is_invalid(replace("DRAG_COEFF", "DRAG_COEF")));
+        assert!(is_invalid(replace(
+            "TIME_SYSTEM = UTC",
+            "TIME_SYSTEM = MET"
+        )));
+        // Metadata after the data, and maneuvers out of order
+        assert!(is_invalid(replace("MASS =", "OBJECT_ID = X\nMASS =")));
+        assert!(is_invalid(replace(
+            "2000-06-05T18:59:21.0",
+            "2000-06-01T18:59:21.0"
+        )));
+        assert!(is_invalid(replace(
+            "MAN_EPOCH_IGNITION = 2000-06-03T09:00:34.1\n",
+            ""
+        )));
+        assert!(is_invalid(parse_opm("<oem></oem>")));
+        assert!(is_invalid(parse_opm("{}")));
+
+        let mut opm = parse_opm(OPM_KVN).unwrap();
+        opm.covariance = Some(Covariance6::new(Matrix6::identity()).unwrap());
+        opm.spacecraft.mass = None;
+        assert!(opm.apply_propellant(300.0).is_err());
+    }
+}
@@ -0,0 +1,470 @@
+//! NDM/XML reading and writing
+//!
+//! A small XML reader covering what navigation data messages use: elements,
+//! attributes, character data, entity and character references, comments,
+//! CDATA sections and the prolog. Namespace prefixes are dropped from element
+//! names, so `ndm:omm` reads as `omm`.
+
+use super::Fields;
+use crate::core::{PoliastroError, PoliastroResult};
+
+/// Location of the CCSDS NDM/XML schema
+pub(crate) const NDM_XML_SCHEMA: &str =
+    "https://sanaregistry.org/r/ndmxml_unqualified/ndmxml-2.0.0-master-2.0.xsd";
+
+/// An XML element with its attributes, child elements and trimmed text
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct XmlElement {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<XmlElement>,
+    pub text: String,
+}
+
+impl XmlElement {
+    /// Parse a document into its root element
+    pub fn parse(text: &str, message_type: &str) -> PoliastroResult<Self> {
+        let mut parser = Parser {
+            text,
+            position: 0,
+            message_type,
+        };
+        parser.skip_misc()?;
+        let root = parser.element()?;
+        parser.skip_misc()?;
+        if parser.position < text.len() {
+            return Err(parser.error("content after the root element"));
+        }
+        Ok(root)
+    }
+
+    /// Children named `name`
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlElement> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    /// First child named `name`
+    pub fn child(&self, name: &str) -> Option<&XmlElement> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Descendant elements without children of their own, as keyword/value
+    /// fields, for messages whose keywords are unique across blocks
+    ///
+    /// `COMMENT` elements become comments, and `USER_DEFINED` elements take
+    /// the KVN keyword `USER_DEFINED_<parameter>`.
+    pub fn flat_fields(&self, message_type: &'static str) -> Fields {
+        let mut fields = Fields::new(message_type);
+        self.collect_fields(&mut fields);
+        fields
+    }
+
+    /// Child elements without children of their own, as keyword/value fields
+    pub fn fields(&self, message_type: &'static str) -> Fields {
+        let mut fields = Fields::new(message_type);
+        for child in self
+            .children
+            .iter()
+            .filter(|child| child.children.is_empty())
+        {
+            child.push_field(&mut fields);
+        }
+        fields
+    }
+
+    fn collect_fields(&self, fields: &mut Fields) {
+        for child in &self.children {
+            if child.children.is_empty() {
+                child.push_field(fields);
+            } else {
+                child.collect_fields(fields);
+            }
+        }
+    }
+
+    fn push_field(&self, fields: &mut Fields) {
+        if self.name == "COMMENT" {
+            fields.comments.push(self.text.clone());
+        } else if let Some(parameter) = self.attribute("parameter") {
+            fields.push(format!("{}_{parameter}", self.name), self.text.clone());
+        } else {
+            fields.push(self.name.clone(), self.text.clone());
+        }
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+    message_type: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    fn error(&self, reason: &str) -> PoliastroError {
+        let line = self.text[..self.position].matches('\n').count() + 1;
+        PoliastroError::invalid_message(self.message_type, format!("XML line {line}: {reason}"))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        let found = self.rest().starts_with(token);
+        if found {
+            self.position += token.len();
+        }
+        found
+    }
+
+    fn expect(&mut self, token: &str) -> PoliastroResult<()> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{token}'")))
+        }
+    }
+
+    /// Skip to just after the next `end`
+    fn skip_past(&mut self, end: &str) -> PoliastroResult<()> {
+        match self.rest().find(end) {
+            Some(offset) => {
+                self.position += offset + end.len();
+                Ok(())
+            }
+            None => Err(self.error(&format!("unterminated construct (missing '{end}')"))),
+        }
+    }
+
+    /// Skip whitespace, the XML declaration, processing instructions,
+    /// comments and the document type declaration
+    fn skip_misc(&mut self) -> PoliastroResult<()> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if self.rest().starts_with("<!") {
+                self.skip_past(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> PoliastroResult<&'a str> {
+        let rest = self.rest();
+        let length = rest
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '-' | ':' | '.')))
+            .unwrap_or(rest.len());
+        if length == 0 {
+            return Err(self.error("expected a name"));
+        }
+        let start = self.position;
+        self.position += length;
+        Ok(&self.text[start..self.position])
+    }
+
+    fn element(&mut self) -> PoliastroResult<XmlElement> {
+        self.expect("<")?;
+        let mut element = XmlElement {
+            name: local_name(self.name()?).to_string(),
+            ..XmlElement::default()
+        };
+
+        loop {
+            self.skip_whitespace();
+            if self.eat("/>") {
+                return Ok(element);
+            }
+            if self.eat(">") {
+                break;
+            }
+            let key = self.name()?.to_string();
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = if self.eat("\"") {
+                '"'
+            } else {
+                self.expect("'")?;
+                '\''
+            };
+            let end = self
+                .rest()
+                .find(quote)
+                .ok_or_else(|| self.error("unterminated attribute value"))?;
+            let value = unescape(&self.rest()[..end]).map_err(|reason| self.error(&reason))?;
+            self.position += end + 1;
+            element.attributes.push((key, value));
+        }
+
+        loop {
+            let rest = self.rest();
+            if rest.is_empty() {
+                return Err(self.error(&format!("unclosed element <{}>", element.name)));
+            } else if rest.starts_with("</") {
+                self.position += 2;
+                let name = local_name(self.name()?).to_string();
+                if name != element.name {
+                    return Err(self.error(&format!("</{name}> does not close <{}>", element.name)));
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                element.text = element.text.trim().to_string();
+                return Ok(element);
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if let Some(data) = rest.strip_prefix("<![CDATA[") {
+                let end = data
+                    .find("]]>")
+                    .ok_or_else(|| self.error("unterminated CDATA section"))?;
+                element.text += &data[..end];
+                self.position += "<![CDATA[".len() + end + "]]>".len();
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with('<') {
+                element.children.push(self.element()?);
+            } else {
+                let end = rest.find('<').unwrap_or(rest.len());
+                element.text += &unescape(&rest[..end]).map_err(|reason| self.error(&reason))?;
+                self.position += end;
+            }
+        }
+    }
+}
+
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// Replace entity and character references
+fn unescape(text: &str) -> Result<String, String> {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result += &rest[..start];
+        let end = rest[start..]
+            .find(';')
+            .ok_or_else(|| format!("unterminated reference in '{text}'"))?;
+        let reference = &rest[start + 1..start + end];
+        let character = match reference {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => reference
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| reference.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        result.push(character.ok_or_else(|| format!("unknown reference '&{reference};'"))?);
+        rest = &rest[start + end + 1..];
+    }
+    result += rest;
+    Ok(result)
+}
+
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Builds an NDM/XML message with two-space indentation
+#[derive(Debug)]
+pub(crate) struct XmlWriter {
+    xml: String,
+    depth: usize,
+}
+
+impl XmlWriter {
+    /// Start a message with its root element, e.g. `<omm ... id="CCSDS_OMM_VERS" version="2.0">`
+    pub fn new(root: &str, version: &str) -> Self {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml += &format!(
+            "<{root} xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" \
+             xsi:noNamespaceSchemaLocation=\"{NDM_XML_SCHEMA}\" id=\"CCSDS_{}_VERS\" version=\"{version}\">\n",
+            root.to_ascii_uppercase()
+        );
+        Self { xml, depth: 1 }
+    }
+
+    fn indent(&mut self) {
+        self.xml += &"  ".repeat(self.depth);
+    }
+
+    pub fn open(&mut self, tag: &str) {
+        self.open_with(tag, &[]);
+    }
+
+    pub fn open_with(&mut self, tag: &str, attributes: &[(&str, &str)]) {
+        self.indent();
+        self.xml += &format!("<{tag}");
+        for (key, value) in attributes {
+            self.xml += &format!(" {key}=\"{}\"", escape(value));
+        }
+        self.xml += ">\n";
+        self.depth += 1;
+    }
+
+    pub fn close(&mut self, tag: &str) {
+        self.depth -= 1;
+        self.indent();
+        self.xml += &format!("</{tag}>\n");
+    }
+
+    pub fn leaf(&mut self, tag: &str, value: &str, units: Option<&str>) {
+        self.indent();
+        let units = units
+            .map(|units| format!(" units=\"{units}\""))
+            .unwrap_or_default();
+        self.xml += &format!("<{tag}{units}>{}</{tag}>\n", escape(value));
+    }
+
+    /// Leaf with attributes, e.g. `<USER_DEFINED parameter="...">`
+    pub fn leaf_with(&mut self, tag: &str, attributes: &[(&str, &str)], value: &str) {
+        self.indent();
+        self.xml += &format!("<{tag}");
+        for (key, value) in attributes {
+            self.xml += &format!(" {key}=\"{}\"", escape(value));
+        }
+        self.xml += &format!(">{}</{tag}>\n", escape(value));
+    }
+
+    /// `tag` enclosing one leaf per keyword, value and units
+    pub fn block<'a>(
+        &mut self,
+        tag: &str,
+        fields: impl IntoIterator<Item = (&'a str, String, Option<&'a str>)>,
+    ) {
+        self.open(tag);
+        for (key, value, units) in fields {
+            self.leaf(key, &value, units);
+        }
+        self.close(tag);
+    }
+
+    pub fn comments(&mut self, comments: &[String]) {
+        for comment in comments {
+            self.leaf("COMMENT", comment, None);
+        }
+    }
+
+    /// Close the root element and return the message
+    pub fn finish(mut self, root: &str) -> String {
+        self.close(root);
+        self.xml
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_xml() {
+        let text = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- message -->
+<ndm:omm xmlns:ndm="urn:ccsds" id='CCSDS_OMM_VERS' version="2.0">
+  <header>
+    <COMMENT>first &amp; only</COMMENT>
+    <ORIGINATOR>A &lt;B&gt; &#67;&#x44;</ORIGINATOR>
+  </header>
+  <body><segment>
+    <data>
+      <INCLINATION units="deg">51.6</INCLINATION>
+      <USER_DEFINED parameter="COLOUR">red</USER_DEFINED>
+      <EMPTY/>
+      <NOTE><![CDATA[x < y]]></NOTE>
+    </data>
+  </segment></body>
+</ndm:omm>
+"#;
+        let root = XmlElement::parse(text, "OMM").unwrap();
+        assert_eq!(root.name, "omm");
+        assert_eq!(root.attribute("version"), Some("2.0"));
+        assert_eq!(root.attribute("id"), Some("CCSDS_OMM_VERS"));
+
+        let data = &root.children[1].children[0].children[0];
+        assert_eq!(data.name, "data");
+        assert_eq!(data.children[0].attribute("units"), Some("deg"));
+
+        let header = root.child("header").unwrap().fields("OMM");
+        assert_eq!(header.comments.len(), 1);
+        assert_eq!(header.get("ORIGINATOR"), Some("A <B> CD"));
+        assert!(root.fields("OMM").get("ORIGINATOR").is_none());
+        assert!(root.child("data").is_none());
+
+        let fields = root.flat_fields("OMM");
+        assert_eq!(fields.comments, vec!["first & only".to_string()]);
+        assert_eq!(fields.get("ORIGINATOR"), Some("A <B> CD"));
+        assert_eq!(fields.get("INCLINATION"), Some("51.6"));
+        assert_eq!(fields.get("USER_DEFINED_COLOUR"), Some("red"));
+        assert_eq!(fields.get("EMPTY"), Some(""));
+        assert_eq!(fields.get("NOTE"), Some("x < y"));
+    }
+
+    #[test]
+    fn test_parse_xml_errors() {
+        for text in [
+            "<omm><header></omm>",
+            "<omm>",
+            "<omm a=\"1></omm>",
+            "<omm>&unknown;</omm>",
+            "<omm></omm><omm></omm>",
+            "plain text",
+        ] {
+            assert!(
+                matches!(
+                    XmlElement::parse(text, "OMM"),
+                    Err(PoliastroError::InvalidMessage { .. })
+                ),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_xml_writer_round_trip() {
+        let mut xml = XmlWriter::new("oem", "2.0");
+        xml.open("header");
+        xml.comments(&["a & b".to_string()]);
+        xml.leaf("ORIGINATOR", "<ME>", None);
+        xml.close("header");
+        xml.open_with("segment", &[("name", "\"1\"")]);
+        xml.leaf("X", "1.5", Some("km"));
+        xml.close("segment");
+        let text = xml.finish("oem");
+        assert!(text.contains("\n  <header>\n    <COMMENT>a &amp; b</COMMENT>\n"));
+        assert!(text.contains("<X units=\"km\">1.5</X>"));
+
+        let root = XmlElement::parse(&text, "OEM").unwrap();
+        assert_eq!(root.attribute("id"), Some("CCSDS_OEM_VERS"));
+        assert_eq!(root.flat_fields("OEM").get("ORIGINATOR"), Some("<ME>"));
+        assert_eq!(
+            root.children_named("segment")
+                .next()
+                .unwrap()
+                .attribute("name"),
+            Some("\"1\"")
+        );
+    }
+}
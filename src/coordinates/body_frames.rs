@@ -0,0 +1,705 @@
+//! Body-centered inertial and body-fixed frames for solar system bodies
+//!
+//! These frames extend the Earth-centred GCRS/ITRS pair to the Moon, the
+//! planets and the major satellites:
+//!
+//! - [`BodyCenteredInertial`]: origin at the body's center, axes parallel to
+//!   the ICRF (the body-centered analogue of GCRS)
+//! - [`BodyFixed`]: origin at the body's center, axes rotating with the body
+//!   according to its IAU WGCCRE rotation model
+//!   ([`crate::coordinates::iau_rotation`]); the analogue of ITRS
+//!
+//! Translations between the body and the Earth or the solar system barycenter
+//! use the active ephemeris source ([`crate::ephemeris`]). The built-in
+//! analytic ephemeris covers the Sun, Moon and planetary barycenters; load an
+//! SPK kernel for satellites of other planets.
+//!
+//! Geodetic coordinates on the body use the reference ellipsoids of
+//! [`Ellipsoid::for_body`], so body-fixed positions can be passed to
+//! [`crate::satellite::groundtrack::ecef_to_geodetic_on`] and to the
+//! visibility functions with an [`crate::satellite::visibility::Observer`] on
+//! the same body.
+//!
+//! # Example
+//! ```rust,ignore
+//! use astrora_core::coordinates::{BodyCenteredInertial, GCRS};
+//! use astrora_core::ephemeris::Body;
+//!
+//! // Mars orbiter state, Mars-centered inertial
+//! let mci = BodyCenteredInertial::new(Body::Mars, position, velocity, epoch);
+//! let fixed = mci.to_body_fixed()?;
+//! let geodetic = fixed.geodetic()?;
+//! ```
+
+use nalgebra::{Matrix3, Vector3};
+use numpy::{PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::coordinates::frames::{state_from_arrays, GCRS, ICRS};
+use crate::coordinates::iau_rotation::{iau_rotation_model, BodyOrientation};
+use crate::core::error::PoliastroError;
+use crate::core::time::Epoch;
+use crate::core::PoliastroResult;
+use crate::ephemeris::{ephemeris_state, Body};
+use crate::satellite::groundtrack::{ecef_to_geodetic_on, Ellipsoid, GeodeticCoordinates};
+
+/// State of `target` relative to `center` (zero when they coincide)
+fn relative_state(
+    target: Body,
+    center: Body,
+    epoch: &Epoch,
+) -> PoliastroResult<(Vector3<f64>, Vector3<f64>)> {
+    if target == center {
+        return Ok((Vector3::zeros(), Vector3::zeros()));
+    }
+    ephemeris_state(target, center, epoch)
+}
+
+/// Orientation of `body` at `epoch`, or a transformation error if the body
+/// has no rotation model
+fn body_orientation(body: Body, epoch: &Epoch) -> PoliastroResult<BodyOrientation> {
+    iau_rotation_model(body)
+        .map(|model| model.orientation(epoch))
+        .ok_or_else(|| PoliastroError::TransformationFailure {
+            from_frame: format!("{} inertial", body),
+            to_frame: format!("{} body-fixed", body),
+            reason: "no IAU rotation model for this body".to_string(),
+        })
+}
+
+/// Reference ellipsoid of `body`, or an error if none is tabulated
+fn body_ellipsoid(body: Body) -> PoliastroResult<Ellipsoid> {
+    Ellipsoid::for_body(body).ok_or_else(|| {
+        PoliastroError::invalid_parameter(
+            "body",
+            body.naif_id() as f64,
+            "must have a tabulated reference ellipsoid",
+        )
+    })
+}
+
+fn format_repr(
+    frame: &str,
+    body: Body,
+    position: &Vector3<f64>,
+    velocity: &Vector3<f64>,
+    obstime: &Epoch,
+) -> String {
+    let (y, m, d, h, min, s, _) = obstime.to_gregorian_utc();
+    format!(
+        "{}(body={}, position=[{:.3e}, {:.3e}, {:.3e}] m, velocity=[{:.3e}, {:.3e}, {:.3e}] m/s, obstime={:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z)",
+        frame,
+        body,
+        position.x, position.y, position.z,
+        velocity.x, velocity.y, velocity.z,
+        y, m, d, h, min, s
+    )
+}
+
+/// Body-centered inertial frame
+///
+/// Origin at the center of `body`, axes parallel to the ICRF. With
+/// `body = Earth` this is the GCRS.
+///
+/// # Coordinates
+/// - Position: Cartesian (x, y, z) in meters, body-centered
+/// - Velocity: Cartesian (vx, vy, vz) in m/s, body-centered
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BodyCenteredInertial {
+    /// Central body
+    pub body: Body,
+    /// Position vector (meters, body-centered)
+    pub position: Vector3<f64>,
+    /// Velocity vector (m/s, body-centered)
+    pub velocity: Vector3<f64>,
+    /// Observation time
+    pub obstime: Epoch,
+}
+
+#[pymethods]
+impl BodyCenteredInertial {
+    /// Create a new body-centered inertial frame
+    ///
+    /// # Arguments
+    /// - `body`: NAIF ID of the central body (e.g. 301 Moon, 499 Mars)
+    /// - `position`: Position vector in meters [x, y, z]
+    /// - `velocity`: Velocity vector in m/s [vx, vy, vz]
+    /// - `obstime`: Observation epoch
+    #[new]
+    pub fn py_new(
+        body: i32,
+        position: PyReadonlyArray1<f64>,
+        velocity: PyReadonlyArray1<f64>,
+        obstime: Epoch,
+    ) -> PyResult<Self> {
+        let (position, velocity) = state_from_arrays(&position, &velocity)?;
+        Ok(Self::new(
+            Body::from_naif_id(body),
+            position,
+            velocity,
+            obstime,
+        ))
+    }
+
+    /// Create from a GCRS state by translating to the center of `body`
+    ///
+    /// # Arguments
+    /// - `gcrs`: Geocentric state
+    /// - `body`: NAIF ID of the new central body
+    #[staticmethod]
+    #[pyo3(name = "from_gcrs")]
+    pub fn py_from_gcrs(gcrs: &GCRS, body: i32) -> PoliastroResult<Self> {
+        Self::from_gcrs(gcrs, Body::from_naif_id(body))
+    }
+
+    /// Create from an ICRS state by translating to the center of `body`
+    ///
+    /// # Arguments
+    /// - `icrs`: Barycentric state
+    /// - `body`: NAIF ID of the new central body
+    #[staticmethod]
+    #[pyo3(name = "from_icrs")]
+    pub fn py_from_icrs(icrs: &ICRS, body: i32) -> PoliastroResult<Self> {
+        Self::from_icrs(icrs, Body::from_naif_id(body))
+    }
+
+    /// Get the NAIF ID of the central body
+    #[getter]
+    pub fn get_body(&self) -> i32 {
+        self.body.naif_id()
+    }
+
+    /// Get the position vector
+    #[getter]
+    pub fn get_position<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.position.as_slice())
+    }
+
+    /// Get the velocity vector
+    #[getter]
+    pub fn get_velocity<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.velocity.as_slice())
+    }
+
+    /// Get the observation time
+    #[getter]
+    pub fn get_obstime(&self) -> Epoch {
+        self.obstime
+    }
+
+    /// Convert to GCRS by translating to the geocenter
+    pub fn to_gcrs(&self) -> PoliastroResult<GCRS> {
+        let (r, v) = relative_state(self.body, Body::Earth, &self.obstime)?;
+        Ok(GCRS::new(
+            self.position + r,
+            self.velocity + v,
+            self.obstime,
+        ))
+    }
+
+    /// Convert to ICRS by translating to the solar system barycenter
+    pub fn to_icrs(&self) -> PoliastroResult<ICRS> {
+        let (r, v) = relative_state(self.body, Body::SolarSystemBarycenter, &self.obstime)?;
+        Ok(ICRS::with_obstime(
+            self.position + r,
+            self.velocity + v,
+            self.obstime,
+        ))
+    }
+
+    /// Convert to the body-fixed frame of the same body
+    ///
+    /// # Errors
+    /// `RuntimeError` if the body has no IAU rotation model
+    pub fn to_body_fixed(&self) -> PoliastroResult<BodyFixed> {
+        let orientation = body_orientation(self.body, &self.obstime)?;
+        let rotation = orientation.icrf_to_body_fixed();
+
+        let position = rotation * self.position;
+        let velocity = rotation * self.velocity - orientation.angular_velocity().cross(&position);
+
+        Ok(BodyFixed::new(self.body, position, velocity, self.obstime))
+    }
+
+    fn __repr__(&self) -> String {
+        format_repr(
+            "BodyCenteredInertial",
+            self.body,
+            &self.position,
+            &self.velocity,
+            &self.obstime,
+        )
+    }
+}
+
+impl BodyCenteredInertial {
+    /// Create a new body-centered inertial frame (internal Rust API)
+    ///
+    /// # Arguments
+    /// - `body`: Central body
+    /// - `position`: Position vector in meters
+    /// - `velocity`: Velocity vector in m/s
+    /// - `obstime`: Observation epoch
+    pub fn new(body: Body, position: Vector3<f64>, velocity: Vector3<f64>, obstime: Epoch) -> Self {
+        Self {
+            body,
+            position,
+            velocity,
+            obstime,
+        }
+    }
+
+    /// Create from a GCRS state by translating to the center of `body`
+    pub fn from_gcrs(gcrs: &GCRS, body: Body) -> PoliastroResult<Self> {
+        let (r, v) = relative_state(body, Body::Earth, gcrs.obstime())?;
+        Ok(Self::new(
+            body,
+            gcrs.position() - r,
+            gcrs.velocity() - v,
+            *gcrs.obstime(),
+        ))
+    }
+
+    /// Create from an ICRS state by translating to the center of `body`
+    pub fn from_icrs(icrs: &ICRS, body: Body) -> PoliastroResult<Self> {
+        let (r, v) = relative_state(body, Body::SolarSystemBarycenter, icrs.obstime())?;
+        Ok(Self::new(
+            body,
+            icrs.position() - r,
+            icrs.velocity() - v,
+            *icrs.obstime(),
+        ))
+    }
+
+    /// Get the central body
+    pub fn body(&self) -> Body {
+        self.body
+    }
+
+    /// Get position vector
+    pub fn position(&self) -> &Vector3<f64> {
+        &self.position
+    }
+
+    /// Get velocity vector
+    pub fn velocity(&self) -> &Vector3<f64> {
+        &self.velocity
+    }
+
+    /// Get observation time
+    pub fn obstime(&self) -> &Epoch {
+        &self.obstime
+    }
+}
+
+/// Body-fixed (rotating) frame of a solar system body
+///
+/// Origin at the center of `body`, z-axis along the IAU north pole and x-axis
+/// through the prime meridian. The frame rotates with the body, so velocities
+/// are relative to the rotating axes.
+///
+/// # Coordinates
+/// - Position: Cartesian (x, y, z) in meters, body-fixed
+/// - Velocity: Cartesian (vx, vy, vz) in m/s, relative to the rotating frame
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BodyFixed {
+    /// Body the frame is attached to
+    pub body: Body,
+    /// Position vector (meters, body-fixed)
+    pub position: Vector3<f64>,
+    /// Velocity vector (m/s, relative to the rotating frame)
+    pub velocity: Vector3<f64>,
+    /// Observation time
+    pub obstime: Epoch,
+}
+
+#[pymethods]
+impl BodyFixed {
+    /// Create a new body-fixed frame
+    ///
+    /// # Arguments
+    /// - `body`: NAIF ID of the body (e.g. 301 Moon, 499 Mars)
+    /// - `position`: Position vector in meters [x, y, z]
+    /// - `velocity`: Velocity vector in m/s [vx, vy, vz]
+    /// - `obstime`: Observation epoch
+    #[new]
+    pub fn py_new(
+        body: i32,
+        position: PyReadonlyArray1<f64>,
+        velocity: PyReadonlyArray1<f64>,
+        obstime: Epoch,
+    ) -> PyResult<Self> {
+        let (position, velocity) = state_from_arrays(&position, &velocity)?;
+        Ok(Self::new(
+            Body::from_naif_id(body),
+            position,
+            velocity,
+            obstime,
+        ))
+    }
+
+    /// Create a point at rest on the body from geodetic coordinates
+    ///
+    /// # Arguments
+    /// - `body`: NAIF ID of the body
+    /// - `latitude_deg`: Geodetic latitude in degrees
+    /// - `longitude_deg`: Longitude in degrees (east positive)
+    /// - `altitude_km`: Altitude above the body's reference ellipsoid in km
+    /// - `obstime`: Observation epoch
+    #[staticmethod]
+    #[pyo3(name = "from_geodetic")]
+    pub fn py_from_geodetic(
+        body: i32,
+        latitude_deg: f64,
+        longitude_deg: f64,
+        altitude_km: f64,
+        obstime: Epoch,
+    ) -> PoliastroResult<Self> {
+        Self::from_geodetic(
+            Body::from_naif_id(body),
+            latitude_deg.to_radians(),
+            longitude_deg.to_radians(),
+            altitude_km,
+            obstime,
+        )
+    }
+
+    /// Get the NAIF ID of the body
+    #[getter]
+    pub fn get_body(&self) -> i32 {
+        self.body.naif_id()
+    }
+
+    /// Get the position vector
+    #[getter]
+    pub fn get_position<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.position.as_slice())
+    }
+
+    /// Get the velocity vector
+    #[getter]
+    pub fn get_velocity<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.velocity.as_slice())
+    }
+
+    /// Get the observation time
+    #[getter]
+    pub fn get_obstime(&self) -> Epoch {
+        self.obstime
+    }
+
+    /// Convert to the body-centered inertial frame of the same body
+    ///
+    /// # Errors
+    /// `RuntimeError` if the body has no IAU rotation model
+    pub fn to_inertial(&self) -> PoliastroResult<BodyCenteredInertial> {
+        let orientation = body_orientation(self.body, &self.obstime)?;
+        let rotation = orientation.icrf_to_body_fixed().transpose();
+
+        let inertial_velocity =
+            self.velocity + orientation.angular_velocity().cross(&self.position);
+
+        Ok(BodyCenteredInertial::new(
+            self.body,
+            rotation * self.position,
+            rotation * inertial_velocity,
+            self.obstime,
+        ))
+    }
+
+    /// Convert to GCRS (via the body-centered inertial frame)
+    pub fn to_gcrs(&self) -> PoliastroResult<GCRS> {
+        self.to_inertial()?.to_gcrs()
+    }
+
+    /// Convert to ICRS (via the body-centered inertial frame)
+    pub fn to_icrs(&self) -> PoliastroResult<ICRS> {
+        self.to_inertial()?.to_icrs()
+    }
+
+    /// Geodetic coordinates of the position on the body's reference ellipsoid
+    ///
+    /// # Returns
+    /// Dictionary with `latitude_deg`, `longitude_deg` and `altitude_km`
+    #[pyo3(name = "geodetic")]
+    pub fn py_geodetic(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let geodetic = self.geodetic()?;
+
+        let dict = pyo3::types::PyDict::new_bound(py);
+        dict.set_item("latitude_deg", geodetic.latitude.to_degrees())?;
+        dict.set_item("longitude_deg", geodetic.longitude.to_degrees())?;
+        dict.set_item("altitude_km", geodetic.altitude)?;
+
+        Ok(dict.into())
+    }
+
+    fn __repr__(&self) -> String {
+        format_repr(
+            "BodyFixed",
+            self.body,
+            &self.position,
+            &self.velocity,
+            &self.obstime,
+        )
+    }
+}
+
+impl BodyFixed {
+    /// Create a new body-fixed frame (internal Rust API)
+    ///
+    /// # Arguments
+    /// - `body`: Body the frame is attached to
+    /// - `position`: Position vector in meters
+    /// - `velocity`: Velocity vector in m/s, relative to the rotating frame
+    /// - `obstime`: Observation epoch
+    pub fn new(body: Body, position: Vector3<f64>, velocity: Vector3<f64>, obstime: Epoch) -> Self {
+        Self {
+            body,
+            position,
+            velocity,
+            obstime,
+        }
+    }
+
+    /// Create a point at rest on the body from geodetic coordinates
+    ///
+    /// # Arguments
+    /// - `body`: Body the point is attached to
+    /// - `latitude`: Geodetic latitude in radians
+    /// - `longitude`: Longitude in radians (east positive)
+    /// - `altitude`: Altitude above the body's reference ellipsoid in km
+    /// - `obstime`: Observation epoch
+    ///
+    /// # Errors
+    /// `InvalidParameter` if the body has no tabulated reference ellipsoid
+    pub fn from_geodetic(
+        body: Body,
+        latitude: f64,
+        longitude: f64,
+        altitude: f64,
+        obstime: Epoch,
+    ) -> PoliastroResult<Self> {
+        let [x, y, z] = body_ellipsoid(body)?.geodetic_to_cartesian(latitude, longitude, altitude);
+        Ok(Self::new(
+            body,
+            Vector3::new(x, y, z) * 1000.0,
+            Vector3::zeros(),
+            obstime,
+        ))
+    }
+
+    /// Geodetic coordinates of the position on the body's reference ellipsoid
+    ///
+    /// # Errors
+    /// `InvalidParameter` if the body has no tabulated reference ellipsoid
+    pub fn geodetic(&self) -> PoliastroResult<GeodeticCoordinates> {
+        let ellipsoid = body_ellipsoid(self.body)?;
+        let position_km = self.position / 1000.0;
+        Ok(ecef_to_geodetic_on(
+            &[position_km.x, position_km.y, position_km.z],
+            &ellipsoid,
+        ))
+    }
+
+    /// Rotation matrix from ICRF axes to the axes of this frame at `obstime`
+    ///
+    /// # Errors
+    /// `TransformationFailure` if the body has no IAU rotation model
+    pub fn rotation_from_icrf(&self) -> PoliastroResult<Matrix3<f64>> {
+        Ok(body_orientation(self.body, &self.obstime)?.icrf_to_body_fixed())
+    }
+
+    /// Get the body
+    pub fn body(&self) -> Body {
+        self.body
+    }
+
+    /// Get position vector
+    pub fn position(&self) -> &Vector3<f64> {
+        &self.position
+    }
+
+    /// Get velocity vector
+    pub fn velocity(&self) -> &Vector3<f64> {
+        &self.velocity
+    }
+
+    /// Get observation time
+    pub fn obstime(&self) -> &Epoch {
+        &self.obstime
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::{R_MARS, R_MOON};
+    use approx::assert_relative_eq;
+
+    fn epoch() -> Epoch {
+        Epoch::from_gregorian_utc(2026, 5, 1, 12, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_inertial_body_fixed_roundtrip() {
+        let inertial = BodyCenteredInertial::new(
+            Body::Moon,
+            Vector3::new(1.2e6, -1.5e6, 0.8e6),
+            Vector3::new(900.0, 700.0, -1100.0),
+            epoch(),
+        );
+
+        let fixed = inertial.to_body_fixed().unwrap();
+        assert_relative_eq!(
+            fixed.position.norm(),
+            inertial.position.norm(),
+            epsilon = 1e-6
+        );
+
+        let back = fixed.to_inertial().unwrap();
+        assert_eq!(back.body, Body::Moon);
+        assert_relative_eq!(back.position, inertial.position, epsilon = 1e-6);
+        assert_relative_eq!(back.velocity, inertial.velocity, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_surface_point_moves_with_rotation() {
+        // A point at rest on the Mars equator moves at ~240 m/s inertially
+        let fixed = BodyFixed::from_geodetic(Body::Mars, 0.0, 0.0, 0.0, epoch()).unwrap();
+        assert_relative_eq!(fixed.position.norm(), R_MARS, epsilon = 1e-6);
+
+        let inertial = fixed.to_inertial().unwrap();
+        let expected_speed = std::f64::consts::TAU * R_MARS / 88642.66;
+        assert_relative_eq!(
+            inertial.velocity.norm(),
+            expected_speed,
+            max_relative = 1e-6
+        );
+
+        // Velocity is perpendicular to both the position and the pole
+        let pole = body_orientation(Body::Mars, &epoch()).unwrap().pole();
+        assert!(inertial.velocity.dot(&inertial.position).abs() < 1e-3);
+        assert!(inertial.velocity.dot(&pole).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_body_fixed_velocity_matches_finite_difference() {
+        let inertial = BodyCenteredInertial::new(
+            Body::Mars,
+            Vector3::new(3.0e6, 2.5e6, 1.0e6),
+            Vector3::new(-1500.0, 2000.0, 800.0),
+            epoch(),
+        );
+        let fixed = inertial.to_body_fixed().unwrap();
+
+        let h = 1.0;
+        let shifted = |dt: f64| {
+            let t = Epoch::from_tdb_seconds_since_j2000(epoch().to_tdb_seconds_since_j2000() + dt);
+            BodyCenteredInertial::new(
+                Body::Mars,
+                inertial.position + inertial.velocity * dt,
+                inertial.velocity,
+                t,
+            )
+            .to_body_fixed()
+            .unwrap()
+            .position
+        };
+        let numerical = (shifted(h) - shifted(-h)) / (2.0 * h);
+
+        assert_relative_eq!(fixed.velocity, numerical, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_gcrs_roundtrip_and_moon_distance() {
+        let gcrs = GCRS::new(
+            Vector3::new(7.0e6, 0.0, 0.0),
+            Vector3::new(0.0, 7500.0, 0.0),
+            epoch(),
+        );
+
+        let selenocentric = BodyCenteredInertial::from_gcrs(&gcrs, Body::Moon).unwrap();
+        let distance = selenocentric.position.norm();
+        assert!(distance > 3.4e8 && distance < 4.2e8);
+
+        let back = selenocentric.to_gcrs().unwrap();
+        assert_relative_eq!(back.position(), gcrs.position(), epsilon = 1e-6);
+        assert_relative_eq!(back.velocity(), gcrs.velocity(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_icrs_roundtrip() {
+        let inertial = BodyCenteredInertial::new(
+            Body::Naif(499),
+            Vector3::new(4.0e6, 0.0, 0.0),
+            Vector3::new(0.0, 3400.0, 0.0),
+            epoch(),
+        );
+
+        let icrs = inertial.to_icrs().unwrap();
+        assert!(icrs.position().norm() > 1.3e11);
+
+        let back = BodyCenteredInertial::from_icrs(&icrs, Body::Naif(499)).unwrap();
+        assert_relative_eq!(back.position, inertial.position, epsilon = 1e-3);
+        assert_relative_eq!(back.velocity, inertial.velocity, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_earth_centered_inertial_is_gcrs() {
+        let inertial = BodyCenteredInertial::new(
+            Body::Earth,
+            Vector3::new(7.0e6, 1.0e5, -2.0e5),
+            Vector3::new(10.0, 7500.0, 20.0),
+            epoch(),
+        );
+        let gcrs = inertial.to_gcrs().unwrap();
+        assert_eq!(gcrs.position(), &inertial.position);
+        assert_eq!(gcrs.velocity(), &inertial.velocity);
+    }
+
+    #[test]
+    fn test_lunar_geodetic() {
+        let fixed = BodyFixed::new(
+            Body::Moon,
+            Vector3::new(0.0, R_MOON + 100_000.0, 0.0),
+            Vector3::zeros(),
+            epoch(),
+        );
+        let geodetic = fixed.geodetic().unwrap();
+
+        assert_relative_eq!(geodetic.latitude, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(geodetic.longitude.to_degrees(), 90.0, epsilon = 1e-9);
+        assert_relative_eq!(geodetic.altitude, 100.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_mars_geodetic_roundtrip() {
+        let latitude = 18.4_f64.to_radians();
+        let longitude = 77.5_f64.to_radians();
+        let fixed =
+            BodyFixed::from_geodetic(Body::Mars, latitude, longitude, -2.6, epoch()).unwrap();
+        let geodetic = fixed.geodetic().unwrap();
+
+        assert_relative_eq!(geodetic.latitude, latitude, epsilon = 1e-10);
+        assert_relative_eq!(geodetic.longitude, longitude, epsilon = 1e-10);
+        assert_relative_eq!(geodetic.altitude, -2.6, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_body_without_rotation_model() {
+        let inertial = BodyCenteredInertial::new(
+            Body::Naif(-82),
+            Vector3::new(1.0e6, 0.0, 0.0),
+            Vector3::zeros(),
+            epoch(),
+        );
+
+        assert!(matches!(
+            inertial.to_body_fixed(),
+            Err(PoliastroError::TransformationFailure { .. })
+        ));
+        assert!(BodyFixed::from_geodetic(Body::Naif(-82), 0.0, 0.0, 0.0, epoch()).is_err());
+    }
+}
@@ -0,0 +1,584 @@
+//! IERS 2010 CIO-based transformation between GCRS and ITRS
+//!
+//! This module implements the celestial-to-terrestrial transformation recommended
+//! by the IERS Conventions (2010), Chapter 5, using the Celestial Intermediate
+//! Origin (CIO) paradigm:
+//!
+//! **[ITRS] = W(t) · R₃(ERA) · C(t) · [GCRS]**
+//!
+//! where:
+//! - **C(t)**: celestial-to-intermediate matrix built from the CIP coordinates
+//!   X, Y (IAU 2006 precession / IAU 2000 nutation) and the CIO locator s
+//! - **R₃(ERA)**: Earth rotation about the CIP by the Earth Rotation Angle,
+//!   computed from UT1
+//! - **W(t)**: polar motion matrix built from the pole coordinates xp, yp and
+//!   the TIO locator s′
+//!
+//! The intermediate frames are the CIRS (after C) and the TIRS (after R₃).
+//!
+//! # Earth Orientation Parameters
+//!
+//! The observed parts of Earth's rotation (UT1−UTC, polar motion and the
+//! celestial pole offsets dX, dY) are supplied through [`EarthOrientation`],
+//! normally obtained with [`EarthOrientation::at`] from the installed EOP
+//! table (see [`crate::core::eop`]).
+//! With all parameters set to zero the chain is still rigorous in its
+//! precession-nutation part, but Earth rotation uses UT1 = UTC (up to 0.9 s,
+//! i.e. ~400 m at the equator) and the pole is placed at the CIP (~10 m).
+//!
+//! # Conventions
+//!
+//! The rotation helpers in [`crate::coordinates::rotations`] are active
+//! rotations, whereas the IERS/SOFA formulation uses passive rotations
+//! Rₙ(θ). The two are related by Rₙ(θ) = `rotation_n(-θ)`.
+//!
+//! # References
+//!
+//! - IERS Conventions (2010), IERS Technical Note 36, Chapter 5
+//! - SOFA/ERFA routines `c2ixys`, `era00`, `pom00`, `s06`, `sp00`
+//! - Capitaine, N., Wallace, P.T. (2006), "High precision methods for locating
+//!   the celestial intermediate pole and origin", A&A 450, 855-872
+
+use nalgebra::{Matrix3, Vector3};
+use std::f64::consts::PI;
+
+use crate::coordinates::precession_nutation::{
+    delaunay_arguments, iau2006_precession_nutation_matrix, planetary_arguments, ARCSEC_TO_RAD,
+    JD_J2000,
+};
+use crate::coordinates::rotations::{rotation_x, rotation_y, rotation_z};
+use crate::core::eop::{bundled_eop, eop_at, EopRecord};
+use crate::core::time::Epoch;
+use crate::core::PoliastroResult;
+
+/// Nominal mean angular velocity of the Earth (rad/s, IERS Conventions 2010)
+pub const EARTH_ANGULAR_VELOCITY: f64 = 7.2921150e-5;
+
+/// Modified Julian Date zero point (JD 2400000.5)
+const MJD_ZERO: f64 = 2400000.5;
+
+/// Days per Julian century
+const DAYS_PER_JULIAN_CENTURY: f64 = 36525.0;
+
+/// Seconds per day
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/// Earth Orientation Parameters at a single instant
+///
+/// These are the observed quantities published by the IERS that cannot be
+/// predicted by theory. All angles are in radians.
+///
+/// The default value has every parameter set to zero, which corresponds to
+/// UT1 = UTC, no polar motion and no celestial pole offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EarthOrientation {
+    /// UT1 − UTC (seconds)
+    pub dut1: f64,
+    /// Polar motion x coordinate of the CIP in the ITRS (radians)
+    pub xp: f64,
+    /// Polar motion y coordinate of the CIP in the ITRS (radians)
+    pub yp: f64,
+    /// Celestial pole offset dX relative to IAU 2006/2000A (radians)
+    pub dx: f64,
+    /// Celestial pole offset dY relative to IAU 2006/2000A (radians)
+    pub dy: f64,
+}
+
+impl EarthOrientation {
+    /// Create a new set of Earth Orientation Parameters
+    ///
+    /// # Arguments
+    /// - `dut1`: UT1 − UTC in seconds
+    /// - `xp`, `yp`: Polar motion in radians
+    /// - `dx`, `dy`: Celestial pole offsets in radians
+    pub fn new(dut1: f64, xp: f64, yp: f64, dx: f64, dy: f64) -> Self {
+        Self { dut1, xp, yp, dx, dy }
+    }
+
+    /// Earth Orientation Parameters at `epoch` from the installed EOP table
+    ///
+    /// Falls back to the bundled data when no table is installed.
+    ///
+    /// # Errors
+    /// `OutOfRange` if an EOP table is installed and does not cover `epoch`.
+    pub fn at(epoch: &Epoch) -> PoliastroResult<Self> {
+        Ok(eop_at(epoch)?.into())
+    }
+
+    /// Earth Orientation Parameters at `epoch`, using the bundled data when
+    /// the installed table does not cover `epoch`
+    ///
+    /// For force models, where a missing EOP value should degrade accuracy
+    /// rather than abort a propagation.
+    pub fn at_or_fallback(epoch: &Epoch) -> Self {
+        Self::at(epoch).unwrap_or_else(|_| bundled_eop(epoch.to_mjd_utc()).into())
+    }
+
+    /// UT1 of `epoch` as a two-part Julian Date (MJD zero point, days)
+    pub fn ut1_two_part(&self, epoch: &Epoch) -> (f64, f64) {
+        (MJD_ZERO, epoch.to_mjd_utc() + self.dut1 / SECONDS_PER_DAY)
+    }
+}
+
+impl From<EopRecord> for EarthOrientation {
+    fn from(record: EopRecord) -> Self {
+        Self::new(record.dut1, record.xp, record.yp, record.dx, record.dy)
+    }
+}
+
+/// Earth Rotation Angle (IAU 2000) from a two-part UT1 Julian Date
+///
+/// ERA = 2π (0.7790572732640 + 1.00273781191135448 Tᵤ), with Tᵤ = JD(UT1) − 2451545.0.
+/// The fractional days of both parts are used separately to preserve precision.
+///
+/// # Returns
+/// Earth Rotation Angle in radians [0, 2π)
+pub fn earth_rotation_angle_ut1(ut1_1: f64, ut1_2: f64) -> f64 {
+    let (d1, d2) = if ut1_1 < ut1_2 { (ut1_1, ut1_2) } else { (ut1_2, ut1_1) };
+    let t = d1 + (d2 - JD_J2000);
+
+    // Fractional part of JD(UT1), in days
+    let f = d1 % 1.0 + d2 % 1.0;
+
+    let theta = 2.0 * PI * (f + 0.7790572732640 + 0.00273781191135448 * t);
+    theta.rem_euclid(2.0 * PI)
+}
+
+/// TIO locator s′ (IERS Conventions 2010, Eq. 5.13)
+///
+/// s′ = −47 μas × t, where t is TT in Julian centuries since J2000.0.
+///
+/// # Returns
+/// s′ in radians
+pub fn tio_locator_sp(jd1: f64, jd2: f64) -> f64 {
+    let t = ((jd1 - JD_J2000) + jd2) / DAYS_PER_JULIAN_CENTURY;
+    -47e-6 * t * ARCSEC_TO_RAD
+}
+
+/// Extract the CIP coordinates X, Y from a bias-precession-nutation matrix
+///
+/// X and Y are the GCRS components of the CIP unit vector, i.e. the bottom
+/// row of the NPB matrix.
+pub fn cip_xy(npb: &Matrix3<f64>) -> (f64, f64) {
+    (npb[(2, 0)], npb[(2, 1)])
+}
+
+/// Celestial pole offset matrix
+///
+/// Right-multiplying a bias-precession-nutation matrix by
+/// (1 0 −dX; 0 1 −dY; dX dY 1) moves the CIP by the EOP offsets dX, dY in the
+/// GCRS, which is how the equinox-based chain takes up the corrections that
+/// the CIO-based chain adds to X, Y (IERS Conventions 2010, Section 5.5.4).
+pub fn celestial_pole_offset_matrix(dx: f64, dy: f64) -> Matrix3<f64> {
+    Matrix3::new(1.0, 0.0, -dx, 0.0, 1.0, -dy, dx, dy, 1.0)
+}
+
+/// One term of the CIO locator series
+///
+/// Multipliers of (l, l', F, D, Ω, L_Ve, L_E, p_A) followed by the sine and
+/// cosine coefficients in arcseconds.
+struct CioTerm {
+    nfa: [i8; 8],
+    s: f64,
+    c: f64,
+}
+
+macro_rules! cio {
+    ($l:expr, $lp:expr, $f:expr, $d:expr, $om:expr, $ve:expr, $e:expr, $pa:expr, $s:expr, $c:expr) => {
+        CioTerm { nfa: [$l, $lp, $f, $d, $om, $ve, $e, $pa], s: $s, c: $c }
+    };
+}
+
+/// Polynomial part of s + XY/2 (arcseconds)
+const CIO_POLYNOMIAL: [f64; 6] = [94.00e-6, 3808.65e-6, -122.68e-6, -72574.11e-6, 27.98e-6, 15.62e-6];
+
+/// Periodic terms of order t⁰
+const CIO_S0: [CioTerm; 33] = [
+    cio!(0, 0, 0, 0, 1, 0, 0, 0, -2640.73e-6, 0.39e-6),
+    cio!(0, 0, 0, 0, 2, 0, 0, 0, -63.53e-6, 0.02e-6),
+    cio!(0, 0, 2, -2, 3, 0, 0, 0, -11.75e-6, -0.01e-6),
+    cio!(0, 0, 2, -2, 1, 0, 0, 0, -11.21e-6, -0.01e-6),
+    cio!(0, 0, 2, -2, 2, 0, 0, 0, 4.57e-6, 0.00e-6),
+    cio!(0, 0, 2, 0, 3, 0, 0, 0, -2.02e-6, 0.00e-6),
+    cio!(0, 0, 2, 0, 1, 0, 0, 0, -1.98e-6, 0.00e-6),
+    cio!(0, 0, 0, 0, 3, 0, 0, 0, 1.72e-6, 0.00e-6),
+    cio!(0, 1, 0, 0, 1, 0, 0, 0, 1.41e-6, 0.01e-6),
+    cio!(0, 1, 0, 0, -1, 0, 0, 0, 1.26e-6, 0.01e-6),
+    cio!(1, 0, 0, 0, -1, 0, 0, 0, 0.63e-6, 0.00e-6),
+    cio!(1, 0, 0, 0, 1, 0, 0, 0, 0.63e-6, 0.00e-6),
+    cio!(0, 1, 2, -2, 3, 0, 0, 0, -0.46e-6, 0.00e-6),
+    cio!(0, 1, 2, -2, 1, 0, 0, 0, -0.45e-6, 0.00e-6),
+    cio!(0, 0, 4, -4, 4, 0, 0, 0, -0.36e-6, 0.00e-6),
+    cio!(0, 0, 1, -1, 1, -8, 12, 0, 0.24e-6, 0.12e-6),
+    cio!(0, 0, 2, 0, 0, 0, 0, 0, -0.32e-6, 0.00e-6),
+    cio!(0, 0, 2, 0, 2, 0, 0, 0, -0.28e-6, 0.00e-6),
+    cio!(1, 0, 2, 0, 3, 0, 0, 0, -0.27e-6, 0.00e-6),
+    cio!(1, 0, 2, 0, 1, 0, 0, 0, -0.26e-6, 0.00e-6),
+    cio!(0, 0, 2, -2, 0, 0, 0, 0, 0.21e-6, 0.00e-6),
+    cio!(0, 1, -2, 2, -3, 0, 0, 0, -0.19e-6, 0.00e-6),
+    cio!(0, 1, -2, 2, -1, 0, 0, 0, -0.18e-6, 0.00e-6),
+    cio!(0, 0, 0, 0, 0, 8, -13, -1, 0.10e-6, -0.05e-6),
+    cio!(0, 0, 0, 2, 0, 0, 0, 0, -0.15e-6, 0.00e-6),
+    cio!(2, 0, -2, 0, -1, 0, 0, 0, 0.14e-6, 0.00e-6),
+    cio!(0, 1, 2, -2, 2, 0, 0, 0, 0.14e-6, 0.00e-6),
+    cio!(1, 0, 0, -2, 1, 0, 0, 0, -0.14e-6, 0.00e-6),
+    cio!(1, 0, 0, -2, -1, 0, 0, 0, -0.14e-6, 0.00e-6),
+    cio!(0, 0, 4, -2, 4, 0, 0, 0, -0.13e-6, 0.00e-6),
+    cio!(0, 0, 2, -2, 4, 0, 0, 0, 0.11e-6, 0.00e-6),
+    cio!(1, 0, -2, 0, -3, 0, 0, 0, -0.11e-6, 0.00e-6),
+    cio!(1, 0, -2, 0, -1, 0, 0, 0, -0.11e-6, 0.00e-6),
+];
+
+/// Periodic terms of order t¹
+const CIO_S1: [CioTerm; 3] = [
+    cio!(0, 0, 0, 0, 2, 0, 0, 0, -0.07e-6, 3.57e-6),
+    cio!(0, 0, 0, 0, 1, 0, 0, 0, 1.73e-6, -0.03e-6),
+    cio!(0, 0, 2, -2, 3, 0, 0, 0, 0.00e-6, 0.48e-6),
+];
+
+/// Periodic terms of order t²
+const CIO_S2: [CioTerm; 25] = [
+    cio!(0, 0, 0, 0, 1, 0, 0, 0, 743.52e-6, -0.17e-6),
+    cio!(0, 0, 2, -2, 2, 0, 0, 0, 56.91e-6, 0.06e-6),
+    cio!(0, 0, 2, 0, 2, 0, 0, 0, 9.84e-6, -0.01e-6),
+    cio!(0, 0, 0, 0, 2, 0, 0, 0, -8.85e-6, 0.01e-6),
+    cio!(0, 1, 0, 0, 0, 0, 0, 0, -6.38e-6, -0.05e-6),
+    cio!(1, 0, 0, 0, 0, 0, 0, 0, -3.07e-6, 0.00e-6),
+    cio!(0, 1, 2, -2, 2, 0, 0, 0, 2.23e-6, 0.00e-6),
+    cio!(0, 0, 2, 0, 1, 0, 0, 0, 1.67e-6, 0.00e-6),
+    cio!(1, 0, 2, 0, 2, 0, 0, 0, 1.30e-6, 0.00e-6),
+    cio!(0, 1, -2, 2, -2, 0, 0, 0, 0.93e-6, 0.00e-6),
+    cio!(1, 0, 0, -2, 0, 0, 0, 0, 0.68e-6, 0.00e-6),
+    cio!(0, 0, 2, -2, 1, 0, 0, 0, -0.55e-6, 0.00e-6),
+    cio!(1, 0, -2, 0, -2, 0, 0, 0, 0.53e-6, 0.00e-6),
+    cio!(0, 0, 0, 2, 0, 0, 0, 0, -0.27e-6, 0.00e-6),
+    cio!(1, 0, 0, 0, 1, 0, 0, 0, -0.27e-6, 0.00e-6),
+    cio!(1, 0, -2, -2, -2, 0, 0, 0, -0.26e-6, 0.00e-6),
+    cio!(1, 0, 0, 0, -1, 0, 0, 0, -0.25e-6, 0.00e-6),
+    cio!(1, 0, 2, 0, 1, 0, 0, 0, 0.22e-6, 0.00e-6),
+    cio!(2, 0, 0, -2, 0, 0, 0, 0, -0.21e-6, 0.00e-6),
+    cio!(2, 0, -2, 0, -1, 0, 0, 0, 0.20e-6, 0.00e-6),
+    cio!(0, 0, 2, 2, 2, 0, 0, 0, 0.17e-6, 0.00e-6),
+    cio!(2, 0, 2, 0, 2, 0, 0, 0, 0.13e-6, 0.00e-6),
+    cio!(2, 0, 0, 0, 0, 0, 0, 0, -0.13e-6, 0.00e-6),
+    cio!(1, 0, 2, -2, 2, 0, 0, 0, -0.12e-6, 0.00e-6),
+    cio!(0, 0, 2, 0, 0, 0, 0, 0, -0.11e-6, 0.00e-6),
+];
+
+/// Periodic terms of order t³
+const CIO_S3: [CioTerm; 4] = [
+    cio!(0, 0, 0, 0, 1, 0, 0, 0, 0.30e-6, -23.42e-6),
+    cio!(0, 0, 2, -2, 2, 0, 0, 0, -0.03e-6, -1.46e-6),
+    cio!(0, 0, 2, 0, 2, 0, 0, 0, -0.01e-6, -0.25e-6),
+    cio!(0, 0, 0, 0, 2, 0, 0, 0, 0.00e-6, 0.23e-6),
+];
+
+/// Periodic terms of order t⁴
+const CIO_S4: [CioTerm; 1] = [cio!(0, 0, 0, 0, 1, 0, 0, 0, -0.26e-6, -0.01e-6)];
+
+/// Sum one block of CIO locator terms
+fn sum_cio_terms(terms: &[CioTerm], fa: &[f64; 8]) -> f64 {
+    terms
+        .iter()
+        .rev()
+        .map(|term| {
+            let a: f64 = term
+                .nfa
+                .iter()
+                .zip(fa.iter())
+                .map(|(&n, &arg)| f64::from(n) * arg)
+                .sum();
+            term.s * a.sin() + term.c * a.cos()
+        })
+        .sum()
+}
+
+/// CIO locator s, consistent with IAU 2006 precession and IAU 2000A nutation
+///
+/// s positions the Celestial Intermediate Origin on the equator of the CIP.
+/// It is evaluated as a series for s + XY/2 (IERS Conventions 2010, Table 5.2d),
+/// from which XY/2 is subtracted using the supplied CIP coordinates.
+///
+/// # Arguments
+/// - `jd1`, `jd2`: TT as a two-part Julian Date
+/// - `x`, `y`: CIP coordinates (radians)
+///
+/// # Returns
+/// The CIO locator s in radians
+pub fn cio_locator_s(jd1: f64, jd2: f64, x: f64, y: f64) -> f64 {
+    let t = ((jd1 - JD_J2000) + jd2) / DAYS_PER_JULIAN_CENTURY;
+
+    let delaunay = delaunay_arguments(t);
+    let planetary = planetary_arguments(t);
+    let fa = [
+        delaunay[0],
+        delaunay[1],
+        delaunay[2],
+        delaunay[3],
+        delaunay[4],
+        planetary[1], // Venus
+        planetary[2], // Earth
+        planetary[8], // general precession p_A
+    ];
+
+    let w0 = CIO_POLYNOMIAL[0] + sum_cio_terms(&CIO_S0, &fa);
+    let w1 = CIO_POLYNOMIAL[1] + sum_cio_terms(&CIO_S1, &fa);
+    let w2 = CIO_POLYNOMIAL[2] + sum_cio_terms(&CIO_S2, &fa);
+    let w3 = CIO_POLYNOMIAL[3] + sum_cio_terms(&CIO_S3, &fa);
+    let w4 = CIO_POLYNOMIAL[4] + sum_cio_terms(&CIO_S4, &fa);
+    let w5 = CIO_POLYNOMIAL[5];
+
+    (w0 + (w1 + (w2 + (w3 + (w4 + w5 * t) * t) * t) * t) * t) * ARCSEC_TO_RAD - x * y / 2.0
+}
+
+/// Celestial-to-intermediate matrix C from the CIP coordinates and CIO locator
+///
+/// C = R₃(−(E + s)) · R₂(d) · R₃(E), with E = atan2(Y, X) and
+/// d = atan(√((X² + Y²) / (1 − X² − Y²))).
+///
+/// # Returns
+/// Rotation matrix GCRS → CIRS
+pub fn celestial_to_intermediate_matrix(x: f64, y: f64, s: f64) -> Matrix3<f64> {
+    let r2 = x * x + y * y;
+    let e = if r2 > 0.0 { y.atan2(x) } else { 0.0 };
+    let d = (r2 / (1.0 - r2)).sqrt().atan();
+
+    rotation_z(e + s) * rotation_y(-d) * rotation_z(-e)
+}
+
+/// Polar motion matrix W
+///
+/// W = R₁(−yp) · R₂(−xp) · R₃(s′) (IERS Conventions 2010, Eq. 5.3).
+///
+/// # Returns
+/// Rotation matrix TIRS → ITRS
+pub fn polar_motion_matrix(xp: f64, yp: f64, sp: f64) -> Matrix3<f64> {
+    rotation_x(yp) * rotation_y(xp) * rotation_z(-sp)
+}
+
+/// The three factors of the IERS 2010 celestial-to-terrestrial transformation
+///
+/// Holding the factors separately (instead of only their product) lets the
+/// velocity transformation apply Earth's rotation rate in the TIRS, where the
+/// angular velocity vector is aligned with the z axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TerrestrialRotation {
+    /// Celestial-to-intermediate matrix (GCRS → CIRS)
+    pub c2i: Matrix3<f64>,
+    /// Earth Rotation Angle (radians)
+    pub era: f64,
+    /// Polar motion matrix (TIRS → ITRS)
+    pub polar_motion: Matrix3<f64>,
+}
+
+impl TerrestrialRotation {
+    /// Build the GCRS ↔ ITRS transformation at `epoch`
+    ///
+    /// Precession-nutation uses [`iau2006_precession_nutation_matrix`] (IAU 2000A
+    /// when installed, otherwise IAU 2000B) corrected by the celestial pole
+    /// offsets in `eop`; Earth rotation uses UT1 = UTC + `eop.dut1`.
+    pub fn new(epoch: &Epoch, eop: &EarthOrientation) -> Self {
+        let (tt1, tt2) = epoch.to_jd_tt_two_part();
+
+        let npb = iau2006_precession_nutation_matrix(tt1, tt2);
+        let (x, y) = cip_xy(&npb);
+        let (x, y) = (x + eop.dx, y + eop.dy);
+        let s = cio_locator_s(tt1, tt2, x, y);
+        let c2i = celestial_to_intermediate_matrix(x, y, s);
+
+        let (ut1_1, ut1_2) = eop.ut1_two_part(epoch);
+        let era = earth_rotation_angle_ut1(ut1_1, ut1_2);
+
+        let sp = tio_locator_sp(tt1, tt2);
+        let polar_motion = polar_motion_matrix(eop.xp, eop.yp, sp);
+
+        Self { c2i, era, polar_motion }
+    }
+
+    /// Earth rotation matrix R₃(ERA) (CIRS → TIRS)
+    pub fn earth_rotation(&self) -> Matrix3<f64> {
+        rotation_z(-self.era)
+    }
+
+    /// Combined rotation matrix GCRS → ITRS
+    pub fn matrix(&self) -> Matrix3<f64> {
+        self.polar_motion * self.earth_rotation() * self.c2i
+    }
+
+    /// Transform a GCRS state into the ITRS
+    ///
+    /// The velocity includes the transport term −ω × r evaluated in the TIRS.
+    pub fn gcrs_to_itrs(
+        &self,
+        position: &Vector3<f64>,
+        velocity: &Vector3<f64>,
+    ) -> (Vector3<f64>, Vector3<f64>) {
+        let r = self.earth_rotation() * self.c2i;
+        let omega = Vector3::new(0.0, 0.0, EARTH_ANGULAR_VELOCITY);
+
+        let tirs_position = r * position;
+        let tirs_velocity = r * velocity - omega.cross(&tirs_position);
+
+        (self.polar_motion * tirs_position, self.polar_motion * tirs_velocity)
+    }
+
+    /// Transform an ITRS state into the GCRS
+    ///
+    /// The velocity includes the transport term +ω × r evaluated in the TIRS.
+    pub fn itrs_to_gcrs(
+        &self,
+        position: &Vector3<f64>,
+        velocity: &Vector3<f64>,
+    ) -> (Vector3<f64>, Vector3<f64>) {
+        let r_t = (self.earth_rotation() * self.c2i).transpose();
+        let w_t = self.polar_motion.transpose();
+        let omega = Vector3::new(0.0, 0.0, EARTH_ANGULAR_VELOCITY);
+
+        let tirs_position = w_t * position;
+        let tirs_velocity = w_t * velocity + omega.cross(&tirs_position);
+
+        (r_t * tirs_position, r_t * tirs_velocity)
+    }
+}
+
+/// Rotation matrix GCRS → ITRS at `epoch` (IERS Conventions 2010)
+///
+/// Convenience wrapper around [`TerrestrialRotation`].
+pub fn gcrs_to_itrs_matrix(epoch: &Epoch, eop: &EarthOrientation) -> Matrix3<f64> {
+    TerrestrialRotation::new(epoch, eop).matrix()
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)] // SOFA reference values are quoted verbatim
+mod tests {
+    use super::*;
+    use crate::coordinates::rotations::is_rotation_matrix;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_era_matches_sofa() {
+        // SOFA t_era00
+        let era = earth_rotation_angle_ut1(2400000.5, 54388.0);
+        assert_relative_eq!(era, 0.4022837240028158102, epsilon = 1e-12);
+
+        // Argument order must not matter
+        let era_swapped = earth_rotation_angle_ut1(54388.0, 2400000.5);
+        assert_relative_eq!(era_swapped, era, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_tio_locator_matches_sofa() {
+        // SOFA t_sp00
+        let sp = tio_locator_sp(2400000.5, 52541.0);
+        assert_relative_eq!(sp, -0.6216698469981019309e-11, epsilon = 1e-22);
+    }
+
+    #[test]
+    fn test_cio_locator_matches_sofa() {
+        // SOFA t_s06
+        let s = cio_locator_s(
+            2400000.5,
+            53736.0,
+            0.5791308486706011000e-3,
+            0.4020579816732961219e-4,
+        );
+        assert_relative_eq!(s, -0.1220032213076463117e-7, epsilon = 1e-18);
+    }
+
+    #[test]
+    fn test_cip_xy_matches_sofa() {
+        // SOFA t_xy06 reference is IAU 2000A; IAU 2000B agrees to ~1 mas
+        let npb = iau2006_precession_nutation_matrix(2400000.5, 53736.0);
+        let (x, y) = cip_xy(&npb);
+        assert_relative_eq!(x, 0.5791308486706010975e-3, epsilon = 1e-8);
+        assert_relative_eq!(y, 0.4020579816732958141e-4, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_celestial_to_intermediate_matches_sofa() {
+        // SOFA t_c2ixys
+        let c2i = celestial_to_intermediate_matrix(
+            0.5791308486706011000e-3,
+            0.4020579816732961219e-4,
+            -0.1220040848472271978e-7,
+        );
+        let expected = Matrix3::new(
+            0.9999998323037157138,
+            0.5581526349032241205e-9,
+            -0.5791308491611263745e-3,
+            -0.2384257057469842953e-7,
+            0.9999999991917468964,
+            -0.4020579110172324363e-4,
+            0.5791308486706011000e-3,
+            0.4020579816732961219e-4,
+            0.9999998314954627590,
+        );
+        assert_relative_eq!(c2i, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_polar_motion_matches_sofa() {
+        // SOFA t_pom00
+        let w = polar_motion_matrix(2.55060238e-7, 1.860359247e-6, -0.1367174580728891460e-10);
+        let expected = Matrix3::new(
+            0.9999999999999674721,
+            -0.1367174580728846989e-10,
+            0.2550602379999972345e-6,
+            0.1414624947957029801e-10,
+            0.9999999999982695317,
+            -0.1860359246998866389e-5,
+            -0.2550602379741215021e-6,
+            0.1860359247002414021e-5,
+            0.9999999999982370039,
+        );
+        assert_relative_eq!(w, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_ut1_two_part_applies_dut1() {
+        let epoch = Epoch::from_gregorian_utc(2024, 3, 1, 0, 0, 0, 0);
+        let eop = EarthOrientation::new(0.5, 0.0, 0.0, 0.0, 0.0);
+        let (ut1_1, ut1_2) = eop.ut1_two_part(&epoch);
+
+        assert_eq!(ut1_1, 2400000.5);
+        assert_relative_eq!(ut1_2, epoch.to_mjd_utc() + 0.5 / 86400.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_terrestrial_rotation_is_orthonormal() {
+        let epoch = Epoch::from_gregorian_utc(2024, 6, 15, 12, 0, 0, 0);
+        let eop = EarthOrientation::new(-0.02, 1.0e-6, 2.0e-6, 1.0e-9, -1.0e-9);
+        let m = gcrs_to_itrs_matrix(&epoch, &eop);
+        assert!(is_rotation_matrix(&m, 1e-12));
+    }
+
+    #[test]
+    fn test_prime_meridian_points_along_era() {
+        // The ITRS x axis lies in the CIRS at right ascension ERA (CIO-based),
+        // which is close to the GCRS x axis for present epochs
+        let epoch = Epoch::from_gregorian_utc(2024, 1, 1, 0, 0, 0, 0);
+        let rot = TerrestrialRotation::new(&epoch, &EarthOrientation::default());
+
+        let (r_gcrs, _) = rot.itrs_to_gcrs(&Vector3::new(1.0, 0.0, 0.0), &Vector3::zeros());
+        let ra = r_gcrs.y.atan2(r_gcrs.x).rem_euclid(2.0 * PI);
+
+        let diff = (ra - rot.era + PI).rem_euclid(2.0 * PI) - PI;
+        assert!(diff.abs() < 2e-3, "RA of prime meridian {} vs ERA {}", ra, rot.era);
+    }
+
+    #[test]
+    fn test_state_roundtrip() {
+        let epoch = Epoch::from_gregorian_utc(2025, 3, 20, 6, 30, 0, 0);
+        let eop = EarthOrientation::new(0.04, 5.0e-7, 1.5e-6, 0.0, 0.0);
+        let rot = TerrestrialRotation::new(&epoch, &eop);
+
+        let r = Vector3::new(-4500e3, 3200e3, 4100e3);
+        let v = Vector3::new(-2.1e3, -5.8e3, 3.9e3);
+        let (r_itrs, v_itrs) = rot.gcrs_to_itrs(&r, &v);
+        let (r_back, v_back) = rot.itrs_to_gcrs(&r_itrs, &v_itrs);
+
+        assert_relative_eq!(r_back, r, epsilon = 1e-6);
+        assert_relative_eq!(v_back, v, epsilon = 1e-9);
+        assert_relative_eq!(r_itrs, rot.matrix() * r, epsilon = 1e-6);
+    }
+}
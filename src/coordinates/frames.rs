@@ -5,21 +5,33 @@
 //! - GCRS (Geocentric Celestial Reference System) - Geocentric, inertial
 //! - ITRS (International Terrestrial Reference System) - Earth-fixed, rotating
 //! - TEME (True Equator Mean Equinox) - Geocentric, inertial (legacy SGP4 frame)
+//! - MOD / TOD (Mean / True Of Date) - Geocentric, equinox-based frames of date
+//! - CIRS / TIRS (Celestial / Terrestrial Intermediate) - Geocentric, CIO-based
+//!   intermediate frames between GCRS and ITRS
 //!
 //! # Reference Frame Relationships
 //!
 //! ## ICRS vs GCRS
 //! - **ICRS**: Origin at solar system barycenter, "space-fixed" axes
 //! - **GCRS**: Origin at Earth's center of mass, axes parallel to ICRS
-//! - **Relationship**: No rotation between frames, only translation by Earth's barycentric position
+//! - **Relationship**: No rotation between frames, only translation by Earth's barycentric
+//!   position and velocity (~1 AU, ~30 km/s)
 //! - **For satellite work**: GCRS is typically used (geocentric is more convenient)
 //!
 //! ## GCRS vs ITRS
 //! - **GCRS**: Geocentric, inertial (non-rotating with the stars)
 //! - **ITRS**: Geocentric, Earth-fixed (rotates with Earth)
-//! - **Relationship**: Rotation by Earth Rotation Angle (ERA) about polar axis
+//! - **Relationship**: IERS 2010 CIO-based chain W(t) · R₃(ERA) · C(t), i.e.
+//!   precession-nutation (CIP X, Y and CIO locator s), Earth rotation from UT1,
+//!   and polar motion (xp, yp and TIO locator s′)
 //! - **For ground stations**: ITRS is used (coordinates fixed to Earth's surface)
 //!
+//! ## Frames of Date
+//! - **MOD**: GCRS rotated by frame bias and IAU 2006 precession
+//! - **TOD**: MOD rotated by IAU 2000 nutation (z-axis along the CIP)
+//! - **CIRS**: GCRS rotated by C(t); same pole as TOD, x-axis at the CIO
+//! - **TIRS**: CIRS rotated by R₃(ERA); ITRS without polar motion
+//!
 //! ## TEME vs Other Frames
 //! - **TEME**: True Equator Mean Equinox - legacy geocentric inertial frame
 //! - **Usage**: Native output frame for SGP4/SDP4 propagators (TLE data)
@@ -28,13 +40,16 @@
 //! - **Historical**: Uses IAU 1982 definition of GMST (predates IAU 2000 ERA standard)
 //! - **Accuracy**: ~10-100 meters typical for satellite tracking applications
 //!
-//! ## Implementation Notes (Version 1)
-//! - ICRS ↔ GCRS: Simple implementation with identity rotation (axes aligned)
-//! - Barycentric correction for ICRS ↔ GCRS can be added later (typically ~1 AU offset)
-//! - GCRS ↔ ITRS: Simplified ERA-based rotation (no precession/nutation in V1)
-//! - Full IAU 2006/2000A precession-nutation models can be added later
-//! - Polar motion corrections can be added for ultra-high precision
-//! - TEME ↔ ITRS: GMST82-based rotation (no polar motion in V1)
+//! ## Implementation Notes
+//! - ICRS ↔ GCRS: Parallel axes; translation by Earth's barycentric position and
+//!   velocity from the active ephemeris source ([`crate::ephemeris`]).
+//!   Relativistic effects (aberration, light deflection) are not modelled.
+//! - GCRS ↔ ITRS: IERS 2010 CIO-based transformation, see
+//!   [`crate::coordinates::earth_orientation`]. The plain methods interpolate
+//!   Earth Orientation Parameters from the loaded IERS table
+//!   ([`crate::core::eop`]); `to_itrs_with_eop` / `to_gcrs_with_eop` take them
+//!   explicitly
+//! - TEME ↔ ITRS: GMST82-based rotation (no polar motion)
 //! - TEME uses legacy IAU 1982 GMST for SGP4/TLE compatibility
 //!
 //! # References
@@ -51,7 +66,15 @@ use numpy::{PyArray1, PyReadonlyArray1};
 use crate::core::PoliastroResult;
 use crate::core::time::Epoch;
 use hifitime::TimeScale;
+use crate::coordinates::earth_orientation::{
+    celestial_pole_offset_matrix, earth_rotation_angle_ut1, EarthOrientation,
+    TerrestrialRotation, EARTH_ANGULAR_VELOCITY,
+};
+use crate::coordinates::precession_nutation::{
+    iau2006_precession_matrix, iau2006_precession_nutation_matrix,
+};
 use crate::coordinates::rotations::{rotation_x, rotation_z};
+use crate::ephemeris::earth_barycentric_state;
 use std::f64::consts::PI;
 use rayon::prelude::*;
 
@@ -67,6 +90,10 @@ use rayon::prelude::*;
 /// - Position: Cartesian (x, y, z) in meters, barycentric
 /// - Velocity: Cartesian (vx, vy, vz) in m/s, barycentric
 ///
+/// # Frame Attributes
+/// - `obstime`: Epoch of the state (defaults to J2000.0), used to place the
+///   Earth when converting to GCRS
+///
 /// # Examples
 /// ```rust,ignore
 /// use nalgebra::Vector3;
@@ -84,6 +111,8 @@ pub struct ICRS {
     pub position: Vector3<f64>,
     /// Velocity vector in ICRS frame (m/s, barycentric)
     pub velocity: Vector3<f64>,
+    /// Epoch of the state
+    pub obstime: Epoch,
 }
 
 #[pymethods]
@@ -93,8 +122,14 @@ impl ICRS {
     /// # Arguments
     /// - `position`: Position vector in meters (barycentric) [x, y, z]
     /// - `velocity`: Velocity vector in m/s (barycentric) [vx, vy, vz]
+    /// - `obstime`: Epoch of the state (default: J2000.0)
     #[new]
-    pub fn py_new(position: PyReadonlyArray1<f64>, velocity: PyReadonlyArray1<f64>) -> PyResult<Self> {
+    #[pyo3(signature = (position, velocity, obstime=None))]
+    pub fn py_new(
+        position: PyReadonlyArray1<f64>,
+        velocity: PyReadonlyArray1<f64>,
+        obstime: Option<Epoch>,
+    ) -> PyResult<Self> {
         let pos_slice = position.as_slice()?;
         let vel_slice = velocity.as_slice()?;
 
@@ -107,6 +142,7 @@ impl ICRS {
         Ok(Self {
             position: Vector3::new(pos_slice[0], pos_slice[1], pos_slice[2]),
             velocity: Vector3::new(vel_slice[0], vel_slice[1], vel_slice[2]),
+            obstime: obstime.unwrap_or_else(Epoch::j2000),
         })
     }
 
@@ -122,31 +158,38 @@ impl ICRS {
         PyArray1::from_slice_bound(py, self.velocity.as_slice())
     }
 
+    /// Get the observation time
+    #[getter]
+    pub fn get_obstime(&self) -> Epoch {
+        self.obstime
+    }
+
     /// Convert to GCRS frame
     ///
-    /// This is a simple conversion that assumes the axes are aligned (which they are).
-    /// For full accuracy, a barycentric correction would be needed (Earth's position
-    /// relative to solar system barycenter, ~1 AU offset). For Earth satellite work,
-    /// this correction is typically negligible.
+    /// The axes are parallel; the origin moves from the solar system
+    /// barycenter to the geocenter by subtracting Earth's barycentric position
+    /// and velocity at `epoch`, taken from the active ephemeris source (see
+    /// [`crate::ephemeris`]).
     ///
     /// # Arguments
-    /// - `epoch`: Time of observation (for future barycentric corrections)
+    /// - `epoch`: Observation time of the resulting GCRS state
     pub fn to_gcrs(&self, epoch: &Epoch) -> PoliastroResult<GCRS> {
-        // Version 1: Simple conversion with aligned axes
-        // Future enhancement: Add barycentric correction using JPL ephemerides
+        let (earth_position, earth_velocity) = earth_barycentric_state(epoch)?;
         Ok(GCRS {
-            position: self.position,
-            velocity: self.velocity,
+            position: self.position - earth_position,
+            velocity: self.velocity - earth_velocity,
             obstime: *epoch,
         })
     }
 
     /// String representation
     fn __repr__(&self) -> String {
+        let (y, m, d, h, min, s, _) = self.obstime.to_gregorian_utc();
         format!(
-            "ICRS(position=[{:.3e}, {:.3e}, {:.3e}] m, velocity=[{:.3e}, {:.3e}, {:.3e}] m/s)",
+            "ICRS(position=[{:.3e}, {:.3e}, {:.3e}] m, velocity=[{:.3e}, {:.3e}, {:.3e}] m/s, obstime={:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z)",
             self.position.x, self.position.y, self.position.z,
-            self.velocity.x, self.velocity.y, self.velocity.z
+            self.velocity.x, self.velocity.y, self.velocity.z,
+            y, m, d, h, min, s
         )
     }
 }
@@ -157,18 +200,26 @@ impl ICRS {
     /// # Arguments
     /// - `position`: Position vector in meters (barycentric)
     /// - `velocity`: Velocity vector in m/s (barycentric)
+    ///
+    /// The state is taken to be at J2000.0; see [`ICRS::with_obstime`].
     pub fn new(position: Vector3<f64>, velocity: Vector3<f64>) -> Self {
-        Self { position, velocity }
+        Self::with_obstime(position, velocity, Epoch::j2000())
     }
 
-    /// Create ICRS from position only (zero velocity)
-    pub fn from_position(position: Vector3<f64>) -> Self {
+    /// Create a new ICRS coordinate frame at the given epoch
+    pub fn with_obstime(position: Vector3<f64>, velocity: Vector3<f64>, obstime: Epoch) -> Self {
         Self {
             position,
-            velocity: Vector3::zeros(),
+            velocity,
+            obstime,
         }
     }
 
+    /// Create ICRS from position only (zero velocity)
+    pub fn from_position(position: Vector3<f64>) -> Self {
+        Self::new(position, Vector3::zeros())
+    }
+
     /// Get the position vector
     pub fn position(&self) -> &Vector3<f64> {
         &self.position
@@ -178,6 +229,11 @@ impl ICRS {
     pub fn velocity(&self) -> &Vector3<f64> {
         &self.velocity
     }
+
+    /// Get the observation time
+    pub fn obstime(&self) -> &Epoch {
+        &self.obstime
+    }
 }
 
 /// Geocentric Celestial Reference System (GCRS)
@@ -267,57 +323,93 @@ impl GCRS {
 
     /// Convert to ICRS frame
     ///
-    /// This is a simple conversion that assumes the axes are aligned (which they are).
-    /// For full accuracy, a barycentric correction would be needed (Earth's position
-    /// relative to solar system barycenter). For Earth satellite work, this correction
-    /// is typically negligible.
+    /// The axes are parallel; the origin moves from the geocenter to the solar
+    /// system barycenter by adding Earth's barycentric position and velocity
+    /// at `obstime`, taken from the active ephemeris source (see
+    /// [`crate::ephemeris`]).
     pub fn to_icrs(&self) -> PoliastroResult<ICRS> {
-        // Version 1: Simple conversion with aligned axes
-        // Future enhancement: Add barycentric correction using JPL ephemerides
+        let (earth_position, earth_velocity) = earth_barycentric_state(&self.obstime)?;
         Ok(ICRS {
-            position: self.position,
-            velocity: self.velocity,
+            position: self.position + earth_position,
+            velocity: self.velocity + earth_velocity,
+            obstime: self.obstime,
         })
     }
 
     /// Convert to ITRS frame
     ///
     /// This transformation rotates the GCRS (inertial) coordinates into
-    /// ITRS (Earth-fixed) coordinates using the Earth Rotation Angle (ERA).
+    /// ITRS (Earth-fixed) coordinates using the IERS 2010 CIO-based chain:
+    /// precession-nutation (CIP X, Y and CIO locator s), Earth Rotation Angle
+    /// and polar motion.
     ///
-    /// # V1 Implementation
-    /// - Uses simplified ERA rotation about the polar (z) axis
-    /// - Ignores precession, nutation, and polar motion
-    /// - Suitable for most satellite tracking applications (10-100m accuracy)
+    /// Earth Orientation Parameters are interpolated from the installed EOP
+    /// table (see `load_eop`), or taken from the bundled data when
+    /// no table is loaded; use `to_itrs_with_eop` to supply values directly.
     ///
     /// # Returns
     /// ITRS coordinate frame at the same observation time
+    ///
+    /// # Errors
+    /// `ValueError` if the installed EOP table does not cover `obstime`
     pub fn to_itrs(&self) -> PoliastroResult<ITRS> {
-        // Calculate ERA at observation time
-        let era = earth_rotation_angle(&self.obstime);
-
-        // Rotation matrix: GCRS → ITRS is R_z(+ERA)
-        // (rotating FROM inertial TO Earth-fixed means rotating forwards)
-        let rotation = rotation_z(era);
-
-        // Transform position
-        let itrs_position = rotation * self.position;
+        self.to_itrs_with_eop(&EarthOrientation::at(&self.obstime)?)
+    }
 
-        // Transform velocity (includes Coriolis term from Earth's rotation)
-        // v_itrs = R * v_gcrs - ω × R * r_gcrs
-        // where ω is Earth's angular velocity vector [0, 0, ω_earth]
-        let omega_earth = 7.2921150e-5; // rad/s (from IERS)
-        let omega_vec = Vector3::new(0.0, 0.0, omega_earth);
+    /// Convert to MOD frame (mean equator and equinox of date)
+    ///
+    /// # Returns
+    /// MOD coordinate frame at the same observation time
+    pub fn to_mod(&self) -> PoliastroResult<MOD> {
+        let rotation = mean_of_date_matrix(&self.obstime);
+        Ok(MOD {
+            position: rotation * self.position,
+            velocity: rotation * self.velocity,
+            obstime: self.obstime,
+        })
+    }
 
-        let itrs_velocity = rotation * self.velocity - omega_vec.cross(&itrs_position);
+    /// Convert to TOD frame (true equator and equinox of date)
+    ///
+    /// The celestial pole offsets are taken from the installed EOP table, as
+    /// for CIRS.
+    ///
+    /// # Returns
+    /// TOD coordinate frame at the same observation time
+    ///
+    /// # Errors
+    /// `ValueError` if the installed EOP table does not cover `obstime`
+    pub fn to_tod(&self) -> PoliastroResult<TOD> {
+        self.to_tod_with_eop(&EarthOrientation::at(&self.obstime)?)
+    }
 
-        Ok(ITRS {
-            position: itrs_position,
-            velocity: itrs_velocity,
+    /// Convert to CIRS frame (CIP and CIO of date)
+    ///
+    /// # Returns
+    /// CIRS coordinate frame at the same observation time
+    ///
+    /// # Errors
+    /// `ValueError` if the installed EOP table does not cover `obstime`
+    pub fn to_cirs(&self) -> PoliastroResult<CIRS> {
+        let rotation = terrestrial_rotation(&self.obstime)?.c2i;
+        Ok(CIRS {
+            position: rotation * self.position,
+            velocity: rotation * self.velocity,
             obstime: self.obstime,
         })
     }
 
+    /// Convert to TIRS frame (GCRS → CIRS → TIRS)
+    ///
+    /// # Returns
+    /// TIRS coordinate frame at the same observation time
+    ///
+    /// # Errors
+    /// `ValueError` if the installed EOP table does not cover `obstime`
+    pub fn to_tirs(&self) -> PoliastroResult<TIRS> {
+        self.to_cirs()?.to_tirs()
+    }
+
     /// String representation
     fn __repr__(&self) -> String {
         let (y, m, d, h, min, s, _) = self.obstime.to_gregorian_utc();
@@ -377,6 +469,30 @@ impl GCRS {
     pub fn obstime(&self) -> &Epoch {
         &self.obstime
     }
+
+    /// Convert to ITRS frame using the given Earth Orientation Parameters
+    ///
+    /// The velocity includes the transport term −ω × r from Earth's rotation.
+    pub fn to_itrs_with_eop(&self, eop: &EarthOrientation) -> PoliastroResult<ITRS> {
+        let rotation = TerrestrialRotation::new(&self.obstime, eop);
+        let (position, velocity) = rotation.gcrs_to_itrs(&self.position, &self.velocity);
+
+        Ok(ITRS {
+            position,
+            velocity,
+            obstime: self.obstime,
+        })
+    }
+
+    /// Convert to TOD frame using the celestial pole offsets in `eop`
+    pub fn to_tod_with_eop(&self, eop: &EarthOrientation) -> PoliastroResult<TOD> {
+        let rotation = true_of_date_matrix(&self.obstime, eop);
+        Ok(TOD {
+            position: rotation * self.position,
+            velocity: rotation * self.velocity,
+            obstime: self.obstime,
+        })
+    }
 }
 
 /// Calculate Earth Rotation Angle (ERA) in radians
@@ -391,7 +507,7 @@ impl GCRS {
 /// where T_u = (JD_UT1 - 2451545.0) is the Julian date in UT1 minus J2000 epoch.
 ///
 /// # Arguments
-/// - `epoch`: Time at which to calculate ERA (should be in UT1, but UTC is acceptable for V1)
+/// - `epoch`: Time at which to calculate ERA (UT1 is approximated by UTC)
 ///
 /// # Returns
 /// Earth Rotation Angle in radians [0, 2π)
@@ -402,30 +518,11 @@ impl GCRS {
 /// - <https://www.celestialprogramming.com/snippets/greenwichMeanSiderealTime.html>>
 ///
 /// # Note
-/// This is a simplified V1 implementation. For highest precision:
-/// - Use UT1 time scale (requires Earth orientation parameters from IERS)
-/// - Add TIO (Terrestrial Intermediate Origin) locator corrections
-/// - The difference between UTC and UT1 is typically < 1 second (DUT1)
+/// The difference between UTC and UT1 (DUT1) is below 0.9 s. To account for it,
+/// use [`earth_rotation_angle_ut1`] with [`EarthOrientation::ut1_two_part`].
 pub fn earth_rotation_angle(epoch: &Epoch) -> f64 {
-    // Constants from IERS Conventions (2010)
-    const ERA_OFFSET: f64 = 0.7790572732640; // Radians at J2000
-    const ERA_RATE: f64 = 1.002_737_811_911_354_6; // Rotations per UT1 day
-
-    // Get Julian date in UT1 (approximated by UTC for V1)
-    // hifitime provides JD in the epoch's current time scale
-    let jd_ut1 = epoch.to_time_scale(hifitime::TimeScale::UTC).to_jd_utc();
-
-    // Days since J2000 epoch (JD 2451545.0)
-    let t_u = jd_ut1 - 2451545.0;
-
-    // Calculate ERA in rotations, then convert to radians
-    let era_rotations = ERA_OFFSET + ERA_RATE * t_u;
-
-    // Normalize to [0, 1) rotations, then convert to [0, 2π) radians
-    let era_normalized = era_rotations - era_rotations.floor();
-    
-
-    2.0 * PI * era_normalized
+    let (ut1_1, ut1_2) = EarthOrientation::default().ut1_two_part(epoch);
+    earth_rotation_angle_ut1(ut1_1, ut1_2)
 }
 
 /// Calculate Greenwich Mean Sidereal Time (GMST) using IAU 1982 model
@@ -514,18 +611,16 @@ pub fn greenwich_mean_sidereal_time_82(epoch: &Epoch) -> f64 {
 /// let frame = ITRS::new(position, velocity, epoch);
 /// ```
 ///
-/// # V1 Implementation Notes
-/// This is a simplified implementation that:
-/// - Uses ERA (Earth Rotation Angle) for GCRS ↔ ITRS rotation
-/// - Ignores precession and nutation (small effect for short timespans)
-/// - Ignores polar motion (< 1 meter effect)
-/// - Accuracy: ~10-100 meters for typical satellite applications
+/// # Implementation Notes
+/// GCRS ↔ ITRS follows the IERS Conventions (2010) CIO-based transformation:
+/// - CIP (Celestial Intermediate Pole) coordinates X, Y from IAU 2006/2000
+///   precession-nutation, plus optional celestial pole offsets dX, dY
+/// - CIO locator s and Earth Rotation Angle from UT1
+/// - Polar motion xp, yp and TIO (Terrestrial Intermediate Origin) locator s′
 ///
-/// Future enhancements for higher precision:
-/// - IAU 2006/2000A precession-nutation models
-/// - Polar motion corrections from IERS EOP data
-/// - TIO (Terrestrial Intermediate Origin) locator
-/// - CIP (Celestial Intermediate Pole) position
+/// Earth Orientation Parameters come from the loaded IERS table; without one,
+/// UT1 − UTC (< 0.9 s) is neglected and polar motion (~10 m at the surface)
+/// follows a secular model.
 #[pyclass(module = "astrora._core")]
 #[derive(Debug, Clone, PartialEq)]
 pub struct ITRS {
@@ -601,9 +696,9 @@ impl ITRS {
         // Calculate GMST82 at observation time
         let gmst = greenwich_mean_sidereal_time_82(&self.obstime);
 
-        // Rotation matrix: ITRS → TEME is R_z(-GMST)
-        // (rotating FROM Earth-fixed TO inertial means rotating backwards)
-        let rotation = rotation_z(-gmst);
+        // Rotation matrix: ITRS → TEME is the transpose of the passive R₃(GMST),
+        // i.e. an active rotation by +GMST about the pole
+        let rotation = rotation_z(gmst);
 
         // Transform position
         let teme_position = rotation * self.position;
@@ -625,38 +720,696 @@ impl ITRS {
 
     /// Convert to GCRS frame
     ///
-    /// This transformation rotates the ITRS (Earth-fixed) coordinates into
-    /// GCRS (inertial) coordinates using the Earth Rotation Angle (ERA).
-    ///
-    /// # V1 Implementation
-    /// - Uses simplified ERA rotation about the polar (z) axis
-    /// - Ignores precession, nutation, and polar motion
-    /// - Suitable for most satellite tracking applications (10-100m accuracy)
-    ///
+    /// This transformation rotates the ITRS (Earth-fixed) coordinates into
+    /// GCRS (inertial) coordinates using the IERS 2010 CIO-based chain
+    /// (inverse of `GCRS.to_itrs`).
+    ///
+    /// Earth Orientation Parameters are interpolated from the installed EOP
+    /// table (see `load_eop`), or taken from the bundled data when
+    /// no table is loaded; use `to_gcrs_with_eop` to supply values directly.
+    ///
+    /// # Returns
+    /// GCRS coordinate frame at the same observation time
+    ///
+    /// # Errors
+    /// `ValueError` if the installed EOP table does not cover `obstime`
+    pub fn to_gcrs(&self) -> PoliastroResult<GCRS> {
+        self.to_gcrs_with_eop(&EarthOrientation::at(&self.obstime)?)
+    }
+
+    /// Convert to TIRS frame (removes polar motion)
+    ///
+    /// # Returns
+    /// TIRS coordinate frame at the same observation time
+    ///
+    /// # Errors
+    /// `ValueError` if the installed EOP table does not cover `obstime`
+    pub fn to_tirs(&self) -> PoliastroResult<TIRS> {
+        let rotation = terrestrial_rotation(&self.obstime)?.polar_motion.transpose();
+        Ok(TIRS {
+            position: rotation * self.position,
+            velocity: rotation * self.velocity,
+            obstime: self.obstime,
+        })
+    }
+
+    /// String representation
+    fn __repr__(&self) -> String {
+        let (y, m, d, h, min, s, _) = self.obstime.to_gregorian_utc();
+        format!(
+            "ITRS(position=[{:.3e}, {:.3e}, {:.3e}] m, velocity=[{:.3e}, {:.3e}, {:.3e}] m/s, obstime={:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z)",
+            self.position.x, self.position.y, self.position.z,
+            self.velocity.x, self.velocity.y, self.velocity.z,
+            y, m, d, h, min, s
+        )
+    }
+}
+
+impl ITRS {
+    /// Create a new ITRS coordinate frame (internal Rust API)
+    ///
+    /// # Arguments
+    /// - `position`: Position vector in meters (Earth-fixed)
+    /// - `velocity`: Velocity vector in m/s (Earth-fixed)
+    /// - `obstime`: Observation epoch
+    pub fn new(position: Vector3<f64>, velocity: Vector3<f64>, obstime: Epoch) -> Self {
+        Self {
+            position,
+            velocity,
+            obstime,
+        }
+    }
+
+    /// Create ITRS from position only (zero velocity)
+    pub fn from_position(position: Vector3<f64>, obstime: Epoch) -> Self {
+        Self {
+            position,
+            velocity: Vector3::zeros(),
+            obstime,
+        }
+    }
+
+    /// Get the position vector
+    pub fn position(&self) -> &Vector3<f64> {
+        &self.position
+    }
+
+    /// Get the velocity vector
+    pub fn velocity(&self) -> &Vector3<f64> {
+        &self.velocity
+    }
+
+    /// Get the observation time
+    pub fn obstime(&self) -> &Epoch {
+        &self.obstime
+    }
+
+    /// Convert to GCRS frame using the given Earth Orientation Parameters
+    ///
+    /// The velocity includes the transport term +ω × r from Earth's rotation.
+    pub fn to_gcrs_with_eop(&self, eop: &EarthOrientation) -> PoliastroResult<GCRS> {
+        let rotation = TerrestrialRotation::new(&self.obstime, eop);
+        let (position, velocity) = rotation.itrs_to_gcrs(&self.position, &self.velocity);
+
+        Ok(GCRS {
+            position,
+            velocity,
+            obstime: self.obstime,
+        })
+    }
+}
+
+/// True Equator Mean Equinox (TEME) Frame
+///
+/// TEME is a geocentric inertial reference frame defined by the true equator
+/// (instantaneous Earth rotation axis) and mean equinox (average position of
+/// vernal equinox). It is the native output frame for SGP4/SDP4 propagators
+/// used with Two-Line Element (TLE) sets.
+///
+/// # Characteristics
+/// - Geocentric: Origin at Earth's center of mass
+/// - Inertial: Does not rotate with Earth (but uses mean equinox, not true)
+/// - Legacy: Defined by IAU 1982 standards (predates modern IAU 2000/2006)
+/// - Application: SGP4/SDP4 satellite propagation from TLE data
+///
+/// # Coordinates
+/// - Position: Cartesian (x, y, z) in meters, geocentric
+/// - Velocity: Cartesian (vx, vy, vz) in m/s, geocentric
+///
+/// # Frame Attributes
+/// - `obstime`: Observation epoch (for time-dependent transformations)
+///
+/// # Historical Context
+/// The TEME frame uses the Greenwich Mean Sidereal Time (GMST) based on the
+/// IAU 1982 model. This differs from modern frames like GCRS which use the
+/// Earth Rotation Angle (ERA) from IAU 2000. The TEME definition is maintained
+/// for backward compatibility with legacy TLE data and SGP4 propagation.
+///
+/// # Examples
+/// ```rust,ignore
+/// use nalgebra::Vector3;
+/// use astrora_core::coordinates::frames::TEME;
+/// use astrora_core::core::time::Epoch;
+///
+/// // Create TEME coordinate from SGP4 output
+/// let position = Vector3::new(7000000.0, 0.0, 0.0);
+/// let velocity = Vector3::new(0.0, 7500.0, 0.0);
+/// let epoch = Epoch::from_gregorian_utc(2024, 1, 1, 0, 0, 0, 0);
+/// let frame = TEME::new(position, velocity, epoch);
+/// ```
+///
+/// # V1 Implementation Notes
+/// This is a simplified implementation that:
+/// - Uses GMST82 (IAU 1982) for TEME ↔ ITRS rotation
+/// - Ignores polar motion (< 1 meter effect)
+/// - Accuracy: ~10-100 meters for typical satellite tracking
+///
+/// Future enhancements:
+/// - Polar motion corrections from IERS EOP data
+/// - Full compatibility validation with Vallado's SGP4 implementation
+///
+/// # References
+/// - Vallado et al. (2006), "Revisiting Spacetrack Report #3", AIAA 2006-6753
+/// - Vallado, "Fundamentals of Astrodynamics and Applications", 4th Ed., Ch. 3
+/// - <https://celestrak.org/publications/AIAA/2006-6753/>
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TEME {
+    /// Position vector in TEME frame (meters, geocentric)
+    pub position: Vector3<f64>,
+    /// Velocity vector in TEME frame (m/s, geocentric)
+    pub velocity: Vector3<f64>,
+    /// Observation time (for transformations to other frames)
+    pub obstime: Epoch,
+}
+
+#[pymethods]
+impl TEME {
+    /// Create a new TEME coordinate frame
+    ///
+    /// # Arguments
+    /// - `position`: Position vector in meters (geocentric) [x, y, z]
+    /// - `velocity`: Velocity vector in m/s (geocentric) [vx, vy, vz]
+    /// - `obstime`: Observation epoch
+    #[new]
+    pub fn py_new(
+        position: PyReadonlyArray1<f64>,
+        velocity: PyReadonlyArray1<f64>,
+        obstime: Epoch,
+    ) -> PyResult<Self> {
+        let pos_slice = position.as_slice()?;
+        let vel_slice = velocity.as_slice()?;
+
+        if pos_slice.len() != 3 || vel_slice.len() != 3 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Position and velocity must be 3-element arrays"
+            ));
+        }
+
+        Ok(Self {
+            position: Vector3::new(pos_slice[0], pos_slice[1], pos_slice[2]),
+            velocity: Vector3::new(vel_slice[0], vel_slice[1], vel_slice[2]),
+            obstime,
+        })
+    }
+
+    /// Get the position vector
+    #[getter]
+    pub fn get_position<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.position.as_slice())
+    }
+
+    /// Get the velocity vector
+    #[getter]
+    pub fn get_velocity<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.velocity.as_slice())
+    }
+
+    /// Get the observation time
+    #[getter]
+    pub fn get_obstime(&self) -> Epoch {
+        self.obstime
+    }
+
+    /// Convert to ITRS frame
+    ///
+    /// This transformation rotates the TEME (inertial) coordinates into
+    /// ITRS (Earth-fixed) coordinates using Greenwich Mean Sidereal Time (GMST82).
+    ///
+    /// # V1 Implementation
+    /// - Uses IAU 1982 GMST rotation about the polar (z) axis
+    /// - Ignores polar motion corrections
+    /// - Suitable for TLE-based satellite tracking (~10-100m accuracy)
+    ///
+    /// # Returns
+    /// ITRS coordinate frame at the same observation time
+    pub fn to_itrs(&self) -> PoliastroResult<ITRS> {
+        // Calculate GMST82 at observation time
+        let gmst = greenwich_mean_sidereal_time_82(&self.obstime);
+
+        // Rotation matrix: TEME → ITRS is the passive R₃(GMST),
+        // i.e. an active rotation by −GMST about the pole
+        let rotation = rotation_z(-gmst);
+
+        // Transform position
+        let itrs_position = rotation * self.position;
+
+        // Transform velocity (includes Coriolis term from Earth's rotation)
+        // v_itrs = R * v_teme - ω × R * r_teme
+        // where ω is Earth's angular velocity vector [0, 0, ω_earth]
+        let omega_earth = 7.2921150e-5; // rad/s (from IERS)
+        let omega_vec = Vector3::new(0.0, 0.0, omega_earth);
+
+        let itrs_velocity = rotation * self.velocity - omega_vec.cross(&itrs_position);
+
+        Ok(ITRS {
+            position: itrs_position,
+            velocity: itrs_velocity,
+            obstime: self.obstime,
+        })
+    }
+
+    /// Convert to GCRS frame
+    ///
+    /// This transformation converts TEME coordinates to GCRS by chaining
+    /// through ITRS: TEME → ITRS → GCRS.
+    ///
+    /// # V1 Implementation
+    /// - Uses GMST82 for TEME → ITRS
+    /// - Uses ERA for ITRS → GCRS
+    /// - Combined accuracy: ~10-100 meters
+    ///
+    /// # Returns
+    /// GCRS coordinate frame at the same observation time
+    pub fn to_gcrs(&self) -> PoliastroResult<GCRS> {
+        // Chain transformations: TEME → ITRS → GCRS
+        let itrs = self.to_itrs()?;
+        itrs.to_gcrs()
+    }
+
+    /// String representation
+    fn __repr__(&self) -> String {
+        let (y, m, d, h, min, s, _) = self.obstime.to_gregorian_utc();
+        format!(
+            "TEME(position=[{:.3e}, {:.3e}, {:.3e}] m, velocity=[{:.3e}, {:.3e}, {:.3e}] m/s, obstime={:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z)",
+            self.position.x, self.position.y, self.position.z,
+            self.velocity.x, self.velocity.y, self.velocity.z,
+            y, m, d, h, min, s
+        )
+    }
+}
+
+impl TEME {
+    /// Create a new TEME coordinate frame (internal Rust API)
+    ///
+    /// # Arguments
+    /// - `position`: Position vector in meters (geocentric)
+    /// - `velocity`: Velocity vector in m/s (geocentric)
+    /// - `obstime`: Observation epoch
+    pub fn new(position: Vector3<f64>, velocity: Vector3<f64>, obstime: Epoch) -> Self {
+        Self {
+            position,
+            velocity,
+            obstime,
+        }
+    }
+
+    /// Create TEME from position only (zero velocity)
+    pub fn from_position(position: Vector3<f64>, obstime: Epoch) -> Self {
+        Self {
+            position,
+            velocity: Vector3::zeros(),
+            obstime,
+        }
+    }
+
+    /// Get the position vector
+    pub fn position(&self) -> &Vector3<f64> {
+        &self.position
+    }
+
+    /// Get the velocity vector
+    pub fn velocity(&self) -> &Vector3<f64> {
+        &self.velocity
+    }
+
+    /// Get the observation time
+    pub fn obstime(&self) -> &Epoch {
+        &self.obstime
+    }
+}
+
+/// Mean Of Date (MOD) Frame
+///
+/// MOD is a geocentric frame aligned with the mean equator and mean equinox of
+/// date: the GCRS rotated by frame bias and IAU 2006 precession, without
+/// nutation.
+///
+/// # Characteristics
+/// - Geocentric: Origin at Earth's center of mass
+/// - Quasi-inertial: Axes follow precession only (~50″/year)
+/// - Equinox-based: x-axis toward the mean equinox of date
+///
+/// # Frame Attributes
+/// - `obstime`: Epoch defining the mean equator and equinox
+///
+/// # Implementation Notes
+/// - GCRS → MOD applies the IAU 2006 bias-precession matrix (Fukushima-Williams)
+/// - The slow rotation rate of the axes is neglected in the velocity transform
+///
+/// # References
+/// - IERS Conventions (2010), Chapter 5.6
+/// - Vallado, "Fundamentals of Astrodynamics and Applications", 4th Ed., Ch. 3.7
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MOD {
+    /// Position vector in MOD frame (meters, geocentric)
+    pub position: Vector3<f64>,
+    /// Velocity vector in MOD frame (m/s, geocentric)
+    pub velocity: Vector3<f64>,
+    /// Observation time (defines the mean equator and equinox)
+    pub obstime: Epoch,
+}
+
+#[pymethods]
+impl MOD {
+    /// Create a new MOD coordinate frame
+    ///
+    /// # Arguments
+    /// - `position`: Position vector in meters (geocentric) [x, y, z]
+    /// - `velocity`: Velocity vector in m/s (geocentric) [vx, vy, vz]
+    /// - `obstime`: Observation epoch
+    #[new]
+    pub fn py_new(
+        position: PyReadonlyArray1<f64>,
+        velocity: PyReadonlyArray1<f64>,
+        obstime: Epoch,
+    ) -> PyResult<Self> {
+        let (position, velocity) = state_from_arrays(&position, &velocity)?;
+        Ok(Self::new(position, velocity, obstime))
+    }
+
+    /// Get the position vector
+    #[getter]
+    pub fn get_position<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.position.as_slice())
+    }
+
+    /// Get the velocity vector
+    #[getter]
+    pub fn get_velocity<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.velocity.as_slice())
+    }
+
+    /// Get the observation time
+    #[getter]
+    pub fn get_obstime(&self) -> Epoch {
+        self.obstime
+    }
+
+    /// Convert to GCRS frame (inverse bias-precession)
+    ///
+    /// # Returns
+    /// GCRS coordinate frame at the same observation time
+    pub fn to_gcrs(&self) -> PoliastroResult<GCRS> {
+        let rotation = mean_of_date_matrix(&self.obstime).transpose();
+        Ok(GCRS {
+            position: rotation * self.position,
+            velocity: rotation * self.velocity,
+            obstime: self.obstime,
+        })
+    }
+
+    /// Convert to TOD frame (applies nutation)
+    ///
+    /// # Returns
+    /// TOD coordinate frame at the same observation time
+    pub fn to_tod(&self) -> PoliastroResult<TOD> {
+        self.to_gcrs()?.to_tod()
+    }
+
+    /// String representation
+    fn __repr__(&self) -> String {
+        let (y, m, d, h, min, s, _) = self.obstime.to_gregorian_utc();
+        format!(
+            "MOD(position=[{:.3e}, {:.3e}, {:.3e}] m, velocity=[{:.3e}, {:.3e}, {:.3e}] m/s, obstime={:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z)",
+            self.position.x, self.position.y, self.position.z,
+            self.velocity.x, self.velocity.y, self.velocity.z,
+            y, m, d, h, min, s
+        )
+    }
+}
+
+impl MOD {
+    /// Create a new MOD coordinate frame (internal Rust API)
+    ///
+    /// # Arguments
+    /// - `position`: Position vector in meters (geocentric)
+    /// - `velocity`: Velocity vector in m/s (geocentric)
+    /// - `obstime`: Observation epoch
+    pub fn new(position: Vector3<f64>, velocity: Vector3<f64>, obstime: Epoch) -> Self {
+        Self {
+            position,
+            velocity,
+            obstime,
+        }
+    }
+
+    /// Get the position vector
+    pub fn position(&self) -> &Vector3<f64> {
+        &self.position
+    }
+
+    /// Get the velocity vector
+    pub fn velocity(&self) -> &Vector3<f64> {
+        &self.velocity
+    }
+
+    /// Get the observation time
+    pub fn obstime(&self) -> &Epoch {
+        &self.obstime
+    }
+}
+
+/// True Of Date (TOD) Frame
+///
+/// TOD is a geocentric frame aligned with the true equator and true equinox of
+/// date: the GCRS rotated by frame bias, IAU 2006 precession and IAU 2000
+/// nutation. Its z-axis is the CIP.
+///
+/// # Characteristics
+/// - Geocentric: Origin at Earth's center of mass
+/// - Quasi-inertial: Axes follow precession and nutation
+/// - Equinox-based: x-axis toward the true equinox of date
+///
+/// # Frame Attributes
+/// - `obstime`: Epoch defining the true equator and equinox
+///
+/// # Implementation Notes
+/// - GCRS → TOD applies [`iau2006_precession_nutation_matrix`] (IAU 2000A
+///   nutation when installed, otherwise IAU 2000B)
+/// - The EOP celestial pole offsets (dX, dY) are applied as for CIRS, so both
+///   frames share the same corrected CIP
+/// - The slow rotation rate of the axes is neglected in the velocity transform
+///
+/// # References
+/// - IERS Conventions (2010), Chapter 5.6
+/// - Vallado, "Fundamentals of Astrodynamics and Applications", 4th Ed., Ch. 3.7
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TOD {
+    /// Position vector in TOD frame (meters, geocentric)
+    pub position: Vector3<f64>,
+    /// Velocity vector in TOD frame (m/s, geocentric)
+    pub velocity: Vector3<f64>,
+    /// Observation time (defines the true equator and equinox)
+    pub obstime: Epoch,
+}
+
+#[pymethods]
+impl TOD {
+    /// Create a new TOD coordinate frame
+    ///
+    /// # Arguments
+    /// - `position`: Position vector in meters (geocentric) [x, y, z]
+    /// - `velocity`: Velocity vector in m/s (geocentric) [vx, vy, vz]
+    /// - `obstime`: Observation epoch
+    #[new]
+    pub fn py_new(
+        position: PyReadonlyArray1<f64>,
+        velocity: PyReadonlyArray1<f64>,
+        obstime: Epoch,
+    ) -> PyResult<Self> {
+        let (position, velocity) = state_from_arrays(&position, &velocity)?;
+        Ok(Self::new(position, velocity, obstime))
+    }
+
+    /// Get the position vector
+    #[getter]
+    pub fn get_position<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.position.as_slice())
+    }
+
+    /// Get the velocity vector
+    #[getter]
+    pub fn get_velocity<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.velocity.as_slice())
+    }
+
+    /// Get the observation time
+    #[getter]
+    pub fn get_obstime(&self) -> Epoch {
+        self.obstime
+    }
+
+    /// Convert to GCRS frame (inverse precession-nutation)
+    ///
+    /// # Returns
+    /// GCRS coordinate frame at the same observation time
+    ///
+    /// # Errors
+    /// `ValueError` if the installed EOP table does not cover `obstime`
+    pub fn to_gcrs(&self) -> PoliastroResult<GCRS> {
+        self.to_gcrs_with_eop(&EarthOrientation::at(&self.obstime)?)
+    }
+
+    /// Convert to MOD frame (removes nutation)
+    ///
+    /// # Returns
+    /// MOD coordinate frame at the same observation time
+    pub fn to_mod(&self) -> PoliastroResult<MOD> {
+        self.to_gcrs()?.to_mod()
+    }
+
+    /// String representation
+    fn __repr__(&self) -> String {
+        let (y, m, d, h, min, s, _) = self.obstime.to_gregorian_utc();
+        format!(
+            "TOD(position=[{:.3e}, {:.3e}, {:.3e}] m, velocity=[{:.3e}, {:.3e}, {:.3e}] m/s, obstime={:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z)",
+            self.position.x, self.position.y, self.position.z,
+            self.velocity.x, self.velocity.y, self.velocity.z,
+            y, m, d, h, min, s
+        )
+    }
+}
+
+impl TOD {
+    /// Create a new TOD coordinate frame (internal Rust API)
+    ///
+    /// # Arguments
+    /// - `position`: Position vector in meters (geocentric)
+    /// - `velocity`: Velocity vector in m/s (geocentric)
+    /// - `obstime`: Observation epoch
+    pub fn new(position: Vector3<f64>, velocity: Vector3<f64>, obstime: Epoch) -> Self {
+        Self {
+            position,
+            velocity,
+            obstime,
+        }
+    }
+
+    /// Get the position vector
+    pub fn position(&self) -> &Vector3<f64> {
+        &self.position
+    }
+
+    /// Get the velocity vector
+    pub fn velocity(&self) -> &Vector3<f64> {
+        &self.velocity
+    }
+
+    /// Get the observation time
+    pub fn obstime(&self) -> &Epoch {
+        &self.obstime
+    }
+
+    /// Convert to GCRS frame using the celestial pole offsets in `eop`
+    pub fn to_gcrs_with_eop(&self, eop: &EarthOrientation) -> PoliastroResult<GCRS> {
+        let rotation = true_of_date_matrix(&self.obstime, eop).transpose();
+        Ok(GCRS {
+            position: rotation * self.position,
+            velocity: rotation * self.velocity,
+            obstime: self.obstime,
+        })
+    }
+}
+
+/// Celestial Intermediate Reference System (CIRS)
+///
+/// CIRS is the geocentric frame of the IERS 2010 CIO-based chain between GCRS
+/// and TIRS: its z-axis is the CIP and its x-axis the Celestial Intermediate
+/// Origin (CIO). It is the CIO-based counterpart of TOD; the two differ by a
+/// rotation about the pole through the equation of the origins.
+///
+/// # Frame Attributes
+/// - `obstime`: Epoch defining the CIP and CIO
+///
+/// # Implementation Notes
+/// - GCRS → CIRS applies the celestial-to-intermediate matrix built from the
+///   CIP X, Y (corrected by the EOP celestial pole offsets) and CIO locator s
+/// - The slow rotation rate of the axes is neglected in the velocity transform
+///
+/// # References
+/// - IERS Conventions (2010), Chapter 5.4
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CIRS {
+    /// Position vector in CIRS frame (meters, geocentric)
+    pub position: Vector3<f64>,
+    /// Velocity vector in CIRS frame (m/s, geocentric)
+    pub velocity: Vector3<f64>,
+    /// Observation time (defines the CIP and CIO)
+    pub obstime: Epoch,
+}
+
+#[pymethods]
+impl CIRS {
+    /// Create a new CIRS coordinate frame
+    ///
+    /// # Arguments
+    /// - `position`: Position vector in meters (geocentric) [x, y, z]
+    /// - `velocity`: Velocity vector in m/s (geocentric) [vx, vy, vz]
+    /// - `obstime`: Observation epoch
+    #[new]
+    pub fn py_new(
+        position: PyReadonlyArray1<f64>,
+        velocity: PyReadonlyArray1<f64>,
+        obstime: Epoch,
+    ) -> PyResult<Self> {
+        let (position, velocity) = state_from_arrays(&position, &velocity)?;
+        Ok(Self::new(position, velocity, obstime))
+    }
+
+    /// Get the position vector
+    #[getter]
+    pub fn get_position<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.position.as_slice())
+    }
+
+    /// Get the velocity vector
+    #[getter]
+    pub fn get_velocity<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.velocity.as_slice())
+    }
+
+    /// Get the observation time
+    #[getter]
+    pub fn get_obstime(&self) -> Epoch {
+        self.obstime
+    }
+
+    /// Convert to GCRS frame
+    ///
     /// # Returns
     /// GCRS coordinate frame at the same observation time
+    ///
+    /// # Errors
+    /// `ValueError` if the installed EOP table does not cover `obstime`
     pub fn to_gcrs(&self) -> PoliastroResult<GCRS> {
-        // Calculate ERA at observation time
-        let era = earth_rotation_angle(&self.obstime);
-
-        // Rotation matrix: ITRS → GCRS is R_z(-ERA)
-        // (rotating FROM Earth-fixed TO inertial means rotating backwards)
-        let rotation = rotation_z(-era);
-
-        // Transform position
-        let gcrs_position = rotation * self.position;
+        let rotation = terrestrial_rotation(&self.obstime)?.c2i.transpose();
+        Ok(GCRS {
+            position: rotation * self.position,
+            velocity: rotation * self.velocity,
+            obstime: self.obstime,
+        })
+    }
 
-        // Transform velocity (includes Coriolis term from Earth's rotation)
-        // v_gcrs = R * v_itrs + ω × R * r_itrs
-        // where ω is Earth's angular velocity vector [0, 0, ω_earth]
-        let omega_earth = 7.2921150e-5; // rad/s (from IERS)
-        let omega_vec = Vector3::new(0.0, 0.0, omega_earth);
+    /// Convert to TIRS frame (Earth Rotation Angle about the CIP)
+    ///
+    /// The velocity includes the transport term −ω × r from Earth's rotation.
+    ///
+    /// # Returns
+    /// TIRS coordinate frame at the same observation time
+    pub fn to_tirs(&self) -> PoliastroResult<TIRS> {
+        let rotation = terrestrial_rotation(&self.obstime)?.earth_rotation();
+        let omega = Vector3::new(0.0, 0.0, EARTH_ANGULAR_VELOCITY);
 
-        let gcrs_velocity = rotation * self.velocity + omega_vec.cross(&gcrs_position);
+        let position = rotation * self.position;
+        let velocity = rotation * self.velocity - omega.cross(&position);
 
-        Ok(GCRS {
-            position: gcrs_position,
-            velocity: gcrs_velocity,
+        Ok(TIRS {
+            position,
+            velocity,
             obstime: self.obstime,
         })
     }
@@ -665,7 +1418,7 @@ impl ITRS {
     fn __repr__(&self) -> String {
         let (y, m, d, h, min, s, _) = self.obstime.to_gregorian_utc();
         format!(
-            "ITRS(position=[{:.3e}, {:.3e}, {:.3e}] m, velocity=[{:.3e}, {:.3e}, {:.3e}] m/s, obstime={:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z)",
+            "CIRS(position=[{:.3e}, {:.3e}, {:.3e}] m, velocity=[{:.3e}, {:.3e}, {:.3e}] m/s, obstime={:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z)",
             self.position.x, self.position.y, self.position.z,
             self.velocity.x, self.velocity.y, self.velocity.z,
             y, m, d, h, min, s
@@ -673,12 +1426,12 @@ impl ITRS {
     }
 }
 
-impl ITRS {
-    /// Create a new ITRS coordinate frame (internal Rust API)
+impl CIRS {
+    /// Create a new CIRS coordinate frame (internal Rust API)
     ///
     /// # Arguments
-    /// - `position`: Position vector in meters (Earth-fixed)
-    /// - `velocity`: Velocity vector in m/s (Earth-fixed)
+    /// - `position`: Position vector in meters (geocentric)
+    /// - `velocity`: Velocity vector in m/s (geocentric)
     /// - `obstime`: Observation epoch
     pub fn new(position: Vector3<f64>, velocity: Vector3<f64>, obstime: Epoch) -> Self {
         Self {
@@ -688,15 +1441,6 @@ impl ITRS {
         }
     }
 
-    /// Create ITRS from position only (zero velocity)
-    pub fn from_position(position: Vector3<f64>, obstime: Epoch) -> Self {
-        Self {
-            position,
-            velocity: Vector3::zeros(),
-            obstime,
-        }
-    }
-
     /// Get the position vector
     pub fn position(&self) -> &Vector3<f64> {
         &self.position
@@ -713,77 +1457,41 @@ impl ITRS {
     }
 }
 
-/// True Equator Mean Equinox (TEME) Frame
-///
-/// TEME is a geocentric inertial reference frame defined by the true equator
-/// (instantaneous Earth rotation axis) and mean equinox (average position of
-/// vernal equinox). It is the native output frame for SGP4/SDP4 propagators
-/// used with Two-Line Element (TLE) sets.
-///
-/// # Characteristics
-/// - Geocentric: Origin at Earth's center of mass
-/// - Inertial: Does not rotate with Earth (but uses mean equinox, not true)
-/// - Legacy: Defined by IAU 1982 standards (predates modern IAU 2000/2006)
-/// - Application: SGP4/SDP4 satellite propagation from TLE data
+/// Terrestrial Intermediate Reference System (TIRS)
 ///
-/// # Coordinates
-/// - Position: Cartesian (x, y, z) in meters, geocentric
-/// - Velocity: Cartesian (vx, vy, vz) in m/s, geocentric
+/// TIRS is the Earth-fixed frame of the IERS 2010 CIO-based chain between CIRS
+/// and ITRS: its z-axis is the CIP and its x-axis the Terrestrial Intermediate
+/// Origin (TIO). It differs from the ITRS only by polar motion (~10 m at the
+/// surface), and corresponds to the classical Pseudo Earth Fixed (PEF) frame.
 ///
 /// # Frame Attributes
-/// - `obstime`: Observation epoch (for time-dependent transformations)
-///
-/// # Historical Context
-/// The TEME frame uses the Greenwich Mean Sidereal Time (GMST) based on the
-/// IAU 1982 model. This differs from modern frames like GCRS which use the
-/// Earth Rotation Angle (ERA) from IAU 2000. The TEME definition is maintained
-/// for backward compatibility with legacy TLE data and SGP4 propagation.
-///
-/// # Examples
-/// ```rust,ignore
-/// use nalgebra::Vector3;
-/// use astrora_core::coordinates::frames::TEME;
-/// use astrora_core::core::time::Epoch;
-///
-/// // Create TEME coordinate from SGP4 output
-/// let position = Vector3::new(7000000.0, 0.0, 0.0);
-/// let velocity = Vector3::new(0.0, 7500.0, 0.0);
-/// let epoch = Epoch::from_gregorian_utc(2024, 1, 1, 0, 0, 0, 0);
-/// let frame = TEME::new(position, velocity, epoch);
-/// ```
-///
-/// # V1 Implementation Notes
-/// This is a simplified implementation that:
-/// - Uses GMST82 (IAU 1982) for TEME ↔ ITRS rotation
-/// - Ignores polar motion (< 1 meter effect)
-/// - Accuracy: ~10-100 meters for typical satellite tracking
+/// - `obstime`: Observation epoch
 ///
-/// Future enhancements:
-/// - Polar motion corrections from IERS EOP data
-/// - Full compatibility validation with Vallado's SGP4 implementation
+/// # Implementation Notes
+/// - CIRS → TIRS: rotation by the Earth Rotation Angle (from UT1) about the CIP
+/// - TIRS → ITRS: polar motion xp, yp and TIO locator s′
+/// - Velocities are relative to the rotating frame
 ///
 /// # References
-/// - Vallado et al. (2006), "Revisiting Spacetrack Report #3", AIAA 2006-6753
-/// - Vallado, "Fundamentals of Astrodynamics and Applications", 4th Ed., Ch. 3
-/// - <https://celestrak.org/publications/AIAA/2006-6753/>
+/// - IERS Conventions (2010), Chapter 5.4
 #[pyclass(module = "astrora._core")]
 #[derive(Debug, Clone, PartialEq)]
-pub struct TEME {
-    /// Position vector in TEME frame (meters, geocentric)
+pub struct TIRS {
+    /// Position vector in TIRS frame (meters, Earth-fixed)
     pub position: Vector3<f64>,
-    /// Velocity vector in TEME frame (m/s, geocentric)
+    /// Velocity vector in TIRS frame (m/s, Earth-fixed)
     pub velocity: Vector3<f64>,
     /// Observation time (for transformations to other frames)
     pub obstime: Epoch,
 }
 
 #[pymethods]
-impl TEME {
-    /// Create a new TEME coordinate frame
+impl TIRS {
+    /// Create a new TIRS coordinate frame
     ///
     /// # Arguments
-    /// - `position`: Position vector in meters (geocentric) [x, y, z]
-    /// - `velocity`: Velocity vector in m/s (geocentric) [vx, vy, vz]
+    /// - `position`: Position vector in meters (Earth-fixed) [x, y, z]
+    /// - `velocity`: Velocity vector in m/s (Earth-fixed) [vx, vy, vz]
     /// - `obstime`: Observation epoch
     #[new]
     pub fn py_new(
@@ -791,20 +1499,8 @@ impl TEME {
         velocity: PyReadonlyArray1<f64>,
         obstime: Epoch,
     ) -> PyResult<Self> {
-        let pos_slice = position.as_slice()?;
-        let vel_slice = velocity.as_slice()?;
-
-        if pos_slice.len() != 3 || vel_slice.len() != 3 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "Position and velocity must be 3-element arrays"
-            ));
-        }
-
-        Ok(Self {
-            position: Vector3::new(pos_slice[0], pos_slice[1], pos_slice[2]),
-            velocity: Vector3::new(vel_slice[0], vel_slice[1], vel_slice[2]),
-            obstime,
-        })
+        let (position, velocity) = state_from_arrays(&position, &velocity)?;
+        Ok(Self::new(position, velocity, obstime))
     }
 
     /// Get the position vector
@@ -825,67 +1521,51 @@ impl TEME {
         self.obstime
     }
 
-    /// Convert to ITRS frame
-    ///
-    /// This transformation rotates the TEME (inertial) coordinates into
-    /// ITRS (Earth-fixed) coordinates using Greenwich Mean Sidereal Time (GMST82).
+    /// Convert to CIRS frame (inverse Earth rotation)
     ///
-    /// # V1 Implementation
-    /// - Uses IAU 1982 GMST rotation about the polar (z) axis
-    /// - Ignores polar motion corrections
-    /// - Suitable for TLE-based satellite tracking (~10-100m accuracy)
+    /// The velocity includes the transport term +ω × r from Earth's rotation.
     ///
     /// # Returns
-    /// ITRS coordinate frame at the same observation time
-    pub fn to_itrs(&self) -> PoliastroResult<ITRS> {
-        // Calculate GMST82 at observation time
-        let gmst = greenwich_mean_sidereal_time_82(&self.obstime);
+    /// CIRS coordinate frame at the same observation time
+    pub fn to_cirs(&self) -> PoliastroResult<CIRS> {
+        let rotation = terrestrial_rotation(&self.obstime)?.earth_rotation().transpose();
+        let omega = Vector3::new(0.0, 0.0, EARTH_ANGULAR_VELOCITY);
 
-        // Rotation matrix: TEME → ITRS is R_z(+GMST)
-        // (rotating FROM inertial TO Earth-fixed means rotating forwards)
-        let rotation = rotation_z(gmst);
-
-        // Transform position
-        let itrs_position = rotation * self.position;
-
-        // Transform velocity (includes Coriolis term from Earth's rotation)
-        // v_itrs = R * v_teme - ω × R * r_teme
-        // where ω is Earth's angular velocity vector [0, 0, ω_earth]
-        let omega_earth = 7.2921150e-5; // rad/s (from IERS)
-        let omega_vec = Vector3::new(0.0, 0.0, omega_earth);
+        let velocity = self.velocity + omega.cross(&self.position);
 
-        let itrs_velocity = rotation * self.velocity - omega_vec.cross(&itrs_position);
+        Ok(CIRS {
+            position: rotation * self.position,
+            velocity: rotation * velocity,
+            obstime: self.obstime,
+        })
+    }
 
+    /// Convert to ITRS frame (applies polar motion)
+    ///
+    /// # Returns
+    /// ITRS coordinate frame at the same observation time
+    pub fn to_itrs(&self) -> PoliastroResult<ITRS> {
+        let rotation = terrestrial_rotation(&self.obstime)?.polar_motion;
         Ok(ITRS {
-            position: itrs_position,
-            velocity: itrs_velocity,
+            position: rotation * self.position,
+            velocity: rotation * self.velocity,
             obstime: self.obstime,
         })
     }
 
-    /// Convert to GCRS frame
-    ///
-    /// This transformation converts TEME coordinates to GCRS by chaining
-    /// through ITRS: TEME → ITRS → GCRS.
-    ///
-    /// # V1 Implementation
-    /// - Uses GMST82 for TEME → ITRS
-    /// - Uses ERA for ITRS → GCRS
-    /// - Combined accuracy: ~10-100 meters
+    /// Convert to GCRS frame (TIRS → CIRS → GCRS)
     ///
     /// # Returns
     /// GCRS coordinate frame at the same observation time
     pub fn to_gcrs(&self) -> PoliastroResult<GCRS> {
-        // Chain transformations: TEME → ITRS → GCRS
-        let itrs = self.to_itrs()?;
-        itrs.to_gcrs()
+        self.to_cirs()?.to_gcrs()
     }
 
     /// String representation
     fn __repr__(&self) -> String {
         let (y, m, d, h, min, s, _) = self.obstime.to_gregorian_utc();
         format!(
-            "TEME(position=[{:.3e}, {:.3e}, {:.3e}] m, velocity=[{:.3e}, {:.3e}, {:.3e}] m/s, obstime={:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z)",
+            "TIRS(position=[{:.3e}, {:.3e}, {:.3e}] m, velocity=[{:.3e}, {:.3e}, {:.3e}] m/s, obstime={:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z)",
             self.position.x, self.position.y, self.position.z,
             self.velocity.x, self.velocity.y, self.velocity.z,
             y, m, d, h, min, s
@@ -893,12 +1573,12 @@ impl TEME {
     }
 }
 
-impl TEME {
-    /// Create a new TEME coordinate frame (internal Rust API)
+impl TIRS {
+    /// Create a new TIRS coordinate frame (internal Rust API)
     ///
     /// # Arguments
-    /// - `position`: Position vector in meters (geocentric)
-    /// - `velocity`: Velocity vector in m/s (geocentric)
+    /// - `position`: Position vector in meters (Earth-fixed)
+    /// - `velocity`: Velocity vector in m/s (Earth-fixed)
     /// - `obstime`: Observation epoch
     pub fn new(position: Vector3<f64>, velocity: Vector3<f64>, obstime: Epoch) -> Self {
         Self {
@@ -908,15 +1588,6 @@ impl TEME {
         }
     }
 
-    /// Create TEME from position only (zero velocity)
-    pub fn from_position(position: Vector3<f64>, obstime: Epoch) -> Self {
-        Self {
-            position,
-            velocity: Vector3::zeros(),
-            obstime,
-        }
-    }
-
     /// Get the position vector
     pub fn position(&self) -> &Vector3<f64> {
         &self.position
@@ -933,6 +1604,44 @@ impl TEME {
     }
 }
 
+/// Parse a Python position/velocity pair into vectors
+pub(crate) fn state_from_arrays(
+    position: &PyReadonlyArray1<f64>,
+    velocity: &PyReadonlyArray1<f64>,
+) -> PyResult<(Vector3<f64>, Vector3<f64>)> {
+    let pos_slice = position.as_slice()?;
+    let vel_slice = velocity.as_slice()?;
+
+    if pos_slice.len() != 3 || vel_slice.len() != 3 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "Position and velocity must be 3-element arrays"
+        ));
+    }
+
+    Ok((
+        Vector3::new(pos_slice[0], pos_slice[1], pos_slice[2]),
+        Vector3::new(vel_slice[0], vel_slice[1], vel_slice[2]),
+    ))
+}
+
+/// Bias-precession matrix GCRS → MOD at `epoch` (IAU 2006)
+fn mean_of_date_matrix(epoch: &Epoch) -> Matrix3<f64> {
+    let (tt1, tt2) = epoch.to_jd_tt_two_part();
+    iau2006_precession_matrix(tt1, tt2)
+}
+
+/// Bias-precession-nutation matrix GCRS → TOD at `epoch` (IAU 2006/2000),
+/// corrected by the celestial pole offsets in `eop`
+fn true_of_date_matrix(epoch: &Epoch, eop: &EarthOrientation) -> Matrix3<f64> {
+    let (tt1, tt2) = epoch.to_jd_tt_two_part();
+    iau2006_precession_nutation_matrix(tt1, tt2) * celestial_pole_offset_matrix(eop.dx, eop.dy)
+}
+
+/// IERS 2010 transformation factors at `epoch` using the installed EOP
+fn terrestrial_rotation(epoch: &Epoch) -> PoliastroResult<TerrestrialRotation> {
+    Ok(TerrestrialRotation::new(epoch, &EarthOrientation::at(epoch)?))
+}
+
 /// Perifocal coordinate frame (PQW)
 ///
 /// The perifocal frame is a coordinate system aligned with the orbital plane:
@@ -1408,30 +2117,28 @@ impl J2000 {
 
     /// Convert to ICRS frame
     ///
-    /// J2000 and ICRS differ by ~0.02 arcseconds, which is negligible for
-    /// most applications. This implementation treats them as identical.
+    /// J2000 and ICRS axes differ by ~0.02 arcseconds, which is negligible for
+    /// most applications and treated as identical. The origin moves to the
+    /// solar system barycenter as in `GCRS::to_icrs` (Earth at J2000.0).
     ///
     /// # Returns
-    /// ICRS frame with identical position and velocity
+    /// ICRS frame at J2000.0
     pub fn to_icrs(&self) -> PoliastroResult<ICRS> {
-        Ok(ICRS::new(self.position, self.velocity))
+        self.to_gcrs().to_icrs()
     }
 
     /// Create J2000 from ICRS frame
     ///
-    /// J2000 and ICRS differ by ~0.02 arcseconds, which is negligible for
-    /// most applications. This implementation treats them as identical.
+    /// J2000 and ICRS axes are treated as identical; the origin moves to the
+    /// geocenter as in `ICRS::to_gcrs` at the ICRS observation time.
     ///
     /// # Arguments
     /// - `icrs`: ICRS coordinate frame
     ///
     /// # Returns
-    /// J2000 frame with position and velocity from the ICRS frame
-    pub fn from_icrs(icrs: &ICRS) -> Self {
-        Self {
-            position: *icrs.position(),
-            velocity: *icrs.velocity(),
-        }
+    /// J2000 frame with the geocentric position and velocity
+    pub fn from_icrs(icrs: &ICRS) -> PoliastroResult<Self> {
+        Ok(Self::from_gcrs(&icrs.to_gcrs(icrs.obstime())?))
     }
 
     /// Convert to ITRS (Earth-fixed) frame
@@ -1521,8 +2228,8 @@ impl J2000 {
     /// Create J2000 from ICRS frame
     #[staticmethod]
     #[pyo3(name = "from_icrs")]
-    pub fn py_from_icrs(icrs: &ICRS) -> Self {
-        Self::from_icrs(icrs)
+    pub fn py_from_icrs(icrs: &ICRS) -> PyResult<Self> {
+        Self::from_icrs(icrs).map_err(|e| e.into())
     }
 
     /// Convert to ITRS (Earth-fixed) frame at J2000 epoch
@@ -1577,6 +2284,8 @@ impl J2000 {
 /// parallel processing with rayon. This is 10-20x faster than sequential
 /// transformations and 20-80x faster than Python for large batches.
 ///
+/// Uses the same IERS 2010 CIO-based chain as `GCRS::to_itrs`.
+///
 /// # Arguments
 /// * `positions` - Nx3 array of GCRS positions in meters
 /// * `velocities` - Nx3 array of GCRS velocities in m/s
@@ -1603,31 +2312,16 @@ pub fn batch_gcrs_to_itrs(
         ));
     }
 
-    let omega_earth = 7.2921150e-5; // rad/s (from IERS)
-    let omega_vec = Vector3::new(0.0, 0.0, omega_earth);
-
     // Parallel processing using rayon
     let results: Vec<(Vector3<f64>, Vector3<f64>)> = positions
         .par_iter()
         .zip(velocities.par_iter())
         .zip(obstimes.par_iter())
         .map(|((pos, vel), obstime)| {
-            // Calculate ERA at observation time
-            let era = earth_rotation_angle(obstime);
-
-            // Rotation matrix: GCRS → ITRS is R_z(+ERA)
-            let rotation = rotation_z(era);
-
-            // Transform position
-            let itrs_position = rotation * pos;
-
-            // Transform velocity (includes Coriolis term)
-            // v_itrs = R * v_gcrs - ω × R * r_gcrs
-            let itrs_velocity = rotation * vel - omega_vec.cross(&itrs_position);
-
-            (itrs_position, itrs_velocity)
+            let eop = EarthOrientation::at(obstime)?;
+            Ok(TerrestrialRotation::new(obstime, &eop).gcrs_to_itrs(pos, vel))
         })
-        .collect();
+        .collect::<PoliastroResult<_>>()?;
 
     let (positions_out, velocities_out): (Vec<_>, Vec<_>) = results.into_iter().unzip();
     Ok((positions_out, velocities_out))
@@ -1638,6 +2332,8 @@ pub fn batch_gcrs_to_itrs(
 /// Transforms multiple ITRS (Earth-fixed) positions and velocities to GCRS
 /// (inertial) frame using parallel processing.
 ///
+/// Uses the same IERS 2010 CIO-based chain as `ITRS::to_gcrs`.
+///
 /// # Arguments
 /// * `positions` - Nx3 array of ITRS positions in meters
 /// * `velocities` - Nx3 array of ITRS velocities in m/s
@@ -1656,32 +2352,16 @@ pub fn batch_itrs_to_gcrs(
         ));
     }
 
-    let omega_earth = 7.2921150e-5; // rad/s (from IERS)
-    let omega_vec = Vector3::new(0.0, 0.0, omega_earth);
-
     // Parallel processing using rayon
     let results: Vec<(Vector3<f64>, Vector3<f64>)> = positions
         .par_iter()
         .zip(velocities.par_iter())
         .zip(obstimes.par_iter())
         .map(|((pos, vel), obstime)| {
-            // Calculate ERA at observation time
-            let era = earth_rotation_angle(obstime);
-
-            // Rotation matrix: ITRS → GCRS is R_z(-ERA)
-            let rotation = rotation_z(-era);
-
-            // Transform position
-            let gcrs_position = rotation * pos;
-
-            // Transform velocity (includes Coriolis term)
-            // v_gcrs = R * v_itrs + ω × R * r_itrs
-            // Note: ω × (R * r_itrs) = ω × r_gcrs
-            let gcrs_velocity = rotation * vel + omega_vec.cross(&gcrs_position);
-
-            (gcrs_position, gcrs_velocity)
+            let eop = EarthOrientation::at(obstime)?;
+            Ok(TerrestrialRotation::new(obstime, &eop).itrs_to_gcrs(pos, vel))
         })
-        .collect();
+        .collect::<PoliastroResult<_>>()?;
 
     let (positions_out, velocities_out): (Vec<_>, Vec<_>) = results.into_iter().unzip();
     Ok((positions_out, velocities_out))
@@ -1719,21 +2399,20 @@ pub fn batch_gcrs_to_teme(
         .zip(velocities.par_iter())
         .zip(obstimes.par_iter())
         .map(|((pos, vel), obstime)| {
-            // GCRS → ITRS (using ERA)
-            let era = earth_rotation_angle(obstime);
-            let r_era = rotation_z(era);
-            let itrs_position = r_era * pos;
-            let itrs_velocity = r_era * vel - omega_vec.cross(&itrs_position);
+            // GCRS → ITRS (IERS 2010 CIO-based chain)
+            let eop = EarthOrientation::at(obstime)?;
+            let (itrs_position, itrs_velocity) =
+                TerrestrialRotation::new(obstime, &eop).gcrs_to_itrs(pos, vel);
 
             // ITRS → TEME (using GMST82)
             let gmst = greenwich_mean_sidereal_time_82(obstime);
-            let r_gmst = rotation_z(-gmst); // Reverse rotation
+            let r_gmst = rotation_z(gmst); // Reverse rotation
             let teme_position = r_gmst * itrs_position;
             let teme_velocity = r_gmst * itrs_velocity + omega_vec.cross(&teme_position);
 
-            (teme_position, teme_velocity)
+            Ok((teme_position, teme_velocity))
         })
-        .collect();
+        .collect::<PoliastroResult<_>>()?;
 
     let (positions_out, velocities_out): (Vec<_>, Vec<_>) = results.into_iter().unzip();
     Ok((positions_out, velocities_out))
@@ -1772,19 +2451,15 @@ pub fn batch_teme_to_gcrs(
         .map(|((pos, vel), obstime)| {
             // TEME → ITRS (using GMST82)
             let gmst = greenwich_mean_sidereal_time_82(obstime);
-            let r_gmst = rotation_z(gmst);
+            let r_gmst = rotation_z(-gmst);
             let itrs_position = r_gmst * pos;
             let itrs_velocity = r_gmst * vel - omega_vec.cross(&itrs_position);
 
-            // ITRS → GCRS (using ERA)
-            let era = earth_rotation_angle(obstime);
-            let r_era = rotation_z(-era); // Reverse rotation
-            let gcrs_position = r_era * itrs_position;
-            let gcrs_velocity = r_era * itrs_velocity + omega_vec.cross(&gcrs_position);
-
-            (gcrs_position, gcrs_velocity)
+            // ITRS → GCRS (IERS 2010 CIO-based chain)
+            let eop = EarthOrientation::at(obstime)?;
+            Ok(TerrestrialRotation::new(obstime, &eop).itrs_to_gcrs(&itrs_position, &itrs_velocity))
         })
-        .collect();
+        .collect::<PoliastroResult<_>>()?;
 
     let (positions_out, velocities_out): (Vec<_>, Vec<_>) = results.into_iter().unzip();
     Ok((positions_out, velocities_out))
@@ -1822,7 +2497,7 @@ pub fn batch_teme_to_itrs(
         .zip(obstimes.par_iter())
         .map(|((pos, vel), obstime)| {
             let gmst = greenwich_mean_sidereal_time_82(obstime);
-            let rotation = rotation_z(gmst);
+            let rotation = rotation_z(-gmst);
 
             let itrs_position = rotation * pos;
             let itrs_velocity = rotation * vel - omega_vec.cross(&itrs_position);
@@ -1867,7 +2542,7 @@ pub fn batch_itrs_to_teme(
         .zip(obstimes.par_iter())
         .map(|((pos, vel), obstime)| {
             let gmst = greenwich_mean_sidereal_time_82(obstime);
-            let rotation = rotation_z(-gmst); // Reverse rotation
+            let rotation = rotation_z(gmst); // Reverse rotation
 
             let teme_position = rotation * pos;
             let teme_velocity = rotation * vel + omega_vec.cross(&teme_position);
@@ -1883,8 +2558,17 @@ pub fn batch_itrs_to_teme(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::coordinates::earth_orientation::cip_xy;
+    use crate::coordinates::precession_nutation::iau2006_precession_nutation_matrix;
     use approx::assert_relative_eq;
 
+    /// GCRS unit vector of the Celestial Intermediate Pole at `epoch`
+    fn cip_unit_vector(epoch: &Epoch) -> Vector3<f64> {
+        let (jd1, jd2) = epoch.to_jd_tt_two_part();
+        let (x, y) = cip_xy(&iau2006_precession_nutation_matrix(jd1, jd2));
+        Vector3::new(x, y, (1.0 - x * x - y * y).sqrt())
+    }
+
     #[test]
     fn test_icrs_creation() {
         let pos = Vector3::new(1.496e11, 0.0, 0.0);
@@ -1939,7 +2623,7 @@ mod tests {
 
     #[test]
     fn test_icrs_to_gcrs_simple() {
-        // Test simple conversion (identity transformation for now)
+        // ICRS → GCRS subtracts the Earth's barycentric state
         let pos = Vector3::new(7000000.0, 1000000.0, 500000.0);
         let vel = Vector3::new(1000.0, 7000.0, 500.0);
         let icrs = ICRS::new(pos, vel);
@@ -1947,19 +2631,15 @@ mod tests {
 
         let gcrs = icrs.to_gcrs(&epoch).unwrap();
 
-        // For simple conversion, positions and velocities should be equal
-        assert_relative_eq!(gcrs.position().x, pos.x, epsilon = 1e-6);
-        assert_relative_eq!(gcrs.position().y, pos.y, epsilon = 1e-6);
-        assert_relative_eq!(gcrs.position().z, pos.z, epsilon = 1e-6);
-        assert_relative_eq!(gcrs.velocity().x, vel.x, epsilon = 1e-9);
-        assert_relative_eq!(gcrs.velocity().y, vel.y, epsilon = 1e-9);
-        assert_relative_eq!(gcrs.velocity().z, vel.z, epsilon = 1e-9);
+        let (earth_pos, earth_vel) = earth_barycentric_state(&epoch).unwrap();
+        assert_relative_eq!(*gcrs.position(), pos - earth_pos, epsilon = 1e-3);
+        assert_relative_eq!(*gcrs.velocity(), vel - earth_vel, epsilon = 1e-9);
         assert_eq!(gcrs.obstime(), &epoch);
     }
 
     #[test]
     fn test_gcrs_to_icrs_simple() {
-        // Test simple conversion (identity transformation for now)
+        // GCRS → ICRS adds the Earth's barycentric state
         let pos = Vector3::new(42164000.0, 0.0, 1000000.0);
         let vel = Vector3::new(0.0, 3075.0, 100.0);
         let epoch = Epoch::from_gregorian_utc(2024, 1, 1, 0, 0, 0, 0);
@@ -1967,13 +2647,14 @@ mod tests {
 
         let icrs = gcrs.to_icrs().unwrap();
 
-        // For simple conversion, positions and velocities should be equal
-        assert_relative_eq!(icrs.position().x, pos.x, epsilon = 1e-6);
-        assert_relative_eq!(icrs.position().y, pos.y, epsilon = 1e-6);
-        assert_relative_eq!(icrs.position().z, pos.z, epsilon = 1e-6);
-        assert_relative_eq!(icrs.velocity().x, vel.x, epsilon = 1e-9);
-        assert_relative_eq!(icrs.velocity().y, vel.y, epsilon = 1e-9);
-        assert_relative_eq!(icrs.velocity().z, vel.z, epsilon = 1e-9);
+        let (earth_pos, earth_vel) = earth_barycentric_state(&epoch).unwrap();
+        assert_relative_eq!(*icrs.position(), pos + earth_pos, epsilon = 1e-3);
+        assert_relative_eq!(*icrs.velocity(), vel + earth_vel, epsilon = 1e-9);
+        assert_eq!(icrs.obstime(), &epoch);
+
+        // A geocentric orbit sits about 1 AU from the barycenter in early January
+        let au = 1.495978707e11;
+        assert!((icrs.position().norm() / au - 0.983).abs() < 0.02);
     }
 
     #[test]
@@ -1987,10 +2668,10 @@ mod tests {
         let gcrs = icrs1.to_gcrs(&epoch).unwrap();
         let icrs2 = gcrs.to_icrs().unwrap();
 
-        // Should get back the same values (within numerical precision)
-        assert_relative_eq!(icrs2.position().x, pos.x, epsilon = 1e-6);
-        assert_relative_eq!(icrs2.position().y, pos.y, epsilon = 1e-6);
-        assert_relative_eq!(icrs2.position().z, pos.z, epsilon = 1e-6);
+        // Should get back the same values (the ~1.5e11 m translation limits precision)
+        assert_relative_eq!(icrs2.position().x, pos.x, epsilon = 1e-3);
+        assert_relative_eq!(icrs2.position().y, pos.y, epsilon = 1e-3);
+        assert_relative_eq!(icrs2.position().z, pos.z, epsilon = 1e-3);
         assert_relative_eq!(icrs2.velocity().x, vel.x, epsilon = 1e-9);
         assert_relative_eq!(icrs2.velocity().y, vel.y, epsilon = 1e-9);
         assert_relative_eq!(icrs2.velocity().z, vel.z, epsilon = 1e-9);
@@ -2109,14 +2790,15 @@ mod tests {
         let epoch = Epoch::j2000();
 
         let gcrs = GCRS::new(pos_gcrs, vel_gcrs, epoch);
-        let itrs = gcrs.to_itrs().unwrap();
+        let itrs = gcrs.to_itrs_with_eop(&EarthOrientation::default()).unwrap();
 
         // Position should be rotated by ERA
         let r_itrs = itrs.position().norm();
         assert_relative_eq!(r_itrs, r, epsilon = 1.0);
 
-        // Z-component should remain unchanged (rotation around z-axis)
-        assert_relative_eq!(itrs.position().z, 0.0, epsilon = 1e-6);
+        // Z-component is the projection onto the CIP (ITRS pole without polar motion)
+        let cip = cip_unit_vector(&epoch);
+        assert_relative_eq!(itrs.position().z, pos_gcrs.dot(&cip), epsilon = 1e-6);
     }
 
     #[test]
@@ -2128,14 +2810,15 @@ mod tests {
         let epoch = Epoch::from_gregorian_utc(2024, 1, 1, 0, 0, 0, 0);
 
         let itrs = ITRS::new(pos_itrs, vel_itrs, epoch);
-        let gcrs = itrs.to_gcrs().unwrap();
+        let gcrs = itrs.to_gcrs_with_eop(&EarthOrientation::default()).unwrap();
 
         // Position magnitude should be preserved
         let r_gcrs = gcrs.position().norm();
         assert_relative_eq!(r_gcrs, r, epsilon = 1.0);
 
-        // Z-component should remain unchanged
-        assert_relative_eq!(gcrs.position().z, 0.0, epsilon = 1e-6);
+        // Equatorial point stays in the CIP equator
+        let cip = cip_unit_vector(&epoch);
+        assert_relative_eq!(gcrs.position().dot(&cip), 0.0, epsilon = 1e-6);
     }
 
     #[test]
@@ -2208,19 +2891,18 @@ mod tests {
 
     #[test]
     fn test_itrs_polar_position_unchanged() {
-        // Position at North Pole should be rotation-invariant
+        // Position at North Pole lies on the rotation axis (the CIP)
         let r = 6356752.0; // WGS84 polar radius
         let pos_itrs = Vector3::new(0.0, 0.0, r);
         let vel_itrs = Vector3::zeros();
         let epoch = Epoch::from_gregorian_utc(2024, 1, 1, 0, 0, 0, 0);
 
         let itrs = ITRS::new(pos_itrs, vel_itrs, epoch);
-        let gcrs = itrs.to_gcrs().unwrap();
+        let gcrs = itrs.to_gcrs_with_eop(&EarthOrientation::default()).unwrap();
 
-        // Position at pole should remain essentially unchanged (rotation axis)
-        assert_relative_eq!(gcrs.position().x, 0.0, epsilon = 1e-3);
-        assert_relative_eq!(gcrs.position().y, 0.0, epsilon = 1e-3);
-        assert_relative_eq!(gcrs.position().z, r, epsilon = 1.0);
+        // Pole maps onto the CIP direction, independent of Earth rotation
+        let cip = cip_unit_vector(&epoch);
+        assert_relative_eq!(*gcrs.position(), cip * r, epsilon = 1e-3);
 
         // Velocity should also be zero (no rotation at pole)
         assert_relative_eq!(gcrs.velocity().x, 0.0, epsilon = 1e-6);
@@ -2897,9 +3579,11 @@ mod tests {
 
         let icrs = j2000.to_icrs().unwrap();
 
-        // Position and velocity should be identical (no rotation for V1 implementation)
-        assert_eq!(icrs.position(), &pos);
-        assert_eq!(icrs.velocity(), &vel);
+        // Axes are shared; the origin moves to the barycenter (Earth at J2000.0)
+        let (earth_pos, earth_vel) = earth_barycentric_state(&Epoch::j2000()).unwrap();
+        assert_relative_eq!(*icrs.position(), pos + earth_pos, epsilon = 1e-3);
+        assert_relative_eq!(*icrs.velocity(), vel + earth_vel, epsilon = 1e-9);
+        assert_eq!(icrs.obstime(), &Epoch::j2000());
     }
 
     #[test]
@@ -2909,11 +3593,12 @@ mod tests {
         let vel = Vector3::new(1000.0, 7000.0, 500.0);
         let icrs = ICRS::new(pos, vel);
 
-        let j2000 = J2000::from_icrs(&icrs);
+        let j2000 = J2000::from_icrs(&icrs).unwrap();
 
-        // Position and velocity should be identical
-        assert_eq!(j2000.position(), &pos);
-        assert_eq!(j2000.velocity(), &vel);
+        // Origin moves from the barycenter to the geocenter
+        let (earth_pos, earth_vel) = earth_barycentric_state(&Epoch::j2000()).unwrap();
+        assert_relative_eq!(*j2000.position(), pos - earth_pos, epsilon = 1e-3);
+        assert_relative_eq!(*j2000.velocity(), vel - earth_vel, epsilon = 1e-9);
     }
 
     #[test]
@@ -2924,7 +3609,7 @@ mod tests {
         let j2000_1 = J2000::new(pos, vel);
 
         let icrs = j2000_1.to_icrs().unwrap();
-        let j2000_2 = J2000::from_icrs(&icrs);
+        let j2000_2 = J2000::from_icrs(&icrs).unwrap();
 
         // Should preserve position and velocity exactly
         assert_relative_eq!(j2000_2.position().x, j2000_1.position().x, epsilon = 1e-6);
@@ -3048,10 +3733,10 @@ mod tests {
         let r_gcrs = gcrs.position().norm();
         assert_relative_eq!(r_gcrs, r, epsilon = 1.0);
 
-        // ICRS conversion
+        // ICRS conversion changes origin, so only the round trip preserves it
         let icrs = j2000.to_icrs().unwrap();
-        let r_icrs = icrs.position().norm();
-        assert_relative_eq!(r_icrs, r, epsilon = 1.0);
+        let r_back = J2000::from_icrs(&icrs).unwrap().position().norm();
+        assert_relative_eq!(r_back, r, epsilon = 1e-3);
     }
 
     // ========== Batch Transformation Tests ==========
@@ -3164,6 +3849,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_batch_gcrs_to_itrs_matches_single() {
+        // Batch and single-state paths must use the same transformation chain
+        let positions = vec![Vector3::new(-4500e3, 3200e3, 4100e3)];
+        let velocities = vec![Vector3::new(-2100.0, -5800.0, 3900.0)];
+        let obstimes = vec![Epoch::from_gregorian_utc(2025, 3, 20, 6, 30, 0, 0)];
+
+        let (itrs_pos, itrs_vel) = batch_gcrs_to_itrs(&positions, &velocities, &obstimes).unwrap();
+        let itrs = GCRS::new(positions[0], velocities[0], obstimes[0]).to_itrs().unwrap();
+
+        assert_relative_eq!(itrs_pos[0], *itrs.position(), epsilon = 1e-9);
+        assert_relative_eq!(itrs_vel[0], *itrs.velocity(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_to_gcrs_uses_interpolated_eop() {
+        // Without an installed table the fallback model places the pole
+        // a few metres off the CIP
+        let epoch = Epoch::from_gregorian_utc(2024, 1, 1, 0, 0, 0, 0);
+        let itrs = ITRS::new(Vector3::new(0.0, 0.0, 6356752.0), Vector3::zeros(), epoch);
+
+        let eop = EarthOrientation::at(&epoch).unwrap();
+        let gcrs = itrs.to_gcrs().unwrap();
+        let expected = itrs.to_gcrs_with_eop(&eop).unwrap();
+        let offset = gcrs.position() - cip_unit_vector(&epoch) * 6356752.0;
+
+        assert_relative_eq!(*gcrs.position(), *expected.position(), epsilon = 1e-9);
+        assert!(offset.norm() > 1.0 && offset.norm() < 30.0);
+    }
+
+    #[test]
+    fn test_itrs_to_gcrs_with_eop_dut1() {
+        // A positive UT1-UTC advances Earth rotation: an equatorial station
+        // moves eastward in GCRS by ω·DUT1·r
+        let r = 6378137.0;
+        let epoch = Epoch::from_gregorian_utc(2024, 1, 1, 0, 0, 0, 0);
+        let itrs = ITRS::new(Vector3::new(r, 0.0, 0.0), Vector3::zeros(), epoch);
+
+        let dut1 = 0.5;
+        let eop = EarthOrientation::new(dut1, 0.0, 0.0, 0.0, 0.0);
+        let gcrs_zero = itrs.to_gcrs_with_eop(&EarthOrientation::default()).unwrap();
+        let gcrs_eop = itrs.to_gcrs_with_eop(&eop).unwrap();
+
+        let shift = gcrs_eop.position() - gcrs_zero.position();
+        let expected = 2.0 * PI * 1.002_737_811_911_354_6 / 86400.0 * dut1 * r;
+        assert_relative_eq!(shift.norm(), expected, epsilon = 1e-3);
+        // Eastward: along the velocity of the rotating station
+        assert!(shift.dot(gcrs_zero.velocity()) > 0.0);
+    }
+
+    #[test]
+    fn test_tod_and_cirs_share_eop_pole() {
+        // Both chains move the CIP by dX, dY: positions agree along the pole
+        let mas = PI / (180.0 * 3600.0 * 1000.0);
+        let epoch = Epoch::from_gregorian_utc(2024, 1, 1, 0, 0, 0, 0);
+        let eop = EarthOrientation::new(0.0, 0.0, 0.0, 0.5 * mas, -0.3 * mas);
+        let gcrs = GCRS::new(Vector3::new(-4500e3, 3200e3, 4100e3), Vector3::zeros(), epoch);
+
+        let tod = gcrs.to_tod_with_eop(&eop).unwrap();
+        let tod_zero = gcrs.to_tod_with_eop(&EarthOrientation::default()).unwrap();
+        let cirs = TerrestrialRotation::new(&epoch, &eop).c2i * gcrs.position();
+
+        assert_relative_eq!(tod.position().z, cirs.z, epsilon = 1e-6);
+        assert!((tod_zero.position().z - cirs.z).abs() > 1e-3);
+        assert_relative_eq!(tod.position().norm(), gcrs.position().norm(), epsilon = 1e-6);
+
+        let back = tod.to_gcrs_with_eop(&eop).unwrap();
+        assert_relative_eq!(*back.position(), *gcrs.position(), epsilon = 1e-6);
+    }
+
     #[test]
     fn test_batch_gcrs_to_teme_basic() {
         // Test GCRS to TEME batch transformation
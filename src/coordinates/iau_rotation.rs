@@ -0,0 +1,519 @@
+//! IAU WGCCRE rotation models for solar system bodies
+//!
+//! The orientation of a body relative to the ICRF is given by the right
+//! ascension α₀ and declination δ₀ of its north pole and the angle W of its
+//! prime meridian, measured along the body's equator from the ascending node
+//! of that equator on the ICRF equator:
+//!
+//! ```text
+//! α₀ = a₀ + a₁·T + Σ aₖ sin(k·Eₖ)
+//! δ₀ = d₀ + d₁·T + Σ dₖ cos(k·Eₖ)
+//! W  = W₀ + W₁·d + W₂·d² + Σ wₖ sin(k·Eₖ)
+//! ```
+//!
+//! with T in TDB Julian centuries and d in TDB days since J2000.0, and Eₖ the
+//! body-specific nutation/precession arguments.
+//!
+//! The ICRF → body-fixed rotation is then R₃(W) · R₁(90° − δ₀) · R₃(90° + α₀).
+//!
+//! # Coverage
+//!
+//! - Sun, Mercury, Venus, Earth, Mars, Jupiter (System III), Saturn, Uranus,
+//!   Neptune, Pluto
+//! - Moon (IAU mean-Earth/polar-axis approximation with the E1–E13 terms)
+//! - Io, Europa, Ganymede, Callisto, Titan, Triton
+//!
+//! Planets may be given either as their system barycenter
+//! (e.g. [`Body::Mars`]) or as the planet itself (NAIF 499).
+//!
+//! Periodic terms below 0.01° in the pole of Jupiter and Mars (IAU 2015) are
+//! omitted. The IAU Earth model is included for completeness only; use
+//! [`crate::coordinates::ITRS`] for Earth-fixed work.
+//!
+//! # References
+//! - Archinal, B. A. et al. (2011), "Report of the IAU Working Group on
+//!   Cartographic Coordinates and Rotational Elements: 2009",
+//!   Celest. Mech. Dyn. Astr. 109, 101–135
+//! - Archinal, B. A. et al. (2018), "… 2015", Celest. Mech. Dyn. Astr. 130:22
+
+use nalgebra::{Matrix3, Vector3};
+use std::f64::consts::PI;
+
+use crate::coordinates::rotations::{rotation_x, rotation_z};
+use crate::core::time::Epoch;
+use crate::ephemeris::Body;
+
+/// Days per Julian century
+const DAYS_PER_CENTURY: f64 = 36525.0;
+
+/// Seconds per day
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/// One periodic term of a rotation model
+///
+/// Contributes `ra·sin(multiple·E)` to α₀, `dec·cos(multiple·E)` to δ₀ and
+/// `w·sin(multiple·E)` to W (all degrees), where E is the model's argument
+/// number `argument`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PeriodicTerm {
+    /// Index into [`IauRotationModel::arguments`]
+    pub argument: usize,
+    /// Multiple of the argument
+    pub multiple: f64,
+    /// Right ascension amplitude (deg)
+    pub ra: f64,
+    /// Declination amplitude (deg)
+    pub dec: f64,
+    /// Prime meridian amplitude (deg)
+    pub w: f64,
+}
+
+const fn term(argument: usize, multiple: f64, ra: f64, dec: f64, w: f64) -> PeriodicTerm {
+    PeriodicTerm {
+        argument,
+        multiple,
+        ra,
+        dec,
+        w,
+    }
+}
+
+/// IAU rotation model of one body
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IauRotationModel {
+    /// α₀ at J2000 (deg) and rate (deg/century)
+    pub ra: [f64; 2],
+    /// δ₀ at J2000 (deg) and rate (deg/century)
+    pub dec: [f64; 2],
+    /// W at J2000 (deg), rate (deg/day) and quadratic term (deg/day²)
+    pub w: [f64; 3],
+    /// Arguments Eₖ at J2000 (deg) and rate (deg/day)
+    pub arguments: &'static [[f64; 2]],
+    /// Periodic terms
+    pub terms: &'static [PeriodicTerm],
+}
+
+/// Orientation angles of a body at an epoch
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BodyOrientation {
+    /// Right ascension of the north pole α₀ (rad)
+    pub pole_ra: f64,
+    /// Declination of the north pole δ₀ (rad)
+    pub pole_dec: f64,
+    /// Prime meridian angle W (rad)
+    pub prime_meridian: f64,
+    /// Rotation rate dW/dt (rad/s)
+    pub rotation_rate: f64,
+}
+
+impl BodyOrientation {
+    /// Rotation matrix from ICRF axes to body-fixed axes
+    pub fn icrf_to_body_fixed(&self) -> Matrix3<f64> {
+        // Passive rotations, built from the active rotations in `rotations`
+        rotation_z(-self.prime_meridian)
+            * rotation_x(-(PI / 2.0 - self.pole_dec))
+            * rotation_z(-(PI / 2.0 + self.pole_ra))
+    }
+
+    /// Unit vector of the north pole in ICRF axes
+    pub fn pole(&self) -> Vector3<f64> {
+        Vector3::new(
+            self.pole_dec.cos() * self.pole_ra.cos(),
+            self.pole_dec.cos() * self.pole_ra.sin(),
+            self.pole_dec.sin(),
+        )
+    }
+
+    /// Angular velocity of the body-fixed frame, in body-fixed axes (rad/s)
+    ///
+    /// The slow motion of the pole is neglected.
+    pub fn angular_velocity(&self) -> Vector3<f64> {
+        Vector3::new(0.0, 0.0, self.rotation_rate)
+    }
+}
+
+impl IauRotationModel {
+    /// Orientation of the body at `epoch`
+    pub fn orientation(&self, epoch: &Epoch) -> BodyOrientation {
+        let d = epoch.to_tdb_seconds_since_j2000() / SECONDS_PER_DAY;
+        let t = d / DAYS_PER_CENTURY;
+
+        let mut ra = self.ra[0] + self.ra[1] * t;
+        let mut dec = self.dec[0] + self.dec[1] * t;
+        let mut w = self.w[0] + self.w[1] * d + self.w[2] * d * d;
+        let mut w_rate = self.w[1] + 2.0 * self.w[2] * d;
+
+        for term in self.terms {
+            let [e0, e_rate] = self.arguments[term.argument];
+            let angle = (term.multiple * (e0 + e_rate * d)).to_radians();
+            ra += term.ra * angle.sin();
+            dec += term.dec * angle.cos();
+            w += term.w * angle.sin();
+            w_rate += term.w * angle.cos() * (term.multiple * e_rate).to_radians();
+        }
+
+        BodyOrientation {
+            pole_ra: ra.to_radians(),
+            pole_dec: dec.to_radians(),
+            prime_meridian: w.rem_euclid(360.0).to_radians(),
+            rotation_rate: w_rate.to_radians() / SECONDS_PER_DAY,
+        }
+    }
+
+    /// Rotation matrix from ICRF axes to body-fixed axes at `epoch`
+    pub fn icrf_to_body_fixed(&self, epoch: &Epoch) -> Matrix3<f64> {
+        self.orientation(epoch).icrf_to_body_fixed()
+    }
+}
+
+/// IAU rotation model of `body`, if one is tabulated
+pub fn iau_rotation_model(body: Body) -> Option<&'static IauRotationModel> {
+    let model = match body {
+        Body::Sun => &SUN,
+        Body::Mercury | Body::Naif(199) => &MERCURY,
+        Body::Venus | Body::Naif(299) => &VENUS,
+        Body::Earth => &EARTH,
+        Body::Moon => &MOON,
+        Body::Mars | Body::Naif(499) => &MARS,
+        Body::Jupiter | Body::Naif(599) => &JUPITER,
+        Body::Saturn | Body::Naif(699) => &SATURN,
+        Body::Uranus | Body::Naif(799) => &URANUS,
+        Body::Neptune | Body::Naif(899) => &NEPTUNE,
+        Body::Pluto | Body::Naif(999) => &PLUTO,
+        Body::Naif(501) => &IO,
+        Body::Naif(502) => &EUROPA,
+        Body::Naif(503) => &GANYMEDE,
+        Body::Naif(504) => &CALLISTO,
+        Body::Naif(606) => &TITAN,
+        Body::Naif(801) => &TRITON,
+        _ => return None,
+    };
+    Some(model)
+}
+
+const SUN: IauRotationModel = IauRotationModel {
+    ra: [286.13, 0.0],
+    dec: [63.87, 0.0],
+    w: [84.176, 14.1844000, 0.0],
+    arguments: &[],
+    terms: &[],
+};
+
+/// IAU 2015
+const MERCURY: IauRotationModel = IauRotationModel {
+    ra: [281.0103, -0.0328],
+    dec: [61.4155, -0.0049],
+    w: [329.5988, 6.1385108, 0.0],
+    arguments: &[
+        [174.7910857, 4.092335],
+        [349.5821714, 8.184670],
+        [164.3732571, 12.277005],
+        [339.1643429, 16.369340],
+        [153.9554286, 20.461675],
+    ],
+    terms: &[
+        term(0, 1.0, 0.0, 0.0, 0.01067257),
+        term(1, 1.0, 0.0, 0.0, -0.00112309),
+        term(2, 1.0, 0.0, 0.0, -0.00011040),
+        term(3, 1.0, 0.0, 0.0, -0.00002539),
+        term(4, 1.0, 0.0, 0.0, -0.00000571),
+    ],
+};
+
+const VENUS: IauRotationModel = IauRotationModel {
+    ra: [272.76, 0.0],
+    dec: [67.16, 0.0],
+    w: [160.20, -1.4813688, 0.0],
+    arguments: &[],
+    terms: &[],
+};
+
+const EARTH: IauRotationModel = IauRotationModel {
+    ra: [0.00, -0.641],
+    dec: [90.00, -0.557],
+    w: [190.147, 360.9856235, 0.0],
+    arguments: &[],
+    terms: &[],
+};
+
+const MOON: IauRotationModel = IauRotationModel {
+    ra: [269.9949, 0.0031],
+    dec: [66.5392, 0.0130],
+    w: [38.3213, 13.17635815, -1.4e-12],
+    arguments: &[
+        [125.045, -0.0529921],
+        [250.089, -0.1059842],
+        [260.008, 13.0120009],
+        [176.625, 13.3407154],
+        [357.529, 0.9856003],
+        [311.589, 26.4057084],
+        [134.963, 13.0649930],
+        [276.617, 0.3287146],
+        [34.226, 1.7484877],
+        [15.134, -0.1589763],
+        [119.743, 0.0036096],
+        [239.961, 0.1643573],
+        [25.053, 12.9590088],
+    ],
+    terms: &[
+        term(0, 1.0, -3.8787, 1.5419, 3.5610),
+        term(1, 1.0, -0.1204, 0.0239, 0.1208),
+        term(2, 1.0, 0.0700, -0.0278, -0.0642),
+        term(3, 1.0, -0.0172, 0.0068, 0.0158),
+        term(4, 1.0, 0.0, 0.0, 0.0252),
+        term(5, 1.0, 0.0072, -0.0029, -0.0066),
+        term(6, 1.0, 0.0, 0.0009, -0.0047),
+        term(7, 1.0, 0.0, 0.0, -0.0046),
+        term(8, 1.0, 0.0, 0.0, 0.0028),
+        term(9, 1.0, -0.0052, 0.0008, 0.0052),
+        term(10, 1.0, 0.0, 0.0, 0.0040),
+        term(11, 1.0, 0.0, 0.0, 0.0019),
+        term(12, 1.0, 0.0043, -0.0009, -0.0044),
+    ],
+};
+
+/// IAU 2009
+const MARS: IauRotationModel = IauRotationModel {
+    ra: [317.68143, -0.1061],
+    dec: [52.88650, -0.0609],
+    w: [176.630, 350.89198226, 0.0],
+    arguments: &[],
+    terms: &[],
+};
+
+/// System III
+const JUPITER: IauRotationModel = IauRotationModel {
+    ra: [268.056595, -0.006499],
+    dec: [64.495303, 0.002413],
+    w: [284.95, 870.5360000, 0.0],
+    arguments: &[],
+    terms: &[],
+};
+
+const SATURN: IauRotationModel = IauRotationModel {
+    ra: [40.589, -0.036],
+    dec: [83.537, -0.004],
+    w: [38.90, 810.7939024, 0.0],
+    arguments: &[],
+    terms: &[],
+};
+
+const URANUS: IauRotationModel = IauRotationModel {
+    ra: [257.311, 0.0],
+    dec: [-15.175, 0.0],
+    w: [203.81, -501.1600928, 0.0],
+    arguments: &[],
+    terms: &[],
+};
+
+/// Neptune argument N = 357.85° + 52.316°·T
+const NEPTUNE_N: [f64; 2] = [357.85, 52.316 / DAYS_PER_CENTURY];
+
+const NEPTUNE: IauRotationModel = IauRotationModel {
+    ra: [299.36, 0.0],
+    dec: [43.46, 0.0],
+    w: [249.978, 541.1397757, 0.0],
+    arguments: &[NEPTUNE_N],
+    terms: &[term(0, 1.0, 0.70, -0.51, -0.48)],
+};
+
+/// IAU 2015
+const PLUTO: IauRotationModel = IauRotationModel {
+    ra: [132.993, 0.0],
+    dec: [-6.163, 0.0],
+    w: [302.695, 56.3625225, 0.0],
+    arguments: &[],
+    terms: &[],
+};
+
+/// Galilean satellite arguments J3–J8
+const JUPITER_SATELLITE_ARGUMENTS: &[[f64; 2]] = &[
+    [283.90, 4850.7 / DAYS_PER_CENTURY],
+    [355.80, 1191.3 / DAYS_PER_CENTURY],
+    [119.90, 262.1 / DAYS_PER_CENTURY],
+    [229.80, 64.3 / DAYS_PER_CENTURY],
+    [352.25, 2382.6 / DAYS_PER_CENTURY],
+    [113.35, 6070.0 / DAYS_PER_CENTURY],
+];
+
+const IO: IauRotationModel = IauRotationModel {
+    ra: [268.05, -0.009],
+    dec: [64.50, 0.003],
+    w: [200.39, 203.4889538, 0.0],
+    arguments: JUPITER_SATELLITE_ARGUMENTS,
+    terms: &[
+        term(0, 1.0, 0.094, 0.040, -0.085),
+        term(1, 1.0, 0.024, 0.011, -0.022),
+    ],
+};
+
+const EUROPA: IauRotationModel = IauRotationModel {
+    ra: [268.08, -0.009],
+    dec: [64.51, 0.003],
+    w: [36.022, 101.3747235, 0.0],
+    arguments: JUPITER_SATELLITE_ARGUMENTS,
+    terms: &[
+        term(1, 1.0, 1.086, 0.468, -0.980),
+        term(2, 1.0, 0.060, 0.026, -0.054),
+        term(3, 1.0, 0.015, 0.007, -0.014),
+        term(4, 1.0, 0.009, 0.002, -0.008),
+    ],
+};
+
+const GANYMEDE: IauRotationModel = IauRotationModel {
+    ra: [268.20, -0.009],
+    dec: [64.57, 0.003],
+    w: [44.064, 50.3176081, 0.0],
+    arguments: JUPITER_SATELLITE_ARGUMENTS,
+    terms: &[
+        term(1, 1.0, -0.037, -0.016, 0.033),
+        term(2, 1.0, 0.431, 0.186, -0.389),
+        term(3, 1.0, 0.091, 0.039, -0.082),
+    ],
+};
+
+const CALLISTO: IauRotationModel = IauRotationModel {
+    ra: [268.72, -0.009],
+    dec: [64.83, 0.003],
+    w: [259.51, 21.5710715, 0.0],
+    arguments: JUPITER_SATELLITE_ARGUMENTS,
+    terms: &[
+        term(2, 1.0, -0.068, -0.029, 0.061),
+        term(3, 1.0, 0.590, 0.254, -0.533),
+        term(5, 1.0, 0.010, -0.004, -0.009),
+    ],
+};
+
+const TITAN: IauRotationModel = IauRotationModel {
+    ra: [39.4827, 0.0],
+    dec: [83.4279, 0.0],
+    w: [186.5855, 22.5769768, 0.0],
+    arguments: &[],
+    terms: &[],
+};
+
+/// Triton argument N7 = 177.85° + 52.316°·T
+#[allow(clippy::approx_constant)] // -6.28 is a tabulated amplitude, not 2π
+const TRITON: IauRotationModel = IauRotationModel {
+    ra: [299.36, 0.0],
+    dec: [41.17, 0.0],
+    w: [296.53, -61.2572637, 0.0],
+    arguments: &[[177.85, 52.316 / DAYS_PER_CENTURY]],
+    terms: &[
+        term(0, 1.0, -32.35, 22.55, 22.25),
+        term(0, 2.0, -6.28, 2.10, 6.73),
+        term(0, 3.0, -2.08, 0.55, 2.05),
+        term(0, 4.0, -0.74, 0.16, 0.74),
+        term(0, 5.0, -0.28, 0.05, 0.28),
+        term(0, 6.0, -0.11, 0.02, 0.11),
+        term(0, 7.0, -0.07, 0.01, 0.05),
+        term(0, 8.0, -0.02, 0.0, 0.02),
+        term(0, 9.0, -0.01, 0.0, 0.01),
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinates::precession_nutation::iau2006_precession_matrix;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_rotation_matrix_is_orthonormal() {
+        let epoch = Epoch::from_gregorian_utc(2025, 3, 1, 0, 0, 0, 0);
+        for body in [Body::Moon, Body::Mars, Body::Jupiter, Body::Naif(801)] {
+            let m = iau_rotation_model(body).unwrap().icrf_to_body_fixed(&epoch);
+            assert_relative_eq!(m * m.transpose(), Matrix3::identity(), epsilon = 1e-12);
+            assert_relative_eq!(m.determinant(), 1.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_pole_and_prime_meridian_geometry() {
+        let epoch = Epoch::from_gregorian_utc(2030, 7, 4, 6, 0, 0, 0);
+        let orientation = iau_rotation_model(Body::Mars).unwrap().orientation(&epoch);
+        let m = orientation.icrf_to_body_fixed();
+
+        // The pole maps onto the body z-axis
+        assert_relative_eq!(m * orientation.pole(), Vector3::z(), epsilon = 1e-12);
+
+        // The ascending node of the body equator lies W behind the prime meridian
+        let ra = orientation.pole_ra;
+        let node = Vector3::new(-ra.sin(), ra.cos(), 0.0);
+        let w = orientation.prime_meridian;
+        assert_relative_eq!(
+            m * node,
+            Vector3::new(w.cos(), -w.sin(), 0.0),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_mars_orientation_at_j2000() {
+        let orientation = iau_rotation_model(Body::Naif(499))
+            .unwrap()
+            .orientation(&Epoch::j2000());
+
+        assert_relative_eq!(orientation.pole_ra.to_degrees(), 317.68143, epsilon = 1e-6);
+        assert_relative_eq!(orientation.pole_dec.to_degrees(), 52.88650, epsilon = 1e-6);
+        assert_relative_eq!(
+            orientation.prime_meridian.to_degrees(),
+            176.630,
+            epsilon = 1e-4
+        );
+
+        // Sidereal day of 24h 37m 22.66s
+        let period = std::f64::consts::TAU / orientation.rotation_rate;
+        assert_relative_eq!(period, 88642.66, epsilon = 0.1);
+    }
+
+    #[test]
+    fn test_moon_librations_and_rate() {
+        let model = iau_rotation_model(Body::Moon).unwrap();
+        let orientation = model.orientation(&Epoch::j2000());
+
+        // Pole within the 1.5°-amplitude E1 libration of its mean position
+        assert!((orientation.pole_dec.to_degrees() - 66.5392).abs() < 1.6);
+
+        // Rotation rate matches the W series derivative numerically
+        let epoch = Epoch::from_gregorian_utc(2025, 1, 1, 0, 0, 0, 0);
+        let h = 600.0;
+        let w_plus = model
+            .orientation(&Epoch::from_tdb_seconds_since_j2000(
+                epoch.to_tdb_seconds_since_j2000() + h,
+            ))
+            .prime_meridian;
+        let w_minus = model
+            .orientation(&Epoch::from_tdb_seconds_since_j2000(
+                epoch.to_tdb_seconds_since_j2000() - h,
+            ))
+            .prime_meridian;
+        let numerical = (w_plus - w_minus).rem_euclid(std::f64::consts::TAU) / (2.0 * h);
+        assert_relative_eq!(
+            model.orientation(&epoch).rotation_rate,
+            numerical,
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_iau_earth_pole_follows_precession() {
+        // The IAU Earth pole is a linear approximation of the precessing pole
+        let epoch = Epoch::from_gregorian_utc(2040, 1, 1, 0, 0, 0, 0);
+        let pole = iau_rotation_model(Body::Earth)
+            .unwrap()
+            .orientation(&epoch)
+            .pole();
+
+        let (tt1, tt2) = epoch.to_jd_tt_two_part();
+        let mean_pole = iau2006_precession_matrix(tt1, tt2).transpose() * Vector3::z();
+
+        assert!(pole.angle(&mean_pole).to_degrees() < 0.01);
+    }
+
+    #[test]
+    fn test_unknown_body() {
+        assert!(iau_rotation_model(Body::Naif(-82)).is_none());
+        assert!(iau_rotation_model(Body::EarthMoonBarycenter).is_none());
+    }
+}
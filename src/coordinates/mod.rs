@@ -7,8 +7,15 @@
 //! - **GCRS**: Geocentric Celestial Reference System (geocentric, inertial)
 //! - **J2000**: J2000 inertial reference frame (geocentric, inertial at J2000.0 epoch)
 //! - **ITRS**: International Terrestrial Reference System (Earth-fixed, rotating)
+//!   reached from GCRS through the IERS 2010 CIO-based chain (`earth_orientation`)
 //! - **TEME**: True Equator Mean Equinox (geocentric, inertial, legacy SGP4 frame)
+//! - **MOD / TOD**: Mean / True equator and equinox Of Date (geocentric)
+//! - **CIRS / TIRS**: Celestial / Terrestrial Intermediate Reference Systems
+//!   (geocentric, CIO-based intermediate frames between GCRS and ITRS)
 //! - **Perifocal**: Perifocal coordinate frame (PQW - orbital plane coordinates)
+//! - **BodyCenteredInertial / BodyFixed**: inertial and rotating frames centred
+//!   on the Moon, the planets and major satellites, oriented by the IAU WGCCRE
+//!   rotation models (`iau_rotation`)
 //!
 //! # Rotation Matrices
 //!
@@ -42,24 +49,54 @@
 //! let v_rotated = rz * pos;
 //! ```
 
+pub mod body_frames;
+pub mod earth_orientation;
 pub mod frames;
+pub mod iau_rotation;
 pub mod rotations;
 pub mod precession_nutation;
 pub mod transform;
 
 // Re-export commonly used types
-pub use frames::{ICRS, GCRS, J2000, ITRS, TEME, Perifocal};
+pub use earth_orientation::{
+    EarthOrientation,
+    TerrestrialRotation,
+    EARTH_ANGULAR_VELOCITY,
+    earth_rotation_angle_ut1,
+    tio_locator_sp,
+    cio_locator_s,
+    cip_xy,
+    celestial_to_intermediate_matrix,
+    polar_motion_matrix,
+    gcrs_to_itrs_matrix,
+};
+pub use frames::{ICRS, GCRS, J2000, ITRS, TEME, MOD, TOD, CIRS, TIRS, Perifocal};
+pub use body_frames::{BodyCenteredInertial, BodyFixed};
+pub use iau_rotation::{iau_rotation_model, BodyOrientation, IauRotationModel, PeriodicTerm};
 pub use precession_nutation::{
     PrecessionAngles,
     PrecessionNutationError,
+    NutationAngles,
+    NutationModel,
+    NutationSeries,
     iau2006_precession,
     fukushima_williams_to_matrix,
     iau2006_precession_matrix,
+    iau2000a_nutation,
+    iau2000b_nutation,
+    nutation,
+    nutation_matrix,
+    nutation_matrix_from_angles,
+    install_iau2000a_series,
+    clear_iau2000a_series,
+    iau2000a_series_installed,
     simplified_nutation_matrix,
     iau2006_precession_nutation_matrix,
+    iau2006_precession_nutation_matrix_with,
 };
 pub use transform::{
     CoordinateFrame,
     FrameType,
     transform_position_velocity,
+    transform_state,
 };
@@ -1,7 +1,7 @@
 //! IAU 2006 Precession and IAU 2000A Nutation Models (Pure Rust Implementation)
 //!
 //! This module provides high-precision calculations for Earth's precession and nutation
-//! using the IAU 2006 precession model and a simplified nutation model. These implement
+//! using the IAU 2006 precession model and the IAU 2000A/2000B nutation models. These implement
 //! the IAU-recommended standards for coordinate transformations in pure Rust.
 //!
 //! # Implementation Approach
@@ -25,19 +25,22 @@
 //!
 //! The rotation matrix is: **R = R₁(-εₐ) · R₃(-ψ̄) · R₁(φ̄) · R₃(γ̄)**
 //!
-//! ## Simplified Nutation (Basic Implementation)
+//! ## IAU 2000 Nutation
 //!
-//! The current nutation implementation provides a simplified model suitable for
-//! most astrodynamics applications (accuracy: ~1-10 arcseconds).
+//! Nutation is selectable through [`NutationModel`]:
+//! - **IAU 2000B**: 77 luni-solar terms plus fixed planetary offsets, embedded
+//!   in the library (accuracy ~1 mas between 1995 and 2050)
+//! - **IAU 2000A**: the full luni-solar and planetary series, evaluated from the
+//!   IERS Conventions (2010) Tables 5.3a/5.3b loaded with [`NutationSeries`]
+//!   and registered with [`install_iau2000a_series`] (accuracy ~0.1 mas)
 //!
-//! For applications requiring ultimate precision (< 1 mas), a future version may
-//! implement the full IAU 2000A model (~1400 terms) or integrate with ERFA.
+//! The nutation matrix is: **N = R₁(-(εₐ+Δε)) · R₃(-Δψ) · R₁(εₐ)**
 //!
 //! # Accuracy
 //!
 //! - **Precession**: ~0.1 milliarcseconds over 100 years (using IAU 2006 polynomials)
-//! - **Nutation**: ~1-10 arcseconds (simplified model - sufficient for most applications)
-//! - **Combined**: ~1-10 arcseconds (nutation-limited)
+//! - **Nutation**: ~1 mas (IAU 2000B) or ~0.1 mas (IAU 2000A)
+//! - **Combined**: nutation-limited
 //!
 //! # Time Scale
 //!
@@ -68,6 +71,11 @@
 //! // Apply to a position vector (GCRS -> mean of date transformation)
 //! let pos_gcrs = Vector3::new(7000000.0, 0.0, 0.0);
 //! let pos_mean = p_matrix * pos_gcrs;
+//!
+//! // IAU 2000B nutation angles and the mean -> true of date matrix
+//! let nut = iau2000b_nutation(jd1, jd2);
+//! let n_matrix = nutation_matrix_from_angles(prec.epsa, &nut);
+//! let pos_true = n_matrix * pos_mean;
 //! ```
 //!
 //! # References
@@ -76,23 +84,37 @@
 //!   precession quantities". A&A, 412, 567-586.
 //! - Wallace, P. T., & Capitaine, N. (2006). "Precession-nutation procedures consistent
 //!   with IAU 2006 resolutions". A&A, 459, 981-985.
+//! - Mathews, P. M., Herring, T. A., & Buffett, B. A. (2002). "Modeling of nutation and
+//!   precession: New nutation series for nonrigid Earth". JGR, 107(B4).
+//! - McCarthy, D. D., & Luzum, B. J. (2003). "An abridged model of the precession-nutation
+//!   of the celestial pole". Celest. Mech. Dyn. Astron., 85, 37-49.
 //! - IERS Technical Note 36: IERS Conventions (2010)
 
 use nalgebra::Matrix3;
+use std::sync::{Arc, RwLock};
 use thiserror::Error;
 use crate::coordinates::rotations::{rotation_x, rotation_z};
 
 /// Arcseconds to radians conversion factor
-const ARCSEC_TO_RAD: f64 = 4.84813681109536e-6;
+pub(crate) const ARCSEC_TO_RAD: f64 = 4.84813681109536e-6;
 
 /// Julian Date of J2000.0 epoch (TT)
-const JD_J2000: f64 = 2451545.0;
+pub(crate) const JD_J2000: f64 = 2451545.0;
 
 /// Errors that can occur during precession/nutation calculations
 #[derive(Error, Debug)]
 pub enum PrecessionNutationError {
     #[error("Invalid Julian Date: date must be within valid range")]
     InvalidDate,
+
+    #[error("IAU 2000A nutation series not installed (see install_iau2000a_series)")]
+    SeriesNotInstalled,
+
+    #[error("Failed to parse nutation table at line {line}: {reason}")]
+    SeriesParse { line: usize, reason: String },
+
+    #[error("Failed to read nutation table: {0}")]
+    Io(String),
 }
 
 /// IAU 2006 precession angles (Fukushima-Williams parameterization)
@@ -232,11 +254,12 @@ pub fn iau2006_precession(jd1: f64, jd2: f64) -> PrecessionAngles {
 /// ```
 pub fn fukushima_williams_to_matrix(angles: &PrecessionAngles) -> Matrix3<f64> {
     // Rotation sequence: R = R₁(-εₐ) · R₃(-ψ̄) · R₁(φ̄) · R₃(γ̄)
-    // Using our standardized rotation functions from rotations module
-    let r_gamb = rotation_z(angles.gamb);
-    let r_phib = rotation_x(angles.phib);
-    let r_psib = rotation_z(-angles.psib);
-    let r_epsa = rotation_x(-angles.epsa);
+    // Rₙ(θ) are frame (passive) rotations as in SOFA, while the rotations module
+    // builds active rotations, so each Rₙ(θ) corresponds to rotation_n(-θ)
+    let r_gamb = rotation_z(-angles.gamb);
+    let r_phib = rotation_x(-angles.phib);
+    let r_psib = rotation_z(angles.psib);
+    let r_epsa = rotation_x(angles.epsa);
 
     // Matrix multiplication (applied right to left)
     r_epsa * r_psib * r_phib * r_gamb
@@ -269,52 +292,709 @@ pub fn iau2006_precession_matrix(jd1: f64, jd2: f64) -> Matrix3<f64> {
     fukushima_williams_to_matrix(&angles)
 }
 
-/// Calculate simplified nutation matrix
+// ============================================================================
+// IAU 2000 Nutation
+// ============================================================================
+
+/// Arcseconds in a full circle
+const TURNAS: f64 = 1296000.0;
+
+/// Units of 0.1 microarcsecond to radians (IAU 2000B coefficient unit)
+const U2R: f64 = ARCSEC_TO_RAD / 1.0e7;
+
+/// Microarcseconds to radians (IERS Conventions table unit)
+const UAS_TO_RAD: f64 = ARCSEC_TO_RAD / 1.0e6;
+
+/// Milliarcseconds to radians
+const MAS_TO_RAD: f64 = ARCSEC_TO_RAD / 1.0e3;
+
+/// IAU 2000B fixed offset in longitude replacing the planetary terms (-0.135 mas)
+const IAU2000B_DPPLAN: f64 = -0.135 * MAS_TO_RAD;
+
+/// IAU 2000B fixed offset in obliquity replacing the planetary terms (+0.388 mas)
+const IAU2000B_DEPLAN: f64 = 0.388 * MAS_TO_RAD;
+
+/// Nutation angles in longitude and obliquity
+///
+/// # Fields
+///
+/// * `dpsi` - Δψ: Nutation in longitude (radians)
+/// * `deps` - Δε: Nutation in obliquity (radians)
+///
+/// # Physical Interpretation
+///
+/// - The dominant 18.6-year term has an amplitude of ~17.2 arcsec in Δψ
+///   and ~9.2 arcsec in Δε
+/// - Semi-annual and fortnightly terms add ~1.3 and ~0.2 arcsec respectively
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NutationAngles {
+    /// Δψ: Nutation in longitude (radians)
+    pub dpsi: f64,
+    /// Δε: Nutation in obliquity (radians)
+    pub deps: f64,
+}
+
+/// Selectable IAU 2000 nutation model
+///
+/// - **IAU 2000A**: Full luni-solar and planetary series (1365 terms in the
+///   SOFA form, 1320+38 / 1037+19 terms in the IERS Conventions tables).
+///   Accuracy ~0.1 mas. Evaluated from a [`NutationSeries`] installed with
+///   [`install_iau2000a_series`].
+/// - **IAU 2000B**: 77 luni-solar terms plus fixed planetary offsets
+///   (McCarthy & Luzum 2003). Accuracy ~1 mas between 1995 and 2050.
+///   Always available (embedded in the library).
+///
+/// The default is [`NutationModel::best_available`]: IAU 2000A once a series
+/// is installed, IAU 2000B until then, so it always evaluates. Asking for
+/// `Iau2000A` explicitly without an installed series is an error rather than
+/// a silent drop to IAU 2000B.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NutationModel {
+    /// Full IAU 2000A luni-solar and planetary series
+    Iau2000A,
+    /// Truncated 77-term IAU 2000B series
+    Iau2000B,
+}
+
+impl NutationModel {
+    /// Most accurate model currently available
+    ///
+    /// Returns `Iau2000A` when a full series has been installed, otherwise
+    /// `Iau2000B`, the model [`iau2006_precession_nutation_matrix`] and the
+    /// frame transformations then use (~1 mas instead of ~0.1 mas).
+    pub fn best_available() -> Self {
+        if iau2000a_series_installed() {
+            NutationModel::Iau2000A
+        } else {
+            NutationModel::Iau2000B
+        }
+    }
+
+    /// IAU designation of the model ("IAU2000A" or "IAU2000B")
+    pub fn name(&self) -> &'static str {
+        match self {
+            NutationModel::Iau2000A => "IAU2000A",
+            NutationModel::Iau2000B => "IAU2000B",
+        }
+    }
+}
+
+impl Default for NutationModel {
+    fn default() -> Self {
+        Self::best_available()
+    }
+}
+
+/// Delaunay fundamental arguments (IERS Conventions 2003, Simon et al. 1994)
+///
+/// Returns [l, l', F, D, Ω] in radians for `t` Julian centuries of TT since J2000.
+pub(crate) fn delaunay_arguments(t: f64) -> [f64; 5] {
+    // l: mean anomaly of the Moon
+    let l = (485868.249036
+        + t * (1717915923.2178 + t * (31.8792 + t * (0.051635 + t * (-0.00024470)))))
+        % TURNAS;
+    // l': mean anomaly of the Sun
+    let lp = (1287104.793048
+        + t * (129596581.0481 + t * (-0.5532 + t * (0.000136 + t * (-0.00001149)))))
+        % TURNAS;
+    // F: mean argument of latitude of the Moon (L - Ω)
+    let f = (335779.526232
+        + t * (1739527262.8478 + t * (-12.7512 + t * (-0.001037 + t * 0.00000417))))
+        % TURNAS;
+    // D: mean elongation of the Moon from the Sun
+    let d = (1072260.703692
+        + t * (1602961601.2090 + t * (-6.3706 + t * (0.006593 + t * (-0.00003169)))))
+        % TURNAS;
+    // Ω: mean longitude of the Moon's ascending node
+    let om = (450160.398036
+        + t * (-6962890.5431 + t * (7.4722 + t * (0.007702 + t * (-0.00005939)))))
+        % TURNAS;
+
+    [
+        l * ARCSEC_TO_RAD,
+        lp * ARCSEC_TO_RAD,
+        f * ARCSEC_TO_RAD,
+        d * ARCSEC_TO_RAD,
+        om * ARCSEC_TO_RAD,
+    ]
+}
+
+/// Planetary fundamental arguments (IERS Conventions 2003, Souchay et al. 1999)
+///
+/// Returns the mean longitudes of Mercury through Neptune followed by the
+/// general accumulated precession in longitude p_A, all in radians.
+pub(crate) fn planetary_arguments(t: f64) -> [f64; 9] {
+    let two_pi = 2.0 * std::f64::consts::PI;
+    [
+        (4.402608842 + 2608.7903141574 * t) % two_pi, // Mercury
+        (3.176146697 + 1021.3285546211 * t) % two_pi, // Venus
+        (1.753470314 + 628.3075849991 * t) % two_pi,  // Earth
+        (6.203480913 + 334.0612426700 * t) % two_pi,  // Mars
+        (0.599546497 + 52.9690962641 * t) % two_pi,   // Jupiter
+        (0.874016757 + 21.3299104960 * t) % two_pi,   // Saturn
+        (5.481293872 + 7.4781598567 * t) % two_pi,    // Uranus
+        (5.311886287 + 3.8133035638 * t) % two_pi,    // Neptune
+        (0.02438175 + 0.00000538691 * t) * t,         // p_A
+    ]
+}
+
+/// One term of the IAU 2000B luni-solar nutation series
+///
+/// Multipliers of (l, l', F, D, Ω), followed by the longitude coefficients
+/// (sin, sin·t, cos) and obliquity coefficients (cos, cos·t, sin) in units
+/// of 0.1 microarcsecond.
+struct LuniSolarTerm {
+    nl: i8,
+    nlp: i8,
+    nf: i8,
+    nd: i8,
+    nom: i8,
+    ps: f64,
+    pst: f64,
+    pc: f64,
+    ec: f64,
+    ect: f64,
+    es: f64,
+}
+
+macro_rules! ls {
+    ($nl:expr, $nlp:expr, $nf:expr, $nd:expr, $nom:expr,
+     $ps:expr, $pst:expr, $pc:expr, $ec:expr, $ect:expr, $es:expr) => {
+        LuniSolarTerm {
+            nl: $nl, nlp: $nlp, nf: $nf, nd: $nd, nom: $nom,
+            ps: $ps, pst: $pst, pc: $pc, ec: $ec, ect: $ect, es: $es,
+        }
+    };
+}
+
+/// IAU 2000B luni-solar nutation series (McCarthy & Luzum 2003)
+///
+/// These are the 77 largest terms of the IAU 2000A (MHB2000) luni-solar
+/// series, in decreasing order of amplitude.
+#[rustfmt::skip]
+const IAU2000B_TERMS: [LuniSolarTerm; 77] = [
+    // 1-10
+    ls!( 0, 0, 0, 0, 1, -172064161.0, -174666.0,  33386.0, 92052331.0,  9086.0, 15377.0),
+    ls!( 0, 0, 2,-2, 2,  -13170906.0,   -1675.0, -13696.0,  5730336.0, -3015.0, -4587.0),
+    ls!( 0, 0, 2, 0, 2,   -2276413.0,    -234.0,   2796.0,   978459.0,  -485.0,  1374.0),
+    ls!( 0, 0, 0, 0, 2,    2074554.0,     207.0,   -698.0,  -897492.0,   470.0,  -291.0),
+    ls!( 0, 1, 0, 0, 0,    1475877.0,   -3633.0,  11817.0,    73871.0,  -184.0, -1924.0),
+    ls!( 0, 1, 2,-2, 2,    -516821.0,    1226.0,   -524.0,   224386.0,  -677.0,  -174.0),
+    ls!( 1, 0, 0, 0, 0,     711159.0,      73.0,   -872.0,    -6750.0,     0.0,   358.0),
+    ls!( 0, 0, 2, 0, 1,    -387298.0,    -367.0,    380.0,   200728.0,    18.0,   318.0),
+    ls!( 1, 0, 2, 0, 2,    -301461.0,     -36.0,    816.0,   129025.0,   -63.0,   367.0),
+    ls!( 0,-1, 2,-2, 2,     215829.0,    -494.0,    111.0,   -95929.0,   299.0,   132.0),
+    // 11-20
+    ls!( 0, 0, 2,-2, 1,     128227.0,     137.0,    181.0,   -68982.0,    -9.0,    39.0),
+    ls!(-1, 0, 2, 0, 2,     123457.0,      11.0,     19.0,   -53311.0,    32.0,    -4.0),
+    ls!(-1, 0, 0, 2, 0,     156994.0,      10.0,   -168.0,    -1235.0,     0.0,    82.0),
+    ls!( 1, 0, 0, 0, 1,      63110.0,      63.0,     27.0,   -33228.0,     0.0,    -9.0),
+    ls!(-1, 0, 0, 0, 1,     -57976.0,     -63.0,   -189.0,    31429.0,     0.0,   -75.0),
+    ls!(-1, 0, 2, 2, 2,     -59641.0,     -11.0,    149.0,    25543.0,   -11.0,    66.0),
+    ls!( 1, 0, 2, 0, 1,     -51613.0,     -42.0,    129.0,    26366.0,     0.0,    78.0),
+    ls!(-2, 0, 2, 0, 1,      45893.0,      50.0,     31.0,   -24236.0,   -10.0,    20.0),
+    ls!( 0, 0, 0, 2, 0,      63384.0,      11.0,   -150.0,    -1220.0,     0.0,    29.0),
+    ls!( 0, 0, 2, 2, 2,     -38571.0,      -1.0,    158.0,    16452.0,   -11.0,    68.0),
+    // 21-30
+    ls!( 0,-2, 2,-2, 2,      32481.0,       0.0,      0.0,   -13870.0,     0.0,     0.0),
+    ls!(-2, 0, 0, 2, 0,     -47722.0,       0.0,    -18.0,      477.0,     0.0,   -25.0),
+    ls!( 2, 0, 2, 0, 2,     -31046.0,      -1.0,    131.0,    13238.0,   -11.0,    59.0),
+    ls!( 1, 0, 2,-2, 2,      28593.0,       0.0,     -1.0,   -12338.0,    10.0,    -3.0),
+    ls!(-1, 0, 2, 0, 1,      20441.0,      21.0,     10.0,   -10758.0,     0.0,    -3.0),
+    ls!( 2, 0, 0, 0, 0,      29243.0,       0.0,    -74.0,     -609.0,     0.0,    13.0),
+    ls!( 0, 0, 2, 0, 0,      25887.0,       0.0,    -66.0,     -550.0,     0.0,    11.0),
+    ls!( 0, 1, 0, 0, 1,     -14053.0,     -25.0,     79.0,     8551.0,    -2.0,   -45.0),
+    ls!(-1, 0, 0, 2, 1,      15164.0,      10.0,     11.0,    -8001.0,     0.0,    -1.0),
+    ls!( 0, 2, 2,-2, 2,     -15794.0,      72.0,    -16.0,     6850.0,   -42.0,    -5.0),
+    // 31-40
+    ls!( 0, 0,-2, 2, 0,      21783.0,       0.0,     13.0,     -167.0,     0.0,    13.0),
+    ls!( 1, 0, 0,-2, 1,     -12873.0,     -10.0,    -37.0,     6953.0,     0.0,   -14.0),
+    ls!( 0,-1, 0, 0, 1,     -12654.0,      11.0,     63.0,     6415.0,     0.0,    26.0),
+    ls!(-1, 0, 2, 2, 1,     -10204.0,       0.0,     25.0,     5222.0,     0.0,    15.0),
+    ls!( 0, 2, 0, 0, 0,      16707.0,     -85.0,    -10.0,      168.0,    -1.0,    10.0),
+    ls!( 1, 0, 2, 2, 2,      -7691.0,       0.0,     44.0,     3268.0,     0.0,    19.0),
+    ls!(-2, 0, 2, 0, 0,     -11024.0,       0.0,    -14.0,      104.0,     0.0,     2.0),
+    ls!( 0, 1, 2, 0, 2,       7566.0,     -21.0,    -11.0,    -3250.0,     0.0,    -5.0),
+    ls!( 0, 0, 2, 2, 1,      -6637.0,     -11.0,     25.0,     3353.0,     0.0,    14.0),
+    ls!( 0,-1, 2, 0, 2,      -7141.0,      21.0,      8.0,     3070.0,     0.0,     4.0),
+    // 41-50
+    ls!( 0, 0, 0, 2, 1,      -6302.0,     -11.0,      2.0,     3272.0,     0.0,     4.0),
+    ls!( 1, 0, 2,-2, 1,       5800.0,      10.0,      2.0,    -3045.0,     0.0,    -1.0),
+    ls!( 2, 0, 2,-2, 2,       6443.0,       0.0,     -7.0,    -2768.0,     0.0,    -4.0),
+    ls!(-2, 0, 0, 2, 1,      -5774.0,     -11.0,    -15.0,     3041.0,     0.0,    -5.0),
+    ls!( 2, 0, 2, 0, 1,      -5350.0,       0.0,     21.0,     2695.0,     0.0,    12.0),
+    ls!( 0,-1, 2,-2, 1,      -4752.0,     -11.0,     -3.0,     2719.0,     0.0,    -3.0),
+    ls!( 0, 0, 0,-2, 1,      -4940.0,     -11.0,    -21.0,     2720.0,     0.0,    -9.0),
+    ls!(-1,-1, 0, 2, 0,       7350.0,       0.0,     -8.0,      -51.0,     0.0,     4.0),
+    ls!( 2, 0, 0,-2, 1,       4065.0,       0.0,      6.0,    -2206.0,     0.0,     1.0),
+    ls!( 1, 0, 0, 2, 0,       6579.0,       0.0,    -24.0,     -199.0,     0.0,     2.0),
+    // 51-60
+    ls!( 0, 1, 2,-2, 1,       3579.0,       0.0,      5.0,    -1900.0,     0.0,     1.0),
+    ls!( 1,-1, 0, 0, 0,       4725.0,       0.0,     -6.0,      -41.0,     0.0,     3.0),
+    ls!(-2, 0, 2, 0, 2,      -3075.0,       0.0,     -2.0,     1313.0,     0.0,    -1.0),
+    ls!( 3, 0, 2, 0, 2,      -2904.0,       0.0,     15.0,     1233.0,     0.0,     7.0),
+    ls!( 0,-1, 0, 2, 0,       4348.0,       0.0,    -10.0,      -81.0,     0.0,     2.0),
+    ls!( 1,-1, 2, 0, 2,      -2878.0,       0.0,      8.0,     1232.0,     0.0,     4.0),
+    ls!( 0, 0, 0, 1, 0,      -4230.0,       0.0,      5.0,      -20.0,     0.0,    -2.0),
+    ls!(-1,-1, 2, 2, 2,      -2819.0,       0.0,      7.0,     1207.0,     0.0,     3.0),
+    ls!(-1, 0, 2, 0, 0,      -4056.0,       0.0,      5.0,       40.0,     0.0,    -2.0),
+    ls!( 0,-1, 2, 2, 2,      -2647.0,       0.0,     11.0,     1129.0,     0.0,     5.0),
+    // 61-70
+    ls!(-2, 0, 0, 0, 1,      -2294.0,       0.0,    -10.0,     1266.0,     0.0,    -4.0),
+    ls!( 1, 1, 2, 0, 2,       2481.0,       0.0,     -7.0,    -1062.0,     0.0,    -3.0),
+    ls!( 2, 0, 0, 0, 1,       2179.0,       0.0,     -2.0,    -1129.0,     0.0,    -2.0),
+    ls!(-1, 1, 0, 1, 0,       3276.0,       0.0,      1.0,       -9.0,     0.0,     0.0),
+    ls!( 1, 1, 0, 0, 0,      -3389.0,       0.0,      5.0,       35.0,     0.0,    -2.0),
+    ls!( 1, 0, 2, 0, 0,       3339.0,       0.0,    -13.0,     -107.0,     0.0,     1.0),
+    ls!(-1, 0, 2,-2, 1,      -1987.0,       0.0,     -6.0,     1073.0,     0.0,    -2.0),
+    ls!( 1, 0, 0, 0, 2,      -1981.0,       0.0,      0.0,      854.0,     0.0,     0.0),
+    ls!(-1, 0, 0, 1, 0,       4026.0,       0.0,   -353.0,     -553.0,     0.0,  -139.0),
+    ls!( 0, 0, 2, 1, 2,       1660.0,       0.0,     -5.0,     -710.0,     0.0,    -2.0),
+    // 71-77
+    ls!(-1, 0, 2, 4, 2,      -1521.0,       0.0,      9.0,      647.0,     0.0,     4.0),
+    ls!(-1, 1, 0, 1, 1,       1314.0,       0.0,      0.0,     -700.0,     0.0,     0.0),
+    ls!( 0,-2, 2,-2, 1,      -1283.0,       0.0,      0.0,      672.0,     0.0,     0.0),
+    ls!( 1, 0, 2, 2, 1,      -1331.0,       0.0,      8.0,      663.0,     0.0,     4.0),
+    ls!(-2, 0, 2, 2, 2,       1383.0,       0.0,     -2.0,     -594.0,     0.0,    -2.0),
+    ls!(-1, 0, 0, 0, 2,       1405.0,       0.0,      4.0,     -610.0,     0.0,     2.0),
+    ls!( 1, 1, 2,-2, 2,       1290.0,       0.0,      0.0,     -556.0,     0.0,     0.0),
+];
+
+/// Calculate nutation using the IAU 2000B model
+///
+/// Evaluates the 77-term luni-solar series of McCarthy & Luzum (2003) and adds
+/// the fixed offsets that stand in for the planetary terms.
+///
+/// # Arguments
+///
+/// * `jd1` - TT as a 2-part Julian Date (integer part)
+/// * `jd2` - TT as a 2-part Julian Date (fractional part)
+///
+/// # Returns
+///
+/// `NutationAngles` (Δψ, Δε) in radians
+///
+/// # Accuracy
+///
+/// ~1 milliarcsecond with respect to IAU 2000A between 1995 and 2050
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let nut = iau2000b_nutation(2451545.0, 0.0);
+/// // Nutation in longitude is dominated by the 18.6-year term (< 17.3 arcsec)
+/// assert!(nut.dpsi.abs() < 17.3 * 4.848e-6);
+/// ```
+pub fn iau2000b_nutation(jd1: f64, jd2: f64) -> NutationAngles {
+    // Time in Julian centuries since J2000.0 (TT)
+    let t = ((jd1 - JD_J2000) + jd2) / 36525.0;
+
+    // IAU 2000B uses linear (Simon et al. 1994) fundamental arguments
+    let el = ((485868.249036 + 1717915923.2178 * t) % TURNAS) * ARCSEC_TO_RAD;
+    let elp = ((1287104.79305 + 129596581.0481 * t) % TURNAS) * ARCSEC_TO_RAD;
+    let f = ((335779.526232 + 1739527262.8478 * t) % TURNAS) * ARCSEC_TO_RAD;
+    let d = ((1072260.70369 + 1602961601.2090 * t) % TURNAS) * ARCSEC_TO_RAD;
+    let om = ((450160.398036 - 6962890.5431 * t) % TURNAS) * ARCSEC_TO_RAD;
+
+    let mut dp = 0.0;
+    let mut de = 0.0;
+
+    // Sum from the smallest term up to reduce rounding error
+    for term in IAU2000B_TERMS.iter().rev() {
+        let arg = (term.nl as f64 * el
+            + term.nlp as f64 * elp
+            + term.nf as f64 * f
+            + term.nd as f64 * d
+            + term.nom as f64 * om)
+            % (2.0 * std::f64::consts::PI);
+        let (sarg, carg) = arg.sin_cos();
+
+        dp += (term.ps + term.pst * t) * sarg + term.pc * carg;
+        de += (term.ec + term.ect * t) * carg + term.es * sarg;
+    }
+
+    NutationAngles {
+        dpsi: dp * U2R + IAU2000B_DPPLAN,
+        deps: de * U2R + IAU2000B_DEPLAN,
+    }
+}
+
+/// One term of an IERS Conventions nutation table
+///
+/// The 14 multipliers apply to the fundamental arguments in the order
+/// l, l', F, D, Ω, L_Me, L_Ve, L_E, L_Ma, L_J, L_Sa, L_U, L_Ne, p_A.
+/// Coefficients are in microarcseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SeriesTerm {
+    /// Power of t (Julian centuries TT) multiplying this term
+    power: u8,
+    /// Coefficient of sin(ARG)
+    sin_coeff: f64,
+    /// Coefficient of cos(ARG)
+    cos_coeff: f64,
+    /// Fundamental argument multipliers
+    multipliers: [i8; 14],
+}
+
+impl SeriesTerm {
+    #[inline]
+    fn argument(&self, args: &[f64; 14]) -> f64 {
+        self.multipliers
+            .iter()
+            .zip(args.iter())
+            .filter(|(&m, _)| m != 0)
+            .map(|(&m, &a)| m as f64 * a)
+            .sum()
+    }
+}
+
+/// Full nutation series in the IERS Conventions (2010) tabular form
+///
+/// Holds the nutation in longitude (Table 5.3a) and obliquity (Table 5.3b)
+/// series, including both the luni-solar and planetary terms. This is the
+/// published, machine-readable form of the IAU 2000A model:
+/// <https://iers-conventions.obspm.fr/content/chapter5/additional_info/tab5.3a.txt>
+///
+/// The IERS 2010 tables already include the IAU 2006 adjustments (the
+/// 0.4697e-6 scaling and J2 secular rate), so the resulting nutation is
+/// directly consistent with [`iau2006_precession`].
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let series = NutationSeries::from_files("tab5.3a.txt", "tab5.3b.txt")?;
+/// install_iau2000a_series(series);
+///
+/// let nut = nutation(2451545.0, 0.0, NutationModel::Iau2000A)?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct NutationSeries {
+    longitude: Vec<SeriesTerm>,
+    obliquity: Vec<SeriesTerm>,
+}
+
+impl NutationSeries {
+    /// Parse the IERS Conventions nutation tables from their text contents
+    ///
+    /// # Arguments
+    ///
+    /// * `longitude_table` - Contents of Table 5.3a (nutation in longitude)
+    /// * `obliquity_table` - Contents of Table 5.3b (nutation in obliquity)
+    ///
+    /// # Format
+    ///
+    /// Each data row holds an index, the sine and cosine amplitudes in µas,
+    /// and the 14 argument multipliers. Rows follow a `j = N` header giving
+    /// the power of t. Header and separator lines are skipped.
+    pub fn from_iers_tables(
+        longitude_table: &str,
+        obliquity_table: &str,
+    ) -> Result<Self, PrecessionNutationError> {
+        // Table 5.3a: Δψ = Σ (A sin + A'' cos); Table 5.3b: Δε = Σ (B cos + B'' sin)
+        let longitude = parse_iers_table(longitude_table, false)?;
+        let obliquity = parse_iers_table(obliquity_table, true)?;
+
+        if longitude.is_empty() || obliquity.is_empty() {
+            return Err(PrecessionNutationError::SeriesParse {
+                line: 0,
+                reason: "table contains no nutation terms".to_string(),
+            });
+        }
+
+        Ok(Self { longitude, obliquity })
+    }
+
+    /// Load the IERS Conventions nutation tables from local files
+    ///
+    /// # Arguments
+    ///
+    /// * `longitude_path` - Path to Table 5.3a (e.g. `tab5.3a.txt`)
+    /// * `obliquity_path` - Path to Table 5.3b (e.g. `tab5.3b.txt`)
+    pub fn from_files(
+        longitude_path: impl AsRef<std::path::Path>,
+        obliquity_path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, PrecessionNutationError> {
+        let read = |path: &std::path::Path| {
+            std::fs::read_to_string(path).map_err(|e| {
+                PrecessionNutationError::Io(format!("{}: {}", path.display(), e))
+            })
+        };
+        let longitude = read(longitude_path.as_ref())?;
+        let obliquity = read(obliquity_path.as_ref())?;
+        Self::from_iers_tables(&longitude, &obliquity)
+    }
+
+    /// Number of terms in the longitude and obliquity series
+    pub fn len(&self) -> (usize, usize) {
+        (self.longitude.len(), self.obliquity.len())
+    }
+
+    /// Whether both series are empty
+    pub fn is_empty(&self) -> bool {
+        self.longitude.is_empty() && self.obliquity.is_empty()
+    }
+
+    /// Evaluate the series at `t` Julian centuries of TT since J2000.0
+    ///
+    /// # Returns
+    ///
+    /// `NutationAngles` (Δψ, Δε) in radians
+    pub fn evaluate(&self, t: f64) -> NutationAngles {
+        let delaunay = delaunay_arguments(t);
+        let planetary = planetary_arguments(t);
+
+        let mut args = [0.0; 14];
+        args[..5].copy_from_slice(&delaunay);
+        args[5..].copy_from_slice(&planetary);
+
+        let sum = |terms: &[SeriesTerm]| -> f64 {
+            terms
+                .iter()
+                .rev()
+                .map(|term| {
+                    let (sarg, carg) = term.argument(&args).sin_cos();
+                    (term.sin_coeff * sarg + term.cos_coeff * carg) * t.powi(term.power as i32)
+                })
+                .sum()
+        };
+
+        NutationAngles {
+            dpsi: sum(&self.longitude) * UAS_TO_RAD,
+            deps: sum(&self.obliquity) * UAS_TO_RAD,
+        }
+    }
+}
+
+/// Parse one IERS nutation table
+///
+/// `cos_first` selects the column order: Table 5.3b lists the cosine
+/// amplitude before the sine amplitude.
+fn parse_iers_table(text: &str, cos_first: bool) -> Result<Vec<SeriesTerm>, PrecessionNutationError> {
+    let mut terms = Vec::new();
+    let mut power: Option<u8> = None;
+
+    for (idx, line) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        let trimmed = line.trim();
+
+        // Section headers look like "j = 0  Number of terms = 1320"
+        if let Some(rest) = trimmed.strip_prefix('j') {
+            if let Some(value) = rest.trim_start().strip_prefix('=') {
+                let token = value.split_whitespace().next().unwrap_or("");
+                power = Some(token.parse().map_err(|_| PrecessionNutationError::SeriesParse {
+                    line: line_no,
+                    reason: format!("invalid power of t '{token}'"),
+                })?);
+                continue;
+            }
+        }
+
+        let tokens: Vec<&str> = trimmed.split_whitespace().collect();
+        if tokens.len() != 17 || tokens[0].parse::<u32>().is_err() {
+            // Titles, column headers, separators and blank lines
+            continue;
+        }
+
+        let power = power.ok_or_else(|| PrecessionNutationError::SeriesParse {
+            line: line_no,
+            reason: "data row before any 'j = N' section header".to_string(),
+        })?;
+
+        let parse_f64 = |s: &str| {
+            s.parse::<f64>().map_err(|_| PrecessionNutationError::SeriesParse {
+                line: line_no,
+                reason: format!("invalid amplitude '{s}'"),
+            })
+        };
+        let first = parse_f64(tokens[1])?;
+        let second = parse_f64(tokens[2])?;
+
+        let mut multipliers = [0i8; 14];
+        for (m, s) in multipliers.iter_mut().zip(&tokens[3..]) {
+            *m = s.parse().map_err(|_| PrecessionNutationError::SeriesParse {
+                line: line_no,
+                reason: format!("invalid argument multiplier '{s}'"),
+            })?;
+        }
+
+        let (sin_coeff, cos_coeff) = if cos_first { (second, first) } else { (first, second) };
+        terms.push(SeriesTerm {
+            power,
+            sin_coeff,
+            cos_coeff,
+            multipliers,
+        });
+    }
+
+    Ok(terms)
+}
+
+/// Globally installed IAU 2000A series (None until installed)
+static IAU2000A_SERIES: RwLock<Option<Arc<NutationSeries>>> = RwLock::new(None);
+
+/// Install the full IAU 2000A series used by [`NutationModel::Iau2000A`]
 ///
-/// Provides a basic nutation transformation suitable for most astrodynamics applications.
-/// This is a simplified model with accuracy of ~1-10 arcseconds.
+/// Replaces any previously installed series. Once installed, the default
+/// precession-nutation functions and frame transformations use IAU 2000A
+/// instead of IAU 2000B.
+pub fn install_iau2000a_series(series: NutationSeries) {
+    let mut guard = IAU2000A_SERIES.write().unwrap_or_else(|e| e.into_inner());
+    *guard = Some(Arc::new(series));
+}
+
+/// Remove the installed IAU 2000A series, reverting to IAU 2000B
+pub fn clear_iau2000a_series() {
+    let mut guard = IAU2000A_SERIES.write().unwrap_or_else(|e| e.into_inner());
+    *guard = None;
+}
+
+/// Whether a full IAU 2000A series has been installed
+pub fn iau2000a_series_installed() -> bool {
+    IAU2000A_SERIES
+        .read()
+        .map(|guard| guard.is_some())
+        .unwrap_or(false)
+}
+
+/// Calculate nutation using the IAU 2000A model
 ///
-/// For ultimate precision (< 1 mas), a future implementation may add the full
-/// IAU 2000A model (~1400 terms).
+/// Evaluates the installed full luni-solar and planetary series.
 ///
 /// # Arguments
 ///
 /// * `jd1` - TT as a 2-part Julian Date (integer part)
 /// * `jd2` - TT as a 2-part Julian Date (fractional part)
 ///
+/// # Errors
+///
+/// `PrecessionNutationError::SeriesNotInstalled` if no series has been
+/// installed with [`install_iau2000a_series`].
+pub fn iau2000a_nutation(jd1: f64, jd2: f64) -> Result<NutationAngles, PrecessionNutationError> {
+    let series = IAU2000A_SERIES
+        .read()
+        .ok()
+        .and_then(|guard| guard.clone())
+        .ok_or(PrecessionNutationError::SeriesNotInstalled)?;
+
+    let t = ((jd1 - JD_J2000) + jd2) / 36525.0;
+    Ok(series.evaluate(t))
+}
+
+/// Calculate nutation angles using the selected model
+///
+/// # Arguments
+///
+/// * `jd1` - TT as a 2-part Julian Date (integer part)
+/// * `jd2` - TT as a 2-part Julian Date (fractional part)
+/// * `model` - IAU 2000A or IAU 2000B
+pub fn nutation(
+    jd1: f64,
+    jd2: f64,
+    model: NutationModel,
+) -> Result<NutationAngles, PrecessionNutationError> {
+    match model {
+        NutationModel::Iau2000A => iau2000a_nutation(jd1, jd2),
+        NutationModel::Iau2000B => Ok(iau2000b_nutation(jd1, jd2)),
+    }
+}
+
+/// Form the nutation matrix from the mean obliquity and nutation angles
+///
+/// Computes **N = R₁(-(εₐ+Δε)) · R₃(-Δψ) · R₁(εₐ)**, which rotates mean-of-date
+/// coordinates into true-of-date coordinates.
+///
+/// # Arguments
+///
+/// * `epsa` - Mean obliquity of date (radians)
+/// * `nut` - Nutation in longitude and obliquity
+pub fn nutation_matrix_from_angles(epsa: f64, nut: &NutationAngles) -> Matrix3<f64> {
+    // R_n(θ) here are frame rotations, i.e. active rotations by -θ
+    rotation_x(epsa + nut.deps) * rotation_z(nut.dpsi) * rotation_x(-epsa)
+}
+
+/// Calculate the nutation matrix for the selected model
+///
+/// # Arguments
+///
+/// * `jd1` - TT as a 2-part Julian Date (integer part)
+/// * `jd2` - TT as a 2-part Julian Date (fractional part)
+/// * `model` - IAU 2000A or IAU 2000B
+///
 /// # Returns
 ///
 /// 3×3 nutation matrix (mean of date -> true of date)
+pub fn nutation_matrix(
+    jd1: f64,
+    jd2: f64,
+    model: NutationModel,
+) -> Result<Matrix3<f64>, PrecessionNutationError> {
+    let nut = nutation(jd1, jd2, model)?;
+    let epsa = iau2006_precession(jd1, jd2).epsa;
+    Ok(nutation_matrix_from_angles(epsa, &nut))
+}
+
+/// Calculate the IAU 2000B nutation matrix
 ///
-/// # Note
+/// Retained under its original name for compatibility; this now applies the
+/// 77-term IAU 2000B series (~1 mas accuracy).
 ///
-/// This is a simplified implementation. For applications requiring milliarcsecond
-/// precision, consider using astropy or implementing the full IAU 2000A series.
+/// # Arguments
+///
+/// * `jd1` - TT as a 2-part Julian Date (integer part)
+/// * `jd2` - TT as a 2-part Julian Date (fractional part)
+///
+/// # Returns
+///
+/// 3×3 nutation matrix (mean of date -> true of date)
 pub fn simplified_nutation_matrix(jd1: f64, jd2: f64) -> Matrix3<f64> {
-    // For now, return identity matrix (no nutation correction)
-    // TODO: Implement simplified nutation model (IAU 2000B with 77 terms)
-    // or full IAU 2000A if needed
-    Matrix3::identity()
+    let epsa = iau2006_precession(jd1, jd2).epsa;
+    nutation_matrix_from_angles(epsa, &iau2000b_nutation(jd1, jd2))
 }
 
-/// Calculate combined IAU 2006 precession matrix (nutation pending)
+/// Calculate combined IAU 2006 precession / IAU 2000 nutation matrix for a model
 ///
-/// Computes the precession matrix using IAU 2006 model. Nutation is currently
-/// not applied (simplified model returns identity).
+/// The bias-precession-nutation matrix is formed directly from the
+/// Fukushima-Williams angles with the nutation added to ψ̄ and εₐ
+/// (Wallace & Capitaine 2006).
 ///
 /// # Arguments
 ///
 /// * `jd1` - TT as a 2-part Julian Date (integer part)
 /// * `jd2` - TT as a 2-part Julian Date (fractional part)
+/// * `model` - IAU 2000A or IAU 2000B
 ///
 /// # Returns
 ///
-/// 3×3 rotation matrix (GCRS -> mean/true of date)
+/// 3×3 rotation matrix (GCRS -> true equator and equinox of date)
+pub fn iau2006_precession_nutation_matrix_with(
+    jd1: f64,
+    jd2: f64,
+    model: NutationModel,
+) -> Result<Matrix3<f64>, PrecessionNutationError> {
+    let nut = nutation(jd1, jd2, model)?;
+    let prec = iau2006_precession(jd1, jd2);
+    Ok(precession_nutation_from_angles(&prec, &nut))
+}
+
+/// Calculate combined IAU 2006 precession / IAU 2000 nutation matrix
+///
+/// Uses IAU 2000A nutation when a full series has been installed
+/// (see [`install_iau2000a_series`]), otherwise IAU 2000B; the model in use
+/// is [`NutationModel::best_available`].
+///
+/// # Arguments
+///
+/// * `jd1` - TT as a 2-part Julian Date (integer part)
+/// * `jd2` - TT as a 2-part Julian Date (fractional part)
+///
+/// # Returns
+///
+/// 3×3 rotation matrix (GCRS -> true equator and equinox of date)
 ///
 /// # Accuracy
 ///
 /// Precession: ~0.1 milliarcseconds
-/// Nutation: Not yet implemented (future enhancement)
+/// Nutation: ~0.1 mas (IAU 2000A) or ~1 mas (IAU 2000B)
 ///
 /// # Examples
 ///
@@ -325,22 +1005,36 @@ pub fn simplified_nutation_matrix(jd1: f64, jd2: f64) -> Matrix3<f64> {
 /// let epoch = Epoch::from_gregorian_utc(2025, 10, 22, 0, 0, 0, 0);
 /// let (jd1, jd2) = epoch.to_jd_tt_two_part();
 ///
-/// // Get precession matrix
+/// // Get precession-nutation matrix
 /// let pn = iau2006_precession_nutation_matrix(jd1, jd2);
 ///
-/// // Transform position from GCRS
+/// // Transform position from GCRS to true of date
 /// let pos_gcrs = Vector3::new(7000000.0, 0.0, 0.0);
-/// let pos_transformed = pn * pos_gcrs;
+/// let pos_tod = pn * pos_gcrs;
 /// ```
 pub fn iau2006_precession_nutation_matrix(jd1: f64, jd2: f64) -> Matrix3<f64> {
-    let p_matrix = iau2006_precession_matrix(jd1, jd2);
-    let n_matrix = simplified_nutation_matrix(jd1, jd2);
+    match iau2006_precession_nutation_matrix_with(jd1, jd2, NutationModel::best_available()) {
+        Ok(matrix) => matrix,
+        // The IAU 2000A series may have been cleared concurrently; IAU 2000B cannot fail
+        Err(_) => precession_nutation_from_angles(
+            &iau2006_precession(jd1, jd2),
+            &iau2000b_nutation(jd1, jd2),
+        ),
+    }
+}
 
-    // Combined matrix: nutation then precession
-    n_matrix * p_matrix
+/// Add nutation to the Fukushima-Williams angles and form the NPB matrix
+fn precession_nutation_from_angles(prec: &PrecessionAngles, nut: &NutationAngles) -> Matrix3<f64> {
+    fukushima_williams_to_matrix(&PrecessionAngles {
+        gamb: prec.gamb,
+        phib: prec.phib,
+        psib: prec.psib + nut.dpsi,
+        epsa: prec.epsa + nut.deps,
+    })
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision)] // SOFA reference values are quoted verbatim
 mod tests {
     use super::*;
     use approx::assert_relative_eq;
@@ -441,4 +1135,201 @@ mod tests {
         // Determinant should be +1
         assert_relative_eq!(pn.determinant(), 1.0, epsilon = 1e-14);
     }
+
+    #[test]
+    fn test_precession_matrix_sofa_reference() {
+        // SOFA t_sofa_c: iauPmat06(2400000.5, 50123.9999)
+        let p = iau2006_precession_matrix(2400000.5, 50123.9999);
+        let expected = Matrix3::new(
+            0.9999995505176007047, 0.8695404617348208406e-3, 0.3779735201865589104e-3,
+            -0.8695404723772031414e-3, 0.9999996219496027161, -0.1361752497080270143e-6,
+            -0.3779734957034089490e-3, -0.1924880847894457113e-6, 0.9999999285679971958,
+        );
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_relative_eq!(p[(i, j)], expected[(i, j)], epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_iau2000b_nutation_sofa_reference() {
+        // SOFA t_sofa_c: iauNut00b(2400000.5, 53736.0)
+        let nut = iau2000b_nutation(2400000.5, 53736.0);
+        assert_relative_eq!(nut.dpsi, -0.9632552291148362783e-5, epsilon = 1e-13);
+        assert_relative_eq!(nut.deps, 0.4063197106621159367e-4, epsilon = 1e-13);
+    }
+
+    #[test]
+    fn test_iau2000b_nutation_magnitude() {
+        // 18.6-year term plus the semi-annual and shorter terms stay below ~19" / ~10"
+        for year in 0..50 {
+            let nut = iau2000b_nutation(2451545.0, year as f64 * 365.25);
+            assert!(nut.dpsi.abs() < 20.0 * ARCSEC_TO_RAD);
+            assert!(nut.deps.abs() < 10.5 * ARCSEC_TO_RAD);
+        }
+    }
+
+    #[test]
+    fn test_nutation_matrix_sofa_reference() {
+        // SOFA t_sofa_c: iauNumat(epsa, dpsi, deps)
+        let nut = NutationAngles {
+            dpsi: -0.9630909107115582393e-5,
+            deps: 0.4063239174001678826e-4,
+        };
+        let n = nutation_matrix_from_angles(0.4090789763356509900, &nut);
+        let expected = Matrix3::new(
+            0.9999999999536227949, 0.8836239320236250577e-5, 0.3830833447458251908e-5,
+            -0.8836083657016688588e-5, 0.9999999991354654959, -0.4063240865361857698e-4,
+            -0.3831192481833385226e-5, 0.4063237480216934159e-4, 0.9999999991671660407,
+        );
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_relative_eq!(n[(i, j)], expected[(i, j)], epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_precession_nutation_matrix_2000b_sofa_reference() {
+        // SOFA t_sofa_c: iauPnm06a(2400000.5, 50123.9999) uses IAU 2000A;
+        // IAU 2000B agrees to ~1 mas (5e-9 rad)
+        let pn = iau2006_precession_nutation_matrix_with(
+            2400000.5,
+            50123.9999,
+            NutationModel::Iau2000B,
+        )
+        .unwrap();
+        let expected = Matrix3::new(
+            0.9999995832794205484, 0.8372382772630962111e-3, 0.3639684771140623099e-3,
+            -0.8372533744743683605e-3, 0.9999996486492861646, 0.4132905944611019498e-4,
+            -0.3639337469629464969e-3, -0.4163377605910663999e-4, 0.9999999329094260057,
+        );
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((pn[(i, j)] - expected[(i, j)]).abs() < 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_combined_matrix_equals_nutation_times_precession() {
+        let (jd1, jd2) = (2451545.0, 8000.25);
+        let pn = iau2006_precession_nutation_matrix_with(jd1, jd2, NutationModel::Iau2000B).unwrap();
+        let n = nutation_matrix(jd1, jd2, NutationModel::Iau2000B).unwrap();
+        let p = iau2006_precession_matrix(jd1, jd2);
+        let np = n * p;
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_relative_eq!(pn[(i, j)], np[(i, j)], epsilon = 1e-14);
+            }
+        }
+        assert_eq!(simplified_nutation_matrix(jd1, jd2), n);
+    }
+
+    #[test]
+    fn test_iau2000a_requires_installed_series() {
+        let result = iau2000a_nutation(2451545.0, 0.0);
+        assert!(matches!(result, Err(PrecessionNutationError::SeriesNotInstalled)));
+
+        // IAU 2000A only when asked for; the default evaluates with IAU 2000B
+        let result = nutation(2451545.0, 0.0, NutationModel::Iau2000A);
+        assert!(matches!(result, Err(PrecessionNutationError::SeriesNotInstalled)));
+        assert_eq!(NutationModel::best_available(), NutationModel::Iau2000B);
+        assert_eq!(NutationModel::best_available().name(), "IAU2000B");
+        assert_eq!(NutationModel::default(), NutationModel::Iau2000B);
+        assert_eq!(
+            nutation(2451545.0, 0.0, NutationModel::default()).unwrap(),
+            nutation(2451545.0, 0.0, NutationModel::Iau2000B).unwrap()
+        );
+    }
+
+    #[test]
+    #[ignore] // Needs IERS Tables 5.3a/5.3b in the directory named by ASTRORA_NUTATION_TABLES
+    fn test_iau2000a_iers_tables_sofa_reference() {
+        let dir = std::path::PathBuf::from(std::env::var("ASTRORA_NUTATION_TABLES").unwrap());
+        let series = NutationSeries::from_files(dir.join("tab5.3a.txt"), dir.join("tab5.3b.txt")).unwrap();
+
+        // SOFA t_sofa_c: iauNut06a(2400000.5, 53736.0). The IERS tables use the
+        // full fundamental arguments throughout, so agreement is at the µas level
+        let (jd1, jd2) = (2400000.5, 53736.0);
+        let nut = series.evaluate(((jd1 - JD_J2000) + jd2) / 36525.0);
+        assert!((nut.dpsi - -0.9630912025820308797e-5).abs() < 1e-11);
+        assert!((nut.deps - 0.4063238496887249798e-4).abs() < 1e-11);
+
+        // SOFA t_sofa_c: iauPnm06a(2400000.5, 50123.9999)
+        let (jd1, jd2) = (2400000.5, 50123.9999);
+        let nut = series.evaluate(((jd1 - JD_J2000) + jd2) / 36525.0);
+        let pn = precession_nutation_from_angles(&iau2006_precession(jd1, jd2), &nut);
+        let expected = Matrix3::new(
+            0.9999995832794205484, 0.8372382772630962111e-3, 0.3639684771140623099e-3,
+            -0.8372533744743683605e-3, 0.9999996486492861646, 0.4132905944611019498e-4,
+            -0.3639337469629464969e-3, -0.4163377605910663999e-4, 0.9999999329094260057,
+        );
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((pn[(i, j)] - expected[(i, j)]).abs() < 1e-11);
+            }
+        }
+    }
+
+    /// Write the IAU 2000B luni-solar terms in IERS Table 5.3a/5.3b layout
+    fn iau2000b_as_iers_tables() -> (String, String) {
+        let row = |i: usize, a: f64, b: f64, t: &LuniSolarTerm| {
+            format!(
+                "{:5} {:16.2} {:14.2} {:4} {:4} {:4} {:4} {:4}    0    0    0    0    0    0    0    0    0\n",
+                i, a, b, t.nl, t.nlp, t.nf, t.nd, t.nom
+            )
+        };
+        let header = "Table 5.3x test excerpt\n    i  A_i  A\"_i  l  l'  F  D  Om ...\n";
+
+        let mut lon = format!("{header} j = 0  Number of terms = 77\n");
+        let mut obl = format!("{header} j = 0  Number of terms = 77\n");
+        for (i, t) in IAU2000B_TERMS.iter().enumerate() {
+            lon.push_str(&row(i + 1, t.ps / 10.0, t.pc / 10.0, t));
+            obl.push_str(&row(i + 1, t.ec / 10.0, t.es / 10.0, t));
+        }
+        lon.push_str(" j = 1  Number of terms = 77\n");
+        obl.push_str(" j = 1  Number of terms = 77\n");
+        for (i, t) in IAU2000B_TERMS.iter().enumerate() {
+            lon.push_str(&row(i + 78, t.pst / 10.0, 0.0, t));
+            obl.push_str(&row(i + 78, t.ect / 10.0, 0.0, t));
+        }
+        (lon, obl)
+    }
+
+    #[test]
+    fn test_iers_table_parse_and_evaluate() {
+        let (lon, obl) = iau2000b_as_iers_tables();
+        let series = NutationSeries::from_iers_tables(&lon, &obl).unwrap();
+        assert_eq!(series.len(), (154, 154));
+        assert!(!series.is_empty());
+
+        // Same luni-solar terms with the polynomial arguments reproduce IAU 2000B
+        // (without its planetary offsets) to a few microarcseconds
+        let (jd1, jd2) = (2400000.5, 53736.0);
+        let t = ((jd1 - JD_J2000) + jd2) / 36525.0;
+        let from_table = series.evaluate(t);
+        let reference = iau2000b_nutation(jd1, jd2);
+        assert!((from_table.dpsi - (reference.dpsi - IAU2000B_DPPLAN)).abs() < 5e-11);
+        assert!((from_table.deps - (reference.deps - IAU2000B_DEPLAN)).abs() < 5e-11);
+    }
+
+    #[test]
+    fn test_iers_table_parse_errors() {
+        let row = "    1   -17206424.18        3338.60    0    0    0    0    1    0    0    0    0    0    0    0    0    0";
+
+        // Data before any section header
+        let err = NutationSeries::from_iers_tables(row, row).unwrap_err();
+        assert!(matches!(err, PrecessionNutationError::SeriesParse { line: 1, .. }));
+
+        // Empty tables
+        let err = NutationSeries::from_iers_tables("header only", "header only").unwrap_err();
+        assert!(matches!(err, PrecessionNutationError::SeriesParse { .. }));
+
+        // Missing files
+        let err = NutationSeries::from_files("/nonexistent/tab5.3a.txt", "/nonexistent/tab5.3b.txt")
+            .unwrap_err();
+        assert!(matches!(err, PrecessionNutationError::Io(_)));
+    }
 }
@@ -7,10 +7,12 @@
 //! # Design
 //!
 //! The transformation system uses GCRS as a central hub, since:
-//! - ICRS ↔ GCRS: Simple (nearly identity for Earth satellites)
+//! - ICRS ↔ GCRS: Translation by Earth's barycentric state (parallel axes)
 //! - J2000 ↔ GCRS: Simple (identity at J2000 epoch)
 //! - ITRS ↔ GCRS: ERA rotation + Coriolis
 //! - TEME ↔ GCRS: Via ITRS with GMST rotation
+//! - MOD / TOD ↔ GCRS: Bias-precession (and nutation) matrix
+//! - CIRS / TIRS ↔ GCRS: Leading factors of the IERS 2010 CIO-based chain
 //!
 //! This hub-and-spoke design minimizes the number of direct transformations needed
 //! while ensuring all frames can be converted to each other.
@@ -34,8 +36,8 @@
 //! let itrs = gcrs.transform_to_itrs().unwrap();
 //! ```
 
-use crate::coordinates::frames::{GCRS, ICRS, ITRS, J2000, TEME};
-use crate::core::error::PoliastroResult;
+use crate::coordinates::frames::{CIRS, GCRS, ICRS, ITRS, J2000, MOD, TEME, TIRS, TOD};
+use crate::core::error::{PoliastroError, PoliastroResult};
 use crate::core::time::Epoch;
 use nalgebra::Vector3;
 
@@ -52,6 +54,14 @@ pub enum FrameType {
     ITRS,
     /// True Equator Mean Equinox (geocentric inertial, legacy SGP4)
     TEME,
+    /// Mean equator and equinox Of Date (geocentric, IAU 2006 precession)
+    MOD,
+    /// True equator and equinox Of Date (geocentric, precession-nutation)
+    TOD,
+    /// Celestial Intermediate Reference System (geocentric, CIP and CIO of date)
+    CIRS,
+    /// Terrestrial Intermediate Reference System (Earth-fixed, no polar motion)
+    TIRS,
 }
 
 impl std::fmt::Display for FrameType {
@@ -62,6 +72,30 @@ impl std::fmt::Display for FrameType {
             FrameType::J2000 => write!(f, "J2000"),
             FrameType::ITRS => write!(f, "ITRS"),
             FrameType::TEME => write!(f, "TEME"),
+            FrameType::MOD => write!(f, "MOD"),
+            FrameType::TOD => write!(f, "TOD"),
+            FrameType::CIRS => write!(f, "CIRS"),
+            FrameType::TIRS => write!(f, "TIRS"),
+        }
+    }
+}
+
+impl std::str::FromStr for FrameType {
+    type Err = PoliastroError;
+
+    /// Parse a frame from its name as displayed ("GCRS", "ITRS", ...), in any case
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_ascii_uppercase().as_str() {
+            "ICRS" => Ok(FrameType::ICRS),
+            "GCRS" => Ok(FrameType::GCRS),
+            "J2000" => Ok(FrameType::J2000),
+            "ITRS" => Ok(FrameType::ITRS),
+            "TEME" => Ok(FrameType::TEME),
+            "MOD" => Ok(FrameType::MOD),
+            "TOD" => Ok(FrameType::TOD),
+            "CIRS" => Ok(FrameType::CIRS),
+            "TIRS" => Ok(FrameType::TIRS),
+            _ => Err(PoliastroError::unknown_frame(name)),
         }
     }
 }
@@ -82,7 +116,7 @@ pub trait CoordinateFrame: Sized {
     fn velocity(&self) -> Vector3<f64>;
 
     /// Get the observation time (epoch) for this coordinate
-    /// Returns None for frames without a time attribute (like ICRS in V1)
+    /// Returns None for frames without a time attribute
     fn obstime(&self) -> Option<Epoch>;
 
     /// Convert this frame to GCRS (central hub for all transformations)
@@ -121,6 +155,30 @@ pub trait CoordinateFrame: Sized {
         let itrs = gcrs.to_itrs()?;
         itrs.to_teme()
     }
+
+    /// Transform to MOD (mean equator and equinox of date)
+    fn transform_to_mod(&self) -> PoliastroResult<MOD> {
+        let gcrs = self.to_gcrs_frame()?;
+        gcrs.to_mod()
+    }
+
+    /// Transform to TOD (true equator and equinox of date)
+    fn transform_to_tod(&self) -> PoliastroResult<TOD> {
+        let gcrs = self.to_gcrs_frame()?;
+        gcrs.to_tod()
+    }
+
+    /// Transform to CIRS (celestial intermediate frame)
+    fn transform_to_cirs(&self) -> PoliastroResult<CIRS> {
+        let gcrs = self.to_gcrs_frame()?;
+        gcrs.to_cirs()
+    }
+
+    /// Transform to TIRS (terrestrial intermediate frame)
+    fn transform_to_tirs(&self) -> PoliastroResult<TIRS> {
+        let gcrs = self.to_gcrs_frame()?;
+        gcrs.to_tirs()
+    }
 }
 
 // Implement CoordinateFrame for ICRS
@@ -138,12 +196,11 @@ impl CoordinateFrame for ICRS {
     }
 
     fn obstime(&self) -> Option<Epoch> {
-        None // ICRS doesn't have a time attribute in V1
+        Some(self.obstime)
     }
 
     fn to_gcrs_frame(&self) -> PoliastroResult<GCRS> {
-        // Need an epoch for GCRS - use J2000 as default
-        self.to_gcrs(&Epoch::j2000()) // J2000.0
+        self.to_gcrs(&self.obstime)
     }
 
     fn from_gcrs_frame(gcrs: &GCRS) -> PoliastroResult<Self> {
@@ -261,6 +318,114 @@ impl CoordinateFrame for TEME {
     }
 }
 
+// Implement CoordinateFrame for MOD
+impl CoordinateFrame for MOD {
+    fn frame_type(&self) -> FrameType {
+        FrameType::MOD
+    }
+
+    fn position(&self) -> Vector3<f64> {
+        self.position
+    }
+
+    fn velocity(&self) -> Vector3<f64> {
+        self.velocity
+    }
+
+    fn obstime(&self) -> Option<Epoch> {
+        Some(self.obstime)
+    }
+
+    fn to_gcrs_frame(&self) -> PoliastroResult<GCRS> {
+        self.to_gcrs()
+    }
+
+    fn from_gcrs_frame(gcrs: &GCRS) -> PoliastroResult<Self> {
+        gcrs.to_mod()
+    }
+}
+
+// Implement CoordinateFrame for TOD
+impl CoordinateFrame for TOD {
+    fn frame_type(&self) -> FrameType {
+        FrameType::TOD
+    }
+
+    fn position(&self) -> Vector3<f64> {
+        self.position
+    }
+
+    fn velocity(&self) -> Vector3<f64> {
+        self.velocity
+    }
+
+    fn obstime(&self) -> Option<Epoch> {
+        Some(self.obstime)
+    }
+
+    fn to_gcrs_frame(&self) -> PoliastroResult<GCRS> {
+        self.to_gcrs()
+    }
+
+    fn from_gcrs_frame(gcrs: &GCRS) -> PoliastroResult<Self> {
+        gcrs.to_tod()
+    }
+}
+
+// Implement CoordinateFrame for CIRS
+impl CoordinateFrame for CIRS {
+    fn frame_type(&self) -> FrameType {
+        FrameType::CIRS
+    }
+
+    fn position(&self) -> Vector3<f64> {
+        self.position
+    }
+
+    fn velocity(&self) -> Vector3<f64> {
+        self.velocity
+    }
+
+    fn obstime(&self) -> Option<Epoch> {
+        Some(self.obstime)
+    }
+
+    fn to_gcrs_frame(&self) -> PoliastroResult<GCRS> {
+        self.to_gcrs()
+    }
+
+    fn from_gcrs_frame(gcrs: &GCRS) -> PoliastroResult<Self> {
+        gcrs.to_cirs()
+    }
+}
+
+// Implement CoordinateFrame for TIRS
+impl CoordinateFrame for TIRS {
+    fn frame_type(&self) -> FrameType {
+        FrameType::TIRS
+    }
+
+    fn position(&self) -> Vector3<f64> {
+        self.position
+    }
+
+    fn velocity(&self) -> Vector3<f64> {
+        self.velocity
+    }
+
+    fn obstime(&self) -> Option<Epoch> {
+        Some(self.obstime)
+    }
+
+    fn to_gcrs_frame(&self) -> PoliastroResult<GCRS> {
+        self.to_gcrs()
+    }
+
+    fn from_gcrs_frame(gcrs: &GCRS) -> PoliastroResult<Self> {
+        gcrs.to_tirs()
+    }
+}
+
 /// Generic transformation function that can transform any frame to any other frame
 ///
 /// This function provides a convenient way to transform coordinates without knowing
@@ -301,14 +466,79 @@ pub fn transform_position_velocity<F: CoordinateFrame>(
             let teme = from_frame.transform_to_teme()?;
             Ok((*teme.position(), *teme.velocity()))
         }
+        FrameType::MOD => {
+            let mod_frame = from_frame.transform_to_mod()?;
+            Ok((*mod_frame.position(), *mod_frame.velocity()))
+        }
+        FrameType::TOD => {
+            let tod = from_frame.transform_to_tod()?;
+            Ok((*tod.position(), *tod.velocity()))
+        }
+        FrameType::CIRS => {
+            let cirs = from_frame.transform_to_cirs()?;
+            Ok((*cirs.position(), *cirs.velocity()))
+        }
+        FrameType::TIRS => {
+            let tirs = from_frame.transform_to_tirs()?;
+            Ok((*tirs.position(), *tirs.velocity()))
+        }
+    }
+}
+
+/// Transform a position and velocity at `epoch` between two frames known only
+/// at run time
+///
+/// J2000 states are taken to share the GCRS axes at `epoch`, as in
+/// [`J2000::from_gcrs`], so the epoch still applies to time-dependent targets.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let (r_itrs, v_itrs) = transform_state(&r, &v, &epoch, FrameType::TEME, FrameType::ITRS)?;
+/// ```
+pub fn transform_state(
+    position: &Vector3<f64>,
+    velocity: &Vector3<f64>,
+    epoch: &Epoch,
+    from_frame_type: FrameType,
+    to_frame_type: FrameType,
+) -> PoliastroResult<(Vector3<f64>, Vector3<f64>)> {
+    if from_frame_type == to_frame_type {
+        return Ok((*position, *velocity));
+    }
+
+    let (r, v, t) = (*position, *velocity, *epoch);
+    match from_frame_type {
+        FrameType::ICRS => transform_position_velocity(&ICRS::with_obstime(r, v, t), to_frame_type),
+        FrameType::GCRS | FrameType::J2000 => {
+            transform_position_velocity(&GCRS::new(r, v, t), to_frame_type)
+        }
+        FrameType::ITRS => transform_position_velocity(&ITRS::new(r, v, t), to_frame_type),
+        FrameType::TEME => transform_position_velocity(&TEME::new(r, v, t), to_frame_type),
+        FrameType::MOD => transform_position_velocity(&MOD::new(r, v, t), to_frame_type),
+        FrameType::TOD => transform_position_velocity(&TOD::new(r, v, t), to_frame_type),
+        FrameType::CIRS => transform_position_velocity(&CIRS::new(r, v, t), to_frame_type),
+        FrameType::TIRS => transform_position_velocity(&TIRS::new(r, v, t), to_frame_type),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ephemeris::earth_barycentric_state;
     use approx::assert_abs_diff_eq;
 
+    /// ICRS state of a satellite at `position`/`velocity` relative to the geocenter
+    fn icrs_near_earth(position: Vector3<f64>, velocity: Vector3<f64>, epoch: Epoch) -> ICRS {
+        let (earth_pos, earth_vel) = earth_barycentric_state(&epoch).unwrap();
+        ICRS::with_obstime(position + earth_pos, velocity + earth_vel, epoch)
+    }
+
+    /// Geocentric position of an ICRS state (barycentric translation removed)
+    fn geocentric_position(icrs: &ICRS) -> Vector3<f64> {
+        icrs.position() - earth_barycentric_state(icrs.obstime()).unwrap().0
+    }
+
     #[test]
     fn test_gcrs_to_icrs_transform() {
         let epoch = Epoch::j2000(); // J2000.0
@@ -320,9 +550,11 @@ mod tests {
 
         let icrs = gcrs.transform_to_icrs().unwrap();
 
-        // At J2000, GCRS ≈ ICRS (barycentric correction is small for Earth satellites)
-        assert_abs_diff_eq!(icrs.position().norm(), gcrs.position().norm(), epsilon = 1.0);
-        assert_abs_diff_eq!(icrs.velocity().norm(), gcrs.velocity().norm(), epsilon = 0.1);
+        // Same axes, origin moved to the barycenter
+        let (earth_pos, earth_vel) = earth_barycentric_state(&epoch).unwrap();
+        assert_abs_diff_eq!(*icrs.position(), gcrs.position() + earth_pos, epsilon = 1e-3);
+        assert_abs_diff_eq!(*icrs.velocity(), gcrs.velocity() + earth_vel, epsilon = 1e-9);
+        assert_eq!(icrs.obstime(), &epoch);
     }
 
     #[test]
@@ -419,6 +651,10 @@ mod tests {
         assert_eq!(FrameType::J2000.to_string(), "J2000");
         assert_eq!(FrameType::ITRS.to_string(), "ITRS");
         assert_eq!(FrameType::TEME.to_string(), "TEME");
+        assert_eq!(FrameType::MOD.to_string(), "MOD");
+        assert_eq!(FrameType::TOD.to_string(), "TOD");
+        assert_eq!(FrameType::CIRS.to_string(), "CIRS");
+        assert_eq!(FrameType::TIRS.to_string(), "TIRS");
     }
 
     #[test]
@@ -507,7 +743,7 @@ mod tests {
 
         assert_abs_diff_eq!(*icrs.position(), pos, epsilon = 1e-6);
         assert_abs_diff_eq!(*icrs.velocity(), vel, epsilon = 1e-6);
-        assert!(icrs.obstime().is_none());
+        assert_eq!(icrs.obstime(), &Epoch::j2000());
     }
 
     #[test]
@@ -560,54 +796,58 @@ mod tests {
     // Test ICRS transformations
     #[test]
     fn test_icrs_to_gcrs() {
-        let icrs = ICRS::new(
+        let icrs = icrs_near_earth(
             Vector3::new(7000e3, 0.0, 0.0),
             Vector3::new(0.0, 7500.0, 0.0),
+            Epoch::j2000(),
         );
 
         let gcrs = icrs.transform_to_gcrs().unwrap();
 
-        // Position magnitude should be conserved
-        assert_abs_diff_eq!(gcrs.position().norm(), icrs.position().norm(), epsilon = 1.0);
+        // Geocentric position magnitude should be conserved
+        assert_abs_diff_eq!(gcrs.position().norm(), 7000e3, epsilon = 1e-3);
     }
 
     #[test]
     fn test_icrs_to_j2000() {
-        let icrs = ICRS::new(
+        let icrs = icrs_near_earth(
             Vector3::new(7000e3, 0.0, 0.0),
             Vector3::new(0.0, 7500.0, 0.0),
+            Epoch::j2000(),
         );
 
         let j2000 = icrs.transform_to_j2000().unwrap();
 
-        // Position magnitude should be conserved
-        assert_abs_diff_eq!(j2000.position().norm(), icrs.position().norm(), epsilon = 1.0);
+        // Geocentric position magnitude should be conserved
+        assert_abs_diff_eq!(j2000.position().norm(), 7000e3, epsilon = 1e-3);
     }
 
     #[test]
     fn test_icrs_to_itrs() {
-        let icrs = ICRS::new(
+        let icrs = icrs_near_earth(
             Vector3::new(7000e3, 0.0, 0.0),
             Vector3::new(0.0, 7500.0, 0.0),
+            Epoch::j2000(),
         );
 
         let itrs = icrs.transform_to_itrs().unwrap();
 
-        // Position magnitude should be conserved
-        assert_abs_diff_eq!(itrs.position().norm(), icrs.position().norm(), epsilon = 1.0);
+        // Geocentric position magnitude should be conserved
+        assert_abs_diff_eq!(itrs.position().norm(), 7000e3, epsilon = 1e-3);
     }
 
     #[test]
     fn test_icrs_to_teme() {
-        let icrs = ICRS::new(
+        let icrs = icrs_near_earth(
             Vector3::new(7000e3, 0.0, 0.0),
             Vector3::new(0.0, 7500.0, 0.0),
+            Epoch::j2000(),
         );
 
         let teme = icrs.transform_to_teme().unwrap();
 
-        // Position magnitude should be conserved
-        assert_abs_diff_eq!(teme.position().norm(), icrs.position().norm(), epsilon = 1.0);
+        // Geocentric position magnitude should be conserved
+        assert_abs_diff_eq!(teme.position().norm(), 7000e3, epsilon = 1e-3);
     }
 
     #[test]
@@ -634,8 +874,8 @@ mod tests {
 
         let icrs = j2000.transform_to_icrs().unwrap();
 
-        // At J2000 epoch, should be very similar
-        assert_abs_diff_eq!(icrs.position().norm(), j2000.position().norm(), epsilon = 1.0);
+        // Geocentric position magnitude should be conserved
+        assert_abs_diff_eq!(geocentric_position(&icrs).norm(), j2000.position().norm(), epsilon = 1e-3);
     }
 
     #[test]
@@ -691,8 +931,8 @@ mod tests {
 
         let icrs = itrs.transform_to_icrs().unwrap();
 
-        // Position magnitude should be conserved
-        assert_abs_diff_eq!(icrs.position().norm(), itrs.position().norm(), epsilon = 1.0);
+        // Geocentric position magnitude should be conserved
+        assert_abs_diff_eq!(geocentric_position(&icrs).norm(), itrs.position().norm(), epsilon = 1e-3);
     }
 
     #[test]
@@ -737,8 +977,8 @@ mod tests {
 
         let icrs = teme.transform_to_icrs().unwrap();
 
-        // Position magnitude should be conserved
-        assert_abs_diff_eq!(icrs.position().norm(), teme.position().norm(), epsilon = 1.0);
+        // Geocentric position magnitude should be conserved
+        assert_abs_diff_eq!(geocentric_position(&icrs).norm(), teme.position().norm(), epsilon = 1e-3);
     }
 
     #[test]
@@ -962,4 +1202,125 @@ mod tests {
         assert_abs_diff_eq!(gcrs_back.position().norm(), gcrs.position().norm(), epsilon = 1.0);
         assert_abs_diff_eq!(gcrs_back.velocity().norm(), gcrs.velocity().norm(), epsilon = 0.1);
     }
+
+    #[test]
+    fn test_frames_of_date_from_to_consistency() {
+        let epoch = Epoch::from_gregorian_utc(2025, 10, 22, 12, 0, 0, 0);
+        let gcrs = GCRS::new(
+            Vector3::new(7000e3, 1000e3, -2000e3),
+            Vector3::new(-1000.0, 7500.0, 500.0),
+            epoch,
+        );
+
+        let via_mod = MOD::from_gcrs_frame(&gcrs).unwrap().to_gcrs_frame().unwrap();
+        let via_tod = TOD::from_gcrs_frame(&gcrs).unwrap().to_gcrs_frame().unwrap();
+        let via_cirs = CIRS::from_gcrs_frame(&gcrs).unwrap().to_gcrs_frame().unwrap();
+        let via_tirs = TIRS::from_gcrs_frame(&gcrs).unwrap().to_gcrs_frame().unwrap();
+
+        for back in [via_mod, via_tod, via_cirs, via_tirs] {
+            assert_abs_diff_eq!(back.position(), gcrs.position(), epsilon = 1e-6);
+            assert_abs_diff_eq!(back.velocity(), gcrs.velocity(), epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_mod_at_j2000_is_frame_bias() {
+        // At J2000.0 only the frame bias (~23 mas) separates MOD from GCRS
+        let gcrs = GCRS::new(Vector3::new(7000e3, 0.0, 0.0), Vector3::zeros(), Epoch::j2000());
+        let mod_frame = gcrs.transform_to_mod().unwrap();
+
+        let offset = (mod_frame.position() - gcrs.position()).norm();
+        assert!(offset > 0.1 && offset < 2.0, "offset = {offset}");
+    }
+
+    #[test]
+    fn test_tod_and_cirs_share_pole() {
+        // Both frames use the CIP as z-axis; they differ by a rotation about it
+        let epoch = Epoch::from_gregorian_utc(2025, 10, 22, 12, 0, 0, 0);
+        let gcrs = GCRS::new(Vector3::new(7000e3, 1000e3, 3000e3), Vector3::zeros(), epoch);
+
+        let tod = gcrs.transform_to_tod().unwrap();
+        let cirs = gcrs.transform_to_cirs().unwrap();
+        let mod_frame = gcrs.transform_to_mod().unwrap();
+
+        assert_abs_diff_eq!(tod.position().z, cirs.position().z, epsilon = 1e-6);
+        // Nutation moves the pole by up to ~20"
+        let nutation_offset = (tod.position() - mod_frame.position()).norm();
+        assert!(nutation_offset > 1.0 && nutation_offset < 1000.0);
+    }
+
+    #[test]
+    fn test_tirs_matches_itrs_chain() {
+        let epoch = Epoch::from_gregorian_utc(2025, 10, 22, 12, 0, 0, 0);
+        let gcrs = GCRS::new(
+            Vector3::new(7000e3, 0.0, 0.0),
+            Vector3::new(0.0, 7500.0, 0.0),
+            epoch,
+        );
+
+        let itrs = gcrs.transform_to_itrs().unwrap();
+        let via_tirs = gcrs.transform_to_tirs().unwrap().to_itrs().unwrap();
+        assert_abs_diff_eq!(via_tirs.position(), itrs.position(), epsilon = 1e-6);
+        assert_abs_diff_eq!(via_tirs.velocity(), itrs.velocity(), epsilon = 1e-9);
+
+        // ITRS → TIRS removes only polar motion (a few metres)
+        let tirs = itrs.transform_to_tirs().unwrap();
+        let polar_offset = (tirs.position() - itrs.position()).norm();
+        assert!(polar_offset < 50.0);
+    }
+
+    #[test]
+    fn test_transform_position_velocity_to_frames_of_date() {
+        let epoch = Epoch::from_gregorian_utc(2025, 10, 22, 12, 0, 0, 0);
+        let teme = TEME::new(
+            Vector3::new(7000e3, 0.0, 0.0),
+            Vector3::new(0.0, 7500.0, 0.0),
+            epoch,
+        );
+
+        for frame_type in [FrameType::MOD, FrameType::TOD, FrameType::CIRS, FrameType::TIRS] {
+            let (pos, vel) = transform_position_velocity(&teme, frame_type).unwrap();
+            assert_abs_diff_eq!(pos.norm(), 7000e3, epsilon = 1e-3);
+            assert!(vel.norm() > 0.0);
+        }
+
+        // TEME and TOD share the true equator
+        let (tod_pos, _) = transform_position_velocity(&teme, FrameType::TOD).unwrap();
+        assert_abs_diff_eq!(tod_pos.z, teme.position().z, epsilon = 50.0);
+    }
+
+    #[test]
+    fn test_transform_state_matches_typed_frames() {
+        let epoch = Epoch::from_gregorian_utc(2025, 10, 22, 12, 0, 0, 0);
+        let r = Vector3::new(7000e3, 100e3, -50e3);
+        let v = Vector3::new(-10.0, 7500.0, 100.0);
+        let teme = TEME::new(r, v, epoch);
+
+        for frame_type in [FrameType::GCRS, FrameType::ITRS, FrameType::TOD, FrameType::ICRS] {
+            let (pos, vel) = transform_state(&r, &v, &epoch, FrameType::TEME, frame_type).unwrap();
+            let (pos_typed, vel_typed) = transform_position_velocity(&teme, frame_type).unwrap();
+            assert_abs_diff_eq!(pos, pos_typed, epsilon = 1e-6);
+            assert_abs_diff_eq!(vel, vel_typed, epsilon = 1e-9);
+
+            // And back again
+            let (r_back, v_back) = transform_state(&pos, &vel, &epoch, frame_type, FrameType::TEME).unwrap();
+            assert_abs_diff_eq!(r_back, r, epsilon = 1e-4);
+            assert_abs_diff_eq!(v_back, v, epsilon = 1e-7);
+        }
+
+        // J2000 keeps the epoch of the state rather than J2000.0
+        let (itrs_from_j2000, _) = transform_state(&r, &v, &epoch, FrameType::J2000, FrameType::ITRS).unwrap();
+        let (itrs_from_gcrs, _) = transform_state(&r, &v, &epoch, FrameType::GCRS, FrameType::ITRS).unwrap();
+        assert_abs_diff_eq!(itrs_from_j2000, itrs_from_gcrs, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_frame_type_from_str() {
+        for frame_type in [FrameType::ICRS, FrameType::GCRS, FrameType::J2000, FrameType::ITRS, FrameType::TEME,
+                           FrameType::MOD, FrameType::TOD, FrameType::CIRS, FrameType::TIRS] {
+            assert_eq!(frame_type.to_string().parse::<FrameType>().unwrap(), frame_type);
+        }
+        assert_eq!("itrs".parse::<FrameType>().unwrap(), FrameType::ITRS);
+        assert!("ECEF".parse::<FrameType>().is_err());
+    }
 }
@@ -0,0 +1,809 @@
+//! State covariance and its propagation
+//!
+//! [`Covariance6`] is the 6×6 covariance of a Cartesian state
+//! [x, y, z, vx, vy, vz] (or of a set of orbital elements). It is propagated
+//! either linearly through a state transition matrix, P₁ = Φ·P₀·Φᵀ, or with
+//! the unscented transform through any propagator, which captures the
+//! nonlinearity of long arcs and poorly known orbits at the cost of 2n + 1
+//! propagations.
+//!
+//! Covariances are rotated between the inertial frame and the local orbital
+//! frames used in conjunction assessment and maneuver planning:
+//!
+//! - RIC (also RSW): radial, in-track, cross-track
+//! - NTW: normal (in-plane), tangential (along velocity), cross-track
+//!
+//! The rotation is applied to position and velocity alike (block diagonal),
+//! as in CCSDS conjunction data messages, so velocity components are those of
+//! the inertial velocity resolved along the local axes.
+//!
+//! Conversions to Keplerian and equinoctial element covariances use the
+//! Jacobians of [`crate::core::elements`].
+//!
+//! # Example
+//! ```rust,ignore
+//! use astrora_core::core::covariance::Covariance6;
+//!
+//! let p0 = Covariance6::from_diagonal(&Vector6::new(1e2, 1e2, 1e2, 1e-2, 1e-2, 1e-2))?;
+//! let linear = p0.propagate_linear(&stm);
+//! let (mean, unscented) = p0.propagate_unscented(&state, |x| propagate(x))?;
+//! let ric = unscented.to_ric(&r, &v)?;
+//! ```
+
+use nalgebra::{DMatrix, DVector};
+use numpy::{PyArray1, PyArray2, PyReadonlyArray1, PyReadonlyArray2};
+use pyo3::prelude::*;
+
+use crate::coordinates::frames::state_from_arrays;
+use crate::core::elements::{
+    coe_to_rv_jacobian, equinoctial_to_rv_jacobian, rv_to_coe_jacobian, rv_to_equinoctial_jacobian,
+    EquinoctialElements, OrbitalElements, DEFAULT_TOL,
+};
+use crate::core::error::{PoliastroError, PoliastroResult};
+use crate::core::linalg::{Matrix3, Matrix6, Vector3, Vector6};
+
+/// Relative asymmetry accepted by [`Covariance6::new`]
+const SYMMETRY_TOLERANCE: f64 = 1e-9;
+
+/// Scaled unscented transform (Julier & Uhlmann; Wan & van der Merwe)
+///
+/// Maps a mean and covariance through a nonlinear function using 2n + 1
+/// sigma points x₀ = m, xᵢ = m ± (√((n + λ)P))ᵢ with λ = α²(n + κ) − n.
+/// The defaults α = 1, β = 2, κ = 0 place the points √n standard deviations
+/// from the mean with non-negative weights, which keeps the transformed
+/// covariance positive semi-definite.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnscentedTransform {
+    /// Spread of the sigma points around the mean
+    pub alpha: f64,
+    /// Prior knowledge of the distribution (2 is optimal for Gaussians)
+    pub beta: f64,
+    /// Secondary scaling parameter
+    pub kappa: f64,
+}
+
+impl Default for UnscentedTransform {
+    fn default() -> Self {
+        Self {
+            alpha: 1.0,
+            beta: 2.0,
+            kappa: 0.0,
+        }
+    }
+}
+
+impl UnscentedTransform {
+    /// Create a transform with the given scaling parameters
+    pub fn new(alpha: f64, beta: f64, kappa: f64) -> Self {
+        Self { alpha, beta, kappa }
+    }
+
+    fn lambda(&self, n: usize) -> f64 {
+        self.alpha * self.alpha * (n as f64 + self.kappa) - n as f64
+    }
+
+    /// Mean and covariance weights of the 2n + 1 sigma points
+    pub fn weights(&self, n: usize) -> (Vec<f64>, Vec<f64>) {
+        let lambda = self.lambda(n);
+        let scale = n as f64 + lambda;
+        let mut mean_weights = vec![0.5 / scale; 2 * n + 1];
+        let mut cov_weights = mean_weights.clone();
+        mean_weights[0] = lambda / scale;
+        cov_weights[0] = lambda / scale + 1.0 - self.alpha * self.alpha + self.beta;
+        (mean_weights, cov_weights)
+    }
+
+    /// Sigma points of `mean` and `covariance`
+    ///
+    /// # Errors
+    /// Returns an error if the parameters give n + λ ≤ 0 or the covariance
+    /// has negative eigenvalues
+    pub fn sigma_points(
+        &self,
+        mean: &DVector<f64>,
+        covariance: &DMatrix<f64>,
+    ) -> PoliastroResult<Vec<DVector<f64>>> {
+        let n = mean.len();
+        let scale = n as f64 + self.lambda(n);
+        if scale <= 0.0 {
+            return Err(PoliastroError::invalid_parameter(
+                "alpha",
+                self.alpha,
+                "n + λ must be positive",
+            ));
+        }
+        let root = matrix_square_root(&(covariance * scale))?;
+
+        let mut points = Vec::with_capacity(2 * n + 1);
+        points.push(mean.clone());
+        for column in root.column_iter() {
+            points.push(mean + column);
+        }
+        for column in root.column_iter() {
+            points.push(mean - column);
+        }
+        Ok(points)
+    }
+
+    /// Transform `mean` and `covariance` through `f`
+    ///
+    /// # Returns
+    /// Mean and covariance of the transformed sigma points
+    ///
+    /// # Errors
+    /// Returns the first error of `f`, or an error from
+    /// [`Self::sigma_points`]
+    pub fn transform<F>(
+        &self,
+        mean: &DVector<f64>,
+        covariance: &DMatrix<f64>,
+        mut f: F,
+    ) -> PoliastroResult<(DVector<f64>, DMatrix<f64>)>
+    where
+        F: FnMut(&DVector<f64>) -> PoliastroResult<DVector<f64>>,
+    {
+        let (mean_weights, cov_weights) = self.weights(mean.len());
+        let images = self
+            .sigma_points(mean, covariance)?
+            .iter()
+            .map(&mut f)
+            .collect::<PoliastroResult<Vec<_>>>()?;
+
+        let m = images[0].len();
+        let mut image_mean = DVector::zeros(m);
+        for (weight, image) in mean_weights.iter().zip(&images) {
+            image_mean += image * *weight;
+        }
+        let mut image_cov = DMatrix::zeros(m, m);
+        for (weight, image) in cov_weights.iter().zip(&images) {
+            let deviation = image - &image_mean;
+            image_cov += &deviation * deviation.transpose() * *weight;
+        }
+        Ok((image_mean, image_cov))
+    }
+}
+
+/// Square root S of a symmetric positive semi-definite matrix, P = S·Sᵀ
+///
+/// Cholesky factor, falling back to the symmetric eigendecomposition for
+/// singular matrices (e.g. a state with a perfectly known component).
+fn matrix_square_root(matrix: &DMatrix<f64>) -> PoliastroResult<DMatrix<f64>> {
+    if let Some(cholesky) = matrix.clone().cholesky() {
+        return Ok(cholesky.l());
+    }
+    let eigen = matrix.clone().symmetric_eigen();
+    let largest = eigen.eigenvalues.amax();
+    let mut roots = eigen.eigenvalues.clone();
+    for value in roots.iter_mut() {
+        if *value < -SYMMETRY_TOLERANCE * largest.max(f64::MIN_POSITIVE) {
+            return Err(PoliastroError::invalid_parameter(
+                "covariance",
+                *value,
+                "must be positive semi-definite",
+            ));
+        }
+        *value = value.max(0.0).sqrt();
+    }
+    Ok(eigen.eigenvectors * DMatrix::from_diagonal(&roots))
+}
+
+/// Rotation from the inertial frame to RIC (rows R̂, Î, Ĉ)
+pub(crate) fn ric_rotation(r: &Vector3, v: &Vector3) -> PoliastroResult<Matrix3> {
+    let h = r.cross(v);
+    if r.norm() == 0.0 || h.norm() == 0.0 {
+        return Err(PoliastroError::invalid_state(
+            "RIC frame undefined for zero position or rectilinear motion",
+        ));
+    }
+    let radial = r.normalize();
+    let cross_track = h.normalize();
+    let in_track = cross_track.cross(&radial);
+    Ok(Matrix3::from_rows(&[
+        radial.transpose(),
+        in_track.transpose(),
+        cross_track.transpose(),
+    ]))
+}
+
+/// Rotation from the inertial frame to NTW (rows N̂, T̂, Ŵ)
+fn ntw_rotation(r: &Vector3, v: &Vector3) -> PoliastroResult<Matrix3> {
+    let h = r.cross(v);
+    if v.norm() == 0.0 || h.norm() == 0.0 {
+        return Err(PoliastroError::invalid_state(
+            "NTW frame undefined for zero velocity or rectilinear motion",
+        ));
+    }
+    let tangential = v.normalize();
+    let cross_track = h.normalize();
+    let normal = tangential.cross(&cross_track);
+    Ok(Matrix3::from_rows(&[
+        normal.transpose(),
+        tangential.transpose(),
+        cross_track.transpose(),
+    ]))
+}
+
+/// Block-diagonal 6×6 matrix diag(R, R)
+fn block_rotation(rotation: &Matrix3) -> Matrix6 {
+    let mut matrix = Matrix6::zeros();
+    matrix.fixed_view_mut::<3, 3>(0, 0).copy_from(rotation);
+    matrix.fixed_view_mut::<3, 3>(3, 3).copy_from(rotation);
+    matrix
+}
+
+/// 6×6 covariance of an orbital state
+///
+/// Units follow the state: m², m²/s and m²/s² for Cartesian states, and
+/// the element units (m, rad) for element covariances.
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Covariance6 {
+    matrix: Matrix6,
+}
+
+impl Covariance6 {
+    /// Create a covariance from a symmetric matrix
+    ///
+    /// Round-off asymmetry is removed by averaging with the transpose.
+    ///
+    /// # Errors
+    /// Returns an error if the matrix has non-finite entries, negative
+    /// variances, or is not symmetric to a relative 1e-9
+    pub fn new(matrix: Matrix6) -> PoliastroResult<Self> {
+        if let Some(value) = matrix.iter().find(|x| !x.is_finite()) {
+            return Err(PoliastroError::InvalidNumericalValue {
+                context: "covariance matrix".to_string(),
+                value: *value,
+            });
+        }
+        if let Some(value) = matrix.diagonal().iter().find(|x| **x < 0.0) {
+            return Err(PoliastroError::invalid_parameter(
+                "variance",
+                *value,
+                "must be non-negative",
+            ));
+        }
+        let asymmetry = (matrix - matrix.transpose()).amax();
+        if asymmetry > SYMMETRY_TOLERANCE * matrix.amax() {
+            return Err(PoliastroError::invalid_parameter(
+                "covariance",
+                asymmetry,
+                "must be symmetric",
+            ));
+        }
+        Ok(Self::symmetric(matrix))
+    }
+
+    /// Diagonal covariance from the variances
+    ///
+    /// # Errors
+    /// Returns an error if a variance is negative or not finite
+    pub fn from_diagonal(variances: &Vector6) -> PoliastroResult<Self> {
+        Self::new(Matrix6::from_diagonal(variances))
+    }
+
+    /// Diagonal covariance from standard deviations
+    ///
+    /// # Errors
+    /// Returns an error if a standard deviation is not finite
+    pub fn from_std_devs(std_devs: &Vector6) -> PoliastroResult<Self> {
+        Self::from_diagonal(&std_devs.component_mul(std_devs))
+    }
+
+    /// Symmetrized covariance, without validation
+    fn symmetric(matrix: Matrix6) -> Self {
+        Self {
+            matrix: (matrix + matrix.transpose()) * 0.5,
+        }
+    }
+
+    /// The covariance matrix
+    pub fn matrix(&self) -> Matrix6 {
+        self.matrix
+    }
+
+    /// Position block (upper-left 3×3)
+    pub fn position(&self) -> Matrix3 {
+        self.matrix.fixed_view::<3, 3>(0, 0).into_owned()
+    }
+
+    /// Velocity block (lower-right 3×3)
+    pub fn velocity(&self) -> Matrix3 {
+        self.matrix.fixed_view::<3, 3>(3, 3).into_owned()
+    }
+
+    /// Standard deviations (square roots of the diagonal)
+    pub fn std_devs(&self) -> Vector6 {
+        self.matrix.diagonal().map(f64::sqrt)
+    }
+
+    /// Correlation matrix, with zero rows and columns for zero variances
+    pub fn correlation(&self) -> Matrix6 {
+        let sigma = self.std_devs();
+        Matrix6::from_fn(|i, j| {
+            let scale = sigma[i] * sigma[j];
+            if scale > 0.0 {
+                self.matrix[(i, j)] / scale
+            } else {
+                0.0
+            }
+        })
+    }
+
+    /// Transform by a Jacobian: J·P·Jᵀ
+    pub fn transform(&self, jacobian: &Matrix6) -> Self {
+        Self::symmetric(jacobian * self.matrix * jacobian.transpose())
+    }
+
+    /// Propagate linearly through a state transition matrix: Φ·P·Φᵀ
+    pub fn propagate_linear(&self, stm: &Matrix6) -> Self {
+        self.transform(stm)
+    }
+
+    /// Propagate through `propagate` with the default [`UnscentedTransform`]
+    ///
+    /// # Arguments
+    /// * `mean` - State the covariance belongs to
+    /// * `propagate` - Maps a state to the propagated state, e.g. a
+    ///   [`crate::propagators::propagator::NumericalPropagator`] run to the
+    ///   target epoch
+    ///
+    /// # Returns
+    /// Mean and covariance of the propagated sigma points
+    ///
+    /// # Errors
+    /// Returns the first error of `propagate`
+    pub fn propagate_unscented<F>(
+        &self,
+        mean: &Vector6,
+        propagate: F,
+    ) -> PoliastroResult<(Vector6, Self)>
+    where
+        F: FnMut(&Vector6) -> PoliastroResult<Vector6>,
+    {
+        self.propagate_unscented_with(&UnscentedTransform::default(), mean, propagate)
+    }
+
+    /// Propagate through `propagate` with the given [`UnscentedTransform`]
+    ///
+    /// # Errors
+    /// Returns the first error of `propagate`, or an error if the transform
+    /// parameters are invalid
+    pub fn propagate_unscented_with<F>(
+        &self,
+        transform: &UnscentedTransform,
+        mean: &Vector6,
+        mut propagate: F,
+    ) -> PoliastroResult<(Vector6, Self)>
+    where
+        F: FnMut(&Vector6) -> PoliastroResult<Vector6>,
+    {
+        let covariance = DMatrix::from_column_slice(6, 6, self.matrix.as_slice());
+        let (image_mean, image_cov) = transform.transform(
+            &DVector::from_column_slice(mean.as_slice()),
+            &covariance,
+            |x| {
+                let x = Vector6::from_column_slice(x.as_slice());
+                propagate(&x).map(|y| DVector::from_column_slice(y.as_slice()))
+            },
+        )?;
+        Ok((
+            Vector6::from_column_slice(image_mean.as_slice()),
+            Self::symmetric(Matrix6::from_column_slice(image_cov.as_slice())),
+        ))
+    }
+
+    /// Rotate an inertial covariance to the RIC frame of the state (r, v)
+    ///
+    /// # Errors
+    /// Returns an error if the frame is undefined (zero position or angular
+    /// momentum)
+    pub fn to_ric(&self, r: &Vector3, v: &Vector3) -> PoliastroResult<Self> {
+        Ok(self.transform(&block_rotation(&ric_rotation(r, v)?)))
+    }
+
+    /// Rotate a RIC covariance to the inertial frame
+    ///
+    /// # Errors
+    /// Returns an error if the frame is undefined
+    pub fn from_ric(&self, r: &Vector3, v: &Vector3) -> PoliastroResult<Self> {
+        Ok(self.transform(&block_rotation(&ric_rotation(r, v)?.transpose())))
+    }
+
+    /// Rotate an inertial covariance to the NTW frame of the state (r, v)
+    ///
+    /// # Errors
+    /// Returns an error if the frame is undefined (zero velocity or angular
+    /// momentum)
+    pub fn to_ntw(&self, r: &Vector3, v: &Vector3) -> PoliastroResult<Self> {
+        Ok(self.transform(&block_rotation(&ntw_rotation(r, v)?)))
+    }
+
+    /// Rotate an NTW covariance to the inertial frame
+    ///
+    /// # Errors
+    /// Returns an error if the frame is undefined
+    pub fn from_ntw(&self, r: &Vector3, v: &Vector3) -> PoliastroResult<Self> {
+        Ok(self.transform(&block_rotation(&ntw_rotation(r, v)?.transpose())))
+    }
+
+    /// Convert a Cartesian covariance to Keplerian elements (a, e, i, Ω, ω, ν)
+    ///
+    /// # Errors
+    /// Returns an error if the elements are undefined or singular (circular
+    /// or equatorial orbits); use [`Self::to_equinoctial`] there
+    pub fn to_keplerian(&self, r: &Vector3, v: &Vector3, mu: f64) -> PoliastroResult<Self> {
+        Ok(self.transform(&rv_to_coe_jacobian(r, v, mu, DEFAULT_TOL)?))
+    }
+
+    /// Convert a Keplerian element covariance to Cartesian
+    pub fn from_keplerian(&self, elements: &OrbitalElements, mu: f64) -> Self {
+        self.transform(&coe_to_rv_jacobian(elements, mu))
+    }
+
+    /// Convert a Cartesian covariance to modified equinoctial elements
+    /// (p, f, g, h, k, L)
+    ///
+    /// # Errors
+    /// Returns an error if the elements are undefined (see
+    /// [`crate::core::elements::rv_to_equinoctial`])
+    pub fn to_equinoctial(&self, r: &Vector3, v: &Vector3, mu: f64) -> PoliastroResult<Self> {
+        Ok(self.transform(&rv_to_equinoctial_jacobian(r, v, mu, DEFAULT_TOL)?))
+    }
+
+    /// Convert a modified equinoctial element covariance to Cartesian
+    pub fn from_equinoctial(&self, elements: &EquinoctialElements, mu: f64) -> Self {
+        self.transform(&equinoctial_to_rv_jacobian(elements, mu))
+    }
+}
+
+fn matrix_from_array(matrix: &PyReadonlyArray2<f64>) -> PyResult<Matrix6> {
+    let array = matrix.as_array();
+    if array.shape() != [6, 6] {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "Covariance must be a 6×6 array",
+        ));
+    }
+    Ok(Matrix6::from_fn(|i, j| array[[i, j]]))
+}
+
+fn vector6_from_array(vector: &PyReadonlyArray1<f64>) -> PyResult<Vector6> {
+    let slice = vector.as_slice()?;
+    if slice.len() != 6 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "State must be a 6-element array",
+        ));
+    }
+    Ok(Vector6::from_column_slice(slice))
+}
+
+fn matrix_to_pyarray<'py>(py: Python<'py>, matrix: &Matrix6) -> Bound<'py, PyArray2<f64>> {
+    PyArray2::from_owned_array_bound(
+        py,
+        ndarray::Array2::from_shape_fn((6, 6), |(i, j)| matrix[(i, j)]),
+    )
+}
+
+#[pymethods]
+impl Covariance6 {
+    /// Create a covariance from a symmetric 6×6 array
+    #[new]
+    fn py_new(matrix: PyReadonlyArray2<f64>) -> PyResult<Self> {
+        Ok(Self::new(matrix_from_array(&matrix)?)?)
+    }
+
+    /// Diagonal covariance from 6 standard deviations
+    #[staticmethod]
+    #[pyo3(name = "from_std_devs")]
+    fn py_from_std_devs(std_devs: PyReadonlyArray1<f64>) -> PyResult<Self> {
+        Ok(Self::from_std_devs(&vector6_from_array(&std_devs)?)?)
+    }
+
+    /// The 6×6 covariance matrix
+    #[getter]
+    fn get_matrix<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        matrix_to_pyarray(py, &self.matrix)
+    }
+
+    /// Standard deviations of the 6 components
+    #[getter]
+    fn get_std_devs<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.std_devs().as_slice())
+    }
+
+    /// Correlation matrix
+    #[pyo3(name = "correlation")]
+    fn py_correlation<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        matrix_to_pyarray(py, &self.correlation())
+    }
+
+    /// Transform by a 6×6 Jacobian: J·P·Jᵀ
+    #[pyo3(name = "transform")]
+    fn py_transform(&self, jacobian: PyReadonlyArray2<f64>) -> PyResult<Self> {
+        Ok(self.transform(&matrix_from_array(&jacobian)?))
+    }
+
+    /// Propagate linearly through a 6×6 state transition matrix
+    #[pyo3(name = "propagate_linear")]
+    fn py_propagate_linear(&self, stm: PyReadonlyArray2<f64>) -> PyResult<Self> {
+        Ok(self.propagate_linear(&matrix_from_array(&stm)?))
+    }
+
+    /// Propagate with the unscented transform
+    ///
+    /// # Arguments
+    /// - `state`: State [x, y, z, vx, vy, vz] the covariance belongs to
+    /// - `function`: Callable mapping a 6-element state array to the
+    ///   propagated 6-element state, e.g. wrapping any propagator
+    /// - `alpha`, `beta`, `kappa`: Unscented transform parameters
+    ///
+    /// Returns (mean, covariance) of the propagated sigma points.
+    #[pyo3(
+        name = "propagate_unscented",
+        signature = (state, function, alpha=1.0, beta=2.0, kappa=0.0)
+    )]
+    fn py_propagate_unscented<'py>(
+        &self,
+        py: Python<'py>,
+        state: PyReadonlyArray1<f64>,
+        function: &Bound<'py, PyAny>,
+        alpha: f64,
+        beta: f64,
+        kappa: f64,
+    ) -> PyResult<(Bound<'py, PyArray1<f64>>, Self)> {
+        let mean = vector6_from_array(&state)?;
+        let mut error = None;
+        let result = self.propagate_unscented_with(
+            &UnscentedTransform::new(alpha, beta, kappa),
+            &mean,
+            |x| {
+                let image = function
+                    .call1((PyArray1::from_slice_bound(py, x.as_slice()),))
+                    .and_then(|y| y.extract::<Vec<f64>>());
+                match image {
+                    Ok(y) if y.len() == 6 => Ok(Vector6::from_column_slice(&y)),
+                    Ok(_) => Err(PoliastroError::invalid_state(
+                        "function must return a 6-element state",
+                    )),
+                    Err(err) => {
+                        let message = err.to_string();
+                        error = Some(err);
+                        Err(PoliastroError::invalid_state(message))
+                    }
+                }
+            },
+        );
+        if let Some(err) = error {
+            return Err(err);
+        }
+        let (mean, covariance) = result?;
+        Ok((PyArray1::from_slice_bound(py, mean.as_slice()), covariance))
+    }
+
+    /// Rotate to the RIC (radial, in-track, cross-track) frame of (r, v)
+    #[pyo3(name = "to_ric")]
+    fn py_to_ric(&self, r: PyReadonlyArray1<f64>, v: PyReadonlyArray1<f64>) -> PyResult<Self> {
+        let (r, v) = state_from_arrays(&r, &v)?;
+        Ok(self.to_ric(&r, &v)?)
+    }
+
+    /// Rotate from the RIC frame of (r, v) to inertial
+    #[pyo3(name = "from_ric")]
+    fn py_from_ric(&self, r: PyReadonlyArray1<f64>, v: PyReadonlyArray1<f64>) -> PyResult<Self> {
+        let (r, v) = state_from_arrays(&r, &v)?;
+        Ok(self.from_ric(&r, &v)?)
+    }
+
+    /// Rotate to the NTW (normal, tangential, cross-track) frame of (r, v)
+    #[pyo3(name = "to_ntw")]
+    fn py_to_ntw(&self, r: PyReadonlyArray1<f64>, v: PyReadonlyArray1<f64>) -> PyResult<Self> {
+        let (r, v) = state_from_arrays(&r, &v)?;
+        Ok(self.to_ntw(&r, &v)?)
+    }
+
+    /// Rotate from the NTW frame of (r, v) to inertial
+    #[pyo3(name = "from_ntw")]
+    fn py_from_ntw(&self, r: PyReadonlyArray1<f64>, v: PyReadonlyArray1<f64>) -> PyResult<Self> {
+        let (r, v) = state_from_arrays(&r, &v)?;
+        Ok(self.from_ntw(&r, &v)?)
+    }
+
+    /// Convert a Cartesian covariance to Keplerian elements (a, e, i, Ω, ω, ν)
+    #[pyo3(name = "to_keplerian")]
+    fn py_to_keplerian(
+        &self,
+        r: PyReadonlyArray1<f64>,
+        v: PyReadonlyArray1<f64>,
+        mu: f64,
+    ) -> PyResult<Self> {
+        let (r, v) = state_from_arrays(&r, &v)?;
+        Ok(self.to_keplerian(&r, &v, mu)?)
+    }
+
+    /// Convert a Keplerian element covariance to Cartesian
+    #[pyo3(name = "from_keplerian")]
+    fn py_from_keplerian(&self, elements: OrbitalElements, mu: f64) -> Self {
+        self.from_keplerian(&elements, mu)
+    }
+
+    /// Convert a Cartesian covariance to modified equinoctial elements
+    #[pyo3(name = "to_equinoctial")]
+    fn py_to_equinoctial(
+        &self,
+        r: PyReadonlyArray1<f64>,
+        v: PyReadonlyArray1<f64>,
+        mu: f64,
+    ) -> PyResult<Self> {
+        let (r, v) = state_from_arrays(&r, &v)?;
+        Ok(self.to_equinoctial(&r, &v, mu)?)
+    }
+
+    /// Convert a modified equinoctial element covariance to Cartesian
+    #[pyo3(name = "from_equinoctial")]
+    fn py_from_equinoctial(&self, elements: EquinoctialElements, mu: f64) -> Self {
+        self.from_equinoctial(&elements, mu)
+    }
+
+    fn __repr__(&self) -> String {
+        let sigma = self.std_devs();
+        format!(
+            "Covariance6(σ=[{:.3e}, {:.3e}, {:.3e}, {:.3e}, {:.3e}, {:.3e}])",
+            sigma[0], sigma[1], sigma[2], sigma[3], sigma[4], sigma[5]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::GM_EARTH;
+    use crate::core::elements::{coe_to_rv, rv_to_coe, rv_to_equinoctial};
+    use crate::propagators::stm::propagate_stm_rk4;
+    use approx::assert_relative_eq;
+
+    fn leo_state() -> (Vector3, Vector3) {
+        let elements = OrbitalElements::new(7000e3, 0.01, 0.9, 0.4, 0.3, 1.0);
+        coe_to_rv(&elements, GM_EARTH)
+    }
+
+    fn assert_matrix_close(actual: &Matrix6, expected: &Matrix6, tolerance: f64) {
+        let error = (actual - expected).norm() / expected.norm();
+        assert!(error < tolerance, "relative error {error}");
+    }
+
+    fn sample_covariance() -> Covariance6 {
+        let sigma = Vector6::new(100.0, 200.0, 50.0, 0.1, 0.2, 0.05);
+        let mut matrix = Matrix6::from_diagonal(&sigma.component_mul(&sigma));
+        matrix[(0, 1)] = 0.3 * sigma[0] * sigma[1];
+        matrix[(1, 0)] = matrix[(0, 1)];
+        matrix[(0, 4)] = -0.2 * sigma[0] * sigma[4];
+        matrix[(4, 0)] = matrix[(0, 4)];
+        Covariance6::new(matrix).unwrap()
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut matrix = Matrix6::identity();
+        matrix[(0, 1)] = 0.5;
+        assert!(Covariance6::new(matrix).is_err());
+        assert!(Covariance6::new(-Matrix6::identity()).is_err());
+        assert!(Covariance6::new(Matrix6::identity() * f64::NAN).is_err());
+
+        let sigma = Covariance6::from_std_devs(&Vector6::repeat(3.0)).unwrap();
+        assert_relative_eq!(sigma.std_devs(), Vector6::repeat(3.0));
+        assert_relative_eq!(sigma.correlation(), Matrix6::identity());
+    }
+
+    #[test]
+    fn test_ric_ntw_rotations() {
+        let (r, v) = leo_state();
+        let covariance = sample_covariance();
+
+        // Rotations preserve the trace and invert exactly
+        for local in [
+            covariance.to_ric(&r, &v).unwrap(),
+            covariance.to_ntw(&r, &v).unwrap(),
+        ] {
+            assert_relative_eq!(
+                local.position().trace(),
+                covariance.position().trace(),
+                max_relative = 1e-12
+            );
+        }
+        let back = covariance.to_ric(&r, &v).unwrap().from_ric(&r, &v).unwrap();
+        assert_matrix_close(&back.matrix(), &covariance.matrix(), 1e-10);
+        let back = covariance.to_ntw(&r, &v).unwrap().from_ntw(&r, &v).unwrap();
+        assert_matrix_close(&back.matrix(), &covariance.matrix(), 1e-10);
+
+        // A purely radial position uncertainty appears only in R
+        let radial = r.normalize();
+        let mut matrix = Matrix6::zeros();
+        matrix
+            .fixed_view_mut::<3, 3>(0, 0)
+            .copy_from(&(radial * radial.transpose() * 1e4));
+        let ric = Covariance6::new(matrix).unwrap().to_ric(&r, &v).unwrap();
+        assert_relative_eq!(ric.matrix()[(0, 0)], 1e4, max_relative = 1e-12);
+        assert_relative_eq!(ric.position().norm(), 1e4, max_relative = 1e-12);
+
+        // An along-velocity uncertainty appears only in T
+        let tangential = v.normalize();
+        matrix
+            .fixed_view_mut::<3, 3>(0, 0)
+            .copy_from(&(tangential * tangential.transpose() * 1e4));
+        let ntw = Covariance6::new(matrix).unwrap().to_ntw(&r, &v).unwrap();
+        assert_relative_eq!(ntw.matrix()[(1, 1)], 1e4, max_relative = 1e-12);
+        assert_relative_eq!(ntw.position().norm(), 1e4, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn test_element_conversions_roundtrip() {
+        let (r, v) = leo_state();
+        let covariance = sample_covariance();
+
+        let keplerian = covariance.to_keplerian(&r, &v, GM_EARTH).unwrap();
+        let elements = rv_to_coe(&r, &v, GM_EARTH, DEFAULT_TOL).unwrap();
+        let back = keplerian.from_keplerian(&elements, GM_EARTH);
+        assert_matrix_close(&back.matrix(), &covariance.matrix(), 1e-6);
+
+        let equinoctial = covariance.to_equinoctial(&r, &v, GM_EARTH).unwrap();
+        let elements = rv_to_equinoctial(&r, &v, GM_EARTH, DEFAULT_TOL).unwrap();
+        let back = equinoctial.from_equinoctial(&elements, GM_EARTH);
+        assert_matrix_close(&back.matrix(), &covariance.matrix(), 1e-6);
+    }
+
+    #[test]
+    fn test_unscented_matches_linear_for_linear_map() {
+        let covariance = sample_covariance();
+        let mean = Vector6::new(7000e3, 0.0, 0.0, 0.0, 7500.0, 0.0);
+        let map = Matrix6::from_fn(|i, j| {
+            if i == j {
+                1.0
+            } else {
+                0.1 * (i + 2 * j) as f64
+            }
+        });
+
+        let (image, unscented) = covariance
+            .propagate_unscented(&mean, |x| Ok(map * x))
+            .unwrap();
+        assert_relative_eq!(image, map * mean, max_relative = 1e-12);
+        assert_relative_eq!(
+            unscented.matrix(),
+            covariance.propagate_linear(&map).matrix(),
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_unscented_agrees_with_stm_for_small_covariance() {
+        let (r, v) = leo_state();
+        let mean = Vector6::new(r.x, r.y, r.z, v.x, v.y, v.z);
+        let covariance =
+            Covariance6::from_std_devs(&Vector6::new(10.0, 10.0, 10.0, 0.01, 0.01, 0.01)).unwrap();
+        let dt = 1800.0;
+
+        let propagate = |x: &Vector6| -> PoliastroResult<Vector6> {
+            let r = Vector3::new(x[0], x[1], x[2]);
+            let v = Vector3::new(x[3], x[4], x[5]);
+            let (r, v, _) = propagate_stm_rk4(&r, &v, dt, GM_EARTH, 180)?;
+            Ok(Vector6::new(r.x, r.y, r.z, v.x, v.y, v.z))
+        };
+        let (_, _, stm) = propagate_stm_rk4(&r, &v, dt, GM_EARTH, 180).unwrap();
+
+        let (_, unscented) = covariance.propagate_unscented(&mean, propagate).unwrap();
+        let linear = covariance.propagate_linear(&stm);
+        assert_relative_eq!(unscented.std_devs(), linear.std_devs(), max_relative = 1e-3);
+    }
+
+    #[test]
+    fn test_sigma_points_of_singular_covariance() {
+        // A perfectly known component still gives a valid spread
+        let mut variances = Vector6::repeat(1.0);
+        variances[2] = 0.0;
+        let covariance = Covariance6::from_diagonal(&variances).unwrap();
+        let (_, image) = covariance
+            .propagate_unscented(&Vector6::zeros(), |x| Ok(*x))
+            .unwrap();
+        assert_relative_eq!(image.matrix(), covariance.matrix(), epsilon = 1e-12);
+    }
+}
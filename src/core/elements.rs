@@ -6,6 +6,8 @@
 //! # Conversions
 //! - `rv_to_coe`: Convert position and velocity vectors to classical orbital elements
 //! - `coe_to_rv`: Convert classical orbital elements to position and velocity vectors
+//! - `coe_to_rv_jacobian`, `equinoctial_to_rv_jacobian` and their inverses:
+//!   Jacobians of the conversions, e.g. for covariance transformation
 //!
 //! # Edge Cases
 //! The conversions handle special cases including:
@@ -14,7 +16,7 @@
 //! - Parabolic and hyperbolic trajectories
 
 use crate::core::error::{PoliastroError, PoliastroResult};
-use crate::core::linalg::{Vector3, Matrix3};
+use crate::core::linalg::{Vector3, Matrix3, Matrix6};
 use pyo3::prelude::*;
 use std::f64::consts::PI;
 
@@ -732,6 +734,194 @@ pub fn equinoctial_to_rv(
     Ok(coe_to_rv(&coe, mu))
 }
 
+/// Jacobian ∂(r, v)/∂(a, e, i, Ω, ω, ν) of [`coe_to_rv`]
+///
+/// Columns follow the element order of [`OrbitalElements`] and rows the
+/// state [x, y, z, vx, vy, vz]. With r = R·r_pqw and v = R·v_pqw, where
+/// R = R_z(Ω)·R_x(i)·R_z(ω), the size and anomaly columns differentiate the
+/// perifocal vectors and the orientation columns the rotation.
+///
+/// # Arguments
+/// * `elements` - Classical orbital elements (elliptical)
+/// * `mu` - Standard gravitational parameter (m³/s²)
+///
+/// # Returns
+/// 6×6 Jacobian matrix
+pub fn coe_to_rv_jacobian(elements: &OrbitalElements, mu: f64) -> Matrix6 {
+    let OrbitalElements {
+        a,
+        e,
+        i,
+        raan,
+        argp,
+        nu,
+    } = *elements;
+
+    let (sin_nu, cos_nu) = nu.sin_cos();
+    let p = a * (1.0 - e * e);
+    let w = 1.0 + e * cos_nu;
+    let r_mag = p / w;
+    let v_scale = (mu / p).sqrt();
+
+    let r_pqw = Vector3::new(r_mag * cos_nu, r_mag * sin_nu, 0.0);
+    let v_pqw = Vector3::new(-v_scale * sin_nu, v_scale * (e + cos_nu), 0.0);
+    let radial = Vector3::new(cos_nu, sin_nu, 0.0);
+
+    // ∂/∂a: r ∝ a and v ∝ a^(-1/2)
+    let r_a = r_pqw / a;
+    let v_a = v_pqw * (-0.5 / a);
+
+    // ∂/∂e through p = a(1 − e²) and w = 1 + e·cos ν
+    let p_e = -2.0 * a * e;
+    let r_e = radial * (p_e / w - p * cos_nu / (w * w));
+    let v_e = v_pqw * (-0.5 * p_e / p) + Vector3::new(0.0, v_scale, 0.0);
+
+    // ∂/∂ν
+    let r_nu = radial * (p * e * sin_nu / (w * w)) + Vector3::new(-sin_nu, cos_nu, 0.0) * r_mag;
+    let v_nu = Vector3::new(-cos_nu, -sin_nu, 0.0) * v_scale;
+
+    // Rotation R = R_z(Ω)·R_x(i)·R_z(ω) and its derivatives
+    let rot_z = |angle: f64| {
+        let (s, c) = angle.sin_cos();
+        Matrix3::new(c, -s, 0.0, s, c, 0.0, 0.0, 0.0, 1.0)
+    };
+    let rot_z_dot = |angle: f64| {
+        let (s, c) = angle.sin_cos();
+        Matrix3::new(-s, -c, 0.0, c, -s, 0.0, 0.0, 0.0, 0.0)
+    };
+    let (sin_i, cos_i) = i.sin_cos();
+    let rot_x = Matrix3::new(1.0, 0.0, 0.0, 0.0, cos_i, -sin_i, 0.0, sin_i, cos_i);
+    let rot_x_dot = Matrix3::new(0.0, 0.0, 0.0, 0.0, -sin_i, -cos_i, 0.0, cos_i, -sin_i);
+
+    let rotation = rot_z(raan) * rot_x * rot_z(argp);
+    let rotation_i = rot_z(raan) * rot_x_dot * rot_z(argp);
+    let rotation_raan = rot_z_dot(raan) * rot_x * rot_z(argp);
+    let rotation_argp = rot_z(raan) * rot_x * rot_z_dot(argp);
+
+    let columns = [
+        (rotation * r_a, rotation * v_a),
+        (rotation * r_e, rotation * v_e),
+        (rotation_i * r_pqw, rotation_i * v_pqw),
+        (rotation_raan * r_pqw, rotation_raan * v_pqw),
+        (rotation_argp * r_pqw, rotation_argp * v_pqw),
+        (rotation * r_nu, rotation * v_nu),
+    ];
+    jacobian_from_columns(&columns)
+}
+
+/// Jacobian ∂(r, v)/∂(p, f, g, h, k, L) of [`equinoctial_to_rv`]
+///
+/// Uses r = r·(cos L·f̂ + sin L·ĝ) and v = √(μ/p)·(−(sin L + g)·f̂ +
+/// (cos L + f)·ĝ) with the equinoctial basis vectors
+/// f̂ = (1 + h² − k², 2hk, −2k)/s² and ĝ = (2hk, 1 − h² + k², 2h)/s²,
+/// s² = 1 + h² + k², so it stays regular for circular and equatorial
+/// orbits.
+///
+/// # Arguments
+/// * `elements` - Modified equinoctial elements
+/// * `mu` - Standard gravitational parameter (m³/s²)
+///
+/// # Returns
+/// 6×6 Jacobian matrix
+pub fn equinoctial_to_rv_jacobian(elements: &EquinoctialElements, mu: f64) -> Matrix6 {
+    let EquinoctialElements { p, f, g, h, k, L } = *elements;
+
+    let (sin_l, cos_l) = L.sin_cos();
+    let w = 1.0 + f * cos_l + g * sin_l;
+    let r_mag = p / w;
+    let v_scale = (mu / p).sqrt();
+
+    // Equinoctial basis and its derivatives
+    let s2 = 1.0 + h * h + k * k;
+    let f_raw = Vector3::new(1.0 + h * h - k * k, 2.0 * h * k, -2.0 * k);
+    let g_raw = Vector3::new(2.0 * h * k, 1.0 - h * h + k * k, 2.0 * h);
+    let f_hat = f_raw / s2;
+    let g_hat = g_raw / s2;
+    let basis_derivative =
+        |raw: &Vector3, raw_d: Vector3, s2_d: f64| raw_d / s2 - raw * (s2_d / (s2 * s2));
+    let f_hat_h = basis_derivative(&f_raw, Vector3::new(2.0 * h, 2.0 * k, 0.0), 2.0 * h);
+    let f_hat_k = basis_derivative(&f_raw, Vector3::new(-2.0 * k, 2.0 * h, -2.0), 2.0 * k);
+    let g_hat_h = basis_derivative(&g_raw, Vector3::new(2.0 * k, -2.0 * h, 2.0), 2.0 * h);
+    let g_hat_k = basis_derivative(&g_raw, Vector3::new(2.0 * h, 2.0 * k, 0.0), 2.0 * k);
+
+    // In-plane coordinates along (f̂, ĝ)
+    let (x, y) = (r_mag * cos_l, r_mag * sin_l);
+    let (vx, vy) = (-v_scale * (sin_l + g), v_scale * (cos_l + f));
+    let in_plane = |x: f64, y: f64| f_hat * x + g_hat * y;
+
+    // ∂r/∂(f, g, L) through w
+    let r_f = -p * cos_l / (w * w);
+    let r_g = -p * sin_l / (w * w);
+    let r_l = p * (f * sin_l - g * cos_l) / (w * w);
+
+    let columns = [
+        (in_plane(x / p, y / p), in_plane(vx, vy) * (-0.5 / p)),
+        (in_plane(r_f * cos_l, r_f * sin_l), in_plane(0.0, v_scale)),
+        (in_plane(r_g * cos_l, r_g * sin_l), in_plane(-v_scale, 0.0)),
+        (f_hat_h * x + g_hat_h * y, f_hat_h * vx + g_hat_h * vy),
+        (f_hat_k * x + g_hat_k * y, f_hat_k * vx + g_hat_k * vy),
+        (
+            in_plane(r_l * cos_l - r_mag * sin_l, r_l * sin_l + r_mag * cos_l),
+            in_plane(-v_scale * cos_l, -v_scale * sin_l),
+        ),
+    ];
+    jacobian_from_columns(&columns)
+}
+
+/// Jacobian ∂(a, e, i, Ω, ω, ν)/∂(r, v) of [`rv_to_coe`]
+///
+/// The inverse of [`coe_to_rv_jacobian`] at the elements of (r, v).
+///
+/// # Errors
+/// Returns an error if the elements are undefined (see [`rv_to_coe`]) or the
+/// Jacobian is singular, as for circular or equatorial orbits where ω or Ω
+/// is undefined
+pub fn rv_to_coe_jacobian(r: &Vector3, v: &Vector3, mu: f64, tol: f64) -> PoliastroResult<Matrix6> {
+    let elements = rv_to_coe(r, v, mu, tol)?;
+    invert_jacobian(coe_to_rv_jacobian(&elements, mu), "rv_to_coe_jacobian")
+}
+
+/// Jacobian ∂(p, f, g, h, k, L)/∂(r, v) of [`rv_to_equinoctial`]
+///
+/// The inverse of [`equinoctial_to_rv_jacobian`] at the elements of (r, v).
+///
+/// # Errors
+/// Returns an error if the elements are undefined (see [`rv_to_equinoctial`])
+/// or the Jacobian is singular
+pub fn rv_to_equinoctial_jacobian(
+    r: &Vector3,
+    v: &Vector3,
+    mu: f64,
+    tol: f64,
+) -> PoliastroResult<Matrix6> {
+    let elements = rv_to_equinoctial(r, v, mu, tol)?;
+    invert_jacobian(
+        equinoctial_to_rv_jacobian(&elements, mu),
+        "rv_to_equinoctial_jacobian",
+    )
+}
+
+/// Assemble a 6×6 Jacobian from (∂r, ∂v) columns
+fn jacobian_from_columns(columns: &[(Vector3, Vector3); 6]) -> Matrix6 {
+    let mut jacobian = Matrix6::zeros();
+    for (j, (dr, dv)) in columns.iter().enumerate() {
+        jacobian.fixed_view_mut::<3, 1>(0, j).copy_from(dr);
+        jacobian.fixed_view_mut::<3, 1>(3, j).copy_from(dv);
+    }
+    jacobian
+}
+
+fn invert_jacobian(jacobian: Matrix6, context: &str) -> PoliastroResult<Matrix6> {
+    let determinant = jacobian.determinant();
+    jacobian
+        .try_inverse()
+        .filter(|inverse| inverse.iter().all(|x| x.is_finite()))
+        .ok_or_else(|| PoliastroError::SingularMatrix {
+            context: context.to_string(),
+            determinant,
+        })
+}
+
 /// Normalize angle to [0, 2π) range
 #[inline]
 fn normalize_angle(angle: f64) -> f64 {
@@ -1316,4 +1506,86 @@ mod tests {
         assert_relative_eq!(coe_recovered.e, 0.1, epsilon = 1e-10);
         assert_relative_eq!(coe_recovered.i, 1e-10, epsilon = 1e-12);
     }
+
+    fn central_difference<F>(x: [f64; 6], steps: [f64; 6], f: F) -> Matrix6
+    where
+        F: Fn([f64; 6]) -> (Vector3, Vector3),
+    {
+        let mut jacobian = Matrix6::zeros();
+        for j in 0..6 {
+            let (mut plus, mut minus) = (x, x);
+            plus[j] += steps[j];
+            minus[j] -= steps[j];
+            let ((r_p, v_p), (r_m, v_m)) = (f(plus), f(minus));
+            for row in 0..3 {
+                jacobian[(row, j)] = (r_p[row] - r_m[row]) / (2.0 * steps[j]);
+                jacobian[(row + 3, j)] = (v_p[row] - v_m[row]) / (2.0 * steps[j]);
+            }
+        }
+        jacobian
+    }
+
+    fn assert_jacobian_close(analytic: &Matrix6, numeric: &Matrix6) {
+        for j in 0..6 {
+            let scale = numeric.column(j).norm().max(1e-12);
+            let error = (analytic.column(j) - numeric.column(j)).norm();
+            assert!(
+                error / scale < 1e-6,
+                "column {j}: relative error {}",
+                error / scale
+            );
+        }
+    }
+
+    #[test]
+    fn test_coe_to_rv_jacobian_matches_finite_differences() {
+        let x = [8000e3, 0.15, 0.9, 1.2, 0.7, 2.1];
+        let analytic = coe_to_rv_jacobian(
+            &OrbitalElements::new(x[0], x[1], x[2], x[3], x[4], x[5]),
+            GM_EARTH,
+        );
+        let numeric = central_difference(x, [1.0, 1e-7, 1e-7, 1e-7, 1e-7, 1e-7], |y| {
+            coe_to_rv(
+                &OrbitalElements::new(y[0], y[1], y[2], y[3], y[4], y[5]),
+                GM_EARTH,
+            )
+        });
+        assert_jacobian_close(&analytic, &numeric);
+    }
+
+    #[test]
+    fn test_equinoctial_to_rv_jacobian_matches_finite_differences() {
+        let x = [7500e3, 0.02, -0.05, 0.3, -0.1, 1.4];
+        let eq = EquinoctialElements::new(x[0], x[1], x[2], x[3], x[4], x[5]);
+        let analytic = equinoctial_to_rv_jacobian(&eq, GM_EARTH);
+        let numeric = central_difference(x, [1.0, 1e-7, 1e-7, 1e-7, 1e-7, 1e-7], |y| {
+            let eq = EquinoctialElements::new(y[0], y[1], y[2], y[3], y[4], y[5]);
+            equinoctial_to_rv(&eq, GM_EARTH, DEFAULT_TOL).unwrap()
+        });
+        assert_jacobian_close(&analytic, &numeric);
+    }
+
+    #[test]
+    fn test_rv_to_element_jacobians_are_inverses() {
+        let coe = OrbitalElements::new(8000e3, 0.15, 0.9, 1.2, 0.7, 2.1);
+        let (r, v) = coe_to_rv(&coe, GM_EARTH);
+
+        let forward = coe_to_rv_jacobian(&coe, GM_EARTH);
+        let inverse = rv_to_coe_jacobian(&r, &v, GM_EARTH, DEFAULT_TOL).unwrap();
+        assert_relative_eq!(forward * inverse, Matrix6::identity(), epsilon = 1e-8);
+
+        let eq = rv_to_equinoctial(&r, &v, GM_EARTH, DEFAULT_TOL).unwrap();
+        let forward = equinoctial_to_rv_jacobian(&eq, GM_EARTH);
+        let inverse = rv_to_equinoctial_jacobian(&r, &v, GM_EARTH, DEFAULT_TOL).unwrap();
+        assert_relative_eq!(forward * inverse, Matrix6::identity(), epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_rv_to_coe_jacobian_singular_for_circular_equatorial() {
+        // ω and Ω are undefined, so ∂(r, v)/∂(Ω, ω) are dependent
+        let r = Vector3::new(7000e3, 0.0, 0.0);
+        let v = Vector3::new(0.0, (GM_EARTH / 7000e3).sqrt(), 0.0);
+        assert!(rv_to_coe_jacobian(&r, &v, GM_EARTH, DEFAULT_TOL).is_err());
+        assert!(rv_to_equinoctial_jacobian(&r, &v, GM_EARTH, DEFAULT_TOL).is_ok());
+    }
 }
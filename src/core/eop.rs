@@ -0,0 +1,690 @@
+//! Earth Orientation Parameters (EOP)
+//!
+//! This module loads and interpolates the IERS Earth Orientation Parameters
+//! needed for precise Earth-fixed transformations and for UT1:
+//! - **UT1 − UTC**: irregular Earth rotation (|UT1 − UTC| < 0.9 s)
+//! - **xp, yp**: polar motion, the CIP position in the ITRS (~0.5″)
+//! - **LOD**: excess length of day
+//! - **dX, dY**: celestial pole offsets relative to IAU 2006/2000A
+//!
+//! # Data Sources
+//!
+//! Tables are read from local files, no network access is required:
+//! - **finals2000A** (IERS Rapid Service/Prediction Center, fixed-width format).
+//!   Bulletin B values are used where present, Bulletin A values otherwise,
+//!   so a single file covers both final and predicted data.
+//! - **EOP 14 C04** (IERS Earth Orientation Centre, whitespace-separated
+//!   columns `year month day MJD x y UT1-UTC LOD dX dY ...`)
+//!
+//! # Global Table and Fallback
+//!
+//! A table installed with [`install_eop_table`] is used by [`eop_at`], and
+//! through it by `Epoch::to_ut1` and the GCRS/TEME ↔ ITRS transformations.
+//! Epochs outside the installed table produce [`EopError::OutOfRange`].
+//!
+//! When no table is installed, the finals2000A excerpt bundled with the crate
+//! (`src/core/data/finals2000A.bundled`, see [`bundled_eop_table`]) is
+//! interpolated. Outside the bundled span, and whenever the bundled file holds
+//! fewer than two rows, the closed-form [`fallback_eop`] is used instead of
+//! clamping to the edge rows: UT1 − UTC = 0, no celestial pole offsets, and
+//! polar motion from the IERS secular pole. This bounds the errors to < 0.9 s
+//! in UT1 (~400 m at the equator) and a few tenths of an arcsecond in the pole
+//! (~10 m at the surface).
+//!
+//! The crate currently ships the bundled file empty, so the closed form applies
+//! until a table is installed. The file is filled by copying the rows of the
+//! IERS `finals2000A.all` product from 1992-01-01 (MJD 48622) onward, including
+//! the predicted rows at its end, unmodified.
+//!
+//! # Interpolation
+//!
+//! Values are interpolated with 4-point Lagrange polynomials. UT1 − UTC is
+//! interpolated as UT1 − TAI so that leap seconds do not introduce jumps.
+//!
+//! # References
+//! - IERS Conventions (2010), Chapters 5 and 7
+//! - IERS finals2000A format: <https://maia.usno.navy.mil/ser7/readme.finals2000A>
+//! - IERS EOP 14 C04: <https://hpiers.obspm.fr/eoppc/eop/eopc04/>
+
+use std::path::Path;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use hifitime::TimeScale;
+use thiserror::Error;
+
+use crate::core::error::PoliastroError;
+use crate::core::time::Epoch;
+
+/// Arcseconds to radians conversion factor
+const ARCSEC_TO_RAD: f64 = std::f64::consts::PI / (180.0 * 3600.0);
+
+/// Milliarcseconds to radians conversion factor
+const MAS_TO_RAD: f64 = ARCSEC_TO_RAD / 1000.0;
+
+/// MJD of J2000.0 (2000-01-01 12:00)
+const MJD_J2000: f64 = 51544.5;
+
+/// Errors from loading or interpolating EOP data
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum EopError {
+    #[error("Failed to read EOP file: {0}")]
+    Io(String),
+
+    #[error("Failed to parse EOP data at line {line}: {reason}")]
+    Parse { line: usize, reason: String },
+
+    #[error("EOP data contains no records")]
+    Empty,
+
+    #[error("Epoch MJD {mjd:.5} (UTC) is outside the loaded EOP range [{start:.2}, {end:.2}]")]
+    OutOfRange { mjd: f64, start: f64, end: f64 },
+}
+
+impl From<EopError> for PoliastroError {
+    fn from(err: EopError) -> Self {
+        match err {
+            EopError::OutOfRange { mjd, start, end } => PoliastroError::OutOfRange {
+                parameter: "epoch MJD (UTC) for Earth Orientation Parameters".to_string(),
+                value: mjd,
+                min: start,
+                max: end,
+            },
+            other => PoliastroError::ComputationError {
+                message: other.to_string(),
+            },
+        }
+    }
+}
+
+/// EOP file formats understood by [`EopTable::from_file`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EopFormat {
+    /// IERS finals2000A (fixed-width, IAU 2000 celestial pole offsets)
+    Finals2000A,
+    /// IERS EOP 14 C04 (whitespace-separated columns)
+    C04,
+}
+
+/// Earth Orientation Parameters at one instant
+///
+/// Angles are in radians, times in seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EopRecord {
+    /// Modified Julian Date (UTC)
+    pub mjd: f64,
+    /// UT1 − UTC (seconds)
+    pub dut1: f64,
+    /// Polar motion x (radians)
+    pub xp: f64,
+    /// Polar motion y (radians)
+    pub yp: f64,
+    /// Excess length of day (seconds)
+    pub lod: f64,
+    /// Celestial pole offset dX (radians)
+    pub dx: f64,
+    /// Celestial pole offset dY (radians)
+    pub dy: f64,
+}
+
+/// Closed-form EOP used outside the bundled table when no table is installed
+///
+/// UT1 − UTC, LOD and the celestial pole offsets are zero; polar motion is
+/// the IERS secular pole (IERS Conventions 2010, Section 7.1.4 as updated in
+/// 2018): xs = 55.0 + 1.677 t, ys = 320.5 + 3.460 t mas, t in years since 2000.
+pub fn fallback_eop(mjd: f64) -> EopRecord {
+    let t = (mjd - MJD_J2000) / 365.25;
+    EopRecord {
+        mjd,
+        dut1: 0.0,
+        xp: (55.0 + 1.677 * t) * MAS_TO_RAD,
+        yp: (320.5 + 3.460 * t) * MAS_TO_RAD,
+        lod: 0.0,
+        dx: 0.0,
+        dy: 0.0,
+    }
+}
+
+/// TAI − UTC (seconds) at a UTC Modified Julian Date
+fn tai_minus_utc(mjd_utc: f64) -> f64 {
+    Epoch::from_mjd(mjd_utc, TimeScale::UTC)
+        .inner()
+        .leap_seconds(false)
+        .unwrap_or(0.0)
+}
+
+/// Table of daily Earth Orientation Parameters
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use astrora_core::core::eop::{EopTable, install_eop_table};
+///
+/// let table = EopTable::from_finals2000a_file("finals2000A.all")?;
+/// install_eop_table(table);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EopTable {
+    records: Vec<EopRecord>,
+    /// UT1 − TAI at each record, continuous across leap seconds
+    ut1_tai: Vec<f64>,
+}
+
+impl EopTable {
+    /// Build a table from records (sorted by MJD, duplicates removed)
+    pub fn from_records(mut records: Vec<EopRecord>) -> Result<Self, EopError> {
+        if records.is_empty() {
+            return Err(EopError::Empty);
+        }
+
+        records.sort_by(|a, b| a.mjd.total_cmp(&b.mjd));
+        records.dedup_by(|a, b| a.mjd == b.mjd);
+
+        let ut1_tai = records
+            .iter()
+            .map(|r| r.dut1 - tai_minus_utc(r.mjd))
+            .collect();
+
+        Ok(Self { records, ut1_tai })
+    }
+
+    /// Parse the contents of an IERS finals2000A file
+    ///
+    /// Rows without polar motion or UT1 − UTC (the blank tail of the
+    /// prediction section) are skipped.
+    pub fn parse_finals2000a(contents: &str) -> Result<Self, EopError> {
+        let mut records = Vec::new();
+        for (index, line) in contents.lines().enumerate() {
+            if let Some(record) = parse_finals2000a_line(line, index + 1)? {
+                records.push(record);
+            }
+        }
+        Self::from_records(records)
+    }
+
+    /// Parse the contents of an IERS EOP 14 C04 file
+    ///
+    /// Header lines (anything not starting with a year followed by numeric
+    /// columns) are skipped.
+    pub fn parse_c04(contents: &str) -> Result<Self, EopError> {
+        let mut records = Vec::new();
+        for (index, line) in contents.lines().enumerate() {
+            if let Some(record) = parse_c04_line(line, index + 1)? {
+                records.push(record);
+            }
+        }
+        Self::from_records(records)
+    }
+
+    /// Load an EOP file from a local path
+    pub fn from_file(path: impl AsRef<Path>, format: EopFormat) -> Result<Self, EopError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| EopError::Io(format!("{}: {}", path.display(), e)))?;
+
+        match format {
+            EopFormat::Finals2000A => Self::parse_finals2000a(&contents),
+            EopFormat::C04 => Self::parse_c04(&contents),
+        }
+    }
+
+    /// Load an IERS finals2000A file from a local path
+    pub fn from_finals2000a_file(path: impl AsRef<Path>) -> Result<Self, EopError> {
+        Self::from_file(path, EopFormat::Finals2000A)
+    }
+
+    /// Load an IERS EOP 14 C04 file from a local path
+    pub fn from_c04_file(path: impl AsRef<Path>) -> Result<Self, EopError> {
+        Self::from_file(path, EopFormat::C04)
+    }
+
+    /// Tabulated records, sorted by MJD
+    pub fn records(&self) -> &[EopRecord] {
+        &self.records
+    }
+
+    /// Number of records
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Whether the table has no records
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// First and last tabulated MJD (UTC)
+    pub fn mjd_range(&self) -> (f64, f64) {
+        (self.records[0].mjd, self.records[self.records.len() - 1].mjd)
+    }
+
+    /// Interpolate the parameters at a UTC Modified Julian Date
+    ///
+    /// # Errors
+    ///
+    /// `EopError::OutOfRange` if `mjd` lies outside the tabulated range.
+    pub fn interpolate(&self, mjd: f64) -> Result<EopRecord, EopError> {
+        let (start, end) = self.mjd_range();
+        if !(start..=end).contains(&mjd) {
+            return Err(EopError::OutOfRange { mjd, start, end });
+        }
+
+        let n = self.records.len();
+        if n == 1 {
+            return Ok(self.records[0]);
+        }
+
+        // Interval [i, i+1] containing mjd, widened to 4 points where available
+        let i = (self.records.partition_point(|r| r.mjd <= mjd) - 1).min(n - 2);
+        let lo = i.saturating_sub(1);
+        let hi = (i + 2).min(n - 1);
+
+        let xs: Vec<f64> = self.records[lo..=hi].iter().map(|r| r.mjd).collect();
+        let interp = |values: &mut dyn Iterator<Item = f64>| {
+            let ys: Vec<f64> = values.collect();
+            lagrange(&xs, &ys, mjd)
+        };
+
+        let window = &self.records[lo..=hi];
+        let ut1_tai = interp(&mut self.ut1_tai[lo..=hi].iter().copied());
+
+        Ok(EopRecord {
+            mjd,
+            dut1: ut1_tai + tai_minus_utc(mjd),
+            xp: interp(&mut window.iter().map(|r| r.xp)),
+            yp: interp(&mut window.iter().map(|r| r.yp)),
+            lod: interp(&mut window.iter().map(|r| r.lod)),
+            dx: interp(&mut window.iter().map(|r| r.dx)),
+            dy: interp(&mut window.iter().map(|r| r.dy)),
+        })
+    }
+}
+
+/// Lagrange polynomial through (xs, ys) evaluated at x
+fn lagrange(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    let mut sum = 0.0;
+    for (j, (&xj, &yj)) in xs.iter().zip(ys).enumerate() {
+        let mut basis = 1.0;
+        for (k, &xk) in xs.iter().enumerate() {
+            if k != j {
+                basis *= (x - xk) / (xj - xk);
+            }
+        }
+        sum += yj * basis;
+    }
+    sum
+}
+
+/// Trimmed contents of 1-based inclusive columns, or None if blank/missing
+fn column(line: &str, first: usize, last: usize) -> Option<&str> {
+    let end = last.min(line.len());
+    line.get(first - 1..end)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+/// Parse an optional numeric column
+fn numeric_column(
+    line: &str,
+    first: usize,
+    last: usize,
+    line_number: usize,
+    name: &str,
+) -> Result<Option<f64>, EopError> {
+    column(line, first, last)
+        .map(|s| {
+            s.parse::<f64>().map_err(|_| EopError::Parse {
+                line: line_number,
+                reason: format!("invalid {} '{}'", name, s),
+            })
+        })
+        .transpose()
+}
+
+/// Parse one finals2000A row (see the IERS readme.finals2000A column layout)
+fn parse_finals2000a_line(line: &str, line_number: usize) -> Result<Option<EopRecord>, EopError> {
+    let Some(mjd) = numeric_column(line, 8, 15, line_number, "MJD")? else {
+        return Ok(None);
+    };
+
+    // Bulletin A
+    let xp_a = numeric_column(line, 19, 27, line_number, "PM-x")?;
+    let yp_a = numeric_column(line, 38, 46, line_number, "PM-y")?;
+    let dut1_a = numeric_column(line, 59, 68, line_number, "UT1-UTC")?;
+    let lod_a = numeric_column(line, 80, 86, line_number, "LOD")?;
+    let dx_a = numeric_column(line, 98, 106, line_number, "dX")?;
+    let dy_a = numeric_column(line, 117, 125, line_number, "dY")?;
+
+    // Bulletin B (final values, present for past dates only)
+    let xp_b = numeric_column(line, 135, 144, line_number, "Bulletin B PM-x")?;
+    let yp_b = numeric_column(line, 145, 154, line_number, "Bulletin B PM-y")?;
+    let dut1_b = numeric_column(line, 155, 165, line_number, "Bulletin B UT1-UTC")?;
+    let dx_b = numeric_column(line, 166, 175, line_number, "Bulletin B dX")?;
+    let dy_b = numeric_column(line, 176, 185, line_number, "Bulletin B dY")?;
+
+    let (Some(xp), Some(yp), Some(dut1)) = (xp_b.or(xp_a), yp_b.or(yp_a), dut1_b.or(dut1_a)) else {
+        return Ok(None);
+    };
+
+    Ok(Some(EopRecord {
+        mjd,
+        dut1,
+        xp: xp * ARCSEC_TO_RAD,
+        yp: yp * ARCSEC_TO_RAD,
+        lod: lod_a.unwrap_or(0.0) * 1e-3,
+        dx: dx_b.or(dx_a).unwrap_or(0.0) * MAS_TO_RAD,
+        dy: dy_b.or(dy_a).unwrap_or(0.0) * MAS_TO_RAD,
+    }))
+}
+
+/// Parse one EOP 14 C04 row: `year month day MJD x y UT1-UTC LOD dX dY ...`
+fn parse_c04_line(line: &str, line_number: usize) -> Result<Option<EopRecord>, EopError> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    if tokens.len() < 10 || tokens[0].parse::<i32>().is_err() {
+        return Ok(None);
+    }
+
+    let mut values = [0.0; 7];
+    for (value, (token, name)) in values.iter_mut().zip(
+        tokens[3..10]
+            .iter()
+            .zip(["MJD", "x", "y", "UT1-UTC", "LOD", "dX", "dY"]),
+    ) {
+        *value = token.parse::<f64>().map_err(|_| EopError::Parse {
+            line: line_number,
+            reason: format!("invalid {} '{}'", name, token),
+        })?;
+    }
+    let [mjd, x, y, dut1, lod, dx, dy] = values;
+
+    Ok(Some(EopRecord {
+        mjd,
+        dut1,
+        xp: x * ARCSEC_TO_RAD,
+        yp: y * ARCSEC_TO_RAD,
+        lod,
+        dx: dx * ARCSEC_TO_RAD,
+        dy: dy * ARCSEC_TO_RAD,
+    }))
+}
+
+/// finals2000A excerpt compiled into the crate
+const BUNDLED_FINALS2000A: &str = include_str!("data/finals2000A.bundled");
+
+/// The finals2000A excerpt bundled with the crate
+///
+/// Parsed on first use. `None` if the bundled file holds fewer than two
+/// records, since a single row would only cover its own day.
+pub fn bundled_eop_table() -> Option<&'static EopTable> {
+    static BUNDLED: OnceLock<Option<EopTable>> = OnceLock::new();
+    BUNDLED
+        .get_or_init(|| {
+            EopTable::parse_finals2000a(BUNDLED_FINALS2000A)
+                .ok()
+                .filter(|table| table.len() >= 2)
+        })
+        .as_ref()
+}
+
+/// Earth Orientation Parameters at a UTC Modified Julian Date without an
+/// installed table
+///
+/// Interpolates the bundled table where it covers `mjd`, and evaluates
+/// [`fallback_eop`] elsewhere.
+pub fn bundled_eop(mjd: f64) -> EopRecord {
+    bundled_eop_table()
+        .and_then(|table| table.interpolate(mjd).ok())
+        .unwrap_or_else(|| fallback_eop(mjd))
+}
+
+/// Globally installed EOP table (None until installed)
+static EOP_TABLE: RwLock<Option<Arc<EopTable>>> = RwLock::new(None);
+
+/// Install the EOP table used by [`eop_at`] and the frame transformations
+///
+/// Replaces any previously installed table.
+pub fn install_eop_table(table: EopTable) {
+    let mut guard = EOP_TABLE.write().unwrap_or_else(|e| e.into_inner());
+    *guard = Some(Arc::new(table));
+}
+
+/// Remove the installed EOP table, reverting to the bundled data
+pub fn clear_eop_table() {
+    let mut guard = EOP_TABLE.write().unwrap_or_else(|e| e.into_inner());
+    *guard = None;
+}
+
+/// Whether an EOP table has been installed
+pub fn eop_table_installed() -> bool {
+    EOP_TABLE
+        .read()
+        .map(|guard| guard.is_some())
+        .unwrap_or(false)
+}
+
+/// The currently installed EOP table, if any
+pub fn installed_eop_table() -> Option<Arc<EopTable>> {
+    EOP_TABLE.read().ok().and_then(|guard| guard.clone())
+}
+
+/// Earth Orientation Parameters at a UTC Modified Julian Date
+///
+/// Interpolates the installed table, or the bundled data ([`bundled_eop`])
+/// when no table is installed.
+///
+/// # Errors
+///
+/// `EopError::OutOfRange` if a table is installed and `mjd` lies outside it.
+pub fn eop_at_mjd(mjd: f64) -> Result<EopRecord, EopError> {
+    match installed_eop_table() {
+        Some(table) => table.interpolate(mjd),
+        None => Ok(bundled_eop(mjd)),
+    }
+}
+
+/// Earth Orientation Parameters at `epoch`
+///
+/// See [`eop_at_mjd`].
+pub fn eop_at(epoch: &Epoch) -> Result<EopRecord, EopError> {
+    eop_at_mjd(epoch.to_mjd_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    /// Format a finals2000A row with Bulletin A values only
+    fn finals_line(mjd: f64, xp: f64, yp: f64, dut1: f64, dx: f64, dy: f64) -> String {
+        format!(
+            "{:>6} {:8.2} I {:9.6}{:9.6} {:9.6}{:9.6}  I{:10.7}{:10.7} {:7.4}{:7.4}  I {:9.3}{:9.3} {:9.3}{:9.3}",
+            "24 1 1", mjd, xp, 0.0001, yp, 0.0001, dut1, 0.00001, 0.5, 0.01, dx, 0.1, dy, 0.1
+        )
+    }
+
+    #[test]
+    fn test_parse_finals2000a_prefers_bulletin_b() {
+        let line = "73 1 2 41684.00 I  0.120733 0.009786  0.136966 0.015902  I 0.8084178 0.0002710  0.0000 0.1916  P    -0.766    0.199    -0.720    0.300   .143000   .137000   .8075000   -18.637    -3.667  ";
+        let table = EopTable::parse_finals2000a(line).unwrap();
+        let r = table.records()[0];
+
+        assert_eq!(r.mjd, 41684.0);
+        assert_relative_eq!(r.xp, 0.143 * ARCSEC_TO_RAD, epsilon = 1e-15);
+        assert_relative_eq!(r.yp, 0.137 * ARCSEC_TO_RAD, epsilon = 1e-15);
+        assert_relative_eq!(r.dut1, 0.8075, epsilon = 1e-12);
+        assert_relative_eq!(r.dx, -18.637 * MAS_TO_RAD, epsilon = 1e-15);
+        assert_relative_eq!(r.dy, -3.667 * MAS_TO_RAD, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_parse_finals2000a_bulletin_a_and_blank_rows() {
+        let contents = [
+            finals_line(60310.0, 0.1, 0.3, 0.012, 0.2, -0.1),
+            finals_line(60311.0, 0.101, 0.301, 0.011, 0.2, -0.1),
+            // Future rows are blank after the MJD
+            "24 1 3 60312.00".to_string(),
+        ]
+        .join("\n");
+
+        let table = EopTable::parse_finals2000a(&contents).unwrap();
+        assert_eq!(table.len(), 2);
+
+        let r = table.records()[1];
+        assert_relative_eq!(r.xp, 0.101 * ARCSEC_TO_RAD, epsilon = 1e-15);
+        assert_relative_eq!(r.yp, 0.301 * ARCSEC_TO_RAD, epsilon = 1e-15);
+        assert_relative_eq!(r.dut1, 0.011, epsilon = 1e-12);
+        assert_relative_eq!(r.lod, 0.5e-3, epsilon = 1e-12);
+        assert_relative_eq!(r.dx, 0.2 * MAS_TO_RAD, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_parse_finals2000a_invalid_number() {
+        let mut line = finals_line(60310.0, 0.1, 0.3, 0.012, 0.0, 0.0);
+        line.replace_range(20..22, "xx");
+        let err = EopTable::parse_finals2000a(&line).unwrap_err();
+        assert!(matches!(err, EopError::Parse { line: 1, .. }));
+    }
+
+    #[test]
+    fn test_parse_c04() {
+        let contents = "\
+                          EOP (IERS) 14 C04 TIME SERIES  consistent with ITRF 2014 - sampled at 0h UTC
+      Date      MJD      x          y        UT1-UTC       LOD         dX        dY
+                         \"          \"           s           s          \"         \"
+     (0h UTC)
+
+2020   1   1  58849   0.076577   0.282336  -0.1772263   0.0003521   0.000091  -0.000064   0.000033   0.000029  0.0000100  0.0000110    0.000059    0.000055
+2020   1   2  58850   0.074961   0.282324  -0.1775616   0.0003114   0.000098  -0.000071   0.000032   0.000029  0.0000097  0.0000110    0.000059    0.000055
+";
+        let table = EopTable::parse_c04(contents).unwrap();
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.mjd_range(), (58849.0, 58850.0));
+
+        let r = table.records()[0];
+        assert_relative_eq!(r.xp, 0.076577 * ARCSEC_TO_RAD, epsilon = 1e-15);
+        assert_relative_eq!(r.yp, 0.282336 * ARCSEC_TO_RAD, epsilon = 1e-15);
+        assert_relative_eq!(r.dut1, -0.1772263, epsilon = 1e-12);
+        assert_relative_eq!(r.lod, 0.0003521, epsilon = 1e-12);
+        assert_relative_eq!(r.dx, 0.000091 * ARCSEC_TO_RAD, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_empty_table_is_error() {
+        assert_eq!(EopTable::parse_c04("header only\n"), Err(EopError::Empty));
+    }
+
+    #[test]
+    fn test_interpolation_is_exact_for_cubic_data() {
+        let records: Vec<EopRecord> = (0..6)
+            .map(|k| {
+                let t = f64::from(k);
+                EopRecord {
+                    mjd: 60000.0 + t,
+                    dut1: 0.0,
+                    xp: 1e-6 * (1.0 + 0.5 * t - 0.1 * t * t + 0.01 * t * t * t),
+                    yp: 2e-6,
+                    lod: 0.0,
+                    dx: 0.0,
+                    dy: 0.0,
+                }
+            })
+            .collect();
+        let table = EopTable::from_records(records).unwrap();
+
+        let t: f64 = 2.3;
+        let r = table.interpolate(60000.0 + t).unwrap();
+        let expected = 1e-6 * (1.0 + 0.5 * t - 0.1 * t * t + 0.01 * t * t * t);
+        assert_relative_eq!(r.xp, expected, epsilon = 1e-18);
+        assert_relative_eq!(r.yp, 2e-6, epsilon = 1e-18);
+
+        // Tabulated points are reproduced, including both ends
+        assert_relative_eq!(table.interpolate(60000.0).unwrap().xp, 1e-6, epsilon = 1e-18);
+        assert_relative_eq!(
+            table.interpolate(60005.0).unwrap().xp,
+            table.records()[5].xp,
+            epsilon = 1e-18
+        );
+    }
+
+    #[test]
+    fn test_interpolation_across_leap_second() {
+        // Leap second at the end of 2016-12-31 (MJD 57753 -> 57754):
+        // UT1-UTC jumps by +1 s while UT1-TAI stays smooth
+        let record = |mjd: f64, dut1: f64| EopRecord {
+            mjd,
+            dut1,
+            xp: 0.0,
+            yp: 0.0,
+            lod: 0.0,
+            dx: 0.0,
+            dy: 0.0,
+        };
+        let table = EopTable::from_records(vec![
+            record(57752.0, -0.4080),
+            record(57753.0, -0.4089),
+            record(57754.0, 0.5903),
+            record(57755.0, 0.5894),
+        ])
+        .unwrap();
+
+        let before = table.interpolate(57753.5).unwrap();
+        let after = table.interpolate(57754.5).unwrap();
+        assert_relative_eq!(before.dut1, -0.40935, epsilon = 1e-4);
+        assert_relative_eq!(after.dut1, 0.58985, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_out_of_range_error() {
+        let table = EopTable::parse_finals2000a(&finals_line(60310.0, 0.1, 0.3, 0.01, 0.0, 0.0)).unwrap();
+        let err = table.interpolate(60320.0).unwrap_err();
+        assert_eq!(
+            err,
+            EopError::OutOfRange {
+                mjd: 60320.0,
+                start: 60310.0,
+                end: 60310.0
+            }
+        );
+
+        let converted: PoliastroError = err.into();
+        assert!(matches!(converted, PoliastroError::OutOfRange { .. }));
+    }
+
+    #[test]
+    fn test_fallback_secular_pole() {
+        let r = fallback_eop(MJD_J2000);
+        assert_eq!(r.dut1, 0.0);
+        assert_relative_eq!(r.xp, 55.0 * MAS_TO_RAD, epsilon = 1e-15);
+        assert_relative_eq!(r.yp, 320.5 * MAS_TO_RAD, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_bundled_table() {
+        let Some(table) = bundled_eop_table() else {
+            // Without bundled rows the closed form applies at every epoch
+            let mjd = 60584.0;
+            assert_eq!(bundled_eop(mjd), fallback_eop(mjd));
+            return;
+        };
+        let (start, end) = table.mjd_range();
+        assert!(table.len() >= 2);
+
+        // Tabulated rows are reproduced, the closed form is used outside them
+        let first = table.records()[0];
+        assert_eq!(bundled_eop(start), first);
+        assert_eq!(bundled_eop(end + 1.0), fallback_eop(end + 1.0));
+        assert_eq!(bundled_eop(start - 1.0), fallback_eop(start - 1.0));
+    }
+
+    #[test]
+    fn test_load_from_file() {
+        let path = std::env::temp_dir().join(format!("astrora_eop_test_{}.txt", std::process::id()));
+        std::fs::write(&path, finals_line(60310.0, 0.1, 0.3, 0.01, 0.0, 0.0)).unwrap();
+
+        let table = EopTable::from_finals2000a_file(&path).unwrap();
+        assert_eq!(table.len(), 1);
+        std::fs::remove_file(&path).unwrap();
+
+        let missing = EopTable::from_c04_file(&path).unwrap_err();
+        assert!(matches!(missing, EopError::Io(_)));
+    }
+}
@@ -139,6 +139,12 @@ pub enum PoliastroError {
         reason: String,
     },
 
+    /// Reference frame name that does not match a supported frame
+    #[error("Unknown reference frame '{name}'")]
+    UnknownFrame {
+        name: String,
+    },
+
     /// Singularity in orbital element conversion
     ///
     /// Occurs for circular orbits (e=0), equatorial orbits (i=0), or
@@ -228,6 +234,16 @@ pub enum PoliastroError {
         reason: String,
     },
 
+    // ========================================================================
+    // Data Interchange Errors
+    // ========================================================================
+    /// Malformed or invalid data message (e.g. a CCSDS OMM or OEM)
+    #[error("Invalid {message_type} message: {reason}")]
+    InvalidMessage {
+        message_type: String,
+        reason: String,
+    },
+
     // ========================================================================
     // General Errors
     // ========================================================================
@@ -297,7 +313,8 @@ impl From<PoliastroError> for PyErr {
             InvalidStateVector { .. }
             | ZeroPosition { .. }
             | ZeroVelocity { .. }
-            | OrbitalSingularity { .. } => PyValueError::new_err(err.to_string()),
+            | OrbitalSingularity { .. }
+            | UnknownFrame { .. } => PyValueError::new_err(err.to_string()),
 
             // Transformation errors → RuntimeError
             TransformationFailure { .. } => PyRuntimeError::new_err(err.to_string()),
@@ -320,6 +337,9 @@ impl From<PoliastroError> for PyErr {
             UnsupportedOrbitType { .. }
             | AmbiguousOrbitType { .. } => PyValueError::new_err(err.to_string()),
 
+            // Message errors → ValueError
+            InvalidMessage { .. } => PyValueError::new_err(err.to_string()),
+
             // General errors → RuntimeError
             ComputationError { .. }
             | NotImplemented { .. }
@@ -372,6 +392,19 @@ impl PoliastroError {
         }
     }
 
+    /// Create an unknown reference frame error
+    pub fn unknown_frame(name: impl Into<String>) -> Self {
+        Self::UnknownFrame { name: name.into() }
+    }
+
+    /// Create an invalid message error
+    pub fn invalid_message(message_type: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidMessage {
+            message_type: message_type.into(),
+            reason: reason.into(),
+        }
+    }
+
     /// Create a not implemented error
     pub fn not_implemented(feature: impl Into<String>) -> Self {
         Self::NotImplemented {
@@ -459,6 +492,18 @@ mod tests {
         assert!(err.to_string().contains("bug"));
     }
 
+    #[test]
+    fn test_invalid_message() {
+        let err = PoliastroError::invalid_message("OEM", "missing META_STOP");
+        assert_eq!(err.to_string(), "Invalid OEM message: missing META_STOP");
+    }
+
+    #[test]
+    fn test_unknown_frame() {
+        let err = PoliastroError::unknown_frame("ECEF");
+        assert_eq!(err.to_string(), "Unknown reference frame 'ECEF'");
+    }
+
     #[test]
     fn test_energy_conservation() {
         let err = PoliastroError::EnergyNotConserved {
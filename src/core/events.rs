@@ -0,0 +1,702 @@
+//! Event detection for the adaptive ODE integrators
+//!
+//! An [`Event`] is a scalar function g(t, y) whose zero crossings mark
+//! something of interest: an apsis (r·v = 0), a node (z = 0), an altitude
+//! threshold, shadow entry or exit. While integrating, the sign of every g is
+//! checked at each accepted step; when it changes in the requested
+//! [`EventDirection`], the crossing is located on the step's dense output
+//! (see [`DenseOutput`]) to within the event tolerance, without any further
+//! integration steps.
+//!
+//! Each event then either records the crossing and continues, stops the
+//! integration at the crossing, or resets the state there (e.g. an impulsive
+//! manoeuvre) and restarts the integration from the new state
+//! ([`EventAction`]).
+//!
+//! # Example
+//! ```ignore
+//! use astrora::core::events::{dopri5_integrate_with_events, Event, EventAction, EventDirection};
+//!
+//! // Stop a falling body when it hits the ground: y = [height, velocity]
+//! let f = |_t: f64, y: &DVector<f64>| DVector::from_vec(vec![y[1], -9.81]);
+//! let ground = Event::new("ground", |_t, y| y[0])
+//!     .with_direction(EventDirection::Decreasing)
+//!     .with_action(EventAction::Stop);
+//!
+//! let y0 = DVector::from_vec(vec![100.0, 0.0]);
+//! let result = dopri5_integrate_with_events(f, 0.0, &y0, 60.0, 1.0, 1e-10, None, &[ground])?;
+//! assert!(result.stopped); // result.t ≈ 4.515 s
+//! ```
+//!
+//! # References
+//! - Hairer, Nørsett & Wanner, "Solving Ordinary Differential Equations I",
+//!   Section II.6 (dense output)
+//! - Shampine & Thompson (2000), "Event location for ordinary differential
+//!   equations", Computers & Mathematics with Applications 39
+
+use std::fmt;
+
+use nalgebra as na;
+
+use crate::core::error::{PoliastroError, PoliastroResult};
+use crate::core::numerical::{
+    dop853_interpolant, dop853_trial, dopri5_interpolant, dopri5_trial, DenseOutput,
+    Dop853Coefficients, DopriCoefficients, TrialStep,
+};
+
+/// Default time tolerance for locating events
+pub const DEFAULT_EVENT_TOLERANCE: f64 = 1e-9;
+
+/// Maximum iterations when locating an event within a step
+const MAX_LOCATE_ITER: usize = 200;
+
+/// Event function g(t, y)
+pub type EventFunction<'a> = Box<dyn Fn(f64, &na::DVector<f64>) -> f64 + 'a>;
+
+/// State reset applied at an event: new y from (t, y)
+pub type ResetFunction<'a> = Box<dyn Fn(f64, &na::DVector<f64>) -> na::DVector<f64> + 'a>;
+
+/// Direction of the zero crossings that trigger an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EventDirection {
+    /// g goes from negative to non-negative
+    Increasing,
+    /// g goes from positive to non-positive
+    Decreasing,
+    /// Either direction
+    #[default]
+    Any,
+}
+
+impl EventDirection {
+    /// Whether g changing from `g_a` to `g_b` is a crossing in this direction
+    pub fn is_crossing(self, g_a: f64, g_b: f64) -> bool {
+        let increasing = g_a < 0.0 && g_b >= 0.0;
+        let decreasing = g_a > 0.0 && g_b <= 0.0;
+        match self {
+            EventDirection::Increasing => increasing,
+            EventDirection::Decreasing => decreasing,
+            EventDirection::Any => increasing || decreasing,
+        }
+    }
+}
+
+/// What happens when an event occurs
+#[derive(Default)]
+pub enum EventAction<'a> {
+    /// Record the crossing and continue
+    #[default]
+    Record,
+    /// Record the crossing and stop the integration there
+    Stop,
+    /// Record the crossing, replace the state and restart from there
+    Reset(ResetFunction<'a>),
+}
+
+impl fmt::Debug for EventAction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventAction::Record => write!(f, "Record"),
+            EventAction::Stop => write!(f, "Stop"),
+            EventAction::Reset(_) => write!(f, "Reset(..)"),
+        }
+    }
+}
+
+/// A zero crossing of g(t, y) to detect during integration
+///
+/// Crossings are located to within `tolerance` (time units); the reported
+/// time is on the far side of the crossing, so g has already changed sign
+/// there. Only sign changes between the integrator's steps are seen: if g can
+/// cross twice within one step (e.g. a short shadow pass with large steps),
+/// set a maximum check interval to sample g on the dense output in between.
+pub struct Event<'a> {
+    /// Name reported with each occurrence
+    pub name: String,
+    /// Crossing direction that triggers the event
+    pub direction: EventDirection,
+    /// Action on occurrence
+    pub action: EventAction<'a>,
+    /// Time tolerance for locating the crossing
+    pub tolerance: f64,
+    /// Maximum interval between checks of g (None: once per step)
+    pub max_check_interval: Option<f64>,
+    g: EventFunction<'a>,
+}
+
+impl<'a> Event<'a> {
+    /// Create an event recording crossings of g in either direction
+    pub fn new(name: impl Into<String>, g: impl Fn(f64, &na::DVector<f64>) -> f64 + 'a) -> Self {
+        Self {
+            name: name.into(),
+            direction: EventDirection::Any,
+            action: EventAction::Record,
+            tolerance: DEFAULT_EVENT_TOLERANCE,
+            max_check_interval: None,
+            g: Box::new(g),
+        }
+    }
+
+    /// Only trigger on crossings in `direction`
+    pub fn with_direction(mut self, direction: EventDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Set the action on occurrence
+    pub fn with_action(mut self, action: EventAction<'a>) -> Self {
+        self.action = action;
+        self
+    }
+
+    /// Reset the state with `reset` on occurrence
+    pub fn with_reset(
+        self,
+        reset: impl Fn(f64, &na::DVector<f64>) -> na::DVector<f64> + 'a,
+    ) -> Self {
+        self.with_action(EventAction::Reset(Box::new(reset)))
+    }
+
+    /// Set the time tolerance for locating crossings
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Check g at least every `interval` (time units) within each step
+    pub fn with_max_check_interval(mut self, interval: f64) -> Self {
+        self.max_check_interval = Some(interval);
+        self
+    }
+
+    /// Evaluate g(t, y)
+    pub fn evaluate(&self, t: f64, y: &na::DVector<f64>) -> f64 {
+        (self.g)(t, y)
+    }
+
+    /// Whether the event stops the integration
+    pub fn is_terminal(&self) -> bool {
+        matches!(self.action, EventAction::Stop)
+    }
+}
+
+impl fmt::Debug for Event<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Event")
+            .field("name", &self.name)
+            .field("direction", &self.direction)
+            .field("action", &self.action)
+            .field("tolerance", &self.tolerance)
+            .field("max_check_interval", &self.max_check_interval)
+            .finish()
+    }
+}
+
+/// One detected event
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventOccurrence {
+    /// Index of the event in the slice passed to the integrator
+    pub index: usize,
+    /// Name of the event
+    pub name: String,
+    /// Time of the crossing
+    pub t: f64,
+    /// State at the crossing (before any reset)
+    pub y: na::DVector<f64>,
+    /// Whether g was increasing through zero
+    pub increasing: bool,
+}
+
+/// Result of an integration with event detection
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventIntegration {
+    /// Final time (the final time requested, or the stopping event)
+    pub t: f64,
+    /// State at the final time
+    pub y: na::DVector<f64>,
+    /// Detected events in chronological order
+    pub events: Vec<EventOccurrence>,
+    /// Whether a [`EventAction::Stop`] event ended the integration
+    pub stopped: bool,
+}
+
+impl EventIntegration {
+    /// Occurrences of the event with the given name
+    pub fn occurrences<'s>(&'s self, name: &'s str) -> impl Iterator<Item = &'s EventOccurrence> {
+        self.events.iter().filter(move |e| e.name == name)
+    }
+}
+
+/// Integrate from t0 to tf with DOPRI5, detecting events
+///
+/// Same step control as [`dopri5_integrate`](crate::core::numerical::dopri5_integrate);
+/// crossings are located on the 4th order Dormand-Prince continuous
+/// extension, which reuses the stages of the step and so costs no extra
+/// evaluations.
+///
+/// # Arguments
+/// * `f` - Right-hand side function dy/dt = f(t, y)
+/// * `t0` - Initial time
+/// * `y0` - Initial state
+/// * `tf` - Final time
+/// * `h0` - Initial step size
+/// * `tol` - Error tolerance
+/// * `max_steps` - Maximum number of steps (None = 100000)
+/// * `events` - Events to detect
+///
+/// # Returns
+/// Final time and state, with the events that occurred
+///
+/// # Errors
+/// Returns error if integration fails or exceeds max_steps
+#[allow(clippy::too_many_arguments)]
+pub fn dopri5_integrate_with_events<F>(
+    f: F,
+    t0: f64,
+    y0: &na::DVector<f64>,
+    tf: f64,
+    h0: f64,
+    tol: f64,
+    max_steps: Option<usize>,
+    events: &[Event],
+) -> PoliastroResult<EventIntegration>
+where
+    F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+{
+    let coeff = DopriCoefficients::new();
+    integrate_with_events(
+        "DOPRI5 integration",
+        |t, y, h| dopri5_trial(&f, t, y, h, tol, &coeff),
+        |t, y, h, step| dopri5_interpolant(t, y, h, &step.y, &step.k),
+        t0,
+        y0,
+        tf,
+        h0,
+        tol,
+        max_steps,
+        events,
+    )
+}
+
+/// Integrate from t0 to tf with DOP853, detecting events
+///
+/// Same step control as [`dop853_integrate`](crate::core::numerical::dop853_integrate);
+/// crossings are located on the 7th order Dormand-Prince continuous
+/// extension, which reuses the stages of the step and adds 4 evaluations per
+/// step containing an event.
+///
+/// # Arguments
+/// See [`dopri5_integrate_with_events`]
+#[allow(clippy::too_many_arguments)]
+pub fn dop853_integrate_with_events<F>(
+    f: F,
+    t0: f64,
+    y0: &na::DVector<f64>,
+    tf: f64,
+    h0: f64,
+    tol: f64,
+    max_steps: Option<usize>,
+    events: &[Event],
+) -> PoliastroResult<EventIntegration>
+where
+    F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+{
+    let coeff = Dop853Coefficients::new();
+    integrate_with_events(
+        "DOP853 integration",
+        |t, y, h| dop853_trial(&f, t, y, h, tol, &coeff),
+        |t, y, h, step| dop853_interpolant(&f, t, y, h, &step.y, &step.k, &coeff),
+        t0,
+        y0,
+        tf,
+        h0,
+        tol,
+        max_steps,
+        events,
+    )
+}
+
+/// Adaptive integration loop shared by the integrators
+#[allow(clippy::too_many_arguments)]
+fn integrate_with_events<S, D>(
+    operation: &str,
+    trial: S,
+    dense: D,
+    t0: f64,
+    y0: &na::DVector<f64>,
+    tf: f64,
+    h0: f64,
+    tol: f64,
+    max_steps: Option<usize>,
+    events: &[Event],
+) -> PoliastroResult<EventIntegration>
+where
+    S: Fn(f64, &na::DVector<f64>, f64) -> TrialStep,
+    D: Fn(f64, &na::DVector<f64>, f64, &TrialStep) -> DenseOutput,
+{
+    let max_steps = max_steps.unwrap_or(100000);
+    let mut t = t0;
+    let mut y = y0.clone();
+    let mut h = h0.abs() * (tf - t0).signum();
+    let min_step_size = 1e-14;
+    let mut g: Vec<f64> = events.iter().map(|e| e.evaluate(t, &y)).collect();
+    let mut occurrences = Vec::new();
+
+    let finish = |t: f64, y: na::DVector<f64>, events, stopped| {
+        Ok(EventIntegration {
+            t,
+            y,
+            events,
+            stopped,
+        })
+    };
+
+    for _step in 0..max_steps {
+        // Check if we've reached the final time
+        if (t - tf).abs() < min_step_size {
+            return finish(t, y, occurrences, false);
+        }
+
+        // Don't overshoot the final time
+        if (tf - t0).signum() * (t + h - tf) > 0.0 {
+            h = tf - t;
+        }
+
+        // Check for tiny step size before taking step
+        if h.abs() < min_step_size {
+            if (t - tf).abs() < 1e-10 {
+                return finish(t, y, occurrences, false);
+            }
+            return Err(PoliastroError::NumericalInstability {
+                operation: operation.to_string(),
+                details: format!("Step size became too small at t = {t} (h = {h})"),
+            });
+        }
+
+        let step = trial(t, &y, h);
+        let h_taken = h;
+        let t_new = t + h_taken;
+        h = step.h_next;
+
+        // Reject step and retry with smaller step size
+        if step.error > tol {
+            continue;
+        }
+
+        let g_new: Vec<f64> = events.iter().map(|e| e.evaluate(t_new, &step.y)).collect();
+        let mut output: Option<DenseOutput> = None;
+        let mut found = Vec::new();
+
+        for (index, event) in events.iter().enumerate() {
+            let samples = event
+                .max_check_interval
+                .map_or(1, |dt| (h_taken.abs() / dt).ceil().max(1.0) as usize);
+            // g = 0 at the start of the step (just after a reset, or at the
+            // initial state) needs the dense output to tell which side it is on
+            if samples == 1
+                && g[index] != 0.0
+                && !event.direction.is_crossing(g[index], g_new[index])
+            {
+                continue;
+            }
+
+            let output = output.get_or_insert_with(|| dense(t, &y, h_taken, &step));
+            let g_theta = |theta: f64| {
+                if theta == 1.0 {
+                    g_new[index]
+                } else {
+                    let t_theta = t + theta * h_taken;
+                    event.evaluate(t_theta, &output.evaluate(t_theta))
+                }
+            };
+
+            let tolerance = event.tolerance.max(4.0 * f64::EPSILON * t_new.abs());
+            let theta_tolerance = tolerance / h_taken.abs();
+
+            // Crossings in each checked sub-interval
+            let mut a = (0.0, g[index]);
+            if a.1 == 0.0 {
+                let theta = theta_tolerance.min(0.5 / samples as f64);
+                a = (theta, g_theta(theta));
+            }
+            for i in 1..=samples {
+                let theta = i as f64 / samples as f64;
+                let b = (theta, g_theta(theta));
+                if event.direction.is_crossing(a.1, b.1) {
+                    let theta = locate_crossing(&g_theta, a, b, theta_tolerance);
+                    found.push((theta, index, a.1 < 0.0));
+                }
+                a = b;
+            }
+        }
+
+        found.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut reset = false;
+        if let Some(output) = &output {
+            for (theta, index, increasing) in found {
+                let t_event = if theta == 1.0 {
+                    t_new
+                } else {
+                    t + theta * h_taken
+                };
+                let y_event = if theta == 1.0 {
+                    step.y.clone()
+                } else {
+                    output.evaluate(t_event)
+                };
+                occurrences.push(EventOccurrence {
+                    index,
+                    name: events[index].name.clone(),
+                    t: t_event,
+                    y: y_event.clone(),
+                    increasing,
+                });
+
+                match &events[index].action {
+                    EventAction::Record => {}
+                    EventAction::Stop => return finish(t_event, y_event, occurrences, true),
+                    EventAction::Reset(reset_state) => {
+                        // Restart from the new state; later crossings in this
+                        // step are re-detected from there
+                        t = t_event;
+                        y = reset_state(t_event, &y_event);
+                        g = events.iter().map(|e| e.evaluate(t, &y)).collect();
+                        reset = true;
+                        break;
+                    }
+                }
+            }
+        }
+
+        if !reset {
+            t = t_new;
+            y = step.y;
+            g = g_new;
+        }
+    }
+
+    Err(PoliastroError::convergence_failure(
+        operation, max_steps, tol,
+    ))
+}
+
+/// Locate the crossing of g between θ = `a` and θ = `b` (bracketing points
+/// with their g values), to within `tolerance` in θ
+///
+/// Alternates Illinois (modified regula falsi) and bisection steps. Returns
+/// the end of the final bracket on the far side of the crossing.
+fn locate_crossing(g: &impl Fn(f64) -> f64, a: (f64, f64), b: (f64, f64), tolerance: f64) -> f64 {
+    let (mut a, mut g_a) = a;
+    let (mut b, mut g_b) = b;
+    let sign = g_a.signum();
+    let mut side = 0;
+
+    for iteration in 0..MAX_LOCATE_ITER {
+        if b - a <= tolerance {
+            break;
+        }
+
+        let mut m = if iteration % 3 == 2 {
+            0.5 * (a + b)
+        } else {
+            (a * g_b - b * g_a) / (g_b - g_a)
+        };
+        if !(m > a && m < b) {
+            m = 0.5 * (a + b);
+        }
+
+        let g_m = g(m);
+        if g_m == 0.0 || g_m.signum() != sign {
+            b = m;
+            g_b = g_m;
+            if side == -1 {
+                g_a *= 0.5;
+            }
+            side = -1;
+        } else {
+            a = m;
+            g_a = g_m;
+            if side == 1 {
+                g_b *= 0.5;
+            }
+            side = 1;
+        }
+    }
+    b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use std::cell::Cell;
+    use std::f64::consts::PI;
+
+    /// Harmonic oscillator y'' = -y: y = [sin t, cos t] from [0, 1]
+    fn oscillator(_t: f64, y: &na::DVector<f64>) -> na::DVector<f64> {
+        na::DVector::from_vec(vec![y[1], -y[0]])
+    }
+
+    fn initial() -> na::DVector<f64> {
+        na::DVector::from_vec(vec![0.0, 1.0])
+    }
+
+    #[test]
+    fn test_direction() {
+        assert!(EventDirection::Increasing.is_crossing(-1.0, 0.0));
+        assert!(!EventDirection::Increasing.is_crossing(0.0, 1.0));
+        assert!(!EventDirection::Increasing.is_crossing(1.0, -1.0));
+        assert!(EventDirection::Decreasing.is_crossing(1.0, -1.0));
+        assert!(EventDirection::Any.is_crossing(1.0, -1.0));
+        assert!(EventDirection::Any.is_crossing(-1.0, 1.0));
+        assert!(!EventDirection::Any.is_crossing(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_record_zero_crossings() {
+        // sin t = 0 at kπ; increasing at even k
+        let events = [
+            Event::new("zero", |_t, y| y[0]),
+            Event::new("rising", |_t, y| y[0]).with_direction(EventDirection::Increasing),
+        ];
+
+        let results = [
+            dopri5_integrate_with_events(
+                oscillator,
+                0.0,
+                &initial(),
+                10.0,
+                0.5,
+                1e-10,
+                None,
+                &events,
+            ),
+            dop853_integrate_with_events(
+                oscillator,
+                0.0,
+                &initial(),
+                10.0,
+                0.5,
+                1e-10,
+                None,
+                &events,
+            ),
+        ];
+        for result in results {
+            let result = result.unwrap();
+
+            assert!(!result.stopped);
+            assert_relative_eq!(result.t, 10.0);
+            assert_relative_eq!(result.y[0], 10.0_f64.sin(), epsilon = 1e-7);
+
+            let zeros: Vec<_> = result.occurrences("zero").collect();
+            assert_eq!(zeros.len(), 3);
+            for (k, event) in zeros.iter().enumerate() {
+                assert_relative_eq!(event.t, (k + 1) as f64 * PI, epsilon = 1e-7);
+                assert_eq!(event.increasing, k % 2 == 1);
+                assert!(event.y[0].abs() < 1e-7);
+            }
+
+            let rising: Vec<_> = result.occurrences("rising").collect();
+            assert_eq!(rising.len(), 1);
+            assert_relative_eq!(rising[0].t, 2.0 * PI, epsilon = 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_stop() {
+        // Falling body hitting the ground: t = sqrt(2 h / g)
+        let f = |_t: f64, y: &na::DVector<f64>| na::DVector::from_vec(vec![y[1], -9.81]);
+        let ground = Event::new("ground", |_t, y| y[0])
+            .with_direction(EventDirection::Decreasing)
+            .with_action(EventAction::Stop);
+        assert!(ground.is_terminal());
+
+        let y0 = na::DVector::from_vec(vec![100.0, 0.0]);
+        let result =
+            dopri5_integrate_with_events(f, 0.0, &y0, 60.0, 1.0, 1e-10, None, &[ground]).unwrap();
+
+        assert!(result.stopped);
+        assert_eq!(result.events.len(), 1);
+        assert_relative_eq!(result.t, (200.0 / 9.81_f64).sqrt(), epsilon = 1e-8);
+        assert!(result.y[0] <= 0.0 && result.y[0] > -1e-6);
+    }
+
+    #[test]
+    fn test_reset_bouncing_ball() {
+        // Ball dropped from 10 m, restitution 0.8
+        let f = |_t: f64, y: &na::DVector<f64>| na::DVector::from_vec(vec![y[1], -9.81]);
+        let bounce = Event::new("bounce", |_t, y| y[0])
+            .with_direction(EventDirection::Decreasing)
+            .with_reset(|_t, y| na::DVector::from_vec(vec![0.0, -0.8 * y[1]]));
+
+        let y0 = na::DVector::from_vec(vec![10.0, 0.0]);
+        let result =
+            dopri5_integrate_with_events(f, 0.0, &y0, 8.0, 0.1, 1e-10, None, &[bounce]).unwrap();
+
+        // First impact at sqrt(2h/g), then flights of 2·0.8ⁿ·v₀/g
+        let t1 = (20.0 / 9.81_f64).sqrt();
+        let v1 = 9.81 * t1;
+        let bounces: Vec<f64> = result.events.iter().map(|e| e.t).collect();
+        assert!(bounces.len() >= 3);
+        assert_relative_eq!(bounces[0], t1, epsilon = 1e-8);
+        assert_relative_eq!(bounces[1], t1 + 2.0 * 0.8 * v1 / 9.81, epsilon = 1e-7);
+        assert_relative_eq!(bounces[2], t1 + 2.0 * 1.8 * 0.8 * v1 / 9.81, epsilon = 1e-6);
+        assert!(result.y[0] >= -1e-6);
+    }
+
+    #[test]
+    fn test_max_check_interval() {
+        // y - 0.999 is positive only briefly around t = π/2: both crossings
+        // fall within one large step
+        let f = |_t: f64, _y: &na::DVector<f64>| na::DVector::from_vec(vec![0.0]);
+        let g = |t: f64, _y: &na::DVector<f64>| t.sin() - 0.999;
+        let y0 = na::DVector::from_vec(vec![0.0]);
+
+        let coarse = [Event::new("peak", g)];
+        let result =
+            dopri5_integrate_with_events(f, 0.0, &y0, 3.0, 3.0, 1e-10, None, &coarse).unwrap();
+        assert!(result.events.is_empty());
+
+        let fine = [Event::new("peak", g).with_max_check_interval(0.01)];
+        let result =
+            dopri5_integrate_with_events(f, 0.0, &y0, 3.0, 3.0, 1e-10, None, &fine).unwrap();
+        assert_eq!(result.events.len(), 2);
+        assert_relative_eq!(result.events[0].t, 0.999_f64.asin(), epsilon = 1e-8);
+        assert_relative_eq!(result.events[1].t, PI - 0.999_f64.asin(), epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_backward_integration() {
+        let events = [Event::new("zero", |_t, y| y[0])];
+        let result = dopri5_integrate_with_events(
+            oscillator,
+            0.0,
+            &initial(),
+            -4.0,
+            0.5,
+            1e-10,
+            None,
+            &events,
+        )
+        .unwrap();
+        assert_eq!(result.events.len(), 1);
+        assert_relative_eq!(result.events[0].t, -PI, epsilon = 1e-7);
+    }
+
+    #[test]
+    fn test_dense_output_only_when_needed() {
+        let calls = Cell::new(0);
+        let f = |_t: f64, y: &na::DVector<f64>| {
+            calls.set(calls.get() + 1);
+            oscillator(0.0, y)
+        };
+        let never = [Event::new("never", |_t, _y| 1.0)];
+        dopri5_integrate_with_events(f, 0.0, &initial(), 10.0, 0.5, 1e-10, None, &never).unwrap();
+        let without_events = calls.replace(0);
+
+        crate::core::numerical::dopri5_integrate(f, 0.0, &initial(), 10.0, 0.5, 1e-10, None)
+            .unwrap();
+        assert_eq!(calls.get(), without_events);
+    }
+}
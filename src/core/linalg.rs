@@ -54,6 +54,9 @@ pub type Matrix6 = na::Matrix6<f64>;
 /// 3D rotation representation
 pub type Rotation3 = na::Rotation3<f64>;
 
+/// Quaternion, not necessarily of unit norm
+pub type Quaternion = na::Quaternion<f64>;
+
 /// Unit quaternion for 3D rotations (more numerically stable)
 pub type UnitQuaternion = na::UnitQuaternion<f64>;
 
@@ -5,12 +5,17 @@ pub mod constants;
 pub mod error;
 pub mod linalg;
 pub mod numerical;
+pub mod events;
+pub mod multistep;
 pub mod integrators_static; // High-performance stack-allocated integrators
 pub mod fast_math; // Optimized math functions for Lambert solver
 pub mod numpy_integration;
 pub mod state;
 pub mod elements;
+pub mod covariance;
 pub mod time;
+pub mod eop;
+pub mod space_weather;
 pub mod anomaly;
 
 // Re-export commonly used types for convenience
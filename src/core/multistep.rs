@@ -0,0 +1,1065 @@
+//! Multistep predictor-corrector integrators
+//!
+//! Methods that reuse the accelerations (or derivatives) of past steps, so
+//! each step costs only two force evaluations (PECE: predict, evaluate,
+//! correct, evaluate) instead of the 7 or 12 of the Dormand-Prince methods.
+//! This makes them the workhorses of long-duration orbit propagation:
+//!
+//! - [`AdamsBashforthMoulton`]: Adams-Bashforth predictor and Adams-Moulton
+//!   corrector with variable step size and order (up to a selectable
+//!   maximum of at most [`MAX_ABM_ORDER`]) under local error control, for
+//!   any first-order system dy/dt = f(t, y)
+//! - [`GaussJackson`]: fixed-step 8th order Gauss-Jackson (summed
+//!   Störmer-Cowell) for second-order systems d²r/dt² = a(t, r, v), the
+//!   classic choice for satellite orbits
+//!
+//! Both start with single-step Runge-Kutta (DOP853) steps until enough past
+//! values are available, and restart the same way after
+//! [`reinitialize`](GaussJackson::reinitialize), e.g. following an impulsive
+//! maneuver that breaks the smoothness of the trajectory. Neither owns the
+//! right-hand side, which is passed to every step, so an integrator can be
+//! kept and continued by a later propagation.
+//!
+//! The Gauss-Jackson coefficients are generated from the series expansions
+//! of the backward difference operators rather than tabulated; the
+//! Adams-Bashforth-Moulton weights are integrals of the polynomial
+//! interpolating the derivatives at the actual times of the past steps.
+//!
+//! # References
+//! - Hairer, Nørsett & Wanner, "Solving Ordinary Differential Equations I",
+//!   Sections III.1, III.5, III.7 and III.10
+//! - Shampine, L. F. & Gordon, M. K., "Computer Solution of Ordinary
+//!   Differential Equations: The Initial Value Problem", Freeman (1975)
+//! - Berry, M. M. & Healy, L. M., "Implementation of Gauss-Jackson
+//!   Integration for Orbit Propagation", J. Astronaut. Sci. 52 (2004)
+
+use std::collections::VecDeque;
+
+use crate::core::error::{PoliastroError, PoliastroResult};
+use crate::core::numerical::dop853_integrate;
+use nalgebra as na;
+
+/// Highest supported Adams-Bashforth-Moulton order
+pub const MAX_ABM_ORDER: usize = 12;
+
+/// Default maximum Adams-Bashforth-Moulton order
+pub const DEFAULT_ABM_ORDER: usize = 8;
+
+/// Safety factor and limits of the Adams-Bashforth-Moulton step size
+/// changes
+const ABM_SAFETY: f64 = 0.9;
+const ABM_MIN_FACTOR: f64 = 0.2;
+const ABM_MAX_FACTOR: f64 = 2.0;
+
+/// Rejected attempts allowed per Adams-Bashforth-Moulton step
+const ABM_MAX_REJECTIONS: usize = 50;
+
+/// Steps shorter than this fraction of the previous one replace it in the
+/// Adams-Bashforth-Moulton history
+const ABM_SLIVER_RATIO: f64 = 0.01;
+
+/// A rejected Adams-Bashforth-Moulton step retried at less than this
+/// fraction of the last accepted step restarts with start-up steps
+const ABM_RESTART_RATIO: f64 = 0.5;
+
+/// Order of the Gauss-Jackson integrator
+pub const GAUSS_JACKSON_ORDER: usize = 8;
+
+/// Tolerance of the DOP853 start-up steps
+const STARTUP_TOLERANCE: f64 = 1e-14;
+
+/// Number of past accelerations used by Gauss-Jackson (differences up to
+/// the 8th)
+const GAUSS_JACKSON_POINTS: usize = GAUSS_JACKSON_ORDER + 1;
+
+/// Backward difference coefficients of the Adams and Störmer-Cowell methods
+///
+/// Returns the first `n` coefficients of (Adams-Bashforth γ, Adams-Moulton
+/// γ*, Störmer σ, Cowell σ*), from the generating functions
+/// −t/((1−t)ln(1−t)), −t/ln(1−t), t²/((1−t)ln²(1−t)) and t²/ln²(1−t).
+fn difference_coefficients(n: usize) -> [Vec<f64>; 4] {
+    // −ln(1−t)/t = Σ t^j/(j+1), and its reciprocal by series division
+    let log_series: Vec<f64> = (0..n).map(|j| 1.0 / (j + 1) as f64).collect();
+    let mut moulton = vec![0.0; n];
+    for j in 0..n {
+        let sum: f64 = (1..=j).map(|i| log_series[i] * moulton[j - i]).sum();
+        moulton[j] = if j == 0 { 1.0 } else { -sum };
+    }
+
+    let cowell: Vec<f64> = (0..n)
+        .map(|j| (0..=j).map(|i| moulton[i] * moulton[j - i]).sum())
+        .collect();
+
+    // Division by (1−t) is a running sum
+    let running_sum = |c: &[f64]| {
+        c.iter()
+            .scan(0.0, |sum, &x| {
+                *sum += x;
+                Some(*sum)
+            })
+            .collect::<Vec<f64>>()
+    };
+
+    [running_sum(&moulton), moulton, running_sum(&cowell), cowell]
+}
+
+/// Weights of f_n, f_{n−1}, … equivalent to Σ_j d_j ∇^j f_n
+fn ordinate_weights(differences: &[f64]) -> Vec<f64> {
+    let mut weights = vec![0.0; differences.len()];
+    // Binomial coefficients C(j, m), row by row
+    let mut binomial = vec![0.0; differences.len()];
+    for (j, &d) in differences.iter().enumerate() {
+        for m in (1..=j).rev() {
+            binomial[m] += binomial[m - 1];
+        }
+        binomial[0] = 1.0;
+        for m in 0..=j {
+            let sign = if m % 2 == 0 { 1.0 } else { -1.0 };
+            weights[m] += sign * d * binomial[m];
+        }
+    }
+    weights
+}
+
+/// Weights w_m with ∫₀¹ p(s) ds = Σ_m w_m·p(s_m) for every polynomial p of
+/// degree below the number of `nodes` s_m
+///
+/// Integrates the Lagrange basis polynomials, expanded in powers of s.
+fn quadrature_weights(nodes: &[f64]) -> Vec<f64> {
+    nodes
+        .iter()
+        .enumerate()
+        .map(|(m, &s_m)| {
+            let mut coefficients = vec![1.0];
+            let mut denominator = 1.0;
+            for (_, &s_i) in nodes.iter().enumerate().filter(|&(i, _)| i != m) {
+                // Multiply by (s − s_i)
+                coefficients.push(0.0);
+                for j in (1..coefficients.len()).rev() {
+                    coefficients[j] = coefficients[j - 1] - s_i * coefficients[j];
+                }
+                coefficients[0] *= -s_i;
+                denominator *= s_m - s_i;
+            }
+            let integral: f64 = coefficients
+                .iter()
+                .enumerate()
+                .map(|(j, c)| c / (j + 1) as f64)
+                .sum();
+            integral / denominator
+        })
+        .collect()
+}
+
+/// Σ_m w_m x_m over the most recent values (newest first)
+fn weighted_sum<'a>(
+    weights: &[f64],
+    values: impl Iterator<Item = &'a na::DVector<f64>>,
+) -> na::DVector<f64> {
+    let mut values = values.zip(weights).map(|(x, &w)| x * w);
+    let first = values.next().expect("at least one value");
+    values.fold(first, |sum, x| sum + x)
+}
+
+/// Number of steps that divides `t0 → tf` into steps of at most `h_max`,
+/// and the resulting step
+fn fixed_steps(t0: f64, tf: f64, h_max: f64) -> PoliastroResult<(usize, f64)> {
+    if !(h_max.is_finite() && h_max != 0.0) {
+        return Err(PoliastroError::invalid_parameter(
+            "h",
+            h_max,
+            "step size must be finite and non-zero",
+        ));
+    }
+    let n_steps = ((tf - t0).abs() / h_max.abs()).ceil().max(1.0) as usize;
+    Ok((n_steps, (tf - t0) / n_steps as f64))
+}
+
+/// One start-up step of size `h` with DOP853
+fn startup_step<F>(f: &F, t: f64, y: &na::DVector<f64>, h: f64) -> PoliastroResult<na::DVector<f64>>
+where
+    F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+{
+    dop853_integrate(f, t, y, t + h, h, STARTUP_TOLERANCE, None)
+}
+
+/// Adams-Bashforth-Moulton predictor-corrector for dy/dt = f(t, y), with
+/// variable step size and order
+///
+/// Each step predicts with the Adams-Bashforth formula of order k over the
+/// last k derivatives, evaluates f, and corrects with the Adams-Moulton
+/// formula of order k+1 (PECE with local extrapolation). The distance from
+/// the Adams-Bashforth prediction of order k to the corrected solution
+/// estimates the local error of the prediction (Milne's device), relative
+/// to 1 + |y| as for the Dormand-Prince methods. The corrected solution
+/// inherits part of that error through f, so it is the one controlled: a
+/// step whose error exceeds the tolerance is retried with a smaller step.
+/// After every accepted step the order moves to whichever of k−1, k and k+1
+/// allows the longest next step, up to the maximum order, and the step size
+/// follows.
+///
+/// The Adams weights are computed for the actual times of the past steps,
+/// so the step size can change from one step to the next. The first steps,
+/// until `max_order` past derivatives are available, are taken with DOP853
+/// at the initial step size, and again at the smaller size when a rejected
+/// step has to shrink to less than half of the last one, since predictions
+/// from derivatives that lie many steps back no longer improve as the step
+/// shrinks.
+///
+/// The right-hand side is passed to every step rather than owned, so the
+/// integrator can be kept and continued later.
+///
+/// # Example
+/// ```ignore
+/// let f = |_t: f64, y: &na::DVector<f64>| na::DVector::from_vec(vec![y[1], -y[0]]);
+/// let mut abm = AdamsBashforthMoulton::new(&f, 0.0, &y0, 0.01, 1e-10, 8)?;
+/// while abm.t() < 10.0 {
+///     abm.step_toward(&f, 10.0)?;
+/// }
+/// let y = abm.state(); // at t = 10
+/// ```
+#[derive(Debug, Clone)]
+pub struct AdamsBashforthMoulton {
+    tol: f64,
+    /// Smallest and largest step sizes allowed
+    min_step: f64,
+    max_step: f64,
+    max_order: usize,
+    order: usize,
+    /// Next step size to try
+    h: f64,
+    t: f64,
+    y: na::DVector<f64>,
+    /// Times and derivatives at the most recent steps, newest first
+    history: VecDeque<(f64, na::DVector<f64>)>,
+    /// Whether the start-up steps were redone since the last accepted
+    /// Adams step
+    restarted: bool,
+}
+
+impl AdamsBashforthMoulton {
+    /// Start an integration from (`t0`, `y0`) with initial step `h`
+    /// (negative to integrate backward)
+    ///
+    /// # Arguments
+    /// * `f` - Right-hand side function dy/dt = f(t, y)
+    /// * `t0` - Initial time
+    /// * `y0` - Initial state
+    /// * `h` - Initial step size, also that of the start-up steps
+    /// * `tol` - Error tolerance
+    /// * `max_order` - Highest order used (1 to [`MAX_ABM_ORDER`])
+    ///
+    /// # Errors
+    /// Returns an error if `max_order` is not between 1 and
+    /// [`MAX_ABM_ORDER`], `h` is zero or not finite, or `tol` is not positive
+    pub fn new<F>(
+        f: &F,
+        t0: f64,
+        y0: &na::DVector<f64>,
+        h: f64,
+        tol: f64,
+        max_order: usize,
+    ) -> PoliastroResult<Self>
+    where
+        F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+    {
+        if !(1..=MAX_ABM_ORDER).contains(&max_order) {
+            return Err(PoliastroError::out_of_range(
+                "order",
+                max_order as f64,
+                1.0,
+                MAX_ABM_ORDER as f64,
+            ));
+        }
+        fixed_steps(t0, t0 + h, h)?;
+        if tol.is_nan() || tol <= 0.0 {
+            return Err(PoliastroError::invalid_parameter(
+                "tol",
+                tol,
+                "must be positive",
+            ));
+        }
+
+        let mut integrator = Self {
+            tol,
+            min_step: 0.0,
+            max_step: f64::INFINITY,
+            max_order,
+            order: 1,
+            h,
+            t: t0,
+            y: y0.clone(),
+            history: VecDeque::with_capacity(max_order + 2),
+            restarted: false,
+        };
+        integrator.reinitialize(f, t0, y0);
+        Ok(integrator)
+    }
+
+    /// Keep the step size between `min_step` and `max_step`
+    ///
+    /// A step that must shrink below `min_step` to meet the tolerance fails
+    /// instead; only the last step before a target time may be shorter.
+    pub fn with_step_limits(mut self, min_step: f64, max_step: f64) -> Self {
+        self.min_step = min_step;
+        self.max_step = max_step;
+        self.h = self.h.signum() * self.h.abs().min(max_step);
+        self
+    }
+
+    /// Current time
+    pub fn t(&self) -> f64 {
+        self.t
+    }
+
+    /// Current state
+    pub fn state(&self) -> &na::DVector<f64> {
+        &self.y
+    }
+
+    /// Size of the next step to try
+    pub fn step_size(&self) -> f64 {
+        self.h
+    }
+
+    /// Order of the error-controlled formula for the next step (the
+    /// corrected solution is one order higher)
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Highest order used
+    pub fn max_order(&self) -> usize {
+        self.max_order
+    }
+
+    /// Error tolerance
+    pub fn tolerance(&self) -> f64 {
+        self.tol
+    }
+
+    /// Restart from (`t`, `y`), e.g. after an impulsive change of the state
+    ///
+    /// The past derivatives are discarded and the next steps are start-up
+    /// steps again; the step size is kept.
+    pub fn reinitialize<F>(&mut self, f: &F, t: f64, y: &na::DVector<f64>)
+    where
+        F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+    {
+        self.t = t;
+        self.y = y.clone();
+        self.order = 1;
+        self.restarted = false;
+        self.history.clear();
+        self.history.push_front((t, f(t, y)));
+    }
+
+    /// Advance by one accepted step
+    ///
+    /// # Errors
+    /// Returns an error if a start-up step fails or the step size becomes
+    /// too small to meet the tolerance
+    pub fn step<F>(&mut self, f: &F) -> PoliastroResult<()>
+    where
+        F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+    {
+        if self.h.abs() < self.min_step {
+            return Err(self.step_too_small(self.h));
+        }
+        self.advance(f, self.h).map(|_| ())
+    }
+
+    /// Advance by one accepted step, without passing `t_end`
+    ///
+    /// A step that would overshoot `t_end` lands on it, and one that would
+    /// leave less than a full step to go is halved so that the last step is
+    /// not a sliver. Does nothing at `t_end`.
+    ///
+    /// # Errors
+    /// Returns an error if `t_end` lies behind in the direction of
+    /// integration, or as [`Self::step`]
+    pub fn step_toward<F>(&mut self, f: &F, t_end: f64) -> PoliastroResult<()>
+    where
+        F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+    {
+        let remaining = t_end - self.t;
+        if remaining == 0.0 {
+            return Ok(());
+        }
+        if remaining.signum() != self.h.signum() {
+            return Err(PoliastroError::invalid_parameter(
+                "t_end",
+                t_end,
+                "must lie ahead in the direction of integration",
+            ));
+        }
+
+        let h_natural = self.h;
+        if remaining.abs() > h_natural.abs() && h_natural.abs() < self.min_step {
+            return Err(self.step_too_small(h_natural));
+        }
+        let h = if remaining.abs() <= h_natural.abs() {
+            remaining
+        } else if remaining.abs() < 2.0 * h_natural.abs() {
+            remaining / 2.0
+        } else {
+            h_natural
+        };
+        let h_taken = self.advance(f, h)?;
+        if h_taken == remaining {
+            self.t = t_end;
+        }
+        // A step shortened to fit says little about the step the solution
+        // allows, unless even the shortened step failed
+        if h_taken == h && self.h.abs() < h_natural.abs() {
+            self.h = h_natural;
+        }
+        Ok(())
+    }
+
+    /// Take one step, starting with size `h`; returns the size accepted
+    fn advance<F>(&mut self, f: &F, h: f64) -> PoliastroResult<f64>
+    where
+        F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+    {
+        // Steps the clock can represent exactly, so the history times match
+        // the steps taken even far from t = 0
+        let snap = |h: f64| (self.t + h) - self.t;
+        if self.history.len() < self.max_order {
+            let h = snap(h);
+            let y = startup_step(f, self.t, &self.y, h)?;
+            self.accept(f, h, y);
+            self.order = self.history.len().min(self.max_order);
+            return Ok(h);
+        }
+
+        let mut h = h;
+        for _ in 0..ABM_MAX_REJECTIONS {
+            h = snap(h);
+            let k = self.order;
+            // Past times in units of the step from the current time (0, −1, …
+            // for equal steps)
+            let past: Vec<f64> = self
+                .history
+                .iter()
+                .map(|(t_i, _)| (t_i - self.t) / h)
+                .collect();
+            let derivatives = || self.history.iter().map(|(_, f_i)| f_i);
+
+            // Increments of the predictors of orders k−1 to k+1, as far as the
+            // history and the maximum order go; order j uses j past points
+            let lowest = k.saturating_sub(1).max(1);
+            let highest = (k + 1).min(past.len()).min(self.max_order);
+            let predictions: Vec<na::DVector<f64>> = (lowest..=highest)
+                .map(|j| weighted_sum(&quadrature_weights(&past[..j]), derivatives()) * h)
+                .collect();
+
+            // Evaluate at the order k prediction and correct with order k+1
+            let f_predicted = f(self.t + h, &(&self.y + &predictions[k - lowest]));
+            let nodes: Vec<f64> = std::iter::once(1.0)
+                .chain(past[..k].iter().copied())
+                .collect();
+            let increment = weighted_sum(
+                &quadrature_weights(&nodes),
+                std::iter::once(&f_predicted).chain(derivatives()),
+            ) * h;
+
+            // Local error of the order j prediction
+            let scale = 1.0 + self.y.norm();
+            let error = |j: usize| {
+                (lowest..=highest)
+                    .contains(&j)
+                    .then(|| (&predictions[j - lowest] - &increment).norm() / scale)
+            };
+            let factor = |j: usize, error: f64| {
+                if error == 0.0 {
+                    ABM_MAX_FACTOR
+                } else {
+                    (ABM_SAFETY * (self.tol / error).powf(1.0 / (j + 1) as f64))
+                        .clamp(ABM_MIN_FACTOR, ABM_MAX_FACTOR)
+                }
+            };
+            let error_k = error(k).expect("the history holds at least k derivatives");
+
+            if error_k <= self.tol {
+                // Keep the order unless a neighbour allows a longer step
+                let (order, step_factor) = [k, k - 1, k + 1]
+                    .into_iter()
+                    .filter(|&j| (1..=self.max_order).contains(&j))
+                    .filter_map(|j| error(j).map(|e| (j, factor(j, e))))
+                    .fold((k, factor(k, error_k)), |best, candidate| {
+                        if candidate.1 > best.1 {
+                            candidate
+                        } else {
+                            best
+                        }
+                    });
+                let y = &self.y + &increment;
+                self.accept(f, h, y);
+                self.order = order;
+                self.restarted = false;
+                self.h = h.signum() * (h.abs() * step_factor).min(self.max_step);
+                return Ok(h);
+            }
+
+            if error(k - 1).is_some_and(|e| e < error_k) {
+                self.order = k - 1;
+            }
+            h *= factor(k, error_k);
+            if h.abs() < self.min_step || h.abs() <= 4.0 * f64::EPSILON * self.t.abs().max(1.0) {
+                return Err(self.step_too_small(h));
+            }
+            // Once per accepted step, so the start-up steps cannot stand in for
+            // a step size below the minimum
+            if !self.restarted
+                && self
+                    .history
+                    .get(1)
+                    .is_some_and(|(t_1, _)| h.abs() < ABM_RESTART_RATIO * (self.t - t_1).abs())
+            {
+                self.history.truncate(1);
+                self.order = 1;
+                self.h = h;
+                self.restarted = true;
+                return self.advance(f, h);
+            }
+        }
+
+        Err(PoliastroError::NumericalInstability {
+            operation: "Adams-Bashforth-Moulton integration".to_string(),
+            details: format!("Step rejected {ABM_MAX_REJECTIONS} times at t = {}", self.t),
+        })
+    }
+
+    /// Move to the end of an accepted step of size `h` with state `y`
+    fn accept<F>(&mut self, f: &F, h: f64, y: na::DVector<f64>)
+    where
+        F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+    {
+        // A sliver of a step would make the interpolation through the newest
+        // points ill-conditioned: its end replaces its start instead
+        if self.history.len() >= 2
+            && h.abs() < ABM_SLIVER_RATIO * (self.history[0].0 - self.history[1].0).abs()
+        {
+            self.history.pop_front();
+        }
+        self.t += h;
+        self.y = y;
+        self.history.push_front((self.t, f(self.t, &self.y)));
+        self.history.truncate(self.max_order + 1);
+    }
+
+    fn step_too_small(&self, h: f64) -> PoliastroError {
+        PoliastroError::NumericalInstability {
+            operation: "Adams-Bashforth-Moulton integration".to_string(),
+            details: format!("Step size became too small at t = {} (h = {h})", self.t),
+        }
+    }
+}
+
+/// Gauss-Jackson integrator for d²r/dt² = a(t, r, v)
+///
+/// The 8th order summed Störmer-Cowell method: positions follow from the
+/// second sum of the accelerations and velocities from the first sum
+/// (summed Adams), each plus a correction over the last nine accelerations,
+/// in PECE mode. Keeping the sums instead of differencing the positions
+/// limits the growth of round-off over long propagations. The first eight
+/// steps are taken with DOP853.
+///
+/// The acceleration may depend on the velocity (e.g. drag); the velocity
+/// at the predicted point comes from the summed Adams-Bashforth formula.
+///
+/// # Example
+/// ```ignore
+/// let a = |_t: f64, r: &na::DVector<f64>, _v: &na::DVector<f64>| r * (-GM_EARTH / r.norm().powi(3));
+/// let mut gj = GaussJackson::new(&a, 0.0, &r0, &v0, 60.0)?;
+/// for _ in 0..1440 {
+///     gj.step(&a)?;
+/// }
+/// let (r, v) = (gj.position(), gj.velocity()); // one day later
+/// ```
+#[derive(Debug, Clone)]
+pub struct GaussJackson {
+    h: f64,
+    /// Time of the last (re)initialization and steps taken since, so that
+    /// the time stays on the step grid
+    t_start: f64,
+    steps: usize,
+    r: na::DVector<f64>,
+    v: na::DVector<f64>,
+    /// First and second sums of the accelerations (valid once started)
+    first_sum: na::DVector<f64>,
+    second_sum: na::DVector<f64>,
+    /// Accelerations at the most recent steps, newest first
+    history: VecDeque<na::DVector<f64>>,
+    position_predictor: Vec<f64>,
+    position_corrector: Vec<f64>,
+    velocity_predictor: Vec<f64>,
+    velocity_corrector: Vec<f64>,
+}
+
+impl GaussJackson {
+    /// Start an integration from (`t0`, `r0`, `v0`) with step `h` (negative
+    /// to integrate backward)
+    ///
+    /// # Errors
+    /// Returns an error if `h` is zero or not finite, or if `r0` and `v0`
+    /// differ in dimension
+    pub fn new<A>(
+        a: &A,
+        t0: f64,
+        r0: &na::DVector<f64>,
+        v0: &na::DVector<f64>,
+        h: f64,
+    ) -> PoliastroResult<Self>
+    where
+        A: Fn(f64, &na::DVector<f64>, &na::DVector<f64>) -> na::DVector<f64>,
+    {
+        fixed_steps(t0, t0 + h, h)?;
+        if r0.len() != v0.len() {
+            return Err(PoliastroError::invalid_state(
+                "Position and velocity must have the same dimension",
+            ));
+        }
+
+        // Differences up to the 8th: σ_2.., σ*_2.. for positions and
+        // γ_1.., γ*_1.. for velocities
+        let [bashforth, moulton, stormer, cowell] =
+            difference_coefficients(GAUSS_JACKSON_POINTS + 2);
+        let mut integrator = Self {
+            h,
+            t_start: t0,
+            steps: 0,
+            r: r0.clone(),
+            v: v0.clone(),
+            first_sum: na::DVector::zeros(r0.len()),
+            second_sum: na::DVector::zeros(r0.len()),
+            history: VecDeque::with_capacity(GAUSS_JACKSON_POINTS + 1),
+            position_predictor: ordinate_weights(&stormer[2..GAUSS_JACKSON_POINTS + 2]),
+            position_corrector: ordinate_weights(&cowell[2..GAUSS_JACKSON_POINTS + 2]),
+            velocity_predictor: ordinate_weights(&bashforth[1..GAUSS_JACKSON_POINTS + 1]),
+            velocity_corrector: ordinate_weights(&moulton[1..GAUSS_JACKSON_POINTS + 1]),
+        };
+        integrator.reinitialize(a, t0, r0, v0);
+        Ok(integrator)
+    }
+
+    /// Current time
+    pub fn t(&self) -> f64 {
+        self.t_start + self.steps as f64 * self.h
+    }
+
+    /// Current position
+    pub fn position(&self) -> &na::DVector<f64> {
+        &self.r
+    }
+
+    /// Current velocity
+    pub fn velocity(&self) -> &na::DVector<f64> {
+        &self.v
+    }
+
+    /// Step size
+    pub fn step_size(&self) -> f64 {
+        self.h
+    }
+
+    /// Restart from (`t`, `r`, `v`), e.g. after an impulsive maneuver
+    ///
+    /// The past accelerations are discarded and the next eight steps are
+    /// start-up steps again.
+    pub fn reinitialize<A>(&mut self, a: &A, t: f64, r: &na::DVector<f64>, v: &na::DVector<f64>)
+    where
+        A: Fn(f64, &na::DVector<f64>, &na::DVector<f64>) -> na::DVector<f64>,
+    {
+        self.t_start = t;
+        self.steps = 0;
+        self.r = r.clone();
+        self.v = v.clone();
+        self.history.clear();
+        self.history.push_front(a(t, r, v));
+    }
+
+    /// Advance by one step
+    ///
+    /// # Errors
+    /// Returns an error if a start-up step fails
+    pub fn step<A>(&mut self, a: &A) -> PoliastroResult<()>
+    where
+        A: Fn(f64, &na::DVector<f64>, &na::DVector<f64>) -> na::DVector<f64>,
+    {
+        let (t, h) = (self.t(), self.h);
+
+        if self.history.len() < GAUSS_JACKSON_POINTS {
+            self.startup_step(a)?;
+        } else {
+            // Predict: S_{n+1} = S_n + s_n
+            self.second_sum += &self.first_sum;
+            let r = (&self.second_sum
+                + weighted_sum(&self.position_predictor, self.history.iter()))
+                * (h * h);
+            let v =
+                (&self.first_sum + weighted_sum(&self.velocity_predictor, self.history.iter())) * h;
+
+            // Evaluate and correct: s_{n+1} = s_n + a_{n+1}
+            let a_predicted = a(t + h, &r, &v);
+            let window = || std::iter::once(&a_predicted).chain(self.history.iter());
+            let first_sum = &self.first_sum + &a_predicted;
+            self.r =
+                (&self.second_sum + weighted_sum(&self.position_corrector, window())) * (h * h);
+            self.v = (&first_sum + weighted_sum(&self.velocity_corrector, window())) * h;
+        }
+
+        // Evaluate at the new point
+        self.steps += 1;
+        let a_new = a(self.t(), &self.r, &self.v);
+        self.history.push_front(a_new);
+        self.history.truncate(GAUSS_JACKSON_POINTS);
+
+        if self.history.len() == GAUSS_JACKSON_POINTS {
+            // The first sum follows the final acceleration; on completing the
+            // start-up both sums are initialised from the corrector formulas
+            self.first_sum =
+                &self.v / h - weighted_sum(&self.velocity_corrector, self.history.iter());
+            self.second_sum =
+                &self.r / (h * h) - weighted_sum(&self.position_corrector, self.history.iter());
+        }
+        Ok(())
+    }
+
+    fn startup_step<A>(&mut self, a: &A) -> PoliastroResult<()>
+    where
+        A: Fn(f64, &na::DVector<f64>, &na::DVector<f64>) -> na::DVector<f64>,
+    {
+        let n = self.r.len();
+        let f = |t: f64, y: &na::DVector<f64>| {
+            let r = y.rows(0, n).into_owned();
+            let v = y.rows(n, n).into_owned();
+            let mut dy = na::DVector::zeros(2 * n);
+            dy.rows_mut(n, n).copy_from(&a(t, &r, &v));
+            dy.rows_mut(0, n).copy_from(&v);
+            dy
+        };
+
+        let mut y = na::DVector::zeros(2 * n);
+        y.rows_mut(0, n).copy_from(&self.r);
+        y.rows_mut(n, n).copy_from(&self.v);
+        let y = startup_step(&f, self.t(), &y, self.h)?;
+        self.r = y.rows(0, n).into_owned();
+        self.v = y.rows(n, n).into_owned();
+        Ok(())
+    }
+}
+
+/// Integrate dy/dt = f(t, y) from t0 to tf with Adams-Bashforth-Moulton
+///
+/// # Arguments
+/// * `f` - Right-hand side function dy/dt = f(t, y)
+/// * `t0` - Initial time
+/// * `y0` - Initial state
+/// * `tf` - Final time (may be before t0)
+/// * `h0` - Initial step size
+/// * `tol` - Error tolerance
+/// * `max_order` - Highest order used (1 to [`MAX_ABM_ORDER`])
+/// * `max_steps` - Maximum number of steps (None = 100000)
+///
+/// # Returns
+/// Final state vector at time tf
+///
+/// # Errors
+/// Returns an error for an invalid order, step size or tolerance, if a
+/// step fails, or if the integration exceeds max_steps
+#[allow(clippy::too_many_arguments)]
+pub fn abm_integrate<F>(
+    f: F,
+    t0: f64,
+    y0: &na::DVector<f64>,
+    tf: f64,
+    h0: f64,
+    tol: f64,
+    max_order: usize,
+    max_steps: Option<usize>,
+) -> PoliastroResult<na::DVector<f64>>
+where
+    F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+{
+    if tf == t0 {
+        return Ok(y0.clone());
+    }
+    let max_steps = max_steps.unwrap_or(100000);
+    let h0 = h0.abs() * (tf - t0).signum();
+    let mut abm = AdamsBashforthMoulton::new(&f, t0, y0, h0, tol, max_order)?;
+    for _ in 0..max_steps {
+        abm.step_toward(&f, tf)?;
+        if abm.t == tf {
+            return Ok(abm.y);
+        }
+    }
+    Err(PoliastroError::convergence_failure(
+        "Adams-Bashforth-Moulton integration",
+        max_steps,
+        tol,
+    ))
+}
+
+/// Integrate d²r/dt² = a(t, r, v) from t0 to tf with Gauss-Jackson
+///
+/// # Arguments
+/// * `a` - Acceleration function a(t, r, v)
+/// * `t0` - Initial time
+/// * `r0`, `v0` - Initial position and velocity
+/// * `tf` - Final time
+/// * `h` - Largest step size; the span is divided into equal steps
+///
+/// # Returns
+/// Final position and velocity at time tf
+///
+/// # Errors
+/// Returns an error for an invalid step size or if a start-up step fails
+pub fn gauss_jackson_integrate<A>(
+    a: A,
+    t0: f64,
+    r0: &na::DVector<f64>,
+    v0: &na::DVector<f64>,
+    tf: f64,
+    h: f64,
+) -> PoliastroResult<(na::DVector<f64>, na::DVector<f64>)>
+where
+    A: Fn(f64, &na::DVector<f64>, &na::DVector<f64>) -> na::DVector<f64>,
+{
+    if tf == t0 {
+        return Ok((r0.clone(), v0.clone()));
+    }
+    let (n_steps, h) = fixed_steps(t0, tf, h)?;
+    let mut gj = GaussJackson::new(&a, t0, r0, v0, h)?;
+    for _ in 0..n_steps {
+        gj.step(&a)?;
+    }
+    Ok((gj.r, gj.v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::GM_EARTH;
+    use crate::core::linalg::Vector3;
+    use crate::core::numerical::dop853_integrate;
+    use crate::propagators::keplerian::propagate_state_keplerian;
+    use approx::assert_relative_eq;
+    use std::cell::Cell;
+
+    fn oscillator(_t: f64, y: &na::DVector<f64>) -> na::DVector<f64> {
+        na::DVector::from_vec(vec![y[1], -y[0]])
+    }
+
+    fn kepler(_t: f64, r: &na::DVector<f64>, _v: &na::DVector<f64>) -> na::DVector<f64> {
+        r * (-GM_EARTH / r.norm().powi(3))
+    }
+
+    fn leo() -> (na::DVector<f64>, na::DVector<f64>) {
+        (
+            na::DVector::from_vec(vec![7000e3, 0.0, 0.0]),
+            na::DVector::from_vec(vec![0.0, 6000.0, 4000.0]),
+        )
+    }
+
+    #[test]
+    fn test_difference_coefficients() {
+        let [bashforth, moulton, stormer, cowell] = difference_coefficients(6);
+        let expected = [
+            [1.0, 0.5, 5.0 / 12.0, 3.0 / 8.0, 251.0 / 720.0, 95.0 / 288.0],
+            [
+                1.0,
+                -0.5,
+                -1.0 / 12.0,
+                -1.0 / 24.0,
+                -19.0 / 720.0,
+                -3.0 / 160.0,
+            ],
+            [1.0, 0.0, 1.0 / 12.0, 1.0 / 12.0, 19.0 / 240.0, 3.0 / 40.0],
+            [1.0, -1.0, 1.0 / 12.0, 0.0, -1.0 / 240.0, -1.0 / 240.0],
+        ];
+        for (computed, expected) in [bashforth, moulton, stormer, cowell].iter().zip(expected) {
+            for (c, e) in computed.iter().zip(expected) {
+                assert_relative_eq!(*c, e, epsilon = 1e-15);
+            }
+        }
+
+        // AB4 in ordinate form: (55, −59, 37, −9)/24
+        let weights = ordinate_weights(&difference_coefficients(4)[0]);
+        for (w, e) in weights.iter().zip([55.0, -59.0, 37.0, -9.0]) {
+            assert_relative_eq!(*w, e / 24.0, epsilon = 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_quadrature_weights() {
+        // AB4 and AM4 over unit-spaced nodes
+        let bashforth = quadrature_weights(&[0.0, -1.0, -2.0, -3.0]);
+        for (w, e) in bashforth.iter().zip([55.0, -59.0, 37.0, -9.0]) {
+            assert_relative_eq!(*w, e / 24.0, epsilon = 1e-13);
+        }
+        let moulton = quadrature_weights(&[1.0, 0.0, -1.0, -2.0]);
+        for (w, e) in moulton.iter().zip([9.0, 19.0, -5.0, 1.0]) {
+            assert_relative_eq!(*w, e / 24.0, epsilon = 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_abm_error_control() {
+        let y0 = na::DVector::from_vec(vec![0.0, 1.0]);
+        let error = |tol: f64, max_order: usize| {
+            let y = abm_integrate(oscillator, 0.0, &y0, 10.0, 0.1, tol, max_order, None).unwrap();
+            (y[0] - 10.0_f64.sin()).abs()
+        };
+
+        // The global error follows the tolerance
+        for max_order in [4, 8, 12] {
+            assert!(error(1e-6, max_order) < 1e-4);
+            assert!(error(1e-12, max_order) < 1e-9);
+        }
+
+        // Orders and steps grow once the start-up is over
+        let mut abm = AdamsBashforthMoulton::new(&oscillator, 0.0, &y0, 0.01, 1e-12, 10).unwrap();
+        while abm.t() < 5.0 {
+            abm.step(&oscillator).unwrap();
+        }
+        assert!(abm.order() > 6);
+        assert!(abm.step_size() > 0.05);
+
+        // Landing on intermediate times does not disturb the history
+        for k in 1..=10 {
+            let t_end = 5.0 + 0.5 * k as f64;
+            while abm.t() < t_end {
+                abm.step_toward(&oscillator, t_end).unwrap();
+            }
+        }
+        assert_eq!(abm.t(), 10.0);
+        assert_relative_eq!(abm.state()[0], 10.0_f64.sin(), epsilon = 1e-9);
+
+        // Backward
+        let y = abm_integrate(oscillator, 10.0, &y0, 0.0, 0.1, 1e-12, 8, None).unwrap();
+        assert_relative_eq!(y[0], (-10.0_f64).sin(), epsilon = 1e-9);
+
+        assert!(AdamsBashforthMoulton::new(&oscillator, 0.0, &y0, 0.1, 1e-10, 0).is_err());
+        assert!(AdamsBashforthMoulton::new(&oscillator, 0.0, &y0, 0.1, 1e-10, 13).is_err());
+        assert!(AdamsBashforthMoulton::new(&oscillator, 0.0, &y0, 0.1, 0.0, 8).is_err());
+        assert!(abm_integrate(oscillator, 0.0, &y0, 1.0, 0.0, 1e-10, 4, None).is_err());
+        assert!(abm_integrate(oscillator, 0.0, &y0, 100.0, 0.1, 1e-10, 4, Some(10)).is_err());
+    }
+
+    #[test]
+    fn test_abm_two_body_tolerance() {
+        // Eccentric (e ≈ 0.1) low Earth orbit, one day against the analytic
+        // solution
+        let (r0, v0) = (
+            Vector3::new(7000e3, 0.0, 0.0),
+            Vector3::new(0.0, 6500.0, 4500.0),
+        );
+        let y0 = na::DVector::from_vec(vec![r0.x, r0.y, r0.z, v0.x, v0.y, v0.z]);
+        let f = |_t: f64, y: &na::DVector<f64>| {
+            let r = y.rows(0, 3).into_owned();
+            let a = kepler(0.0, &r, &r);
+            na::DVector::from_vec(vec![y[3], y[4], y[5], a[0], a[1], a[2]])
+        };
+        let tf = 86400.0;
+        let (r, _) = propagate_state_keplerian(&r0, &v0, tf, GM_EARTH).unwrap();
+        let position_error = |y: &na::DVector<f64>| (Vector3::new(y[0], y[1], y[2]) - r).norm();
+
+        for max_order in [4, 8, 12] {
+            let mut errors = Vec::new();
+            for tol in [1e-8, 1e-10, 1e-12] {
+                let y = abm_integrate(f, 0.0, &y0, tf, 60.0, tol, max_order, None).unwrap();
+                let single = position_error(&y);
+
+                // The same span in uneven segments, as a propagator reporting
+                // along the way would
+                let mut abm =
+                    AdamsBashforthMoulton::new(&f, 0.0, &y0, 60.0, tol, max_order).unwrap();
+                for hour in 1..=24 {
+                    let t_end = if hour == 24 {
+                        tf
+                    } else {
+                        3600.0 * hour as f64 - 0.37 * (hour % 3) as f64
+                    };
+                    while abm.t() != t_end {
+                        abm.step_toward(&f, t_end).unwrap();
+                    }
+                }
+                let segmented = position_error(abm.state());
+
+                // The error follows the tolerance, however the span is split
+                let bound = 5e3 * tol * r0.norm();
+                assert!(single < bound, "order {max_order}, tol {tol}: {single} m");
+                assert!(
+                    segmented < bound,
+                    "order {max_order}, tol {tol}: {segmented} m"
+                );
+                errors.push(single.max(segmented));
+            }
+            assert!(
+                errors[2] < 1e-3 * errors[0],
+                "order {max_order}: {errors:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_gauss_jackson_kepler() {
+        let (r0, v0) = leo();
+        let y0 = na::DVector::from_vec(vec![r0[0], r0[1], r0[2], v0[0], v0[1], v0[2]]);
+        let evaluations = Cell::new(0);
+        let f = |_t: f64, y: &na::DVector<f64>| {
+            evaluations.set(evaluations.get() + 1);
+            let r = y.rows(0, 3).into_owned();
+            let a = kepler(0.0, &r, &r);
+            na::DVector::from_vec(vec![y[3], y[4], y[5], a[0], a[1], a[2]])
+        };
+        let reference = dop853_integrate(f, 0.0, &y0, 86400.0, 60.0, 1e-14, None).unwrap();
+
+        let gj_evaluations = Cell::new(0);
+        let a = |t: f64, r: &na::DVector<f64>, v: &na::DVector<f64>| {
+            gj_evaluations.set(gj_evaluations.get() + 1);
+            kepler(t, r, v)
+        };
+        let (r, v) = gauss_jackson_integrate(a, 0.0, &r0, &v0, 86400.0, 30.0).unwrap();
+
+        assert_relative_eq!(r, reference.rows(0, 3).into_owned(), epsilon = 1e-2);
+        assert_relative_eq!(v, reference.rows(3, 3).into_owned(), epsilon = 1e-5);
+        // About two evaluations per step after start-up
+        assert!(gj_evaluations.get() < 2 * 2880 + 500);
+
+        // ABM on the same orbit, at a similar cost
+        let evaluations_before = evaluations.get();
+        let y = abm_integrate(f, 0.0, &y0, 86400.0, 30.0, 1e-12, 10, None).unwrap();
+        assert_relative_eq!(y, reference, epsilon = 1e-2);
+        assert!(evaluations.get() - evaluations_before < 2 * 2880 + 500);
+    }
+
+    #[test]
+    fn test_gauss_jackson_reinitialize_after_impulse() {
+        let (r0, v0) = leo();
+        let dv = na::DVector::from_vec(vec![0.0, 50.0, -20.0]);
+
+        let mut gj = GaussJackson::new(&kepler, 0.0, &r0, &v0, 30.0).unwrap();
+        for _ in 0..100 {
+            gj.step(&kepler).unwrap();
+        }
+        let (r_burn, v_burn) = (gj.position().clone(), gj.velocity() + &dv);
+        gj.reinitialize(&kepler, gj.t(), &r_burn, &v_burn);
+        for _ in 0..100 {
+            gj.step(&kepler).unwrap();
+        }
+        assert_relative_eq!(gj.t(), 6000.0);
+
+        let (r_ref, v_ref) =
+            gauss_jackson_integrate(kepler, 3000.0, &r_burn, &v_burn, 6000.0, 30.0).unwrap();
+        assert_relative_eq!(*gj.position(), r_ref, epsilon = 1e-6);
+        assert_relative_eq!(*gj.velocity(), v_ref, epsilon = 1e-9);
+
+        // Backward integration returns to the start
+        let (r, v) = gauss_jackson_integrate(kepler, 3000.0, &r_burn, &v_burn, 0.0, 30.0).unwrap();
+        let (r_back, _) = gauss_jackson_integrate(kepler, 0.0, &r, &v, 3000.0, 30.0).unwrap();
+        assert_relative_eq!(r_back, r_burn, epsilon = 1e-2);
+    }
+}
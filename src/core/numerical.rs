@@ -3,13 +3,19 @@
 //! This module provides core numerical methods essential for astrodynamics:
 //! - Newton-Raphson root finding (for Kepler's equation)
 //! - Runge-Kutta 4th order integration (fixed-step)
-//! - Dormand-Prince 5(4) adaptive integration
+//! - Dormand-Prince 5(4) and 8(5,3) adaptive integration with dense output
+//!   and ephemerides over the integration span
+//!
+//! Multistep methods (Gauss-Jackson, Adams-Bashforth-Moulton) live in
+//! [`crate::core::multistep`].
 //!
 //! These implementations are optimized for orbital mechanics applications
 //! and designed to minimize allocations for performance.
 
 use crate::core::error::{PoliastroError, PoliastroResult};
 use nalgebra as na;
+use numpy::{PyArray1, PyArray2, PyReadonlyArray1};
+use pyo3::prelude::*;
 
 /// Default tolerance for iterative methods
 pub const DEFAULT_TOL: f64 = 1e-12;
@@ -237,7 +243,7 @@ where
 /// These are the Butcher tableau coefficients for the DOPRI5 method,
 /// a 5th-order embedded Runge-Kutta method with 4th-order error estimation.
 #[allow(dead_code)]
-struct DopriCoefficients {
+pub(crate) struct DopriCoefficients {
     // Time step fractions (c_i)
     c: [f64; 7],
     // RK matrix coefficients (a_ij)
@@ -254,7 +260,7 @@ struct DopriCoefficients {
 /// an 8th-order embedded Runge-Kutta method with 5th-order error estimation.
 /// This method has 12 stages and provides higher accuracy than DOPRI5.
 #[allow(dead_code)]
-struct Dop853Coefficients {
+pub(crate) struct Dop853Coefficients {
     // Time step fractions (c_i) - 12 stages
     c: [f64; 12],
     // RK matrix coefficients (a_ij) - sparse matrix stored efficiently
@@ -263,10 +269,18 @@ struct Dop853Coefficients {
     b: [f64; 12],
     // 5th order solution weights for error estimation
     b_star: [f64; 12],
+    // Time step fractions of the 3 extra stages of the dense output
+    c_dense: [f64; 3],
+    // RK matrix rows of the extra stages, over stages 1-15 (stage 13 is the
+    // derivative at the end of the step)
+    a_dense: [[f64; 15]; 3],
+    // Dense output weights over the 16 stages, one row per coefficient of
+    // the 7th order continuous extension
+    d: [[f64; 16]; 4],
 }
 
 impl DopriCoefficients {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self {
             c: [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0],
             a: [
@@ -324,7 +338,7 @@ impl DopriCoefficients {
 }
 
 impl Dop853Coefficients {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self {
             // Time step fractions (c_i) for 12 stages
             c: [
@@ -387,8 +401,100 @@ impl Dop853Coefficients {
                 2.013_654_008_040_303_4e-1 - 8.192_320_648_511_571e-2,
                 4.471_061_572_777_259e-2 - (-2.235_530_786_388_629_4e-2),
             ],
+            c_dense: [0.1, 0.2, 7.0 / 9.0],
+            a_dense: [
+                [
+                    5.616_750_228_304_795e-2, 0.0, 0.0, 0.0, 0.0, 0.0,
+                    2.535_002_102_166_248e-1, -2.462_390_374_708_025e-1,
+                    -1.241_914_232_638_163_6e-1, 1.532_917_982_787_657e-1,
+                    8.201_052_295_634_69e-3, 7.567_897_660_545_7e-3, -8.298e-3, 0.0, 0.0,
+                ],
+                [
+                    3.183_464_816_350_214e-2, 0.0, 0.0, 0.0, 0.0,
+                    2.830_090_967_236_677_6e-2, 5.354_198_830_743_857e-2,
+                    -5.492_374_857_139_099e-2, 0.0, 0.0, -1.083_473_286_972_493_2e-4,
+                    3.825_710_908_356_584e-4, -3.404_650_086_874_045_6e-4,
+                    1.413_124_436_746_325e-1, 0.0,
+                ],
+                [
+                    -4.288_963_015_837_919e-1, 0.0, 0.0, 0.0, 0.0,
+                    -4.697_621_415_361_164, 7.683_421_196_062_599, 4.068_989_818_397_11,
+                    3.567_271_874_552_811e-1, 0.0, 0.0, 0.0,
+                    -1.399_024_165_159_014_6e-3, 2.947_514_789_152_772,
+                    -9.150_958_472_179_87,
+                ],
+            ],
+            d: [
+                [
+                    -8.428_938_276_109_013, 0.0, 0.0, 0.0, 0.0,
+                    5.667_149_535_193_777e-1, -3.068_949_945_949_891_7,
+                    2.384_667_656_512_07, 2.117_034_582_445_028,
+                    -8.713_915_837_779_73e-1, 2.240_437_430_260_788,
+                    6.315_787_787_694_688e-1, -8.899_033_645_133_331e-2,
+                    1.814_850_552_085_472_7e1, -9.194_632_392_478_356,
+                    -4.436_036_387_594_894,
+                ],
+                [
+                    1.042_750_864_257_913_4e1, 0.0, 0.0, 0.0, 0.0,
+                    2.422_834_917_752_581_7e2, 1.652_004_517_172_702_8e2,
+                    -3.745_467_547_226_902e2, -2.211_366_685_312_530_6e1,
+                    7.733_432_668_472_264, -3.067_408_473_108_939_8e1,
+                    -9.332_130_526_430_229, 1.569_723_812_177_084_5e1,
+                    -3.113_940_321_956_517_8e1, -9.352_924_358_844_478,
+                    3.581_684_148_639_408e1,
+                ],
+                [
+                    1.998_505_324_200_243_3e1, 0.0, 0.0, 0.0, 0.0,
+                    -3.870_373_087_493_518e2, -1.891_781_381_951_675_8e2,
+                    5.278_081_592_054_236e2, -1.157_390_253_995_963e1,
+                    6.881_232_694_696_3, -1.000_605_096_691_083_8,
+                    7.777_137_798_053_443e-1, -2.778_205_752_353_508,
+                    -6.019_669_523_126_412e1, 8.432_040_550_667_716e1,
+                    1.199_229_113_618_279e1,
+                ],
+                [
+                    -2.569_393_346_270_375e1, 0.0, 0.0, 0.0, 0.0,
+                    -1.541_897_486_902_364_3e2, -2.315_293_791_760_455e2,
+                    3.576_391_179_106_141e2, 9.340_532_418_362_432e1,
+                    -3.745_832_313_645_163e1, 1.040_996_495_089_623e2,
+                    2.984_029_342_666_05e1, -4.353_345_659_001_114e1,
+                    9.632_455_395_918_828e1, -3.917_726_167_561_544e1,
+                    -1.497_268_362_579_856_3e2,
+                ],
+            ],
+        }
+    }
+}
+
+/// The 7 stages (k_i) of a DOPRI5 step
+///
+/// The last stage is evaluated at the 5th order solution (FSAL), which the
+/// continuous extension in [`dopri5_dense_output`] relies on.
+fn dopri5_stages<F>(
+    f: &F,
+    t0: f64,
+    y0: &na::DVector<f64>,
+    h: f64,
+    coeff: &DopriCoefficients,
+) -> Vec<na::DVector<f64>>
+where
+    F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+{
+    let mut k = Vec::with_capacity(7);
+
+    // k1 = f(t0, y0)
+    k.push(f(t0, y0));
+
+    // Compute remaining stages
+    for i in 1..7 {
+        let mut y_temp = y0.clone();
+        // k holds the i stages computed so far
+        for (k_j, a_ij) in k.iter().zip(&coeff.a[i]) {
+            y_temp += k_j * (h * a_ij);
         }
+        k.push(f(t0 + coeff.c[i] * h, &y_temp));
     }
+    k
 }
 
 /// Dormand-Prince 5(4) adaptive step integrator (single step)
@@ -422,33 +528,43 @@ pub fn dopri5_step<F>(
 where
     F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
 {
-    let coeff = DopriCoefficients::new();
-
-    // Compute the 7 stages (k_i)
-    let mut k = Vec::with_capacity(7);
+    let trial = dopri5_trial(&f, t0, y0, h, tol, &DopriCoefficients::new());
+    (t0 + h, trial.y, trial.h_next, trial.error)
+}
 
-    // k1 = f(t0, y0)
-    k.push(f(t0, y0));
+/// An attempted step: the new state, the proposed next step size, the error
+/// estimate and the stages the step was built from
+pub(crate) struct TrialStep {
+    pub(crate) y: na::DVector<f64>,
+    pub(crate) h_next: f64,
+    pub(crate) error: f64,
+    pub(crate) k: Vec<na::DVector<f64>>,
+}
 
-    // Compute remaining stages
-    for i in 1..7 {
-        let mut y_temp = y0.clone();
-        for j in 0..i {
-            y_temp += &k[j] * (h * coeff.a[i][j]);
-        }
-        k.push(f(t0 + coeff.c[i] * h, &y_temp));
-    }
+/// Attempt a DOPRI5 step (see [`dopri5_step`]), keeping the stages
+pub(crate) fn dopri5_trial<F>(
+    f: &F,
+    t0: f64,
+    y0: &na::DVector<f64>,
+    h: f64,
+    tol: f64,
+    coeff: &DopriCoefficients,
+) -> TrialStep
+where
+    F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+{
+    let k = dopri5_stages(f, t0, y0, h, coeff);
 
     // Compute 5th order solution
     let mut y_new = y0.clone();
-    for i in 0..7 {
-        y_new += &k[i] * (h * coeff.b[i]);
+    for (k_i, b_i) in k.iter().zip(&coeff.b) {
+        y_new += k_i * (h * b_i);
     }
 
     // Compute 4th order solution for error estimation
     let mut y_star = y0.clone();
-    for i in 0..7 {
-        y_star += &k[i] * (h * coeff.b_star[i]);
+    for (k_i, b_star_i) in k.iter().zip(&coeff.b_star) {
+        y_star += k_i * (h * b_star_i);
     }
 
     // Error estimate (difference between 5th and 4th order solutions)
@@ -466,9 +582,12 @@ where
         max_factor
     };
 
-    let h_new = h * factor.clamp(min_factor, max_factor);
-
-    (t0 + h, y_new, h_new, error_norm)
+    TrialStep {
+        y: y_new,
+        h_next: h * factor.clamp(min_factor, max_factor),
+        error: error_norm,
+        k,
+    }
 }
 
 /// Integrate ODE from t0 to tf using Dormand-Prince 5(4) with adaptive stepping
@@ -548,6 +667,48 @@ where
     ))
 }
 
+/// The 12 stages (k_i) of a DOP853 step
+fn dop853_stages<F>(
+    f: &F,
+    t0: f64,
+    y0: &na::DVector<f64>,
+    h: f64,
+    coeff: &Dop853Coefficients,
+) -> Vec<na::DVector<f64>>
+where
+    F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+{
+    let mut k = Vec::with_capacity(12);
+
+    // k1 = f(t0, y0)
+    k.push(f(t0, y0));
+
+    // Compute remaining stages (i = 1 to 11)
+    for i in 1..12 {
+        let mut y_temp = y0.clone();
+        // k holds the i stages computed so far
+        for (k_j, a_ij) in k.iter().zip(&coeff.a[i]) {
+            y_temp += k_j * (h * a_ij);
+        }
+        k.push(f(t0 + coeff.c[i] * h, &y_temp));
+    }
+    k
+}
+
+/// 8th order DOP853 solution from the stages
+fn dop853_solution(
+    y0: &na::DVector<f64>,
+    h: f64,
+    k: &[na::DVector<f64>],
+    coeff: &Dop853Coefficients,
+) -> na::DVector<f64> {
+    let mut y_new = y0.clone();
+    for (k_i, b_i) in k.iter().zip(&coeff.b) {
+        y_new += k_i * (h * b_i);
+    }
+    y_new
+}
+
 /// Dormand-Prince 8(5,3) adaptive step integrator (single step)
 ///
 /// Performs one adaptive step of DOP853 integration with error control.
@@ -580,33 +741,31 @@ pub fn dop853_step<F>(
 where
     F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
 {
-    let coeff = Dop853Coefficients::new();
-
-    // Compute the 12 stages (k_i)
-    let mut k = Vec::with_capacity(12);
-
-    // k1 = f(t0, y0)
-    k.push(f(t0, y0));
+    let trial = dop853_trial(&f, t0, y0, h, tol, &Dop853Coefficients::new());
+    (t0 + h, trial.y, trial.h_next, trial.error)
+}
 
-    // Compute remaining stages (i = 1 to 11)
-    for i in 1..12 {
-        let mut y_temp = y0.clone();
-        for j in 0..i {
-            y_temp += &k[j] * (h * coeff.a[i][j]);
-        }
-        k.push(f(t0 + coeff.c[i] * h, &y_temp));
-    }
+/// Attempt a DOP853 step (see [`dop853_step`]), keeping the stages
+pub(crate) fn dop853_trial<F>(
+    f: &F,
+    t0: f64,
+    y0: &na::DVector<f64>,
+    h: f64,
+    tol: f64,
+    coeff: &Dop853Coefficients,
+) -> TrialStep
+where
+    F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+{
+    let k = dop853_stages(f, t0, y0, h, coeff);
 
     // Compute 8th order solution
-    let mut y_new = y0.clone();
-    for i in 0..12 {
-        y_new += &k[i] * (h * coeff.b[i]);
-    }
+    let y_new = dop853_solution(y0, h, &k, coeff);
 
     // Compute 5th order solution for error estimation
     let mut y_star = y0.clone();
-    for i in 0..12 {
-        y_star += &k[i] * (h * coeff.b_star[i]);
+    for (k_i, b_star_i) in k.iter().zip(&coeff.b_star) {
+        y_star += k_i * (h * b_star_i);
     }
 
     // Error estimate (difference between 8th and 5th order solutions)
@@ -626,9 +785,12 @@ where
         max_factor
     };
 
-    let h_new = h * factor.clamp(min_factor, max_factor);
-
-    (t0 + h, y_new, h_new, error_norm)
+    TrialStep {
+        y: y_new,
+        h_next: h * factor.clamp(min_factor, max_factor),
+        error: error_norm,
+        k,
+    }
 }
 
 /// Integrate ODE from t0 to tf using Dormand-Prince 8(5,3) with adaptive stepping
@@ -718,6 +880,519 @@ where
     ))
 }
 
+/// Continuous extension (dense output) of one integration step
+///
+/// Interpolates the solution anywhere in a step from `t_start` to `t_end`
+/// without further evaluations of the right-hand side, e.g. to locate
+/// events or to output the solution on a fine time grid.
+///
+/// Built by [`dopri5_dense_output`] or [`dop853_dense_output`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DenseOutput {
+    t0: f64,
+    h: f64,
+    interpolant: Interpolant,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Interpolant {
+    /// Dormand-Prince 4th order continuous extension (Hairer's `CONTD5`):
+    /// y(θ) = r₁ + θ(r₂ + (1−θ)(r₃ + θ(r₄ + (1−θ)r₅)))
+    Dopri5([na::DVector<f64>; 5]),
+    /// Dormand-Prince 7th order continuous extension (Hairer's `CONTD8`):
+    /// y(θ) = r₁ + θ(r₂ + (1−θ)(r₃ + θ(r₄ + (1−θ)p))) with
+    /// p = r₅ + θ(r₆ + (1−θ)(r₇ + θr₈))
+    Dop853([na::DVector<f64>; 8]),
+}
+
+impl DenseOutput {
+    /// Start time of the step
+    pub fn t_start(&self) -> f64 {
+        self.t0
+    }
+
+    /// End time of the step
+    pub fn t_end(&self) -> f64 {
+        self.t0 + self.h
+    }
+
+    /// Interpolated solution at time `t` (within the step)
+    pub fn evaluate(&self, t: f64) -> na::DVector<f64> {
+        let theta = if self.h == 0.0 {
+            0.0
+        } else {
+            (t - self.t0) / self.h
+        };
+
+        match &self.interpolant {
+            Interpolant::Dopri5([r1, r2, r3, r4, r5]) => {
+                let theta1 = 1.0 - theta;
+                r1 + (r2 + (r3 + (r4 + r5 * theta1) * theta) * theta1) * theta
+            }
+            Interpolant::Dop853([r1, r2, r3, r4, r5, r6, r7, r8]) => {
+                let theta1 = 1.0 - theta;
+                let p = r5 + (r6 + (r7 + r8 * theta) * theta1) * theta;
+                r1 + (r2 + (r3 + (r4 + p * theta1) * theta) * theta1) * theta
+            }
+        }
+    }
+}
+
+/// Dormand-Prince continuous extension of a DOPRI5 step from its stages
+pub(crate) fn dopri5_interpolant(
+    t0: f64,
+    y0: &na::DVector<f64>,
+    h: f64,
+    y1: &na::DVector<f64>,
+    k: &[na::DVector<f64>],
+) -> DenseOutput {
+    // Shampine's dense output coefficients
+    const D: [f64; 7] = [
+        -12_715_105_075.0 / 11_282_082_432.0,
+        0.0,
+        87_487_479_700.0 / 32_700_410_799.0,
+        -10_690_763_975.0 / 1_880_347_072.0,
+        701_980_252_875.0 / 199_316_789_632.0,
+        -1_453_857_185.0 / 822_651_844.0,
+        69_997_945.0 / 29_380_423.0,
+    ];
+
+    let mut r5 = na::DVector::zeros(y0.len());
+    for (k_i, d_i) in k.iter().zip(D) {
+        r5 += k_i * (h * d_i);
+    }
+
+    let r2 = y1 - y0;
+    let r3 = &k[0] * h - &r2;
+    let r4 = &r2 - &k[6] * h - &r3;
+
+    DenseOutput {
+        t0,
+        h,
+        interpolant: Interpolant::Dopri5([y0.clone(), r2, r3, r4, r5]),
+    }
+}
+
+/// Dormand-Prince continuous extension of a DOP853 step from its 12 stages
+///
+/// Evaluates the derivative at the end of the step and the 3 extra stages
+/// the 7th order interpolant needs; the other stages are those of the step.
+pub(crate) fn dop853_interpolant<F>(
+    f: &F,
+    t0: f64,
+    y0: &na::DVector<f64>,
+    h: f64,
+    y1: &na::DVector<f64>,
+    k: &[na::DVector<f64>],
+    coeff: &Dop853Coefficients,
+) -> DenseOutput
+where
+    F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+{
+    let mut k = k.to_vec();
+    k.push(f(t0 + h, y1));
+    for (i, (c, a)) in coeff.c_dense.iter().zip(&coeff.a_dense).enumerate() {
+        let mut y_temp = y0.clone();
+        for (k_j, a_j) in k.iter().zip(&a[..12 + i + 1]) {
+            if *a_j != 0.0 {
+                y_temp += k_j * (h * a_j);
+            }
+        }
+        k.push(f(t0 + c * h, &y_temp));
+    }
+
+    let r2 = y1 - y0;
+    let r3 = &k[0] * h - &r2;
+    let r4 = &r2 - &k[12] * h - &r3;
+    let [r5, r6, r7, r8] = coeff.d.map(|d| {
+        let mut r = na::DVector::zeros(y0.len());
+        for (k_i, d_i) in k.iter().zip(d) {
+            if d_i != 0.0 {
+                r += k_i * (h * d_i);
+            }
+        }
+        r
+    });
+
+    DenseOutput {
+        t0,
+        h,
+        interpolant: Interpolant::Dop853([y0.clone(), r2, r3, r4, r5, r6, r7, r8]),
+    }
+}
+
+/// Dense output of a DOPRI5 step of size `h` from (`t0`, `y0`)
+///
+/// Uses the 4th order continuous extension of Dormand & Prince, at the cost
+/// of the step's 7 stages.
+///
+/// # References
+/// - Hairer, Nørsett & Wanner, "Solving Ordinary Differential Equations I",
+///   Section II.6
+pub fn dopri5_dense_output<F>(f: F, t0: f64, y0: &na::DVector<f64>, h: f64) -> DenseOutput
+where
+    F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+{
+    let coeff = DopriCoefficients::new();
+    let k = dopri5_stages(&f, t0, y0, h, &coeff);
+
+    let mut y1 = y0.clone();
+    for (k_i, b_i) in k.iter().zip(coeff.b) {
+        y1 += k_i * (h * b_i);
+    }
+
+    dopri5_interpolant(t0, y0, h, &y1, &k)
+}
+
+/// Dense output of a DOP853 step of size `h` from (`t0`, `y0`)
+///
+/// Uses the 7th order continuous extension of Dormand & Prince, at the cost
+/// of the step's 12 stages, the derivative at the end of the step and 3
+/// extra stages.
+///
+/// # References
+/// - Hairer, Nørsett & Wanner, "Solving Ordinary Differential Equations I",
+///   Section II.6 (code `DOP853`, function `CONTD8`)
+pub fn dop853_dense_output<F>(f: F, t0: f64, y0: &na::DVector<f64>, h: f64) -> DenseOutput
+where
+    F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+{
+    let coeff = Dop853Coefficients::new();
+    let k = dop853_stages(&f, t0, y0, h, &coeff);
+    let y1 = dop853_solution(y0, h, &k, &coeff);
+    dop853_interpolant(&f, t0, y0, h, &y1, &k, &coeff)
+}
+
+/// Solution of an ODE over a time span, pieced together from the dense
+/// output of every accepted integration step
+///
+/// Built by [`dopri5_ephemeris`] or [`dop853_ephemeris`], it can be queried at
+/// any time inside the span without further evaluations of the right-hand
+/// side. The error at any time is that of the continuous extension of the
+/// step containing it, which is of the order of the step's local error (4th
+/// order for DOPRI5, 7th order for DOP853) and so is kept in check by the
+/// integrator tolerance.
+///
+/// # Example
+/// ```ignore
+/// let f = |_t: f64, y: &na::DVector<f64>| na::DVector::from_vec(vec![y[1], -y[0]]);
+/// let y0 = na::DVector::from_vec(vec![1.0, 0.0]);
+/// let ephemeris = dop853_ephemeris(f, 0.0, &y0, 10.0, 0.1, 1e-12, None)?;
+/// let y = ephemeris.evaluate(3.7)?; // ≈ [cos 3.7, -sin 3.7]
+/// ```
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ephemeris {
+    segments: Vec<DenseOutput>,
+}
+
+impl Ephemeris {
+    /// Create from the dense output of consecutive steps
+    ///
+    /// # Errors
+    /// Returns an error if there are no steps
+    pub fn from_segments(segments: Vec<DenseOutput>) -> PoliastroResult<Self> {
+        if segments.is_empty() {
+            return Err(PoliastroError::invalid_state(
+                "An ephemeris needs at least one step",
+            ));
+        }
+        Ok(Self { segments })
+    }
+
+    /// Start time of the span
+    pub fn t_start(&self) -> f64 {
+        self.segments[0].t_start()
+    }
+
+    /// End time of the span (before the start for backward integration)
+    pub fn t_end(&self) -> f64 {
+        self.segments[self.segments.len() - 1].t_end()
+    }
+
+    /// The dense output of each step, in integration order
+    pub fn segments(&self) -> &[DenseOutput] {
+        &self.segments
+    }
+
+    /// Times of the accepted steps, from the start to the end of the span
+    pub fn step_times(&self) -> Vec<f64> {
+        std::iter::once(self.t_start())
+            .chain(self.segments.iter().map(DenseOutput::t_end))
+            .collect()
+    }
+
+    /// Number of components of the state
+    pub fn dimension(&self) -> usize {
+        self.segments[0].evaluate(self.t_start()).len()
+    }
+
+    /// Whether `t` is inside the span
+    pub fn contains(&self, t: f64) -> bool {
+        let (t_min, t_max) = self.bounds();
+        t >= t_min && t <= t_max
+    }
+
+    /// Interpolated state at time `t`
+    ///
+    /// # Errors
+    /// Returns an out of range error if `t` is outside the span
+    pub fn evaluate(&self, t: f64) -> PoliastroResult<na::DVector<f64>> {
+        if !self.contains(t) {
+            let (t_min, t_max) = self.bounds();
+            return Err(PoliastroError::out_of_range("t", t, t_min, t_max));
+        }
+
+        let forward = self.t_end() >= self.t_start();
+        let index = self.segments.partition_point(|segment| {
+            if forward {
+                segment.t_end() < t
+            } else {
+                segment.t_end() > t
+            }
+        });
+        Ok(self.segments[index.min(self.segments.len() - 1)].evaluate(t))
+    }
+
+    /// Interpolated states at each of `times`
+    ///
+    /// # Errors
+    /// Returns an out of range error if any time is outside the span
+    pub fn evaluate_many(&self, times: &[f64]) -> PoliastroResult<Vec<na::DVector<f64>>> {
+        times.iter().map(|&t| self.evaluate(t)).collect()
+    }
+
+    fn bounds(&self) -> (f64, f64) {
+        let (t_start, t_end) = (self.t_start(), self.t_end());
+        (t_start.min(t_end), t_start.max(t_end))
+    }
+}
+
+#[pymethods]
+impl Ephemeris {
+    /// Start time of the span
+    #[getter]
+    fn get_t_start(&self) -> f64 {
+        self.t_start()
+    }
+
+    /// End time of the span
+    #[getter]
+    fn get_t_end(&self) -> f64 {
+        self.t_end()
+    }
+
+    /// Number of components of the state
+    #[getter]
+    fn get_dimension(&self) -> usize {
+        self.dimension()
+    }
+
+    /// Times of the accepted steps
+    #[pyo3(name = "step_times")]
+    fn py_step_times<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_vec_bound(py, self.step_times())
+    }
+
+    /// Interpolated state at time `t`
+    ///
+    /// Raises ValueError if `t` is outside the span.
+    #[pyo3(name = "evaluate")]
+    fn py_evaluate<'py>(&self, py: Python<'py>, t: f64) -> PyResult<Bound<'py, PyArray1<f64>>> {
+        let y = self.evaluate(t)?;
+        Ok(PyArray1::from_slice_bound(py, y.as_slice()))
+    }
+
+    /// Interpolated states at each of `times`, as an array of shape
+    /// (len(times), dimension)
+    ///
+    /// Raises ValueError if any time is outside the span.
+    #[pyo3(name = "evaluate_many")]
+    fn py_evaluate_many<'py>(
+        &self,
+        py: Python<'py>,
+        times: PyReadonlyArray1<f64>,
+    ) -> PyResult<Bound<'py, PyArray2<f64>>> {
+        let times = times.as_array();
+        let mut states = ndarray::Array2::zeros((times.len(), self.dimension()));
+        for (mut row, &t) in states.rows_mut().into_iter().zip(times.iter()) {
+            let y = self.evaluate(t)?;
+            row.assign(&ndarray::ArrayView1::from(y.as_slice()));
+        }
+        Ok(PyArray2::from_owned_array_bound(py, states))
+    }
+
+    fn __len__(&self) -> usize {
+        self.segments.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Ephemeris(t_start={}, t_end={}, steps={})",
+            self.t_start(),
+            self.t_end(),
+            self.segments.len()
+        )
+    }
+}
+
+/// Adaptive integration from t0 to tf, keeping the dense output of each
+/// accepted step
+#[allow(clippy::too_many_arguments)]
+fn integrate_ephemeris<S, D>(
+    operation: &str,
+    trial: S,
+    dense: D,
+    t0: f64,
+    y0: &na::DVector<f64>,
+    tf: f64,
+    h0: f64,
+    tol: f64,
+    max_steps: Option<usize>,
+) -> PoliastroResult<Ephemeris>
+where
+    S: Fn(f64, &na::DVector<f64>, f64) -> TrialStep,
+    D: Fn(f64, &na::DVector<f64>, f64, &TrialStep) -> DenseOutput,
+{
+    if tf == t0 {
+        return Err(PoliastroError::invalid_parameter(
+            "tf",
+            tf,
+            "must differ from t0 for an ephemeris",
+        ));
+    }
+
+    let max_steps = max_steps.unwrap_or(100000);
+    let mut t = t0;
+    let mut y = y0.clone();
+    let mut h = h0.abs() * (tf - t0).signum();
+    let mut segments = Vec::new();
+    let min_step_size = 1e-14;
+
+    for _step in 0..max_steps {
+        // Check if we've reached the final time
+        if (t - tf).abs() < min_step_size {
+            return Ephemeris::from_segments(segments);
+        }
+
+        // Don't overshoot the final time
+        if (tf - t0).signum() * (t + h - tf) > 0.0 {
+            h = tf - t;
+        }
+
+        if h.abs() < min_step_size {
+            if (t - tf).abs() < 1e-10 && !segments.is_empty() {
+                return Ephemeris::from_segments(segments);
+            }
+            return Err(PoliastroError::NumericalInstability {
+                operation: operation.to_string(),
+                details: format!("Step size became too small at t = {t} (h = {h})"),
+            });
+        }
+
+        let step = trial(t, &y, h);
+        if step.error <= tol {
+            segments.push(dense(t, &y, h, &step));
+            t += h;
+            y = step.y;
+        }
+        h = step.h_next;
+    }
+
+    Err(PoliastroError::convergence_failure(
+        operation, max_steps, tol,
+    ))
+}
+
+/// Integrate ODE from t0 to tf using Dormand-Prince 5(4), keeping the
+/// solution over the whole span
+///
+/// Takes the same steps as [`dopri5_integrate`]; the 4th order continuous
+/// extension of each step reuses its stages, so the ephemeris costs no
+/// extra evaluations.
+///
+/// # Arguments
+/// * `f` - Right-hand side function dy/dt = f(t, y)
+/// * `t0` - Initial time
+/// * `y0` - Initial state
+/// * `tf` - Final time (may be before t0)
+/// * `h0` - Initial step size
+/// * `tol` - Error tolerance
+/// * `max_steps` - Maximum number of steps (prevents infinite loops)
+///
+/// # Errors
+/// Returns error if `tf == t0`, or if integration fails or exceeds max_steps
+pub fn dopri5_ephemeris<F>(
+    f: F,
+    t0: f64,
+    y0: &na::DVector<f64>,
+    tf: f64,
+    h0: f64,
+    tol: f64,
+    max_steps: Option<usize>,
+) -> PoliastroResult<Ephemeris>
+where
+    F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+{
+    let coeff = DopriCoefficients::new();
+    integrate_ephemeris(
+        "DOPRI5 integration",
+        |t, y, h| dopri5_trial(&f, t, y, h, tol, &coeff),
+        |t, y, h, step| dopri5_interpolant(t, y, h, &step.y, &step.k),
+        t0,
+        y0,
+        tf,
+        h0,
+        tol,
+        max_steps,
+    )
+}
+
+/// Integrate ODE from t0 to tf using Dormand-Prince 8(5,3), keeping the
+/// solution over the whole span
+///
+/// Takes the same steps as [`dop853_integrate`], with the 7th order
+/// continuous extension of [`dop853_dense_output`] for each step; it reuses
+/// the stages of the step, for 4 evaluations per step on top of the
+/// integration.
+///
+/// # Arguments
+/// * `f` - Right-hand side function dy/dt = f(t, y)
+/// * `t0` - Initial time
+/// * `y0` - Initial state
+/// * `tf` - Final time (may be before t0)
+/// * `h0` - Initial step size
+/// * `tol` - Error tolerance
+/// * `max_steps` - Maximum number of steps (prevents infinite loops)
+///
+/// # Errors
+/// Returns error if `tf == t0`, or if integration fails or exceeds max_steps
+pub fn dop853_ephemeris<F>(
+    f: F,
+    t0: f64,
+    y0: &na::DVector<f64>,
+    tf: f64,
+    h0: f64,
+    tol: f64,
+    max_steps: Option<usize>,
+) -> PoliastroResult<Ephemeris>
+where
+    F: Fn(f64, &na::DVector<f64>) -> na::DVector<f64>,
+{
+    let coeff = Dop853Coefficients::new();
+    integrate_ephemeris(
+        "DOP853 integration",
+        |t, y, h| dop853_trial(&f, t, y, h, tol, &coeff),
+        |t, y, h, step| dop853_interpolant(&f, t, y, h, &step.y, &step.k, &coeff),
+        t0,
+        y0,
+        tf,
+        h0,
+        tol,
+        max_steps,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -986,4 +1661,132 @@ mod tests {
         assert_relative_eq!(y_final[1], exact2, epsilon = 1e-9);
         assert_relative_eq!(y_final[2], exact3, epsilon = 1e-9);
     }
+
+    #[test]
+    fn test_dense_output() {
+        // Harmonic oscillator: y = [sin t, cos t]
+        let f = |_t: f64, y: &na::DVector<f64>| na::DVector::from_vec(vec![y[1], -y[0]]);
+        let (t0, h): (f64, f64) = (0.3, 0.2);
+        let y0 = na::DVector::from_vec(vec![t0.sin(), t0.cos()]);
+
+        let dopri5 = dopri5_dense_output(f, t0, &y0, h);
+        let dop853 = dop853_dense_output(f, t0, &y0, h);
+        assert_eq!(dopri5.t_start(), t0);
+        assert_relative_eq!(dop853.t_end(), t0 + h);
+
+        // Endpoints reproduce the step
+        let (_, y1, _, _) = dopri5_step(f, t0, &y0, h, 1e-10);
+        assert_relative_eq!(dopri5.evaluate(t0 + h), y1, epsilon = 1e-15);
+        let (_, y1, _, _) = dop853_step(f, t0, &y0, h, 1e-10);
+        assert_relative_eq!(dop853.evaluate(t0 + h), y1, epsilon = 1e-15);
+        assert_relative_eq!(dop853.evaluate(t0), y0, epsilon = 1e-15);
+
+        for theta in [0.1, 0.37, 0.5, 0.81] {
+            let t = t0 + theta * h;
+            let exact = na::DVector::from_vec(vec![t.sin(), t.cos()]);
+            assert_relative_eq!(dopri5.evaluate(t), exact, epsilon = 1e-7);
+            assert_relative_eq!(dop853.evaluate(t), exact, epsilon = 1e-11);
+        }
+    }
+
+    #[test]
+    fn test_dop853_dense_output_degree() {
+        // y = t⁷ is integrated exactly and lies in the 7th degree interpolant
+        let f = |t: f64, _y: &na::DVector<f64>| na::DVector::from_vec(vec![7.0 * t.powi(6)]);
+        let y0 = na::DVector::from_vec(vec![0.0]);
+        let dense = dop853_dense_output(f, 0.0, &y0, 1.0);
+
+        for t in [0.1, 0.37, 0.5, 0.9] {
+            assert_relative_eq!(dense.evaluate(t)[0], t.powi(7), epsilon = 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_dop853_dense_output_order_two_body() {
+        // Kepler orbit with μ = a = 1 and e = 0.3, solved analytically
+        let e: f64 = 0.3;
+        let kepler = |t: f64| {
+            let mut ecc = t;
+            for _ in 0..50 {
+                ecc -= (ecc - e * ecc.sin() - t) / (1.0 - e * ecc.cos());
+            }
+            let (sin_e, cos_e) = ecc.sin_cos();
+            let b = (1.0 - e * e).sqrt();
+            let rate = 1.0 / (1.0 - e * cos_e);
+            na::DVector::from_vec(vec![cos_e - e, b * sin_e, -sin_e * rate, b * cos_e * rate])
+        };
+        let f = |_t: f64, y: &na::DVector<f64>| {
+            let r3 = (y[0] * y[0] + y[1] * y[1]).powf(1.5);
+            na::DVector::from_vec(vec![y[2], y[3], -y[0] / r3, -y[1] / r3])
+        };
+
+        // Largest interpolation error over one step from periapsis
+        let max_error = |h: f64| {
+            let dense = dop853_dense_output(f, 0.0, &kepler(0.0), h);
+            [0.13, 0.31, 0.5, 0.72, 0.94]
+                .iter()
+                .map(|theta| (dense.evaluate(theta * h) - kepler(theta * h)).norm())
+                .fold(0.0, f64::max)
+        };
+
+        // Local error of a 7th order continuous extension: O(h⁸)
+        let (coarse, fine) = (max_error(0.4), max_error(0.2));
+        let order = (coarse / fine).log2();
+        assert!(order > 7.5 && order < 9.5, "observed order {order}");
+        assert!(fine < 1e-7);
+    }
+
+    #[test]
+    fn test_ephemeris_matches_integration() {
+        // Harmonic oscillator: y = [sin t, cos t]
+        let f = |_t: f64, y: &na::DVector<f64>| na::DVector::from_vec(vec![y[1], -y[0]]);
+        let y0 = na::DVector::from_vec(vec![0.0, 1.0]);
+        let tf = 20.0;
+
+        let dopri5 = dopri5_ephemeris(f, 0.0, &y0, tf, 0.1, 1e-10, None).unwrap();
+        let dop853 = dop853_ephemeris(f, 0.0, &y0, tf, 0.1, 1e-12, None).unwrap();
+        assert_eq!(dop853.t_start(), 0.0);
+        assert_relative_eq!(dop853.t_end(), tf, epsilon = 1e-12);
+        assert_eq!(dop853.dimension(), 2);
+        assert_eq!(dop853.step_times().len(), dop853.segments().len() + 1);
+
+        // Same steps as the plain integrators
+        let y_end = dopri5_integrate(f, 0.0, &y0, tf, 0.1, 1e-10, None).unwrap();
+        assert_relative_eq!(dopri5.evaluate(tf).unwrap(), y_end, epsilon = 1e-14);
+        let y_end = dop853_integrate(f, 0.0, &y0, tf, 0.1, 1e-12, None).unwrap();
+        assert_relative_eq!(dop853.evaluate(tf).unwrap(), y_end, epsilon = 1e-14);
+
+        // Bounded error anywhere in the span, without restarting
+        let times: Vec<f64> = (0..=400).map(|i| 0.05 * i as f64 + 0.0123).collect();
+        let times = &times[..times.len() - 1];
+        let dopri5_states = dopri5.evaluate_many(times).unwrap();
+        let dop853_states = dop853.evaluate_many(times).unwrap();
+        for ((&t, a), b) in times.iter().zip(&dopri5_states).zip(&dop853_states) {
+            let exact = na::DVector::from_vec(vec![t.sin(), t.cos()]);
+            assert_relative_eq!(*a, exact, epsilon = 1e-7);
+            assert_relative_eq!(*b, exact, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_ephemeris_backward_and_out_of_range() {
+        // dy/dt = -y backward from t = 2
+        let f = |_t: f64, y: &na::DVector<f64>| -y.clone();
+        let y0 = na::DVector::from_vec(vec![1.0]);
+        let ephemeris = dop853_ephemeris(f, 2.0, &y0, -1.0, 0.1, 1e-12, None).unwrap();
+
+        assert_relative_eq!(ephemeris.t_end(), -1.0, epsilon = 1e-12);
+        assert!(ephemeris.contains(0.5) && !ephemeris.contains(2.5));
+        for t in [1.9, 0.5, -0.73] {
+            let y = ephemeris.evaluate(t).unwrap();
+            assert_relative_eq!(y[0], (2.0 - t).exp(), max_relative = 1e-10);
+        }
+
+        assert!(matches!(
+            ephemeris.evaluate(-1.5),
+            Err(PoliastroError::OutOfRange { .. })
+        ));
+        assert!(dopri5_ephemeris(f, 1.0, &y0, 1.0, 0.1, 1e-10, None).is_err());
+        assert!(Ephemeris::from_segments(Vec::new()).is_err());
+    }
 }
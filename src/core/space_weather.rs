@@ -0,0 +1,723 @@
+//! Space weather indices (solar flux and geomagnetic activity)
+//!
+//! This module loads the daily solar and geomagnetic indices that drive
+//! thermospheric density models:
+//! - **F10.7**: 10.7 cm solar radio flux (sfu), daily observed value and its
+//!   81-day centred mean
+//! - **Ap / Kp**: planetary geomagnetic indices, eight 3-hourly values per day
+//!
+//! # Data Sources
+//!
+//! Files are read from local paths, no network access is required:
+//! - **CelesTrak `SW-All.csv`** (columns identified by the header row)
+//! - **CelesTrak legacy `sw19571001.txt`** (fixed-width `SW-All.txt` format
+//!   with `BEGIN OBSERVED` / `DAILY_PREDICTED` / `MONTHLY_PREDICTED`
+//!   sections)
+//!
+//! Predicted rows are loaded too, so a current file covers about 20 years
+//! ahead for F10.7. Monthly predictions carry no Ap; missing values use the
+//! fallback.
+//!
+//! # Global Table and Fallback
+//!
+//! A table installed with [`install_space_weather`] is used by [`f107_at`],
+//! [`f107_centred81_at`], [`ap_at`] and [`SolarActivity::at`], and through
+//! them by the atmosphere models. Epochs not covered by the table, and
+//! fields missing from it, use the constant fallback activity set with
+//! [`set_space_weather_fallback`] (F10.7 = 150 sfu, Ap = 15 by default).
+//!
+//! # References
+//! - CelesTrak space weather data: <https://celestrak.org/SpaceData/>
+//! - Vallado, D. A. & Kelso, T. S. (2005), "Using EOP and Space Weather Data
+//!   for Satellite Operations", AAS 05-406
+
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use thiserror::Error;
+
+use crate::core::error::PoliastroError;
+use crate::core::time::Epoch;
+
+/// Errors from loading space weather data
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum SpaceWeatherError {
+    #[error("Failed to read space weather file: {0}")]
+    Io(String),
+
+    #[error("Failed to parse space weather data at line {line}: {reason}")]
+    Parse { line: usize, reason: String },
+
+    #[error("Space weather data contains no records")]
+    Empty,
+}
+
+impl From<SpaceWeatherError> for PoliastroError {
+    fn from(err: SpaceWeatherError) -> Self {
+        PoliastroError::ComputationError {
+            message: err.to_string(),
+        }
+    }
+}
+
+/// Space weather file formats understood by [`SpaceWeatherTable::from_file`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpaceWeatherFormat {
+    /// CelesTrak `SW-All.csv`
+    CelestrakCsv,
+    /// CelesTrak legacy fixed-width format (`sw19571001.txt`, `SW-All.txt`)
+    Legacy,
+}
+
+/// Solar and geomagnetic activity driving a thermospheric model
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarActivity {
+    /// Daily 10.7 cm solar flux of the previous day (sfu)
+    pub f107: f64,
+    /// 81-day centred mean of the 10.7 cm solar flux (sfu)
+    pub f107_avg: f64,
+    /// Planetary geomagnetic index Ap
+    pub ap: f64,
+}
+
+/// Default fallback activity: F10.7 = 150 sfu, Ap = 15
+const DEFAULT_ACTIVITY: SolarActivity = SolarActivity {
+    f107: 150.0,
+    f107_avg: 150.0,
+    ap: 15.0,
+};
+
+impl SolarActivity {
+    /// Create a set of activity indices
+    pub fn new(f107: f64, f107_avg: f64, ap: f64) -> Self {
+        Self { f107, f107_avg, ap }
+    }
+
+    /// Activity at `epoch` from the installed space weather table
+    ///
+    /// `f107` is the observed flux of the previous UTC day (the daily flux
+    /// the Jacchia and MSIS models take, also given by [`f107_at`]),
+    /// `f107_avg` the 81-day centred mean of the current day and `ap` the
+    /// 3-hourly index of the interval containing `epoch`. Each value falls back to
+    /// [`space_weather_fallback`] where the table has no data.
+    pub fn at(epoch: &Epoch) -> Self {
+        activity_from(
+            installed_space_weather().as_deref(),
+            &space_weather_fallback(),
+            epoch.to_mjd_utc(),
+        )
+    }
+
+    /// Planetary index Kp equivalent to `ap` (interpolated on the standard
+    /// Kp–ap conversion table)
+    pub fn kp(&self) -> f64 {
+        const AP: [f64; 28] = [
+            0.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 9.0, 12.0, 15.0, 18.0, 22.0, 27.0, 32.0, 39.0, 48.0,
+            56.0, 67.0, 80.0, 94.0, 111.0, 132.0, 154.0, 179.0, 207.0, 236.0, 300.0, 400.0,
+        ];
+        let ap = self.ap.clamp(0.0, 400.0);
+        let i = AP
+            .iter()
+            .rposition(|&a| a <= ap)
+            .unwrap_or(0)
+            .min(AP.len() - 2);
+        (i as f64 + (ap - AP[i]) / (AP[i + 1] - AP[i])) / 3.0
+    }
+}
+
+impl Default for SolarActivity {
+    /// Moderate activity: F10.7 = 150 sfu, Ap = 15
+    fn default() -> Self {
+        DEFAULT_ACTIVITY
+    }
+}
+
+/// Space weather indices of one UTC day
+///
+/// Values missing from the file (e.g. Ap in monthly predictions) are NaN.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpaceWeatherRecord {
+    /// Modified Julian Date (UTC) of 00:00 of the day
+    pub mjd: f64,
+    /// 3-hourly Kp indices (0–9, thirds as fractions: 1+ = 1.333)
+    pub kp: [f64; 8],
+    /// 3-hourly ap indices
+    pub ap: [f64; 8],
+    /// Daily Ap index
+    pub ap_daily: f64,
+    /// Observed 10.7 cm solar flux (sfu)
+    pub f107_obs: f64,
+    /// 10.7 cm solar flux adjusted to 1 AU (sfu)
+    pub f107_adj: f64,
+    /// 81-day centred mean of the observed flux (sfu)
+    pub f107_obs_centred81: f64,
+}
+
+/// Table of daily space weather records
+///
+/// # Example
+/// ```ignore
+/// use astrora::core::space_weather::{install_space_weather, SpaceWeatherTable};
+///
+/// install_space_weather(SpaceWeatherTable::from_csv_file("SW-All.csv")?);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpaceWeatherTable {
+    records: Vec<SpaceWeatherRecord>,
+}
+
+impl SpaceWeatherTable {
+    /// Build a table from records (sorted by MJD, duplicates removed)
+    pub fn from_records(mut records: Vec<SpaceWeatherRecord>) -> Result<Self, SpaceWeatherError> {
+        if records.is_empty() {
+            return Err(SpaceWeatherError::Empty);
+        }
+
+        records.sort_by(|a, b| a.mjd.total_cmp(&b.mjd));
+        records.dedup_by(|a, b| a.mjd == b.mjd);
+        Ok(Self { records })
+    }
+
+    /// Parse the contents of a CelesTrak `SW-All.csv` file
+    ///
+    /// Columns are located by name from the header row (`DATE`, `KP1`–`KP8`,
+    /// `AP1`–`AP8`, `AP_AVG`, `F10.7_OBS`, `F10.7_ADJ`,
+    /// `F10.7_OBS_CENTER81`).
+    pub fn parse_celestrak_csv(contents: &str) -> Result<Self, SpaceWeatherError> {
+        let mut lines = contents.lines().enumerate();
+        let header: Vec<&str> = loop {
+            match lines.next() {
+                Some((_, line)) if line.starts_with("DATE") => {
+                    break line.split(',').map(str::trim).collect()
+                }
+                Some(_) => continue,
+                None => return Err(SpaceWeatherError::Empty),
+            }
+        };
+
+        let find = |name: &str| {
+            header
+                .iter()
+                .position(|&h| h == name)
+                .ok_or_else(|| SpaceWeatherError::Parse {
+                    line: 1,
+                    reason: format!("missing column '{}'", name),
+                })
+        };
+        let kp_columns = (1..=8)
+            .map(|i| find(&format!("KP{}", i)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let ap_columns = (1..=8)
+            .map(|i| find(&format!("AP{}", i)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let ap_avg = find("AP_AVG")?;
+        let f107_obs = find("F10.7_OBS")?;
+        let f107_adj = find("F10.7_ADJ")?;
+        let f107_centred = find("F10.7_OBS_CENTER81")?;
+
+        let mut records = Vec::new();
+        for (index, line) in lines {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let line_number = index + 1;
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let value = |column: usize, name: &str| {
+                optional_number(fields.get(column).copied(), line_number, name)
+            };
+
+            let mut kp = [f64::NAN; 8];
+            let mut ap = [f64::NAN; 8];
+            for i in 0..8 {
+                kp[i] = kp_from_tenths(value(kp_columns[i], "Kp")?);
+                ap[i] = value(ap_columns[i], "Ap")?;
+            }
+
+            records.push(SpaceWeatherRecord {
+                mjd: mjd_from_date(fields[0], line_number)?,
+                kp,
+                ap,
+                ap_daily: value(ap_avg, "Ap average")?,
+                f107_obs: value(f107_obs, "F10.7")?,
+                f107_adj: value(f107_adj, "adjusted F10.7")?,
+                f107_obs_centred81: value(f107_centred, "centred 81-day F10.7")?,
+            });
+        }
+        Self::from_records(records)
+    }
+
+    /// Parse the contents of a CelesTrak legacy fixed-width file
+    ///
+    /// Only rows starting with a year are read; section markers and header
+    /// lines are skipped.
+    pub fn parse_legacy(contents: &str) -> Result<Self, SpaceWeatherError> {
+        let mut records = Vec::new();
+        for (index, line) in contents.lines().enumerate() {
+            if let Some(record) = parse_legacy_line(line, index + 1)? {
+                records.push(record);
+            }
+        }
+        Self::from_records(records)
+    }
+
+    /// Load a space weather file from a local path
+    pub fn from_file(
+        path: impl AsRef<Path>,
+        format: SpaceWeatherFormat,
+    ) -> Result<Self, SpaceWeatherError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| SpaceWeatherError::Io(format!("{}: {}", path.display(), e)))?;
+
+        match format {
+            SpaceWeatherFormat::CelestrakCsv => Self::parse_celestrak_csv(&contents),
+            SpaceWeatherFormat::Legacy => Self::parse_legacy(&contents),
+        }
+    }
+
+    /// Load a CelesTrak `SW-All.csv` file from a local path
+    pub fn from_csv_file(path: impl AsRef<Path>) -> Result<Self, SpaceWeatherError> {
+        Self::from_file(path, SpaceWeatherFormat::CelestrakCsv)
+    }
+
+    /// Load a CelesTrak legacy fixed-width file from a local path
+    pub fn from_legacy_file(path: impl AsRef<Path>) -> Result<Self, SpaceWeatherError> {
+        Self::from_file(path, SpaceWeatherFormat::Legacy)
+    }
+
+    /// Tabulated records, sorted by MJD
+    pub fn records(&self) -> &[SpaceWeatherRecord] {
+        &self.records
+    }
+
+    /// Number of records
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Whether the table has no records
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// First and last tabulated day (MJD, UTC)
+    pub fn mjd_range(&self) -> (f64, f64) {
+        (
+            self.records[0].mjd,
+            self.records[self.records.len() - 1].mjd,
+        )
+    }
+
+    /// Record of the UTC day containing `mjd`, if tabulated
+    pub fn record(&self, mjd: f64) -> Option<&SpaceWeatherRecord> {
+        let day = mjd.floor();
+        let i = self.records.partition_point(|r| r.mjd < day);
+        self.records.get(i).filter(|r| r.mjd == day)
+    }
+
+    /// Observed F10.7 of the UTC day containing `mjd`
+    pub fn f107(&self, mjd: f64) -> Option<f64> {
+        self.record(mjd)
+            .map(|r| r.f107_obs)
+            .filter(|v| v.is_finite())
+    }
+
+    /// 81-day centred mean of the observed F10.7 for the day containing `mjd`
+    pub fn f107_centred81(&self, mjd: f64) -> Option<f64> {
+        self.record(mjd)
+            .map(|r| r.f107_obs_centred81)
+            .filter(|v| v.is_finite())
+    }
+
+    /// 3-hourly ap of the interval containing `mjd`
+    pub fn ap(&self, mjd: f64) -> Option<f64> {
+        let interval = ((mjd - mjd.floor()) * 8.0).floor().clamp(0.0, 7.0) as usize;
+        self.record(mjd)
+            .map(|r| r.ap[interval])
+            .filter(|v| v.is_finite())
+    }
+
+    /// Daily Ap of the UTC day containing `mjd`
+    pub fn ap_daily(&self, mjd: f64) -> Option<f64> {
+        self.record(mjd)
+            .map(|r| r.ap_daily)
+            .filter(|v| v.is_finite())
+    }
+}
+
+/// Parse an optional numeric CSV field (blank is NaN)
+fn optional_number(field: Option<&str>, line: usize, name: &str) -> Result<f64, SpaceWeatherError> {
+    match field.map(str::trim).filter(|s| !s.is_empty()) {
+        None => Ok(f64::NAN),
+        Some(s) => s.parse().map_err(|_| SpaceWeatherError::Parse {
+            line,
+            reason: format!("invalid {} '{}'", name, s),
+        }),
+    }
+}
+
+/// Kp from the tabulated ×10 integer form (0+ = 3, 1− = 7, 1o = 10)
+fn kp_from_tenths(value: f64) -> f64 {
+    if !value.is_finite() {
+        return f64::NAN;
+    }
+    let whole = (value / 10.0).trunc();
+    whole + ((value - 10.0 * whole) / 3.0).round() / 3.0
+}
+
+/// MJD of 00:00 UTC on the civil date (proleptic Gregorian calendar)
+fn mjd_from_ymd(year: i64, month: i64, day: i64) -> f64 {
+    // Fliegel & Van Flandern (1968)
+    let a = (14 - month) / 12;
+    let y = year + 4800 - a;
+    let m = month + 12 * a - 3;
+    let jdn = day + (153 * m + 2) / 5 + 365 * y + y / 4 - y / 100 + y / 400 - 32045;
+    jdn as f64 - 2_400_001.0
+}
+
+/// MJD of a `YYYY-MM-DD` date
+fn mjd_from_date(date: &str, line: usize) -> Result<f64, SpaceWeatherError> {
+    let error = || SpaceWeatherError::Parse {
+        line,
+        reason: format!("invalid date '{}'", date),
+    };
+    let parts: Vec<i64> = date
+        .split('-')
+        .map(|p| p.parse().map_err(|_| error()))
+        .collect::<Result<_, _>>()?;
+    match parts[..] {
+        [year, month, day] if (1..=12).contains(&month) && (1..=31).contains(&day) => {
+            Ok(mjd_from_ymd(year, month, day))
+        }
+        _ => Err(error()),
+    }
+}
+
+/// Trimmed contents of 0-based column range, or None if blank/missing
+fn field(line: &str, start: usize, end: usize) -> Option<&str> {
+    line.get(start..end.min(line.len()))
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+/// Parse one legacy row:
+/// `FORMAT(I4,I3,I3,I5,I3,8I3,I4,8I4,I4,F4.1,I2,I4,F6.1,I2,5F6.1)`
+fn parse_legacy_line(
+    line: &str,
+    line_number: usize,
+) -> Result<Option<SpaceWeatherRecord>, SpaceWeatherError> {
+    let Some(year) = field(line, 0, 4).and_then(|s| s.parse::<i64>().ok()) else {
+        return Ok(None);
+    };
+    let value = |start: usize, end: usize, name: &str| {
+        optional_number(field(line, start, end), line_number, name)
+    };
+
+    let month = value(4, 7, "month")?;
+    let day = value(7, 10, "day")?;
+    if !(1.0..=12.0).contains(&month) || !(1.0..=31.0).contains(&day) {
+        return Err(SpaceWeatherError::Parse {
+            line: line_number,
+            reason: "invalid date".to_string(),
+        });
+    }
+
+    let mut kp = [f64::NAN; 8];
+    let mut ap = [f64::NAN; 8];
+    for i in 0..8 {
+        kp[i] = kp_from_tenths(value(18 + 3 * i, 21 + 3 * i, "Kp")?);
+        ap[i] = value(46 + 4 * i, 50 + 4 * i, "Ap")?;
+    }
+
+    Ok(Some(SpaceWeatherRecord {
+        mjd: mjd_from_ymd(year, month as i64, day as i64),
+        kp,
+        ap,
+        ap_daily: value(78, 82, "Ap average")?,
+        f107_adj: value(92, 98, "adjusted F10.7")?,
+        f107_obs: value(112, 118, "F10.7")?,
+        f107_obs_centred81: value(118, 124, "centred 81-day F10.7")?,
+    }))
+}
+
+/// Activity at `mjd` from an optional table, field by field over `fallback`
+fn activity_from(
+    table: Option<&SpaceWeatherTable>,
+    fallback: &SolarActivity,
+    mjd: f64,
+) -> SolarActivity {
+    let Some(table) = table else {
+        return *fallback;
+    };
+    SolarActivity {
+        f107: table.f107(mjd - 1.0).unwrap_or(fallback.f107),
+        f107_avg: table.f107_centred81(mjd).unwrap_or(fallback.f107_avg),
+        ap: table.ap(mjd).unwrap_or(fallback.ap),
+    }
+}
+
+/// Globally installed space weather table (None until installed)
+static SPACE_WEATHER: RwLock<Option<Arc<SpaceWeatherTable>>> = RwLock::new(None);
+
+/// Constant activity used beyond the installed table
+static FALLBACK: RwLock<SolarActivity> = RwLock::new(DEFAULT_ACTIVITY);
+
+/// Install the space weather table used by [`SolarActivity::at`] and the
+/// atmosphere models
+///
+/// Replaces any previously installed table.
+pub fn install_space_weather(table: SpaceWeatherTable) {
+    let mut guard = SPACE_WEATHER.write().unwrap_or_else(|e| e.into_inner());
+    *guard = Some(Arc::new(table));
+}
+
+/// Remove the installed space weather table, reverting to the fallback
+pub fn clear_space_weather() {
+    let mut guard = SPACE_WEATHER.write().unwrap_or_else(|e| e.into_inner());
+    *guard = None;
+}
+
+/// Whether a space weather table has been installed
+pub fn space_weather_installed() -> bool {
+    SPACE_WEATHER
+        .read()
+        .map(|guard| guard.is_some())
+        .unwrap_or(false)
+}
+
+/// The currently installed space weather table, if any
+pub fn installed_space_weather() -> Option<Arc<SpaceWeatherTable>> {
+    SPACE_WEATHER.read().ok().and_then(|guard| guard.clone())
+}
+
+/// Set the constant activity used where the installed table has no data
+pub fn set_space_weather_fallback(activity: SolarActivity) {
+    let mut guard = FALLBACK.write().unwrap_or_else(|e| e.into_inner());
+    *guard = activity;
+}
+
+/// The constant activity used where the installed table has no data
+pub fn space_weather_fallback() -> SolarActivity {
+    FALLBACK
+        .read()
+        .map(|guard| *guard)
+        .unwrap_or(DEFAULT_ACTIVITY)
+}
+
+/// Observed F10.7 of the UTC day before the one containing `epoch` (sfu)
+///
+/// The daily flux the atmosphere models take, as in [`SolarActivity::at`].
+/// From the installed table, or the fallback's `f107`.
+pub fn f107_at(epoch: &Epoch) -> f64 {
+    SolarActivity::at(epoch).f107
+}
+
+/// 81-day centred mean of the observed F10.7 at `epoch` (sfu)
+///
+/// From the installed table, or the fallback's `f107_avg`.
+pub fn f107_centred81_at(epoch: &Epoch) -> f64 {
+    SolarActivity::at(epoch).f107_avg
+}
+
+/// 3-hourly ap of the interval containing `epoch`
+///
+/// From the installed table, or the fallback's `ap`.
+pub fn ap_at(epoch: &Epoch) -> f64 {
+    SolarActivity::at(epoch).ap
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    const CSV: &str = "\
+DATE,BSRN,ND,KP1,KP2,KP3,KP4,KP5,KP6,KP7,KP8,KP_SUM,AP1,AP2,AP3,AP4,AP5,AP6,AP7,AP8,AP_AVG,CP,C9,ISN,F10.7_OBS,F10.7_ADJ,F10.7_DATA_TYPE,F10.7_OBS_CENTER81,F10.7_OBS_LAST81,F10.7_ADJ_CENTER81,F10.7_ADJ_LAST81
+2024-05-10,2606,2,33,37,40,50,70,83,87,90,490,18,22,27,48,132,236,300,400,173,2.1,8,187,230.6,227.9,OBS,178.2,171.5,176.1,169.8
+2024-05-11,2606,3,87,80,63,57,50,37,30,27,431,300,207,67,56,48,22,15,12,91,1.8,7,204,211.3,208.9,OBS,177.9,172.0,175.8,170.2
+2024-06-01,2607,1,,,,,,,,,,,,,,,,,,,,,,160.0,157.4,PRM,155.0,158.0,152.6,155.6
+";
+
+    /// Format a legacy row with the given indices
+    fn legacy_line(
+        date: (i32, u32, u32),
+        kp: [u32; 8],
+        ap: [u32; 8],
+        f107_obs: f64,
+        f107_centred: f64,
+    ) -> String {
+        let mut line = format!("{:4}{:3}{:3}{:5}{:3}", date.0, date.1, date.2, 2606, 2);
+        for k in kp {
+            line += &format!("{:3}", k);
+        }
+        line += &format!("{:4}", kp.iter().sum::<u32>());
+        for a in ap {
+            line += &format!("{:4}", a);
+        }
+        let ap_avg = ap.iter().sum::<u32>() / 8;
+        line += &format!(
+            "{:4}{:4.1}{:2}{:4}{:6.1}{:2}",
+            ap_avg, 1.2, 5, 150, 150.0, 0
+        );
+        line += &format!(
+            "{:6.1}{:6.1}{:6.1}{:6.1}{:6.1}",
+            148.0, 149.0, f107_obs, f107_centred, 151.0
+        );
+        line
+    }
+
+    #[test]
+    fn test_kp_from_tenths() {
+        assert_eq!(kp_from_tenths(0.0), 0.0);
+        assert_relative_eq!(kp_from_tenths(3.0), 1.0 / 3.0);
+        assert_relative_eq!(kp_from_tenths(7.0), 2.0 / 3.0);
+        assert_relative_eq!(kp_from_tenths(50.0), 5.0);
+        assert_relative_eq!(kp_from_tenths(87.0), 8.0 + 2.0 / 3.0);
+        assert!(kp_from_tenths(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_kp_from_ap() {
+        assert_eq!(SolarActivity::new(150.0, 150.0, 0.0).kp(), 0.0);
+        assert_relative_eq!(SolarActivity::new(150.0, 150.0, 15.0).kp(), 3.0);
+        assert_relative_eq!(SolarActivity::new(150.0, 150.0, 400.0).kp(), 9.0);
+        assert_relative_eq!(SolarActivity::new(150.0, 150.0, 13.5).kp(), 2.0 + 5.0 / 6.0);
+    }
+
+    #[test]
+    fn test_mjd_from_date() {
+        assert_eq!(mjd_from_ymd(1858, 11, 17), 0.0);
+        assert_eq!(mjd_from_ymd(2000, 1, 1), 51544.0);
+        assert_eq!(mjd_from_date("2024-05-10", 1).unwrap(), 60440.0);
+        assert!(mjd_from_date("2024-13-10", 1).is_err());
+        assert!(mjd_from_date("20240510", 1).is_err());
+    }
+
+    #[test]
+    fn test_parse_celestrak_csv() {
+        let table = SpaceWeatherTable::parse_celestrak_csv(CSV).unwrap();
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.mjd_range(), (60440.0, 60462.0));
+
+        let storm = table.record(60440.7).unwrap();
+        assert_eq!(storm.ap[7], 400.0);
+        assert_relative_eq!(storm.kp[0], 3.0 + 1.0 / 3.0);
+        assert_eq!(storm.ap_daily, 173.0);
+        assert_eq!(storm.f107_adj, 227.9);
+
+        assert_eq!(table.f107(60440.2), Some(230.6));
+        assert_eq!(table.f107_centred81(60441.0), Some(177.9));
+        // 3-hourly intervals
+        assert_eq!(table.ap(60440.0), Some(18.0));
+        assert_eq!(table.ap(60440.99), Some(400.0));
+        assert_eq!(table.ap(60441.5), Some(48.0));
+
+        // Monthly prediction: F10.7 only
+        assert_eq!(table.f107(60462.5), Some(160.0));
+        assert_eq!(table.ap(60462.5), None);
+        // Days not tabulated
+        assert_eq!(table.f107(60450.0), None);
+        assert_eq!(table.f107(70000.0), None);
+    }
+
+    #[test]
+    fn test_parse_legacy() {
+        let contents = [
+            "DATATYPE CelesTrak Space Weather Data".to_string(),
+            "BEGIN OBSERVED".to_string(),
+            legacy_line(
+                (2024, 5, 10),
+                [33, 37, 40, 50, 70, 83, 87, 90],
+                [18, 22, 27, 48, 132, 236, 300, 400],
+                230.6,
+                178.2,
+            ),
+            legacy_line(
+                (2024, 5, 11),
+                [87, 80, 63, 57, 50, 37, 30, 27],
+                [300, 207, 67, 56, 48, 22, 15, 12],
+                211.3,
+                177.9,
+            ),
+            "END OBSERVED".to_string(),
+        ]
+        .join("\n");
+
+        let table = SpaceWeatherTable::parse_legacy(&contents).unwrap();
+        assert_eq!(table.len(), 2);
+        let record = table.record(60441.0).unwrap();
+        assert_eq!(record.f107_obs, 211.3);
+        assert_eq!(record.f107_obs_centred81, 177.9);
+        assert_eq!(record.f107_adj, 150.0);
+        assert_eq!(
+            record.ap,
+            [300.0, 207.0, 67.0, 56.0, 48.0, 22.0, 15.0, 12.0]
+        );
+        assert_relative_eq!(record.kp[0], 8.0 + 2.0 / 3.0);
+
+        // Both formats give the same lookups
+        let csv = SpaceWeatherTable::parse_celestrak_csv(CSV).unwrap();
+        for mjd in [60440.1, 60440.6, 60441.3, 60441.9] {
+            assert_eq!(table.f107(mjd), csv.f107(mjd));
+            assert_eq!(table.ap(mjd), csv.ap(mjd));
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            SpaceWeatherTable::parse_celestrak_csv("no header\n"),
+            Err(SpaceWeatherError::Empty)
+        );
+        assert!(matches!(
+            SpaceWeatherTable::parse_celestrak_csv("DATE,KP1\n2024-01-01,3\n"),
+            Err(SpaceWeatherError::Parse { .. })
+        ));
+        let bad = CSV.replace("230.6", "abc");
+        assert!(matches!(
+            SpaceWeatherTable::parse_celestrak_csv(&bad),
+            Err(SpaceWeatherError::Parse { line: 2, .. })
+        ));
+        assert_eq!(
+            SpaceWeatherTable::parse_legacy("BEGIN OBSERVED\n"),
+            Err(SpaceWeatherError::Empty)
+        );
+        assert!(matches!(
+            SpaceWeatherTable::from_csv_file("/nonexistent/SW-All.csv"),
+            Err(SpaceWeatherError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_activity_with_fallback() {
+        let table = SpaceWeatherTable::parse_celestrak_csv(CSV).unwrap();
+        let fallback = SolarActivity::new(120.0, 110.0, 7.0);
+
+        // Previous day's flux, current day's centred mean and 3-hourly ap
+        let activity = activity_from(Some(&table), &fallback, 60441.1);
+        assert_eq!(activity, SolarActivity::new(230.6, 177.9, 300.0));
+
+        // The flux changes at 0h UTC, to that of the day just ended
+        let activity = activity_from(Some(&table), &fallback, 60441.0);
+        assert_eq!(activity.f107, 230.6);
+        let activity = activity_from(Some(&table), &fallback, 60440.99);
+        assert_eq!(activity.f107, 120.0);
+
+        // First day: no previous-day flux
+        let activity = activity_from(Some(&table), &fallback, 60440.5);
+        assert_eq!(activity, SolarActivity::new(120.0, 178.2, 132.0));
+
+        // Beyond the file and without a table
+        assert_eq!(activity_from(Some(&table), &fallback, 70000.0), fallback);
+        assert_eq!(activity_from(None, &fallback, 60441.1), fallback);
+    }
+
+    #[test]
+    fn test_index_functions_match_activity() {
+        let epoch = Epoch::from_gregorian_utc(2024, 5, 11, 1, 0, 0, 0);
+        let activity = SolarActivity::at(&epoch);
+        assert_eq!(f107_at(&epoch), activity.f107);
+        assert_eq!(f107_centred81_at(&epoch), activity.f107_avg);
+        assert_eq!(ap_at(&epoch), activity.ap);
+    }
+}
@@ -9,7 +9,7 @@ use pyo3::prelude::*;
 
 /// Cartesian state vector (position and velocity)
 #[pyclass(name = "CartesianState", module = "astrora._core")]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct CartesianState {
     /// Position vector [x, y, z] in meters
     pub position: Vector3,
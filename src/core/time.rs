@@ -13,6 +13,9 @@
 use hifitime::{Duration as HifiDuration, Epoch as HifiEpoch, TimeScale};
 use pyo3::prelude::*;
 
+use crate::core::eop::eop_at;
+use crate::core::error::PoliastroResult;
+
 /// High-precision epoch representation for astrodynamics
 ///
 /// Wraps hifitime::Epoch to provide nanosecond-precision time handling
@@ -175,6 +178,35 @@ impl Epoch {
         self.to_time_scale(TimeScale::GPST)
     }
 
+    /// Get UT1 − UTC in seconds at this epoch
+    ///
+    /// Interpolated from the installed EOP table, or from the bundled data
+    /// when no table is installed (see [`crate::core::eop`]).
+    ///
+    /// # Errors
+    /// `OutOfRange` if an EOP table is installed and does not cover this epoch.
+    pub fn ut1_minus_utc(&self) -> PoliastroResult<f64> {
+        Ok(eop_at(self)?.dut1)
+    }
+
+    /// Get epoch in UT1
+    ///
+    /// hifitime has no UT1 time scale, so UT1 is represented as a UTC epoch
+    /// shifted by UT1 − UTC: the UTC calendar, MJD and JD readings of the
+    /// returned epoch are UT1 readings.
+    ///
+    /// # Errors
+    /// `OutOfRange` if an EOP table is installed and does not cover this epoch.
+    pub fn to_ut1(&self) -> PoliastroResult<Self> {
+        let dut1 = self.ut1_minus_utc()?;
+        Ok(self.to_utc().add_duration(Duration::from_seconds(dut1)))
+    }
+
+    /// Get Modified Julian Date in UT1
+    pub fn to_mjd_ut1(&self) -> PoliastroResult<f64> {
+        Ok(self.to_mjd_utc() + self.ut1_minus_utc()? / 86400.0)
+    }
+
     /// Get Modified Julian Date in TT
     pub fn to_mjd_tt(&self) -> f64 {
         self.inner.to_mjd_tt_days()
@@ -254,6 +286,16 @@ impl Epoch {
         (tdb_self.inner - tdb_j2000.inner).to_seconds()
     }
 
+    /// Create epoch from seconds since J2000 in TDB
+    ///
+    /// Inverse of [`Epoch::to_tdb_seconds_since_j2000`]; this is the "ET"
+    /// time argument used by JPL ephemerides.
+    pub fn from_tdb_seconds_since_j2000(seconds: f64) -> Self {
+        Self::j2000()
+            .to_tdb()
+            .add_duration(Duration::from_seconds(seconds))
+    }
+
     /// Get duration since J2000 epoch
     pub fn duration_since_j2000(&self) -> Duration {
         let j2000 = Epoch::j2000();
@@ -498,6 +540,17 @@ impl Epoch {
         self.to_tai()
     }
 
+    /// Convert to UT1 (UTC epoch shifted by UT1 − UTC from the loaded EOP)
+    fn as_ut1(&self) -> PyResult<Self> {
+        Ok(self.to_ut1()?)
+    }
+
+    /// UT1 − UTC in seconds from the loaded EOP
+    #[pyo3(name = "ut1_minus_utc")]
+    fn py_ut1_minus_utc(&self) -> PyResult<f64> {
+        Ok(self.ut1_minus_utc()?)
+    }
+
     /// Convert to TT time scale
     fn as_tt(&self) -> Self {
         self.to_tt()
@@ -0,0 +1,500 @@
+//! Built-in analytic ephemeris
+//!
+//! - **Planets and Earth-Moon barycenter**: heliocentric orbits from the JPL
+//!   approximate Keplerian elements (Standish, Table 1, valid 1800–2050),
+//!   referred to the ecliptic and equinox of J2000
+//! - **Sun**: barycentric offset from the mass-weighted planetary positions
+//! - **Moon**: geocentric position from the truncated lunar theory of
+//!   Montenbruck & Gill (~500 km, a few arcminutes)
+//! - **Earth**: Earth-Moon barycenter minus the lunar offset scaled by the
+//!   Moon/(Earth+Moon) mass ratio
+//!
+//! Velocities are obtained by central differences of the positions.
+
+use std::f64::consts::PI;
+
+use nalgebra::{Matrix3, Vector3};
+
+use super::{Body, EphemerisError, EphemerisSource};
+use crate::coordinates::rotations::rotation_x;
+use crate::core::anomaly::mean_to_eccentric_anomaly;
+use crate::core::constants::{
+    AU, GM_EARTH, GM_JUPITER, GM_MARS, GM_MERCURY, GM_MOON, GM_NEPTUNE, GM_SATURN, GM_SUN,
+    GM_URANUS, GM_VENUS,
+};
+use crate::core::time::Epoch;
+
+/// Obliquity of the ecliptic at J2000 (IAU 2006, 84381.406″)
+const OBLIQUITY_J2000: f64 = 84381.406 / 3600.0 * PI / 180.0;
+
+/// Seconds per Julian century
+const SECONDS_PER_CENTURY: f64 = 36525.0 * 86400.0;
+
+/// Half-step of the central difference used for velocities (s)
+const VELOCITY_STEP: f64 = 600.0;
+
+/// Keplerian elements of a planet and their rates per Julian century
+///
+/// a (AU), e, I (deg), L (deg), ϖ (deg), Ω (deg)
+struct PlanetElements {
+    elements: [f64; 6],
+    rates: [f64; 6],
+}
+
+/// JPL approximate elements, Table 1 (1800 AD – 2050 AD)
+const MERCURY: PlanetElements = PlanetElements {
+    elements: [
+        0.38709927,
+        0.20563593,
+        7.00497902,
+        252.25032350,
+        77.45779628,
+        48.33076593,
+    ],
+    rates: [
+        0.00000037,
+        0.00001906,
+        -0.00594749,
+        149472.67411175,
+        0.16047689,
+        -0.12534081,
+    ],
+};
+const VENUS: PlanetElements = PlanetElements {
+    elements: [
+        0.72333566,
+        0.00677672,
+        3.39467605,
+        181.97909950,
+        131.60246718,
+        76.67984255,
+    ],
+    rates: [
+        0.00000390,
+        -0.00004107,
+        -0.00078890,
+        58517.81538729,
+        0.00268329,
+        -0.27769418,
+    ],
+};
+const EARTH_MOON_BARYCENTER: PlanetElements = PlanetElements {
+    elements: [
+        1.00000261,
+        0.01671123,
+        -0.00001531,
+        100.46457166,
+        102.93768193,
+        0.0,
+    ],
+    rates: [
+        0.00000562,
+        -0.00004392,
+        -0.01294668,
+        35999.37244981,
+        0.32327364,
+        0.0,
+    ],
+};
+const MARS: PlanetElements = PlanetElements {
+    elements: [
+        1.52371034,
+        0.09339410,
+        1.84969142,
+        -4.55343205,
+        -23.94362959,
+        49.55953891,
+    ],
+    rates: [
+        0.00001847,
+        0.00007882,
+        -0.00813131,
+        19140.30268499,
+        0.44441088,
+        -0.29257343,
+    ],
+};
+const JUPITER: PlanetElements = PlanetElements {
+    elements: [
+        5.20288700,
+        0.04838624,
+        1.30439695,
+        34.39644051,
+        14.72847983,
+        100.47390909,
+    ],
+    rates: [
+        -0.00011607,
+        -0.00013253,
+        -0.00183714,
+        3034.74612775,
+        0.21252668,
+        0.20469106,
+    ],
+};
+const SATURN: PlanetElements = PlanetElements {
+    elements: [
+        9.53667594,
+        0.05386179,
+        2.48599187,
+        49.95424423,
+        92.59887831,
+        113.66242448,
+    ],
+    rates: [
+        -0.00125060,
+        -0.00050991,
+        0.00193609,
+        1222.49362201,
+        -0.41897216,
+        -0.28867794,
+    ],
+};
+const URANUS: PlanetElements = PlanetElements {
+    elements: [
+        19.18916464,
+        0.04725744,
+        0.77263783,
+        313.23810451,
+        170.95427630,
+        74.01692503,
+    ],
+    rates: [
+        -0.00196176,
+        -0.00004397,
+        -0.00242939,
+        428.48202785,
+        0.40805281,
+        0.04240589,
+    ],
+};
+const NEPTUNE: PlanetElements = PlanetElements {
+    elements: [
+        30.06992276,
+        0.00859048,
+        1.77004347,
+        -55.12002969,
+        44.96476227,
+        131.78422574,
+    ],
+    rates: [
+        0.00026291,
+        0.00005105,
+        0.00035372,
+        218.45945325,
+        -0.32241464,
+        -0.00508664,
+    ],
+};
+
+/// Analytic ephemeris of the Sun, Moon, Earth and planetary barycenters
+///
+/// Needs no data files. Planet centers (NAIF 199, 299, 499, …, 899) are
+/// approximated by their system barycenters. Pluto and other bodies are not
+/// covered.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AnalyticEphemeris;
+
+impl AnalyticEphemeris {
+    /// Barycentric position (m, ICRF axes) of `body` at `t` TDB centuries past J2000
+    fn barycentric_position(&self, body: Body, t: f64) -> Result<Vector3<f64>, EphemerisError> {
+        let sun = sun_barycentric_position(t)?;
+        let position = match body {
+            Body::SolarSystemBarycenter => Vector3::zeros(),
+            Body::Sun => sun,
+            Body::Mercury => sun + heliocentric_position(&MERCURY, t)?,
+            Body::Venus => sun + heliocentric_position(&VENUS, t)?,
+            Body::EarthMoonBarycenter => sun + heliocentric_position(&EARTH_MOON_BARYCENTER, t)?,
+            Body::Mars => sun + heliocentric_position(&MARS, t)?,
+            Body::Jupiter => sun + heliocentric_position(&JUPITER, t)?,
+            Body::Saturn => sun + heliocentric_position(&SATURN, t)?,
+            Body::Uranus => sun + heliocentric_position(&URANUS, t)?,
+            Body::Neptune => sun + heliocentric_position(&NEPTUNE, t)?,
+            Body::Earth | Body::Moon => {
+                let emb = sun + heliocentric_position(&EARTH_MOON_BARYCENTER, t)?;
+                let moon = moon_geocentric_position(t);
+                let earth = emb - moon * (GM_MOON / (GM_EARTH + GM_MOON));
+                if body == Body::Earth {
+                    earth
+                } else {
+                    earth + moon
+                }
+            }
+            Body::Naif(id @ (199 | 299 | 499 | 599 | 699 | 799 | 899)) => {
+                return self.barycentric_position(Body::from_naif_id(id / 100), t)
+            }
+            Body::Pluto | Body::Naif(_) => {
+                return Err(EphemerisError::UnsupportedBody {
+                    body,
+                    source_name: self.name().to_string(),
+                })
+            }
+        };
+        Ok(position)
+    }
+
+    /// Position of `target` relative to `center` at `t` TDB centuries past J2000
+    fn relative_position(
+        &self,
+        target: Body,
+        center: Body,
+        t: f64,
+    ) -> Result<Vector3<f64>, EphemerisError> {
+        Ok(self.barycentric_position(target, t)? - self.barycentric_position(center, t)?)
+    }
+}
+
+impl EphemerisSource for AnalyticEphemeris {
+    fn name(&self) -> &str {
+        "analytic"
+    }
+
+    fn state(
+        &self,
+        target: Body,
+        center: Body,
+        epoch: &Epoch,
+    ) -> Result<(Vector3<f64>, Vector3<f64>), EphemerisError> {
+        let t = epoch.to_tdb_seconds_since_j2000() / SECONDS_PER_CENTURY;
+        let dt = VELOCITY_STEP / SECONDS_PER_CENTURY;
+
+        let position = self.relative_position(target, center, t)?;
+        let ahead = self.relative_position(target, center, t + dt)?;
+        let behind = self.relative_position(target, center, t - dt)?;
+        let velocity = (ahead - behind) / (2.0 * VELOCITY_STEP);
+
+        Ok((position, velocity))
+    }
+}
+
+/// Rotation from the J2000 ecliptic to ICRF (equatorial) axes
+fn ecliptic_to_equatorial() -> Matrix3<f64> {
+    rotation_x(OBLIQUITY_J2000)
+}
+
+/// Heliocentric position (m, ICRF axes) from approximate Keplerian elements
+fn heliocentric_position(planet: &PlanetElements, t: f64) -> Result<Vector3<f64>, EphemerisError> {
+    let el: [f64; 6] = std::array::from_fn(|i| planet.elements[i] + planet.rates[i] * t);
+    let (a, e) = (el[0] * AU, el[1]);
+    let (inc, lon, varpi, node) = (
+        el[2].to_radians(),
+        el[3].to_radians(),
+        el[4].to_radians(),
+        el[5].to_radians(),
+    );
+
+    let argp = varpi - node;
+    let mean_anomaly = (lon - varpi).rem_euclid(2.0 * PI);
+    let ecc_anomaly = mean_to_eccentric_anomaly(mean_anomaly, e, None, None)
+        .map_err(|err| EphemerisError::Computation(err.to_string()))?;
+
+    // Position in the orbital plane
+    let x_orb = a * (ecc_anomaly.cos() - e);
+    let y_orb = a * (1.0 - e * e).sqrt() * ecc_anomaly.sin();
+
+    let (sw, cw) = argp.sin_cos();
+    let (sn, cn) = node.sin_cos();
+    let (si, ci) = inc.sin_cos();
+    let ecliptic = Vector3::new(
+        (cw * cn - sw * sn * ci) * x_orb + (-sw * cn - cw * sn * ci) * y_orb,
+        (cw * sn + sw * cn * ci) * x_orb + (-sw * sn + cw * cn * ci) * y_orb,
+        (sw * si) * x_orb + (cw * si) * y_orb,
+    );
+
+    Ok(ecliptic_to_equatorial() * ecliptic)
+}
+
+/// Barycentric position of the Sun (m, ICRF axes)
+///
+/// The Sun balances the mass-weighted heliocentric positions of the planets:
+/// r☉ = −Σ μᵢ rᵢ / (μ☉ + Σ μᵢ).
+fn sun_barycentric_position(t: f64) -> Result<Vector3<f64>, EphemerisError> {
+    let planets = [
+        (&MERCURY, GM_MERCURY),
+        (&VENUS, GM_VENUS),
+        (&EARTH_MOON_BARYCENTER, GM_EARTH + GM_MOON),
+        (&MARS, GM_MARS),
+        (&JUPITER, GM_JUPITER),
+        (&SATURN, GM_SATURN),
+        (&URANUS, GM_URANUS),
+        (&NEPTUNE, GM_NEPTUNE),
+    ];
+
+    let mut weighted = Vector3::zeros();
+    let mut total_gm = GM_SUN;
+    for (planet, gm) in planets {
+        weighted += heliocentric_position(planet, t)? * gm;
+        total_gm += gm;
+    }
+
+    Ok(-weighted / total_gm)
+}
+
+/// Geocentric position of the Moon (m, ICRF axes)
+///
+/// Montenbruck & Gill (2000), Eq. 3.47–3.49; the mean longitude includes the
+/// precession correction to the equinox of J2000.
+fn moon_geocentric_position(t: f64) -> Vector3<f64> {
+    let arcsec = PI / (180.0 * 3600.0);
+
+    let l0 = (218.31617 + 481267.88088 * t - 1.3972 * t).to_radians();
+    let l = (134.96292 + 477198.86753 * t).to_radians();
+    let lp = (357.52543 + 35999.04944 * t).to_radians();
+    let f = (93.27283 + 483202.01873 * t).to_radians();
+    let d = (297.85027 + 445267.11135 * t).to_radians();
+
+    let lambda = l0
+        + arcsec
+            * (22640.0 * l.sin() + 769.0 * (2.0 * l).sin() - 4586.0 * (l - 2.0 * d).sin()
+                + 2370.0 * (2.0 * d).sin()
+                - 668.0 * lp.sin()
+                - 412.0 * (2.0 * f).sin()
+                - 212.0 * (2.0 * l - 2.0 * d).sin()
+                - 206.0 * (l + lp - 2.0 * d).sin()
+                + 192.0 * (l + 2.0 * d).sin()
+                - 165.0 * (lp - 2.0 * d).sin()
+                + 148.0 * (l - lp).sin()
+                - 125.0 * d.sin()
+                - 110.0 * (l + lp).sin()
+                - 55.0 * (2.0 * f - 2.0 * d).sin());
+
+    let beta = arcsec
+        * (18520.0
+            * (f + lambda - l0 + arcsec * (412.0 * (2.0 * f).sin() + 541.0 * lp.sin())).sin()
+            - 526.0 * (f - 2.0 * d).sin()
+            + 44.0 * (l + f - 2.0 * d).sin()
+            - 31.0 * (-l + f - 2.0 * d).sin()
+            - 25.0 * (-2.0 * l + f).sin()
+            - 23.0 * (lp + f - 2.0 * d).sin()
+            + 21.0 * (-l + f).sin()
+            + 11.0 * (-lp + f - 2.0 * d).sin());
+
+    let r = 1000.0
+        * (385000.0
+            - 20905.0 * l.cos()
+            - 3699.0 * (2.0 * d - l).cos()
+            - 2956.0 * (2.0 * d).cos()
+            - 570.0 * (2.0 * l).cos()
+            + 246.0 * (2.0 * l - 2.0 * d).cos()
+            - 205.0 * (lp - 2.0 * d).cos()
+            - 171.0 * (l + 2.0 * d).cos()
+            - 152.0 * (l + lp - 2.0 * d).cos());
+
+    let ecliptic = r * Vector3::new(
+        beta.cos() * lambda.cos(),
+        beta.cos() * lambda.sin(),
+        beta.sin(),
+    );
+    ecliptic_to_equatorial() * ecliptic
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_earth_heliocentric_at_j2000() {
+        // JPL Horizons (DE440), Earth w.r.t. Sun at 2000-01-01 12:00 TDB, ICRF
+        let epoch = Epoch::j2000();
+        let (r, v) = AnalyticEphemeris
+            .state(Body::Earth, Body::Sun, &epoch)
+            .unwrap();
+
+        let expected = Vector3::new(-0.1771351, 0.8874254, 0.3847304) * AU;
+        assert!(
+            (r - expected).norm() < 5e-4 * AU,
+            "error = {} km",
+            (r - expected).norm() / 1e3
+        );
+
+        // Orbital speed ~30 km/s, roughly along +x in January
+        assert_relative_eq!(v.norm(), 30.3e3, epsilon = 0.5e3);
+        assert!(v.x < -25e3);
+    }
+
+    #[test]
+    fn test_sun_distance_at_perihelion_and_aphelion() {
+        let perihelion = Epoch::from_gregorian_utc(2024, 1, 3, 0, 0, 0, 0);
+        let aphelion = Epoch::from_gregorian_utc(2024, 7, 5, 0, 0, 0, 0);
+
+        let r_peri = AnalyticEphemeris
+            .position(Body::Sun, Body::Earth, &perihelion)
+            .unwrap();
+        let r_aph = AnalyticEphemeris
+            .position(Body::Sun, Body::Earth, &aphelion)
+            .unwrap();
+
+        assert_relative_eq!(r_peri.norm() / AU, 0.98331, epsilon = 2e-4);
+        assert_relative_eq!(r_aph.norm() / AU, 1.01673, epsilon = 2e-4);
+    }
+
+    #[test]
+    fn test_sun_barycentric_offset() {
+        // DE440 places the Sun at (-7.14, -2.64, -0.92)e-3 AU from the SSB at J2000
+        let r_j2000 = AnalyticEphemeris
+            .position(Body::Sun, Body::SolarSystemBarycenter, &Epoch::j2000())
+            .unwrap();
+        let expected = Vector3::new(-7.14e-3, -2.64e-3, -0.92e-3) * AU;
+        assert!((r_j2000 - expected).norm() < 1e-4 * AU);
+
+        // The Sun wanders within ~2 solar radii of the barycenter
+        for year in [2000, 2010, 2020, 2030] {
+            let epoch = Epoch::from_gregorian_utc(year, 1, 1, 0, 0, 0, 0);
+            let r = AnalyticEphemeris
+                .position(Body::Sun, Body::SolarSystemBarycenter, &epoch)
+                .unwrap();
+            assert!(r.norm() < 0.012 * AU);
+        }
+    }
+
+    #[test]
+    fn test_moon_distance_and_earth_offset() {
+        let epoch = Epoch::from_gregorian_utc(2024, 3, 10, 0, 0, 0, 0);
+        let (r_moon, v_moon) = AnalyticEphemeris
+            .state(Body::Moon, Body::Earth, &epoch)
+            .unwrap();
+
+        assert!(r_moon.norm() > 356_000e3 && r_moon.norm() < 407_000e3);
+        assert_relative_eq!(v_moon.norm(), 1.02e3, epsilon = 0.1e3);
+
+        // Earth sits ~4,700 km from the Earth-Moon barycenter, opposite the Moon
+        let earth_emb = AnalyticEphemeris
+            .position(Body::Earth, Body::EarthMoonBarycenter, &epoch)
+            .unwrap();
+        assert_relative_eq!(
+            earth_emb.norm(),
+            r_moon.norm() * GM_MOON / (GM_EARTH + GM_MOON),
+            epsilon = 1.0
+        );
+        assert!(earth_emb.dot(&r_moon) < 0.0);
+    }
+
+    #[test]
+    fn test_state_is_antisymmetric() {
+        let epoch = Epoch::from_gregorian_utc(2030, 6, 1, 0, 0, 0, 0);
+        let (r1, v1) = AnalyticEphemeris
+            .state(Body::Mars, Body::Earth, &epoch)
+            .unwrap();
+        let (r2, v2) = AnalyticEphemeris
+            .state(Body::Earth, Body::Mars, &epoch)
+            .unwrap();
+
+        assert_relative_eq!(r1, -r2, epsilon = 1e-3);
+        assert_relative_eq!(v1, -v2, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_unsupported_body() {
+        let err = AnalyticEphemeris
+            .state(Body::Pluto, Body::SolarSystemBarycenter, &Epoch::j2000())
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            EphemerisError::UnsupportedBody {
+                body: Body::Pluto,
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,311 @@
+//! Solar system ephemerides
+//!
+//! This module provides positions and velocities of solar system bodies,
+//! used for the barycentric ICRS ↔ GCRS translation and wherever the state
+//! of the Sun, Moon or planets is needed.
+//!
+//! # Sources
+//!
+//! Ephemerides are supplied through the [`EphemerisSource`] trait:
+//! - [`AnalyticEphemeris`] (built in): JPL approximate Keplerian elements for
+//!   the planets and a truncated lunar theory. Heliocentric directions are good
+//!   to tens of arcseconds, i.e. Earth's barycentric position to roughly
+//!   10⁴ km, which is ample for the barycentric translation of Earth
+//!   satellites and preliminary interplanetary work.
+//! - [`SpkFile`]: JPL SPK (`.bsp`) kernels such as DE440, read natively
+//!   (DAF container, Chebyshev segment types 2 and 3).
+//! - Any implementation can be made the global source with
+//!   [`install_ephemeris`]; the frame transformations, third-body
+//!   perturbations and eclipse geometry then use it.
+//!
+//! # Conventions
+//!
+//! - Axes: ICRF (equatorial, aligned with ICRS/GCRS)
+//! - Units: meters and meters per second
+//! - Time argument: TDB
+//!
+//! # References
+//! - Standish, E. M., "Keplerian Elements for Approximate Positions of the
+//!   Major Planets", JPL Solar System Dynamics
+//! - Montenbruck & Gill, "Satellite Orbits", Section 3.3 (2000)
+//! - NAIF, "SPK Required Reading" and "DAF Required Reading"
+
+pub mod analytic;
+pub mod spk;
+
+use std::sync::{Arc, RwLock};
+
+use nalgebra::Vector3;
+use thiserror::Error;
+
+use crate::core::error::{PoliastroError, PoliastroResult};
+use crate::core::time::Epoch;
+
+pub use analytic::AnalyticEphemeris;
+pub use spk::{SpkFile, SpkSegment};
+
+/// Errors from ephemeris sources
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum EphemerisError {
+    #[error("Ephemeris source '{source_name}' has no data for {body}")]
+    UnsupportedBody { body: Body, source_name: String },
+
+    #[error(
+        "No ephemeris data for {body} relative to {center} at TDB {tdb_seconds:.3} s past J2000"
+    )]
+    OutOfRange {
+        body: Body,
+        center: Body,
+        tdb_seconds: f64,
+    },
+
+    #[error("Ephemeris computation failed: {0}")]
+    Computation(String),
+
+    #[error("Failed to read ephemeris file: {0}")]
+    Io(String),
+
+    #[error("Invalid ephemeris file: {0}")]
+    Format(String),
+}
+
+impl From<EphemerisError> for PoliastroError {
+    fn from(err: EphemerisError) -> Self {
+        PoliastroError::ComputationError {
+            message: err.to_string(),
+        }
+    }
+}
+
+/// Solar system bodies, identified by their NAIF integer codes
+///
+/// Planets other than the Earth are represented by their system barycenters
+/// (the centers tabulated by the JPL DE ephemerides); the offset from the
+/// planet itself is negligible outside the planet's own satellite system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Body {
+    /// Solar System Barycenter (NAIF 0)
+    SolarSystemBarycenter,
+    /// Mercury barycenter (NAIF 1)
+    Mercury,
+    /// Venus barycenter (NAIF 2)
+    Venus,
+    /// Earth-Moon barycenter (NAIF 3)
+    EarthMoonBarycenter,
+    /// Mars barycenter (NAIF 4)
+    Mars,
+    /// Jupiter barycenter (NAIF 5)
+    Jupiter,
+    /// Saturn barycenter (NAIF 6)
+    Saturn,
+    /// Uranus barycenter (NAIF 7)
+    Uranus,
+    /// Neptune barycenter (NAIF 8)
+    Neptune,
+    /// Pluto barycenter (NAIF 9)
+    Pluto,
+    /// Sun (NAIF 10)
+    Sun,
+    /// Moon (NAIF 301)
+    Moon,
+    /// Earth (NAIF 399)
+    Earth,
+    /// Any other body by NAIF code
+    Naif(i32),
+}
+
+impl Body {
+    /// NAIF integer code of the body
+    pub fn naif_id(&self) -> i32 {
+        match self {
+            Body::SolarSystemBarycenter => 0,
+            Body::Mercury => 1,
+            Body::Venus => 2,
+            Body::EarthMoonBarycenter => 3,
+            Body::Mars => 4,
+            Body::Jupiter => 5,
+            Body::Saturn => 6,
+            Body::Uranus => 7,
+            Body::Neptune => 8,
+            Body::Pluto => 9,
+            Body::Sun => 10,
+            Body::Moon => 301,
+            Body::Earth => 399,
+            Body::Naif(id) => *id,
+        }
+    }
+
+    /// Body from a NAIF integer code
+    pub fn from_naif_id(id: i32) -> Self {
+        match id {
+            0 => Body::SolarSystemBarycenter,
+            1 => Body::Mercury,
+            2 => Body::Venus,
+            3 => Body::EarthMoonBarycenter,
+            4 => Body::Mars,
+            5 => Body::Jupiter,
+            6 => Body::Saturn,
+            7 => Body::Uranus,
+            8 => Body::Neptune,
+            9 => Body::Pluto,
+            10 => Body::Sun,
+            301 => Body::Moon,
+            399 => Body::Earth,
+            other => Body::Naif(other),
+        }
+    }
+}
+
+impl std::fmt::Display for Body {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Body::SolarSystemBarycenter => write!(f, "Solar System Barycenter"),
+            Body::Mercury => write!(f, "Mercury"),
+            Body::Venus => write!(f, "Venus"),
+            Body::EarthMoonBarycenter => write!(f, "Earth-Moon Barycenter"),
+            Body::Mars => write!(f, "Mars"),
+            Body::Jupiter => write!(f, "Jupiter"),
+            Body::Saturn => write!(f, "Saturn"),
+            Body::Uranus => write!(f, "Uranus"),
+            Body::Neptune => write!(f, "Neptune"),
+            Body::Pluto => write!(f, "Pluto"),
+            Body::Sun => write!(f, "Sun"),
+            Body::Moon => write!(f, "Moon"),
+            Body::Earth => write!(f, "Earth"),
+            Body::Naif(id) => write!(f, "NAIF body {}", id),
+        }
+    }
+}
+
+/// A source of solar system body states
+///
+/// Implementations return the state of `target` relative to `center` in ICRF
+/// axes, in meters and meters per second.
+pub trait EphemerisSource: Send + Sync {
+    /// Short name of the source, used in error messages
+    fn name(&self) -> &str;
+
+    /// Position and velocity of `target` relative to `center` at `epoch`
+    fn state(
+        &self,
+        target: Body,
+        center: Body,
+        epoch: &Epoch,
+    ) -> Result<(Vector3<f64>, Vector3<f64>), EphemerisError>;
+
+    /// Position of `target` relative to `center` at `epoch`
+    fn position(
+        &self,
+        target: Body,
+        center: Body,
+        epoch: &Epoch,
+    ) -> Result<Vector3<f64>, EphemerisError> {
+        self.state(target, center, epoch)
+            .map(|(position, _)| position)
+    }
+
+    /// Time span over which `target` relative to `center` is available, as
+    /// (first, last) TDB seconds past J2000
+    ///
+    /// The default implementation returns `None`, for sources without a
+    /// bounded coverage.
+    fn time_span(&self, _target: Body, _center: Body) -> Option<(f64, f64)> {
+        None
+    }
+}
+
+/// Globally installed ephemeris source (None until installed)
+static EPHEMERIS: RwLock<Option<Arc<dyn EphemerisSource>>> = RwLock::new(None);
+
+/// Install the ephemeris source used by [`ephemeris_state`] and the frame
+/// transformations
+///
+/// Replaces any previously installed source.
+pub fn install_ephemeris(source: impl EphemerisSource + 'static) {
+    let mut guard = EPHEMERIS.write().unwrap_or_else(|e| e.into_inner());
+    *guard = Some(Arc::new(source));
+}
+
+/// Remove the installed ephemeris, reverting to [`AnalyticEphemeris`]
+pub fn clear_ephemeris() {
+    let mut guard = EPHEMERIS.write().unwrap_or_else(|e| e.into_inner());
+    *guard = None;
+}
+
+/// Whether an ephemeris source has been installed
+pub fn ephemeris_installed() -> bool {
+    EPHEMERIS
+        .read()
+        .map(|guard| guard.is_some())
+        .unwrap_or(false)
+}
+
+/// The currently installed ephemeris source, if any
+pub fn installed_ephemeris() -> Option<Arc<dyn EphemerisSource>> {
+    EPHEMERIS.read().ok().and_then(|guard| guard.clone())
+}
+
+/// State of `target` relative to `center` at `epoch` (m, m/s, ICRF axes)
+///
+/// Uses the installed source, or [`AnalyticEphemeris`] when none is installed.
+pub fn ephemeris_state(
+    target: Body,
+    center: Body,
+    epoch: &Epoch,
+) -> PoliastroResult<(Vector3<f64>, Vector3<f64>)> {
+    let state = match installed_ephemeris() {
+        Some(source) => source.state(target, center, epoch),
+        None => AnalyticEphemeris.state(target, center, epoch),
+    };
+    Ok(state?)
+}
+
+/// Position of `target` relative to `center` at `epoch` (m, ICRF axes)
+///
+/// See [`ephemeris_state`].
+pub fn ephemeris_position(
+    target: Body,
+    center: Body,
+    epoch: &Epoch,
+) -> PoliastroResult<Vector3<f64>> {
+    ephemeris_state(target, center, epoch).map(|(position, _)| position)
+}
+
+/// Barycentric position and velocity of the Earth at `epoch`
+///
+/// This is the translation between the ICRS and the GCRS.
+pub fn earth_barycentric_state(epoch: &Epoch) -> PoliastroResult<(Vector3<f64>, Vector3<f64>)> {
+    ephemeris_state(Body::Earth, Body::SolarSystemBarycenter, epoch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_naif_id_roundtrip() {
+        for id in [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 301, 399, 499, -82] {
+            assert_eq!(Body::from_naif_id(id).naif_id(), id);
+        }
+        assert_eq!(Body::from_naif_id(399), Body::Earth);
+        assert_eq!(Body::from_naif_id(499), Body::Naif(499));
+    }
+
+    #[test]
+    fn test_ephemeris_state_defaults_to_analytic() {
+        let epoch = Epoch::j2000();
+        let (r, v) = earth_barycentric_state(&epoch).unwrap();
+        let (r_ref, v_ref) = AnalyticEphemeris
+            .state(Body::Earth, Body::SolarSystemBarycenter, &epoch)
+            .unwrap();
+
+        assert_eq!(r, r_ref);
+        assert_eq!(v, v_ref);
+    }
+
+    #[test]
+    fn test_unsupported_body_error() {
+        let err = ephemeris_state(Body::Naif(-82), Body::Earth, &Epoch::j2000());
+        assert!(matches!(err, Err(PoliastroError::ComputationError { .. })));
+    }
+}
@@ -0,0 +1,1030 @@
+//! JPL SPK (`.bsp`) ephemeris reader
+//!
+//! Reads binary SPK kernels such as the JPL DE4xx planetary ephemerides
+//! directly, without the SPICE toolkit. The file is a DAF (Double precision
+//! Array File) whose arrays ("segments") each hold the state of one target
+//! body relative to one center body over a time span.
+//!
+//! # Supported segments
+//!
+//! - Type 2: Chebyshev polynomials for position; velocity is obtained by
+//!   differentiating the polynomials (used by all DE4xx planetary kernels)
+//! - Type 3: separate Chebyshev polynomials for position and velocity
+//!
+//! Both little- and big-endian IEEE files are accepted. Segments must be
+//! referenced to the J2000 frame (NAIF frame 1), which for the DE kernels is
+//! the ICRF.
+//!
+//! # Chaining
+//!
+//! Kernels rarely store the state of every body relative to every other
+//! (DE440 stores e.g. Earth relative to the Earth-Moon barycenter and the
+//! Earth-Moon barycenter relative to the Solar System Barycenter).
+//! [`SpkFile`] walks the segment tree from both bodies to their nearest
+//! common center, so any pair of bodies covered by the kernel can be queried.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use astrora_core::ephemeris::{install_ephemeris, Body, EphemerisSource, SpkFile};
+//! use astrora_core::core::time::Epoch;
+//!
+//! let de440 = SpkFile::open("de440s.bsp")?;
+//! let (r, v) = de440.state(Body::Moon, Body::Earth, &Epoch::j2000())?;
+//!
+//! // Use it for frame transformations and perturbations
+//! install_ephemeris(de440);
+//! ```
+//!
+//! # References
+//! - NAIF, "SPK Required Reading", Sections "SPK Type 2" and "SPK Type 3"
+//! - NAIF, "DAF Required Reading"
+
+use std::path::Path;
+
+use nalgebra::Vector3;
+
+use super::{Body, EphemerisError, EphemerisSource};
+use crate::core::time::Epoch;
+
+/// DAF record length (bytes)
+const RECORD_BYTES: usize = 1024;
+
+/// NAIF frame code of J2000 (ICRF for the DE kernels)
+const FRAME_J2000: i32 = 1;
+
+/// Kilometers to meters
+const KM: f64 = 1000.0;
+
+/// Longest chain of segments followed from a body towards its root center
+const MAX_CHAIN: usize = 32;
+
+/// A center on a segment chain and the state of the chain's target relative to it
+type ChainLink = (Body, Vector3<f64>, Vector3<f64>);
+
+/// Description of one SPK segment
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpkSegment {
+    /// Segment name from the DAF name record
+    pub name: String,
+    /// Body whose state the segment describes
+    pub target: Body,
+    /// Body the state is relative to
+    pub center: Body,
+    /// NAIF reference frame code
+    pub frame: i32,
+    /// SPK data type
+    pub data_type: i32,
+    /// First covered epoch (TDB seconds past J2000)
+    pub start_et: f64,
+    /// Last covered epoch (TDB seconds past J2000)
+    pub end_et: f64,
+    /// Initial DAF address of the segment data (1-based, in doubles)
+    start_address: usize,
+    /// Final DAF address of the segment data (1-based, in doubles)
+    end_address: usize,
+}
+
+impl SpkSegment {
+    /// Whether the segment covers `et` (TDB seconds past J2000)
+    pub fn covers(&self, et: f64) -> bool {
+        self.start_et <= et && et <= self.end_et
+    }
+}
+
+/// A JPL SPK ephemeris kernel held in memory
+///
+/// Implements [`EphemerisSource`]; install it with
+/// [`install_ephemeris`](super::install_ephemeris) to make it the global
+/// source.
+#[derive(Debug, Clone)]
+pub struct SpkFile {
+    name: String,
+    data: Vec<u8>,
+    little_endian: bool,
+    segments: Vec<SpkSegment>,
+}
+
+impl SpkFile {
+    /// Read an SPK kernel from disk
+    ///
+    /// # Errors
+    /// `EphemerisError::Io` if the file cannot be read, `EphemerisError::Format`
+    /// if it is not a valid SPK file.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, EphemerisError> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)
+            .map_err(|e| EphemerisError::Io(format!("{}: {}", path.display(), e)))?;
+        Self::from_bytes(data, path.display().to_string())
+    }
+
+    /// Parse an SPK kernel already loaded into memory
+    ///
+    /// # Arguments
+    /// * `data` - Complete file contents
+    /// * `name` - Name used in error messages (typically the file name)
+    pub fn from_bytes(data: Vec<u8>, name: impl Into<String>) -> Result<Self, EphemerisError> {
+        if data.len() < RECORD_BYTES {
+            return Err(EphemerisError::Format(
+                "file is shorter than one DAF record".to_string(),
+            ));
+        }
+
+        let id_word = String::from_utf8_lossy(&data[0..8]).to_string();
+        if !id_word.starts_with("DAF/SPK") && !id_word.starts_with("NAIF/DAF") {
+            return Err(EphemerisError::Format(format!(
+                "not an SPK file (identification word '{}')",
+                id_word.trim_end()
+            )));
+        }
+
+        let little_endian = match &data[88..96] {
+            b"LTL-IEEE" => true,
+            b"BIG-IEEE" => false,
+            // Pre-N0050 files have no format string; ND is always 2 for SPK
+            _ => i32::from_le_bytes([data[8], data[9], data[10], data[11]]) == 2,
+        };
+
+        let mut spk = Self {
+            name: name.into(),
+            data,
+            little_endian,
+            segments: Vec::new(),
+        };
+        spk.segments = spk.read_summaries()?;
+        Ok(spk)
+    }
+
+    /// Segments in file order
+    pub fn segments(&self) -> &[SpkSegment] {
+        &self.segments
+    }
+
+    /// Time span covered for `target`, as (first, last) TDB seconds past J2000
+    ///
+    /// Returns `None` if no segment has `target` as its target body.
+    pub fn coverage(&self, target: Body) -> Option<(f64, f64)> {
+        self.segments
+            .iter()
+            .filter(|segment| segment.target == target)
+            .fold(None, |span, segment| match span {
+                None => Some((segment.start_et, segment.end_et)),
+                Some((start, end)) => Some((start.min(segment.start_et), end.max(segment.end_et))),
+            })
+    }
+
+    /// Read the segment summaries from the DAF summary record chain
+    fn read_summaries(&self) -> Result<Vec<SpkSegment>, EphemerisError> {
+        let nd = self.read_i32(8)? as usize;
+        let ni = self.read_i32(12)? as usize;
+        if nd != 2 || ni != 6 {
+            return Err(EphemerisError::Format(format!(
+                "unexpected DAF summary format ND={}, NI={} (SPK uses 2 and 6)",
+                nd, ni
+            )));
+        }
+        let summary_doubles = nd + (ni + 1) / 2;
+        let name_bytes = 8 * summary_doubles;
+
+        let mut segments = Vec::new();
+        let first = self.read_i32(76)?;
+        let mut record = usize::try_from(first).map_err(|_| {
+            EphemerisError::Format(format!("invalid first summary record {}", first))
+        })?;
+        let mut visited = 0;
+        while record != 0 {
+            visited += 1;
+            if visited > self.data.len() / RECORD_BYTES {
+                return Err(EphemerisError::Format(
+                    "summary record chain does not terminate".to_string(),
+                ));
+            }
+
+            let base = record
+                .checked_sub(1)
+                .and_then(|index| index.checked_mul(RECORD_BYTES))
+                .ok_or_else(|| {
+                    EphemerisError::Format(format!("invalid summary record {}", record))
+                })?;
+            let next = self.read_f64_at(base)? as usize;
+            let count = self.read_f64_at(base + 16)? as usize;
+
+            for i in 0..count {
+                let offset = base + 24 + i * summary_doubles * 8;
+                let start_et = self.read_f64_at(offset)?;
+                let end_et = self.read_f64_at(offset + 8)?;
+                let ints_offset = offset + nd * 8;
+                let int = |k: usize| self.read_i32(ints_offset + 4 * k);
+                // DAF word addresses are 1-based
+                let address = |k: usize| -> Result<usize, EphemerisError> {
+                    let value = int(k)?;
+                    usize::try_from(value)
+                        .ok()
+                        .filter(|&address| address >= 1)
+                        .ok_or_else(|| {
+                            EphemerisError::Format(format!("invalid segment address {}", value))
+                        })
+                };
+
+                let name_offset = base + RECORD_BYTES + i * name_bytes;
+                let name = self
+                    .data
+                    .get(name_offset..name_offset + name_bytes)
+                    .map(|bytes| String::from_utf8_lossy(bytes).trim_end().to_string())
+                    .unwrap_or_default();
+
+                segments.push(SpkSegment {
+                    name,
+                    target: Body::from_naif_id(int(0)?),
+                    center: Body::from_naif_id(int(1)?),
+                    frame: int(2)?,
+                    data_type: int(3)?,
+                    start_et,
+                    end_et,
+                    start_address: address(4)?,
+                    end_address: address(5)?,
+                });
+            }
+
+            record = next;
+        }
+
+        Ok(segments)
+    }
+
+    /// The segment with highest priority (latest in the file) for `target` at `et`
+    fn segment_for(&self, target: Body, et: f64) -> Option<&SpkSegment> {
+        self.segments
+            .iter()
+            .rev()
+            .find(|segment| segment.target == target && segment.covers(et))
+    }
+
+    /// State of `target` relative to every center along its segment chain
+    ///
+    /// The first entry is `target` itself with a zero state.
+    fn chain(&self, target: Body, et: f64) -> Result<Vec<ChainLink>, EphemerisError> {
+        let mut chain = vec![(target, Vector3::zeros(), Vector3::zeros())];
+        let mut body = target;
+        while let Some(segment) = self.segment_for(body, et) {
+            if chain.len() > MAX_CHAIN {
+                return Err(EphemerisError::Format(format!(
+                    "segment chain from {} does not terminate",
+                    target
+                )));
+            }
+            let (r, v) = self.segment_state(segment, et)?;
+            let (_, r_acc, v_acc) = chain[chain.len() - 1];
+            body = segment.center;
+            chain.push((body, r_acc + r, v_acc + v));
+        }
+        Ok(chain)
+    }
+
+    /// `body` followed by the centers of its segment chain, as resolved by
+    /// the highest-priority segments
+    fn chain_bodies(&self, body: Body) -> Vec<Body> {
+        let mut bodies = vec![body];
+        while bodies.len() <= MAX_CHAIN {
+            let last = bodies[bodies.len() - 1];
+            match self
+                .segments
+                .iter()
+                .rev()
+                .find(|segment| segment.target == last)
+            {
+                Some(segment) => bodies.push(segment.center),
+                None => break,
+            }
+        }
+        bodies
+    }
+
+    /// Whether the kernel mentions `body` as a target or center
+    fn knows(&self, body: Body) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| segment.target == body || segment.center == body)
+    }
+
+    /// Evaluate one segment at `et` (m, m/s)
+    fn segment_state(
+        &self,
+        segment: &SpkSegment,
+        et: f64,
+    ) -> Result<(Vector3<f64>, Vector3<f64>), EphemerisError> {
+        if segment.frame != FRAME_J2000 {
+            return Err(EphemerisError::Format(format!(
+                "segment '{}' uses reference frame {}; only J2000 (1) is supported",
+                segment.name, segment.frame
+            )));
+        }
+        let components = match segment.data_type {
+            2 => 3,
+            3 => 6,
+            other => {
+                return Err(EphemerisError::Format(format!(
+                    "segment '{}' has SPK type {}; only types 2 and 3 are supported",
+                    segment.name, other
+                )))
+            }
+        };
+
+        // Directory at the end of the segment: INIT, INTLEN, RSIZE, N
+        let directory = segment.end_address.checked_sub(3).ok_or_else(|| {
+            EphemerisError::Format(format!(
+                "segment '{}' ends at address {}, before its directory",
+                segment.name, segment.end_address
+            ))
+        })?;
+        let init = self.read_double(directory)?;
+        let interval = self.read_double(directory + 1)?;
+        let record_size = self.read_double(directory + 2)? as usize;
+        let n_records = self.read_double(directory + 3)? as usize;
+        if interval <= 0.0
+            || n_records == 0
+            || record_size <= 2
+            || (record_size - 2) % components != 0
+        {
+            return Err(EphemerisError::Format(format!(
+                "segment '{}' has an invalid Chebyshev directory",
+                segment.name
+            )));
+        }
+
+        let index = (((et - init) / interval).floor().max(0.0) as usize).min(n_records - 1);
+        let record = index
+            .checked_mul(record_size)
+            .and_then(|offset| segment.start_address.checked_add(offset))
+            .filter(|&record| {
+                record
+                    .checked_add(record_size)
+                    .is_some_and(|record_end| record_end <= directory)
+            })
+            .ok_or_else(|| {
+                EphemerisError::Format(format!(
+                    "segment '{}' record {} lies outside addresses {}..{}",
+                    segment.name, index, segment.start_address, segment.end_address
+                ))
+            })?;
+        let mid = self.read_double(record)?;
+        let radius = self.read_double(record + 1)?;
+        let x = (et - mid) / radius;
+        let n_coeffs = (record_size - 2) / components;
+
+        let mut position = Vector3::zeros();
+        let mut velocity = Vector3::zeros();
+        for axis in 0..3 {
+            let coeffs = self.read_doubles(record + 2 + axis * n_coeffs, n_coeffs)?;
+            let (value, derivative) = chebyshev(&coeffs, x);
+            position[axis] = value;
+            velocity[axis] = if components == 3 {
+                derivative / radius
+            } else {
+                let coeffs = self.read_doubles(record + 2 + (axis + 3) * n_coeffs, n_coeffs)?;
+                chebyshev(&coeffs, x).0
+            };
+        }
+
+        Ok((position * KM, velocity * KM))
+    }
+
+    /// Double at a 1-based DAF word address
+    fn read_double(&self, address: usize) -> Result<f64, EphemerisError> {
+        let offset = address
+            .checked_sub(1)
+            .and_then(|index| index.checked_mul(8))
+            .ok_or_else(|| EphemerisError::Format(format!("invalid DAF address {}", address)))?;
+        self.read_f64_at(offset)
+    }
+
+    /// `count` consecutive doubles starting at a 1-based DAF word address
+    fn read_doubles(&self, address: usize, count: usize) -> Result<Vec<f64>, EphemerisError> {
+        (0..count)
+            .map(|k| {
+                let address = address.checked_add(k).ok_or_else(|| {
+                    EphemerisError::Format(format!("invalid DAF address {} + {}", address, k))
+                })?;
+                self.read_double(address)
+            })
+            .collect()
+    }
+
+    fn read_f64_at(&self, offset: usize) -> Result<f64, EphemerisError> {
+        let bytes: [u8; 8] = self
+            .data
+            .get(offset..offset.saturating_add(8))
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or_else(|| self.truncated(offset))?;
+        Ok(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_i32(&self, offset: usize) -> Result<i32, EphemerisError> {
+        let bytes: [u8; 4] = self
+            .data
+            .get(offset..offset.saturating_add(4))
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or_else(|| self.truncated(offset))?;
+        Ok(if self.little_endian {
+            i32::from_le_bytes(bytes)
+        } else {
+            i32::from_be_bytes(bytes)
+        })
+    }
+
+    fn truncated(&self, offset: usize) -> EphemerisError {
+        EphemerisError::Format(format!(
+            "'{}' is truncated (read at byte {} of {})",
+            self.name,
+            offset,
+            self.data.len()
+        ))
+    }
+}
+
+impl EphemerisSource for SpkFile {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn state(
+        &self,
+        target: Body,
+        center: Body,
+        epoch: &Epoch,
+    ) -> Result<(Vector3<f64>, Vector3<f64>), EphemerisError> {
+        for body in [target, center] {
+            if !self.knows(body) {
+                return Err(EphemerisError::UnsupportedBody {
+                    body,
+                    source_name: self.name.clone(),
+                });
+            }
+        }
+
+        let et = epoch.to_tdb_seconds_since_j2000();
+        let target_chain = self.chain(target, et)?;
+        let center_chain = self.chain(center, et)?;
+
+        for (node, r_target, v_target) in &target_chain {
+            if let Some((_, r_center, v_center)) =
+                center_chain.iter().find(|(body, _, _)| body == node)
+            {
+                return Ok((r_target - r_center, v_target - v_center));
+            }
+        }
+
+        Err(EphemerisError::OutOfRange {
+            body: target,
+            center,
+            tdb_seconds: et,
+        })
+    }
+
+    /// Overlap of the coverage of every segment target on the chains from
+    /// `target` and `center` to the body where they meet
+    ///
+    /// `None` if the chains do not meet, in which case [`Self::state`]
+    /// fails at every epoch.
+    fn time_span(&self, target: Body, center: Body) -> Option<(f64, f64)> {
+        let target_chain = self.chain_bodies(target);
+        let center_chain = self.chain_bodies(center);
+        let (i, j) = target_chain.iter().enumerate().find_map(|(i, body)| {
+            center_chain
+                .iter()
+                .position(|center| center == body)
+                .map(|j| (i, j))
+        })?;
+
+        target_chain[..i]
+            .iter()
+            .chain(&center_chain[..j])
+            .filter_map(|&body| self.coverage(body))
+            .fold(None, |span, (start, end)| match span {
+                None => Some((start, end)),
+                Some((span_start, span_end)) => Some((span_start.max(start), span_end.min(end))),
+            })
+    }
+}
+
+/// Chebyshev series Σ cₖ Tₖ(x) and its derivative with respect to x
+fn chebyshev(coeffs: &[f64], x: f64) -> (f64, f64) {
+    let mut value = 0.0;
+    let mut derivative = 0.0;
+
+    // Tₖ₋₂, Tₖ₋₁ and their derivatives
+    let (mut t_prev, mut t_curr) = (1.0, x);
+    let (mut dt_prev, mut dt_curr) = (0.0, 1.0);
+
+    for (k, c) in coeffs.iter().enumerate() {
+        match k {
+            0 => value += c,
+            1 => {
+                value += c * x;
+                derivative += c;
+            }
+            _ => {
+                let t_next = 2.0 * x * t_curr - t_prev;
+                let dt_next = 2.0 * t_curr + 2.0 * x * dt_curr - dt_prev;
+                value += c * t_next;
+                derivative += c * dt_next;
+                t_prev = t_curr;
+                t_curr = t_next;
+                dt_prev = dt_curr;
+                dt_curr = dt_next;
+            }
+        }
+    }
+
+    (value, derivative)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    /// A segment to write into a test kernel
+    struct TestSegment {
+        target: i32,
+        center: i32,
+        data_type: i32,
+        init: f64,
+        interval: f64,
+        /// Per record: Chebyshev coefficients per component (3 or 6 components)
+        records: Vec<Vec<Vec<f64>>>,
+    }
+
+    /// Write a minimal SPK kernel: file record, one summary record, one name
+    /// record and the segment data
+    fn build_spk(segments: &[TestSegment], little_endian: bool) -> Vec<u8> {
+        let put_f64 = |buf: &mut Vec<u8>, value: f64| {
+            buf.extend_from_slice(&if little_endian {
+                value.to_le_bytes()
+            } else {
+                value.to_be_bytes()
+            })
+        };
+        let put_i32 = |buf: &mut Vec<u8>, value: i32| {
+            buf.extend_from_slice(&if little_endian {
+                value.to_le_bytes()
+            } else {
+                value.to_be_bytes()
+            })
+        };
+
+        // Segment data starts in record 4 (word address 385)
+        let mut words = Vec::new();
+        let mut summaries = Vec::new();
+        let mut address = 3 * 128 + 1;
+        for segment in segments {
+            let start = address;
+            let n_coeffs = segment.records[0][0].len();
+            let components = segment.records[0].len();
+            let record_size = 2 + components * n_coeffs;
+            for (i, record) in segment.records.iter().enumerate() {
+                words.push(segment.init + (i as f64 + 0.5) * segment.interval);
+                words.push(segment.interval / 2.0);
+                for component in record {
+                    words.extend_from_slice(component);
+                }
+            }
+            words.extend_from_slice(&[
+                segment.init,
+                segment.interval,
+                record_size as f64,
+                segment.records.len() as f64,
+            ]);
+            address = start + segment.records.len() * record_size + 4;
+            summaries.push((segment, start, address - 1));
+        }
+
+        let mut file = Vec::new();
+        file.extend_from_slice(b"DAF/SPK ");
+        put_i32(&mut file, 2);
+        put_i32(&mut file, 6);
+        file.extend_from_slice(&[b' '; 60]);
+        put_i32(&mut file, 2);
+        put_i32(&mut file, 2);
+        put_i32(&mut file, address as i32);
+        file.extend_from_slice(if little_endian {
+            b"LTL-IEEE"
+        } else {
+            b"BIG-IEEE"
+        });
+        file.resize(RECORD_BYTES, 0);
+
+        put_f64(&mut file, 0.0);
+        put_f64(&mut file, 0.0);
+        put_f64(&mut file, summaries.len() as f64);
+        for (segment, start, end) in &summaries {
+            let n = segment.records.len() as f64;
+            put_f64(&mut file, segment.init);
+            put_f64(&mut file, segment.init + n * segment.interval);
+            for value in [
+                segment.target,
+                segment.center,
+                FRAME_J2000,
+                segment.data_type,
+            ] {
+                put_i32(&mut file, value);
+            }
+            put_i32(&mut file, *start as i32);
+            put_i32(&mut file, *end as i32);
+        }
+        file.resize(2 * RECORD_BYTES, 0);
+
+        for (segment, _, _) in &summaries {
+            let mut name = format!("TEST {} WRT {}", segment.target, segment.center).into_bytes();
+            name.resize(40, b' ');
+            file.extend_from_slice(&name);
+        }
+        file.resize(3 * RECORD_BYTES, 0);
+
+        for word in words {
+            put_f64(&mut file, word);
+        }
+        file
+    }
+
+    const DAY: f64 = 86400.0;
+
+    /// EMB (3) relative to SSB (0): type 2, two one-day records, degree 2
+    fn emb_segment() -> TestSegment {
+        TestSegment {
+            target: 3,
+            center: 0,
+            data_type: 2,
+            init: -DAY,
+            interval: DAY,
+            records: vec![
+                vec![
+                    vec![1.0e8, 2.0e3, 3.0e1],
+                    vec![-5.0e7, 1.0e3, -2.0e1],
+                    vec![2.0e7, -4.0e2, 1.0e1],
+                ],
+                vec![
+                    vec![1.1e8, 2.5e3, 3.5e1],
+                    vec![-4.5e7, 1.5e3, -2.5e1],
+                    vec![2.5e7, -4.5e2, 1.5e1],
+                ],
+            ],
+        }
+    }
+
+    /// Earth (399) relative to EMB (3): type 3, one two-day record
+    fn earth_segment() -> TestSegment {
+        TestSegment {
+            target: 399,
+            center: 3,
+            data_type: 3,
+            init: -DAY,
+            interval: 2.0 * DAY,
+            records: vec![vec![
+                vec![4.0e3, 10.0, 1.0],
+                vec![-3.0e3, 5.0, -1.0],
+                vec![1.0e3, -2.0, 0.5],
+                vec![1.0e-2, 1.0e-4, 0.0],
+                vec![2.0e-2, -1.0e-4, 0.0],
+                vec![-1.0e-2, 0.0, 1.0e-5],
+            ]],
+        }
+    }
+
+    /// Moon (301) relative to EMB (3): type 2, one two-day record
+    fn moon_segment() -> TestSegment {
+        TestSegment {
+            target: 301,
+            center: 3,
+            data_type: 2,
+            init: -DAY,
+            interval: 2.0 * DAY,
+            records: vec![vec![
+                vec![-3.0e5, 100.0, 2.0],
+                vec![2.5e5, -80.0, 1.0],
+                vec![-1.0e5, 30.0, -0.5],
+            ]],
+        }
+    }
+
+    fn test_kernel(little_endian: bool) -> SpkFile {
+        let bytes = build_spk(
+            &[emb_segment(), earth_segment(), moon_segment()],
+            little_endian,
+        );
+        SpkFile::from_bytes(bytes, "test.bsp").unwrap()
+    }
+
+    /// Degree-2 Chebyshev value and derivative evaluated directly
+    fn cheb2(c: &[f64], x: f64) -> (f64, f64) {
+        (
+            c[0] + c[1] * x + c[2] * (2.0 * x * x - 1.0),
+            c[1] + 4.0 * c[2] * x,
+        )
+    }
+
+    fn epoch_at(et: f64) -> Epoch {
+        Epoch::from_tdb_seconds_since_j2000(et)
+    }
+
+    #[test]
+    fn test_chebyshev_matches_explicit_polynomials() {
+        let coeffs = [0.5, -1.0, 2.0, 0.25, -0.125];
+        for &x in &[-1.0_f64, -0.3, 0.0, 0.7, 1.0] {
+            let t3 = 4.0 * x * x * x - 3.0 * x;
+            let t4 = 8.0 * x.powi(4) - 8.0 * x * x + 1.0;
+            let value = 0.5 - x + 2.0 * (2.0 * x * x - 1.0) + 0.25 * t3 - 0.125 * t4;
+            let derivative = -1.0 + 8.0 * x + 0.25 * (12.0 * x * x - 3.0)
+                - 0.125 * (32.0 * x.powi(3) - 16.0 * x);
+
+            let (v, d) = chebyshev(&coeffs, x);
+            assert_relative_eq!(v, value, epsilon = 1e-12);
+            assert_relative_eq!(d, derivative, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_segment_summaries() {
+        let spk = test_kernel(true);
+        let segments = spk.segments();
+
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0].target, Body::EarthMoonBarycenter);
+        assert_eq!(segments[0].center, Body::SolarSystemBarycenter);
+        assert_eq!(segments[0].data_type, 2);
+        assert_eq!(segments[1].target, Body::Earth);
+        assert_eq!(segments[1].data_type, 3);
+        assert_eq!(segments[2].name, "TEST 301 WRT 3");
+        assert_eq!(segments[0].start_et, -DAY);
+        assert_eq!(segments[0].end_et, DAY);
+        assert_eq!(spk.coverage(Body::Moon), Some((-DAY, DAY)));
+        assert_eq!(spk.coverage(Body::Mars), None);
+        assert_eq!(
+            spk.time_span(Body::Moon, Body::SolarSystemBarycenter),
+            Some((-DAY, DAY))
+        );
+    }
+
+    #[test]
+    fn test_time_span_follows_the_segment_chain() {
+        // Earth and Moon relative to the EMB over four days, the EMB relative
+        // to the SSB over two
+        let four_days = |segment: TestSegment| TestSegment {
+            init: -2.0 * DAY,
+            interval: 4.0 * DAY,
+            ..segment
+        };
+        let bytes = build_spk(
+            &[
+                emb_segment(),
+                four_days(earth_segment()),
+                four_days(moon_segment()),
+            ],
+            true,
+        );
+        let spk = SpkFile::from_bytes(bytes, "chain.bsp").unwrap();
+
+        // The EMB segment lies on the chain from the Moon to the SSB only
+        assert_eq!(
+            spk.time_span(Body::Moon, Body::SolarSystemBarycenter),
+            Some((-DAY, DAY))
+        );
+        assert_eq!(
+            spk.time_span(Body::Moon, Body::Earth),
+            Some((-2.0 * DAY, 2.0 * DAY))
+        );
+        assert!(spk
+            .state(Body::Moon, Body::Earth, &epoch_at(1.5 * DAY))
+            .is_ok());
+        assert!(spk
+            .state(
+                Body::Moon,
+                Body::SolarSystemBarycenter,
+                &epoch_at(1.5 * DAY)
+            )
+            .is_err());
+
+        // Bodies without a common node have no span
+        assert_eq!(spk.time_span(Body::Moon, Body::Mars), None);
+    }
+
+    #[test]
+    fn test_type2_position_and_velocity() {
+        let spk = test_kernel(true);
+        let segment = emb_segment();
+
+        // Second record: midpoint DAY/2, radius DAY/2
+        let et = 0.8 * DAY;
+        let x = (et - 0.5 * DAY) / (0.5 * DAY);
+        let (r, v) = spk
+            .state(
+                Body::EarthMoonBarycenter,
+                Body::SolarSystemBarycenter,
+                &epoch_at(et),
+            )
+            .unwrap();
+
+        for axis in 0..3 {
+            let (value, derivative) = cheb2(&segment.records[1][axis], x);
+            assert_relative_eq!(r[axis], value * KM, max_relative = 1e-9);
+            assert_relative_eq!(v[axis], derivative / (0.5 * DAY) * KM, max_relative = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_type2_velocity_matches_finite_difference() {
+        let spk = test_kernel(true);
+        let body = Body::EarthMoonBarycenter;
+        let center = Body::SolarSystemBarycenter;
+        let et = -0.4 * DAY;
+        let h = 10.0;
+
+        let (_, v) = spk.state(body, center, &epoch_at(et)).unwrap();
+        let (r_plus, _) = spk.state(body, center, &epoch_at(et + h)).unwrap();
+        let (r_minus, _) = spk.state(body, center, &epoch_at(et - h)).unwrap();
+        let v_fd = (r_plus - r_minus) / (2.0 * h);
+
+        assert_relative_eq!(v, v_fd, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_type3_uses_velocity_coefficients() {
+        let spk = test_kernel(true);
+        let segment = earth_segment();
+        let et = 0.3 * DAY;
+        let x = et / DAY;
+
+        let (r, v) = spk
+            .state(Body::Earth, Body::EarthMoonBarycenter, &epoch_at(et))
+            .unwrap();
+
+        for axis in 0..3 {
+            assert_relative_eq!(
+                r[axis],
+                cheb2(&segment.records[0][axis], x).0 * KM,
+                max_relative = 1e-9
+            );
+            assert_relative_eq!(
+                v[axis],
+                cheb2(&segment.records[0][axis + 3], x).0 * KM,
+                max_relative = 1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn test_chained_states() {
+        let spk = test_kernel(true);
+        let epoch = epoch_at(0.25 * DAY);
+
+        let (r_emb, v_emb) = spk
+            .state(
+                Body::EarthMoonBarycenter,
+                Body::SolarSystemBarycenter,
+                &epoch,
+            )
+            .unwrap();
+        let (r_earth_emb, v_earth_emb) = spk
+            .state(Body::Earth, Body::EarthMoonBarycenter, &epoch)
+            .unwrap();
+        let (r_moon_emb, v_moon_emb) = spk
+            .state(Body::Moon, Body::EarthMoonBarycenter, &epoch)
+            .unwrap();
+
+        // Earth relative to the SSB goes through the EMB
+        let (r_earth, v_earth) = spk
+            .state(Body::Earth, Body::SolarSystemBarycenter, &epoch)
+            .unwrap();
+        assert_relative_eq!(r_earth, r_emb + r_earth_emb, epsilon = 1e-6);
+        assert_relative_eq!(v_earth, v_emb + v_earth_emb, epsilon = 1e-9);
+
+        // Moon relative to the Earth meets at the common center (EMB)
+        let (r_moon, v_moon) = spk.state(Body::Moon, Body::Earth, &epoch).unwrap();
+        assert_relative_eq!(r_moon, r_moon_emb - r_earth_emb, epsilon = 1e-6);
+        assert_relative_eq!(v_moon, v_moon_emb - v_earth_emb, epsilon = 1e-9);
+
+        // And the reverse query is the negation
+        let (r_ssb, _) = spk
+            .state(Body::SolarSystemBarycenter, Body::Earth, &epoch)
+            .unwrap();
+        assert_relative_eq!(r_ssb, -r_earth, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_big_endian_kernel() {
+        let little = test_kernel(true);
+        let big = test_kernel(false);
+        let epoch = epoch_at(-0.6 * DAY);
+
+        assert_eq!(
+            little.state(Body::Moon, Body::Earth, &epoch).unwrap(),
+            big.state(Body::Moon, Body::Earth, &epoch).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let spk = test_kernel(true);
+
+        let unsupported = spk.state(Body::Mars, Body::SolarSystemBarycenter, &epoch_at(0.0));
+        assert!(matches!(
+            unsupported,
+            Err(EphemerisError::UnsupportedBody {
+                body: Body::Mars,
+                ..
+            })
+        ));
+
+        let out_of_range = spk.state(
+            Body::Moon,
+            Body::SolarSystemBarycenter,
+            &epoch_at(3.0 * DAY),
+        );
+        assert!(matches!(
+            out_of_range,
+            Err(EphemerisError::OutOfRange { .. })
+        ));
+
+        let not_spk = SpkFile::from_bytes(vec![0u8; RECORD_BYTES], "zeros");
+        assert!(matches!(not_spk, Err(EphemerisError::Format(_))));
+
+        let mut truncated = build_spk(&[emb_segment()], true);
+        truncated.truncate(3 * RECORD_BYTES + 16);
+        let truncated = SpkFile::from_bytes(truncated, "truncated.bsp").unwrap();
+        assert!(matches!(
+            truncated.state(
+                Body::EarthMoonBarycenter,
+                Body::SolarSystemBarycenter,
+                &epoch_at(0.0)
+            ),
+            Err(EphemerisError::Format(_))
+        ));
+
+        // Negative first summary record
+        let mut bad_chain = build_spk(&[emb_segment()], true);
+        bad_chain[76..80].copy_from_slice(&(-1i32).to_le_bytes());
+        assert!(matches!(
+            SpkFile::from_bytes(bad_chain, "bad_chain.bsp"),
+            Err(EphemerisError::Format(_))
+        ));
+
+        // Negative segment addresses
+        let start_offset = RECORD_BYTES + 24 + 16 + 4 * 4;
+        for offset in [start_offset, start_offset + 4] {
+            let mut negative = build_spk(&[emb_segment()], true);
+            negative[offset..offset + 4].copy_from_slice(&(-1i32).to_le_bytes());
+            assert!(matches!(
+                SpkFile::from_bytes(negative, "negative.bsp"),
+                Err(EphemerisError::Format(_))
+            ));
+        }
+
+        // Segment data outside the file or past the directory
+        for start in [i32::MAX, 1_000] {
+            let mut bad_start = build_spk(&[emb_segment()], true);
+            bad_start[start_offset..start_offset + 4].copy_from_slice(&start.to_le_bytes());
+            let bad_start = SpkFile::from_bytes(bad_start, "bad_start.bsp").unwrap();
+            assert!(matches!(
+                bad_start.state(
+                    Body::EarthMoonBarycenter,
+                    Body::SolarSystemBarycenter,
+                    &epoch_at(0.0)
+                ),
+                Err(EphemerisError::Format(_))
+            ));
+        }
+
+        // Segment end address too small to hold the directory
+        let mut bad_end = build_spk(&[emb_segment()], true);
+        let end_offset = RECORD_BYTES + 24 + 16 + 5 * 4;
+        bad_end[end_offset..end_offset + 4].copy_from_slice(&2i32.to_le_bytes());
+        let bad_end = SpkFile::from_bytes(bad_end, "bad_end.bsp").unwrap();
+        assert!(matches!(
+            bad_end.state(
+                Body::EarthMoonBarycenter,
+                Body::SolarSystemBarycenter,
+                &epoch_at(0.0)
+            ),
+            Err(EphemerisError::Format(_))
+        ));
+    }
+
+    #[test]
+    fn test_open_from_disk() {
+        let path =
+            std::env::temp_dir().join(format!("astrora_spk_test_{}.bsp", std::process::id()));
+        std::fs::write(&path, build_spk(&[emb_segment()], true)).unwrap();
+        let spk = SpkFile::open(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(spk.segments().len(), 1);
+        assert!(spk.name().ends_with(".bsp"));
+
+        let missing = SpkFile::open(&path).unwrap_err();
+        assert!(matches!(missing, EphemerisError::Io(_)));
+    }
+}
@@ -0,0 +1,618 @@
+//! Weighted batch least-squares orbit determination
+//!
+//! [`BatchLeastSquares`] estimates the state of a [`NumericalPropagator`] at
+//! its current epoch from a set of [`Measurement`]s (Tapley, Schutz & Born,
+//! *Statistical Orbit Determination*, Algorithm 4.6.1). Each iteration
+//! propagates the reference trajectory with the state transition matrix
+//! (see [`NumericalPropagator::propagate_with_stm`]), maps the measurement
+//! partials to the epoch, H̃ₖ = Hₖ·Φ(tₖ, t₀), and accumulates the normal
+//! equations
+//!
+//! ```text
+//! (P̄₀⁻¹ + Σ H̃ₖᵀ Wₖ H̃ₖ) δx = P̄₀⁻¹ (x̄₀ − x₀) + Σ H̃ₖᵀ Wₖ yₖ
+//! ```
+//!
+//! with Wₖ = diag(1/σ²) and yₖ the observed-minus-computed residuals. The
+//! optional a priori covariance P̄₀ ties the solution to the initial state
+//! x̄₀. Iterations stop when the weighted RMS of the residuals settles; the
+//! inverse of the information matrix at the solution is the formal
+//! covariance.
+//!
+//! Measurements may lie before and after the epoch, in any order.
+//!
+//! # Example
+//! ```rust,ignore
+//! use astrora_core::estimation::{BatchLeastSquares, Measurement};
+//!
+//! let mut propagator = NumericalPropagator::new(r_guess, v_guess, epoch, GM_EARTH);
+//! propagator.add_perturbation(J2Perturbation::earth());
+//!
+//! let solution = BatchLeastSquares::new().solve(&mut propagator, &measurements)?;
+//! println!("{} iterations, RMS {:.3}", solution.iterations, solution.rms);
+//! let sigma = solution.covariance.std_devs();
+//! ```
+
+use nalgebra::{DMatrix, DVector};
+use numpy::PyArray1;
+use pyo3::prelude::*;
+
+use crate::core::covariance::Covariance6;
+use crate::core::error::{PoliastroError, PoliastroResult};
+use crate::core::linalg::{Matrix6, Vector3, Vector6};
+use crate::core::time::Epoch;
+use crate::estimation::measurements::{Measurement, MeasurementType};
+use crate::propagators::propagator::NumericalPropagator;
+
+/// Default maximum number of iterations
+pub const DEFAULT_MAX_ITERATIONS: usize = 20;
+
+/// Default convergence tolerance on the change of the weighted RMS,
+/// relative to the previous RMS or 1, whichever is larger
+pub const DEFAULT_RMS_TOLERANCE: f64 = 1e-3;
+
+/// Post-fit residual of one measurement
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone)]
+pub struct Residual {
+    /// Time tag of the measurement
+    pub epoch: Epoch,
+    /// Measurement type
+    pub kind: MeasurementType,
+    /// Observing station, if any
+    pub station: Option<String>,
+    /// Observed minus computed values
+    pub values: DVector<f64>,
+    /// Residuals divided by the measurement sigmas
+    pub normalized: DVector<f64>,
+}
+
+/// Result of a batch least-squares fit
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone)]
+pub struct BatchSolution {
+    /// Estimation epoch
+    pub epoch: Epoch,
+    /// Estimated position (m)
+    pub position: Vector3,
+    /// Estimated velocity (m/s)
+    pub velocity: Vector3,
+    /// Formal covariance of the estimate
+    pub covariance: Covariance6,
+    /// Residuals at the estimate, in measurement order
+    pub residuals: Vec<Residual>,
+    /// Number of state corrections applied
+    pub iterations: usize,
+    /// Whether the RMS settled within the iteration limit
+    pub converged: bool,
+    /// Weighted RMS of the residuals at the estimate
+    pub rms: f64,
+    /// Weighted RMS before each correction, then at the estimate
+    pub rms_history: Vec<f64>,
+}
+
+/// Weighted batch least-squares estimator
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone)]
+pub struct BatchLeastSquares {
+    max_iterations: usize,
+    tolerance: f64,
+    a_priori: Option<Covariance6>,
+}
+
+impl Default for BatchLeastSquares {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Normal equations accumulated over all measurements
+struct NormalEquations {
+    information: Matrix6,
+    right_hand_side: Vector6,
+    residuals: Vec<Residual>,
+    weighted_sum: f64,
+    count: usize,
+}
+
+impl NormalEquations {
+    fn rms(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        (self.weighted_sum / self.count as f64).sqrt()
+    }
+}
+
+impl BatchLeastSquares {
+    /// Create an estimator without a priori information
+    pub fn new() -> Self {
+        Self {
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            tolerance: DEFAULT_RMS_TOLERANCE,
+            a_priori: None,
+        }
+    }
+
+    /// Set the maximum number of iterations
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Set the tolerance on the change of the weighted RMS
+    ///
+    /// Relative to the previous RMS or 1, whichever is larger, so a fit down
+    /// to the integration noise of exact measurements also converges.
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Constrain the solution to the initial state with this covariance
+    pub fn with_a_priori(mut self, covariance: Covariance6) -> Self {
+        self.a_priori = Some(covariance);
+        self
+    }
+
+    /// Estimate the state at the propagator's epoch
+    ///
+    /// The propagator's state is the initial guess (and the a priori state);
+    /// its force model is used for the reference trajectory. On success the
+    /// propagator is left at the estimated state.
+    ///
+    /// # Errors
+    /// Returns an error if there are fewer measurement values than states,
+    /// the normal equations are singular (unobservable state), or a
+    /// propagation or measurement model fails
+    pub fn solve(
+        &self,
+        propagator: &mut NumericalPropagator,
+        measurements: &[Measurement],
+    ) -> PoliastroResult<BatchSolution> {
+        let count: usize = measurements.iter().map(Measurement::dimension).sum();
+        if count < 6 && self.a_priori.is_none() {
+            return Err(PoliastroError::invalid_state(format!(
+                "Batch least squares needs at least 6 measurement values, got {count}"
+            )));
+        }
+        let a_priori_information = match &self.a_priori {
+            Some(covariance) => Some(invert(&covariance.matrix(), "a priori covariance")?),
+            None => None,
+        };
+
+        let epoch = propagator.epoch();
+        let a_priori_state = state_vector(&propagator.position(), &propagator.velocity());
+        let mut state = a_priori_state;
+        let mut rms_history: Vec<f64> = Vec::new();
+        let mut iterations = 0;
+
+        loop {
+            let (r, v) = split(&state);
+            propagator.set_state(r, v, epoch);
+            let mut normal = accumulate(propagator, epoch, measurements)?;
+            propagator.set_state(r, v, epoch);
+            if let Some(information) = &a_priori_information {
+                normal.information += information;
+                normal.right_hand_side += information * (a_priori_state - state);
+            }
+
+            let rms = normal.rms();
+            let converged = rms_history.last().is_some_and(|&previous| {
+                (previous - rms).abs() <= self.tolerance * previous.max(1.0)
+            });
+            rms_history.push(rms);
+
+            let covariance = invert(&normal.information, "batch least-squares normal equations")?;
+            if converged || iterations == self.max_iterations {
+                return Ok(BatchSolution {
+                    epoch,
+                    position: r,
+                    velocity: v,
+                    covariance: Covariance6::new((covariance + covariance.transpose()) * 0.5)?,
+                    residuals: normal.residuals,
+                    iterations,
+                    converged,
+                    rms,
+                    rms_history,
+                });
+            }
+
+            state += covariance * normal.right_hand_side;
+            iterations += 1;
+        }
+    }
+}
+
+/// Residuals and normal equations of the trajectory from the propagator's
+/// state at `epoch`
+///
+/// Measurements after the epoch are processed forwards and those before it
+/// backwards, chaining the STM from one measurement epoch to the next.
+fn accumulate(
+    propagator: &mut NumericalPropagator,
+    epoch: Epoch,
+    measurements: &[Measurement],
+) -> PoliastroResult<NormalEquations> {
+    let t0 = epoch.to_tdb_seconds_since_j2000();
+    let (r0, v0) = (propagator.position(), propagator.velocity());
+    let times: Vec<f64> = measurements
+        .iter()
+        .map(|m| m.epoch.to_tdb_seconds_since_j2000() - t0)
+        .collect();
+    let mut order: Vec<usize> = (0..measurements.len()).collect();
+    order.sort_by(|&a, &b| times[a].total_cmp(&times[b]));
+    let split_at = order.partition_point(|&i| times[i] < 0.0);
+    let (before, after) = order.split_at(split_at);
+
+    let mut normal = NormalEquations {
+        information: Matrix6::zeros(),
+        right_hand_side: Vector6::zeros(),
+        residuals: Vec::with_capacity(measurements.len()),
+        weighted_sum: 0.0,
+        count: 0,
+    };
+    let mut residuals = vec![None; measurements.len()];
+
+    let forward = after.to_vec();
+    let backward: Vec<usize> = before.iter().rev().copied().collect();
+    for leg in [forward, backward] {
+        propagator.set_state(r0, v0, epoch);
+        let mut stm = Matrix6::identity();
+        let mut t = 0.0;
+        for index in leg {
+            let measurement = &measurements[index];
+            if times[index] != t {
+                let segment = propagator.propagate_with_stm(measurement.epoch, &[])?;
+                stm = segment.stm * stm;
+                t = times[index];
+            }
+            let (r, v) = (propagator.position(), propagator.velocity());
+            let (predicted, partials) = measurement.predict(&r, &v)?;
+            let residual = measurement.residual(&predicted);
+
+            let weights = measurement.sigmas.map(|sigma| 1.0 / (sigma * sigma));
+            let mapped = partials * DMatrix::from_column_slice(6, 6, stm.as_slice());
+            let weighted = mapped.transpose() * DMatrix::from_diagonal(&weights);
+            let information = &weighted * &mapped;
+            let right_hand_side = &weighted * &residual;
+            normal.information += Matrix6::from_column_slice(information.as_slice());
+            normal.right_hand_side += Vector6::from_column_slice(right_hand_side.as_slice());
+
+            let normalized = residual.component_div(&measurement.sigmas);
+            normal.weighted_sum += normalized.norm_squared();
+            normal.count += measurement.dimension();
+            residuals[index] = Some(Residual {
+                epoch: measurement.epoch,
+                kind: measurement.kind,
+                station: measurement.station_name().map(str::to_string),
+                values: residual,
+                normalized,
+            });
+        }
+    }
+
+    normal.residuals = residuals.into_iter().flatten().collect();
+    Ok(normal)
+}
+
+fn invert(matrix: &Matrix6, context: &str) -> PoliastroResult<Matrix6> {
+    matrix
+        .cholesky()
+        .map(|cholesky| cholesky.inverse())
+        .ok_or_else(|| PoliastroError::SingularMatrix {
+            context: context.to_string(),
+            determinant: matrix.determinant(),
+        })
+}
+
+fn state_vector(r: &Vector3, v: &Vector3) -> Vector6 {
+    Vector6::new(r.x, r.y, r.z, v.x, v.y, v.z)
+}
+
+fn split(state: &Vector6) -> (Vector3, Vector3) {
+    (
+        state.fixed_rows::<3>(0).into_owned(),
+        state.fixed_rows::<3>(3).into_owned(),
+    )
+}
+
+#[pymethods]
+impl Residual {
+    /// Time tag of the measurement
+    #[getter]
+    fn get_epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Measurement type name
+    #[getter]
+    fn get_kind(&self) -> &'static str {
+        self.kind.name()
+    }
+
+    /// Observing station name (None for GNSS)
+    #[getter]
+    fn get_station(&self) -> Option<String> {
+        self.station.clone()
+    }
+
+    /// Observed minus computed values
+    #[getter]
+    fn get_values<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.values.as_slice())
+    }
+
+    /// Residuals divided by the measurement sigmas
+    #[getter]
+    fn get_normalized<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.normalized.as_slice())
+    }
+}
+
+#[pymethods]
+impl BatchSolution {
+    /// Estimation epoch
+    #[getter]
+    fn get_epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Estimated position (m)
+    #[getter]
+    fn get_position<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.position.as_slice())
+    }
+
+    /// Estimated velocity (m/s)
+    #[getter]
+    fn get_velocity<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.velocity.as_slice())
+    }
+
+    /// Formal covariance of the estimate
+    #[getter]
+    fn get_covariance(&self) -> Covariance6 {
+        self.covariance
+    }
+
+    /// Residuals at the estimate
+    #[getter]
+    fn get_residuals(&self) -> Vec<Residual> {
+        self.residuals.clone()
+    }
+
+    /// Normalized residual values of all measurements, concatenated
+    #[getter]
+    fn get_normalized_residuals<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        let values: Vec<f64> = self
+            .residuals
+            .iter()
+            .flat_map(|residual| residual.normalized.iter().copied())
+            .collect();
+        PyArray1::from_vec_bound(py, values)
+    }
+
+    /// Number of state corrections applied
+    #[getter]
+    fn get_iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Whether the fit converged
+    #[getter]
+    fn get_converged(&self) -> bool {
+        self.converged
+    }
+
+    /// Weighted RMS of the residuals at the estimate
+    #[getter]
+    fn get_rms(&self) -> f64 {
+        self.rms
+    }
+
+    /// Weighted RMS per iteration
+    #[getter]
+    fn get_rms_history(&self) -> Vec<f64> {
+        self.rms_history.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "BatchSolution(converged={}, iterations={}, rms={:.4}, residuals={})",
+            self.converged,
+            self.iterations,
+            self.rms,
+            self.residuals.len()
+        )
+    }
+}
+
+#[pymethods]
+impl BatchLeastSquares {
+    /// Create a batch least-squares estimator
+    ///
+    /// # Arguments
+    /// - `max_iterations`: Maximum number of state corrections (default: 20)
+    /// - `tolerance`: Change of the weighted RMS at convergence, relative to
+    ///   the previous RMS or 1, whichever is larger (default: 1e-3)
+    /// - `a_priori_covariance`: Optional `Covariance6` of the initial state
+    #[new]
+    #[pyo3(signature = (
+        max_iterations=DEFAULT_MAX_ITERATIONS,
+        tolerance=DEFAULT_RMS_TOLERANCE,
+        a_priori_covariance=None
+    ))]
+    fn py_new(
+        max_iterations: usize,
+        tolerance: f64,
+        a_priori_covariance: Option<Covariance6>,
+    ) -> Self {
+        let estimator = Self::new()
+            .with_max_iterations(max_iterations)
+            .with_tolerance(tolerance);
+        match a_priori_covariance {
+            Some(covariance) => estimator.with_a_priori(covariance),
+            None => estimator,
+        }
+    }
+
+    /// Estimate the state at the propagator's epoch
+    ///
+    /// The propagator's state is the initial guess; it is left at the
+    /// estimate.
+    #[pyo3(name = "solve")]
+    fn py_solve(
+        &self,
+        mut propagator: PyRefMut<'_, NumericalPropagator>,
+        measurements: Vec<Measurement>,
+    ) -> PyResult<BatchSolution> {
+        Ok(self.solve(&mut propagator, &measurements)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::time::Duration;
+    use crate::estimation::simulation::NormalSampler;
+    use crate::estimation::test_fixtures::{epoch, propagator, stations, R0, V0};
+
+    /// Measurements of the true orbit every minute for two hours from
+    /// two stations, with Gaussian noise of the given sigmas
+    fn simulate(kinds: &[MeasurementType], sigmas: &[f64], noisy: bool) -> Vec<Measurement> {
+        let stations = stations();
+        let mut truth = propagator(Vector3::from(R0), Vector3::from(V0));
+        let mut normal = NormalSampler::new(42);
+        let mut measurements = Vec::new();
+        for minute in (-30i32..90).step_by(2) {
+            let t = epoch().add_duration(Duration::from_seconds(60.0 * minute as f64));
+            let (r, v) = truth.propagate_to(t).unwrap();
+            for (kind, sigma) in kinds.iter().zip(sigmas) {
+                let station = stations[(minute.unsigned_abs() as usize / 2) % 2].clone();
+                let station = kind.needs_station().then_some(station);
+                let template = Measurement::new(
+                    t,
+                    *kind,
+                    station.clone(),
+                    &vec![0.0; kind.dimension()],
+                    &vec![*sigma; kind.dimension()],
+                )
+                .unwrap();
+                let (mut values, _) = template.predict(&r, &v).unwrap();
+                if noisy {
+                    values
+                        .iter_mut()
+                        .for_each(|x| *x += sigma * normal.sample());
+                }
+                measurements.push(
+                    Measurement::new(
+                        t,
+                        *kind,
+                        station,
+                        values.as_slice(),
+                        &vec![*sigma; kind.dimension()],
+                    )
+                    .unwrap(),
+                );
+            }
+        }
+        measurements
+    }
+
+    fn perturbed_guess() -> NumericalPropagator {
+        propagator(
+            Vector3::from(R0) + Vector3::new(2e3, -1.5e3, 1e3),
+            Vector3::from(V0) + Vector3::new(-1.0, 2.0, 0.5),
+        )
+    }
+
+    #[test]
+    fn test_recovers_truth_from_exact_measurements() {
+        // Range and range rate, including measurements before the epoch
+        let measurements = simulate(
+            &[MeasurementType::Range, MeasurementType::RangeRate],
+            &[1.0, 1e-3],
+            false,
+        );
+        let mut guess = perturbed_guess();
+        let solution = BatchLeastSquares::new()
+            .solve(&mut guess, &measurements)
+            .unwrap();
+
+        assert!(solution.converged);
+        assert!(
+            solution.iterations <= 8,
+            "{} iterations",
+            solution.iterations
+        );
+        assert!((solution.position - Vector3::from(R0)).norm() < 1e-2);
+        assert!((solution.velocity - Vector3::from(V0)).norm() < 1e-5);
+        assert!(solution.rms < 1e-2);
+        assert_eq!(solution.residuals.len(), measurements.len());
+        assert_eq!(guess.position(), solution.position);
+    }
+
+    #[test]
+    fn test_noisy_angles_and_gnss() {
+        let measurements = simulate(
+            &[
+                MeasurementType::AzimuthElevation,
+                MeasurementType::RightAscensionDeclination,
+                MeasurementType::GnssPosition,
+            ],
+            &[1e-4, 1e-4, 10.0],
+            true,
+        );
+        let solution = BatchLeastSquares::new()
+            .solve(&mut perturbed_guess(), &measurements)
+            .unwrap();
+
+        assert!(solution.converged);
+        // Correct noise model: weighted RMS near 1, errors within ~4σ
+        assert!((0.8..1.2).contains(&solution.rms), "rms {}", solution.rms);
+        let sigma = solution.covariance.std_devs();
+        let error = state_vector(&solution.position, &solution.velocity)
+            - state_vector(&Vector3::from(R0), &Vector3::from(V0));
+        for i in 0..6 {
+            assert!(
+                error[i].abs() < 4.0 * sigma[i],
+                "component {i}: {} vs σ {}",
+                error[i],
+                sigma[i]
+            );
+        }
+        assert!(sigma[0] < 10.0);
+        assert!(solution.rms_history[0] > 100.0);
+    }
+
+    #[test]
+    fn test_a_priori_and_errors() {
+        let measurements = simulate(&[MeasurementType::Range], &[1.0], false);
+
+        // A single range is not enough without a priori information
+        let mut guess = perturbed_guess();
+        assert!(BatchLeastSquares::new()
+            .solve(&mut guess, &measurements[..1])
+            .is_err());
+
+        // A tight a priori keeps the solution near the initial guess, 2.7 km
+        // from the truth
+        let tight =
+            Covariance6::from_std_devs(&Vector6::new(1e-3, 1e-3, 1e-3, 1e-6, 1e-6, 1e-6)).unwrap();
+        let initial = guess.position();
+        let solution = BatchLeastSquares::new()
+            .with_a_priori(tight)
+            .solve(&mut guess, &measurements)
+            .unwrap();
+        assert!((solution.position - initial).norm() < 10.0);
+        assert!(solution.covariance.std_devs()[0] <= 1e-3);
+
+        // The iteration limit is reported, not an error
+        let solution = BatchLeastSquares::new()
+            .with_max_iterations(1)
+            .solve(&mut perturbed_guess(), &measurements)
+            .unwrap();
+        assert!(!solution.converged);
+        assert_eq!(solution.iterations, 1);
+    }
+}
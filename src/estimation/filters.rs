@@ -0,0 +1,1170 @@
+//! Sequential orbit determination: extended and unscented Kalman filters
+//!
+//! [`KalmanFilter`] processes time-ordered [`Measurement`]s one at a time,
+//! starting from the state of a [`NumericalPropagator`] and an initial
+//! [`Covariance6`]. Between measurements the state is propagated with the
+//! propagator's force model:
+//!
+//! - [`FilterType::Extended`] (EKF) propagates the estimate with the state
+//!   transition matrix ([`NumericalPropagator::propagate_with_stm`]) and
+//!   linearizes the measurement models about it
+//! - [`FilterType::Unscented`] (UKF) propagates and measures the sigma
+//!   points of an [`UnscentedTransform`], which tolerates larger initial
+//!   uncertainties
+//!
+//! Unmodelled accelerations are absorbed by [`ProcessNoise`]:
+//!
+//! - State noise compensation (SNC): white-noise acceleration of spectral
+//!   density σ² on each inertial axis
+//! - Dynamic model compensation (DMC): a first-order Gauss-Markov
+//!   acceleration w with time constant τ and steady-state sigma σ, estimated
+//!   as three extra states (Tapley, Schutz & Born, §4.9). Its effect on
+//!   position and velocity is integrated analytically, without the gravity
+//!   gradient.
+//!
+//! Measurements whose normalized innovation exceeds the editing threshold
+//! (sigma rejection) are reported but not processed. After the forward pass
+//! the Rauch-Tung-Striebel smoother runs backwards over the stored
+//! estimates, using the cross covariance of consecutive states, which is
+//! P·Φᵀ for the EKF and the sigma-point cross covariance for the UKF.
+//! Residuals are grouped into passes per station for monitoring.
+//!
+//! # Example
+//! ```rust,ignore
+//! use astrora_core::estimation::{KalmanFilter, ProcessNoise};
+//!
+//! let filter = KalmanFilter::unscented()
+//!     .with_process_noise(ProcessNoise::Snc { sigma: 1e-6 })
+//!     .with_editing(3.0);
+//! let result = filter.run(&mut propagator, &initial_covariance, &measurements)?;
+//! for pass in &result.passes {
+//!     println!("{:?}: {} points, RMS {:.2}", pass.station, pass.measurements, pass.rms);
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+
+use nalgebra::{DMatrix, DVector};
+use numpy::{PyArray1, PyArray2};
+use pyo3::prelude::*;
+
+use crate::core::covariance::{Covariance6, UnscentedTransform};
+use crate::core::error::{PoliastroError, PoliastroResult};
+use crate::core::linalg::{Matrix6, Vector3};
+use crate::core::time::Epoch;
+use crate::estimation::measurements::{Measurement, MeasurementType};
+use crate::propagators::propagator::NumericalPropagator;
+
+/// Default largest gap between measurements of one pass (s)
+pub const DEFAULT_PASS_GAP: f64 = 600.0;
+
+/// Kalman filter formulation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FilterType {
+    /// Extended Kalman filter
+    Extended,
+    /// Unscented Kalman filter with the given sigma-point scaling
+    Unscented(UnscentedTransform),
+}
+
+/// Process noise model
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ProcessNoise {
+    /// No process noise
+    #[default]
+    None,
+    /// State noise compensation: white acceleration noise with spectral
+    /// density `sigma`² (m²/s³) on each axis
+    Snc {
+        /// Square root of the spectral density (m/s^1.5)
+        sigma: f64,
+    },
+    /// Dynamic model compensation: estimated Gauss-Markov acceleration
+    Dmc {
+        /// Steady-state sigma of the acceleration (m/s²)
+        sigma: f64,
+        /// Correlation time (s)
+        tau: f64,
+    },
+}
+
+impl ProcessNoise {
+    /// Dimension of the filter state: 9 with DMC, 6 otherwise
+    pub fn state_dimension(&self) -> usize {
+        match self {
+            Self::Dmc { .. } => 9,
+            _ => 6,
+        }
+    }
+
+    fn validate(&self) -> PoliastroResult<()> {
+        match *self {
+            Self::None => Ok(()),
+            Self::Snc { sigma } | Self::Dmc { sigma, .. } if sigma.is_nan() || sigma < 0.0 => Err(
+                PoliastroError::invalid_parameter("sigma", sigma, "must be non-negative"),
+            ),
+            Self::Dmc { tau, .. } if tau.is_nan() || tau <= 0.0 => Err(
+                PoliastroError::invalid_parameter("tau", tau, "must be positive"),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Coefficients (α, β, e) of the DMC acceleration over `dt`:
+    /// Δr = α·w, Δv = β·w and w → e·w
+    fn dmc_coefficients(tau: f64, dt: f64) -> (f64, f64, f64) {
+        let decay = (-dt / tau).exp();
+        let beta = -tau * (-dt / tau).exp_m1();
+        let alpha = tau * (dt - beta);
+        (alpha, beta, decay)
+    }
+
+    /// Transition matrix of the filter state given the 6×6 STM of (r, v)
+    fn transition(&self, stm: &Matrix6, dt: f64) -> DMatrix<f64> {
+        let n = self.state_dimension();
+        let mut phi = DMatrix::zeros(n, n);
+        phi.view_mut((0, 0), (6, 6)).copy_from(stm);
+        if let Self::Dmc { tau, .. } = *self {
+            let (alpha, beta, decay) = Self::dmc_coefficients(tau, dt);
+            for i in 0..3 {
+                phi[(i, 6 + i)] = alpha;
+                phi[(3 + i, 6 + i)] = beta;
+                phi[(6 + i, 6 + i)] = decay;
+            }
+        }
+        phi
+    }
+
+    /// Add the DMC acceleration's effect over `dt` to a propagated state
+    fn apply(&self, state: &mut DVector<f64>, dt: f64) {
+        if let Self::Dmc { tau, .. } = *self {
+            let (alpha, beta, decay) = Self::dmc_coefficients(tau, dt);
+            for i in 0..3 {
+                let w = state[6 + i];
+                state[i] += alpha * w;
+                state[3 + i] += beta * w;
+                state[6 + i] = decay * w;
+            }
+        }
+    }
+
+    /// Process noise covariance accumulated over `dt`
+    fn covariance(&self, dt: f64) -> DMatrix<f64> {
+        let n = self.state_dimension();
+        let mut q = DMatrix::zeros(n, n);
+        let dt = dt.abs();
+        let per_axis: Vec<(usize, usize, f64)> = match *self {
+            Self::None => return q,
+            Self::Snc { sigma } => {
+                let psd = sigma * sigma;
+                vec![
+                    (0, 0, psd * dt.powi(3) / 3.0),
+                    (0, 3, psd * dt * dt / 2.0),
+                    (3, 3, psd * dt),
+                ]
+            }
+            Self::Dmc { sigma, tau } => {
+                // Driving noise of spectral density 2σ²/τ, integrated over
+                // the impulse response (α(s), β(s), e(s)) of each axis
+                let b = 1.0 / tau;
+                let psd = 2.0 * sigma * sigma * b;
+                let i1 = -(-b * dt).exp_m1() / b;
+                let i2 = -(-2.0 * b * dt).exp_m1() / (2.0 * b);
+                let j = (-(-b * dt).exp_m1() - b * dt * (-b * dt).exp()) / (b * b);
+                let int_bc = (i1 - i2) / b;
+                let int_bb = (dt - 2.0 * i1 + i2) / (b * b);
+                let int_sb = (dt * dt / 2.0 - j) / b;
+                let int_ac = (j - int_bc) / b;
+                let int_ab = (int_sb - int_bb) / b;
+                let int_aa = (dt.powi(3) / 3.0 - 2.0 * int_sb + int_bb) / (b * b);
+                vec![
+                    (0, 0, psd * int_aa),
+                    (0, 3, psd * int_ab),
+                    (0, 6, psd * int_ac),
+                    (3, 3, psd * int_bb),
+                    (3, 6, psd * int_bc),
+                    (6, 6, psd * i2),
+                ]
+            }
+        };
+        for (row, col, value) in per_axis {
+            for axis in 0..3 {
+                q[(row + axis, col + axis)] = value;
+                q[(col + axis, row + axis)] = value;
+            }
+        }
+        q
+    }
+}
+
+/// Sequential (Kalman) orbit determination filter
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone)]
+pub struct KalmanFilter {
+    filter_type: FilterType,
+    process_noise: ProcessNoise,
+    editing: Option<f64>,
+    pass_gap: f64,
+}
+
+/// Filter state and covariance at one measurement epoch
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone)]
+pub struct FilterEstimate {
+    /// Measurement epoch
+    pub epoch: Epoch,
+    /// Position (m)
+    pub position: Vector3,
+    /// Velocity (m/s)
+    pub velocity: Vector3,
+    /// Covariance of position and velocity
+    pub covariance: Covariance6,
+    /// Estimated DMC acceleration (m/s²), when DMC is enabled
+    pub acceleration: Option<Vector3>,
+}
+
+/// Residual of one measurement processed by a filter
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone)]
+pub struct FilterResidual {
+    /// Time tag of the measurement
+    pub epoch: Epoch,
+    /// Measurement type
+    pub kind: MeasurementType,
+    /// Observing station, if any
+    pub station: Option<String>,
+    /// Innovation: observed minus predicted before the update
+    pub prefit: DVector<f64>,
+    /// Observed minus computed after the update
+    pub postfit: DVector<f64>,
+    /// Innovation divided by its predicted standard deviation
+    pub normalized: DVector<f64>,
+    /// Whether the measurement passed editing and updated the state
+    pub accepted: bool,
+}
+
+/// Residual statistics of one tracking pass
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone)]
+pub struct PassSummary {
+    /// Observing station, or `None` for GNSS
+    pub station: Option<String>,
+    /// First measurement epoch
+    pub start: Epoch,
+    /// Last measurement epoch
+    pub end: Epoch,
+    /// Number of measurements
+    pub measurements: usize,
+    /// Number of measurements rejected by editing
+    pub rejected: usize,
+    /// RMS of the normalized innovations of the accepted measurements
+    pub rms: f64,
+    /// Largest absolute normalized innovation
+    pub max_normalized: f64,
+}
+
+/// Filtered and smoothed histories with residuals
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone)]
+pub struct FilterResult {
+    /// Forward-pass estimates, one per measurement
+    pub filtered: Vec<FilterEstimate>,
+    /// Rauch-Tung-Striebel smoothed estimates, one per measurement
+    pub smoothed: Vec<FilterEstimate>,
+    /// Residuals in processing order
+    pub residuals: Vec<FilterResidual>,
+    /// Residual statistics per station pass, in time order
+    pub passes: Vec<PassSummary>,
+}
+
+/// Forward-pass quantities needed by the smoother
+struct Step {
+    predicted: DVector<f64>,
+    predicted_cov: DMatrix<f64>,
+    /// Cross covariance of the previous estimate and this prediction
+    cross_cov: DMatrix<f64>,
+    state: DVector<f64>,
+    cov: DMatrix<f64>,
+}
+
+impl Default for KalmanFilter {
+    fn default() -> Self {
+        Self::extended()
+    }
+}
+
+impl KalmanFilter {
+    /// Extended Kalman filter without process noise or editing
+    pub fn extended() -> Self {
+        Self::new(FilterType::Extended)
+    }
+
+    /// Unscented Kalman filter with the default sigma points, without
+    /// process noise or editing
+    pub fn unscented() -> Self {
+        Self::new(FilterType::Unscented(UnscentedTransform::default()))
+    }
+
+    /// Filter of the given type without process noise or editing
+    pub fn new(filter_type: FilterType) -> Self {
+        Self {
+            filter_type,
+            process_noise: ProcessNoise::None,
+            editing: None,
+            pass_gap: DEFAULT_PASS_GAP,
+        }
+    }
+
+    /// Set the process noise model
+    pub fn with_process_noise(mut self, process_noise: ProcessNoise) -> Self {
+        self.process_noise = process_noise;
+        self
+    }
+
+    /// Reject measurements with a normalized innovation above `sigmas`
+    pub fn with_editing(mut self, sigmas: f64) -> Self {
+        self.editing = Some(sigmas);
+        self
+    }
+
+    /// Set the largest gap between measurements of one pass (s)
+    pub fn with_pass_gap(mut self, seconds: f64) -> Self {
+        self.pass_gap = seconds;
+        self
+    }
+
+    /// Filter `measurements` and smooth the result
+    ///
+    /// The propagator's state and epoch are the initial estimate, with
+    /// `covariance` its uncertainty (the DMC acceleration starts at zero with
+    /// its steady-state sigma). Measurements are processed in time order and
+    /// must not precede the propagator's epoch. The propagator is left at the
+    /// last filtered estimate.
+    ///
+    /// # Errors
+    /// Returns an error if a measurement precedes the initial epoch, the
+    /// process noise parameters are invalid, an innovation covariance is
+    /// singular, or a propagation or measurement model fails
+    pub fn run(
+        &self,
+        propagator: &mut NumericalPropagator,
+        covariance: &Covariance6,
+        measurements: &[Measurement],
+    ) -> PoliastroResult<FilterResult> {
+        self.process_noise.validate()?;
+        let n = self.process_noise.state_dimension();
+
+        let mut order: Vec<usize> = (0..measurements.len()).collect();
+        order.sort_by(|&a, &b| {
+            let ta = measurements[a].epoch.to_tdb_seconds_since_j2000();
+            let tb = measurements[b].epoch.to_tdb_seconds_since_j2000();
+            ta.total_cmp(&tb)
+        });
+
+        let mut epoch = propagator.epoch();
+        if let Some(&first) = order.first() {
+            let dt = measurements[first].epoch.to_tdb_seconds_since_j2000()
+                - epoch.to_tdb_seconds_since_j2000();
+            if dt < 0.0 {
+                return Err(PoliastroError::invalid_state(format!(
+                    "Measurement at {} precedes the filter epoch {}",
+                    measurements[first].epoch.to_iso_string(),
+                    epoch.to_iso_string()
+                )));
+            }
+        }
+
+        let mut state = DVector::zeros(n);
+        state.rows_mut(0, 3).copy_from(&propagator.position());
+        state.rows_mut(3, 3).copy_from(&propagator.velocity());
+        let mut cov = DMatrix::zeros(n, n);
+        cov.view_mut((0, 0), (6, 6)).copy_from(&covariance.matrix());
+        if let ProcessNoise::Dmc { sigma, .. } = self.process_noise {
+            for i in 6..9 {
+                cov[(i, i)] = sigma * sigma;
+            }
+        }
+
+        let mut steps = Vec::with_capacity(order.len());
+        let mut residuals = Vec::with_capacity(order.len());
+        for &index in &order {
+            let measurement = &measurements[index];
+            let dt =
+                measurement.epoch.to_tdb_seconds_since_j2000() - epoch.to_tdb_seconds_since_j2000();
+            let (predicted, predicted_cov, cross_cov) =
+                self.predict(propagator, epoch, &state, &cov, measurement.epoch, dt)?;
+            epoch = measurement.epoch;
+
+            let (updated, updated_cov, residual) =
+                self.update(measurement, &predicted, &predicted_cov)?;
+            state = updated;
+            cov = updated_cov;
+            residuals.push(residual);
+            steps.push(Step {
+                predicted,
+                predicted_cov,
+                cross_cov,
+                state: state.clone(),
+                cov: cov.clone(),
+            });
+        }
+        propagator.set_state(
+            Vector3::new(state[0], state[1], state[2]),
+            Vector3::new(state[3], state[4], state[5]),
+            epoch,
+        );
+
+        let epochs: Vec<Epoch> = order.iter().map(|&i| measurements[i].epoch).collect();
+        let filtered = steps
+            .iter()
+            .zip(&epochs)
+            .map(|(step, epoch)| estimate(*epoch, &step.state, &step.cov))
+            .collect::<PoliastroResult<Vec<_>>>()?;
+        let smoothed = smooth(&steps)?
+            .iter()
+            .zip(&epochs)
+            .map(|((state, cov), epoch)| estimate(*epoch, state, cov))
+            .collect::<PoliastroResult<Vec<_>>>()?;
+        let passes = summarize_passes(&residuals, self.pass_gap);
+
+        Ok(FilterResult {
+            filtered,
+            smoothed,
+            residuals,
+            passes,
+        })
+    }
+
+    /// Propagate the estimate over `dt` to `target`
+    ///
+    /// # Returns
+    /// Predicted state, its covariance, and the cross covariance with the
+    /// previous estimate
+    fn predict(
+        &self,
+        propagator: &mut NumericalPropagator,
+        epoch: Epoch,
+        state: &DVector<f64>,
+        cov: &DMatrix<f64>,
+        target: Epoch,
+        dt: f64,
+    ) -> PoliastroResult<(DVector<f64>, DMatrix<f64>, DMatrix<f64>)> {
+        if dt == 0.0 {
+            return Ok((state.clone(), cov.clone(), cov.clone()));
+        }
+        let q = self.process_noise.covariance(dt);
+
+        match self.filter_type {
+            FilterType::Extended => {
+                set_state(propagator, state, epoch);
+                let segment = propagator.propagate_with_stm(target, &[])?;
+                let phi = self.process_noise.transition(&segment.stm, dt);
+                let mut predicted = state.clone();
+                predicted.rows_mut(0, 3).copy_from(&segment.position);
+                predicted.rows_mut(3, 3).copy_from(&segment.velocity);
+                self.process_noise.apply(&mut predicted, dt);
+
+                let cross_cov = cov * phi.transpose();
+                let predicted_cov = &phi * &cross_cov + q;
+                Ok((predicted, symmetrize(predicted_cov), cross_cov))
+            }
+            FilterType::Unscented(transform) => {
+                let points = transform.sigma_points(state, cov)?;
+                let images = points
+                    .iter()
+                    .map(|point| {
+                        set_state(propagator, point, epoch);
+                        let (r, v) = propagator.propagate_to(target)?;
+                        let mut image = point.clone();
+                        image.rows_mut(0, 3).copy_from(&r);
+                        image.rows_mut(3, 3).copy_from(&v);
+                        self.process_noise.apply(&mut image, dt);
+                        Ok(image)
+                    })
+                    .collect::<PoliastroResult<Vec<_>>>()?;
+
+                let (mean_weights, cov_weights) = transform.weights(state.len());
+                let predicted = weighted_mean(&mean_weights, &images);
+                let mut predicted_cov = q;
+                let mut cross_cov = DMatrix::zeros(state.len(), state.len());
+                for ((weight, point), image) in cov_weights.iter().zip(&points).zip(&images) {
+                    let deviation = image - &predicted;
+                    predicted_cov += &deviation * deviation.transpose() * *weight;
+                    cross_cov += (point - state) * deviation.transpose() * *weight;
+                }
+                Ok((predicted, symmetrize(predicted_cov), cross_cov))
+            }
+        }
+    }
+
+    /// Process one measurement against the predicted state
+    fn update(
+        &self,
+        measurement: &Measurement,
+        state: &DVector<f64>,
+        cov: &DMatrix<f64>,
+    ) -> PoliastroResult<(DVector<f64>, DMatrix<f64>, FilterResidual)> {
+        let n = state.len();
+        let m = measurement.dimension();
+        let noise = DMatrix::from_diagonal(&measurement.sigmas.map(|sigma| sigma * sigma));
+
+        // Innovation, its covariance and the state-measurement cross covariance
+        let (innovation, innovation_cov, cross_cov, jacobian) = match self.filter_type {
+            FilterType::Extended => {
+                let (r, v) = position_velocity(state);
+                let (predicted, partials) = measurement.predict(&r, &v)?;
+                let mut jacobian = DMatrix::zeros(m, n);
+                jacobian.view_mut((0, 0), (m, 6)).copy_from(&partials);
+                let cross_cov = cov * jacobian.transpose();
+                let innovation_cov = &jacobian * &cross_cov + &noise;
+                (
+                    measurement.residual(&predicted),
+                    innovation_cov,
+                    cross_cov,
+                    Some(jacobian),
+                )
+            }
+            FilterType::Unscented(transform) => {
+                let points = transform.sigma_points(state, cov)?;
+                let images = points
+                    .iter()
+                    .map(|point| {
+                        let (r, v) = position_velocity(point);
+                        Ok(measurement.predict(&r, &v)?.0)
+                    })
+                    .collect::<PoliastroResult<Vec<_>>>()?;
+
+                // Deviations from the central point, so angles average
+                // correctly across the 0/2π cut
+                let deviations: Vec<DVector<f64>> = images
+                    .iter()
+                    .map(|image| measurement.difference(image, &images[0]))
+                    .collect();
+                let (mean_weights, cov_weights) = transform.weights(n);
+                let mean_deviation = weighted_mean(&mean_weights, &deviations);
+                let predicted = &images[0] + &mean_deviation;
+
+                let mut innovation_cov = noise.clone();
+                let mut cross_cov = DMatrix::zeros(n, m);
+                for ((weight, point), deviation) in cov_weights.iter().zip(&points).zip(&deviations)
+                {
+                    let deviation = deviation - &mean_deviation;
+                    innovation_cov += &deviation * deviation.transpose() * *weight;
+                    cross_cov += (point - state) * deviation.transpose() * *weight;
+                }
+                (
+                    measurement.residual(&predicted),
+                    innovation_cov,
+                    cross_cov,
+                    None,
+                )
+            }
+        };
+
+        let normalized = DVector::from_fn(m, |i, _| innovation[i] / innovation_cov[(i, i)].sqrt());
+        let accepted = self
+            .editing
+            .map_or(true, |limit| normalized.amax() <= limit);
+
+        let (updated, updated_cov) = if accepted {
+            let inverse = innovation_cov.clone().cholesky().ok_or_else(|| {
+                PoliastroError::SingularMatrix {
+                    context: "innovation covariance".to_string(),
+                    determinant: innovation_cov.determinant(),
+                }
+            })?;
+            let gain = &cross_cov * inverse.inverse();
+            let updated = state + &gain * &innovation;
+            let updated_cov = match jacobian {
+                // Joseph form, which keeps P positive definite
+                Some(jacobian) => {
+                    let factor = DMatrix::identity(n, n) - &gain * jacobian;
+                    &factor * cov * factor.transpose() + &gain * &noise * gain.transpose()
+                }
+                None => cov - &gain * &innovation_cov * gain.transpose(),
+            };
+            (updated, symmetrize(updated_cov))
+        } else {
+            (state.clone(), cov.clone())
+        };
+
+        let (r, v) = position_velocity(&updated);
+        let postfit = measurement.residual(&measurement.predict(&r, &v)?.0);
+        let residual = FilterResidual {
+            epoch: measurement.epoch,
+            kind: measurement.kind,
+            station: measurement.station_name().map(str::to_string),
+            prefit: innovation,
+            postfit,
+            normalized,
+            accepted,
+        };
+        Ok((updated, updated_cov, residual))
+    }
+}
+
+/// Rauch-Tung-Striebel smoother over the forward-pass steps
+fn smooth(steps: &[Step]) -> PoliastroResult<Vec<(DVector<f64>, DMatrix<f64>)>> {
+    let Some(last) = steps.last() else {
+        return Ok(Vec::new());
+    };
+    let mut smoothed = vec![(last.state.clone(), last.cov.clone())];
+    for k in (0..steps.len() - 1).rev() {
+        let (current, next) = (&steps[k], &steps[k + 1]);
+        let inverse = next.predicted_cov.clone().try_inverse().ok_or_else(|| {
+            PoliastroError::SingularMatrix {
+                context: "smoother predicted covariance".to_string(),
+                determinant: next.predicted_cov.determinant(),
+            }
+        })?;
+        let gain = &next.cross_cov * inverse;
+        let (next_state, next_cov) = &smoothed[smoothed.len() - 1];
+        let state = &current.state + &gain * (next_state - &next.predicted);
+        let cov = &current.cov + &gain * (next_cov - &next.predicted_cov) * gain.transpose();
+        smoothed.push((state, symmetrize(cov)));
+    }
+    smoothed.reverse();
+    Ok(smoothed)
+}
+
+/// Group residuals into passes: consecutive measurements of one station no
+/// more than `gap` seconds apart
+fn summarize_passes(residuals: &[FilterResidual], gap: f64) -> Vec<PassSummary> {
+    let mut by_station: BTreeMap<Option<&str>, Vec<&FilterResidual>> = BTreeMap::new();
+    for residual in residuals {
+        by_station
+            .entry(residual.station.as_deref())
+            .or_default()
+            .push(residual);
+    }
+
+    let mut passes = Vec::new();
+    for station_residuals in by_station.values() {
+        let mut start = 0;
+        for i in 1..=station_residuals.len() {
+            let split = i == station_residuals.len()
+                || station_residuals[i].epoch.to_tdb_seconds_since_j2000()
+                    - station_residuals[i - 1].epoch.to_tdb_seconds_since_j2000()
+                    > gap;
+            if split {
+                passes.push(pass_summary(&station_residuals[start..i]));
+                start = i;
+            }
+        }
+    }
+    passes.sort_by(|a, b| {
+        a.start
+            .to_tdb_seconds_since_j2000()
+            .total_cmp(&b.start.to_tdb_seconds_since_j2000())
+    });
+    passes
+}
+
+fn pass_summary(residuals: &[&FilterResidual]) -> PassSummary {
+    let accepted: Vec<&&FilterResidual> = residuals.iter().filter(|r| r.accepted).collect();
+    let count: usize = accepted.iter().map(|r| r.normalized.len()).sum();
+    let sum: f64 = accepted.iter().map(|r| r.normalized.norm_squared()).sum();
+    PassSummary {
+        station: residuals[0].station.clone(),
+        start: residuals[0].epoch,
+        end: residuals[residuals.len() - 1].epoch,
+        measurements: residuals.len(),
+        rejected: residuals.len() - accepted.len(),
+        rms: if count > 0 {
+            (sum / count as f64).sqrt()
+        } else {
+            0.0
+        },
+        max_normalized: residuals
+            .iter()
+            .map(|r| r.normalized.amax())
+            .fold(0.0, f64::max),
+    }
+}
+
+fn estimate(
+    epoch: Epoch,
+    state: &DVector<f64>,
+    cov: &DMatrix<f64>,
+) -> PoliastroResult<FilterEstimate> {
+    let (position, velocity) = position_velocity(state);
+    Ok(FilterEstimate {
+        epoch,
+        position,
+        velocity,
+        covariance: Covariance6::new(Matrix6::from_fn(|i, j| cov[(i, j)]))?,
+        acceleration: (state.len() == 9).then(|| Vector3::new(state[6], state[7], state[8])),
+    })
+}
+
+fn position_velocity(state: &DVector<f64>) -> (Vector3, Vector3) {
+    (
+        Vector3::new(state[0], state[1], state[2]),
+        Vector3::new(state[3], state[4], state[5]),
+    )
+}
+
+fn set_state(propagator: &mut NumericalPropagator, state: &DVector<f64>, epoch: Epoch) {
+    let (r, v) = position_velocity(state);
+    propagator.set_state(r, v, epoch);
+}
+
+fn weighted_mean(weights: &[f64], points: &[DVector<f64>]) -> DVector<f64> {
+    let mut mean = DVector::zeros(points[0].len());
+    for (weight, point) in weights.iter().zip(points) {
+        mean += point * *weight;
+    }
+    mean
+}
+
+fn symmetrize(matrix: DMatrix<f64>) -> DMatrix<f64> {
+    (&matrix + matrix.transpose()) * 0.5
+}
+
+fn states_to_pyarray<'py>(
+    py: Python<'py>,
+    estimates: &[FilterEstimate],
+) -> Bound<'py, PyArray2<f64>> {
+    let array = ndarray::Array2::from_shape_fn((estimates.len(), 6), |(i, j)| {
+        if j < 3 {
+            estimates[i].position[j]
+        } else {
+            estimates[i].velocity[j - 3]
+        }
+    });
+    PyArray2::from_owned_array_bound(py, array)
+}
+
+#[pymethods]
+impl KalmanFilter {
+    /// Create a Kalman filter
+    ///
+    /// # Arguments
+    /// - `kind`: "ekf" (default) or "ukf"
+    /// - `snc_sigma`: State noise compensation, square root of the
+    ///   acceleration spectral density (m/s^1.5)
+    /// - `dmc_sigma`, `dmc_tau`: Dynamic model compensation, steady-state
+    ///   acceleration sigma (m/s²) and correlation time (s)
+    /// - `editing_sigma`: Reject measurements with a larger normalized
+    ///   innovation
+    /// - `pass_gap`: Largest gap between measurements of a pass (s)
+    /// - `alpha`, `beta`, `kappa`: UKF sigma-point parameters
+    #[new]
+    #[pyo3(signature = (
+        kind="ekf",
+        snc_sigma=None,
+        dmc_sigma=None,
+        dmc_tau=None,
+        editing_sigma=None,
+        pass_gap=DEFAULT_PASS_GAP,
+        alpha=1.0,
+        beta=2.0,
+        kappa=0.0
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn py_new(
+        kind: &str,
+        snc_sigma: Option<f64>,
+        dmc_sigma: Option<f64>,
+        dmc_tau: Option<f64>,
+        editing_sigma: Option<f64>,
+        pass_gap: f64,
+        alpha: f64,
+        beta: f64,
+        kappa: f64,
+    ) -> PyResult<Self> {
+        let filter_type = match kind.to_ascii_lowercase().as_str() {
+            "ekf" | "extended" => FilterType::Extended,
+            "ukf" | "unscented" => {
+                FilterType::Unscented(UnscentedTransform::new(alpha, beta, kappa))
+            }
+            _ => {
+                return Err(PoliastroError::invalid_state(format!(
+                    "Unknown filter type '{kind}' (expected ekf or ukf)"
+                ))
+                .into())
+            }
+        };
+        let process_noise = match (snc_sigma, dmc_sigma, dmc_tau) {
+            (None, None, None) => ProcessNoise::None,
+            (Some(sigma), None, None) => ProcessNoise::Snc { sigma },
+            (None, Some(sigma), Some(tau)) => ProcessNoise::Dmc { sigma, tau },
+            _ => {
+                return Err(PoliastroError::invalid_state(
+                    "Give either snc_sigma, or both dmc_sigma and dmc_tau",
+                )
+                .into())
+            }
+        };
+        process_noise.validate()?;
+        let filter = Self::new(filter_type)
+            .with_process_noise(process_noise)
+            .with_pass_gap(pass_gap);
+        Ok(match editing_sigma {
+            Some(sigmas) => filter.with_editing(sigmas),
+            None => filter,
+        })
+    }
+
+    /// Filter and smooth `measurements` from the propagator's state with
+    /// initial `covariance`; the propagator is left at the last estimate
+    #[pyo3(name = "run")]
+    fn py_run(
+        &self,
+        mut propagator: PyRefMut<'_, NumericalPropagator>,
+        covariance: Covariance6,
+        measurements: Vec<Measurement>,
+    ) -> PyResult<FilterResult> {
+        Ok(self.run(&mut propagator, &covariance, &measurements)?)
+    }
+}
+
+#[pymethods]
+impl FilterEstimate {
+    /// Measurement epoch
+    #[getter]
+    fn get_epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Position (m)
+    #[getter]
+    fn get_position<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.position.as_slice())
+    }
+
+    /// Velocity (m/s)
+    #[getter]
+    fn get_velocity<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.velocity.as_slice())
+    }
+
+    /// Covariance of position and velocity
+    #[getter]
+    fn get_covariance(&self) -> Covariance6 {
+        self.covariance
+    }
+
+    /// Estimated DMC acceleration (m/s²), or None without DMC
+    #[getter]
+    fn get_acceleration<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyArray1<f64>>> {
+        self.acceleration
+            .map(|a| PyArray1::from_slice_bound(py, a.as_slice()))
+    }
+}
+
+#[pymethods]
+impl FilterResidual {
+    /// Time tag of the measurement
+    #[getter]
+    fn get_epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Measurement type name
+    #[getter]
+    fn get_kind(&self) -> &'static str {
+        self.kind.name()
+    }
+
+    /// Observing station name (None for GNSS)
+    #[getter]
+    fn get_station(&self) -> Option<String> {
+        self.station.clone()
+    }
+
+    /// Innovation (observed minus predicted)
+    #[getter]
+    fn get_prefit<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.prefit.as_slice())
+    }
+
+    /// Observed minus computed after the update
+    #[getter]
+    fn get_postfit<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.postfit.as_slice())
+    }
+
+    /// Innovation divided by its predicted standard deviation
+    #[getter]
+    fn get_normalized<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.normalized.as_slice())
+    }
+
+    /// Whether the measurement updated the state
+    #[getter]
+    fn get_accepted(&self) -> bool {
+        self.accepted
+    }
+}
+
+#[pymethods]
+impl PassSummary {
+    /// Observing station name (None for GNSS)
+    #[getter]
+    fn get_station(&self) -> Option<String> {
+        self.station.clone()
+    }
+
+    /// First measurement epoch
+    #[getter]
+    fn get_start(&self) -> Epoch {
+        self.start
+    }
+
+    /// Last measurement epoch
+    #[getter]
+    fn get_end(&self) -> Epoch {
+        self.end
+    }
+
+    /// Number of measurements
+    #[getter]
+    fn get_measurements(&self) -> usize {
+        self.measurements
+    }
+
+    /// Number of rejected measurements
+    #[getter]
+    fn get_rejected(&self) -> usize {
+        self.rejected
+    }
+
+    /// RMS of the normalized innovations of accepted measurements
+    #[getter]
+    fn get_rms(&self) -> f64 {
+        self.rms
+    }
+
+    /// Largest absolute normalized innovation
+    #[getter]
+    fn get_max_normalized(&self) -> f64 {
+        self.max_normalized
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PassSummary(station={:?}, measurements={}, rejected={}, rms={:.3})",
+            self.station, self.measurements, self.rejected, self.rms
+        )
+    }
+}
+
+#[pymethods]
+impl FilterResult {
+    /// Forward-pass estimates
+    #[getter]
+    fn get_filtered(&self) -> Vec<FilterEstimate> {
+        self.filtered.clone()
+    }
+
+    /// Smoothed estimates
+    #[getter]
+    fn get_smoothed(&self) -> Vec<FilterEstimate> {
+        self.smoothed.clone()
+    }
+
+    /// Filtered states as an (n, 6) array
+    #[getter]
+    fn get_filtered_states<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        states_to_pyarray(py, &self.filtered)
+    }
+
+    /// Smoothed states as an (n, 6) array
+    #[getter]
+    fn get_smoothed_states<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        states_to_pyarray(py, &self.smoothed)
+    }
+
+    /// Residuals in processing order
+    #[getter]
+    fn get_residuals(&self) -> Vec<FilterResidual> {
+        self.residuals.clone()
+    }
+
+    /// Residual statistics per pass
+    #[getter]
+    fn get_passes(&self) -> Vec<PassSummary> {
+        self.passes.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::linalg::Vector6;
+    use crate::core::time::Duration;
+    use crate::estimation::simulation::NormalSampler;
+    use crate::estimation::test_fixtures::{epoch, propagator, stations, R0, V0};
+
+    /// Noisy range and range-rate from two stations every 30 s for 40 min
+    fn simulate() -> Vec<Measurement> {
+        let stations = stations();
+        let mut truth = propagator(Vector3::from(R0), Vector3::from(V0));
+        let mut normal = NormalSampler::new(7);
+        let mut measurements = Vec::new();
+        for step in 1..=80 {
+            let t = epoch().add_duration(Duration::from_seconds(30.0 * step as f64));
+            let (r, v) = truth.propagate_to(t).unwrap();
+            let station = stations[usize::from(step > 40)].clone();
+            for (kind, sigma) in [
+                (MeasurementType::Range, 5.0),
+                (MeasurementType::RangeRate, 5e-3),
+            ] {
+                let template =
+                    Measurement::new(t, kind, Some(station.clone()), &[0.0], &[sigma]).unwrap();
+                let value = template.predict(&r, &v).unwrap().0[0] + sigma * normal.sample();
+                measurements.push(
+                    Measurement::new(t, kind, Some(station.clone()), &[value], &[sigma]).unwrap(),
+                );
+            }
+        }
+        measurements
+    }
+
+    fn initial_covariance() -> Covariance6 {
+        Covariance6::from_std_devs(&Vector6::new(1e3, 1e3, 1e3, 1.0, 1.0, 1.0)).unwrap()
+    }
+
+    fn guess() -> NumericalPropagator {
+        propagator(
+            Vector3::from(R0) + Vector3::new(500.0, -300.0, 200.0),
+            Vector3::from(V0) + Vector3::new(0.3, -0.2, 0.1),
+        )
+    }
+
+    fn position_error(estimate: &FilterEstimate) -> f64 {
+        let mut truth = propagator(Vector3::from(R0), Vector3::from(V0));
+        let (r, _) = truth.propagate_to(estimate.epoch).unwrap();
+        (estimate.position - r).norm()
+    }
+
+    #[test]
+    fn test_ekf_and_ukf_converge() {
+        let measurements = simulate();
+        for filter in [KalmanFilter::extended(), KalmanFilter::unscented()] {
+            let filter = filter.with_process_noise(ProcessNoise::Snc { sigma: 1e-6 });
+            let result = filter
+                .run(&mut guess(), &initial_covariance(), &measurements)
+                .unwrap();
+            assert_eq!(result.filtered.len(), measurements.len());
+            assert_eq!(result.smoothed.len(), measurements.len());
+
+            // Filtered error at the end is within the formal uncertainty
+            let last = result.filtered.last().unwrap();
+            let sigma = last.covariance.position().trace().sqrt();
+            assert!(
+                position_error(last) < 4.0 * sigma,
+                "{:?}",
+                filter.filter_type
+            );
+            assert!(sigma < 50.0);
+
+            // The smoother improves the early estimates
+            let first_filtered = position_error(&result.filtered[0]);
+            let first_smoothed = position_error(&result.smoothed[0]);
+            assert!(first_smoothed < first_filtered);
+            let smoothed_sigma = result.smoothed[0].covariance.position().trace().sqrt();
+            assert!(smoothed_sigma < result.filtered[0].covariance.position().trace().sqrt());
+            assert!(first_smoothed < 4.0 * smoothed_sigma);
+        }
+    }
+
+    #[test]
+    fn test_editing_and_passes() {
+        let mut measurements = simulate();
+        // A gross outlier late in the first pass
+        let outlier = &mut measurements[60];
+        outlier.values[0] += 1e4;
+
+        let result = KalmanFilter::extended()
+            .with_editing(5.0)
+            .run(&mut guess(), &initial_covariance(), &measurements)
+            .unwrap();
+        let rejected: Vec<usize> = result
+            .residuals
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| !r.accepted)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(rejected, vec![60]);
+
+        assert_eq!(result.passes.len(), 2);
+        assert_eq!(result.passes[0].station.as_deref(), Some("Kourou"));
+        assert_eq!(result.passes[0].measurements, 80);
+        assert_eq!(result.passes[0].rejected, 1);
+        assert!(result.passes[0].max_normalized > 5.0);
+        assert_eq!(result.passes[1].station.as_deref(), Some("Svalbard"));
+        assert!(result.passes[1].rms < 2.0);
+    }
+
+    #[test]
+    fn test_dmc_estimates_acceleration_state() {
+        let measurements = simulate();
+        let result = KalmanFilter::extended()
+            .with_process_noise(ProcessNoise::Dmc {
+                sigma: 1e-6,
+                tau: 600.0,
+            })
+            .run(&mut guess(), &initial_covariance(), &measurements)
+            .unwrap();
+        let last = result.filtered.last().unwrap();
+        assert!(last.acceleration.unwrap().norm() < 1e-4);
+        assert!(position_error(last) < 50.0);
+    }
+
+    #[test]
+    fn test_dmc_noise_matches_quadrature() {
+        let (sigma, tau, dt) = (1e-5, 300.0, 120.0);
+        let q = ProcessNoise::Dmc { sigma, tau }.covariance(dt);
+
+        // Simpson quadrature of g(s)·g(s)ᵀ with g = (α(s), β(s), e(s))
+        let psd = 2.0 * sigma * sigma / tau;
+        let n = 2000;
+        let mut expected = nalgebra::Matrix3::zeros();
+        for i in 0..=n {
+            let s = dt * i as f64 / n as f64;
+            let weight = if i == 0 || i == n {
+                1.0
+            } else if i % 2 == 1 {
+                4.0
+            } else {
+                2.0
+            };
+            let (alpha, beta, decay) = ProcessNoise::dmc_coefficients(tau, s);
+            let g = Vector3::new(alpha, beta, decay);
+            expected += g * g.transpose() * (weight * dt / (3.0 * n as f64) * psd);
+        }
+        for (a, b) in [(0, 0), (0, 3), (0, 6), (3, 3), (3, 6), (6, 6)] {
+            let e = expected[(a / 3, b / 3)];
+            assert!((q[(a, b)] - e).abs() < 1e-8 * e.abs(), "({a}, {b})");
+        }
+
+        // SNC is the white-noise limit
+        let q = ProcessNoise::Snc { sigma }.covariance(dt);
+        assert_eq!(q[(3, 3)], sigma * sigma * dt);
+        assert_eq!(q[(0, 3)], q[(3, 0)]);
+    }
+
+    #[test]
+    fn test_rejects_measurements_before_epoch() {
+        let measurements = simulate();
+        let mut late = propagator(Vector3::from(R0), Vector3::from(V0));
+        late.set_state(
+            late.position(),
+            late.velocity(),
+            epoch().add_duration(Duration::from_seconds(3600.0)),
+        );
+        assert!(KalmanFilter::extended()
+            .run(&mut late, &initial_covariance(), &measurements)
+            .is_err());
+        assert!(KalmanFilter::extended()
+            .with_process_noise(ProcessNoise::Dmc {
+                sigma: 1.0,
+                tau: 0.0
+            })
+            .run(&mut guess(), &initial_covariance(), &measurements)
+            .is_err());
+    }
+}
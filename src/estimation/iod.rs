@@ -0,0 +1,940 @@
+//! Initial orbit determination (IOD)
+//!
+//! Closed-form and iterative methods that turn a handful of observations
+//! into candidate states, for seeding [`BatchLeastSquares`] or
+//! [`KalmanFilter`](crate::estimation::KalmanFilter):
+//!
+//! - Position-only: [`gibbs`] (three well-separated position vectors),
+//!   [`herrick_gibbs`] (three closely spaced vectors, Taylor-series form) and
+//!   [`lambert`] (two positions and the time of flight)
+//! - Angles-only, from three topocentric right ascension/declination
+//!   [`Measurement`]s of ground stations: [`gauss`] with iterative
+//!   refinement of the Lagrange coefficients, [`laplace`] (line-of-sight
+//!   derivatives by interpolation) and [`gooding`], a two-range ("double-r")
+//!   iteration on Lambert arcs
+//!
+//! Gauss and Laplace solve an eighth-degree polynomial for the middle radius
+//! and may return several candidates. Every [`IodSolution`] carries quality
+//! metrics: the coplanarity of the three positions and the RMS misfit of the
+//! observations when the candidate is propagated with two-body dynamics.
+//! Candidates are sorted by that misfit.
+//!
+//! # References
+//! - Vallado, D. A. (2013). Fundamentals of Astrodynamics and Applications,
+//!   §7.2-7.3 and §7.5
+//! - Curtis, H. D. (2013). Orbital Mechanics for Engineering Students, §5.2-5.3
+//!   and §5.10
+//! - Gooding, R. H. (1997). A new procedure for the solution of the classical
+//!   problem of minimal orbit determination from three lines of sight.
+//!   Celestial Mechanics and Dynamical Astronomy, 66, 387-423
+//!
+//! [`BatchLeastSquares`]: crate::estimation::BatchLeastSquares
+
+use nalgebra::{Matrix2, SMatrix, Vector2};
+use pyo3::prelude::*;
+
+use crate::core::error::{PoliastroError, PoliastroResult};
+use crate::core::fast_math::stumpff_cs;
+use crate::core::linalg::Vector3;
+use crate::core::state::CartesianState;
+use crate::core::time::Epoch;
+use crate::estimation::measurements::{Measurement, MeasurementType};
+use crate::maneuvers::lambert::{Lambert, TransferKind};
+
+/// Maximum iterations of the Gauss refinement and of Gooding's method
+const MAX_ITERATIONS: usize = 50;
+
+/// Relative change of the ranges at which the iterations stop
+const RANGE_TOLERANCE: f64 = 1e-10;
+
+/// Initial orbit determination method
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IodMethod {
+    /// Gibbs, from three position vectors
+    Gibbs,
+    /// Herrick-Gibbs, from three closely spaced position vectors
+    HerrickGibbs,
+    /// Lambert arc between two position vectors
+    Lambert,
+    /// Gauss angles-only method with iterative refinement
+    Gauss,
+    /// Laplace angles-only method
+    Laplace,
+    /// Gooding's two-range angles-only iteration
+    Gooding,
+}
+
+impl IodMethod {
+    /// Lowercase method name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Gibbs => "gibbs",
+            Self::HerrickGibbs => "herrick_gibbs",
+            Self::Lambert => "lambert",
+            Self::Gauss => "gauss",
+            Self::Laplace => "laplace",
+            Self::Gooding => "gooding",
+        }
+    }
+}
+
+/// Candidate state from an initial orbit determination method
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone, Copy)]
+pub struct IodSolution {
+    /// Epoch of the state: the middle observation for three-observation
+    /// methods, the first for Lambert
+    pub epoch: Epoch,
+    /// Position (m) and velocity (m/s)
+    pub state: CartesianState,
+    /// Method that produced the candidate
+    pub method: IodMethod,
+    /// Angle of the first position out of the plane of the other two (rad)
+    pub coplanarity: f64,
+    /// RMS misfit of the observations under two-body propagation of the
+    /// candidate: position error (m) for position methods, line-of-sight
+    /// angle (rad) for angles-only methods
+    pub residual_rms: f64,
+    /// Iterations of the refinement, 0 for closed-form methods
+    pub iterations: usize,
+}
+
+/// Gibbs method: state at the second of three coplanar position vectors
+///
+/// Needs well-separated vectors (more than a few degrees apart); use
+/// [`herrick_gibbs`] for closely spaced ones. The epochs are only used for
+/// the solution epoch and the residual metric.
+///
+/// # Errors
+/// Returns an error unless exactly three observations are given, or if the
+/// vectors do not define an orbit (collinear or inconsistent geometry)
+pub fn gibbs(observations: &[(Epoch, Vector3)], mu: f64) -> PoliastroResult<IodSolution> {
+    let [(_, r1), (epoch, r2), (_, r3)] = three(observations)?;
+    let (n1, n2, n3) = (r1.norm(), r2.norm(), r3.norm());
+
+    let n = r1.cross(&r2) * n3 + r2.cross(&r3) * n1 + r3.cross(&r1) * n2;
+    let d = r1.cross(&r2) + r2.cross(&r3) + r3.cross(&r1);
+    let s = r1 * (n2 - n3) + r2 * (n3 - n1) + r3 * (n1 - n2);
+    let nd = n.dot(&d);
+    if nd.is_nan() || nd <= 0.0 {
+        return Err(PoliastroError::invalid_state(
+            "Gibbs method needs three distinct positions on one orbit",
+        ));
+    }
+    let velocity = (d.cross(&r2) / n2 + s) * (mu / nd).sqrt();
+
+    position_solution(
+        IodMethod::Gibbs,
+        epoch,
+        CartesianState::new(r2, velocity),
+        observations,
+        mu,
+    )
+}
+
+/// Herrick-Gibbs method: state at the second of three closely spaced
+/// position vectors, from a Taylor series of the position
+///
+/// # Errors
+/// Returns an error unless exactly three observations are given in strictly
+/// increasing time order
+pub fn herrick_gibbs(observations: &[(Epoch, Vector3)], mu: f64) -> PoliastroResult<IodSolution> {
+    let [(t1, r1), (epoch, r2), (t3, r3)] = three(observations)?;
+    let dt21 = seconds_between(&t1, &epoch);
+    let dt32 = seconds_between(&epoch, &t3);
+    let dt31 = dt21 + dt32;
+    if !(dt21 > 0.0 && dt32 > 0.0) {
+        return Err(PoliastroError::invalid_state(
+            "Herrick-Gibbs needs observations in increasing time order",
+        ));
+    }
+
+    let term =
+        |dt_a: f64, dt_b: f64, r: &Vector3| 1.0 / (dt_a * dt_b) + mu / (12.0 * r.norm().powi(3));
+    let velocity = r1 * (-dt32 * term(dt21, dt31, &r1))
+        + r2 * ((dt32 - dt21) * term(dt21, dt32, &r2))
+        + r3 * (dt21 * term(dt32, dt31, &r3));
+
+    position_solution(
+        IodMethod::HerrickGibbs,
+        epoch,
+        CartesianState::new(r2, velocity),
+        observations,
+        mu,
+    )
+}
+
+/// State at the first of two position vectors from the Lambert arc
+/// connecting them
+///
+/// # Errors
+/// Returns an error if the second epoch does not follow the first or the
+/// Lambert solver fails
+pub fn lambert(
+    first: (Epoch, Vector3),
+    second: (Epoch, Vector3),
+    mu: f64,
+    transfer_kind: TransferKind,
+    revs: u32,
+) -> PoliastroResult<IodSolution> {
+    let tof = seconds_between(&first.0, &second.0);
+    let arc = Lambert::solve(first.1, second.1, tof, mu, transfer_kind, revs)?;
+    position_solution(
+        IodMethod::Lambert,
+        first.0,
+        CartesianState::new(first.1, arc.v1),
+        &[first, second],
+        mu,
+    )
+}
+
+/// Gauss angles-only method with iterative refinement
+///
+/// Solves the eighth-degree polynomial for the middle radius from the
+/// series Lagrange coefficients, then refines each root with exact
+/// two-body coefficients until the ranges settle.
+///
+/// # Returns
+/// Candidate states at the middle observation epoch, best first
+///
+/// # Errors
+/// Returns an error unless given three right ascension/declination
+/// measurements from ground stations in increasing time order, if the lines
+/// of sight are coplanar, or if no root gives positive ranges
+pub fn gauss(observations: &[Measurement], mu: f64) -> PoliastroResult<Vec<IodSolution>> {
+    let sight = Sightings::new(observations)?;
+    let (tau1, tau3) = (sight.dt[0], sight.dt[2]);
+    let tau = tau3 - tau1;
+    let [l1, l2, l3] = sight.lines;
+
+    let p = [l2.cross(&l3), l1.cross(&l3), l1.cross(&l2)];
+    let d0 = l1.dot(&p[0]);
+    if d0.abs() < 1e-12 {
+        return Err(PoliastroError::invalid_state(
+            "Gauss method needs non-coplanar lines of sight",
+        ));
+    }
+    let dm = SMatrix::<f64, 3, 3>::from_fn(|i, j| sight.stations[i].dot(&p[j]));
+
+    let a = (-dm[(0, 1)] * tau3 / tau + dm[(1, 1)] + dm[(2, 1)] * tau1 / tau) / d0;
+    let b = (dm[(0, 1)] * (tau3 * tau3 - tau * tau) * tau3 / tau
+        + dm[(2, 1)] * (tau * tau - tau1 * tau1) * tau1 / tau)
+        / (6.0 * d0);
+    let e = sight.stations[1].dot(&l2);
+    let r2_station = sight.stations[1].norm_squared();
+    let roots = radius_roots(
+        -(a * a + 2.0 * a * e + r2_station),
+        -2.0 * mu * b * (a + e),
+        -(mu * b).powi(2),
+        sight.stations[1].norm(),
+    );
+
+    let mut candidates = Vec::new();
+    for r2 in roots {
+        let r2_cubed = r2.powi(3);
+        let rho2 = a + mu * b / r2_cubed;
+        let rho1 = ((6.0 * (dm[(2, 0)] * tau1 / tau3 + dm[(1, 0)] * tau / tau3) * r2_cubed
+            + mu * dm[(2, 0)] * (tau * tau - tau1 * tau1) * tau1 / tau3)
+            / (6.0 * r2_cubed + mu * (tau * tau - tau3 * tau3))
+            - dm[(0, 0)])
+            / d0;
+        let rho3 = ((6.0 * (dm[(0, 2)] * tau3 / tau1 - dm[(1, 2)] * tau / tau1) * r2_cubed
+            + mu * dm[(0, 2)] * (tau * tau - tau3 * tau3) * tau3 / tau1)
+            / (6.0 * r2_cubed + mu * (tau * tau - tau1 * tau1))
+            - dm[(2, 2)])
+            / d0;
+        if !(rho1 > 0.0 && rho2 > 0.0 && rho3 > 0.0) {
+            continue;
+        }
+
+        // Series coefficients for the first velocity estimate
+        let series = |t: f64| {
+            (
+                1.0 - 0.5 * mu * t * t / r2_cubed,
+                t - mu * t.powi(3) / (6.0 * r2_cubed),
+            )
+        };
+        let mut ranges = [rho1, rho2, rho3];
+        let mut coefficients = [series(tau1), series(tau3)];
+        let mut state = gauss_state(&sight, &ranges, &coefficients);
+        let mut iterations = 0;
+
+        // Refine with exact Lagrange coefficients; stop at the last good
+        // state if the iteration breaks down
+        while iterations < MAX_ITERATIONS {
+            let exact = lagrange_coefficients(&state, &[tau1, tau3], mu);
+            let det = exact[0].0 * exact[1].1 - exact[1].0 * exact[0].1;
+            let c1 = exact[1].1 / det;
+            let c3 = -exact[0].1 / det;
+            let refined = [
+                (-dm[(0, 0)] + dm[(1, 0)] / c1 - dm[(2, 0)] * c3 / c1) / d0,
+                (-c1 * dm[(0, 1)] + dm[(1, 1)] - c3 * dm[(2, 1)]) / d0,
+                (-dm[(0, 2)] * c1 / c3 + dm[(1, 2)] / c3 - dm[(2, 2)]) / d0,
+            ];
+            if !refined.iter().all(|rho| rho.is_finite() && *rho > 0.0) {
+                break;
+            }
+            iterations += 1;
+            let change = (0..3)
+                .map(|i| ((refined[i] - ranges[i]) / refined[i]).abs())
+                .fold(0.0, f64::max);
+            ranges = refined;
+            coefficients = exact;
+            state = gauss_state(&sight, &ranges, &coefficients);
+            if change < RANGE_TOLERANCE {
+                break;
+            }
+        }
+
+        candidates.push(sight.solution(IodMethod::Gauss, state, iterations, mu));
+    }
+    sorted(candidates, "Gauss")
+}
+
+/// Laplace angles-only method
+///
+/// Differentiates the line of sight at the middle observation by Lagrange
+/// interpolation through the three observations and solves the
+/// eighth-degree polynomial for the middle radius. Less accurate than
+/// [`gauss`] for widely spaced observations.
+///
+/// # Returns
+/// Candidate states at the middle observation epoch, best first
+///
+/// # Errors
+/// Returns an error unless given three right ascension/declination
+/// measurements from ground stations in increasing time order, or if no
+/// root gives a positive range
+pub fn laplace(observations: &[Measurement], mu: f64) -> PoliastroResult<Vec<IodSolution>> {
+    let sight = Sightings::new(observations)?;
+    let (l, l_dot, l_ddot) = sight.interpolate(&sight.lines);
+    let (station, station_dot) = (sight.stations[1], sight.station_velocities[1]);
+    let station_ddot = sight.interpolate(&sight.station_velocities).1;
+
+    let det = |a: &Vector3, b: &Vector3, c: &Vector3| a.dot(&b.cross(c));
+    let d = 2.0 * det(&l, &l_dot, &l_ddot);
+    if d.abs() < 1e-30 {
+        return Err(PoliastroError::invalid_state(
+            "Laplace method needs a curved line-of-sight track",
+        ));
+    }
+    let d1 = det(&l, &l_dot, &station_ddot);
+    let d2 = det(&l, &l_dot, &station);
+    let d3 = det(&l, &station_ddot, &l_ddot);
+    let d4 = det(&l, &station, &l_ddot);
+
+    // ρ = a + b/r³ with r² = ρ² + 2ρ(L·R) + R²
+    let a = -2.0 * d1 / d;
+    let b = -2.0 * mu * d2 / d;
+    let c = l.dot(&station);
+    let roots = radius_roots(
+        -(a * a + 2.0 * a * c + station.norm_squared()),
+        -2.0 * b * (a + c),
+        -b * b,
+        station.norm(),
+    );
+
+    let mut candidates = Vec::new();
+    for r in roots {
+        let rho = a + b / r.powi(3);
+        if rho.is_nan() || rho <= 0.0 {
+            continue;
+        }
+        let rho_dot = -d3 / d - mu * d4 / (d * r.powi(3));
+        let state = CartesianState::new(station + l * rho, station_dot + l * rho_dot + l_dot * rho);
+        candidates.push(sight.solution(IodMethod::Laplace, state, 0, mu));
+    }
+    sorted(candidates, "Laplace")
+}
+
+/// Gooding's angles-only method
+///
+/// Iterates on the ranges at the first and last observations: the Lambert
+/// arc between the two positions is propagated to the middle epoch and
+/// Newton's method drives its offset from the middle line of sight to zero.
+/// Without `initial_ranges` (m) the iteration starts from each [`gauss`]
+/// candidate.
+///
+/// # Returns
+/// Converged states at the middle observation epoch, best first
+///
+/// # Errors
+/// Returns an error unless given three right ascension/declination
+/// measurements from ground stations in increasing time order, or if no
+/// start converges
+pub fn gooding(
+    observations: &[Measurement],
+    mu: f64,
+    initial_ranges: Option<(f64, f64)>,
+) -> PoliastroResult<Vec<IodSolution>> {
+    let sight = Sightings::new(observations)?;
+    let starts = match initial_ranges {
+        Some(ranges) => vec![ranges],
+        None => gauss(observations, mu)?
+            .iter()
+            .map(|candidate| {
+                let ranges = sight.ranges(&candidate.state, mu);
+                (ranges[0], ranges[2])
+            })
+            .collect(),
+    };
+
+    // Offset of the middle position from the middle line of sight, in two
+    // directions perpendicular to it
+    let (u, w) = perpendicular_basis(&sight.lines[1]);
+    let miss = |ranges: &Vector2<f64>| -> PoliastroResult<(Vector2<f64>, CartesianState)> {
+        let r1 = sight.stations[0] + sight.lines[0] * ranges[0];
+        let r3 = sight.stations[2] + sight.lines[2] * ranges[1];
+        let arc = Lambert::solve(r1, r3, sight.dt[2] - sight.dt[0], mu, TransferKind::Auto, 0)?;
+        let (r2, v2) = propagate_kepler(&r1, &arc.v1, mu, -sight.dt[0]);
+        let offset = r2 - sight.stations[1];
+        Ok((
+            Vector2::new(offset.dot(&u), offset.dot(&w)),
+            CartesianState::new(r2, v2),
+        ))
+    };
+
+    let mut candidates = Vec::new();
+    for (rho1, rho3) in starts {
+        let mut ranges = Vector2::new(rho1, rho3);
+        let mut converged = None;
+        for iteration in 1..=MAX_ITERATIONS {
+            let Ok((residual, _)) = miss(&ranges) else {
+                break;
+            };
+            let mut jacobian = Matrix2::zeros();
+            let mut failed = false;
+            for k in 0..2 {
+                let step = 1e-6 * ranges[k];
+                let mut perturbed = ranges;
+                perturbed[k] += step;
+                match miss(&perturbed) {
+                    Ok((value, _)) => jacobian.set_column(k, &((value - residual) / step)),
+                    Err(_) => failed = true,
+                }
+            }
+            let Some(step) = jacobian.try_inverse().map(|inverse| -inverse * residual) else {
+                break;
+            };
+            if failed {
+                break;
+            }
+            // Halve steps that would put the spacecraft behind a station
+            let mut scale = 1.0;
+            while (ranges + step * scale).min() <= 0.0 && scale > 1e-6 {
+                scale *= 0.5;
+            }
+            ranges += step * scale;
+            if (step * scale).abs().max() < RANGE_TOLERANCE * ranges.max() {
+                converged = miss(&ranges).ok().map(|(_, state)| (state, iteration));
+                break;
+            }
+        }
+        if let Some((state, iterations)) = converged {
+            candidates.push(sight.solution(IodMethod::Gooding, state, iterations, mu));
+        }
+    }
+    sorted(candidates, "Gooding")
+}
+
+/// Three angles-only observations relative to the middle one
+struct Sightings {
+    epochs: [Epoch; 3],
+    /// Times relative to the middle observation (s)
+    dt: [f64; 3],
+    /// Unit lines of sight
+    lines: [Vector3; 3],
+    /// Station positions (m)
+    stations: [Vector3; 3],
+    /// Station velocities (m/s)
+    station_velocities: [Vector3; 3],
+}
+
+impl Sightings {
+    fn new(observations: &[Measurement]) -> PoliastroResult<Self> {
+        let [first, second, third] = three(observations)?;
+        let observations = [first, second, third];
+        let mut lines = [Vector3::zeros(); 3];
+        let mut stations = [Vector3::zeros(); 3];
+        let mut station_velocities = [Vector3::zeros(); 3];
+        for (i, observation) in observations.iter().enumerate() {
+            if observation.kind != MeasurementType::RightAscensionDeclination {
+                return Err(PoliastroError::invalid_state(format!(
+                    "Angles-only IOD needs ra_dec measurements, got {}",
+                    observation.kind.name()
+                )));
+            }
+            let station = observation.station.as_ref().ok_or_else(|| {
+                PoliastroError::invalid_state("An ra_dec measurement needs a station")
+            })?;
+            let (right_ascension, declination) = (observation.values[0], observation.values[1]);
+            lines[i] = Vector3::new(
+                declination.cos() * right_ascension.cos(),
+                declination.cos() * right_ascension.sin(),
+                declination.sin(),
+            );
+            (stations[i], station_velocities[i]) = station.gcrs_state(&observation.epoch)?;
+        }
+
+        let epochs = observations.map(|observation| observation.epoch);
+        let dt = [
+            seconds_between(&epochs[1], &epochs[0]),
+            0.0,
+            seconds_between(&epochs[1], &epochs[2]),
+        ];
+        if !(dt[0] < 0.0 && dt[2] > 0.0) {
+            return Err(PoliastroError::invalid_state(
+                "Angles-only IOD needs observations in increasing time order",
+            ));
+        }
+        Ok(Self {
+            epochs,
+            dt,
+            lines,
+            stations,
+            station_velocities,
+        })
+    }
+
+    /// Value, first and second derivative at the middle epoch of the
+    /// quadratic through three samples
+    fn interpolate(&self, values: &[Vector3; 3]) -> (Vector3, Vector3, Vector3) {
+        let [t1, _, t3] = self.dt;
+        let first = values[0] * (-t3 / (t1 * (t1 - t3)))
+            + values[1] * (-(t1 + t3) / (t1 * t3))
+            + values[2] * (-t1 / (t3 * (t3 - t1)));
+        let second =
+            (values[0] / (t1 * (t1 - t3)) + values[1] / (t1 * t3) + values[2] / (t3 * (t3 - t1)))
+                * 2.0;
+        (values[1], first, second)
+    }
+
+    /// Ranges along the three lines of sight of the positions of a
+    /// two-body trajectory through `state` at the middle epoch
+    fn ranges(&self, state: &CartesianState, mu: f64) -> [f64; 3] {
+        let positions = self.positions(state, mu);
+        [0, 1, 2].map(|i| (positions[i] - self.stations[i]).dot(&self.lines[i]))
+    }
+
+    fn positions(&self, state: &CartesianState, mu: f64) -> [Vector3; 3] {
+        self.dt
+            .map(|dt| propagate_kepler(&state.position, &state.velocity, mu, dt).0)
+    }
+
+    /// Solution with coplanarity and line-of-sight misfit metrics
+    fn solution(
+        &self,
+        method: IodMethod,
+        state: CartesianState,
+        iterations: usize,
+        mu: f64,
+    ) -> IodSolution {
+        let positions = self.positions(&state, mu);
+        let squared: f64 = (0..3)
+            .map(|i| {
+                let line = (positions[i] - self.stations[i]).normalize();
+                line.cross(&self.lines[i])
+                    .norm()
+                    .atan2(line.dot(&self.lines[i]))
+                    .powi(2)
+            })
+            .sum();
+        IodSolution {
+            epoch: self.epochs[1],
+            state,
+            method,
+            coplanarity: coplanarity(&positions),
+            residual_rms: (squared / 3.0).sqrt(),
+            iterations,
+        }
+    }
+}
+
+/// Middle-epoch state of the Gauss method from ranges and the Lagrange
+/// coefficients (f, g) of the first and last observations
+fn gauss_state(
+    sight: &Sightings,
+    ranges: &[f64; 3],
+    coefficients: &[(f64, f64); 2],
+) -> CartesianState {
+    let [r1, r2, r3] = [0, 1, 2].map(|i| sight.stations[i] + sight.lines[i] * ranges[i]);
+    let [(f1, g1), (f3, g3)] = *coefficients;
+    let velocity = (r3 * f1 - r1 * f3) / (f1 * g3 - f3 * g1);
+    CartesianState::new(r2, velocity)
+}
+
+/// Exact Lagrange coefficients (f, g) of a two-body trajectory through
+/// `state` at each time offset
+fn lagrange_coefficients(state: &CartesianState, offsets: &[f64; 2], mu: f64) -> [(f64, f64); 2] {
+    let h = state.position.cross(&state.velocity);
+    let h2 = h.norm_squared();
+    let mut coefficients = [(0.0, 0.0); 2];
+    for (coefficient, dt) in coefficients.iter_mut().zip(offsets) {
+        let (r, _) = propagate_kepler(&state.position, &state.velocity, mu, *dt);
+        *coefficient = (
+            r.cross(&state.velocity).dot(&h) / h2,
+            state.position.cross(&r).dot(&h) / h2,
+        );
+    }
+    coefficients
+}
+
+/// Positive real roots of r⁸ + a·r⁶ + b·r³ + c, from the eigenvalues of the
+/// companion matrix scaled by `scale` and polished with Newton's method
+fn radius_roots(a: f64, b: f64, c: f64, scale: f64) -> Vec<f64> {
+    // Monic polynomial in x = r/scale
+    let mut coefficients = [0.0; 8];
+    coefficients[6] = a / scale.powi(2);
+    coefficients[3] = b / scale.powi(5);
+    coefficients[0] = c / scale.powi(8);
+    let mut companion = SMatrix::<f64, 8, 8>::zeros();
+    for i in 1..8 {
+        companion[(i, i - 1)] = 1.0;
+    }
+    for (i, coefficient) in coefficients.iter().enumerate() {
+        companion[(i, 7)] = -coefficient;
+    }
+
+    let polynomial = |r: f64| {
+        (
+            r.powi(8) + a * r.powi(6) + b * r.powi(3) + c,
+            8.0 * r.powi(7) + 6.0 * a * r.powi(5) + 3.0 * b * r * r,
+        )
+    };
+    let mut roots: Vec<f64> = Vec::new();
+    for root in companion.complex_eigenvalues().iter() {
+        if root.re <= 0.0 || root.im.abs() > 1e-6 * root.re {
+            continue;
+        }
+        let mut r = root.re * scale;
+        for _ in 0..20 {
+            let (value, derivative) = polynomial(r);
+            if derivative == 0.0 {
+                break;
+            }
+            let step = value / derivative;
+            r -= step;
+            if step.abs() < 1e-14 * r.abs() {
+                break;
+            }
+        }
+        if r > 0.0 && !roots.iter().any(|other| (other - r).abs() < 1e-9 * r) {
+            roots.push(r);
+        }
+    }
+    roots
+}
+
+fn position_solution(
+    method: IodMethod,
+    epoch: Epoch,
+    state: CartesianState,
+    observations: &[(Epoch, Vector3)],
+    mu: f64,
+) -> PoliastroResult<IodSolution> {
+    let squared: f64 = observations
+        .iter()
+        .map(|(time, position)| {
+            let dt = seconds_between(&epoch, time);
+            let (predicted, _) = propagate_kepler(&state.position, &state.velocity, mu, dt);
+            (predicted - position).norm_squared()
+        })
+        .sum();
+    let positions: Vec<Vector3> = observations.iter().map(|(_, r)| *r).collect();
+    Ok(IodSolution {
+        epoch,
+        state,
+        method,
+        coplanarity: if positions.len() == 3 {
+            coplanarity(&positions)
+        } else {
+            0.0
+        },
+        residual_rms: (squared / observations.len() as f64).sqrt(),
+        iterations: 0,
+    })
+}
+
+/// Two-body propagation by `dt` with the universal anomaly, valid for all
+/// conic types (Curtis, Algorithms 3.3 and 3.4)
+fn propagate_kepler(r0: &Vector3, v0: &Vector3, mu: f64, dt: f64) -> (Vector3, Vector3) {
+    let sqrt_mu = mu.sqrt();
+    let r0_norm = r0.norm();
+    let radial = r0.dot(v0) / r0_norm;
+    let alpha = 2.0 / r0_norm - v0.norm_squared() / mu;
+
+    let mut chi = sqrt_mu * alpha.abs() * dt;
+    for _ in 0..50 {
+        let (c, s) = stumpff_cs(alpha * chi * chi);
+        let chi2 = chi * chi;
+        let value = r0_norm * radial / sqrt_mu * chi2 * c
+            + (1.0 - alpha * r0_norm) * chi2 * chi * s
+            + r0_norm * chi
+            - sqrt_mu * dt;
+        let derivative = r0_norm * radial / sqrt_mu * chi * (1.0 - alpha * chi2 * s)
+            + (1.0 - alpha * r0_norm) * chi2 * c
+            + r0_norm;
+        let step = value / derivative;
+        chi -= step;
+        if step.abs() <= 1e-13 * chi.abs().max(1.0) {
+            break;
+        }
+    }
+
+    let chi2 = chi * chi;
+    let (c, s) = stumpff_cs(alpha * chi2);
+    let f = 1.0 - chi2 / r0_norm * c;
+    let g = dt - chi2 * chi * s / sqrt_mu;
+    let r = r0 * f + v0 * g;
+    let r_norm = r.norm();
+    let f_dot = sqrt_mu / (r_norm * r0_norm) * (alpha * chi2 * chi * s - chi);
+    let g_dot = 1.0 - chi2 / r_norm * c;
+    (r, r0 * f_dot + v0 * g_dot)
+}
+
+/// Angle of the first position out of the plane of the second and third
+fn coplanarity(positions: &[Vector3]) -> f64 {
+    let normal = positions[1].cross(&positions[2]);
+    let norm = normal.norm() * positions[0].norm();
+    if norm == 0.0 {
+        return 0.0;
+    }
+    (normal.dot(&positions[0]) / norm)
+        .clamp(-1.0, 1.0)
+        .asin()
+        .abs()
+}
+
+fn perpendicular_basis(line: &Vector3) -> (Vector3, Vector3) {
+    let reference = if line.x.abs() < 0.9 {
+        Vector3::x()
+    } else {
+        Vector3::y()
+    };
+    let u = line.cross(&reference).normalize();
+    (u, line.cross(&u))
+}
+
+/// Finite candidates, best first
+fn sorted(candidates: Vec<IodSolution>, method: &str) -> PoliastroResult<Vec<IodSolution>> {
+    let mut candidates: Vec<IodSolution> = candidates
+        .into_iter()
+        .filter(|candidate| candidate.residual_rms.is_finite())
+        .collect();
+    if candidates.is_empty() {
+        return Err(PoliastroError::invalid_state(format!(
+            "{method} method found no orbit consistent with the observations"
+        )));
+    }
+    candidates.sort_by(|a, b| a.residual_rms.total_cmp(&b.residual_rms));
+    Ok(candidates)
+}
+
+fn three<T: Clone>(observations: &[T]) -> PoliastroResult<[T; 3]> {
+    <[T; 3]>::try_from(observations.to_vec()).map_err(|_| {
+        PoliastroError::invalid_state(format!(
+            "Expected three observations, got {}",
+            observations.len()
+        ))
+    })
+}
+
+fn seconds_between(from: &Epoch, to: &Epoch) -> f64 {
+    to.to_tdb_seconds_since_j2000() - from.to_tdb_seconds_since_j2000()
+}
+
+#[pymethods]
+impl IodSolution {
+    /// Epoch of the state
+    #[getter]
+    fn get_epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Candidate state
+    #[getter]
+    fn get_state(&self) -> CartesianState {
+        self.state
+    }
+
+    /// Method name
+    #[getter]
+    fn get_method(&self) -> &'static str {
+        self.method.name()
+    }
+
+    /// Out-of-plane angle of the first position (rad)
+    #[getter]
+    fn get_coplanarity(&self) -> f64 {
+        self.coplanarity
+    }
+
+    /// RMS observation misfit: m for position methods, rad for angles-only
+    #[getter]
+    fn get_residual_rms(&self) -> f64 {
+        self.residual_rms
+    }
+
+    /// Refinement iterations
+    #[getter]
+    fn get_iterations(&self) -> usize {
+        self.iterations
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "IodSolution(method={}, epoch={}, residual_rms={:.3e}, iterations={})",
+            self.method.name(),
+            self.epoch.to_iso_string(),
+            self.residual_rms,
+            self.iterations
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::GM_EARTH;
+    use crate::core::time::Duration;
+    use crate::estimation::test_fixtures::{epoch, tracker, R0, V0};
+
+    /// Two-body truth at `dt` seconds from the reference epoch
+    fn truth(dt: f64) -> (Epoch, Vector3, Vector3) {
+        let (r, v) = propagate_kepler(&Vector3::from(R0), &Vector3::from(V0), GM_EARTH, dt);
+        (epoch().add_duration(Duration::from_seconds(dt)), r, v)
+    }
+
+    fn positions(times: &[f64]) -> Vec<(Epoch, Vector3)> {
+        times
+            .iter()
+            .map(|dt| {
+                let (epoch, r, _) = truth(*dt);
+                (epoch, r)
+            })
+            .collect()
+    }
+
+    fn sightings(times: &[f64]) -> Vec<Measurement> {
+        let station = tracker(0.0);
+        times
+            .iter()
+            .map(|dt| {
+                let (epoch, r, v) = truth(*dt);
+                let template = Measurement::ra_dec(epoch, station.clone(), 0.0, 0.0, 1e-5).unwrap();
+                let values = template.predict(&r, &v).unwrap().0;
+                Measurement::ra_dec(epoch, station.clone(), values[0], values[1], 1e-5).unwrap()
+            })
+            .collect()
+    }
+
+    fn assert_state(solution: &IodSolution, dt: f64, position_tol: f64, velocity_tol: f64) {
+        let (_, r, v) = truth(dt);
+        let dr = (solution.state.position - r).norm();
+        let dv = (solution.state.velocity - v).norm();
+        assert!(
+            dr < position_tol,
+            "{:?}: position error {dr}",
+            solution.method
+        );
+        assert!(
+            dv < velocity_tol,
+            "{:?}: velocity error {dv}",
+            solution.method
+        );
+    }
+
+    #[test]
+    fn test_gibbs_and_herrick_gibbs() {
+        let solution = gibbs(&positions(&[0.0, 900.0, 1800.0]), GM_EARTH).unwrap();
+        assert_eq!(solution.epoch, truth(900.0).0);
+        assert_state(&solution, 900.0, 1e-6, 1e-6);
+        assert!(solution.coplanarity < 1e-12);
+        assert!(solution.residual_rms < 1e-2);
+
+        let solution = herrick_gibbs(&positions(&[0.0, 60.0, 120.0]), GM_EARTH).unwrap();
+        assert_state(&solution, 60.0, 1e-6, 1e-2);
+        assert!(solution.residual_rms < 1.0);
+
+        assert!(gibbs(&positions(&[0.0, 900.0]), GM_EARTH).is_err());
+        let collinear = [0.0, 1.0, 2.0].map(|k| (epoch(), Vector3::new(7e6 * (1.0 + k), 0.0, 0.0)));
+        assert!(gibbs(&collinear, GM_EARTH).is_err());
+        assert!(herrick_gibbs(&positions(&[60.0, 0.0, 120.0]), GM_EARTH).is_err());
+    }
+
+    #[test]
+    fn test_lambert_two_positions() {
+        let observations = positions(&[0.0, 1500.0]);
+        let solution = lambert(
+            observations[0],
+            observations[1],
+            GM_EARTH,
+            TransferKind::Auto,
+            0,
+        )
+        .unwrap();
+        assert_eq!(solution.method, IodMethod::Lambert);
+        assert_state(&solution, 0.0, 1e-6, 1e-3);
+        assert!(solution.residual_rms < 1.0);
+    }
+
+    #[test]
+    fn test_angles_only_methods() {
+        let observations = sightings(&[-300.0, 0.0, 300.0]);
+
+        let candidates = gauss(&observations, GM_EARTH).unwrap();
+        let best = &candidates[0];
+        assert!(best.iterations > 0);
+        assert_state(best, 0.0, 1.0, 1e-3);
+        assert!(best.residual_rms < 1e-9);
+
+        let candidates = gooding(&observations, GM_EARTH, None).unwrap();
+        assert_state(&candidates[0], 0.0, 1.0, 1e-3);
+        assert!(candidates[0].residual_rms < 1e-9);
+
+        // Laplace's interpolated line-of-sight derivatives have errors of
+        // second order in the observation spacing
+        let error = |spacing: f64| {
+            let candidates = laplace(&sightings(&[-spacing, 0.0, spacing]), GM_EARTH).unwrap();
+            (candidates[0].state.position - truth(0.0).1).norm()
+        };
+        let (coarse, fine) = (error(20.0), error(10.0));
+        assert!(fine < 5e3);
+        assert!(fine < 0.3 * coarse);
+    }
+
+    #[test]
+    fn test_gooding_from_initial_ranges() {
+        let observations = sightings(&[-300.0, 0.0, 300.0]);
+        let ranges = Sightings::new(&observations)
+            .unwrap()
+            .ranges(&CartesianState::new(truth(0.0).1, truth(0.0).2), GM_EARTH);
+        let start = (ranges[0] * 1.2, ranges[2] * 0.8);
+        let candidates = gooding(&observations, GM_EARTH, Some(start)).unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert_state(&candidates[0], 0.0, 1.0, 1e-3);
+    }
+
+    #[test]
+    fn test_angles_only_validation() {
+        let mut observations = sightings(&[-300.0, 0.0, 300.0]);
+        observations.swap(0, 2);
+        assert!(gauss(&observations, GM_EARTH).is_err());
+        assert!(laplace(&observations[..2], GM_EARTH).is_err());
+
+        let range = Measurement::range(epoch(), observations[0].station.clone().unwrap(), 1e6, 1.0)
+            .unwrap();
+        assert!(gauss(&[range.clone(), range.clone(), range], GM_EARTH).is_err());
+    }
+
+    #[test]
+    fn test_radius_roots() {
+        let (a, b, c) = (-5e13, -3e20, -1e54);
+        let roots = radius_roots(a, b, c, 6.4e6);
+        assert!(!roots.is_empty());
+        for r in roots {
+            let value = r.powi(8) + a * r.powi(6) + b * r.powi(3) + c;
+            assert!(value.abs() < 1e-9 * r.powi(8));
+        }
+    }
+}
@@ -0,0 +1,690 @@
+//! Tracking measurements and their models
+//!
+//! A [`Measurement`] is an observation of a spacecraft at an [`Epoch`] with
+//! its standard deviations. Ground-based types are taken from a
+//! [`GroundStation`] (an [`Observer`] on the Earth); GNSS positions come from
+//! an onboard receiver.
+//!
+//! | Type | Values | Units |
+//! |------|--------|-------|
+//! | [`MeasurementType::Range`] | ρ | m |
+//! | [`MeasurementType::RangeRate`] | ρ̇ | m/s |
+//! | [`MeasurementType::AzimuthElevation`] | Az, El (topocentric, ENU) | rad |
+//! | [`MeasurementType::RightAscensionDeclination`] | α, δ (topocentric, GCRS) | rad |
+//! | [`MeasurementType::GnssPosition`] | x, y, z (ITRS) | m |
+//!
+//! [`Measurement::predict`] evaluates the model and its partials
+//! H = ∂h/∂(r, v) for a GCRS state at the measurement epoch, which is all an
+//! estimator needs. Station positions are rotated to the GCRS with the
+//! installed Earth Orientation Parameters. The models are geometric:
+//! light time, aberration and atmospheric refraction are not modelled, though
+//! [`crate::estimation::simulation`] includes them in simulated data.
+
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+use nalgebra::{DMatrix, DVector};
+use numpy::{PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::coordinates::earth_orientation::{EarthOrientation, TerrestrialRotation};
+use crate::core::error::{PoliastroError, PoliastroResult};
+use crate::core::linalg::{Matrix3, Vector3};
+use crate::core::time::Epoch;
+use crate::satellite::visibility::Observer;
+
+/// Type of a tracking measurement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MeasurementType {
+    /// Station-to-spacecraft range (m)
+    Range,
+    /// Station-to-spacecraft range rate (m/s)
+    RangeRate,
+    /// Topocentric azimuth (from north through east) and elevation (rad)
+    AzimuthElevation,
+    /// Topocentric right ascension and declination in the GCRS (rad)
+    RightAscensionDeclination,
+    /// Receiver position in the ITRS (m)
+    GnssPosition,
+}
+
+impl MeasurementType {
+    /// Parse a measurement type: "range", "range_rate", "az_el",
+    /// "ra_dec" or "gnss"
+    ///
+    /// # Errors
+    /// Returns an error for an unknown name
+    pub fn from_name(name: &str) -> PoliastroResult<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "range" => Ok(Self::Range),
+            "range_rate" | "doppler" => Ok(Self::RangeRate),
+            "az_el" | "azimuth_elevation" => Ok(Self::AzimuthElevation),
+            "ra_dec" | "right_ascension_declination" => Ok(Self::RightAscensionDeclination),
+            "gnss" | "gnss_position" => Ok(Self::GnssPosition),
+            _ => Err(PoliastroError::invalid_state(format!(
+                "Unknown measurement type '{name}' (expected range, range_rate, az_el, ra_dec \
+                 or gnss)"
+            ))),
+        }
+    }
+
+    /// Canonical name, as accepted by [`Self::from_name`]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Range => "range",
+            Self::RangeRate => "range_rate",
+            Self::AzimuthElevation => "az_el",
+            Self::RightAscensionDeclination => "ra_dec",
+            Self::GnssPosition => "gnss",
+        }
+    }
+
+    /// Number of values of a measurement of this type
+    pub fn dimension(&self) -> usize {
+        match self {
+            Self::Range | Self::RangeRate => 1,
+            Self::AzimuthElevation | Self::RightAscensionDeclination => 2,
+            Self::GnssPosition => 3,
+        }
+    }
+
+    /// Whether this type is taken from a ground station
+    pub fn needs_station(&self) -> bool {
+        !matches!(self, Self::GnssPosition)
+    }
+
+    /// Whether the first value is an angle wrapping at 2π
+    fn wraps_first_value(&self) -> bool {
+        matches!(
+            self,
+            Self::AzimuthElevation | Self::RightAscensionDeclination
+        )
+    }
+}
+
+/// Named tracking station on the Earth
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone)]
+pub struct GroundStation {
+    /// Station identifier, used to group residuals
+    pub name: String,
+    /// Geodetic location (WGS84)
+    pub observer: Observer,
+}
+
+impl GroundStation {
+    /// Create a station at a geodetic location
+    pub fn new(name: impl Into<String>, observer: Observer) -> Self {
+        Self {
+            name: name.into(),
+            observer,
+        }
+    }
+
+    /// Rotation GCRS → ITRS and the station position in the ITRS (m)
+    pub(crate) fn frame(&self, epoch: &Epoch) -> PoliastroResult<(TerrestrialRotation, Vector3)> {
+        let rotation = TerrestrialRotation::new(epoch, &EarthOrientation::at(epoch)?);
+        Ok((rotation, self.observer.to_ecef() * 1000.0))
+    }
+
+    /// Station position (m) and velocity (m/s) in the GCRS at `epoch`
+    ///
+    /// # Errors
+    /// Returns an error if the installed EOP table does not cover `epoch`
+    pub fn gcrs_state(&self, epoch: &Epoch) -> PoliastroResult<(Vector3, Vector3)> {
+        let (rotation, position) = self.frame(epoch)?;
+        Ok(rotation.itrs_to_gcrs(&position, &Vector3::zeros()))
+    }
+}
+
+/// A tracking measurement with its standard deviations
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    /// Time tag
+    pub epoch: Epoch,
+    /// Measurement type
+    pub kind: MeasurementType,
+    /// Observing station (`None` for GNSS)
+    pub station: Option<Arc<GroundStation>>,
+    /// Observed values (see [`MeasurementType`] for units)
+    pub values: DVector<f64>,
+    /// Standard deviations of the values
+    pub sigmas: DVector<f64>,
+}
+
+impl Measurement {
+    /// Create a measurement
+    ///
+    /// # Errors
+    /// Returns an error if the number of values or sigmas does not match the
+    /// type, a sigma is not positive, or a station is missing or superfluous
+    pub fn new(
+        epoch: Epoch,
+        kind: MeasurementType,
+        station: Option<Arc<GroundStation>>,
+        values: &[f64],
+        sigmas: &[f64],
+    ) -> PoliastroResult<Self> {
+        if values.len() != kind.dimension() || sigmas.len() != kind.dimension() {
+            return Err(PoliastroError::invalid_state(format!(
+                "A {} measurement has {} values and sigmas",
+                kind.name(),
+                kind.dimension()
+            )));
+        }
+        if let Some(sigma) = sigmas.iter().find(|s| !(**s > 0.0 && s.is_finite())) {
+            return Err(PoliastroError::invalid_parameter(
+                "sigma",
+                *sigma,
+                "must be positive",
+            ));
+        }
+        if kind.needs_station() != station.is_some() {
+            return Err(PoliastroError::invalid_state(format!(
+                "A {} measurement {} a station",
+                kind.name(),
+                if kind.needs_station() {
+                    "needs"
+                } else {
+                    "does not take"
+                }
+            )));
+        }
+        Ok(Self {
+            epoch,
+            kind,
+            station,
+            values: DVector::from_column_slice(values),
+            sigmas: DVector::from_column_slice(sigmas),
+        })
+    }
+
+    /// Range measurement (m)
+    pub fn range(
+        epoch: Epoch,
+        station: Arc<GroundStation>,
+        range: f64,
+        sigma: f64,
+    ) -> PoliastroResult<Self> {
+        Self::new(
+            epoch,
+            MeasurementType::Range,
+            Some(station),
+            &[range],
+            &[sigma],
+        )
+    }
+
+    /// Range-rate measurement (m/s)
+    pub fn range_rate(
+        epoch: Epoch,
+        station: Arc<GroundStation>,
+        range_rate: f64,
+        sigma: f64,
+    ) -> PoliastroResult<Self> {
+        Self::new(
+            epoch,
+            MeasurementType::RangeRate,
+            Some(station),
+            &[range_rate],
+            &[sigma],
+        )
+    }
+
+    /// Azimuth/elevation measurement (rad)
+    pub fn azimuth_elevation(
+        epoch: Epoch,
+        station: Arc<GroundStation>,
+        azimuth: f64,
+        elevation: f64,
+        sigma: f64,
+    ) -> PoliastroResult<Self> {
+        Self::new(
+            epoch,
+            MeasurementType::AzimuthElevation,
+            Some(station),
+            &[azimuth, elevation],
+            &[sigma, sigma],
+        )
+    }
+
+    /// Topocentric right ascension/declination measurement (rad)
+    pub fn ra_dec(
+        epoch: Epoch,
+        station: Arc<GroundStation>,
+        right_ascension: f64,
+        declination: f64,
+        sigma: f64,
+    ) -> PoliastroResult<Self> {
+        Self::new(
+            epoch,
+            MeasurementType::RightAscensionDeclination,
+            Some(station),
+            &[right_ascension, declination],
+            &[sigma, sigma],
+        )
+    }
+
+    /// GNSS receiver position in the ITRS (m)
+    pub fn gnss_position(epoch: Epoch, position: Vector3, sigma: f64) -> PoliastroResult<Self> {
+        Self::new(
+            epoch,
+            MeasurementType::GnssPosition,
+            None,
+            position.as_slice(),
+            &[sigma; 3],
+        )
+    }
+
+    /// Number of values
+    pub fn dimension(&self) -> usize {
+        self.kind.dimension()
+    }
+
+    /// Name of the observing station, if any
+    pub fn station_name(&self) -> Option<&str> {
+        self.station.as_ref().map(|station| station.name.as_str())
+    }
+
+    /// Predicted values and partials for the GCRS state (r, v) at the
+    /// measurement epoch
+    ///
+    /// # Returns
+    /// The modelled values h(r, v) and the m×6 matrix H = ∂h/∂(r, v)
+    ///
+    /// # Errors
+    /// Returns an error if the EOP table does not cover the epoch or the
+    /// geometry is degenerate (spacecraft at the station or at its zenith for
+    /// azimuth)
+    pub fn predict(
+        &self,
+        r: &Vector3,
+        v: &Vector3,
+    ) -> PoliastroResult<(DVector<f64>, DMatrix<f64>)> {
+        let mut partials = DMatrix::zeros(self.dimension(), 6);
+
+        if self.kind == MeasurementType::GnssPosition {
+            let rotation =
+                TerrestrialRotation::new(&self.epoch, &EarthOrientation::at(&self.epoch)?);
+            let (position, _) = rotation.gcrs_to_itrs(r, v);
+            partials
+                .view_mut((0, 0), (3, 3))
+                .copy_from(&rotation.matrix());
+            return Ok((DVector::from_column_slice(position.as_slice()), partials));
+        }
+
+        let station = self.station.as_ref().ok_or_else(|| {
+            PoliastroError::invalid_state(format!(
+                "A {} measurement needs a station",
+                self.kind.name()
+            ))
+        })?;
+        let (rotation, station_itrs) = station.frame(&self.epoch)?;
+        let (station_r, station_v) = rotation.itrs_to_gcrs(&station_itrs, &Vector3::zeros());
+        let rho = r - station_r;
+        let range = rho.norm();
+        if range == 0.0 {
+            return Err(PoliastroError::invalid_state(
+                "Spacecraft coincides with the station",
+            ));
+        }
+        let unit = rho / range;
+
+        let values = match self.kind {
+            MeasurementType::Range => {
+                partials
+                    .view_mut((0, 0), (1, 3))
+                    .copy_from(&unit.transpose());
+                vec![range]
+            }
+            MeasurementType::RangeRate => {
+                let rho_dot = v - station_v;
+                let range_rate = unit.dot(&rho_dot);
+                let d_position = (rho_dot - unit * range_rate) / range;
+                partials
+                    .view_mut((0, 0), (1, 3))
+                    .copy_from(&d_position.transpose());
+                partials
+                    .view_mut((0, 3), (1, 3))
+                    .copy_from(&unit.transpose());
+                vec![range_rate]
+            }
+            MeasurementType::AzimuthElevation => {
+                // GCRS → ENU, with ENU axes (east, north, up)
+                let to_enu = station.observer.ecef_to_enu_matrix() * rotation.matrix();
+                let enu = to_enu * rho;
+                let (azimuth, elevation, d_angles) = spherical_angles(enu.x, enu.y, enu.z)?;
+                partials
+                    .view_mut((0, 0), (2, 3))
+                    .copy_from(&(d_angles * to_enu));
+                vec![azimuth, elevation]
+            }
+            MeasurementType::RightAscensionDeclination => {
+                // Right ascension from x towards y: the ENU formula with
+                // (east, north) = (y, x)
+                let (right_ascension, declination, d_angles) =
+                    spherical_angles(rho.y, rho.x, rho.z)?;
+                let swap = Matrix3::new(0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+                partials
+                    .view_mut((0, 0), (2, 3))
+                    .copy_from(&(d_angles * swap));
+                vec![right_ascension, declination]
+            }
+            MeasurementType::GnssPosition => unreachable!(),
+        };
+        Ok((DVector::from_vec(values), partials))
+    }
+
+    /// Observed minus computed, with angles wrapped to (−π, π]
+    pub fn residual(&self, predicted: &DVector<f64>) -> DVector<f64> {
+        self.difference(&self.values, predicted)
+    }
+
+    /// Difference a − b of two values of this measurement's type, with
+    /// angles wrapped to (−π, π]
+    pub fn difference(&self, a: &DVector<f64>, b: &DVector<f64>) -> DVector<f64> {
+        let mut difference = a - b;
+        if self.kind.wraps_first_value() {
+            difference[0] = wrap_angle(difference[0]);
+        }
+        difference
+    }
+}
+
+/// Azimuth-like angle atan2(a, b) in [0, 2π), elevation-like angle
+/// asin(c/|ρ|), and their 2×3 partials with respect to (a, b, c)
+fn spherical_angles(
+    a: f64,
+    b: f64,
+    c: f64,
+) -> PoliastroResult<(f64, f64, nalgebra::Matrix2x3<f64>)> {
+    let horizontal2 = a * a + b * b;
+    let range2 = horizontal2 + c * c;
+    if horizontal2 == 0.0 {
+        return Err(PoliastroError::invalid_state(
+            "Azimuth undefined with the spacecraft at the pole of the station frame",
+        ));
+    }
+    let horizontal = horizontal2.sqrt();
+
+    let azimuth = a.atan2(b).rem_euclid(2.0 * PI);
+    let elevation = c.atan2(horizontal);
+    let partials = nalgebra::Matrix2x3::new(
+        b / horizontal2,
+        -a / horizontal2,
+        0.0,
+        -a * c / (range2 * horizontal),
+        -b * c / (range2 * horizontal),
+        horizontal / range2,
+    );
+    Ok((azimuth, elevation, partials))
+}
+
+fn wrap_angle(angle: f64) -> f64 {
+    let wrapped = (angle + PI).rem_euclid(2.0 * PI) - PI;
+    if wrapped == -PI {
+        PI
+    } else {
+        wrapped
+    }
+}
+
+type PyState<'py> = (Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>);
+
+#[pymethods]
+impl GroundStation {
+    /// Create a ground station
+    ///
+    /// # Arguments
+    /// - `name`: Station identifier
+    /// - `latitude_deg`: Geodetic latitude in degrees
+    /// - `longitude_deg`: Longitude in degrees
+    /// - `altitude_km`: Altitude above the WGS84 ellipsoid in km
+    #[new]
+    fn py_new(name: String, latitude_deg: f64, longitude_deg: f64, altitude_km: f64) -> Self {
+        Self::new(
+            name,
+            Observer::new(
+                latitude_deg.to_radians(),
+                longitude_deg.to_radians(),
+                altitude_km,
+            ),
+        )
+    }
+
+    /// Station identifier
+    #[getter]
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Station position (m) and velocity (m/s) in the GCRS at `epoch`
+    #[pyo3(name = "gcrs_state")]
+    fn py_gcrs_state<'py>(&self, py: Python<'py>, epoch: Epoch) -> PyResult<PyState<'py>> {
+        let (r, v) = self.gcrs_state(&epoch)?;
+        Ok((
+            PyArray1::from_slice_bound(py, r.as_slice()),
+            PyArray1::from_slice_bound(py, v.as_slice()),
+        ))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "GroundStation('{}', lat={:.4}°, lon={:.4}°, alt={:.3} km)",
+            self.name,
+            self.observer.latitude.to_degrees(),
+            self.observer.longitude.to_degrees(),
+            self.observer.altitude
+        )
+    }
+}
+
+#[pymethods]
+impl Measurement {
+    /// Create a measurement
+    ///
+    /// # Arguments
+    /// - `epoch`: Time tag
+    /// - `kind`: "range" (m), "range_rate" (m/s), "az_el" (rad), "ra_dec"
+    ///   (rad) or "gnss" (ITRS position, m)
+    /// - `values`: Observed values
+    /// - `sigmas`: Standard deviations of the values
+    /// - `station`: Observing station (omitted for GNSS)
+    #[new]
+    #[pyo3(signature = (epoch, kind, values, sigmas, station=None))]
+    fn py_new(
+        epoch: Epoch,
+        kind: &str,
+        values: Vec<f64>,
+        sigmas: Vec<f64>,
+        station: Option<GroundStation>,
+    ) -> PyResult<Self> {
+        Ok(Self::new(
+            epoch,
+            MeasurementType::from_name(kind)?,
+            station.map(Arc::new),
+            &values,
+            &sigmas,
+        )?)
+    }
+
+    /// Time tag
+    #[getter]
+    fn get_epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Measurement type name
+    #[getter]
+    fn get_kind(&self) -> &'static str {
+        self.kind.name()
+    }
+
+    /// Observing station name (None for GNSS)
+    #[getter]
+    fn get_station(&self) -> Option<String> {
+        self.station_name().map(str::to_string)
+    }
+
+    /// Observed values
+    #[getter]
+    fn get_values<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.values.as_slice())
+    }
+
+    /// Standard deviations
+    #[getter]
+    fn get_sigmas<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice_bound(py, self.sigmas.as_slice())
+    }
+
+    /// Predicted values for a GCRS state at the measurement epoch
+    #[pyo3(name = "predict")]
+    fn py_predict<'py>(
+        &self,
+        py: Python<'py>,
+        position: PyReadonlyArray1<f64>,
+        velocity: PyReadonlyArray1<f64>,
+    ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+        let (r, v) = crate::coordinates::frames::state_from_arrays(&position, &velocity)?;
+        let (values, _) = self.predict(&r, &v)?;
+        Ok(PyArray1::from_slice_bound(py, values.as_slice()))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Measurement({}, {}{}, values={:?})",
+            self.kind.name(),
+            self.epoch.to_iso_string(),
+            self.station_name()
+                .map(|name| format!(", station='{name}'"))
+                .unwrap_or_default(),
+            self.values.as_slice()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn station() -> Arc<GroundStation> {
+        Arc::new(GroundStation::new(
+            "Goldstone",
+            Observer::new(35.4_f64.to_radians(), -116.9_f64.to_radians(), 1.0),
+        ))
+    }
+
+    fn epoch() -> Epoch {
+        Epoch::from_gregorian_utc(2024, 3, 1, 12, 0, 0, 0)
+    }
+
+    /// A state above the station, moving roughly eastwards
+    fn visible_state() -> (Vector3, Vector3) {
+        let (r_station, _) = station().gcrs_state(&epoch()).unwrap();
+        let up = r_station.normalize();
+        let east = Vector3::z().cross(&up).normalize();
+        let north = up.cross(&east);
+        let r = r_station + up * 800e3 + north * 300e3 + east * 200e3;
+        (r, east * 7000.0 + north * 1500.0 + up * 100.0)
+    }
+
+    fn assert_partials_match(measurement: &Measurement) {
+        let (r, v) = visible_state();
+        let (_, partials) = measurement.predict(&r, &v).unwrap();
+        for j in 0..6 {
+            let step = if j < 3 { 1.0 } else { 1e-3 };
+            let (mut r_p, mut v_p, mut r_m, mut v_m) = (r, v, r, v);
+            if j < 3 {
+                r_p[j] += step;
+                r_m[j] -= step;
+            } else {
+                v_p[j - 3] += step;
+                v_m[j - 3] -= step;
+            }
+            let plus = measurement.predict(&r_p, &v_p).unwrap().0;
+            let minus = measurement.predict(&r_m, &v_m).unwrap().0;
+            for i in 0..measurement.dimension() {
+                let numeric = (plus[i] - minus[i]) / (2.0 * step);
+                let scale = partials.row(i).amax().max(1e-12);
+                assert!(
+                    (partials[(i, j)] - numeric).abs() < 1e-6 * scale,
+                    "{} row {i} column {j}: {} vs {numeric}",
+                    measurement.kind.name(),
+                    partials[(i, j)]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_partials_match_finite_differences() {
+        let station = station();
+        for measurement in [
+            Measurement::range(epoch(), station.clone(), 0.0, 1.0),
+            Measurement::range_rate(epoch(), station.clone(), 0.0, 1.0),
+            Measurement::azimuth_elevation(epoch(), station.clone(), 0.0, 0.0, 1.0),
+            Measurement::ra_dec(epoch(), station.clone(), 0.0, 0.0, 1.0),
+            Measurement::gnss_position(epoch(), Vector3::zeros(), 1.0),
+        ] {
+            assert_partials_match(&measurement.unwrap());
+        }
+    }
+
+    #[test]
+    fn test_models_agree_with_visibility() {
+        use crate::satellite::visibility::compute_azimuth_elevation_rate;
+
+        let station = station();
+        let (r, v) = visible_state();
+        let rotation = TerrestrialRotation::new(&epoch(), &EarthOrientation::at(&epoch()).unwrap());
+        let (r_itrs, v_itrs) = rotation.gcrs_to_itrs(&r, &v);
+        let km = |x: Vector3| [x.x / 1000.0, x.y / 1000.0, x.z / 1000.0];
+        let topo = compute_azimuth_elevation_rate(&km(r_itrs), &km(v_itrs), &station.observer);
+
+        let predict = |measurement: PoliastroResult<Measurement>| {
+            measurement.unwrap().predict(&r, &v).unwrap().0
+        };
+        let range = predict(Measurement::range(epoch(), station.clone(), 0.0, 1.0));
+        let range_rate = predict(Measurement::range_rate(epoch(), station.clone(), 0.0, 1.0));
+        let az_el = predict(Measurement::azimuth_elevation(
+            epoch(),
+            station.clone(),
+            0.0,
+            0.0,
+            1.0,
+        ));
+
+        assert!((range[0] / 1000.0 - topo.range).abs() < 1e-6);
+        assert!((range_rate[0] / 1000.0 - topo.range_rate.unwrap()).abs() < 1e-9);
+        assert!((az_el[0] - topo.azimuth).abs() < 1e-9);
+        assert!((az_el[1] - topo.elevation).abs() < 1e-9);
+        assert!(az_el[1] > 0.0);
+    }
+
+    #[test]
+    fn test_validation_and_residual_wrapping() {
+        assert!(Measurement::range(epoch(), station(), 1e6, 0.0).is_err());
+        assert!(Measurement::new(epoch(), MeasurementType::Range, None, &[1.0], &[1.0]).is_err());
+        assert!(Measurement::new(
+            epoch(),
+            MeasurementType::GnssPosition,
+            Some(station()),
+            &[0.0; 3],
+            &[1.0; 3]
+        )
+        .is_err());
+        assert_eq!(
+            MeasurementType::from_name("az_el").unwrap(),
+            MeasurementType::AzimuthElevation
+        );
+        assert!(MeasurementType::from_name("lidar").is_err());
+
+        let measurement =
+            Measurement::azimuth_elevation(epoch(), station(), 0.01, 0.5, 1e-4).unwrap();
+        let residual = measurement.residual(&DVector::from_vec(vec![2.0 * PI - 0.01, 0.4]));
+        assert!((residual[0] - 0.02).abs() < 1e-12);
+        assert!((residual[1] - 0.1).abs() < 1e-12);
+    }
+}
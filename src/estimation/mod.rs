@@ -0,0 +1,32 @@
+//! Orbit determination
+//!
+//! This module provides:
+//! - Tracking measurement models (range, range rate, azimuth/elevation,
+//!   right ascension/declination, GNSS position) with their partials
+//! - Weighted batch least-squares estimation on a numerical propagator
+//! - Extended and unscented Kalman filters with process noise, measurement
+//!   editing and Rauch-Tung-Striebel smoothing
+//! - Initial orbit determination from positions (Gibbs, Herrick-Gibbs,
+//!   Lambert) and from angles only (Gauss, Laplace, Gooding)
+//! - Simulated ground-station measurements with light time, aberration,
+//!   tropospheric effects, masks, biases and noise
+
+pub mod batch;
+pub mod filters;
+pub mod iod;
+pub mod measurements;
+pub mod simulation;
+
+#[cfg(test)]
+mod test_fixtures;
+
+pub use batch::{BatchLeastSquares, BatchSolution, Residual};
+pub use filters::{
+    FilterEstimate, FilterResidual, FilterResult, FilterType, KalmanFilter, PassSummary,
+    ProcessNoise,
+};
+pub use iod::{IodMethod, IodSolution};
+pub use measurements::{GroundStation, Measurement, MeasurementType};
+pub use simulation::{
+    two_way_doppler, ElevationMask, MeasurementSimulator, SimulatedStation, Troposphere,
+};
@@ -0,0 +1,995 @@
+//! Simulated tracking measurements from ground stations
+//!
+//! [`MeasurementSimulator`] observes a propagated trajectory (an
+//! [`Ephemeris`] of the GCRS state in TDB seconds since J2000) from a set of
+//! [`SimulatedStation`]s and returns [`Measurement`]s ready for the
+//! estimators. Unlike the geometric models of [`Measurement::predict`], the
+//! simulated values include:
+//!
+//! - Light time: the signal leaves the spacecraft at the transmit time t_T
+//!   solving c·(t_R − t_T) = |r(t_T) − R(t_R)| for the reception time t_R.
+//!   Range and range rate are two-way: the mean of the uplink and downlink
+//!   legs, with the range rate the derivative of that mean with respect to
+//!   t_R (its two-way Doppler shift is [`two_way_doppler`]).
+//! - Aberration: azimuth/elevation are apparent directions, aberrated by the
+//!   station velocity in the GCRS (diurnal aberration). Right
+//!   ascension/declination are astrometric, as reduced against catalogue
+//!   stars that are themselves displaced by the station's barycentric
+//!   velocity, so they carry the annual (stellar) aberration with opposite
+//!   sign. Both use the relativistic formula.
+//! - Troposphere: range is delayed by the Saastamoinen zenith delay mapped
+//!   with the Black & Eisner function, and elevation is raised by the
+//!   Sæmundsson refraction formula. Right ascension/declination are reduced
+//!   against stars seen through the same atmosphere and are not refracted.
+//! - Station masks: a station observes only while the apparent elevation is
+//!   above its [`ElevationMask`].
+//! - Errors: per-station constant biases and white Gaussian noise with the
+//!   measurement sigmas, from a seeded generator so simulations repeat.
+//!
+//! With light time, aberration, troposphere and noise disabled the values
+//! equal [`Measurement::predict`] for the state at the measurement epoch.
+//! GNSS positions, if requested, are simulated once per epoch with noise only.
+//!
+//! # Example
+//! ```rust,ignore
+//! use astrora_core::estimation::{MeasurementSimulator, MeasurementType, SimulatedStation};
+//!
+//! let simulator = MeasurementSimulator::new(42)
+//!     .with_measurement(MeasurementType::Range, 5.0)
+//!     .with_measurement(MeasurementType::AzimuthElevation, 1e-4)
+//!     .with_station(
+//!         SimulatedStation::new(station)
+//!             .with_mask(ElevationMask::constant(10f64.to_radians()))
+//!             .with_troposphere(Troposphere::default())
+//!             .with_bias(MeasurementType::Range, &[12.0]),
+//!     );
+//! let measurements = simulator.simulate(&propagator.ephemeris_to(end)?, &epochs)?;
+//! ```
+//!
+//! # References
+//! - Montenbruck & Gill, "Satellite Orbits", §6.2–6.4 (2000)
+//! - Moyer, "Formulation for Observed and Computed Values of Deep Space
+//!   Network Data Types for Navigation", JPL (2000)
+//! - Sæmundsson, "Astronomical Refraction", Sky and Telescope 72 (1986)
+
+use std::collections::HashMap;
+use std::f64::consts::TAU;
+use std::sync::Arc;
+
+use nalgebra::DVector;
+use numpy::PyReadonlyArray2;
+use pyo3::prelude::*;
+
+use crate::core::constants::C;
+use crate::core::error::{PoliastroError, PoliastroResult};
+use crate::core::linalg::Vector3;
+use crate::core::numerical::Ephemeris;
+use crate::core::time::{Duration, Epoch};
+use crate::ephemeris::earth_barycentric_state;
+use crate::estimation::measurements::{GroundStation, Measurement, MeasurementType};
+
+/// Light-time iterations; the solution converges to 1 ps in three or four
+const LIGHT_TIME_ITERATIONS: usize = 10;
+
+/// Light-time convergence tolerance (s)
+const LIGHT_TIME_TOLERANCE: f64 = 1e-12;
+
+/// Lowest elevation passed to the refraction formula (degrees)
+const REFRACTION_FLOOR: f64 = -1.0;
+
+/// Surface meteorological conditions at a station
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Troposphere {
+    /// Total pressure (hPa)
+    pub pressure: f64,
+    /// Temperature (K)
+    pub temperature: f64,
+    /// Relative humidity (0 to 1)
+    pub relative_humidity: f64,
+}
+
+impl Default for Troposphere {
+    /// Standard sea-level atmosphere at 50 % humidity
+    fn default() -> Self {
+        Self::new(1013.25, 288.15, 0.5)
+    }
+}
+
+impl Troposphere {
+    /// Conditions from pressure (hPa), temperature (K) and relative
+    /// humidity (0 to 1)
+    pub fn new(pressure: f64, temperature: f64, relative_humidity: f64) -> Self {
+        Self {
+            pressure,
+            temperature,
+            relative_humidity,
+        }
+    }
+
+    /// Partial pressure of water vapour (hPa), from the Magnus formula
+    pub fn water_vapour_pressure(&self) -> f64 {
+        let celsius = self.temperature - 273.15;
+        self.relative_humidity * 6.1078 * (17.27 * celsius / (celsius + 237.3)).exp()
+    }
+
+    /// Saastamoinen zenith delay (m) at a geodetic latitude (rad) and
+    /// altitude (km)
+    pub fn zenith_delay(&self, latitude: f64, altitude: f64) -> f64 {
+        let gravity = 1.0 - 0.00266 * (2.0 * latitude).cos() - 0.00028 * altitude;
+        let hydrostatic = 0.0022768 * self.pressure / gravity;
+        let wet = 0.002277 * (1255.0 / self.temperature + 0.05) * self.water_vapour_pressure();
+        hydrostatic + wet
+    }
+
+    /// One-way range delay (m) at a geometric elevation (rad), with the
+    /// Black & Eisner mapping function 1.001/√(0.002001 + sin²E)
+    pub fn range_delay(&self, elevation: f64, latitude: f64, altitude: f64) -> f64 {
+        let sin_elevation = elevation.sin();
+        let mapping = 1.001 / (0.002001 + sin_elevation * sin_elevation).sqrt();
+        mapping * self.zenith_delay(latitude, altitude)
+    }
+
+    /// Refraction (rad) to add to a geometric elevation (rad), from the
+    /// Sæmundsson formula scaled to the pressure and temperature
+    ///
+    /// The refraction is about 29′ at the horizon and 1′ at 45°; below −1°
+    /// it is held at its −1° value.
+    pub fn refraction(&self, elevation: f64) -> f64 {
+        let degrees = elevation.to_degrees().max(REFRACTION_FLOOR);
+        let arcminutes = 1.02 / (degrees + 10.3 / (degrees + 5.11)).to_radians().tan();
+        let scale = self.pressure / 1010.0 * 283.0 / self.temperature;
+        (arcminutes * scale / 60.0).to_radians()
+    }
+}
+
+/// Minimum elevation of a station as a function of azimuth
+///
+/// The minimum elevation is interpolated linearly between the given
+/// (azimuth, elevation) points, wrapping around north.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElevationMask {
+    points: Vec<(f64, f64)>,
+}
+
+impl Default for ElevationMask {
+    /// The horizon
+    fn default() -> Self {
+        Self::constant(0.0)
+    }
+}
+
+impl ElevationMask {
+    /// The same minimum elevation (rad) at every azimuth
+    pub fn constant(min_elevation: f64) -> Self {
+        Self {
+            points: vec![(0.0, min_elevation)],
+        }
+    }
+
+    /// Mask through (azimuth, minimum elevation) points in radians
+    ///
+    /// # Errors
+    /// Returns an error if there are no points or the azimuths are not
+    /// strictly increasing in [0, 2π)
+    pub fn new(points: &[(f64, f64)]) -> PoliastroResult<Self> {
+        if points.is_empty() {
+            return Err(PoliastroError::invalid_state(
+                "An elevation mask needs at least one point",
+            ));
+        }
+        if let Some(&(azimuth, _)) = points
+            .iter()
+            .find(|(azimuth, _)| !(0.0..TAU).contains(azimuth))
+        {
+            return Err(PoliastroError::out_of_range("azimuth", azimuth, 0.0, TAU));
+        }
+        if points.windows(2).any(|pair| pair[1].0 <= pair[0].0) {
+            return Err(PoliastroError::invalid_state(
+                "Elevation mask azimuths must be strictly increasing",
+            ));
+        }
+        Ok(Self {
+            points: points.to_vec(),
+        })
+    }
+
+    /// Minimum elevation (rad) at `azimuth` (rad)
+    pub fn min_elevation(&self, azimuth: f64) -> f64 {
+        let points = &self.points;
+        let n = points.len();
+        if n == 1 {
+            return points[0].1;
+        }
+        let azimuth = azimuth.rem_euclid(TAU);
+        let next = points.partition_point(|(a, _)| *a <= azimuth);
+        let (a0, e0) = if next == 0 {
+            (points[n - 1].0 - TAU, points[n - 1].1)
+        } else {
+            points[next - 1]
+        };
+        let (a1, e1) = if next == n {
+            (points[0].0 + TAU, points[0].1)
+        } else {
+            points[next]
+        };
+        e0 + (e1 - e0) * (azimuth - a0) / (a1 - a0)
+    }
+
+    /// Whether a direction (rad) is above the mask
+    pub fn is_visible(&self, azimuth: f64, elevation: f64) -> bool {
+        elevation >= self.min_elevation(azimuth)
+    }
+}
+
+/// A ground station with its mask, troposphere and measurement biases
+#[derive(Debug, Clone)]
+pub struct SimulatedStation {
+    /// Observing station
+    pub station: Arc<GroundStation>,
+    /// Elevation mask (the horizon by default)
+    pub mask: ElevationMask,
+    /// Surface conditions (`None` for no tropospheric effects)
+    pub troposphere: Option<Troposphere>,
+    biases: HashMap<MeasurementType, DVector<f64>>,
+}
+
+impl SimulatedStation {
+    /// Station observing down to the horizon, without troposphere or biases
+    pub fn new(station: Arc<GroundStation>) -> Self {
+        Self {
+            station,
+            mask: ElevationMask::default(),
+            troposphere: None,
+            biases: HashMap::new(),
+        }
+    }
+
+    /// Set the elevation mask
+    pub fn with_mask(mut self, mask: ElevationMask) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    /// Apply tropospheric delay and refraction for these conditions
+    pub fn with_troposphere(mut self, troposphere: Troposphere) -> Self {
+        self.troposphere = Some(troposphere);
+        self
+    }
+
+    /// Add a constant bias to every measurement of type `kind`
+    pub fn with_bias(mut self, kind: MeasurementType, bias: &[f64]) -> Self {
+        self.biases.insert(kind, DVector::from_column_slice(bias));
+        self
+    }
+
+    /// Bias of measurements of type `kind`, if any
+    pub fn bias(&self, kind: MeasurementType) -> Option<&DVector<f64>> {
+        self.biases.get(&kind)
+    }
+}
+
+/// Simulator of tracking measurements of a propagated trajectory
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone)]
+pub struct MeasurementSimulator {
+    measurements: Vec<(MeasurementType, f64)>,
+    stations: Vec<SimulatedStation>,
+    light_time: bool,
+    aberration: bool,
+    noise: bool,
+    seed: u64,
+}
+
+/// Error-free two-way observables of one station at one epoch
+struct Observables {
+    range: f64,
+    range_rate: f64,
+    azimuth: f64,
+    elevation: f64,
+    right_ascension: f64,
+    declination: f64,
+}
+
+impl MeasurementSimulator {
+    /// Simulator without stations or measurement types, with light time,
+    /// aberration and noise from the generator seeded with `seed`
+    pub fn new(seed: u64) -> Self {
+        Self {
+            measurements: Vec::new(),
+            stations: Vec::new(),
+            light_time: true,
+            aberration: true,
+            noise: true,
+            seed,
+        }
+    }
+
+    /// Simulate measurements of type `kind` with standard deviation `sigma`
+    /// (m, m/s or rad) on each value
+    pub fn with_measurement(mut self, kind: MeasurementType, sigma: f64) -> Self {
+        self.measurements.push((kind, sigma));
+        self
+    }
+
+    /// Observe from `station`
+    pub fn with_station(mut self, station: SimulatedStation) -> Self {
+        self.stations.push(station);
+        self
+    }
+
+    /// Enable or disable the light-time correction
+    pub fn with_light_time(mut self, enabled: bool) -> Self {
+        self.light_time = enabled;
+        self
+    }
+
+    /// Enable or disable aberration of the angles
+    pub fn with_aberration(mut self, enabled: bool) -> Self {
+        self.aberration = enabled;
+        self
+    }
+
+    /// Enable or disable the Gaussian noise (sigmas are still reported)
+    pub fn with_noise(mut self, enabled: bool) -> Self {
+        self.noise = enabled;
+        self
+    }
+
+    /// Stations observing the trajectory
+    pub fn stations(&self) -> &[SimulatedStation] {
+        &self.stations
+    }
+
+    /// Simulate the measurements received at each of `epochs`
+    ///
+    /// Measurements are ordered by epoch, then station, then type, and a
+    /// station contributes only at epochs where the spacecraft is above its
+    /// mask. `ephemeris` holds the GCRS state [r, v] (m, m/s) against TDB
+    /// seconds since J2000, as from [`NumericalPropagator::ephemeris_to`]; with
+    /// light time it must cover the two-way light time before each epoch.
+    ///
+    /// [`NumericalPropagator::ephemeris_to`]: crate::propagators::propagator::NumericalPropagator::ephemeris_to
+    ///
+    /// # Errors
+    /// Returns an error if the ephemeris is not a 6-component state or does
+    /// not cover an epoch, a bias does not match its measurement type, a
+    /// sigma is not positive, or the EOP table does not cover an epoch
+    pub fn simulate(
+        &self,
+        ephemeris: &Ephemeris,
+        epochs: &[Epoch],
+    ) -> PoliastroResult<Vec<Measurement>> {
+        if ephemeris.dimension() != 6 {
+            return Err(PoliastroError::invalid_state(format!(
+                "Simulation needs an ephemeris of [r, v], not {} components",
+                ephemeris.dimension()
+            )));
+        }
+        for station in &self.stations {
+            if let Some((kind, bias)) = station
+                .biases
+                .iter()
+                .find(|(kind, bias)| bias.len() != kind.dimension())
+            {
+                return Err(PoliastroError::invalid_state(format!(
+                    "A {} bias of station {} has {} values, not {}",
+                    kind.name(),
+                    station.station.name,
+                    bias.len(),
+                    kind.dimension()
+                )));
+            }
+        }
+
+        let mut normal = NormalSampler::new(self.seed);
+        let mut measurements = Vec::new();
+        for epoch in epochs {
+            for &(_, sigma) in self
+                .measurements
+                .iter()
+                .filter(|(kind, _)| !kind.needs_station())
+            {
+                let (r, v) = state_at(ephemeris, epoch.to_tdb_seconds_since_j2000())?;
+                let template = Measurement::gnss_position(*epoch, Vector3::zeros(), sigma)?;
+                let (mut values, _) = template.predict(&r, &v)?;
+                self.add_noise(&mut values, sigma, &mut normal);
+                measurements.push(Measurement::gnss_position(
+                    *epoch,
+                    Vector3::from_column_slice(values.as_slice()),
+                    sigma,
+                )?);
+            }
+
+            for station in &self.stations {
+                let Some(observables) = self.observe(ephemeris, station, epoch)? else {
+                    continue;
+                };
+                for &(kind, sigma) in self
+                    .measurements
+                    .iter()
+                    .filter(|(kind, _)| kind.needs_station())
+                {
+                    let mut values = DVector::from_vec(match kind {
+                        MeasurementType::Range => vec![observables.range],
+                        MeasurementType::RangeRate => vec![observables.range_rate],
+                        MeasurementType::AzimuthElevation => {
+                            vec![observables.azimuth, observables.elevation]
+                        }
+                        MeasurementType::RightAscensionDeclination => {
+                            vec![observables.right_ascension, observables.declination]
+                        }
+                        MeasurementType::GnssPosition => unreachable!(),
+                    });
+                    if let Some(bias) = station.bias(kind) {
+                        values += bias;
+                    }
+                    self.add_noise(&mut values, sigma, &mut normal);
+                    if kind.dimension() == 2 {
+                        values[0] = values[0].rem_euclid(TAU);
+                    }
+                    measurements.push(Measurement::new(
+                        *epoch,
+                        kind,
+                        Some(station.station.clone()),
+                        values.as_slice(),
+                        &vec![sigma; kind.dimension()],
+                    )?);
+                }
+            }
+        }
+        Ok(measurements)
+    }
+
+    fn add_noise(&self, values: &mut DVector<f64>, sigma: f64, normal: &mut NormalSampler) {
+        if self.noise {
+            values
+                .iter_mut()
+                .for_each(|x| *x += sigma * normal.sample());
+        }
+    }
+
+    /// Observables of `station` at reception `epoch`, or `None` if the
+    /// spacecraft is below the mask
+    fn observe(
+        &self,
+        ephemeris: &Ephemeris,
+        station: &SimulatedStation,
+        epoch: &Epoch,
+    ) -> PoliastroResult<Option<Observables>> {
+        let t = epoch.to_tdb_seconds_since_j2000();
+        let (rotation, station_itrs) = station.station.frame(epoch)?;
+        let (station_r, station_v) = rotation.itrs_to_gcrs(&station_itrs, &Vector3::zeros());
+
+        // Downlink: transmit time t − τ_d
+        let (mut r, mut v) = state_at(ephemeris, t)?;
+        if self.light_time {
+            let mut downlink = 0.0;
+            for _ in 0..LIGHT_TIME_ITERATIONS {
+                let delay = (r - station_r).norm() / C;
+                let converged = (delay - downlink).abs() <= LIGHT_TIME_TOLERANCE;
+                downlink = delay;
+                (r, v) = state_at(ephemeris, t - downlink)?;
+                if converged {
+                    break;
+                }
+            }
+        }
+        let rho = r - station_r;
+        let unit = rho / rho.norm();
+
+        let (range, range_rate) = if self.light_time {
+            // d(ρ_d)/dt_R, with dt_T/dt_R = 1 − ρ̇_d/c
+            let downlink_rate = unit.dot(&(v - station_v)) / (1.0 + unit.dot(&v) / C);
+            let transmit_rate = 1.0 - downlink_rate / C;
+
+            // Uplink: emitted by the station at t_T − τ_u
+            let transmit = epoch.add_duration(Duration::from_seconds(-rho.norm() / C));
+            let (mut uplink_r, mut uplink_v) = (station_r, station_v);
+            let mut uplink = 0.0;
+            for _ in 0..LIGHT_TIME_ITERATIONS {
+                let delay = (r - uplink_r).norm() / C;
+                let converged = (delay - uplink).abs() <= LIGHT_TIME_TOLERANCE;
+                uplink = delay;
+                (uplink_r, uplink_v) = station
+                    .station
+                    .gcrs_state(&transmit.add_duration(Duration::from_seconds(-uplink)))?;
+                if converged {
+                    break;
+                }
+            }
+            let uplink_rho = r - uplink_r;
+            let uplink_unit = uplink_rho / uplink_rho.norm();
+            // d(ρ_u)/dt_T, with dt_U/dt_T = 1 − ρ̇_u/c
+            let uplink_rate =
+                uplink_unit.dot(&(v - uplink_v)) / (1.0 - uplink_unit.dot(&uplink_v) / C);
+            (
+                0.5 * (rho.norm() + uplink_rho.norm()),
+                0.5 * (downlink_rate + uplink_rate * transmit_rate),
+            )
+        } else {
+            (rho.norm(), unit.dot(&(v - station_v)))
+        };
+
+        let (apparent, astrometric) = if self.aberration {
+            let (_, earth_v) = earth_barycentric_state(epoch)?;
+            let apparent = aberrate(&unit, &(station_v / C));
+            (apparent, aberrate(&apparent, &(-(earth_v + station_v) / C)))
+        } else {
+            (unit, unit)
+        };
+
+        let observer = &station.station.observer;
+        let enu = observer.ecef_to_enu_matrix() * rotation.matrix() * apparent;
+        let azimuth = enu.x.atan2(enu.y).rem_euclid(TAU);
+        let mut elevation = enu.z.atan2(enu.x.hypot(enu.y));
+        let mut range = range;
+        if let Some(troposphere) = &station.troposphere {
+            range += troposphere.range_delay(elevation, observer.latitude, observer.altitude);
+            elevation += troposphere.refraction(elevation);
+        }
+        if !station.mask.is_visible(azimuth, elevation) {
+            return Ok(None);
+        }
+
+        Ok(Some(Observables {
+            range,
+            range_rate,
+            azimuth,
+            elevation,
+            right_ascension: astrometric.y.atan2(astrometric.x).rem_euclid(TAU),
+            declination: astrometric.z.atan2(astrometric.x.hypot(astrometric.y)),
+        }))
+    }
+}
+
+/// Two-way Doppler shift (Hz) of a carrier at `frequency` (Hz) for a two-way
+/// range rate (m/s): −2·f·ρ̇/c, negative when the range increases
+pub fn two_way_doppler(range_rate: f64, frequency: f64) -> f64 {
+    -2.0 * frequency * range_rate / C
+}
+
+/// Direction `unit` as seen by an observer moving at `beta` = v/c
+/// (relativistic aberration)
+fn aberrate(unit: &Vector3, beta: &Vector3) -> Vector3 {
+    let inverse_gamma = (1.0 - beta.norm_squared()).sqrt();
+    let projection = unit.dot(beta);
+    (unit * inverse_gamma + beta * (1.0 + projection / (1.0 + inverse_gamma))) / (1.0 + projection)
+}
+
+/// Position and velocity from an ephemeris of [r, v]
+fn state_at(ephemeris: &Ephemeris, t: f64) -> PoliastroResult<(Vector3, Vector3)> {
+    let y = ephemeris.evaluate(t)?;
+    Ok((
+        Vector3::new(y[0], y[1], y[2]),
+        Vector3::new(y[3], y[4], y[5]),
+    ))
+}
+
+/// Seeded N(0, 1) generator: SplitMix64 with the Box-Muller transform
+pub(crate) struct NormalSampler {
+    state: u64,
+    spare: Option<f64>,
+}
+
+impl NormalSampler {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            spare: None,
+        }
+    }
+
+    /// Uniform sample in (0, 1)
+    fn uniform(&mut self) -> f64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        ((z >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+
+    pub(crate) fn sample(&mut self) -> f64 {
+        if let Some(spare) = self.spare.take() {
+            return spare;
+        }
+        let radius = (-2.0 * self.uniform().ln()).sqrt();
+        let angle = TAU * self.uniform();
+        self.spare = Some(radius * angle.sin());
+        radius * angle.cos()
+    }
+}
+
+#[pymethods]
+impl MeasurementSimulator {
+    /// Create a simulator
+    ///
+    /// # Arguments
+    /// - `seed`: Seed of the noise generator
+    /// - `light_time`: Apply the light-time correction
+    /// - `aberration`: Aberrate the angles
+    /// - `noise`: Add Gaussian noise with the measurement sigmas
+    #[new]
+    #[pyo3(signature = (seed=0, light_time=true, aberration=true, noise=true))]
+    fn py_new(seed: u64, light_time: bool, aberration: bool, noise: bool) -> Self {
+        Self::new(seed)
+            .with_light_time(light_time)
+            .with_aberration(aberration)
+            .with_noise(noise)
+    }
+
+    /// Simulate measurements of type `kind` ("range", "range_rate",
+    /// "az_el", "ra_dec" or "gnss") with standard deviation `sigma`
+    #[pyo3(name = "add_measurement")]
+    fn py_add_measurement(&mut self, kind: &str, sigma: f64) -> PyResult<()> {
+        self.measurements
+            .push((MeasurementType::from_name(kind)?, sigma));
+        Ok(())
+    }
+
+    /// Observe from `station`
+    ///
+    /// # Arguments
+    /// - `station`: Ground station
+    /// - `min_elevation_deg`: Minimum elevation in degrees
+    /// - `mask_deg`: (n, 2) array of (azimuth, minimum elevation) in degrees,
+    ///   replacing `min_elevation_deg`
+    /// - `troposphere`: Apply tropospheric delay and refraction
+    /// - `pressure`: Surface pressure (hPa)
+    /// - `temperature`: Surface temperature (K)
+    /// - `humidity`: Relative humidity (0 to 1)
+    /// - `biases`: Constant biases by measurement type name
+    #[pyo3(name = "add_station", signature = (
+        station,
+        min_elevation_deg=0.0,
+        mask_deg=None,
+        troposphere=false,
+        pressure=1013.25,
+        temperature=288.15,
+        humidity=0.5,
+        biases=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn py_add_station(
+        &mut self,
+        station: GroundStation,
+        min_elevation_deg: f64,
+        mask_deg: Option<PyReadonlyArray2<f64>>,
+        troposphere: bool,
+        pressure: f64,
+        temperature: f64,
+        humidity: f64,
+        biases: Option<HashMap<String, Vec<f64>>>,
+    ) -> PyResult<()> {
+        let mask = match mask_deg {
+            Some(mask) => {
+                let mask = mask.as_array();
+                if mask.ncols() != 2 {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "mask_deg must have shape (n, 2)",
+                    ));
+                }
+                let points: Vec<(f64, f64)> = mask
+                    .rows()
+                    .into_iter()
+                    .map(|row| (row[0].to_radians(), row[1].to_radians()))
+                    .collect();
+                ElevationMask::new(&points)?
+            }
+            None => ElevationMask::constant(min_elevation_deg.to_radians()),
+        };
+        let mut simulated = SimulatedStation::new(Arc::new(station)).with_mask(mask);
+        if troposphere {
+            simulated =
+                simulated.with_troposphere(Troposphere::new(pressure, temperature, humidity));
+        }
+        for (kind, bias) in biases.unwrap_or_default() {
+            simulated = simulated.with_bias(MeasurementType::from_name(&kind)?, &bias);
+        }
+        self.stations.push(simulated);
+        Ok(())
+    }
+
+    /// Simulate the measurements received at each of `epochs` from an
+    /// ephemeris of [x, y, z, vx, vy, vz] (GCRS, m and m/s)
+    #[pyo3(name = "simulate")]
+    fn py_simulate(
+        &self,
+        ephemeris: PyRef<'_, Ephemeris>,
+        epochs: Vec<Epoch>,
+    ) -> PyResult<Vec<Measurement>> {
+        Ok(self.simulate(&ephemeris, &epochs)?)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "MeasurementSimulator(stations={}, types={:?}, light_time={}, aberration={}, noise={})",
+            self.stations.len(),
+            self.measurements
+                .iter()
+                .map(|(kind, _)| kind.name())
+                .collect::<Vec<_>>(),
+            self.light_time,
+            self.aberration,
+            self.noise
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::GM_EARTH;
+    use crate::estimation::test_fixtures::{epoch, tracker, R0, V0};
+    use crate::propagators::propagator::NumericalPropagator;
+    use std::f64::consts::PI;
+
+    fn at(seconds: f64) -> Epoch {
+        epoch().add_duration(Duration::from_seconds(seconds))
+    }
+
+    /// Two-body trajectory from a minute before the reference epoch
+    fn ephemeris() -> Ephemeris {
+        let mut propagator =
+            NumericalPropagator::new(Vector3::from(R0), Vector3::from(V0), epoch(), GM_EARTH);
+        propagator.propagate_to(at(-60.0)).unwrap();
+        propagator.ephemeris_to(at(1500.0)).unwrap()
+    }
+
+    fn epochs(step: f64, count: usize) -> Vec<Epoch> {
+        (0..count).map(|i| at(step * i as f64)).collect()
+    }
+
+    fn simulator() -> MeasurementSimulator {
+        MeasurementSimulator::new(7)
+            .with_measurement(MeasurementType::Range, 5.0)
+            .with_measurement(MeasurementType::RangeRate, 1e-3)
+            .with_measurement(MeasurementType::AzimuthElevation, 1e-5)
+            .with_measurement(MeasurementType::RightAscensionDeclination, 1e-5)
+            .with_noise(false)
+    }
+
+    fn values_of(measurements: &[Measurement], kind: MeasurementType) -> Vec<DVector<f64>> {
+        measurements
+            .iter()
+            .filter(|m| m.kind == kind)
+            .map(|m| m.values.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_geometric_simulation_matches_models() {
+        let ephemeris = ephemeris();
+        let simulator = simulator()
+            .with_measurement(MeasurementType::GnssPosition, 1.0)
+            .with_light_time(false)
+            .with_aberration(false)
+            .with_station(SimulatedStation::new(tracker(0.2)).with_mask(ElevationMask::constant(-PI)));
+        let measurements = simulator.simulate(&ephemeris, &epochs(60.0, 20)).unwrap();
+        assert_eq!(measurements.len(), 20 * 5);
+
+        for measurement in &measurements {
+            let t = measurement.epoch.to_tdb_seconds_since_j2000();
+            let (r, v) = state_at(&ephemeris, t).unwrap();
+            let (predicted, _) = measurement.predict(&r, &v).unwrap();
+            let residual = measurement.residual(&predicted);
+            let tolerance = match measurement.kind {
+                MeasurementType::Range | MeasurementType::GnssPosition => 1e-6,
+                MeasurementType::RangeRate => 1e-9,
+                _ => 1e-12,
+            };
+            assert!(residual.amax() < tolerance, "{measurement:?}: {residual}");
+        }
+    }
+
+    #[test]
+    fn test_light_time_two_way_range_and_rate() {
+        let ephemeris = ephemeris();
+        let station = SimulatedStation::new(tracker(0.2)).with_mask(ElevationMask::constant(-PI));
+        let simulator = simulator()
+            .with_aberration(false)
+            .with_station(station.clone());
+        let geometric = simulator
+            .clone()
+            .with_light_time(false)
+            .simulate(&ephemeris, &epochs(120.0, 10))
+            .unwrap();
+        let corrected = simulator.simulate(&ephemeris, &epochs(120.0, 10)).unwrap();
+
+        // To first order the signal bounces at t_R − ρ/c
+        let geometric_ranges = values_of(&geometric, MeasurementType::Range);
+        let geometric_rates = values_of(&geometric, MeasurementType::RangeRate);
+        let mut largest: f64 = 0.0;
+        for ((range, rate), corrected) in geometric_ranges
+            .iter()
+            .zip(&geometric_rates)
+            .zip(values_of(&corrected, MeasurementType::Range))
+        {
+            let expected = range[0] - rate[0] * range[0] / C;
+            largest = largest.max((corrected[0] - range[0]).abs());
+            assert!((corrected[0] - expected).abs() < 1e-2);
+        }
+        assert!(largest > 10.0, "{largest}");
+
+        // The two-way range rate is the derivative of the two-way range
+        // (five-point stencil: TDB seconds since J2000 resolve only 0.1 µs)
+        for t in [100.0, 400.0, 700.0] {
+            let times: Vec<Epoch> = [-2.0, -1.0, 0.0, 1.0, 2.0]
+                .iter()
+                .map(|dt| at(t + dt))
+                .collect();
+            let simulated = simulator.simulate(&ephemeris, &times).unwrap();
+            let ranges: Vec<f64> = values_of(&simulated, MeasurementType::Range)
+                .iter()
+                .map(|r| r[0])
+                .collect();
+            let rate = values_of(&simulated, MeasurementType::RangeRate)[2][0];
+            let difference = (ranges[0] - 8.0 * ranges[1] + 8.0 * ranges[3] - ranges[4]) / 12.0;
+            assert!((difference - rate).abs() < 2e-3, "{difference} vs {rate}");
+        }
+    }
+
+    #[test]
+    fn test_aberration() {
+        let ephemeris = ephemeris();
+        let simulator = simulator()
+            .with_light_time(false)
+            .with_station(SimulatedStation::new(tracker(0.2)).with_mask(ElevationMask::constant(-PI)));
+        let times = epochs(300.0, 4);
+        let plain = simulator
+            .clone()
+            .with_aberration(false)
+            .simulate(&ephemeris, &times)
+            .unwrap();
+        let aberrated = simulator.simulate(&ephemeris, &times).unwrap();
+
+        let direction = |angles: &DVector<f64>| {
+            let (ra, dec) = (angles[0], angles[1]);
+            Vector3::new(dec.cos() * ra.cos(), dec.cos() * ra.sin(), dec.sin())
+        };
+        let (_, earth_v) = earth_barycentric_state(&epoch()).unwrap();
+        for (geometric, astrometric) in
+            values_of(&plain, MeasurementType::RightAscensionDeclination)
+                .iter()
+                .zip(values_of(
+                    &aberrated,
+                    MeasurementType::RightAscensionDeclination,
+                ))
+        {
+            // Annual aberration, reversed; the diurnal parts cancel
+            let u = direction(geometric);
+            let shift = direction(&astrometric) - u;
+            let expected = -(earth_v - u * u.dot(&earth_v)) / C;
+            assert!((shift - expected).norm() < 0.01 * expected.norm());
+        }
+
+        // Diurnal aberration of the apparent azimuth/elevation stays below
+        // the station speed over c
+        for (geometric, apparent) in values_of(&plain, MeasurementType::AzimuthElevation)
+            .iter()
+            .zip(values_of(&aberrated, MeasurementType::AzimuthElevation))
+        {
+            let shift = (apparent[1] - geometric[1]).abs();
+            assert!(shift > 0.0 && shift < 500.0 / C);
+        }
+    }
+
+    #[test]
+    fn test_troposphere() {
+        let troposphere = Troposphere::default();
+        let zenith = troposphere.zenith_delay(0.8, 0.0);
+        assert!((2.3..2.5).contains(&zenith), "{zenith}");
+        let low = troposphere.range_delay(5f64.to_radians(), 0.8, 0.0);
+        assert!((9.5..11.5).contains(&(low / zenith)));
+
+        let arcminutes =
+            |elevation: f64| troposphere.refraction(elevation.to_radians()).to_degrees() * 60.0;
+        assert!((arcminutes(0.0) - 29.0).abs() < 1.0);
+        assert!((arcminutes(45.0) - 1.0).abs() < 0.05);
+        assert!(arcminutes(90.0).abs() < 0.01);
+
+        let ephemeris = ephemeris();
+        let times = epochs(60.0, 25);
+        let station = SimulatedStation::new(tracker(0.2));
+        let vacuum = simulator()
+            .with_station(station.clone())
+            .simulate(&ephemeris, &times)
+            .unwrap();
+        let refracted = simulator()
+            .with_station(station.with_troposphere(troposphere))
+            .simulate(&ephemeris, &times)
+            .unwrap();
+        // Refraction lifts the spacecraft above the horizon a little earlier
+        assert!(refracted.len() >= vacuum.len());
+        for (vacuum, refracted) in vacuum.chunks(4).zip(refracted.chunks(4)) {
+            assert_eq!(vacuum[0].epoch, refracted[0].epoch);
+            let elevation = vacuum[2].values[1];
+            let delay = refracted[0].values[0] - vacuum[0].values[0];
+            let expected = troposphere.range_delay(elevation, 0.0, 0.0);
+            assert!((delay - expected).abs() < 0.05 * expected);
+            let lift = refracted[2].values[1] - elevation;
+            assert!((lift - troposphere.refraction(elevation)).abs() < 1e-6);
+            assert_eq!(refracted[3].values, vacuum[3].values);
+        }
+    }
+
+    #[test]
+    fn test_elevation_mask() {
+        let mask = ElevationMask::new(&[(0.0, 0.1), (PI, 0.3)]).unwrap();
+        assert!((mask.min_elevation(PI / 2.0) - 0.2).abs() < 1e-15);
+        assert!((mask.min_elevation(1.5 * PI) - 0.2).abs() < 1e-15);
+        assert!((mask.min_elevation(-PI / 2.0) - 0.2).abs() < 1e-15);
+        assert!(mask.is_visible(0.0, 0.1) && !mask.is_visible(PI, 0.29));
+        assert!(ElevationMask::new(&[]).is_err());
+        assert!(ElevationMask::new(&[(1.0, 0.0), (0.5, 0.0)]).is_err());
+        assert!(ElevationMask::new(&[(7.0, 0.0)]).is_err());
+
+        let ephemeris = ephemeris();
+        let times = epochs(30.0, 50);
+        let horizon = simulator()
+            .with_station(SimulatedStation::new(tracker(0.2)))
+            .simulate(&ephemeris, &times)
+            .unwrap();
+        let masked = simulator()
+            .with_station(SimulatedStation::new(tracker(0.2)).with_mask(mask.clone()))
+            .simulate(&ephemeris, &times)
+            .unwrap();
+        assert!(!masked.is_empty() && masked.len() < horizon.len());
+        for angles in values_of(&masked, MeasurementType::AzimuthElevation) {
+            assert!(mask.is_visible(angles[0], angles[1]));
+        }
+    }
+
+    #[test]
+    fn test_noise_and_bias() {
+        let ephemeris = ephemeris();
+        let times = epochs(10.0, 60);
+        let biased = SimulatedStation::new(tracker(0.2)).with_bias(MeasurementType::Range, &[12.0]);
+        let truth = simulator()
+            .with_station(biased.clone())
+            .simulate(&ephemeris, &times)
+            .unwrap();
+        let noisy = simulator().with_noise(true).with_station(biased.clone());
+        let measurements = noisy.simulate(&ephemeris, &times).unwrap();
+        assert_eq!(
+            values_of(&measurements, MeasurementType::Range),
+            values_of(
+                &noisy.simulate(&ephemeris, &times).unwrap(),
+                MeasurementType::Range
+            )
+        );
+
+        let errors: Vec<f64> = values_of(&measurements, MeasurementType::Range)
+            .iter()
+            .zip(values_of(&truth, MeasurementType::Range))
+            .map(|(noisy, truth)| noisy[0] - truth[0])
+            .collect();
+        let n = errors.len() as f64;
+        let mean = errors.iter().sum::<f64>() / n;
+        let spread = (errors.iter().map(|e| (e - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+        // Measured against the biased truth, so zero mean with sigma 5 m
+        assert!(mean.abs() < 3.0 * 5.0 / n.sqrt(), "{mean}");
+        assert!((3.5..6.5).contains(&spread), "{spread}");
+
+        let unbiased = simulator()
+            .with_station(SimulatedStation::new(tracker(0.2)))
+            .simulate(&ephemeris, &times)
+            .unwrap();
+        assert!((truth[0].values[0] - unbiased[0].values[0] - 12.0).abs() < 1e-9);
+
+        let wrong =
+            SimulatedStation::new(tracker(0.2)).with_bias(MeasurementType::AzimuthElevation, &[0.0]);
+        assert!(simulator()
+            .with_station(wrong)
+            .simulate(&ephemeris, &times)
+            .is_err());
+    }
+
+    #[test]
+    fn test_two_way_doppler() {
+        // Receding at 1 km/s at X band
+        let shift = two_way_doppler(1000.0, 8.4e9);
+        assert!((shift + 2.0 * 8.4e9 * 1000.0 / C).abs() < 1e-9);
+        assert!(shift < 0.0);
+    }
+}
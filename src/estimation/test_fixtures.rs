@@ -0,0 +1,57 @@
+//! Shared fixtures of the orbit determination tests: a reference orbit and
+//! epoch, its propagator and the tracking stations
+
+use std::sync::Arc;
+
+use crate::coordinates::earth_orientation::{EarthOrientation, TerrestrialRotation};
+use crate::core::constants::{GM_EARTH, J2_EARTH, R_EARTH};
+use crate::core::linalg::Vector3;
+use crate::core::time::Epoch;
+use crate::estimation::measurements::GroundStation;
+use crate::propagators::perturbations::J2Perturbation;
+use crate::propagators::propagator::NumericalPropagator;
+use crate::satellite::visibility::Observer;
+
+/// Reference position (m), 500 km up on an inclined orbit
+pub(crate) const R0: [f64; 3] = [6878e3, 0.0, 0.0];
+/// Reference velocity (m/s)
+pub(crate) const V0: [f64; 3] = [0.0, 4500.0, 6000.0];
+
+/// Epoch of the reference state
+pub(crate) fn epoch() -> Epoch {
+    Epoch::from_gregorian_utc(2024, 3, 1, 12, 0, 0, 0)
+}
+
+/// Propagator with J2 from (`r`, `v`) at the reference epoch
+pub(crate) fn propagator(r: Vector3, v: Vector3) -> NumericalPropagator {
+    let mut propagator = NumericalPropagator::new(r, v, epoch(), GM_EARTH);
+    propagator.add_perturbation(J2Perturbation::new(J2_EARTH, R_EARTH));
+    propagator
+}
+
+/// Kourou and Svalbard
+pub(crate) fn stations() -> [Arc<GroundStation>; 2] {
+    [
+        Arc::new(GroundStation::new(
+            "Kourou",
+            Observer::new(5.25_f64.to_radians(), -52.8_f64.to_radians(), 0.0),
+        )),
+        Arc::new(GroundStation::new(
+            "Svalbard",
+            Observer::new(78.2_f64.to_radians(), 15.4_f64.to_radians(), 0.5),
+        )),
+    ]
+}
+
+/// Station a few degrees off the ground track at the reference epoch,
+/// `altitude` km above the ellipsoid
+pub(crate) fn tracker(altitude: f64) -> Arc<GroundStation> {
+    let rotation = TerrestrialRotation::new(&epoch(), &EarthOrientation::at(&epoch()).unwrap());
+    let (r, _) = rotation.gcrs_to_itrs(&Vector3::from(R0), &Vector3::from(V0));
+    let latitude = (r.z / r.norm()).asin() + 3f64.to_radians();
+    let longitude = r.y.atan2(r.x) - 5f64.to_radians();
+    Arc::new(GroundStation::new(
+        "Tracker",
+        Observer::new(latitude, longitude, altitude),
+    ))
+}
@@ -13,8 +13,11 @@ use numpy::{PyArray1, PyArray2, PyReadonlyArray1, PyReadonlyArray2, PyReadwriteA
 pub mod core;
 pub mod propagators;
 pub mod coordinates;
+pub mod ephemeris;
 pub mod maneuvers;
 pub mod satellite;
+pub mod estimation;
+pub mod ccsds;
 pub mod utils;
 
 // Test utilities (only compiled in test mode)
@@ -45,6 +48,7 @@ fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<core::time::Duration>()?;
     m.add_class::<core::elements::OrbitalElements>()?;
     m.add_class::<core::elements::EquinoctialElements>()?;
+    m.add_class::<core::covariance::Covariance6>()?;
 
     // Add coordinate frame classes
     m.add_class::<coordinates::frames::ICRS>()?;
@@ -52,7 +56,28 @@ fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<coordinates::frames::J2000>()?;
     m.add_class::<coordinates::frames::ITRS>()?;
     m.add_class::<coordinates::frames::TEME>()?;
+    m.add_class::<coordinates::frames::MOD>()?;
+    m.add_class::<coordinates::frames::TOD>()?;
+    m.add_class::<coordinates::frames::CIRS>()?;
+    m.add_class::<coordinates::frames::TIRS>()?;
     m.add_class::<coordinates::frames::Perifocal>()?;
+    m.add_class::<coordinates::body_frames::BodyCenteredInertial>()?;
+    m.add_class::<coordinates::body_frames::BodyFixed>()?;
+    m.add_class::<propagators::gravity_field::GravityField>()?;
+    m.add_class::<core::numerical::Ephemeris>()?;
+    m.add_class::<propagators::propagator::NumericalPropagator>()?;
+    m.add_class::<estimation::GroundStation>()?;
+    m.add_class::<estimation::Measurement>()?;
+    m.add_class::<estimation::Residual>()?;
+    m.add_class::<estimation::BatchSolution>()?;
+    m.add_class::<estimation::BatchLeastSquares>()?;
+    m.add_class::<estimation::KalmanFilter>()?;
+    m.add_class::<estimation::FilterEstimate>()?;
+    m.add_class::<estimation::FilterResidual>()?;
+    m.add_class::<estimation::PassSummary>()?;
+    m.add_class::<estimation::FilterResult>()?;
+    m.add_class::<estimation::MeasurementSimulator>()?;
+    m.add_function(wrap_pyfunction!(py_two_way_doppler, m)?)?;
 
     // Add orbital element conversion functions
     m.add_function(wrap_pyfunction!(py_rv_to_coe, m)?)?;
@@ -109,6 +134,7 @@ fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(py_propagate_j2_rk4, m)?)?;
     m.add_function(wrap_pyfunction!(py_propagate_j2_dopri5, m)?)?;
     m.add_function(wrap_pyfunction!(py_propagate_j2_dop853, m)?)?;
+    m.add_function(wrap_pyfunction!(py_propagate_ephemeris, m)?)?;
     m.add_function(wrap_pyfunction!(py_propagate_stm_rk4, m)?)?;
     m.add_function(wrap_pyfunction!(py_propagate_stm_dopri5, m)?)?;
     m.add_function(wrap_pyfunction!(py_propagate_stm_j2_rk4, m)?)?;
@@ -119,6 +145,7 @@ fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     // Atmospheric drag functions
     m.add_function(wrap_pyfunction!(py_exponential_density, m)?)?;
+    m.add_function(wrap_pyfunction!(py_atmospheric_density, m)?)?;
     m.add_function(wrap_pyfunction!(py_drag_acceleration, m)?)?;
     m.add_function(wrap_pyfunction!(py_propagate_drag_rk4, m)?)?;
     m.add_function(wrap_pyfunction!(py_propagate_drag_dopri5, m)?)?;
@@ -139,6 +166,25 @@ fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(py_propagate_srp_rk4, m)?)?;
     m.add_function(wrap_pyfunction!(py_propagate_srp_dopri5, m)?)?;
 
+    // Earth Orientation Parameters
+    m.add_function(wrap_pyfunction!(py_load_eop, m)?)?;
+    m.add_function(wrap_pyfunction!(py_clear_eop, m)?)?;
+    m.add_function(wrap_pyfunction!(py_eop_loaded, m)?)?;
+    m.add_function(wrap_pyfunction!(py_load_nutation_series, m)?)?;
+    m.add_function(wrap_pyfunction!(py_clear_nutation_series, m)?)?;
+    m.add_function(wrap_pyfunction!(py_nutation_model, m)?)?;
+    m.add_function(wrap_pyfunction!(py_load_space_weather, m)?)?;
+    m.add_function(wrap_pyfunction!(py_clear_space_weather, m)?)?;
+    m.add_function(wrap_pyfunction!(py_space_weather_loaded, m)?)?;
+    m.add_function(wrap_pyfunction!(py_set_space_weather_fallback, m)?)?;
+    m.add_function(wrap_pyfunction!(py_space_weather, m)?)?;
+
+    // Solar system ephemerides
+    m.add_function(wrap_pyfunction!(py_load_spk, m)?)?;
+    m.add_function(wrap_pyfunction!(py_clear_ephemeris, m)?)?;
+    m.add_function(wrap_pyfunction!(py_ephemeris_loaded, m)?)?;
+    m.add_function(wrap_pyfunction!(py_ephemeris_state, m)?)?;
+
     // Batch coordinate transformations (parallelized with rayon)
     m.add_function(wrap_pyfunction!(py_batch_gcrs_to_itrs, m)?)?;
     m.add_function(wrap_pyfunction!(py_batch_itrs_to_gcrs, m)?)?;
@@ -170,10 +216,45 @@ fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(py_lambert_solve_batch, m)?)?;
     m.add_function(wrap_pyfunction!(py_lambert_solve_batch_parallel, m)?)?;
 
+    // Initial orbit determination
+    m.add_class::<estimation::IodSolution>()?;
+    m.add_function(wrap_pyfunction!(py_iod_gibbs, m)?)?;
+    m.add_function(wrap_pyfunction!(py_iod_herrick_gibbs, m)?)?;
+    m.add_function(wrap_pyfunction!(py_iod_lambert, m)?)?;
+    m.add_function(wrap_pyfunction!(py_iod_gauss, m)?)?;
+    m.add_function(wrap_pyfunction!(py_iod_laplace, m)?)?;
+    m.add_function(wrap_pyfunction!(py_iod_gooding, m)?)?;
+
     // Satellite operations (SGP4/SDP4 TLE propagation)
     m.add_function(wrap_pyfunction!(py_propagate_tle, m)?)?;
     m.add_function(wrap_pyfunction!(py_propagate_tle_batch, m)?)?;
     m.add_function(wrap_pyfunction!(py_propagate_omm, m)?)?;
+    m.add_function(wrap_pyfunction!(py_tle_to_omm, m)?)?;
+    m.add_function(wrap_pyfunction!(py_omm_to_tle, m)?)?;
+    m.add_function(wrap_pyfunction!(py_parse_omm, m)?)?;
+
+    // CCSDS orbit ephemeris messages
+    m.add_class::<ccsds::oem::OemEphemeris>()?;
+    m.add_function(wrap_pyfunction!(py_parse_oem, m)?)?;
+    m.add_function(wrap_pyfunction!(py_write_oem, m)?)?;
+
+    // CCSDS conjunction data messages
+    m.add_class::<ccsds::cdm::CdmObject>()?;
+    m.add_function(wrap_pyfunction!(py_parse_cdm, m)?)?;
+    m.add_function(wrap_pyfunction!(py_write_cdm, m)?)?;
+
+    // CCSDS orbit parameter messages
+    m.add_class::<ccsds::opm::OpmManeuver>()?;
+    m.add_function(wrap_pyfunction!(py_parse_opm, m)?)?;
+    m.add_function(wrap_pyfunction!(py_write_opm, m)?)?;
+    m.add_function(wrap_pyfunction!(py_hohmann_maneuvers, m)?)?;
+    m.add_function(wrap_pyfunction!(py_bielliptic_maneuvers, m)?)?;
+
+    // CCSDS attitude messages
+    m.add_function(wrap_pyfunction!(py_parse_apm, m)?)?;
+    m.add_function(wrap_pyfunction!(py_write_apm, m)?)?;
+    m.add_function(wrap_pyfunction!(py_parse_aem, m)?)?;
+    m.add_function(wrap_pyfunction!(py_write_aem, m)?)?;
 
     // Satellite visibility and ground station operations
     m.add_function(wrap_pyfunction!(py_compute_azimuth_elevation, m)?)?;
@@ -1645,11 +1726,54 @@ fn py_j2_perturbation<'py>(
     Ok(PyArray1::from_owned_array_bound(py, acc_array))
 }
 
+/// Start a `NumericalPropagator` from (`r0`, `v0`) at `t0` (TDB seconds
+/// since J2000) for the deprecated function `name`, warning that it is
+/// deprecated
+fn deprecated_propagator(
+    py: Python<'_>,
+    name: &str,
+    r0: &PyReadonlyArray1<f64>,
+    v0: &PyReadonlyArray1<f64>,
+    t0: f64,
+    mu: f64,
+) -> PyResult<propagators::propagator::NumericalPropagator> {
+    PyErr::warn_bound(
+        py,
+        &py.get_type_bound::<pyo3::exceptions::PyDeprecationWarning>(),
+        &format!("{name} is deprecated; use NumericalPropagator instead"),
+        1,
+    )?;
+    let (r0, v0) = coordinates::frames::state_from_arrays(r0, v0)?;
+    let epoch = core::time::Epoch::from_tdb_seconds_since_j2000(t0);
+    Ok(propagators::propagator::NumericalPropagator::new(r0, v0, epoch, mu))
+}
+
+/// Propagate by `dt` seconds and return the final position and velocity
+fn propagate_deprecated<'py>(
+    py: Python<'py>,
+    mut propagator: propagators::propagator::NumericalPropagator,
+    dt: f64,
+) -> PyResult<propagators::propagator::PyState<'py>> {
+    let (r, v) = propagator.propagate_by(dt)?;
+    Ok((
+        PyArray1::from_slice_bound(py, r.as_slice()),
+        PyArray1::from_slice_bound(py, v.as_slice()),
+    ))
+}
+
+/// RK4 step splitting `dt` into `n_steps` (default `default_steps`) steps
+fn rk4_step_size(dt: f64, n_steps: Option<usize>, default_steps: usize) -> f64 {
+    dt.abs() / n_steps.unwrap_or(default_steps).max(1) as f64
+}
+
 /// Propagate orbit with J2 perturbation using RK4 integrator
 ///
 /// Propagates a state vector forward in time accounting for Earth's oblateness.
 /// Uses fixed-step RK4 integration with multiple sub-steps for accuracy.
 ///
+/// Deprecated: emits a `DeprecationWarning` and runs on
+/// `NumericalPropagator`; use that class with `add_j2` instead.
+///
 /// # Arguments
 /// * `r0` - Initial position vector [x, y, z] in meters (NumPy array)
 /// * `v0` - Initial velocity vector [vx, vy, vz] in m/s (NumPy array)
@@ -1692,32 +1816,11 @@ fn py_propagate_j2_rk4<'py>(
     R: f64,
     n_steps: Option<usize>,
 ) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>)> {
-    // Convert NumPy arrays to Vector3
-    let r0_array = r0.as_array();
-    let v0_array = v0.as_array();
-
-    if r0_array.len() != 3 || v0_array.len() != 3 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "Position and velocity vectors must have exactly 3 components"
-        ));
-    }
-
-    let r0_vec = core::linalg::Vector3::new(r0_array[0], r0_array[1], r0_array[2]);
-    let v0_vec = core::linalg::Vector3::new(v0_array[0], v0_array[1], v0_array[2]);
-
-    // Call propagator
-    let (r_vec, v_vec) = propagators::perturbations::propagate_j2_rk4(
-        &r0_vec, &v0_vec, dt, mu, j2, R, n_steps
-    )?;
-
-    // Convert back to NumPy arrays
-    let r_array = ndarray::arr1(&[r_vec.x, r_vec.y, r_vec.z]);
-    let v_array = ndarray::arr1(&[v_vec.x, v_vec.y, v_vec.z]);
-
-    Ok((
-        PyArray1::from_owned_array_bound(py, r_array),
-        PyArray1::from_owned_array_bound(py, v_array),
-    ))
+    let mut propagator = deprecated_propagator(py, "propagate_j2_rk4", &r0, &v0, 0.0, mu)?
+        .with_integrator(propagators::propagator::Integrator::Rk4)
+        .with_step_size(rk4_step_size(dt, n_steps, 10));
+    propagator.add_perturbation(propagators::perturbations::J2Perturbation::new(j2, R));
+    propagate_deprecated(py, propagator, dt)
 }
 
 /// Propagate orbit with J2 perturbation using adaptive DOPRI5 integrator
@@ -1725,6 +1828,9 @@ fn py_propagate_j2_rk4<'py>(
 /// Higher accuracy propagation using Dormand-Prince 5(4) adaptive integration.
 /// Automatically adjusts step size to maintain specified error tolerance.
 ///
+/// Deprecated: emits a `DeprecationWarning` and runs on
+/// `NumericalPropagator`; use that class with `add_j2` instead.
+///
 /// # Arguments
 /// * `r0` - Initial position vector [x, y, z] in meters (NumPy array)
 /// * `v0` - Initial velocity vector [vx, vy, vz] in m/s (NumPy array)
@@ -1766,32 +1872,11 @@ fn py_propagate_j2_dopri5<'py>(
     R: f64,
     tol: Option<f64>,
 ) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>)> {
-    // Convert NumPy arrays to Vector3
-    let r0_array = r0.as_array();
-    let v0_array = v0.as_array();
-
-    if r0_array.len() != 3 || v0_array.len() != 3 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "Position and velocity vectors must have exactly 3 components"
-        ));
-    }
-
-    let r0_vec = core::linalg::Vector3::new(r0_array[0], r0_array[1], r0_array[2]);
-    let v0_vec = core::linalg::Vector3::new(v0_array[0], v0_array[1], v0_array[2]);
-
-    // Call propagator
-    let (r_vec, v_vec) = propagators::perturbations::propagate_j2_dopri5(
-        &r0_vec, &v0_vec, dt, mu, j2, R, tol
-    )?;
-
-    // Convert back to NumPy arrays
-    let r_array = ndarray::arr1(&[r_vec.x, r_vec.y, r_vec.z]);
-    let v_array = ndarray::arr1(&[v_vec.x, v_vec.y, v_vec.z]);
-
-    Ok((
-        PyArray1::from_owned_array_bound(py, r_array),
-        PyArray1::from_owned_array_bound(py, v_array),
-    ))
+    let mut propagator = deprecated_propagator(py, "propagate_j2_dopri5", &r0, &v0, 0.0, mu)?
+        .with_integrator(propagators::propagator::Integrator::Dopri5)
+        .with_tolerance(tol.unwrap_or(1e-8));
+    propagator.add_perturbation(propagators::perturbations::J2Perturbation::new(j2, R));
+    propagate_deprecated(py, propagator, dt)
 }
 
 /// Propagate orbit with J2 perturbation using ultra-high precision DOP853 integrator
@@ -1800,6 +1885,9 @@ fn py_propagate_j2_dopri5<'py>(
 /// Recommended for problems requiring very tight error tolerances (tol < 1e-10)
 /// or long-duration high-precision propagation.
 ///
+/// Deprecated: emits a `DeprecationWarning` and runs on
+/// `NumericalPropagator`; use that class with `add_j2` instead.
+///
 /// # Arguments
 /// * `r0` - Initial position vector [x, y, z] in meters
 /// * `v0` - Initial velocity vector [vx, vy, vz] in m/s
@@ -1841,7 +1929,61 @@ fn py_propagate_j2_dop853<'py>(
     R: f64,
     tol: Option<f64>,
 ) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>)> {
-    // Convert NumPy arrays to Rust arrays
+    let mut propagator = deprecated_propagator(py, "propagate_j2_dop853", &r0, &v0, 0.0, mu)?
+        .with_integrator(propagators::propagator::Integrator::Dop853)
+        .with_tolerance(tol.unwrap_or(1e-10));
+    propagator.add_perturbation(propagators::perturbations::J2Perturbation::new(j2, R));
+    propagate_deprecated(py, propagator, dt)
+}
+
+/// Propagate orbit (optionally with J2) and return its ephemeris
+///
+/// Integrates once with dense output, so the returned `Ephemeris` can be
+/// sampled at any times inside the span in a single call instead of
+/// restarting the propagation for each sample.
+///
+/// # Arguments
+/// * `r0` - Initial position vector [x, y, z] in meters
+/// * `v0` - Initial velocity vector [vx, vy, vz] in m/s
+/// * `dt` - Propagation time in seconds (may be negative)
+/// * `mu` - Standard gravitational parameter (GM) in m³/s²
+/// * `j2` - Oblateness coefficient (default: two-body only)
+/// * `R` - Body equatorial radius in meters (required with `j2`)
+/// * `t0` - Initial time, TDB seconds since J2000 (default: 0.0)
+/// * `method` - "dop853" (default) or "dopri5"
+/// * `tol` - Error tolerance (default: 1e-10)
+///
+/// # Returns
+/// `Ephemeris` of the state [x, y, z, vx, vy, vz] from `t0` to `t0 + dt`
+///
+/// # Example (Python)
+/// ```python
+/// import numpy as np
+/// from astrora._core import propagate_ephemeris, constants
+///
+/// eph = propagate_ephemeris(
+///     np.array([7000e3, 0.0, 0.0]), np.array([0.0, 7546.0, 0.0]),
+///     86400.0, constants.GM_EARTH, j2=constants.J2_EARTH, R=constants.R_EARTH,
+/// )
+/// states = eph.evaluate_many(np.linspace(0.0, 86400.0, 1441))  # (1441, 6)
+/// ```
+#[pyfunction]
+#[pyo3(
+    name = "propagate_ephemeris",
+    signature = (r0, v0, dt, mu, j2=None, R=None, t0=0.0, method="dop853", tol=None)
+)]
+#[allow(clippy::too_many_arguments)]
+fn py_propagate_ephemeris(
+    r0: PyReadonlyArray1<f64>,
+    v0: PyReadonlyArray1<f64>,
+    dt: f64,
+    mu: f64,
+    j2: Option<f64>,
+    R: Option<f64>,
+    t0: f64,
+    method: &str,
+    tol: Option<f64>,
+) -> PyResult<core::numerical::Ephemeris> {
     let r0_array = r0.as_array();
     let v0_array = v0.as_array();
 
@@ -1854,19 +1996,17 @@ fn py_propagate_j2_dop853<'py>(
     let r0_vec = core::linalg::Vector3::new(r0_array[0], r0_array[1], r0_array[2]);
     let v0_vec = core::linalg::Vector3::new(v0_array[0], v0_array[1], v0_array[2]);
 
-    // Call propagator
-    let (r_vec, v_vec) = propagators::perturbations::propagate_j2_dop853(
-        &r0_vec, &v0_vec, dt, mu, j2, R, tol
-    )?;
-
-    // Convert back to NumPy arrays
-    let r_array = ndarray::arr1(&[r_vec.x, r_vec.y, r_vec.z]);
-    let v_array = ndarray::arr1(&[v_vec.x, v_vec.y, v_vec.z]);
+    let mut perturbations = propagators::perturbations::PerturbationSet::new();
+    if let Some(j2) = j2 {
+        let radius = R.ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err("R is required when j2 is given")
+        })?;
+        perturbations.add(propagators::perturbations::J2Perturbation::new(j2, radius));
+    }
 
-    Ok((
-        PyArray1::from_owned_array_bound(py, r_array),
-        PyArray1::from_owned_array_bound(py, v_array),
-    ))
+    Ok(propagators::perturbations::propagate_ephemeris(
+        &r0_vec, &v0_vec, t0, dt, mu, &perturbations, method, tol
+    )?)
 }
 
 // =============================================================================
@@ -2085,6 +2225,94 @@ fn py_exponential_density(altitude: f64, rho0: f64, H0: f64) -> f64 {
     propagators::perturbations::exponential_density(altitude, rho0, H0)
 }
 
+/// Constant activity from the space weather at `epoch` with per-field
+/// overrides, or None (space weather along the trajectory) if none is given
+fn activity_overrides(
+    epoch: &core::time::Epoch,
+    f107: Option<f64>,
+    f107_avg: Option<f64>,
+    ap: Option<f64>,
+) -> Option<propagators::atmosphere::SolarActivity> {
+    if f107.is_none() && f107_avg.is_none() && ap.is_none() {
+        return None;
+    }
+    let at_epoch = propagators::atmosphere::SolarActivity::at(epoch);
+    Some(propagators::atmosphere::SolarActivity::new(
+        f107.unwrap_or(at_epoch.f107),
+        f107_avg.unwrap_or(at_epoch.f107_avg),
+        ap.unwrap_or(at_epoch.ap),
+    ))
+}
+
+/// Atmosphere model selected by name for the Python API
+fn atmosphere_model(
+    name: &str,
+    activity: Option<propagators::atmosphere::SolarActivity>,
+) -> PyResult<Box<dyn propagators::atmosphere::AtmosphereModel>> {
+    use propagators::atmosphere::{ExponentialAtmosphere, HarrisPriester, Jacchia71};
+
+    match name.to_lowercase().as_str() {
+        "exponential" => Ok(Box::new(ExponentialAtmosphere::earth())),
+        "harris_priester" | "harris-priester" => Ok(Box::new(HarrisPriester::default())),
+        "jacchia71" | "jacchia" | "j71" => Ok(Box::new(
+            activity.map_or_else(Jacchia71::space_weather, Jacchia71::new),
+        )),
+        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Unknown atmosphere model '{}'. Use 'exponential', 'harris_priester' or 'jacchia71'",
+            name
+        ))),
+    }
+}
+
+/// Compute atmospheric density with a selectable atmosphere model
+///
+/// # Arguments
+/// * `r` - GCRS position vector [x, y, z] (m)
+/// * `epoch` - Epoch of evaluation
+/// * `model` - "exponential", "harris_priester" or "jacchia71" (default)
+/// * `f107` - Daily 10.7 cm solar flux of the previous day (sfu)
+/// * `f107_avg` - 81-day centred mean of F10.7 (sfu)
+/// * `ap` - Planetary geomagnetic index Ap
+///
+/// Indices not given come from the loaded space weather at `epoch` (see
+/// `load_space_weather`), or the fallback activity.
+///
+/// # Returns
+/// Atmospheric density (kg/m³)
+///
+/// # Example
+/// ```python
+/// from astrora._core import atmospheric_density, Epoch
+/// import numpy as np
+///
+/// r = np.array([6778e3, 0.0, 0.0])
+/// rho = atmospheric_density(r, Epoch(2024, 6, 1, 0, 0, 0, 0), f107=180.0, f107_avg=170.0, ap=12.0)
+/// ```
+#[pyfunction]
+#[pyo3(
+    name = "atmospheric_density",
+    signature = (r, epoch, model="jacchia71", f107=None, f107_avg=None, ap=None)
+)]
+fn py_atmospheric_density(
+    r: PyReadonlyArray1<f64>,
+    epoch: &core::time::Epoch,
+    model: &str,
+    f107: Option<f64>,
+    f107_avg: Option<f64>,
+    ap: Option<f64>,
+) -> PyResult<f64> {
+    let r = r.as_slice()?;
+    if r.len() != 3 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "Position vector must have exactly 3 components"
+        ));
+    }
+
+    let activity = activity_overrides(epoch, f107, f107_avg, ap);
+    let atmosphere = atmosphere_model(model, activity)?;
+    Ok(atmosphere.density(epoch, &core::linalg::Vector3::new(r[0], r[1], r[2])))
+}
+
 /// Compute drag acceleration using exponential atmosphere model
 ///
 /// Returns the acceleration due to atmospheric drag given the current
@@ -2146,6 +2374,9 @@ fn py_drag_acceleration<'py>(
 
 /// Propagate orbit with atmospheric drag using RK4 integration
 ///
+/// Deprecated: emits a `DeprecationWarning` and runs on
+/// `NumericalPropagator`; use that class instead.
+///
 /// # Arguments
 /// * `r0` - Initial position vector [x, y, z] in meters (NumPy array)
 /// * `v0` - Initial velocity vector [vx, vy, vz] in m/s (NumPy array)
@@ -2193,33 +2424,18 @@ fn py_propagate_drag_rk4<'py>(
     B: f64,
     n_steps: Option<usize>,
 ) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>)> {
-    let r0_array = r0.as_array();
-    let v0_array = v0.as_array();
-
-    if r0_array.len() != 3 || v0_array.len() != 3 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "Position and velocity vectors must have exactly 3 components"
-        ));
-    }
-
-    let r0_vec = core::linalg::Vector3::new(r0_array[0], r0_array[1], r0_array[2]);
-    let v0_vec = core::linalg::Vector3::new(v0_array[0], v0_array[1], v0_array[2]);
-
-    let (r_vec, v_vec) = propagators::perturbations::propagate_drag_rk4(
-        &r0_vec, &v0_vec, dt, mu, R, rho0, H0, B, n_steps
-    )?;
-
-    let r_array = ndarray::arr1(&[r_vec.x, r_vec.y, r_vec.z]);
-    let v_array = ndarray::arr1(&[v_vec.x, v_vec.y, v_vec.z]);
-
-    Ok((
-        PyArray1::from_owned_array_bound(py, r_array),
-        PyArray1::from_owned_array_bound(py, v_array),
-    ))
+    let mut propagator = deprecated_propagator(py, "propagate_drag_rk4", &r0, &v0, 0.0, mu)?
+        .with_integrator(propagators::propagator::Integrator::Rk4)
+        .with_step_size(rk4_step_size(dt, n_steps, 10));
+    propagator.add_perturbation(propagators::perturbations::DragPerturbation::new(R, rho0, H0, B));
+    propagate_deprecated(py, propagator, dt)
 }
 
 /// Propagate orbit with atmospheric drag using adaptive DOPRI5 integration
 ///
+/// Deprecated: emits a `DeprecationWarning` and runs on
+/// `NumericalPropagator`; use that class instead.
+///
 /// # Arguments
 /// * `r0` - Initial position vector [x, y, z] in meters (NumPy array)
 /// * `v0` - Initial velocity vector [vx, vy, vz] in m/s (NumPy array)
@@ -2247,35 +2463,20 @@ fn py_propagate_drag_dopri5<'py>(
     B: f64,
     tol: Option<f64>,
 ) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>)> {
-    let r0_array = r0.as_array();
-    let v0_array = v0.as_array();
-
-    if r0_array.len() != 3 || v0_array.len() != 3 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "Position and velocity vectors must have exactly 3 components"
-        ));
-    }
-
-    let r0_vec = core::linalg::Vector3::new(r0_array[0], r0_array[1], r0_array[2]);
-    let v0_vec = core::linalg::Vector3::new(v0_array[0], v0_array[1], v0_array[2]);
-
-    let (r_vec, v_vec) = propagators::perturbations::propagate_drag_dopri5(
-        &r0_vec, &v0_vec, dt, mu, R, rho0, H0, B, tol
-    )?;
-
-    let r_array = ndarray::arr1(&[r_vec.x, r_vec.y, r_vec.z]);
-    let v_array = ndarray::arr1(&[v_vec.x, v_vec.y, v_vec.z]);
-
-    Ok((
-        PyArray1::from_owned_array_bound(py, r_array),
-        PyArray1::from_owned_array_bound(py, v_array),
-    ))
+    let mut propagator = deprecated_propagator(py, "propagate_drag_dopri5", &r0, &v0, 0.0, mu)?
+        .with_integrator(propagators::propagator::Integrator::Dopri5)
+        .with_tolerance(tol.unwrap_or(1e-8));
+    propagator.add_perturbation(propagators::perturbations::DragPerturbation::new(R, rho0, H0, B));
+    propagate_deprecated(py, propagator, dt)
 }
 
 /// Propagate orbit with J2 + drag perturbations using RK4 integration
 ///
 /// Combines Earth oblateness (J2) and atmospheric drag effects.
 ///
+/// Deprecated: emits a `DeprecationWarning` and runs on
+/// `NumericalPropagator`; use that class instead.
+///
 /// # Arguments
 /// * `r0` - Initial position vector [x, y, z] in meters (NumPy array)
 /// * `v0` - Initial velocity vector [vx, vy, vz] in m/s (NumPy array)
@@ -2305,35 +2506,21 @@ fn py_propagate_j2_drag_rk4<'py>(
     B: f64,
     n_steps: Option<usize>,
 ) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>)> {
-    let r0_array = r0.as_array();
-    let v0_array = v0.as_array();
-
-    if r0_array.len() != 3 || v0_array.len() != 3 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "Position and velocity vectors must have exactly 3 components"
-        ));
-    }
-
-    let r0_vec = core::linalg::Vector3::new(r0_array[0], r0_array[1], r0_array[2]);
-    let v0_vec = core::linalg::Vector3::new(v0_array[0], v0_array[1], v0_array[2]);
-
-    let (r_vec, v_vec) = propagators::perturbations::propagate_j2_drag_rk4(
-        &r0_vec, &v0_vec, dt, mu, j2, R, rho0, H0, B, n_steps
-    )?;
-
-    let r_array = ndarray::arr1(&[r_vec.x, r_vec.y, r_vec.z]);
-    let v_array = ndarray::arr1(&[v_vec.x, v_vec.y, v_vec.z]);
-
-    Ok((
-        PyArray1::from_owned_array_bound(py, r_array),
-        PyArray1::from_owned_array_bound(py, v_array),
-    ))
+    let mut propagator = deprecated_propagator(py, "propagate_j2_drag_rk4", &r0, &v0, 0.0, mu)?
+        .with_integrator(propagators::propagator::Integrator::Rk4)
+        .with_step_size(rk4_step_size(dt, n_steps, 10));
+    propagator.add_perturbation(propagators::perturbations::J2Perturbation::new(j2, R));
+    propagator.add_perturbation(propagators::perturbations::DragPerturbation::new(R, rho0, H0, B));
+    propagate_deprecated(py, propagator, dt)
 }
 
 /// Propagate orbit with J2 + drag perturbations using adaptive DOPRI5 integration
 ///
 /// High-accuracy propagation combining Earth oblateness and atmospheric drag.
 ///
+/// Deprecated: emits a `DeprecationWarning` and runs on
+/// `NumericalPropagator`; use that class instead.
+///
 /// # Arguments
 /// * `r0` - Initial position vector [x, y, z] in meters (NumPy array)
 /// * `v0` - Initial velocity vector [vx, vy, vz] in m/s (NumPy array)
@@ -2363,29 +2550,12 @@ fn py_propagate_j2_drag_dopri5<'py>(
     B: f64,
     tol: Option<f64>,
 ) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>)> {
-    let r0_array = r0.as_array();
-    let v0_array = v0.as_array();
-
-    if r0_array.len() != 3 || v0_array.len() != 3 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "Position and velocity vectors must have exactly 3 components"
-        ));
-    }
-
-    let r0_vec = core::linalg::Vector3::new(r0_array[0], r0_array[1], r0_array[2]);
-    let v0_vec = core::linalg::Vector3::new(v0_array[0], v0_array[1], v0_array[2]);
-
-    let (r_vec, v_vec) = propagators::perturbations::propagate_j2_drag_dopri5(
-        &r0_vec, &v0_vec, dt, mu, j2, R, rho0, H0, B, tol
-    )?;
-
-    let r_array = ndarray::arr1(&[r_vec.x, r_vec.y, r_vec.z]);
-    let v_array = ndarray::arr1(&[v_vec.x, v_vec.y, v_vec.z]);
-
-    Ok((
-        PyArray1::from_owned_array_bound(py, r_array),
-        PyArray1::from_owned_array_bound(py, v_array),
-    ))
+    let mut propagator = deprecated_propagator(py, "propagate_j2_drag_dopri5", &r0, &v0, 0.0, mu)?
+        .with_integrator(propagators::propagator::Integrator::Dopri5)
+        .with_tolerance(tol.unwrap_or(1e-8));
+    propagator.add_perturbation(propagators::perturbations::J2Perturbation::new(j2, R));
+    propagator.add_perturbation(propagators::perturbations::DragPerturbation::new(R, rho0, H0, B));
+    propagate_deprecated(py, propagator, dt)
 }
 
 // =============================================================================
@@ -2549,6 +2719,9 @@ fn py_sun_moon_perturbation<'py>(
 /// Propagates a state vector forward in time accounting for third-body
 /// gravitational perturbations from the Sun and/or Moon.
 ///
+/// Deprecated: emits a `DeprecationWarning` and runs on
+/// `NumericalPropagator`; use that class with `add_third_body` instead.
+///
 /// # Arguments
 /// * `r0` - Initial position vector [x, y, z] in meters (NumPy array)
 /// * `v0` - Initial velocity vector [vx, vy, vz] in m/s (NumPy array)
@@ -2592,35 +2765,25 @@ fn py_propagate_thirdbody_rk4<'py>(
     include_moon: bool,
     n_steps: Option<usize>,
 ) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>)> {
-    let r0_array = r0.as_array();
-    let v0_array = v0.as_array();
-
-    if r0_array.len() != 3 || v0_array.len() != 3 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "Position and velocity vectors must have exactly 3 components"
-        ));
+    let mut propagator = deprecated_propagator(py, "propagate_thirdbody_rk4", &r0, &v0, t0, mu)?
+        .with_integrator(propagators::propagator::Integrator::Rk4)
+        .with_step_size(rk4_step_size(dt, n_steps, 10));
+    if include_sun {
+        propagator.add_perturbation(propagators::perturbations::ThirdBodyPerturbation::sun());
     }
-
-    let r0_vec = core::linalg::Vector3::new(r0_array[0], r0_array[1], r0_array[2]);
-    let v0_vec = core::linalg::Vector3::new(v0_array[0], v0_array[1], v0_array[2]);
-
-    let (r_vec, v_vec) = propagators::perturbations::propagate_thirdbody_rk4(
-        &r0_vec, &v0_vec, dt, mu, t0, include_sun, include_moon, n_steps
-    )?;
-
-    let r_array = ndarray::arr1(&[r_vec.x, r_vec.y, r_vec.z]);
-    let v_array = ndarray::arr1(&[v_vec.x, v_vec.y, v_vec.z]);
-
-    Ok((
-        PyArray1::from_owned_array_bound(py, r_array),
-        PyArray1::from_owned_array_bound(py, v_array),
-    ))
+    if include_moon {
+        propagator.add_perturbation(propagators::perturbations::ThirdBodyPerturbation::moon());
+    }
+    propagate_deprecated(py, propagator, dt)
 }
 
 /// Propagate orbit with third-body perturbations using adaptive DOPRI5 integration
 ///
 /// Higher accuracy propagation using Dormand-Prince 5(4) adaptive integration.
 ///
+/// Deprecated: emits a `DeprecationWarning` and runs on
+/// `NumericalPropagator`; use that class with `add_third_body` instead.
+///
 /// # Arguments
 /// * `r0` - Initial position vector [x, y, z] in meters (NumPy array)
 /// * `v0` - Initial velocity vector [vx, vy, vz] in m/s (NumPy array)
@@ -2662,30 +2825,17 @@ fn py_propagate_thirdbody_dopri5<'py>(
     include_moon: bool,
     tol: Option<f64>,
 ) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>)> {
-    let r0_array = r0.as_array();
-    let v0_array = v0.as_array();
-
-    if r0_array.len() != 3 || v0_array.len() != 3 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "Position and velocity vectors must have exactly 3 components"
-        ));
-    }
-
-    let r0_vec = core::linalg::Vector3::new(r0_array[0], r0_array[1], r0_array[2]);
-    let v0_vec = core::linalg::Vector3::new(v0_array[0], v0_array[1], v0_array[2]);
-
-    let (r_vec, v_vec) = propagators::perturbations::propagate_thirdbody_dopri5(
-        &r0_vec, &v0_vec, dt, mu, t0, include_sun, include_moon, tol
-    )?;
-
-    let r_array = ndarray::arr1(&[r_vec.x, r_vec.y, r_vec.z]);
-    let v_array = ndarray::arr1(&[v_vec.x, v_vec.y, v_vec.z]);
-
-    Ok((
-        PyArray1::from_owned_array_bound(py, r_array),
-        PyArray1::from_owned_array_bound(py, v_array),
-    ))
-}
+    let mut propagator = deprecated_propagator(py, "propagate_thirdbody_dopri5", &r0, &v0, t0, mu)?
+        .with_integrator(propagators::propagator::Integrator::Dopri5)
+        .with_tolerance(tol.unwrap_or(1e-8));
+    if include_sun {
+        propagator.add_perturbation(propagators::perturbations::ThirdBodyPerturbation::sun());
+    }
+    if include_moon {
+        propagator.add_perturbation(propagators::perturbations::ThirdBodyPerturbation::moon());
+    }
+    propagate_deprecated(py, propagator, dt)
+}
 
 // =============================================================================
 // SOLAR RADIATION PRESSURE (SRP) PYTHON BINDINGS
@@ -2804,6 +2954,9 @@ fn py_srp_acceleration<'py>(
 /// Propagates a state vector forward in time accounting for solar radiation
 /// pressure using the cannon-ball model. Uses fixed-step RK4 integration.
 ///
+/// Deprecated: emits a `DeprecationWarning` and runs on
+/// `NumericalPropagator`; use that class with `add_srp` instead.
+///
 /// # Arguments
 /// * `r0` - Initial position vector [x, y, z] in meters (NumPy array)
 /// * `v0` - Initial velocity vector [vx, vy, vz] in m/s (NumPy array)
@@ -2853,35 +3006,22 @@ fn py_propagate_srp_rk4<'py>(
     t0: f64,
     n_steps: Option<usize>,
 ) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>)> {
-    let r0_array = r0.as_array();
-    let v0_array = v0.as_array();
-
-    if r0_array.len() != 3 || v0_array.len() != 3 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "Position and velocity vectors must have exactly 3 components"
-        ));
-    }
-
-    let r0_vec = core::linalg::Vector3::new(r0_array[0], r0_array[1], r0_array[2]);
-    let v0_vec = core::linalg::Vector3::new(v0_array[0], v0_array[1], v0_array[2]);
-
-    let (r_vec, v_vec) = propagators::perturbations::propagate_srp_rk4(
-        &r0_vec, &v0_vec, dt, mu, area_mass_ratio, C_r, R_earth, t0, n_steps
-    )?;
-
-    let r_array = ndarray::arr1(&[r_vec.x, r_vec.y, r_vec.z]);
-    let v_array = ndarray::arr1(&[v_vec.x, v_vec.y, v_vec.z]);
-
-    Ok((
-        PyArray1::from_owned_array_bound(py, r_array),
-        PyArray1::from_owned_array_bound(py, v_array),
-    ))
+    let mut propagator = deprecated_propagator(py, "propagate_srp_rk4", &r0, &v0, t0, mu)?
+        .with_integrator(propagators::propagator::Integrator::Rk4)
+        .with_step_size(rk4_step_size(dt, n_steps, 100));
+    propagator.add_perturbation(propagators::perturbations::SolarRadiationPressure::new(
+        area_mass_ratio, C_r, R_earth,
+    ));
+    propagate_deprecated(py, propagator, dt)
 }
 
 /// Propagate orbit with solar radiation pressure using adaptive DOPRI5 integrator
 ///
 /// Higher accuracy propagation using Dormand-Prince 5(4) adaptive integration.
 ///
+/// Deprecated: emits a `DeprecationWarning` and runs on
+/// `NumericalPropagator`; use that class with `add_srp` instead.
+///
 /// # Arguments
 /// * `r0` - Initial position vector [x, y, z] in meters (NumPy array)
 /// * `v0` - Initial velocity vector [vx, vy, vz] in m/s (NumPy array)
@@ -2930,29 +3070,13 @@ fn py_propagate_srp_dopri5<'py>(
     t0: f64,
     tol: Option<f64>,
 ) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>)> {
-    let r0_array = r0.as_array();
-    let v0_array = v0.as_array();
-
-    if r0_array.len() != 3 || v0_array.len() != 3 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "Position and velocity vectors must have exactly 3 components"
-        ));
-    }
-
-    let r0_vec = core::linalg::Vector3::new(r0_array[0], r0_array[1], r0_array[2]);
-    let v0_vec = core::linalg::Vector3::new(v0_array[0], v0_array[1], v0_array[2]);
-
-    let (r_vec, v_vec) = propagators::perturbations::propagate_srp_dopri5(
-        &r0_vec, &v0_vec, dt, mu, area_mass_ratio, C_r, R_earth, t0, tol
-    )?;
-
-    let r_array = ndarray::arr1(&[r_vec.x, r_vec.y, r_vec.z]);
-    let v_array = ndarray::arr1(&[v_vec.x, v_vec.y, v_vec.z]);
-
-    Ok((
-        PyArray1::from_owned_array_bound(py, r_array),
-        PyArray1::from_owned_array_bound(py, v_array),
-    ))
+    let mut propagator = deprecated_propagator(py, "propagate_srp_dopri5", &r0, &v0, t0, mu)?
+        .with_integrator(propagators::propagator::Integrator::Dopri5)
+        .with_tolerance(tol.unwrap_or(1e-8));
+    propagator.add_perturbation(propagators::perturbations::SolarRadiationPressure::new(
+        area_mass_ratio, C_r, R_earth,
+    ));
+    propagate_deprecated(py, propagator, dt)
 }
 
 /// Propagate orbit with state transition matrix using RK4 integration
@@ -3191,6 +3315,304 @@ fn py_propagate_stm_j2_rk4<'py>(
     ))
 }
 
+// =============================================================================
+// Earth Orientation Parameter Python Wrappers
+// =============================================================================
+
+/// Load an IERS Earth Orientation Parameter file for all frame transformations
+///
+/// Replaces any previously loaded table. Until a table is loaded, the bundled
+/// finals2000A excerpt is interpolated, with a fallback model outside it
+/// (UT1-UTC = 0 and a secular polar motion model).
+///
+/// # Arguments
+/// * `path` - Path to a local EOP file
+/// * `format` - `"finals2000a"` (IERS finals2000A.all/.data) or `"c04"` (EOP 14 C04)
+///
+/// # Returns
+/// Tuple of (first MJD, last MJD) covered by the loaded table
+///
+/// # Errors
+/// Returns an error if the format is unknown or the file cannot be read or parsed
+///
+/// # Example
+/// ```python
+/// from astrora._core import load_eop
+/// start, end = load_eop("finals2000A.all")
+/// ```
+#[pyfunction]
+#[pyo3(name = "load_eop", signature = (path, format="finals2000a"))]
+fn py_load_eop(path: &str, format: &str) -> PyResult<(f64, f64)> {
+    let format = match format.to_ascii_lowercase().as_str() {
+        "finals2000a" | "finals" => core::eop::EopFormat::Finals2000A,
+        "c04" => core::eop::EopFormat::C04,
+        other => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown EOP format '{}': expected 'finals2000a' or 'c04'",
+                other
+            )))
+        }
+    };
+
+    let table = core::eop::EopTable::from_file(path, format).map_err(PoliastroError::from)?;
+    let range = table.mjd_range();
+    core::eop::install_eop_table(table);
+    Ok(range)
+}
+
+/// Unload the Earth Orientation Parameter table and revert to the bundled data
+#[pyfunction]
+#[pyo3(name = "clear_eop")]
+fn py_clear_eop() {
+    core::eop::clear_eop_table();
+}
+
+/// Whether an Earth Orientation Parameter table is currently loaded
+#[pyfunction]
+#[pyo3(name = "eop_loaded")]
+fn py_eop_loaded() -> bool {
+    core::eop::eop_table_installed()
+}
+
+/// Load the IERS Conventions nutation tables for IAU 2000A nutation
+///
+/// Until the tables are loaded, the frame transformations use the 77-term
+/// IAU 2000B series (~1 mas); see `nutation_model`.
+///
+/// # Arguments
+/// * `longitude_path` - Path to IERS Table 5.3a (`tab5.3a.txt`)
+/// * `obliquity_path` - Path to IERS Table 5.3b (`tab5.3b.txt`)
+///
+/// # Returns
+/// Tuple of (longitude terms, obliquity terms) loaded
+///
+/// # Errors
+/// Returns an error if a file cannot be read or parsed
+///
+/// # Example
+/// ```python
+/// from astrora._core import load_nutation_series, nutation_model
+/// load_nutation_series("tab5.3a.txt", "tab5.3b.txt")
+/// assert nutation_model() == "IAU2000A"
+/// ```
+#[pyfunction]
+#[pyo3(name = "load_nutation_series")]
+fn py_load_nutation_series(longitude_path: &str, obliquity_path: &str) -> PyResult<(usize, usize)> {
+    use crate::coordinates::precession_nutation::{install_iau2000a_series, NutationSeries};
+
+    let series = NutationSeries::from_files(longitude_path, obliquity_path)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    let counts = series.len();
+    install_iau2000a_series(series);
+    Ok(counts)
+}
+
+/// Unload the IAU 2000A nutation series and revert to IAU 2000B
+#[pyfunction]
+#[pyo3(name = "clear_nutation_series")]
+fn py_clear_nutation_series() {
+    coordinates::precession_nutation::clear_iau2000a_series();
+}
+
+/// Nutation model used by the frame transformations ("IAU2000A" or "IAU2000B")
+#[pyfunction]
+#[pyo3(name = "nutation_model")]
+fn py_nutation_model() -> &'static str {
+    coordinates::precession_nutation::NutationModel::best_available().name()
+}
+
+// =============================================================================
+// Space Weather Python Wrappers
+// =============================================================================
+
+/// Load a CelesTrak space weather file for the atmosphere models
+///
+/// Replaces any previously loaded table. Epochs outside the file, and values
+/// missing from it, use the fallback activity (see `set_space_weather_fallback`).
+///
+/// # Arguments
+/// * `path` - Path to a local space weather file
+/// * `format` - `"csv"` (CelesTrak SW-All.csv) or `"legacy"` (sw19571001.txt)
+///
+/// # Returns
+/// Tuple of (first MJD, last MJD) covered by the loaded table
+///
+/// # Errors
+/// Returns an error if the format is unknown or the file cannot be read or parsed
+///
+/// # Example
+/// ```python
+/// from astrora._core import load_space_weather
+/// start, end = load_space_weather("SW-All.csv")
+/// ```
+#[pyfunction]
+#[pyo3(name = "load_space_weather", signature = (path, format="csv"))]
+fn py_load_space_weather(path: &str, format: &str) -> PyResult<(f64, f64)> {
+    use core::space_weather::{install_space_weather, SpaceWeatherFormat, SpaceWeatherTable};
+
+    let format = match format.to_ascii_lowercase().as_str() {
+        "csv" => SpaceWeatherFormat::CelestrakCsv,
+        "legacy" | "txt" => SpaceWeatherFormat::Legacy,
+        other => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown space weather format '{}': expected 'csv' or 'legacy'",
+                other
+            )))
+        }
+    };
+
+    let table = SpaceWeatherTable::from_file(path, format).map_err(PoliastroError::from)?;
+    let range = table.mjd_range();
+    install_space_weather(table);
+    Ok(range)
+}
+
+/// Unload the space weather table and revert to the fallback activity
+#[pyfunction]
+#[pyo3(name = "clear_space_weather")]
+fn py_clear_space_weather() {
+    core::space_weather::clear_space_weather();
+}
+
+/// Whether a space weather table is currently loaded
+#[pyfunction]
+#[pyo3(name = "space_weather_loaded")]
+fn py_space_weather_loaded() -> bool {
+    core::space_weather::space_weather_installed()
+}
+
+/// Set the constant activity used beyond the loaded space weather
+///
+/// # Arguments
+/// * `f107` - Daily 10.7 cm solar flux (sfu)
+/// * `f107_avg` - 81-day centred mean of F10.7 (sfu)
+/// * `ap` - Planetary geomagnetic index Ap
+#[pyfunction]
+#[pyo3(name = "set_space_weather_fallback")]
+fn py_set_space_weather_fallback(f107: f64, f107_avg: f64, ap: f64) {
+    core::space_weather::set_space_weather_fallback(core::space_weather::SolarActivity::new(
+        f107, f107_avg, ap,
+    ));
+}
+
+/// Space weather indices at an epoch
+///
+/// # Arguments
+/// * `epoch` - Epoch of evaluation
+///
+/// # Returns
+/// Dict with `f107` (observed flux of the previous UTC day, as taken by the
+/// atmosphere models), `f107_avg` (81-day centred mean), `ap` (3-hourly) and
+/// `kp` (from `ap`),
+/// falling back to the constant activity beyond the loaded table
+///
+/// # Example
+/// ```python
+/// from astrora._core import space_weather, Epoch
+/// sw = space_weather(Epoch(2024, 5, 11, 0, 0, 0, 0))
+/// print(sw["f107"], sw["ap"])
+/// ```
+#[pyfunction]
+#[pyo3(name = "space_weather")]
+fn py_space_weather<'py>(
+    py: Python<'py>,
+    epoch: &core::time::Epoch,
+) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+    let activity = core::space_weather::SolarActivity::at(epoch);
+    let dict = pyo3::types::PyDict::new_bound(py);
+    dict.set_item("f107", activity.f107)?;
+    dict.set_item("f107_avg", activity.f107_avg)?;
+    dict.set_item("ap", activity.ap)?;
+    dict.set_item("kp", activity.kp())?;
+    Ok(dict)
+}
+
+// =============================================================================
+// Solar System Ephemeris Python Wrappers
+// =============================================================================
+
+/// Load a JPL SPK (.bsp) kernel as the ephemeris for all computations
+///
+/// Replaces any previously loaded ephemeris. The kernel is then used for the
+/// ICRS ↔ GCRS translation, Sun/Moon third-body perturbations and eclipse
+/// geometry. Until a kernel is loaded, a built-in analytic ephemeris is used.
+///
+/// # Arguments
+/// * `path` - Path to a local SPK file (e.g. de440s.bsp)
+///
+/// # Returns
+/// List of (target, center) NAIF id pairs of the segments in the kernel
+///
+/// # Errors
+/// Returns an error if the file cannot be read or is not a valid SPK file
+///
+/// # Example
+/// ```python
+/// from astrora._core import load_spk
+/// segments = load_spk("de440s.bsp")
+/// ```
+#[pyfunction]
+#[pyo3(name = "load_spk")]
+fn py_load_spk(path: &str) -> PyResult<Vec<(i32, i32)>> {
+    let spk = ephemeris::SpkFile::open(path).map_err(PoliastroError::from)?;
+    let segments = spk
+        .segments()
+        .iter()
+        .map(|segment| (segment.target.naif_id(), segment.center.naif_id()))
+        .collect();
+    ephemeris::install_ephemeris(spk);
+    Ok(segments)
+}
+
+/// Unload the ephemeris and revert to the built-in analytic ephemeris
+#[pyfunction]
+#[pyo3(name = "clear_ephemeris")]
+fn py_clear_ephemeris() {
+    ephemeris::clear_ephemeris();
+}
+
+/// Whether an ephemeris kernel is currently loaded
+#[pyfunction]
+#[pyo3(name = "ephemeris_loaded")]
+fn py_ephemeris_loaded() -> bool {
+    ephemeris::ephemeris_installed()
+}
+
+/// Position and velocity of one solar system body relative to another
+///
+/// # Arguments
+/// * `target` - NAIF id of the target body (e.g. 301 for the Moon)
+/// * `center` - NAIF id of the center body (e.g. 399 for the Earth, 0 for the
+///   Solar System Barycenter)
+/// * `epoch` - Epoch of the state
+///
+/// # Returns
+/// Tuple of (position, velocity) in ICRF axes (m, m/s) as NumPy arrays
+///
+/// # Example
+/// ```python
+/// from astrora._core import ephemeris_state, Epoch
+/// r_moon, v_moon = ephemeris_state(301, 399, Epoch.j2000_epoch())
+/// ```
+#[pyfunction]
+#[pyo3(name = "ephemeris_state")]
+fn py_ephemeris_state<'py>(
+    py: Python<'py>,
+    target: i32,
+    center: i32,
+    epoch: &core::time::Epoch,
+) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>)> {
+    let (r, v) = ephemeris::ephemeris_state(
+        ephemeris::Body::from_naif_id(target),
+        ephemeris::Body::from_naif_id(center),
+        epoch,
+    )?;
+    Ok((
+        PyArray1::from_slice_bound(py, r.as_slice()),
+        PyArray1::from_slice_bound(py, v.as_slice()),
+    ))
+}
+
 // =============================================================================
 // Batch Coordinate Transformation Python Wrappers
 // =============================================================================
@@ -5003,45 +5425,213 @@ fn py_lambert_solve_batch_parallel<'py>(
 }
 
 // ========================================
-// Satellite Operations (SGP4/SDP4 TLE Propagation)
+// Initial Orbit Determination
 // ========================================
 
-/// Propagate a satellite orbit from TLE string
+/// Pair epochs with the rows of an (n, 3) position array
+fn iod_positions(
+    epochs: Vec<core::time::Epoch>,
+    positions: &PyReadonlyArray2<f64>,
+) -> PyResult<Vec<(core::time::Epoch, core::linalg::Vector3)>> {
+    let positions = positions.as_array();
+    if positions.ncols() != 3 || positions.nrows() != epochs.len() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "positions must have shape (len(epochs), 3)",
+        ));
+    }
+    Ok(epochs
+        .into_iter()
+        .zip(positions.rows())
+        .map(|(epoch, row)| (epoch, core::linalg::Vector3::new(row[0], row[1], row[2])))
+        .collect())
+}
+
+/// Gibbs initial orbit determination from three position vectors
 ///
 /// # Arguments
+/// * `epochs` - Three observation epochs
+/// * `positions` - (3, 3) array of positions in meters
+/// * `mu` - Gravitational parameter (m³/s²)
 ///
-/// * `tle_string` - TLE data (2-line or 3-line format)
-/// * `time_offset_minutes` - Time from epoch in minutes
+/// # Returns
+/// IodSolution with the state at the second epoch
+#[pyfunction]
+#[pyo3(name = "iod_gibbs")]
+fn py_iod_gibbs(
+    epochs: Vec<core::time::Epoch>,
+    positions: PyReadonlyArray2<f64>,
+    mu: f64,
+) -> PyResult<estimation::IodSolution> {
+    let observations = iod_positions(epochs, &positions)?;
+    Ok(estimation::iod::gibbs(&observations, mu)?)
+}
+
+/// Herrick-Gibbs initial orbit determination from three closely spaced
+/// position vectors
+///
+/// # Arguments
+/// * `epochs` - Three observation epochs in increasing order
+/// * `positions` - (3, 3) array of positions in meters
+/// * `mu` - Gravitational parameter (m³/s²)
 ///
 /// # Returns
+/// IodSolution with the state at the second epoch
+#[pyfunction]
+#[pyo3(name = "iod_herrick_gibbs")]
+fn py_iod_herrick_gibbs(
+    epochs: Vec<core::time::Epoch>,
+    positions: PyReadonlyArray2<f64>,
+    mu: f64,
+) -> PyResult<estimation::IodSolution> {
+    let observations = iod_positions(epochs, &positions)?;
+    Ok(estimation::iod::herrick_gibbs(&observations, mu)?)
+}
+
+/// Initial orbit determination from two position vectors via Lambert's problem
 ///
-/// Dictionary with satellite state in TEME frame:
-/// - `position`: [x, y, z] in km (TEME frame)
-/// - `velocity`: [vx, vy, vz] in km/s (TEME frame)
-/// - `time_offset_minutes`: Time from epoch
-/// - `altitude_km`: Altitude above Earth's surface
-/// - `speed_km_s`: Orbital speed
+/// # Arguments
+/// * `epoch1`, `r1` - First epoch and position (m)
+/// * `epoch2`, `r2` - Second epoch and position (m)
+/// * `mu` - Gravitational parameter (m³/s²)
+/// * `short_way` - Transfer direction; None picks it from the geometry
+/// * `revs` - Number of complete revolutions
 ///
-/// # Example
+/// # Returns
+/// IodSolution with the state at the first epoch
+#[pyfunction]
+#[pyo3(name = "iod_lambert", signature = (epoch1, r1, epoch2, r2, mu, short_way=None, revs=0))]
+fn py_iod_lambert(
+    epoch1: core::time::Epoch,
+    r1: PyReadonlyArray1<f64>,
+    epoch2: core::time::Epoch,
+    r2: PyReadonlyArray1<f64>,
+    mu: f64,
+    short_way: Option<bool>,
+    revs: u32,
+) -> PyResult<estimation::IodSolution> {
+    let transfer_kind = match short_way {
+        None => maneuvers::TransferKind::Auto,
+        Some(true) => maneuvers::TransferKind::ShortWay,
+        Some(false) => maneuvers::TransferKind::LongWay,
+    };
+    let (r1, r2) = coordinates::frames::state_from_arrays(&r1, &r2)?;
+    Ok(estimation::iod::lambert(
+        (epoch1, r1),
+        (epoch2, r2),
+        mu,
+        transfer_kind,
+        revs,
+    )?)
+}
+
+/// Gauss angles-only initial orbit determination
 ///
-/// ```python
-/// tle = """ISS (ZARYA)
-/// 1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927
-/// 2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537"""
+/// # Arguments
+/// * `measurements` - Three "ra_dec" Measurements from ground stations
+/// * `mu` - Gravitational parameter (m³/s²)
 ///
-/// state = propagate_tle(tle, 120.0)  # 120 minutes after epoch
-/// print(state['position'])  # [x, y, z] in km
-/// print(state['altitude_km'])  # Altitude above Earth
-/// ```
+/// # Returns
+/// List of IodSolution candidates at the middle epoch, best first
 #[pyfunction]
-fn py_propagate_tle<'py>(
-    py: Python<'py>,
-    tle_string: &str,
-    time_offset_minutes: f64,
-) -> PyResult<PyObject> {
-    use crate::satellite::{parse_tle, propagate_from_elements};
+#[pyo3(name = "iod_gauss")]
+fn py_iod_gauss(
+    measurements: Vec<estimation::Measurement>,
+    mu: f64,
+) -> PyResult<Vec<estimation::IodSolution>> {
+    Ok(estimation::iod::gauss(&measurements, mu)?)
+}
 
-    // Parse TLE
+/// Laplace angles-only initial orbit determination
+///
+/// # Arguments
+/// * `measurements` - Three "ra_dec" Measurements from ground stations
+/// * `mu` - Gravitational parameter (m³/s²)
+///
+/// # Returns
+/// List of IodSolution candidates at the middle epoch, best first
+#[pyfunction]
+#[pyo3(name = "iod_laplace")]
+fn py_iod_laplace(
+    measurements: Vec<estimation::Measurement>,
+    mu: f64,
+) -> PyResult<Vec<estimation::IodSolution>> {
+    Ok(estimation::iod::laplace(&measurements, mu)?)
+}
+
+/// Gooding angles-only initial orbit determination
+///
+/// # Arguments
+/// * `measurements` - Three "ra_dec" Measurements from ground stations
+/// * `mu` - Gravitational parameter (m³/s²)
+/// * `initial_ranges` - Optional (first, last) range guesses in meters;
+///   by default the iteration starts from the Gauss candidates
+///
+/// # Returns
+/// List of converged IodSolution candidates at the middle epoch, best first
+#[pyfunction]
+#[pyo3(name = "iod_gooding", signature = (measurements, mu, initial_ranges=None))]
+fn py_iod_gooding(
+    measurements: Vec<estimation::Measurement>,
+    mu: f64,
+    initial_ranges: Option<(f64, f64)>,
+) -> PyResult<Vec<estimation::IodSolution>> {
+    Ok(estimation::iod::gooding(&measurements, mu, initial_ranges)?)
+}
+
+/// Two-way Doppler shift of a carrier for a two-way range rate
+///
+/// # Arguments
+/// * `range_rate` - Two-way range rate (m/s), positive when receding
+/// * `frequency` - Carrier frequency (Hz)
+///
+/// # Returns
+/// Doppler shift in Hz, −2·f·ρ̇/c
+#[pyfunction]
+#[pyo3(name = "two_way_doppler")]
+fn py_two_way_doppler(range_rate: f64, frequency: f64) -> f64 {
+    estimation::two_way_doppler(range_rate, frequency)
+}
+
+// ========================================
+// Satellite Operations (SGP4/SDP4 TLE Propagation)
+// ========================================
+
+/// Propagate a satellite orbit from TLE string
+///
+/// # Arguments
+///
+/// * `tle_string` - TLE data (2-line or 3-line format)
+/// * `time_offset_minutes` - Time from epoch in minutes
+///
+/// # Returns
+///
+/// Dictionary with satellite state in TEME frame:
+/// - `position`: [x, y, z] in km (TEME frame)
+/// - `velocity`: [vx, vy, vz] in km/s (TEME frame)
+/// - `time_offset_minutes`: Time from epoch
+/// - `altitude_km`: Altitude above Earth's surface
+/// - `speed_km_s`: Orbital speed
+///
+/// # Example
+///
+/// ```python
+/// tle = """ISS (ZARYA)
+/// 1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927
+/// 2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537"""
+///
+/// state = propagate_tle(tle, 120.0)  # 120 minutes after epoch
+/// print(state['position'])  # [x, y, z] in km
+/// print(state['altitude_km'])  # Altitude above Earth
+/// ```
+#[pyfunction]
+fn py_propagate_tle<'py>(
+    py: Python<'py>,
+    tle_string: &str,
+    time_offset_minutes: f64,
+) -> PyResult<PyObject> {
+    use crate::satellite::{parse_tle, propagate_from_elements};
+
+    // Parse TLE
     let elements = parse_tle(tle_string)?;
 
     // Propagate
@@ -5125,13 +5715,19 @@ fn py_propagate_tle_batch<'py>(
 ///
 /// # Arguments
 ///
-/// * `omm_json` - OMM data in JSON format
+/// * `omm_json` - OMM data in JSON, CCSDS KVN or CCSDS XML format
 /// * `time_offset_minutes` - Time from epoch in minutes
 ///
 /// # Returns
 ///
 /// Dictionary with satellite state (same format as `propagate_tle`)
 ///
+/// # Raises
+///
+/// `RuntimeError` if the message is not SGP4 mean elements about the Earth
+/// in TEME with a UTC epoch (CENTER_NAME, REF_FRAME, TIME_SYSTEM and
+/// MEAN_ELEMENT_THEORY are checked)
+///
 /// # Example
 ///
 /// ```python
@@ -5172,10 +5768,869 @@ fn py_propagate_omm<'py>(
     Ok(dict.into())
 }
 
+/// Convert a TLE (2-line or 3-line) to an OMM
+///
+/// # Arguments
+///
+/// * `tle` - TLE text
+/// * `encoding` - "json", "kvn" (CCSDS keyword = value) or "xml" (CCSDS NDM/XML)
+/// * `originator` - ORIGINATOR written in KVN and XML headers
+///
+/// # Returns
+///
+/// OMM text; converting it back with `omm_to_tle` reproduces the TLE
+///
+/// # Example
+///
+/// ```python
+/// kvn = tle_to_omm(tle, encoding="kvn", originator="MY AGENCY")
+/// ```
+#[pyfunction]
+#[pyo3(name = "tle_to_omm", signature = (tle, encoding="json", originator="ASTRORA"))]
+fn py_tle_to_omm(tle: &str, encoding: &str, originator: &str) -> PyResult<String> {
+    use crate::satellite::{parse_tle, write_omm, write_omm_kvn, write_omm_xml, OmmMetadata};
+
+    let elements = parse_tle(tle)?;
+    let metadata = OmmMetadata::new(originator);
+    Ok(match encoding.to_ascii_lowercase().as_str() {
+        "json" => write_omm(&elements)?,
+        "kvn" => write_omm_kvn(&elements, &metadata)?,
+        "xml" => write_omm_xml(&elements, &metadata)?,
+        _ => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown OMM encoding '{encoding}' (expected json, kvn or xml)"
+            )))
+        }
+    })
+}
+
+/// Convert an OMM to TLE text
+///
+/// # Arguments
+///
+/// * `omm` - OMM data in JSON, CCSDS KVN or CCSDS XML format
+///
+/// # Returns
+///
+/// TLE text, in the 3-line format when the OMM has an OBJECT_NAME
+///
+/// # Raises
+///
+/// `RuntimeError` if the message is not SGP4 mean elements (see
+/// `propagate_omm`)
+#[pyfunction]
+#[pyo3(name = "omm_to_tle")]
+fn py_omm_to_tle(omm: &str) -> PyResult<String> {
+    use crate::satellite::{format_tle, parse_omm};
+
+    Ok(format_tle(&parse_omm(omm)?)?)
+}
+
+/// Parse an OMM with its header, metadata and covariance
+///
+/// # Arguments
+///
+/// * `omm` - OMM data in JSON, CCSDS KVN or CCSDS XML format
+///
+/// # Returns
+///
+/// Dictionary keyed by the lower-case CCSDS keywords: the header and
+/// metadata (`originator`, `creation_date`, `center_name`, `ref_frame`,
+/// `time_system`, `mean_element_theory`), the elements (`epoch`,
+/// `mean_motion`, `eccentricity`, `inclination`, ..., `norad_cat_id`,
+/// `bstar`), `comments`, and `covariance` (a `Covariance6` in m and m/s, or
+/// None) with `cov_ref_frame`
+///
+/// # Example
+///
+/// ```python
+/// omm = parse_omm(open("goes9.kvn").read())
+/// print(omm["originator"], omm["epoch"], omm["covariance"])
+/// ```
+#[pyfunction]
+#[pyo3(name = "parse_omm")]
+fn py_parse_omm(py: Python<'_>, omm: &str) -> PyResult<PyObject> {
+    use crate::satellite::parse_omm_message;
+    use crate::satellite::tle::classification_code;
+
+    let omm = parse_omm_message(omm)?;
+    let (metadata, elements) = (&omm.metadata, &omm.elements);
+    let dict = pyo3::types::PyDict::new_bound(py);
+
+    dict.set_item("originator", &metadata.originator)?;
+    dict.set_item("creation_date", &metadata.creation_date)?;
+    dict.set_item("object_name", &elements.object_name)?;
+    dict.set_item("object_id", &elements.international_designator)?;
+    dict.set_item("center_name", &metadata.center_name)?;
+    dict.set_item("ref_frame", &metadata.ref_frame)?;
+    dict.set_item("time_system", &metadata.time_system)?;
+    dict.set_item("mean_element_theory", &metadata.mean_element_theory)?;
+    dict.set_item("epoch", elements.datetime.format("%Y-%m-%dT%H:%M:%S%.f").to_string())?;
+    dict.set_item("mean_motion", elements.mean_motion)?;
+    dict.set_item("eccentricity", elements.eccentricity)?;
+    dict.set_item("inclination", elements.inclination)?;
+    dict.set_item("ra_of_asc_node", elements.right_ascension)?;
+    dict.set_item("arg_of_pericenter", elements.argument_of_perigee)?;
+    dict.set_item("mean_anomaly", elements.mean_anomaly)?;
+    dict.set_item("ephemeris_type", elements.ephemeris_type)?;
+    dict.set_item("classification_type", classification_code(&elements.classification).to_string())?;
+    dict.set_item("norad_cat_id", elements.norad_id)?;
+    dict.set_item("element_set_no", elements.element_set_number)?;
+    dict.set_item("rev_at_epoch", elements.revolution_number)?;
+    dict.set_item("bstar", elements.drag_term)?;
+    dict.set_item("mean_motion_dot", elements.mean_motion_dot)?;
+    dict.set_item("mean_motion_ddot", elements.mean_motion_ddot)?;
+    dict.set_item("comments", &omm.comments)?;
+    dict.set_item("covariance", omm.covariance.map(|covariance| covariance.into_py(py)))?;
+    dict.set_item("cov_ref_frame", &omm.covariance_frame)?;
+
+    Ok(dict.into())
+}
+
+/// Parse a CCSDS OEM (orbit ephemeris message)
+///
+/// # Arguments
+///
+/// * `oem` - OEM in CCSDS KVN or CCSDS XML format
+///
+/// # Returns
+///
+/// Dictionary with the header (`originator`, `creation_date`, `comments`)
+/// and `segments`, a list of dictionaries with the metadata keyed by the
+/// lower-case CCSDS keywords (`object_name`, `ref_frame`, `start_time`,
+/// `interpolation`, ...), `frame` (the matching frame name), `comments`,
+/// `epochs` (list of `Epoch`), `states` (list of `CartesianState` in m and
+/// m/s), `accelerations` (m/s², or None per state) and `covariances` (list of
+/// dictionaries with `epoch`, `cov_ref_frame` and `covariance`)
+///
+/// # Example
+///
+/// ```python
+/// oem = parse_oem(open("iss.oem").read())
+/// segment = oem["segments"][0]
+/// print(segment["object_name"], len(segment["states"]))
+/// ```
+#[pyfunction]
+#[pyo3(name = "parse_oem")]
+fn py_parse_oem(py: Python<'_>, oem: &str) -> PyResult<PyObject> {
+    use pyo3::types::{PyDict, PyList};
+
+    let oem = ccsds::parse_oem(oem)?;
+    let segments = PyList::empty_bound(py);
+    for segment in &oem.segments {
+        let metadata = &segment.metadata;
+        let dict = PyDict::new_bound(py);
+        dict.set_item("object_name", &metadata.object_name)?;
+        dict.set_item("object_id", &metadata.object_id)?;
+        dict.set_item("center_name", &metadata.center_name)?;
+        dict.set_item("ref_frame", &metadata.ref_frame)?;
+        dict.set_item("ref_frame_epoch", &metadata.ref_frame_epoch)?;
+        dict.set_item("frame", metadata.frame()?.to_string())?;
+        dict.set_item("time_system", &metadata.time_system)?;
+        dict.set_item("start_time", metadata.start_time.into_py(py))?;
+        dict.set_item("useable_start_time", metadata.useable_start_time.map(|epoch| epoch.into_py(py)))?;
+        dict.set_item("useable_stop_time", metadata.useable_stop_time.map(|epoch| epoch.into_py(py)))?;
+        dict.set_item("stop_time", metadata.stop_time.into_py(py))?;
+        dict.set_item("interpolation", metadata.interpolation.map(|interpolation| interpolation.name()))?;
+        dict.set_item("interpolation_degree", metadata.interpolation_degree)?;
+        dict.set_item("metadata_comments", &metadata.comments)?;
+        dict.set_item("comments", &segment.comments)?;
+        dict.set_item("epochs", segment.states.iter().map(|state| state.epoch.into_py(py)).collect::<Vec<_>>())?;
+        dict.set_item("states", segment.states.iter().map(|state| state.state.into_py(py)).collect::<Vec<_>>())?;
+        dict.set_item(
+            "accelerations",
+            segment
+                .states
+                .iter()
+                .map(|state| state.acceleration.map(|a| [a.x, a.y, a.z]))
+                .collect::<Vec<_>>(),
+        )?;
+        let covariances = PyList::empty_bound(py);
+        for covariance in &segment.covariances {
+            let entry = PyDict::new_bound(py);
+            entry.set_item("epoch", covariance.epoch.into_py(py))?;
+            entry.set_item("cov_ref_frame", &covariance.frame)?;
+            entry.set_item("covariance", covariance.covariance.into_py(py))?;
+            covariances.append(entry)?;
+        }
+        dict.set_item("covariances", covariances)?;
+        segments.append(dict)?;
+    }
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("originator", &oem.originator)?;
+    dict.set_item("creation_date", &oem.creation_date)?;
+    dict.set_item("comments", &oem.comments)?;
+    dict.set_item("segments", segments)?;
+    Ok(dict.into())
+}
+
+/// Write a single-segment CCSDS OEM from a sequence of states
+///
+/// # Arguments
+///
+/// * `epochs` - State epochs, in increasing order
+/// * `states` - `CartesianState` at each epoch (m, m/s)
+/// * `object_name`, `object_id` - OBJECT_NAME and OBJECT_ID
+/// * `frame` - Frame of the states ("GCRS", "J2000", "ITRS", "TEME", ...)
+/// * `encoding` - "kvn" (CCSDS keyword = value) or "xml" (CCSDS NDM/XML)
+/// * `originator` - ORIGINATOR of the message
+/// * `interpolation` - "LAGRANGE", "HERMITE" or "LINEAR"
+/// * `interpolation_degree` - Interpolation degree, at most one less than the
+///   number of states
+/// * `time_system` - TIME_SYSTEM of the epochs ("UTC", "TAI", "TT", "TDB" or "GPS")
+///
+/// # Returns
+///
+/// The OEM text
+///
+/// # Example
+///
+/// ```python
+/// text = write_oem(epochs, states, "ISS (ZARYA)", "1998-067A", frame="J2000")
+/// ```
+#[pyfunction]
+#[pyo3(
+    name = "write_oem",
+    signature = (
+        epochs, states, object_name, object_id, frame="GCRS", encoding="kvn",
+        originator="ASTRORA", interpolation="LAGRANGE", interpolation_degree=7,
+        time_system="UTC"
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+fn py_write_oem(
+    epochs: Vec<core::time::Epoch>,
+    states: Vec<core::state::CartesianState>,
+    object_name: &str,
+    object_id: &str,
+    frame: &str,
+    encoding: &str,
+    originator: &str,
+    interpolation: &str,
+    interpolation_degree: usize,
+    time_system: &str,
+) -> PyResult<String> {
+    use crate::ccsds::{write_oem, Encoding, Interpolation, Oem, OemSegment, OemStateVector};
+
+    if epochs.len() != states.len() {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Got {} epochs for {} states",
+            epochs.len(),
+            states.len()
+        )));
+    }
+    let states = epochs
+        .into_iter()
+        .zip(states)
+        .map(|(epoch, state)| OemStateVector::new(epoch, state))
+        .collect::<Vec<_>>();
+    let degree = interpolation_degree.min(states.len().saturating_sub(1));
+    let mut segment = OemSegment::new(object_name, object_id, frame.parse()?, states)?;
+    segment.metadata.time_system = time_system.to_ascii_uppercase();
+    segment.metadata.interpolation = Some(Interpolation::from_name(interpolation)?);
+    segment.metadata.interpolation_degree = Some(degree);
+    segment.validate()?;
+    Ok(write_oem(&Oem::new(originator, vec![segment]), Encoding::from_name(encoding)?)?)
+}
+
+/// Parse a CCSDS CDM (conjunction data message)
+///
+/// # Arguments
+///
+/// * `cdm` - CDM in CCSDS KVN or CCSDS XML format
+///
+/// # Returns
+///
+/// Dictionary with the header (`originator`, `creation_date`,
+/// `message_for`, `message_id`, `comments`), the relative data (`tca` as an
+/// `Epoch`, `miss_distance` in m, `relative_speed` in m/s,
+/// `relative_position` and `relative_velocity` in the RTN frame of object 1
+/// or None, `screening` keywords, `collision_probability`,
+/// `collision_probability_method`) and `object1` / `object2` as `CdmObject`s
+///
+/// # Example
+///
+/// ```python
+/// cdm = parse_cdm(open("event.cdm").read())
+/// print(cdm["tca"], cdm["miss_distance"], cdm["collision_probability"])
+/// covariance = cdm["object1"].inertial_covariance()
+/// ```
+#[pyfunction]
+#[pyo3(name = "parse_cdm")]
+fn py_parse_cdm(py: Python<'_>, cdm: &str) -> PyResult<PyObject> {
+    let cdm = ccsds::parse_cdm(cdm)?;
+    let vector = |vector: Option<crate::core::linalg::Vector3>| vector.map(|v| [v.x, v.y, v.z]);
+    let dict = pyo3::types::PyDict::new_bound(py);
+
+    dict.set_item("originator", &cdm.originator)?;
+    dict.set_item("creation_date", &cdm.creation_date)?;
+    dict.set_item("message_for", &cdm.message_for)?;
+    dict.set_item("message_id", &cdm.message_id)?;
+    dict.set_item("comments", &cdm.comments)?;
+    dict.set_item("tca", cdm.tca.into_py(py))?;
+    dict.set_item("miss_distance", cdm.miss_distance)?;
+    dict.set_item("relative_speed", cdm.relative_speed)?;
+    dict.set_item("relative_position", vector(cdm.relative_position))?;
+    dict.set_item("relative_velocity", vector(cdm.relative_velocity))?;
+    let screening = pyo3::types::PyDict::new_bound(py);
+    for (key, value) in &cdm.screening {
+        screening.set_item(key, value)?;
+    }
+    dict.set_item("screening", screening)?;
+    dict.set_item("collision_probability", cdm.collision_probability)?;
+    dict.set_item("collision_probability_method", &cdm.collision_probability_method)?;
+    dict.set_item("relative_comments", &cdm.relative_comments)?;
+    dict.set_item("object1", cdm.object1.into_py(py))?;
+    dict.set_item("object2", cdm.object2.into_py(py))?;
+
+    Ok(dict.into())
+}
+
+/// Write a CCSDS CDM for a conjunction found by screening
+///
+/// The miss distance, relative speed and relative state in the RTN frame of
+/// object 1 are computed from the object states.
+///
+/// # Arguments
+///
+/// * `tca` - Time of closest approach
+/// * `object1`, `object2` - `CdmObject`s with their states at TCA, in the
+///   same frame
+/// * `message_id` - MESSAGE_ID, unique for the originator
+/// * `originator` - ORIGINATOR of the message
+/// * `collision_probability` - Probability of collision, if computed
+/// * `collision_probability_method` - Method of the probability (e.g. "FOSTER-1992")
+/// * `encoding` - "kvn" (CCSDS keyword = value) or "xml" (CCSDS NDM/XML)
+///
+/// # Returns
+///
+/// The CDM text
+///
+/// # Example
+///
+/// ```python
+/// sat = CdmObject("12345", "SAT A", "2020-001A", state1, covariance1)
+/// debris = CdmObject("30337", "DEBRIS", "1999-025AA", state2, covariance2)
+/// text = write_cdm(tca, sat, debris, "SCREEN-0001", collision_probability=1e-5)
+/// ```
+#[pyfunction]
+#[pyo3(
+    name = "write_cdm",
+    signature = (
+        tca, object1, object2, message_id, originator="ASTRORA",
+        collision_probability=None, collision_probability_method=None, encoding="kvn"
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+fn py_write_cdm(
+    tca: core::time::Epoch,
+    object1: ccsds::CdmObject,
+    object2: ccsds::CdmObject,
+    message_id: &str,
+    originator: &str,
+    collision_probability: Option<f64>,
+    collision_probability_method: Option<String>,
+    encoding: &str,
+) -> PyResult<String> {
+    use crate::ccsds::{write_cdm, Cdm, Encoding};
+
+    let mut cdm = Cdm::new(originator, message_id, tca, object1, object2)?;
+    cdm.collision_probability = collision_probability;
+    cdm.collision_probability_method = collision_probability_method;
+    Ok(write_cdm(&cdm, Encoding::from_name(encoding)?)?)
+}
+
+/// Parse a CCSDS OPM (orbit parameter message)
+///
+/// # Arguments
+///
+/// * `opm` - OPM in CCSDS KVN or CCSDS XML format
+///
+/// # Returns
+///
+/// Dictionary with the header (`originator`, `creation_date`, `comments`),
+/// the metadata keyed by the lower-case CCSDS keywords (`object_name`,
+/// `object_id`, `center_name`, `ref_frame`, `time_system`, ...), `frame`
+/// (the matching frame name, or None for frames without one), `epoch`,
+/// `state` (`CartesianState` in m and m/s), `keplerian` (`OrbitalElements`
+/// or None) and `gm` (m³/s²), the spacecraft parameters (`mass`,
+/// `solar_rad_area`, `solar_rad_coeff`, `drag_area`, `drag_coeff`),
+/// `covariance` and `cov_ref_frame`, `maneuvers` (list of `OpmManeuver`)
+/// and `user_defined` (dictionary of USER_DEFINED_* parameters)
+///
+/// # Example
+///
+/// ```python
+/// opm = parse_opm(open("sat.opm").read())
+/// for maneuver in opm["maneuvers"]:
+///     print(maneuver.epoch_ignition, maneuver.inertial_delta_v(opm["state"]))
+/// ```
+#[pyfunction]
+#[pyo3(name = "parse_opm")]
+fn py_parse_opm(py: Python<'_>, opm: &str) -> PyResult<PyObject> {
+    let opm = ccsds::parse_opm(opm)?;
+    let metadata = &opm.metadata;
+    let spacecraft = &opm.spacecraft;
+    let dict = pyo3::types::PyDict::new_bound(py);
+
+    dict.set_item("originator", &opm.originator)?;
+    dict.set_item("creation_date", &opm.creation_date)?;
+    dict.set_item("comments", &opm.comments)?;
+    dict.set_item("object_name", &metadata.object_name)?;
+    dict.set_item("object_id", &metadata.object_id)?;
+    dict.set_item("center_name", &metadata.center_name)?;
+    dict.set_item("ref_frame", &metadata.ref_frame)?;
+    dict.set_item("ref_frame_epoch", &metadata.ref_frame_epoch)?;
+    dict.set_item("frame", metadata.frame().ok().map(|frame| frame.to_string()))?;
+    dict.set_item("time_system", &metadata.time_system)?;
+    dict.set_item("metadata_comments", &metadata.comments)?;
+    dict.set_item("data_comments", &opm.data_comments)?;
+    dict.set_item("epoch", opm.epoch.into_py(py))?;
+    dict.set_item("state", opm.state.into_py(py))?;
+    dict.set_item("keplerian", opm.keplerian.map(|elements| elements.into_py(py)))?;
+    dict.set_item("gm", opm.gm)?;
+    dict.set_item("mass", spacecraft.mass)?;
+    dict.set_item("solar_rad_area", spacecraft.solar_rad_area)?;
+    dict.set_item("solar_rad_coeff", spacecraft.solar_rad_coeff)?;
+    dict.set_item("drag_area", spacecraft.drag_area)?;
+    dict.set_item("drag_coeff", spacecraft.drag_coeff)?;
+    dict.set_item("covariance", opm.covariance.map(|covariance| covariance.into_py(py)))?;
+    dict.set_item("cov_ref_frame", &opm.covariance_frame)?;
+    dict.set_item(
+        "maneuvers",
+        opm.maneuvers.into_iter().map(|maneuver| maneuver.into_py(py)).collect::<Vec<_>>(),
+    )?;
+    let user_defined = pyo3::types::PyDict::new_bound(py);
+    for (key, value) in &opm.user_defined {
+        user_defined.set_item(key, value)?;
+    }
+    dict.set_item("user_defined", user_defined)?;
+
+    Ok(dict.into())
+}
+
+/// Write a CCSDS OPM for a state and its planned maneuvers
+///
+/// # Arguments
+///
+/// * `epoch` - Epoch of the state
+/// * `state` - `CartesianState` (m, m/s)
+/// * `object_name`, `object_id` - OBJECT_NAME and OBJECT_ID
+/// * `maneuvers` - `OpmManeuver`s, in increasing ignition order
+/// * `frame` - Frame of the state ("GCRS", "J2000", "ITRS", "TEME", ...)
+/// * `mu` - Gravitational parameter (m³/s²); adds the osculating Keplerian
+///   elements when given
+/// * `mass` - Spacecraft mass (kg)
+/// * `specific_impulse` - Specific impulse (s); sets the mass change of
+///   each maneuver from the rocket equation, and needs `mass`
+/// * `covariance` - `Covariance6` of the state, in the frame of the state
+/// * `encoding` - "kvn" (CCSDS keyword = value) or "xml" (CCSDS NDM/XML)
+/// * `originator` - ORIGINATOR of the message
+///
+/// # Returns
+///
+/// The OPM text
+///
+/// # Example
+///
+/// ```python
+/// burns = hohmann_maneuvers(r_leo, r_geo, GM_EARTH, epoch)
+/// text = write_opm(epoch, state, "SAT", "2024-001A", burns, mu=GM_EARTH,
+///                  mass=1200.0, specific_impulse=310.0)
+/// ```
+#[pyfunction]
+#[pyo3(
+    name = "write_opm",
+    signature = (
+        epoch, state, object_name, object_id, maneuvers=None, frame="GCRS", mu=None,
+        mass=None, specific_impulse=None, covariance=None, encoding="kvn",
+        originator="ASTRORA"
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+fn py_write_opm(
+    epoch: core::time::Epoch,
+    state: core::state::CartesianState,
+    object_name: &str,
+    object_id: &str,
+    maneuvers: Option<Vec<ccsds::OpmManeuver>>,
+    frame: &str,
+    mu: Option<f64>,
+    mass: Option<f64>,
+    specific_impulse: Option<f64>,
+    covariance: Option<core::covariance::Covariance6>,
+    encoding: &str,
+    originator: &str,
+) -> PyResult<String> {
+    use crate::ccsds::{write_opm, Encoding, Opm};
+
+    let mut opm = Opm::new(originator, object_name, object_id, frame.parse()?, epoch, state);
+    if let Some(mu) = mu {
+        opm = opm.with_keplerian(mu)?;
+    }
+    opm.spacecraft.mass = mass;
+    opm.covariance = covariance;
+    opm.maneuvers = maneuvers.unwrap_or_default();
+    if let Some(specific_impulse) = specific_impulse {
+        opm.apply_propellant(specific_impulse)?;
+    }
+    Ok(write_opm(&opm, Encoding::from_name(encoding)?)?)
+}
+
+/// Hohmann transfer burns as OPM maneuvers
+///
+/// Both burns are along-track in the RTN frame, the second one half a
+/// transfer orbit after the first.
+///
+/// # Arguments
+///
+/// * `r_initial`, `r_final` - Radii of the circular orbits (m)
+/// * `mu` - Gravitational parameter (m³/s²)
+/// * `epoch_ignition` - Epoch of the first burn
+///
+/// # Returns
+///
+/// List of two `OpmManeuver`s
+#[pyfunction]
+#[pyo3(name = "hohmann_maneuvers")]
+fn py_hohmann_maneuvers(
+    r_initial: f64,
+    r_final: f64,
+    mu: f64,
+    epoch_ignition: core::time::Epoch,
+) -> PyResult<Vec<ccsds::OpmManeuver>> {
+    let transfer = maneuvers::HohmannTransfer::calculate(r_initial, r_final, mu)?;
+    Ok(ccsds::OpmManeuver::hohmann(&transfer, epoch_ignition))
+}
+
+/// Bi-elliptic transfer burns as OPM maneuvers
+///
+/// The three burns are along-track in the RTN frame, at the start of the
+/// transfer, at the intermediate apoapsis and at the final orbit.
+///
+/// # Arguments
+///
+/// * `r_initial`, `r_final` - Radii of the circular orbits (m)
+/// * `r_intermediate` - Apoapsis radius of the transfer orbits (m)
+/// * `mu` - Gravitational parameter (m³/s²)
+/// * `epoch_ignition` - Epoch of the first burn
+///
+/// # Returns
+///
+/// List of three `OpmManeuver`s
+#[pyfunction]
+#[pyo3(name = "bielliptic_maneuvers")]
+fn py_bielliptic_maneuvers(
+    r_initial: f64,
+    r_final: f64,
+    r_intermediate: f64,
+    mu: f64,
+    epoch_ignition: core::time::Epoch,
+) -> PyResult<Vec<ccsds::OpmManeuver>> {
+    let transfer = maneuvers::BiellipticTransfer::calculate(r_initial, r_final, r_intermediate, mu)?;
+    Ok(ccsds::OpmManeuver::bielliptic(&transfer, epoch_ignition))
+}
+
+/// Parse a CCSDS APM (attitude parameter message)
+///
+/// # Arguments
+///
+/// * `apm` - APM in CCSDS KVN or CCSDS XML format
+///
+/// # Returns
+///
+/// Dictionary with the header (`originator`, `creation_date`, `comments`),
+/// the metadata (`object_name`, `object_id`, `center_name`, `time_system`),
+/// `epoch`, `q_frame_a`, `q_frame_b`, `q_dir`, `quaternion` ([q1, q2, q3, qc]
+/// in the direction of the message), `rotation` ([q1, q2, q3, qc] from frame
+/// A to frame B), `quaternion_rate` (1/s, or None), `parameters` (Euler
+/// angle and spin keywords, verbatim), `inertia_ref_frame` and `inertia`
+/// (3x3 in kg·m², or None) and `maneuvers` (list of dictionaries with
+/// `epoch_start`, `duration`, `ref_frame` and `torque` in N·m)
+///
+/// # Example
+///
+/// ```python
+/// apm = parse_apm(open("sat.apm").read())
+/// print(apm["epoch"], apm["rotation"])
+/// ```
+#[pyfunction]
+#[pyo3(name = "parse_apm")]
+fn py_parse_apm(py: Python<'_>, apm: &str) -> PyResult<PyObject> {
+    use pyo3::types::{PyDict, PyList};
+
+    let apm = ccsds::parse_apm(apm)?;
+    let components = |q: &crate::core::linalg::UnitQuaternion| [q.i, q.j, q.k, q.w];
+    let dict = PyDict::new_bound(py);
+
+    dict.set_item("originator", &apm.originator)?;
+    dict.set_item("creation_date", &apm.creation_date)?;
+    dict.set_item("comments", &apm.comments)?;
+    dict.set_item("object_name", &apm.metadata.object_name)?;
+    dict.set_item("object_id", &apm.metadata.object_id)?;
+    dict.set_item("center_name", &apm.metadata.center_name)?;
+    dict.set_item("time_system", &apm.metadata.time_system)?;
+    dict.set_item("metadata_comments", &apm.metadata.comments)?;
+    dict.set_item("data_comments", &apm.data_comments)?;
+    dict.set_item("epoch", apm.epoch.into_py(py))?;
+    dict.set_item("q_frame_a", &apm.frame_a)?;
+    dict.set_item("q_frame_b", &apm.frame_b)?;
+    dict.set_item("q_dir", apm.direction.name())?;
+    dict.set_item("quaternion", components(&apm.quaternion))?;
+    dict.set_item("rotation", components(&apm.rotation()))?;
+    dict.set_item("quaternion_rate", apm.quaternion_rate.map(|rate| [rate.x, rate.y, rate.z, rate.w]))?;
+    let parameters = PyDict::new_bound(py);
+    for (key, value) in &apm.parameters {
+        parameters.set_item(key, value)?;
+    }
+    dict.set_item("parameters", parameters)?;
+    dict.set_item("inertia_ref_frame", &apm.inertia_frame)?;
+    dict.set_item(
+        "inertia",
+        apm.inertia.map(|inertia| {
+            [0, 1, 2].map(|row| [inertia[(row, 0)], inertia[(row, 1)], inertia[(row, 2)]])
+        }),
+    )?;
+    let maneuvers = PyList::empty_bound(py);
+    for maneuver in &apm.maneuvers {
+        let entry = PyDict::new_bound(py);
+        entry.set_item("epoch_start", maneuver.epoch_start.into_py(py))?;
+        entry.set_item("duration", maneuver.duration)?;
+        entry.set_item("ref_frame", &maneuver.ref_frame)?;
+        entry.set_item("torque", [maneuver.torque.x, maneuver.torque.y, maneuver.torque.z])?;
+        entry.set_item("comments", &maneuver.comments)?;
+        maneuvers.append(entry)?;
+    }
+    dict.set_item("maneuvers", maneuvers)?;
+
+    Ok(dict.into())
+}
+
+/// Write a CCSDS APM for a quaternion attitude
+///
+/// # Arguments
+///
+/// * `epoch` - Epoch of the attitude
+/// * `quaternion` - [q1, q2, q3, qc] of the rotation in the direction `q_dir`
+/// * `object_name`, `object_id` - OBJECT_NAME and OBJECT_ID
+/// * `q_frame_a`, `q_frame_b` - Frames of the rotation
+/// * `q_dir` - "A2B" or "B2A"
+/// * `quaternion_rate` - Derivative [q1, q2, q3, qc] of the quaternion (1/s)
+/// * `encoding` - "kvn" (CCSDS keyword = value) or "xml" (CCSDS NDM/XML)
+/// * `originator` - ORIGINATOR of the message
+///
+/// # Returns
+///
+/// The APM text
+///
+/// # Example
+///
+/// ```python
+/// text = write_apm(epoch, [0.0, 0.0, 0.0, 1.0], "SAT", "2024-001A")
+/// ```
+#[pyfunction]
+#[pyo3(
+    name = "write_apm",
+    signature = (
+        epoch, quaternion, object_name, object_id, q_frame_a="EME2000",
+        q_frame_b="SC_BODY_1", q_dir="A2B", quaternion_rate=None, encoding="kvn",
+        originator="ASTRORA"
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+fn py_write_apm(
+    epoch: core::time::Epoch,
+    quaternion: [f64; 4],
+    object_name: &str,
+    object_id: &str,
+    q_frame_a: &str,
+    q_frame_b: &str,
+    q_dir: &str,
+    quaternion_rate: Option<[f64; 4]>,
+    encoding: &str,
+    originator: &str,
+) -> PyResult<String> {
+    use crate::ccsds::{write_apm, Apm, AttitudeDirection, Encoding};
+
+    let quaternion = ccsds::quaternion(quaternion, "APM")?;
+    let mut apm = Apm::new(originator, object_name, object_id, epoch, q_frame_a, q_frame_b, quaternion);
+    apm.direction = AttitudeDirection::from_name(q_dir, "APM")?;
+    apm.quaternion_rate = quaternion_rate.map(crate::core::linalg::Vector4::from);
+    Ok(write_apm(&apm, Encoding::from_name(encoding)?)?)
+}
+
+/// Parse a CCSDS AEM (attitude ephemeris message)
+///
+/// # Arguments
+///
+/// * `aem` - AEM in CCSDS KVN or CCSDS XML format
+///
+/// # Returns
+///
+/// Dictionary with the header (`originator`, `creation_date`, `comments`)
+/// and `segments`, a list of dictionaries with the metadata keyed by the
+/// lower-case CCSDS keywords (`object_name`, `ref_frame_a`, `ref_frame_b`,
+/// `attitude_dir`, `start_time`, `attitude_type`, ...), `comments`, `epochs`
+/// (list of `Epoch`), `quaternions` ([q1, q2, q3, qc] in the direction of
+/// the segment) and `rates` (quaternion derivatives in 1/s, angular rates in
+/// rad/s, or None per attitude)
+///
+/// # Example
+///
+/// ```python
+/// aem = parse_aem(open("sat.aem").read())
+/// segment = aem["segments"][0]
+/// print(segment["object_name"], len(segment["quaternions"]))
+/// ```
+#[pyfunction]
+#[pyo3(name = "parse_aem")]
+fn py_parse_aem(py: Python<'_>, aem: &str) -> PyResult<PyObject> {
+    use crate::ccsds::AttitudeRate;
+    use pyo3::types::{PyDict, PyList};
+
+    let aem = ccsds::parse_aem(aem)?;
+    let segments = PyList::empty_bound(py);
+    for segment in &aem.segments {
+        let metadata = &segment.metadata;
+        let dict = PyDict::new_bound(py);
+        dict.set_item("object_name", &metadata.object_name)?;
+        dict.set_item("object_id", &metadata.object_id)?;
+        dict.set_item("center_name", &metadata.center_name)?;
+        dict.set_item("ref_frame_a", &metadata.frame_a)?;
+        dict.set_item("ref_frame_b", &metadata.frame_b)?;
+        dict.set_item("attitude_dir", metadata.direction.name())?;
+        dict.set_item("time_system", &metadata.time_system)?;
+        dict.set_item("start_time", metadata.start_time.into_py(py))?;
+        dict.set_item("useable_start_time", metadata.useable_start_time.map(|epoch| epoch.into_py(py)))?;
+        dict.set_item("useable_stop_time", metadata.useable_stop_time.map(|epoch| epoch.into_py(py)))?;
+        dict.set_item("stop_time", metadata.stop_time.into_py(py))?;
+        dict.set_item("attitude_type", metadata.attitude_type.name())?;
+        dict.set_item("rate_frame", &metadata.rate_frame)?;
+        dict.set_item("interpolation_method", &metadata.interpolation_method)?;
+        dict.set_item("interpolation_degree", metadata.interpolation_degree)?;
+        dict.set_item("metadata_comments", &metadata.comments)?;
+        dict.set_item("comments", &segment.comments)?;
+        dict.set_item("epochs", segment.attitudes.iter().map(|attitude| attitude.epoch.into_py(py)).collect::<Vec<_>>())?;
+        dict.set_item(
+            "quaternions",
+            segment
+                .attitudes
+                .iter()
+                .map(|attitude| {
+                    let q = &attitude.quaternion;
+                    [q.i, q.j, q.k, q.w]
+                })
+                .collect::<Vec<_>>(),
+        )?;
+        dict.set_item(
+            "rates",
+            segment
+                .attitudes
+                .iter()
+                .map(|attitude| match attitude.rate {
+                    Some(AttitudeRate::Derivative(rate)) => Some(vec![rate.x, rate.y, rate.z, rate.w]),
+                    Some(AttitudeRate::Angular(rate)) => Some(vec![rate.x, rate.y, rate.z]),
+                    None => None,
+                })
+                .collect::<Vec<_>>(),
+        )?;
+        segments.append(dict)?;
+    }
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("originator", &aem.originator)?;
+    dict.set_item("creation_date", &aem.creation_date)?;
+    dict.set_item("comments", &aem.comments)?;
+    dict.set_item("segments", segments)?;
+    Ok(dict.into())
+}
+
+/// Write a single-segment CCSDS AEM from a sequence of quaternion attitudes
+///
+/// # Arguments
+///
+/// * `epochs` - Attitude epochs, in increasing order
+/// * `quaternions` - [q1, q2, q3, qc] of the rotation in the direction
+///   `attitude_dir` at each epoch
+/// * `object_name`, `object_id` - OBJECT_NAME and OBJECT_ID
+/// * `ref_frame_a`, `ref_frame_b` - Frames of the rotation
+/// * `attitude_dir` - "A2B" or "B2A"
+/// * `encoding` - "kvn" (CCSDS keyword = value) or "xml" (CCSDS NDM/XML)
+/// * `originator` - ORIGINATOR of the message
+/// * `time_system` - TIME_SYSTEM of the epochs ("UTC", "TAI", "TT", "TDB" or "GPS")
+///
+/// # Returns
+///
+/// The AEM text
+///
+/// # Example
+///
+/// ```python
+/// text = write_aem(epochs, quaternions, "SAT", "2024-001A", ref_frame_b="SC_BODY_1")
+/// ```
+#[pyfunction]
+#[pyo3(
+    name = "write_aem",
+    signature = (
+        epochs, quaternions, object_name, object_id, ref_frame_a="EME2000",
+        ref_frame_b="SC_BODY_1", attitude_dir="A2B", encoding="kvn",
+        originator="ASTRORA", time_system="UTC"
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+fn py_write_aem(
+    epochs: Vec<core::time::Epoch>,
+    quaternions: Vec<[f64; 4]>,
+    object_name: &str,
+    object_id: &str,
+    ref_frame_a: &str,
+    ref_frame_b: &str,
+    attitude_dir: &str,
+    encoding: &str,
+    originator: &str,
+    time_system: &str,
+) -> PyResult<String> {
+    use crate::ccsds::{write_aem, Aem, AemAttitude, AemSegment, AttitudeDirection, Encoding};
+
+    if epochs.len() != quaternions.len() {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Got {} epochs for {} quaternions",
+            epochs.len(),
+            quaternions.len()
+        )));
+    }
+    let attitudes = epochs
+        .into_iter()
+        .zip(quaternions)
+        .map(|(epoch, quaternion)| Ok(AemAttitude::new(epoch, ccsds::quaternion(quaternion, "AEM")?)))
+        .collect::<core::PoliastroResult<Vec<_>>>()?;
+    let mut segment = AemSegment::new(object_name, object_id, ref_frame_a, ref_frame_b, attitudes)?;
+    segment.metadata.direction = AttitudeDirection::from_name(attitude_dir, "AEM")?;
+    segment.metadata.time_system = time_system.to_ascii_uppercase();
+    segment.validate()?;
+    Ok(write_aem(&Aem::new(originator, vec![segment]), Encoding::from_name(encoding)?)?)
+}
+
 // ============================================================================
 // Satellite Visibility and Ground Station Operations
 // ============================================================================
 
+/// Reference ellipsoid for the optional `body` argument (NAIF ID, WGS84 if None)
+fn reference_ellipsoid(body: Option<i32>) -> PyResult<crate::satellite::groundtrack::Ellipsoid> {
+    use crate::satellite::groundtrack::Ellipsoid;
+
+    match body {
+        None => Ok(Ellipsoid::WGS84),
+        Some(id) => Ellipsoid::for_body(ephemeris::Body::from_naif_id(id)).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "No reference ellipsoid for NAIF body {}",
+                id
+            ))
+        }),
+    }
+}
+
 /// Compute azimuth and elevation of a satellite from an observer location
 ///
 /// Transforms satellite position from ECEF (Earth-Centered, Earth-Fixed) coordinates
@@ -5187,6 +6642,9 @@ fn py_propagate_omm<'py>(
 /// * `observer_lat_deg` - Observer latitude in degrees (-90 to 90)
 /// * `observer_lon_deg` - Observer longitude in degrees (-180 to 180)
 /// * `observer_alt_km` - Observer altitude above WGS84 ellipsoid in km
+/// * `body` - Optional NAIF ID of the observer's body (default Earth). For
+///   other bodies, `sat_ecef` is in that body's body-fixed frame and the
+///   observer altitude is above its reference ellipsoid
 ///
 /// # Returns
 ///
@@ -5214,12 +6672,14 @@ fn py_propagate_omm<'py>(
 /// print(f"Range: {result['range_km']:.1f} km")
 /// ```
 #[pyfunction]
+#[pyo3(signature = (sat_ecef, observer_lat_deg, observer_lon_deg, observer_alt_km, body=None))]
 fn py_compute_azimuth_elevation<'py>(
     py: Python<'py>,
     sat_ecef: [f64; 3],
     observer_lat_deg: f64,
     observer_lon_deg: f64,
     observer_alt_km: f64,
+    body: Option<i32>,
 ) -> PyResult<PyObject> {
     use crate::satellite::visibility::{Observer, compute_azimuth_elevation};
 
@@ -5227,7 +6687,8 @@ fn py_compute_azimuth_elevation<'py>(
         observer_lat_deg.to_radians(),
         observer_lon_deg.to_radians(),
         observer_alt_km,
-    );
+    )
+    .with_ellipsoid(reference_ellipsoid(body)?);
 
     let topo = compute_azimuth_elevation(&sat_ecef, &observer);
 
@@ -5251,6 +6712,7 @@ fn py_compute_azimuth_elevation<'py>(
 /// * `observer_lat_deg` - Observer latitude in degrees
 /// * `observer_lon_deg` - Observer longitude in degrees
 /// * `observer_alt_km` - Observer altitude in km
+/// * `body` - Optional NAIF ID of the observer's body (default Earth)
 ///
 /// # Returns
 ///
@@ -5268,6 +6730,7 @@ fn py_compute_azimuth_elevation<'py>(
 /// print(f"Range rate: {result['range_rate_km_s']:.3f} km/s")
 /// ```
 #[pyfunction]
+#[pyo3(signature = (sat_ecef, vel_ecef, observer_lat_deg, observer_lon_deg, observer_alt_km, body=None))]
 fn py_compute_azimuth_elevation_rate<'py>(
     py: Python<'py>,
     sat_ecef: [f64; 3],
@@ -5275,6 +6738,7 @@ fn py_compute_azimuth_elevation_rate<'py>(
     observer_lat_deg: f64,
     observer_lon_deg: f64,
     observer_alt_km: f64,
+    body: Option<i32>,
 ) -> PyResult<PyObject> {
     use crate::satellite::visibility::{Observer, compute_azimuth_elevation_rate};
 
@@ -5282,7 +6746,8 @@ fn py_compute_azimuth_elevation_rate<'py>(
         observer_lat_deg.to_radians(),
         observer_lon_deg.to_radians(),
         observer_alt_km,
-    );
+    )
+    .with_ellipsoid(reference_ellipsoid(body)?);
 
     let topo = compute_azimuth_elevation_rate(&sat_ecef, &vel_ecef, &observer);
 
@@ -5307,6 +6772,7 @@ fn py_compute_azimuth_elevation_rate<'py>(
 /// * `observer_lon_deg` - Observer longitude in degrees
 /// * `observer_alt_km` - Observer altitude in km
 /// * `min_elevation_deg` - Minimum elevation for visibility in degrees (typically 0-10)
+/// * `body` - Optional NAIF ID of the observer's body (default Earth)
 ///
 /// # Returns
 ///
@@ -5321,12 +6787,14 @@ fn py_compute_azimuth_elevation_rate<'py>(
 ///     print("Satellite is visible above 10° elevation")
 /// ```
 #[pyfunction]
+#[pyo3(signature = (sat_ecef, observer_lat_deg, observer_lon_deg, observer_alt_km, min_elevation_deg, body=None))]
 fn py_is_visible(
     sat_ecef: [f64; 3],
     observer_lat_deg: f64,
     observer_lon_deg: f64,
     observer_alt_km: f64,
     min_elevation_deg: f64,
+    body: Option<i32>,
 ) -> PyResult<bool> {
     use crate::satellite::visibility::{Observer, is_visible};
 
@@ -5334,7 +6802,8 @@ fn py_is_visible(
         observer_lat_deg.to_radians(),
         observer_lon_deg.to_radians(),
         observer_alt_km,
-    );
+    )
+    .with_ellipsoid(reference_ellipsoid(body)?);
 
     Ok(is_visible(&sat_ecef, &observer, min_elevation_deg.to_radians()))
 }
@@ -5462,12 +6931,14 @@ fn py_find_satellite_passes<'py>(
 ///
 /// # Arguments
 /// * `ecef_position` - ECEF position [x, y, z] in km (numpy array)
+/// * `body` - Optional NAIF ID of the body (default Earth); the position is
+///   then in that body's body-fixed frame
 ///
 /// # Returns
 /// Dictionary with keys:
 /// * `latitude_deg` - Geodetic latitude in degrees
 /// * `longitude_deg` - Geodetic longitude in degrees
-/// * `altitude_km` - Altitude above WGS84 ellipsoid in km
+/// * `altitude_km` - Altitude above the reference ellipsoid (WGS84 for Earth) in km
 ///
 /// # Example
 /// ```python
@@ -5484,11 +6955,13 @@ fn py_find_satellite_passes<'py>(
 /// print(f"Alt: {geodetic['altitude_km']:.2f} km")
 /// ```
 #[pyfunction(name = "ecef_to_geodetic")]
+#[pyo3(signature = (ecef_position, body=None))]
 fn py_ecef_to_geodetic<'py>(
     py: Python<'py>,
     ecef_position: PyReadonlyArray1<f64>,
+    body: Option<i32>,
 ) -> PyResult<PyObject> {
-    use crate::satellite::groundtrack::ecef_to_geodetic;
+    use crate::satellite::groundtrack::ecef_to_geodetic_on;
 
     // Convert to array
     let ecef = ecef_position.as_array();
@@ -5501,7 +6974,7 @@ fn py_ecef_to_geodetic<'py>(
     let ecef_arr = [ecef[0], ecef[1], ecef[2]];
 
     // Convert to geodetic
-    let geodetic = ecef_to_geodetic(&ecef_arr);
+    let geodetic = ecef_to_geodetic_on(&ecef_arr, &reference_ellipsoid(body)?);
 
     // Return as dictionary
     let dict = pyo3::types::PyDict::new_bound(py);
@@ -5998,6 +7471,12 @@ fn py_eclipse_duration(altitude_km: f64, beta_angle_deg: f64) -> PyResult<f64> {
 /// * `ballistic_coeff` - Ballistic coefficient B = Cd·A/m (m²/kg)
 /// * `terminal_altitude_km` - Altitude at which orbit is considered decayed (km)
 /// * `max_time_days` - Maximum propagation time (days)
+/// * `atmosphere` - Optional model name ("exponential", "harris_priester" or
+///   "jacchia71"); default is the exponential model
+/// * `epoch` - Epoch of the initial state (required with `atmosphere`)
+/// * `f107`, `f107_avg`, `ap` - Constant solar and geomagnetic activity for
+///   "jacchia71" (indices not given come from the space weather at `epoch`);
+///   without any, the loaded space weather is followed along the decay
 ///
 /// # Returns
 /// Estimated lifetime in days until reaching terminal altitude
@@ -6017,17 +7496,38 @@ fn py_eclipse_duration(altitude_km: f64, beta_angle_deg: f64) -> PyResult<f64> {
 /// B = 0.01  # Ballistic coefficient (m²/kg)
 /// lifetime = estimate_satellite_lifetime(r, v, B, 100.0, 365.0)
 /// print(f"Orbital lifetime: {lifetime:.1f} days")
+///
+/// # Solar-activity-driven density
+/// lifetime = estimate_satellite_lifetime(
+///     r, v, B, 100.0, 365.0,
+///     atmosphere="jacchia71", epoch=Epoch(2024, 6, 1, 0, 0, 0, 0),
+///     f107=180.0, f107_avg=170.0, ap=12.0,
+/// )
 /// ```
 #[pyfunction]
-#[pyo3(name = "estimate_satellite_lifetime")]
+#[pyo3(
+    name = "estimate_satellite_lifetime",
+    signature = (
+        r_km, v_km_s, ballistic_coeff, terminal_altitude_km, max_time_days,
+        atmosphere=None, epoch=None, f107=None, f107_avg=None, ap=None
+    )
+)]
+#[allow(clippy::too_many_arguments)]
 fn py_estimate_satellite_lifetime(
     r_km: [f64; 3],
     v_km_s: [f64; 3],
     ballistic_coeff: f64,
     terminal_altitude_km: f64,
     max_time_days: f64,
+    atmosphere: Option<&str>,
+    epoch: Option<core::time::Epoch>,
+    f107: Option<f64>,
+    f107_avg: Option<f64>,
+    ap: Option<f64>,
 ) -> PyResult<f64> {
-    use crate::satellite::lifetime::estimate_lifetime;
+    use crate::satellite::lifetime::{
+        estimate_lifetime, estimate_lifetime_with_atmosphere, DEFAULT_INITIAL_TIME_STEP,
+    };
     use crate::core::linalg::Vector3;
 
     // Convert from km to meters
@@ -6036,7 +7536,28 @@ fn py_estimate_satellite_lifetime(
     let terminal_altitude = terminal_altitude_km * 1000.0;
     let max_time = max_time_days * 86400.0; // days to seconds
 
-    match estimate_lifetime(&r, &v, ballistic_coeff, terminal_altitude, max_time, 60.0) {
+    let result = match atmosphere {
+        None => estimate_lifetime(
+            &r,
+            &v,
+            ballistic_coeff,
+            terminal_altitude,
+            max_time,
+            DEFAULT_INITIAL_TIME_STEP,
+        ),
+        Some(name) => {
+            let epoch = epoch.ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err("epoch is required with an atmosphere model")
+            })?;
+            let activity = activity_overrides(&epoch, f107, f107_avg, ap);
+            let model = atmosphere_model(name, activity)?;
+            estimate_lifetime_with_atmosphere(
+                &r, &v, &epoch, ballistic_coeff, model.as_ref(), terminal_altitude, max_time,
+            )
+        }
+    };
+
+    match result {
         Ok(lifetime_sec) => Ok(lifetime_sec / 86400.0), // Convert to days
         Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
             format!("Lifetime estimation failed: {e}")
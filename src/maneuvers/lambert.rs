@@ -279,18 +279,15 @@ impl Lambert {
                 break;
             }
 
-            // Newton-Raphson derivative - Curtis Eq. 5.43
+            // Newton-Raphson derivative - Curtis Eq. 5.45 and 5.46
             let dt_dz = if z.abs() < 1e-6 {
                 // Near-parabolic case
-                (chi.powi(3) / 40.0 + a_param / 8.0) / mu.sqrt()
+                (std::f64::consts::SQRT_2 / 40.0 * y.powf(1.5)
+                    + a_param / 8.0 * (y.sqrt() + a_param * (1.0 / (2.0 * y)).sqrt()))
+                    / mu.sqrt()
             } else {
-                let (_c2_prime, c3_prime) = fast_math::stumpff_derivatives(z, c2, c3);
-                let dy_dz = a_param * (c3_prime - 1.5 * c2 * c3 / c2) / c2.sqrt();
-                let dchi_dz = (1.0 / (2.0 * chi) - chi / (2.0 * y) * dy_dz) / c2.sqrt();
-
-                (3.0 * chi.powi(2) * c3 * dchi_dz
-                    + chi.powi(3) * c3_prime
-                    + a_param / (2.0 * y.sqrt()) * dy_dz)
+                (chi.powi(3) * ((c2 - 1.5 * c3 / c2) / (2.0 * z) + 0.75 * c3 * c3 / c2)
+                    + a_param / 8.0 * (3.0 * c3 / c2 * y.sqrt() + a_param * (c2 / y).sqrt()))
                     / mu.sqrt()
             };
 
@@ -876,6 +873,25 @@ mod tests {
         assert!(v2_mag > 5000.0 && v2_mag < 10000.0); // 5-10 km/s
     }
 
+    #[test]
+    fn test_lambert_short_arc_circular() {
+        // Short arcs of a circular orbit, as used by initial orbit determination
+        let mu = 3.986004418e14;
+        let r: f64 = 7000e3;
+        let n = (mu / r.powi(3)).sqrt();
+
+        for theta_deg in [2.0_f64, 10.0, 20.0, 45.0] {
+            let theta = theta_deg.to_radians();
+            let r1 = Vector3::new(r, 0.0, 0.0);
+            let r2 = Vector3::new(r * theta.cos(), r * theta.sin(), 0.0);
+
+            let solution = Lambert::solve(r1, r2, theta / n, mu, TransferKind::Auto, 0).unwrap();
+
+            let v1_expected = Vector3::new(0.0, (mu / r).sqrt(), 0.0);
+            assert!((solution.v1 - v1_expected).norm() < 1e-6, "theta = {theta_deg} deg");
+        }
+    }
+
     #[test]
     fn test_lambert_invalid_inputs() {
         let mu = 3.986004418e14;
@@ -0,0 +1,741 @@
+//! Atmospheric density models for drag
+//!
+//! [`AtmosphereModel`] is the common interface used by
+//! [`DragPerturbation`](super::perturbations::DragPerturbation) and
+//! [`estimate_lifetime_with_atmosphere`](crate::satellite::lifetime::estimate_lifetime_with_atmosphere).
+//! Models are evaluated at an epoch and a GCRS position; [`AtmospherePoint`]
+//! derives the geodetic position, local solar time and Sun direction that
+//! the thermospheric models need.
+//!
+//! # Models
+//!
+//! - [`ExponentialAtmosphere`]: single scale height, no solar activity
+//!   (the model behind [`exponential_density`])
+//! - [`HarrisPriester`]: tabulated minimum/maximum density with the diurnal
+//!   bulge lagging the Sun by 30°, for mean solar activity (100–1000 km)
+//! - [`Jacchia71`]: Jacchia (1971) static diffusion model driven by
+//!   [`SolarActivity`] (F10.7, its 81-day mean and Ap), with the diurnal,
+//!   geomagnetic, semi-annual and seasonal-latitudinal variations
+//!   (90–2500 km); by default the activity comes from the installed
+//!   [space weather](crate::core::space_weather)
+//!
+//! # References
+//! - Montenbruck, O. & Gill, E., "Satellite Orbits" (2000), Section 3.5
+//! - Jacchia, L. G. (1971), "Revised static models of the thermosphere and
+//!   exosphere with empirical temperature profiles", SAO Special Report 332
+//! - Vallado, "Fundamentals of Astrodynamics" Section 8.6
+
+use std::f64::consts::{FRAC_PI_4, LN_10, PI, TAU};
+use std::fmt::Debug;
+
+use crate::coordinates::earth_orientation::{earth_rotation_angle_ut1, EarthOrientation};
+use crate::core::constants::{H0_EARTH, RHO0_EARTH, R_EARTH};
+use crate::core::linalg::Vector3;
+pub use crate::core::space_weather::SolarActivity;
+use crate::core::time::Epoch;
+use crate::ephemeris::{ephemeris_position, Body};
+use crate::propagators::perturbations::{exponential_density, sun_position_simple};
+use crate::satellite::groundtrack::ecef_to_geodetic;
+
+/// Earth rotation rate used for the co-rotating atmosphere (rad/s)
+pub const EARTH_ROTATION_RATE: f64 = 7.292_115e-5;
+
+/// Velocity of a spacecraft relative to an atmosphere co-rotating with the
+/// Earth (m/s)
+pub fn velocity_relative_to_atmosphere(r: &Vector3, v: &Vector3) -> Vector3 {
+    v - Vector3::new(0.0, 0.0, EARTH_ROTATION_RATE).cross(r)
+}
+
+/// Position of a spacecraft as seen by an atmosphere model
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtmospherePoint {
+    /// Epoch of evaluation
+    pub epoch: Epoch,
+    /// GCRS position (m)
+    pub position: Vector3,
+    /// Geodetic latitude (rad)
+    pub latitude: f64,
+    /// Geodetic longitude (rad)
+    pub longitude: f64,
+    /// Height above the WGS84 ellipsoid (m)
+    pub altitude: f64,
+    /// Local solar time (hours, 0–24)
+    pub local_solar_time: f64,
+    /// Right ascension of the Sun (rad)
+    pub sun_right_ascension: f64,
+    /// Declination of the Sun (rad)
+    pub sun_declination: f64,
+}
+
+impl AtmospherePoint {
+    /// Evaluate the geometry at a GCRS position (m)
+    ///
+    /// The Sun comes from the installed ephemeris (or the analytic fallback).
+    /// The Earth-fixed position uses only the Earth Rotation Angle (UT1 from
+    /// the installed EOP or the bundled model): precession-nutation and polar
+    /// motion shift latitude and longitude by well under a degree, which is
+    /// immaterial for density and keeps force-model evaluation cheap.
+    pub fn new(epoch: &Epoch, position: &Vector3) -> Self {
+        let (ut1_1, ut1_2) = EarthOrientation::at_or_fallback(epoch).ut1_two_part(epoch);
+        let (sin_era, cos_era) = earth_rotation_angle_ut1(ut1_1, ut1_2).sin_cos();
+        let fixed = [
+            (cos_era * position.x + sin_era * position.y) / 1000.0,
+            (cos_era * position.y - sin_era * position.x) / 1000.0,
+            position.z / 1000.0,
+        ];
+        let geodetic = ecef_to_geodetic(&fixed);
+
+        let sun = ephemeris_position(Body::Sun, Body::Earth, epoch)
+            .unwrap_or_else(|_| sun_position_simple(epoch.to_tdb_seconds_since_j2000()));
+        let sun_right_ascension = sun.y.atan2(sun.x);
+        let sun_declination = (sun.z / sun.norm()).asin();
+
+        // Hour angle of the Sun at the spacecraft, measured from local noon
+        let hour_angle = position.y.atan2(position.x) - sun_right_ascension;
+        let local_solar_time = match (12.0 + hour_angle.to_degrees() / 15.0).rem_euclid(24.0) {
+            lst if lst < 24.0 => lst,
+            _ => 0.0, // rounding of a tiny negative value
+        };
+
+        Self {
+            epoch: *epoch,
+            position: *position,
+            latitude: geodetic.latitude,
+            longitude: geodetic.longitude,
+            altitude: geodetic.altitude * 1000.0,
+            local_solar_time,
+            sun_right_ascension,
+            sun_declination,
+        }
+    }
+
+    /// Hour angle of the Sun at the spacecraft (rad, 0 at local noon)
+    pub fn sun_hour_angle(&self) -> f64 {
+        (self.local_solar_time - 12.0) * PI / 12.0
+    }
+}
+
+/// Atmospheric density model
+///
+/// Implementations must be thread-safe, matching
+/// [`Perturbation`](super::perturbations::Perturbation).
+pub trait AtmosphereModel: Debug + Send + Sync {
+    /// Mass density (kg/m³) at a GCRS position (m) and epoch
+    fn density(&self, epoch: &Epoch, position: &Vector3) -> f64;
+
+    /// Name of the model
+    fn name(&self) -> &str;
+}
+
+/// Exponential atmosphere with a single scale height
+///
+/// Altitude is measured above a sphere of radius `radius`, as in
+/// [`drag_acceleration`](super::perturbations::drag_acceleration).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExponentialAtmosphere {
+    /// Reference radius (m)
+    pub radius: f64,
+    /// Density at the reference radius (kg/m³)
+    pub rho0: f64,
+    /// Scale height (m)
+    pub scale_height: f64,
+}
+
+impl ExponentialAtmosphere {
+    /// Create an exponential atmosphere
+    pub fn new(radius: f64, rho0: f64, scale_height: f64) -> Self {
+        Self {
+            radius,
+            rho0,
+            scale_height,
+        }
+    }
+
+    /// Sea-level Earth atmosphere (R_EARTH, RHO0_EARTH, H0_EARTH)
+    pub fn earth() -> Self {
+        Self::new(R_EARTH, RHO0_EARTH, H0_EARTH)
+    }
+}
+
+impl AtmosphereModel for ExponentialAtmosphere {
+    fn density(&self, _epoch: &Epoch, position: &Vector3) -> f64 {
+        exponential_density(position.norm() - self.radius, self.rho0, self.scale_height)
+    }
+
+    fn name(&self) -> &str {
+        "Exponential"
+    }
+}
+
+/// Harris-Priester table: height (km), minimum and maximum density (g/km³),
+/// mean solar activity (Montenbruck & Gill, Table 3.7)
+const HARRIS_PRIESTER_TABLE: [(f64, f64, f64); 50] = [
+    (100.0, 497400.0, 497400.0),
+    (120.0, 24900.0, 24900.0),
+    (130.0, 8377.0, 8710.0),
+    (140.0, 3899.0, 4059.0),
+    (150.0, 2122.0, 2215.0),
+    (160.0, 1263.0, 1344.0),
+    (170.0, 800.8, 875.8),
+    (180.0, 528.3, 601.0),
+    (190.0, 361.7, 429.7),
+    (200.0, 255.7, 316.2),
+    (210.0, 183.9, 239.6),
+    (220.0, 134.1, 185.3),
+    (230.0, 99.49, 145.5),
+    (240.0, 74.88, 115.7),
+    (250.0, 57.09, 93.08),
+    (260.0, 44.03, 75.55),
+    (270.0, 34.30, 61.82),
+    (280.0, 26.97, 50.95),
+    (290.0, 21.39, 42.26),
+    (300.0, 17.08, 35.26),
+    (320.0, 10.99, 25.11),
+    (340.0, 7.214, 18.19),
+    (360.0, 4.824, 13.37),
+    (380.0, 3.274, 9.955),
+    (400.0, 2.249, 7.492),
+    (420.0, 1.558, 5.684),
+    (440.0, 1.091, 4.355),
+    (460.0, 0.7701, 3.362),
+    (480.0, 0.5474, 2.612),
+    (500.0, 0.3916, 2.042),
+    (520.0, 0.2819, 1.605),
+    (540.0, 0.2042, 1.267),
+    (560.0, 0.1488, 1.005),
+    (580.0, 0.1092, 0.7997),
+    (600.0, 0.08070, 0.6390),
+    (620.0, 0.06012, 0.5123),
+    (640.0, 0.04519, 0.4121),
+    (660.0, 0.03430, 0.3325),
+    (680.0, 0.02632, 0.2691),
+    (700.0, 0.02043, 0.2185),
+    (720.0, 0.01607, 0.1779),
+    (740.0, 0.01281, 0.1452),
+    (760.0, 0.01036, 0.1190),
+    (780.0, 0.008496, 0.09776),
+    (800.0, 0.007069, 0.08059),
+    (840.0, 0.004680, 0.05741),
+    (880.0, 0.003200, 0.04210),
+    (920.0, 0.002210, 0.03130),
+    (960.0, 0.001560, 0.02360),
+    (1000.0, 0.001150, 0.01810),
+];
+
+/// Harris-Priester atmosphere
+///
+/// Interpolates exponentially between tabulated minimum (antapex) and
+/// maximum (apex) densities and blends them with cosⁿ(ψ/2), where ψ is the
+/// angle to the apex of the diurnal bulge, 30° east of the Sun. The table is
+/// for mean solar activity, so F10.7/Ap are not used. Density is zero outside
+/// 100–1000 km.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HarrisPriester {
+    /// Exponent n of the bulge blending: 2 for low-inclination orbits, up to
+    /// 6 for polar orbits
+    pub exponent: f64,
+}
+
+impl HarrisPriester {
+    /// Lag of the bulge apex behind the Sun (rad)
+    pub const BULGE_LAG: f64 = 30.0 * PI / 180.0;
+
+    /// Create a Harris-Priester model with the given blending exponent
+    pub fn new(exponent: f64) -> Self {
+        Self { exponent }
+    }
+
+    /// Density (kg/m³) at an evaluated point
+    pub fn density_at(&self, point: &AtmospherePoint) -> f64 {
+        let height = point.altitude / 1000.0;
+        let table = &HARRIS_PRIESTER_TABLE;
+        if height < table[0].0 || height > table[table.len() - 1].0 {
+            return 0.0;
+        }
+
+        let i = table
+            .iter()
+            .rposition(|&(h, ..)| h <= height)
+            .unwrap_or(0)
+            .min(table.len() - 2);
+        let ((h0, min0, max0), (h1, min1, max1)) = (table[i], table[i + 1]);
+        let interpolate = |lower: f64, upper: f64| {
+            let scale_height = (h0 - h1) / (upper / lower).ln();
+            lower * ((h0 - height) / scale_height).exp()
+        };
+        let rho_min = interpolate(min0, min1);
+        let rho_max = interpolate(max0, max1);
+
+        let (dec, ra) = (
+            point.sun_declination,
+            point.sun_right_ascension + Self::BULGE_LAG,
+        );
+        let apex = Vector3::new(dec.cos() * ra.cos(), dec.cos() * ra.sin(), dec.sin());
+        let cos_psi = point.position.normalize().dot(&apex);
+        let blend = (0.5 + 0.5 * cos_psi).max(0.0).powf(0.5 * self.exponent);
+
+        // g/km³ to kg/m³
+        (rho_min + (rho_max - rho_min) * blend) * 1e-12
+    }
+}
+
+impl Default for HarrisPriester {
+    /// Exponent 4 (intermediate inclinations)
+    fn default() -> Self {
+        Self::new(4.0)
+    }
+}
+
+impl AtmosphereModel for HarrisPriester {
+    fn density(&self, epoch: &Epoch, position: &Vector3) -> f64 {
+        self.density_at(&AtmospherePoint::new(epoch, position))
+    }
+
+    fn name(&self) -> &str {
+        "Harris-Priester"
+    }
+}
+
+// Jacchia 1971 constants
+const J71_Z0: f64 = 90.0; // Lower boundary (km)
+const J71_ZX: f64 = 125.0; // Inflection point of the temperature profile (km)
+const J71_Z_MIXED: f64 = 105.0; // Top of the mixed region (km)
+const J71_Z_HYDROGEN: f64 = 500.0; // Hydrogen reference height (km)
+const J71_T0: f64 = 183.0; // Temperature at 90 km (K)
+const J71_RHO0: f64 = 3.46e-6; // Density at 90 km (kg/m³)
+const J71_M0: f64 = 28.960; // Sea-level mean molecular mass (g/mol)
+const GAS_CONSTANT: f64 = 8.31432; // J/(mol·K)
+const AVOGADRO: f64 = 6.022_57e23; // 1/mol
+const G0: f64 = 9.80665; // m/s²
+const J71_EARTH_RADIUS: f64 = 6356.766; // km
+
+/// Diffusing species: molecular mass (g/mol), sea-level volume fraction and
+/// thermal diffusion coefficient (N₂, O₂, O, Ar, He)
+const J71_N2: (f64, f64, f64) = (28.0134, 0.78110, 0.0);
+const J71_O2: (f64, f64, f64) = (31.9988, 0.20955, 0.0);
+const J71_O: (f64, f64, f64) = (15.9994, 0.0, 0.0);
+const J71_AR: (f64, f64, f64) = (39.948, 0.009_343_2, 0.0);
+const J71_HE: (f64, f64, f64) = (4.0026, 6.1471e-6, -0.38);
+const J71_H_MASS: f64 = 1.00797;
+
+/// Jacchia (1971) thermosphere
+///
+/// Static diffusion model: an empirical temperature profile fixed by the
+/// exospheric temperature T∞, a mixed atmosphere from 90 to 105 km and
+/// diffusive equilibrium of N₂, O₂, O, Ar, He and H above. T∞ follows the
+/// solar flux, the diurnal bulge and geomagnetic activity; the semi-annual,
+/// seasonal-latitudinal and helium seasonal variations are applied to the
+/// density. Valid from 90 to 2500 km; zero above, and clamped at 90 km below.
+///
+/// The default model takes its activity from the installed space weather
+/// (see [`SolarActivity::at`]).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Jacchia71 {
+    /// Solar and geomagnetic activity (None: installed space weather at the
+    /// epoch of evaluation)
+    pub activity: Option<SolarActivity>,
+}
+
+impl Jacchia71 {
+    /// Create a Jacchia 1971 model for constant activity
+    pub fn new(activity: SolarActivity) -> Self {
+        Self {
+            activity: Some(activity),
+        }
+    }
+
+    /// Create a Jacchia 1971 model driven by the installed space weather
+    pub fn space_weather() -> Self {
+        Self { activity: None }
+    }
+
+    /// Activity used at `epoch`
+    pub fn activity_at(&self, epoch: &Epoch) -> SolarActivity {
+        self.activity.unwrap_or_else(|| SolarActivity::at(epoch))
+    }
+
+    /// Exospheric temperature T∞ (K) at an evaluated point
+    pub fn exospheric_temperature(&self, point: &AtmospherePoint) -> f64 {
+        let activity = self.activity_at(&point.epoch);
+
+        // Global nighttime minimum
+        let tc = 379.0 + 3.24 * activity.f107_avg + 1.3 * (activity.f107 - activity.f107_avg);
+
+        // Diurnal variation
+        let (lat, dec) = (point.latitude, point.sun_declination);
+        let eta = 0.5 * (lat - dec).abs();
+        let theta = 0.5 * (lat + dec).abs();
+        let hour_angle = point.sun_hour_angle();
+        let tau = (hour_angle - 37.0_f64.to_radians()
+            + 6.0_f64.to_radians() * (hour_angle + 43.0_f64.to_radians()).sin()
+            + PI)
+            .rem_euclid(TAU)
+            - PI;
+        let (r, m, n) = (0.3, 2.2, 3.0);
+        let sin_theta = theta.sin().powf(m);
+        let tl = tc
+            * (1.0
+                + r * sin_theta
+                + r * (eta.cos().powf(m) - sin_theta) * (0.5 * tau).cos().powf(n));
+
+        // Geomagnetic heating (above 200 km; below, applied to the density)
+        if point.altitude / 1000.0 >= 200.0 {
+            let kp = activity.kp();
+            tl + 28.0 * kp + 0.03 * kp.exp()
+        } else {
+            tl
+        }
+    }
+
+    /// Density (kg/m³) at an evaluated point
+    pub fn density_at(&self, point: &AtmospherePoint) -> f64 {
+        let z = (point.altitude / 1000.0).max(J71_Z0);
+        if z > 2500.0 {
+            return 0.0;
+        }
+
+        let t_inf = self.exospheric_temperature(point);
+        let profile = TemperatureProfile::new(t_inf);
+
+        let log_density = if z <= J71_Z_MIXED {
+            // Mixed region: barometric equation with variable molecular mass
+            let integral = simpson(J71_Z0, z, |zeta| {
+                mean_molecular_mass(zeta) * gravity(zeta) / profile.temperature(zeta)
+            });
+            (J71_RHO0 * mean_molecular_mass(z) * J71_T0
+                / (mean_molecular_mass(J71_Z0) * profile.temperature(z)))
+            .log10()
+                - integral * 1e-3 / (GAS_CONSTANT * LN_10)
+        } else {
+            self.diffusive_density(point, z, &profile).log10()
+        };
+
+        10f64.powf(log_density + self.density_corrections(point, z))
+    }
+
+    /// Density above 105 km from diffusive equilibrium of each species
+    fn diffusive_density(
+        &self,
+        point: &AtmospherePoint,
+        z: f64,
+        profile: &TemperatureProfile,
+    ) -> f64 {
+        // Density and composition at the top of the mixed region
+        let rho_mixed = {
+            let integral = simpson(J71_Z0, J71_Z_MIXED, |zeta| {
+                mean_molecular_mass(zeta) * gravity(zeta) / profile.temperature(zeta)
+            });
+            J71_RHO0 * mean_molecular_mass(J71_Z_MIXED) * J71_T0
+                / (mean_molecular_mass(J71_Z0) * profile.temperature(J71_Z_MIXED))
+                * (-integral * 1e-3 / GAS_CONSTANT).exp()
+        };
+        let m_mixed = mean_molecular_mass(J71_Z_MIXED);
+        let moles = rho_mixed * 1e3 * AVOGADRO / J71_M0;
+        let dissociated = J71_M0 / m_mixed - 1.0;
+
+        let t_mixed = profile.temperature(J71_Z_MIXED);
+        let t_z = profile.temperature(z);
+        // ∫ g/(R T) dz per unit molecular mass (mol/g)
+        let integral = simpson(J71_Z_MIXED, z, |zeta| {
+            gravity(zeta) / profile.temperature(zeta)
+        }) * 1e-3
+            / GAS_CONSTANT;
+
+        let diffuse = |(mass, _, alpha): (f64, f64, f64), n_mixed: f64| {
+            n_mixed * (t_mixed / t_z).powf(1.0 + alpha) * (-mass * integral).exp()
+        };
+
+        let mut n_he = diffuse(J71_HE, moles * J71_HE.1);
+        // Seasonal variation of helium
+        let dec = point.sun_declination;
+        let obliquity = 23.44_f64.to_radians();
+        let phase = FRAC_PI_4 - 0.5 * point.latitude * dec.signum();
+        n_he *= 10f64.powf(0.65 * (dec / obliquity).abs() * (phase.sin().powi(3) - 0.35355));
+
+        let species = [
+            (J71_N2, moles * J71_N2.1),
+            (J71_O2, moles * (1.0 + J71_O2.1 - J71_M0 / m_mixed)),
+            (J71_O, moles * 2.0 * dissociated),
+            (J71_AR, moles * J71_AR.1),
+        ];
+        let mut mass_density: f64 = species
+            .iter()
+            .map(|&(s, n)| diffuse(s, n) * s.0)
+            .sum::<f64>()
+            + n_he * J71_HE.0;
+
+        if z > J71_Z_HYDROGEN {
+            let log_t = profile.t_inf.log10();
+            // cm⁻³ to m⁻³
+            let n_h500 = 10f64.powf(73.13 - 39.4 * log_t + 5.5 * log_t * log_t) * 1e6;
+            let integral = simpson(J71_Z_HYDROGEN, z, |zeta| {
+                gravity(zeta) / profile.temperature(zeta)
+            }) * 1e-3
+                / GAS_CONSTANT;
+            let n_h = n_h500
+                * (profile.temperature(J71_Z_HYDROGEN) / t_z).powf(1.0 + J71_HE.2)
+                * (-J71_H_MASS * integral).exp();
+            mass_density += n_h * J71_H_MASS;
+        }
+
+        // g/mol per molecule to kg
+        mass_density * 1e-3 / AVOGADRO
+    }
+
+    /// Geomagnetic (below 200 km), semi-annual and seasonal-latitudinal
+    /// corrections to log₁₀ ρ
+    fn density_corrections(&self, point: &AtmospherePoint, z: f64) -> f64 {
+        let mut correction = 0.0;
+
+        if z < 200.0 {
+            let kp = self.activity_at(&point.epoch).kp();
+            correction += 0.012 * kp + 1.2e-5 * kp.exp();
+        }
+
+        // Semi-annual variation
+        let phi = (point.epoch.to_mjd_utc() - 36204.0) / 365.2422;
+        let tau = phi + 0.09544 * ((0.5 + 0.5 * (TAU * phi + 6.035).sin()).powf(1.65) - 0.5);
+        let f = (5.876e-7 * z.powf(2.331) + 0.06328) * (-0.002868 * z).exp();
+        let g = 0.02835
+            + (0.3817 + 0.17829 * (TAU * tau + 4.137).sin()) * (2.0 * TAU * tau + 4.259).sin();
+        correction += f * g;
+
+        // Seasonal-latitudinal variation of the lower thermosphere
+        let sin_lat = point.latitude.sin();
+        correction += 0.014
+            * (z - J71_Z0)
+            * (-0.0013 * (z - J71_Z0).powi(2)).exp()
+            * (TAU * phi + 1.72).sin()
+            * sin_lat
+            * sin_lat.abs();
+
+        correction
+    }
+}
+
+impl AtmosphereModel for Jacchia71 {
+    fn density(&self, epoch: &Epoch, position: &Vector3) -> f64 {
+        self.density_at(&AtmospherePoint::new(epoch, position))
+    }
+
+    fn name(&self) -> &str {
+        "Jacchia 1971"
+    }
+}
+
+/// Jacchia (1971) empirical temperature profile
+struct TemperatureProfile {
+    t_inf: f64,
+    tx: f64,
+    gx: f64,
+}
+
+impl TemperatureProfile {
+    fn new(t_inf: f64) -> Self {
+        let tx = 371.6678 + 0.0518806 * t_inf - 294.3505 * (-0.00216222 * t_inf).exp();
+        let gx = 1.9 * (tx - J71_T0) / (J71_ZX - J71_Z0);
+        Self { t_inf, tx, gx }
+    }
+
+    /// Temperature (K) at height `z` (km)
+    fn temperature(&self, z: f64) -> f64 {
+        if z <= J71_ZX {
+            const C: [f64; 5] = [-89_284_375.0, 3_542_400.0, -52_687.5, 340.5, -0.8];
+            let poly = C.iter().rev().fold(0.0, |acc, &c| acc * z + c);
+            self.tx + (self.tx - J71_T0) / 35.0_f64.powi(4) * poly
+        } else {
+            let dz = z - J71_ZX;
+            let dt = self.t_inf - self.tx;
+            self.tx + 2.0 / PI * dt * ((self.gx / dt) * dz * (1.0 + 4.5e-6 * dz.powf(2.5))).atan()
+        }
+    }
+}
+
+/// Mean molecular mass (g/mol) of the mixed region, 90–105 km
+fn mean_molecular_mass(z: f64) -> f64 {
+    const A: [f64; 7] = [
+        28.15204, -0.085586, 1.284e-4, -1.0056e-5, -1.021e-5, 1.5044e-6, 9.9826e-8,
+    ];
+    let dz = z - 100.0;
+    A.iter().rev().fold(0.0, |acc, &a| acc * dz + a)
+}
+
+/// Gravitational acceleration (m/s²) at height `z` (km)
+fn gravity(z: f64) -> f64 {
+    G0 / (1.0 + z / J71_EARTH_RADIUS).powi(2)
+}
+
+/// Composite Simpson integral over km, in units of the integrand × m
+fn simpson(a: f64, b: f64, f: impl Fn(f64) -> f64) -> f64 {
+    if b <= a {
+        return 0.0;
+    }
+    let intervals = (((b - a) / 2.0).ceil() as usize).max(1) * 2;
+    let h = (b - a) / intervals as f64;
+    let sum: f64 = (1..intervals)
+        .map(|i| f(a + i as f64 * h) * if i % 2 == 1 { 4.0 } else { 2.0 })
+        .sum::<f64>()
+        + f(a)
+        + f(b);
+    sum * h / 3.0 * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn equinox() -> Epoch {
+        Epoch::from_gregorian_utc(2024, 3, 20, 12, 0, 0, 0)
+    }
+
+    /// Point at `height` km on the equator, `hour_angle` (rad) from the Sun
+    fn equatorial_point(height: f64, hour_angle: f64) -> AtmospherePoint {
+        let radius = R_EARTH + height * 1000.0;
+        AtmospherePoint {
+            epoch: equinox(),
+            position: Vector3::new(radius * hour_angle.cos(), radius * hour_angle.sin(), 0.0),
+            latitude: 0.0,
+            longitude: 0.0,
+            altitude: height * 1000.0,
+            local_solar_time: (12.0 + hour_angle * 12.0 / PI).rem_euclid(24.0),
+            sun_right_ascension: 0.0,
+            sun_declination: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_point_geometry() {
+        let epoch = equinox();
+        let sun = ephemeris_position(Body::Sun, Body::Earth, &epoch).unwrap();
+        let position = sun.normalize() * (R_EARTH + 400e3);
+        let point = AtmospherePoint::new(&epoch, &position);
+
+        assert_relative_eq!(point.altitude, 400e3, max_relative = 1e-3);
+        assert_relative_eq!(point.local_solar_time, 12.0, epsilon = 1e-9);
+        assert!(point.sun_declination.abs() < 0.01); // equinox
+        assert_relative_eq!(point.latitude, point.sun_declination, epsilon = 0.01);
+
+        let midnight = AtmospherePoint::new(&epoch, &(-position));
+        assert_relative_eq!(midnight.local_solar_time, 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_exponential_matches_free_function() {
+        let model = ExponentialAtmosphere::earth();
+        let r = Vector3::new(0.0, R_EARTH + 50e3, 0.0);
+        assert_eq!(
+            model.density(&equinox(), &r),
+            exponential_density(50e3, RHO0_EARTH, H0_EARTH)
+        );
+    }
+
+    #[test]
+    fn test_harris_priester_table_values() {
+        let model = HarrisPriester::new(2.0);
+        // Apex of the bulge is 30° east of the Sun
+        let apex = equatorial_point(400.0, HarrisPriester::BULGE_LAG);
+        let antapex = equatorial_point(400.0, HarrisPriester::BULGE_LAG + PI);
+        assert_relative_eq!(model.density_at(&apex), 7.492e-12, max_relative = 1e-12);
+        assert_relative_eq!(model.density_at(&antapex), 2.249e-12, max_relative = 1e-12);
+
+        // Exponential interpolation between nodes
+        let mid = model.density_at(&equatorial_point(410.0, HarrisPriester::BULGE_LAG));
+        assert_relative_eq!(
+            mid,
+            (7.492e-12_f64 * 5.684e-12).sqrt(),
+            max_relative = 1e-12
+        );
+
+        assert_eq!(model.density_at(&equatorial_point(1200.0, 0.0)), 0.0);
+        assert_eq!(model.density_at(&equatorial_point(80.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn test_jacchia_temperature_profile() {
+        let profile = TemperatureProfile::new(1000.0);
+        assert_relative_eq!(profile.temperature(90.0), J71_T0, epsilon = 1e-9);
+        assert_relative_eq!(profile.temperature(125.0), profile.tx, epsilon = 1e-9);
+        assert!(profile.temperature(400.0) < 1000.0);
+        assert_relative_eq!(profile.temperature(2000.0), 1000.0, max_relative = 0.01);
+        assert_relative_eq!(mean_molecular_mass(100.0), 28.15204);
+    }
+
+    #[test]
+    fn test_jacchia_density_profile() {
+        let model = Jacchia71::new(SolarActivity::default());
+
+        // Lower boundary density
+        let rho_90 = model.density_at(&equatorial_point(90.0, 0.0));
+        assert_relative_eq!(rho_90, J71_RHO0, max_relative = 0.2);
+
+        // Continuous between the mixed and diffusive regions
+        let below = model.density_at(&equatorial_point(104.999, 0.0));
+        let above = model.density_at(&equatorial_point(105.001, 0.0));
+        assert_relative_eq!(below, above, max_relative = 1e-3);
+
+        // Monotonically decreasing with height
+        let heights = [100.0, 150.0, 200.0, 300.0, 400.0, 600.0, 1000.0, 2000.0];
+        let densities: Vec<f64> = heights
+            .iter()
+            .map(|&h| model.density_at(&equatorial_point(h, 0.0)))
+            .collect();
+        assert!(densities.windows(2).all(|pair| pair[1] < pair[0]));
+
+        // Moderate activity at 400 km: a few 1e-12 kg/m³
+        let rho_400 = model.density_at(&equatorial_point(400.0, 0.0));
+        assert!(
+            rho_400 > 1e-12 && rho_400 < 1e-11,
+            "rho(400 km) = {}",
+            rho_400
+        );
+    }
+
+    #[test]
+    fn test_jacchia_solar_activity_and_bulge() {
+        let quiet = Jacchia71::new(SolarActivity::new(70.0, 70.0, 4.0));
+        let active = Jacchia71::new(SolarActivity::new(250.0, 250.0, 4.0));
+        let storm = Jacchia71::new(SolarActivity::new(70.0, 70.0, 200.0));
+        let point = equatorial_point(400.0, 0.0);
+
+        assert!(active.density_at(&point) > 5.0 * quiet.density_at(&point));
+        assert!(
+            storm.exospheric_temperature(&point) > quiet.exospheric_temperature(&point) + 100.0
+        );
+
+        // Diurnal bulge peaks in the afternoon, minimum before dawn
+        let model = Jacchia71::new(SolarActivity::default());
+        let afternoon = model.density_at(&equatorial_point(400.0, 30.0_f64.to_radians()));
+        let night = model.density_at(&equatorial_point(400.0, -150.0_f64.to_radians()));
+        assert!(afternoon > 1.5 * night);
+    }
+
+    #[test]
+    fn test_models_through_trait() {
+        let epoch = equinox();
+        let r = Vector3::new(R_EARTH + 500e3, 1000.0, 2000.0);
+        let models: Vec<Box<dyn AtmosphereModel>> = vec![
+            Box::new(ExponentialAtmosphere::earth()),
+            Box::new(HarrisPriester::default()),
+            Box::new(Jacchia71::default()),
+        ];
+        for model in &models {
+            let rho = model.density(&epoch, &r);
+            assert!(rho.is_finite() && rho >= 0.0, "{}: {}", model.name(), rho);
+        }
+        assert_eq!(models[2].name(), "Jacchia 1971");
+    }
+
+    #[test]
+    fn test_relative_velocity() {
+        let r = Vector3::new(7.0e6, 0.0, 0.0);
+        let v = Vector3::new(0.0, 7500.0, 0.0);
+        let v_rel = velocity_relative_to_atmosphere(&r, &v);
+        assert_relative_eq!(
+            v_rel.y,
+            7500.0 - EARTH_ROTATION_RATE * 7.0e6,
+            epsilon = 1e-9
+        );
+        assert_eq!(v_rel.x, 0.0);
+    }
+}
@@ -0,0 +1,1258 @@
+//! Spherical harmonic gravity field of arbitrary degree and order
+//!
+//! The gravitational potential of a non-spherical body is expanded in fully
+//! normalized spherical harmonics:
+//!
+//! ```text
+//! U = GM/r Σₙ Σₘ (R/r)ⁿ P̄ₙₘ(sin φ) (C̄ₙₘ cos mλ + S̄ₙₘ sin mλ)
+//! ```
+//!
+//! in the body-fixed frame (ITRS for the Earth). [`GravityField`] holds the
+//! coefficients, read from ICGEM `.gfc` files or NGA EGM coefficient files,
+//! and [`SphericalHarmonicGravity`] is the [`Perturbation`] that evaluates the
+//! non-central part of the field for a state in an inertial frame.
+//!
+//! # Algorithm
+//!
+//! Accelerations are computed with the Cunningham recursion for the solid
+//! harmonics Vₙₘ, Wₙₘ (Montenbruck & Gill, Section 3.2.4), carried out in
+//! fully normalized form so that the recursion stays in range for high
+//! degrees (EGM2008 to several hundred). The formulation is Cartesian and has
+//! no singularity at the poles.
+//!
+//! # File Formats
+//!
+//! - **ICGEM** (`.gfc`): header terminated by `end_of_head` giving
+//!   `earth_gravity_constant`, `radius`, `max_degree` and `norm`, followed by
+//!   `gfc n m C S [σC σS]` records. Only the static part is used: `gfct`
+//!   records are read as static coefficients and `trnd`/`acos`/`asin`
+//!   records are ignored.
+//! - **EGM** (EGM96/EGM2008 `n m C S [σC σS]` tables): no header, so GM and
+//!   the reference radius are supplied by the caller (EGM2008:
+//!   [`EGM2008_GM`], [`EGM2008_RADIUS`]).
+//!
+//! Fortran `D` exponents are accepted in both formats.
+//!
+//! # References
+//! - Montenbruck, O. & Gill, E., "Satellite Orbits" (2000), Section 3.2
+//! - Barthelmes, F. & Förste, C., "The ICGEM-format" (ICGEM, GFZ Potsdam)
+//! - Pavlis, N. K. et al. (2012), "The development and evaluation of the
+//!   Earth Gravitational Model 2008 (EGM2008)", J. Geophys. Res. 117, B04406
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use nalgebra::Matrix3;
+use numpy::{PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+use thiserror::Error;
+
+use super::perturbations::Perturbation;
+use crate::coordinates::earth_orientation::{EarthOrientation, TerrestrialRotation};
+use crate::coordinates::iau_rotation::{iau_rotation_model, IauRotationModel};
+use crate::core::error::{PoliastroError, PoliastroResult};
+use crate::core::linalg::Vector3;
+use crate::core::time::Epoch;
+use crate::ephemeris::Body;
+
+/// EGM2008 gravitational parameter (m³/s²)
+pub const EGM2008_GM: f64 = 3.986004415e14;
+
+/// EGM2008 reference radius (m)
+pub const EGM2008_RADIUS: f64 = 6_378_136.3;
+
+/// Spacing of the Earth rotation nodes interpolated by
+/// [`SphericalHarmonicGravity`] (s)
+///
+/// Linear interpolation of precession-nutation and polar motion over an
+/// hour is accurate to a few μas.
+const EARTH_ROTATION_NODE_SPACING: f64 = 3600.0;
+
+/// Errors from reading or using gravity field models
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum GravityFieldError {
+    #[error("Failed to read gravity field file: {0}")]
+    Io(String),
+
+    #[error("Failed to parse gravity field at line {line}: {reason}")]
+    Parse { line: usize, reason: String },
+
+    #[error("Gravity field header is missing '{0}'")]
+    MissingHeader(String),
+
+    #[error(
+        "Requested degree/order {degree}x{order} exceeds the field's maximum degree {max_degree}"
+    )]
+    DegreeTooHigh {
+        degree: usize,
+        order: usize,
+        max_degree: usize,
+    },
+}
+
+impl From<GravityFieldError> for PoliastroError {
+    fn from(err: GravityFieldError) -> Self {
+        match err {
+            GravityFieldError::DegreeTooHigh {
+                degree, max_degree, ..
+            } => PoliastroError::invalid_parameter(
+                "degree",
+                degree as f64,
+                format!("must not exceed the field's maximum degree {}", max_degree),
+            ),
+            other => PoliastroError::ComputationError {
+                message: other.to_string(),
+            },
+        }
+    }
+}
+
+/// Index of (n, m) in the triangular coefficient storage
+#[inline]
+fn index(n: usize, m: usize) -> usize {
+    n * (n + 1) / 2 + m
+}
+
+/// Storage of the solid harmonics V̄ₙₘ, W̄ₙₘ, kept between evaluations
+#[derive(Debug, Clone, Default)]
+struct SolidHarmonics {
+    v: Vec<f64>,
+    w: Vec<f64>,
+}
+
+/// Normalization factor N̄ₙₘ relating unnormalized and fully normalized
+/// coefficients, Cₙₘ = N̄ₙₘ C̄ₙₘ
+fn normalization(n: usize, m: usize) -> f64 {
+    let delta = if m == 0 { 1.0 } else { 2.0 };
+    // (n - m)! / (n + m)! as a running product to stay in range
+    let ratio: f64 = ((n - m + 1)..=(n + m)).map(|k| 1.0 / k as f64).product();
+    (delta * (2 * n + 1) as f64 * ratio).sqrt()
+}
+
+/// Parse a number that may use a Fortran `D` exponent
+fn parse_number(token: &str) -> Option<f64> {
+    token.replace(['D', 'd'], "E").parse().ok()
+}
+
+/// Spherical harmonic coefficients of a gravity field
+///
+/// Coefficients are fully normalized and stored up to `max_degree` in both
+/// degree and order. C̄₀₀ = 1 and the degree-1 terms are zero for fields
+/// referred to the center of mass.
+#[pyclass(module = "astrora._core")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GravityField {
+    name: String,
+    gm: f64,
+    radius: f64,
+    max_degree: usize,
+    c: Vec<f64>,
+    s: Vec<f64>,
+}
+
+#[pymethods]
+impl GravityField {
+    /// Read an ICGEM `.gfc` gravity field file
+    ///
+    /// # Arguments
+    /// - `path`: Path to the `.gfc` file
+    /// - `max_degree`: Optional truncation degree (default: the full field)
+    #[staticmethod]
+    #[pyo3(name = "from_gfc", signature = (path, max_degree=None))]
+    pub fn py_from_gfc(path: &str, max_degree: Option<usize>) -> PyResult<Self> {
+        Ok(Self::from_gfc_file(path, max_degree).map_err(PoliastroError::from)?)
+    }
+
+    /// Read an EGM coefficient file (`n m C S ...` records)
+    ///
+    /// # Arguments
+    /// - `path`: Path to the coefficient file
+    /// - `gm`: Gravitational parameter of the model (m³/s², default EGM2008)
+    /// - `radius`: Reference radius of the model (m, default EGM2008)
+    /// - `max_degree`: Optional truncation degree (default: the full field)
+    #[staticmethod]
+    #[pyo3(
+        name = "from_egm",
+        signature = (path, gm=EGM2008_GM, radius=EGM2008_RADIUS, max_degree=None)
+    )]
+    pub fn py_from_egm(
+        path: &str,
+        gm: f64,
+        radius: f64,
+        max_degree: Option<usize>,
+    ) -> PyResult<Self> {
+        Ok(Self::from_egm_file(path, gm, radius, max_degree).map_err(PoliastroError::from)?)
+    }
+
+    /// EGM2008 truncated to degree and order 4 (bundled)
+    #[staticmethod]
+    #[pyo3(name = "egm2008_degree4")]
+    pub fn py_egm2008_degree4() -> Self {
+        Self::egm2008_degree4()
+    }
+
+    /// Model name
+    #[getter]
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Gravitational parameter of the model (m³/s²)
+    #[getter]
+    pub fn get_gm(&self) -> f64 {
+        self.gm
+    }
+
+    /// Reference radius of the model (m)
+    #[getter]
+    pub fn get_radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Maximum degree (and order) of the stored coefficients
+    #[getter]
+    pub fn get_max_degree(&self) -> usize {
+        self.max_degree
+    }
+
+    /// Fully normalized coefficients (C̄ₙₘ, S̄ₙₘ); zero beyond the field
+    #[pyo3(name = "coefficients")]
+    pub fn py_coefficients(&self, n: usize, m: usize) -> (f64, f64) {
+        self.coefficients(n, m)
+    }
+
+    /// Non-central acceleration at a body-fixed position
+    ///
+    /// # Arguments
+    /// - `r`: Body-fixed position [x, y, z] in meters
+    /// - `degree`, `order`: Truncation of the expansion
+    ///
+    /// # Returns
+    /// Acceleration [ax, ay, az] in body-fixed axes (m/s²)
+    #[pyo3(name = "acceleration")]
+    pub fn py_acceleration<'py>(
+        &self,
+        py: Python<'py>,
+        r: PyReadonlyArray1<f64>,
+        degree: usize,
+        order: usize,
+    ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+        let r = r.as_slice()?;
+        if r.len() != 3 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Position vector must have exactly 3 components",
+            ));
+        }
+        self.check_truncation(degree, order)
+            .map_err(PoliastroError::from)?;
+
+        let acc = self.acceleration(&Vector3::new(r[0], r[1], r[2]), degree, order);
+        Ok(PyArray1::from_slice_bound(py, acc.as_slice()))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "GravityField(name='{}', max_degree={}, gm={:.10e}, radius={:.1})",
+            self.name, self.max_degree, self.gm, self.radius
+        )
+    }
+}
+
+impl GravityField {
+    /// Create a field with all coefficients zero except C̄₀₀ = 1
+    ///
+    /// # Arguments
+    /// - `name`: Model name
+    /// - `gm`: Gravitational parameter (m³/s²)
+    /// - `radius`: Reference radius (m)
+    /// - `max_degree`: Maximum degree and order
+    pub fn new(name: impl Into<String>, gm: f64, radius: f64, max_degree: usize) -> Self {
+        let size = index(max_degree, max_degree) + 1;
+        let mut c = vec![0.0; size];
+        c[0] = 1.0;
+        Self {
+            name: name.into(),
+            gm,
+            radius,
+            max_degree,
+            c,
+            s: vec![0.0; size],
+        }
+    }
+
+    /// EGM2008 truncated to degree and order 4 (tide-free)
+    ///
+    /// Bundled so that a realistic Earth field is available without data
+    /// files; load the full model with [`GravityField::from_egm_file`] or
+    /// [`GravityField::from_gfc_file`] for precise work.
+    #[allow(clippy::excessive_precision)] // EGM2008 values are quoted verbatim
+    pub fn egm2008_degree4() -> Self {
+        const COEFFICIENTS: [(usize, usize, f64, f64); 12] = [
+            (2, 0, -4.84165143790815e-4, 0.0),
+            (2, 1, -2.06615509074176e-10, 1.38441389137979e-9),
+            (2, 2, 2.43938357328313e-6, -1.40027370385934e-6),
+            (3, 0, 9.57161207093473e-7, 0.0),
+            (3, 1, 2.03046201047864e-6, 2.48200415856872e-7),
+            (3, 2, 9.04787894809528e-7, -6.19005475177618e-7),
+            (3, 3, 7.21321757121568e-7, 1.41434926192941e-6),
+            (4, 0, 5.39965866638991e-7, 0.0),
+            (4, 1, -5.36157389388867e-7, -4.73567346518086e-7),
+            (4, 2, 3.50501623962649e-7, 6.62480026275829e-7),
+            (4, 3, 9.90856766672321e-7, -2.00956723567452e-7),
+            (4, 4, -1.88519633023033e-7, 3.08803882149194e-7),
+        ];
+
+        let mut field = Self::new("EGM2008 (degree 4)", EGM2008_GM, EGM2008_RADIUS, 4);
+        for (n, m, c, s) in COEFFICIENTS {
+            field.set_coefficients(n, m, c, s);
+        }
+        field
+    }
+
+    /// Read an ICGEM `.gfc` file
+    ///
+    /// # Arguments
+    /// - `path`: Path to the file
+    /// - `max_degree`: Optional truncation degree; coefficients above it are
+    ///   skipped while reading
+    pub fn from_gfc_file(
+        path: impl AsRef<Path>,
+        max_degree: Option<usize>,
+    ) -> Result<Self, GravityFieldError> {
+        let contents = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| GravityFieldError::Io(format!("{}: {}", path.as_ref().display(), e)))?;
+        Self::from_gfc_str(&contents, max_degree)
+    }
+
+    /// Parse the contents of an ICGEM `.gfc` file
+    ///
+    /// See [`GravityField::from_gfc_file`].
+    pub fn from_gfc_str(
+        contents: &str,
+        max_degree: Option<usize>,
+    ) -> Result<Self, GravityFieldError> {
+        let mut lines = contents.lines().enumerate();
+
+        let mut name = String::from("unnamed");
+        let mut gm = None;
+        let mut radius = None;
+        let mut header_degree = None;
+        let mut normalized = true;
+        let mut end_of_head = false;
+
+        for (number, line) in lines.by_ref() {
+            let mut tokens = line.split_whitespace();
+            let Some(key) = tokens.next() else {
+                continue;
+            };
+            if key == "end_of_head" {
+                end_of_head = true;
+                break;
+            }
+            let Some(value) = tokens.next() else {
+                continue;
+            };
+            let parse_error = || GravityFieldError::Parse {
+                line: number + 1,
+                reason: format!("invalid value '{}' for '{}'", value, key),
+            };
+            match key {
+                "modelname" => name = value.to_string(),
+                "earth_gravity_constant" | "gravity_constant" => {
+                    gm = Some(parse_number(value).ok_or_else(parse_error)?)
+                }
+                "radius" => radius = Some(parse_number(value).ok_or_else(parse_error)?),
+                "max_degree" => header_degree = Some(value.parse().map_err(|_| parse_error())?),
+                "norm" => normalized = value != "unnormalized",
+                _ => {}
+            }
+        }
+
+        if !end_of_head {
+            return Err(GravityFieldError::MissingHeader("end_of_head".to_string()));
+        }
+        let gm = gm.ok_or_else(|| {
+            GravityFieldError::MissingHeader("earth_gravity_constant".to_string())
+        })?;
+        let radius =
+            radius.ok_or_else(|| GravityFieldError::MissingHeader("radius".to_string()))?;
+        let header_degree: usize = header_degree
+            .ok_or_else(|| GravityFieldError::MissingHeader("max_degree".to_string()))?;
+        let degree = max_degree.map_or(header_degree, |d| d.min(header_degree));
+
+        let mut field = Self::new(name, gm, radius, degree);
+        for (number, line) in lines {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            match tokens.first() {
+                Some(&"gfc") | Some(&"gfct") => {}
+                _ => continue,
+            }
+            let (n, m, c, s) = parse_record(&tokens[1..], number + 1)?;
+            if n > degree {
+                continue;
+            }
+            if normalized {
+                field.set_coefficients(n, m, c, s);
+            } else {
+                let factor = normalization(n, m);
+                field.set_coefficients(n, m, c / factor, s / factor);
+            }
+        }
+
+        Ok(field)
+    }
+
+    /// Read an EGM coefficient file (fully normalized `n m C S ...` records)
+    ///
+    /// # Arguments
+    /// - `path`: Path to the file
+    /// - `gm`: Gravitational parameter of the model (m³/s²)
+    /// - `radius`: Reference radius of the model (m)
+    /// - `max_degree`: Optional truncation degree (default: highest degree in
+    ///   the file)
+    pub fn from_egm_file(
+        path: impl AsRef<Path>,
+        gm: f64,
+        radius: f64,
+        max_degree: Option<usize>,
+    ) -> Result<Self, GravityFieldError> {
+        let contents = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| GravityFieldError::Io(format!("{}: {}", path.as_ref().display(), e)))?;
+        Self::from_egm_str(&contents, gm, radius, max_degree)
+    }
+
+    /// Parse the contents of an EGM coefficient file
+    ///
+    /// See [`GravityField::from_egm_file`].
+    pub fn from_egm_str(
+        contents: &str,
+        gm: f64,
+        radius: f64,
+        max_degree: Option<usize>,
+    ) -> Result<Self, GravityFieldError> {
+        let mut records = Vec::new();
+        for (number, line) in contents.lines().enumerate() {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.is_empty() {
+                continue;
+            }
+            let record = parse_record(&tokens, number + 1)?;
+            if max_degree.map_or(true, |d| record.0 <= d) {
+                records.push(record);
+            }
+        }
+
+        let file_degree = records.iter().map(|&(n, ..)| n).max().unwrap_or(0);
+        let degree = max_degree.map_or(file_degree, |d| d.min(file_degree));
+
+        let mut field = Self::new("EGM", gm, radius, degree);
+        for (n, m, c, s) in records {
+            field.set_coefficients(n, m, c, s);
+        }
+        Ok(field)
+    }
+
+    /// Model name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gravitational parameter of the model (m³/s²)
+    pub fn gm(&self) -> f64 {
+        self.gm
+    }
+
+    /// Reference radius of the model (m)
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Maximum degree (and order) of the stored coefficients
+    pub fn max_degree(&self) -> usize {
+        self.max_degree
+    }
+
+    /// Fully normalized coefficients (C̄ₙₘ, S̄ₙₘ); zero beyond the field
+    pub fn coefficients(&self, n: usize, m: usize) -> (f64, f64) {
+        if n > self.max_degree || m > n {
+            return (0.0, 0.0);
+        }
+        let i = index(n, m);
+        (self.c[i], self.s[i])
+    }
+
+    /// Set the fully normalized coefficients (C̄ₙₘ, S̄ₙₘ)
+    ///
+    /// # Panics
+    /// Panics if `n > max_degree` or `m > n`.
+    pub fn set_coefficients(&mut self, n: usize, m: usize, c: f64, s: f64) {
+        assert!(
+            n <= self.max_degree && m <= n,
+            "coefficient ({}, {}) outside a degree {} field",
+            n,
+            m,
+            self.max_degree
+        );
+        let i = index(n, m);
+        self.c[i] = c;
+        self.s[i] = s;
+    }
+
+    /// Unnormalized zonal coefficient Jₙ = −Cₙ₀
+    pub fn zonal(&self, n: usize) -> f64 {
+        -self.coefficients(n, 0).0 * normalization(n, 0)
+    }
+
+    /// Copy of the field truncated to `degree`
+    pub fn truncated(&self, degree: usize) -> Result<Self, GravityFieldError> {
+        self.check_truncation(degree, degree)?;
+        let mut field = Self::new(self.name.clone(), self.gm, self.radius, degree);
+        let size = index(degree, degree) + 1;
+        field.c.copy_from_slice(&self.c[..size]);
+        field.s.copy_from_slice(&self.s[..size]);
+        Ok(field)
+    }
+
+    /// Check that a degree × order truncation is available
+    pub fn check_truncation(&self, degree: usize, order: usize) -> Result<(), GravityFieldError> {
+        if degree > self.max_degree || order > degree {
+            return Err(GravityFieldError::DegreeTooHigh {
+                degree,
+                order,
+                max_degree: self.max_degree,
+            });
+        }
+        Ok(())
+    }
+
+    /// Fully normalized solid harmonics V̄ₙₘ, W̄ₙₘ up to `n_max` × `m_max`
+    ///
+    /// V̄ₙₘ + i W̄ₙₘ = (R/r)ⁿ⁺¹ P̄ₙₘ(sin φ) e^{imλ}, computed with the
+    /// normalized Cunningham recursion into `buffers`, which grow as needed.
+    /// Only the entries with m ≤ `m_max` are written.
+    fn solid_harmonics<'a>(
+        &self,
+        r: &Vector3,
+        n_max: usize,
+        m_max: usize,
+        buffers: &'a mut SolidHarmonics,
+    ) -> (&'a [f64], &'a [f64]) {
+        let size = index(n_max, n_max) + 1;
+        if buffers.v.len() < size {
+            buffers.v.resize(size, 0.0);
+            buffers.w.resize(size, 0.0);
+        }
+        let (v, w) = (&mut buffers.v[..size], &mut buffers.w[..size]);
+
+        let r2 = r.norm_squared();
+        let scale = self.radius / r2;
+        let (x0, y0, z0) = (r.x * scale, r.y * scale, r.z * scale);
+        let rho = self.radius * scale;
+
+        v[0] = self.radius / r2.sqrt();
+        w[0] = 0.0;
+
+        for m in 0..=m_max.min(n_max) {
+            if m > 0 {
+                // Sectoral terms
+                let factor = if m == 1 {
+                    3.0_f64.sqrt()
+                } else {
+                    ((2 * m + 1) as f64 / (2 * m) as f64).sqrt()
+                };
+                let (vp, wp) = (v[index(m - 1, m - 1)], w[index(m - 1, m - 1)]);
+                v[index(m, m)] = factor * (x0 * vp - y0 * wp);
+                w[index(m, m)] = factor * (x0 * wp + y0 * vp);
+            }
+
+            for n in (m + 1)..=n_max {
+                let (nf, mf) = (n as f64, m as f64);
+                let a = ((2.0 * nf + 1.0) * (2.0 * nf - 1.0) / ((nf - mf) * (nf + mf))).sqrt();
+                let mut vn = a * z0 * v[index(n - 1, m)];
+                let mut wn = a * z0 * w[index(n - 1, m)];
+                if n >= m + 2 {
+                    let b = ((2.0 * nf + 1.0) * (nf + mf - 1.0) * (nf - mf - 1.0)
+                        / ((2.0 * nf - 3.0) * (nf + mf) * (nf - mf)))
+                        .sqrt();
+                    vn -= b * rho * v[index(n - 2, m)];
+                    wn -= b * rho * w[index(n - 2, m)];
+                }
+                v[index(n, m)] = vn;
+                w[index(n, m)] = wn;
+            }
+        }
+
+        (v, w)
+    }
+
+    /// Non-central potential (degrees 1 to `degree`) at a body-fixed position
+    /// (m²/s²)
+    ///
+    /// Coefficients beyond the field are treated as zero.
+    pub fn potential(&self, r: &Vector3, degree: usize, order: usize) -> f64 {
+        let degree = degree.min(self.max_degree);
+        let order = order.min(degree);
+        let mut buffers = SolidHarmonics::default();
+        let (v, w) = self.solid_harmonics(r, degree, order, &mut buffers);
+
+        let mut sum = 0.0;
+        for n in 1..=degree {
+            for m in 0..=n.min(order) {
+                let i = index(n, m);
+                sum += self.c[i] * v[i] + self.s[i] * w[i];
+            }
+        }
+        self.gm / self.radius * sum
+    }
+
+    /// Non-central acceleration (degrees 1 to `degree`) at a body-fixed
+    /// position, in body-fixed axes (m/s²)
+    ///
+    /// This is the gradient of [`GravityField::potential`]; the central term
+    /// GM/r² is left to the two-body dynamics. Coefficients beyond the field
+    /// are treated as zero.
+    pub fn acceleration(&self, r: &Vector3, degree: usize, order: usize) -> Vector3 {
+        self.acceleration_with(r, degree, order, &mut SolidHarmonics::default())
+    }
+
+    /// [`GravityField::acceleration`] with the solid harmonics computed in
+    /// reusable buffers
+    fn acceleration_with(
+        &self,
+        r: &Vector3,
+        degree: usize,
+        order: usize,
+        buffers: &mut SolidHarmonics,
+    ) -> Vector3 {
+        let degree = degree.min(self.max_degree);
+        let order = order.min(degree);
+        let (v, w) = self.solid_harmonics(r, degree + 1, order + 1, buffers);
+
+        let (mut ax, mut ay, mut az) = (0.0, 0.0, 0.0);
+        for n in 1..=degree {
+            let nf = n as f64;
+            let ratio = (2.0 * nf + 1.0) / (2.0 * nf + 3.0);
+
+            for m in 0..=n.min(order) {
+                let mf = m as f64;
+                let i = index(n, m);
+                let (c, s) = (self.c[i], self.s[i]);
+                if c == 0.0 && s == 0.0 {
+                    continue;
+                }
+
+                let up = index(n + 1, m + 1);
+                if m == 0 {
+                    let f = (0.5 * ratio * (nf + 1.0) * (nf + 2.0)).sqrt();
+                    ax -= f * c * v[up];
+                    ay -= f * c * w[up];
+                } else {
+                    let down = index(n + 1, m - 1);
+                    let f_up = (ratio * (nf + mf + 1.0) * (nf + mf + 2.0)).sqrt();
+                    let delta = if m == 1 { 2.0 } else { 1.0 };
+                    let f_down = (delta * ratio * (nf - mf + 1.0) * (nf - mf + 2.0)).sqrt();
+
+                    ax += 0.5
+                        * (f_up * (-c * v[up] - s * w[up]) + f_down * (c * v[down] + s * w[down]));
+                    ay += 0.5
+                        * (f_up * (-c * w[up] + s * v[up]) + f_down * (-c * w[down] + s * v[down]));
+                }
+
+                let same = index(n + 1, m);
+                let f_z = (ratio * (nf + mf + 1.0) * (nf - mf + 1.0)).sqrt();
+                az += f_z * (-c * v[same] - s * w[same]);
+            }
+        }
+
+        Vector3::new(ax, ay, az) * (self.gm / (self.radius * self.radius))
+    }
+}
+
+/// Parse `n m C S` from the leading tokens of a coefficient record
+fn parse_record(
+    tokens: &[&str],
+    line: usize,
+) -> Result<(usize, usize, f64, f64), GravityFieldError> {
+    let error = |reason: &str| GravityFieldError::Parse {
+        line,
+        reason: reason.to_string(),
+    };
+    if tokens.len() < 4 {
+        return Err(error("expected at least 'n m C S'"));
+    }
+    let n: usize = tokens[0].parse().map_err(|_| error("invalid degree"))?;
+    let m: usize = tokens[1].parse().map_err(|_| error("invalid order"))?;
+    if m > n {
+        return Err(error("order exceeds degree"));
+    }
+    let c = parse_number(tokens[2]).ok_or_else(|| error("invalid C coefficient"))?;
+    let s = parse_number(tokens[3]).ok_or_else(|| error("invalid S coefficient"))?;
+    Ok((n, m, c, s))
+}
+
+/// Body-fixed frame in which a gravity field is evaluated
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GravityFieldFrame {
+    /// Positions are already body-fixed; no rotation is applied
+    BodyFixed,
+    /// Earth field: inertial positions are GCRS and are rotated to the ITRS
+    /// with the IERS 2010 chain and the installed Earth Orientation
+    /// Parameters
+    Earth,
+    /// Other bodies: inertial positions have ICRF axes and are rotated with
+    /// the body's IAU rotation model
+    Iau(Body),
+}
+
+/// Rotation to the body-fixed frame, resolved from a [`GravityFieldFrame`]
+#[derive(Debug, Clone, Copy)]
+enum BodyFixedRotation {
+    Identity,
+    Earth,
+    Iau(&'static IauRotationModel),
+}
+
+/// GCRS → ITRS transformations at the two Earth rotation nodes around the
+/// last evaluation
+#[derive(Debug, Clone, Copy)]
+struct EarthRotationNodes {
+    /// Time of the first node (TDB seconds since J2000), a multiple of
+    /// [`EARTH_ROTATION_NODE_SPACING`]
+    t0: f64,
+    start: TerrestrialRotation,
+    end: TerrestrialRotation,
+}
+
+impl EarthRotationNodes {
+    /// Nodes of the interval containing `t`
+    fn around(t: f64) -> Self {
+        let t0 = (t / EARTH_ROTATION_NODE_SPACING).floor() * EARTH_ROTATION_NODE_SPACING;
+        let node = |t: f64| {
+            let epoch = Epoch::from_tdb_seconds_since_j2000(t);
+            TerrestrialRotation::new(&epoch, &EarthOrientation::at_or_fallback(&epoch))
+        };
+        Self {
+            t0,
+            start: node(t0),
+            end: node(t0 + EARTH_ROTATION_NODE_SPACING),
+        }
+    }
+
+    fn contains(&self, t: f64) -> bool {
+        (self.t0..=self.t0 + EARTH_ROTATION_NODE_SPACING).contains(&t)
+    }
+
+    /// GCRS → ITRS rotation at `t`, interpolated linearly between the nodes
+    ///
+    /// The Earth Rotation Angle is linear in UT1 and so interpolates exactly
+    /// up to the slow drift of UT1 − TDB; the matrix elements of the
+    /// precession-nutation and polar motion change by less than 10⁻⁷ over
+    /// the interval, so that interpolating them keeps the result orthogonal
+    /// to ~10⁻¹⁵.
+    fn rotation(&self, t: f64) -> Matrix3<f64> {
+        let fraction = (t - self.t0) / EARTH_ROTATION_NODE_SPACING;
+        let lerp = |a: &Matrix3<f64>, b: &Matrix3<f64>| a + (b - a) * fraction;
+        let era_step = (self.end.era - self.start.era).rem_euclid(2.0 * std::f64::consts::PI);
+        TerrestrialRotation {
+            c2i: lerp(&self.start.c2i, &self.end.c2i),
+            era: self.start.era + era_step * fraction,
+            polar_motion: lerp(&self.start.polar_motion, &self.end.polar_motion),
+        }
+        .matrix()
+    }
+}
+
+/// Per-instance state reused between force evaluations
+#[derive(Debug, Default)]
+struct EvaluationCache {
+    earth_rotation: Option<EarthRotationNodes>,
+    harmonics: SolidHarmonics,
+}
+
+/// Non-spherical gravity perturbation of degree × order N × M
+///
+/// Rotates the inertial position into the body-fixed frame at time `t`
+/// (TDB seconds since J2000), evaluates [`GravityField::acceleration`] and
+/// rotates the result back. The field's own GM is used rather than the `mu`
+/// passed by the propagator.
+///
+/// With [`GravityFieldFrame::Earth`], epochs outside an installed EOP table
+/// use the bundled fallback EOP model instead of failing. The GCRS → ITRS
+/// rotation is computed with the full IERS chain at hourly nodes and
+/// interpolated between them, so that an integrator's force evaluations
+/// within the hour need no precession-nutation series or EOP lookup; the
+/// solid harmonics are computed in buffers kept between evaluations. An EOP
+/// table or nutation series installed later takes effect from the next
+/// node interval.
+///
+/// # Example
+/// ```ignore
+/// use std::sync::Arc;
+/// use astrora::propagators::gravity_field::{GravityField, SphericalHarmonicGravity};
+///
+/// let field = Arc::new(GravityField::from_gfc_file("EGM2008.gfc", Some(70))?);
+/// let gravity = SphericalHarmonicGravity::earth(field, 70, 70)?;
+/// ```
+#[derive(Debug)]
+pub struct SphericalHarmonicGravity {
+    field: Arc<GravityField>,
+    degree: usize,
+    order: usize,
+    frame: GravityFieldFrame,
+    rotation: BodyFixedRotation,
+    cache: Mutex<EvaluationCache>,
+}
+
+impl Clone for SphericalHarmonicGravity {
+    fn clone(&self) -> Self {
+        Self {
+            field: self.field.clone(),
+            degree: self.degree,
+            order: self.order,
+            frame: self.frame,
+            rotation: self.rotation,
+            cache: Mutex::new(EvaluationCache::default()),
+        }
+    }
+}
+
+impl SphericalHarmonicGravity {
+    /// Create a gravity perturbation
+    ///
+    /// # Arguments
+    /// * `field` - Gravity field coefficients
+    /// * `degree`, `order` - Truncation of the expansion
+    /// * `frame` - Body-fixed frame of the field
+    ///
+    /// # Errors
+    /// `InvalidParameter` if the truncation exceeds the field, or
+    /// `UnknownFrame` for a [`GravityFieldFrame::Iau`] body without an IAU
+    /// rotation model
+    pub fn new(
+        field: Arc<GravityField>,
+        degree: usize,
+        order: usize,
+        frame: GravityFieldFrame,
+    ) -> PoliastroResult<Self> {
+        field.check_truncation(degree, order)?;
+        let rotation = match frame {
+            GravityFieldFrame::BodyFixed => BodyFixedRotation::Identity,
+            GravityFieldFrame::Earth => BodyFixedRotation::Earth,
+            GravityFieldFrame::Iau(body) => BodyFixedRotation::Iau(
+                iau_rotation_model(body)
+                    .ok_or_else(|| PoliastroError::unknown_frame(format!("IAU_{}", body)))?,
+            ),
+        };
+        Ok(Self {
+            field,
+            degree,
+            order,
+            frame,
+            rotation,
+            cache: Mutex::new(EvaluationCache::default()),
+        })
+    }
+
+    /// Earth gravity perturbation for GCRS states
+    ///
+    /// # Errors
+    /// `InvalidParameter` if the truncation exceeds the field
+    pub fn earth(field: Arc<GravityField>, degree: usize, order: usize) -> PoliastroResult<Self> {
+        Self::new(field, degree, order, GravityFieldFrame::Earth)
+    }
+
+    /// Gravity field coefficients
+    pub fn field(&self) -> &GravityField {
+        &self.field
+    }
+
+    /// Truncation degree
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Truncation order
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Body-fixed frame of the field
+    pub fn frame(&self) -> GravityFieldFrame {
+        self.frame
+    }
+
+    /// Rotation from inertial to body-fixed axes at `t` (TDB seconds since J2000)
+    ///
+    /// For the Earth this is the rotation interpolated between the hourly
+    /// nodes, as used by the force evaluations.
+    pub fn body_fixed_rotation(&self, t: f64) -> Matrix3<f64> {
+        match self.cache.try_lock() {
+            Ok(mut cache) => self.rotation_with(t, &mut cache),
+            // Shared with another thread: evaluate without the cache
+            Err(_) => self.rotation_with(t, &mut EvaluationCache::default()),
+        }
+    }
+
+    fn rotation_with(&self, t: f64, cache: &mut EvaluationCache) -> Matrix3<f64> {
+        match self.rotation {
+            BodyFixedRotation::Identity => Matrix3::identity(),
+            BodyFixedRotation::Earth => match cache.earth_rotation {
+                Some(nodes) if nodes.contains(t) => nodes.rotation(t),
+                _ => {
+                    let nodes = EarthRotationNodes::around(t);
+                    cache.earth_rotation = Some(nodes);
+                    nodes.rotation(t)
+                }
+            },
+            BodyFixedRotation::Iau(model) => {
+                model.icrf_to_body_fixed(&Epoch::from_tdb_seconds_since_j2000(t))
+            }
+        }
+    }
+
+    fn acceleration_with(&self, t: f64, r: &Vector3, cache: &mut EvaluationCache) -> Vector3 {
+        let rotation = self.rotation_with(t, cache);
+        let acc = self.field.acceleration_with(
+            &(rotation * r),
+            self.degree,
+            self.order,
+            &mut cache.harmonics,
+        );
+        rotation.transpose() * acc
+    }
+}
+
+impl Perturbation for SphericalHarmonicGravity {
+    fn acceleration(&self, t: f64, r: &Vector3, _v: &Vector3, _mu: f64) -> Vector3 {
+        match self.cache.try_lock() {
+            Ok(mut cache) => self.acceleration_with(t, r, &mut cache),
+            Err(_) => self.acceleration_with(t, r, &mut EvaluationCache::default()),
+        }
+    }
+
+    fn name(&self) -> &str {
+        "Spherical Harmonic Gravity"
+    }
+
+    fn is_time_dependent(&self) -> bool {
+        self.frame != GravityFieldFrame::BodyFixed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::{GM_EARTH, J2_EARTH, R_EARTH};
+    use crate::propagators::perturbations::j2_perturbation;
+    use approx::assert_relative_eq;
+
+    /// Central-difference gradient of the potential
+    fn numerical_gradient(field: &GravityField, r: &Vector3, degree: usize) -> Vector3 {
+        let h = 1.0;
+        let mut gradient = Vector3::zeros();
+        for k in 0..3 {
+            let mut dr = Vector3::zeros();
+            dr[k] = h;
+            gradient[k] = (field.potential(&(r + dr), degree, degree)
+                - field.potential(&(r - dr), degree, degree))
+                / (2.0 * h);
+        }
+        gradient
+    }
+
+    /// Field with pseudo-random coefficients following Kaula's rule
+    fn kaula_field(degree: usize) -> GravityField {
+        let mut field = GravityField::new("kaula", GM_EARTH, R_EARTH, degree);
+        for n in 2..=degree {
+            let sigma = 1e-5 / (n * n) as f64;
+            for m in 0..=n {
+                let c = sigma * ((n * 31 + m * 17) as f64).sin();
+                let s = if m == 0 {
+                    0.0
+                } else {
+                    sigma * ((n * 13 + m * 7) as f64).cos()
+                };
+                field.set_coefficients(n, m, c, s);
+            }
+        }
+        field
+    }
+
+    #[test]
+    fn test_normalization_factors() {
+        assert_relative_eq!(normalization(2, 0), 5.0_f64.sqrt(), epsilon = 1e-15);
+        assert_relative_eq!(
+            normalization(2, 2),
+            (5.0_f64 / 12.0).sqrt(),
+            epsilon = 1e-15
+        );
+        assert_relative_eq!(normalization(3, 1), (7.0_f64 / 6.0).sqrt(), epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_c20_matches_j2_perturbation() {
+        let mut field = GravityField::new("J2", GM_EARTH, R_EARTH, 2);
+        field.set_coefficients(2, 0, -J2_EARTH / 5.0_f64.sqrt(), 0.0);
+        assert_relative_eq!(field.zonal(2), J2_EARTH, epsilon = 1e-18);
+
+        for r in [
+            Vector3::new(7.0e6, 0.0, 0.0),
+            Vector3::new(4.0e6, -3.0e6, 5.0e6),
+            Vector3::new(0.0, 0.0, 7.2e6),
+        ] {
+            let expected = j2_perturbation(&r, GM_EARTH, J2_EARTH, R_EARTH);
+            let acc = field.acceleration(&r, 2, 0);
+            assert_relative_eq!(acc, expected, max_relative = 1e-12, epsilon = 1e-20);
+        }
+    }
+
+    #[test]
+    fn test_acceleration_is_gradient_of_potential() {
+        let field = GravityField::egm2008_degree4();
+        let r = Vector3::new(-2.1e6, 5.3e6, 3.9e6);
+
+        let acc = field.acceleration(&r, 4, 4);
+        let numerical = numerical_gradient(&field, &r, 4);
+        assert_relative_eq!(acc, numerical, max_relative = 1e-6);
+
+        // Tesseral terms matter: the full field differs from its zonal part
+        let zonal = field.acceleration(&r, 4, 0);
+        assert!((acc - zonal).norm() > 1e-6);
+    }
+
+    #[test]
+    fn test_high_degree_is_stable_near_pole() {
+        let field = kaula_field(120);
+        let r = Vector3::new(1.0, -2.0, 6.9e6);
+
+        let acc = field.acceleration(&r, 120, 120);
+        assert!(acc.iter().all(|a| a.is_finite()));
+        assert_relative_eq!(
+            acc,
+            numerical_gradient(&field, &r, 120),
+            max_relative = 1e-5
+        );
+
+        let r = Vector3::new(3.1e6, 4.2e6, -4.0e6);
+        assert_relative_eq!(
+            field.acceleration(&r, 120, 120),
+            numerical_gradient(&field, &r, 120),
+            max_relative = 1e-5
+        );
+    }
+
+    #[test]
+    fn test_parse_gfc() {
+        let contents = "\
+generating_institute  test
+product_type          gravity_field
+modelname             TEST_GFC
+earth_gravity_constant 0.3986004415D+15
+radius                0.63781363E+07
+max_degree            3
+norm                  fully_normalized
+errors                formal
+
+key    L    M    C                      S                    sigma C  sigma S
+end_of_head ====================================================================
+gfc    0    0    1.0D+00                0.0D+00              0.0      0.0
+gfc    2    0   -0.484165143790815D-03  0.0D+00              1.0E-12  0.0
+gfc    2    2    0.243938357328313D-05 -0.140027370385934D-05 1.0E-12 1.0E-12
+gfct   3    1    0.203046201047864D-05  0.248200415856872D-06 0 0 19500101.0000
+trnd   3    1    1.0D-11                1.0D-11
+gfc    3    3    0.721321757121568D-06  0.141434926192941D-05 0 0
+";
+        let field = GravityField::from_gfc_str(contents, None).unwrap();
+        assert_eq!(field.name(), "TEST_GFC");
+        assert_eq!(field.max_degree(), 3);
+        assert_eq!(field.gm(), EGM2008_GM);
+        assert_eq!(field.radius(), EGM2008_RADIUS);
+        assert_eq!(
+            field.coefficients(2, 2),
+            (2.43938357328313e-6, -1.40027370385934e-6)
+        );
+        assert_eq!(
+            field.coefficients(3, 1),
+            (2.03046201047864e-6, 2.48200415856872e-7)
+        );
+
+        let truncated = GravityField::from_gfc_str(contents, Some(2)).unwrap();
+        assert_eq!(truncated.max_degree(), 2);
+        assert_eq!(truncated.coefficients(3, 3), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_parse_unnormalized_gfc() {
+        let contents = "\
+earth_gravity_constant 3.986004415e14
+radius 6378136.3
+max_degree 2
+norm unnormalized
+end_of_head
+gfc 2 0 -1.0826e-3 0.0
+";
+        let field = GravityField::from_gfc_str(contents, None).unwrap();
+        assert_relative_eq!(field.zonal(2), 1.0826e-3, epsilon = 1e-18);
+    }
+
+    #[test]
+    fn test_gfc_errors() {
+        assert_eq!(
+            GravityField::from_gfc_str("radius 1.0\ngfc 2 0 1.0 0.0\n", None),
+            Err(GravityFieldError::MissingHeader("end_of_head".to_string()))
+        );
+        assert!(matches!(
+            GravityField::from_gfc_str("radius 1.0\nmax_degree 2\nend_of_head\n", None),
+            Err(GravityFieldError::MissingHeader(key)) if key == "earth_gravity_constant"
+        ));
+        assert!(matches!(
+            GravityField::from_gfc_str(
+                "earth_gravity_constant 1.0\nradius 1.0\nmax_degree 2\nend_of_head\ngfc 2 x 1.0 0.0\n",
+                None
+            ),
+            Err(GravityFieldError::Parse { line: 5, .. })
+        ));
+        assert!(matches!(
+            GravityField::from_gfc_file("/nonexistent/field.gfc", None),
+            Err(GravityFieldError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_egm() {
+        let contents = "\
+    2    0   -0.484165143790815D-03    0.000000000000000D+00    0.7481239490D-11    0.0000000000D+00
+    2    1   -0.206615509074176D-09    0.138441389137979D-08    0.7063781502D-11    0.7348347201D-11
+    2    2    0.243938357328313D-05   -0.140027370385934D-05    0.7230231722D-11    0.7425816951D-11
+    3    0    0.957161207093473D-06    0.000000000000000D+00    0.5731430751D-11    0.0000000000D+00
+";
+        let field = GravityField::from_egm_str(contents, EGM2008_GM, EGM2008_RADIUS, None).unwrap();
+        assert_eq!(field.max_degree(), 3);
+        assert_eq!(field.coefficients(0, 0), (1.0, 0.0));
+        assert_eq!(
+            field.coefficients(2, 1),
+            (-2.06615509074176e-10, 1.38441389137979e-9)
+        );
+
+        let truncated =
+            GravityField::from_egm_str(contents, EGM2008_GM, EGM2008_RADIUS, Some(2)).unwrap();
+        assert_eq!(truncated.max_degree(), 2);
+        assert_eq!(truncated, field.truncated(2).unwrap());
+    }
+
+    #[test]
+    fn test_truncation_limits() {
+        let field = Arc::new(GravityField::egm2008_degree4());
+        assert!(matches!(
+            field.check_truncation(8, 8),
+            Err(GravityFieldError::DegreeTooHigh { max_degree: 4, .. })
+        ));
+        assert!(matches!(
+            SphericalHarmonicGravity::earth(field.clone(), 8, 8),
+            Err(PoliastroError::InvalidParameter { .. })
+        ));
+        assert!(SphericalHarmonicGravity::earth(field.clone(), 4, 5).is_err());
+        assert!(SphericalHarmonicGravity::earth(field, 4, 4).is_ok());
+
+        let err: PoliastroError = GravityFieldError::DegreeTooHigh {
+            degree: 8,
+            order: 8,
+            max_degree: 4,
+        }
+        .into();
+        assert!(matches!(err, PoliastroError::InvalidParameter { .. }));
+    }
+
+    #[test]
+    fn test_perturbation_rotates_into_itrs() {
+        let field = Arc::new(GravityField::egm2008_degree4());
+        let gravity = SphericalHarmonicGravity::earth(field.clone(), 4, 4).unwrap();
+        assert!(gravity.is_time_dependent());
+
+        let t = Epoch::from_gregorian_utc(2025, 6, 1, 0, 0, 0, 0).to_tdb_seconds_since_j2000();
+        let r = Vector3::new(6.8e6, 1.0e6, 0.5e6);
+        let acc = gravity.acceleration(t, &r, &Vector3::zeros(), GM_EARTH);
+
+        let rotation = gravity.body_fixed_rotation(t);
+        let expected = rotation.transpose() * field.acceleration(&(rotation * r), 4, 4);
+        assert_relative_eq!(acc, expected, epsilon = 1e-18);
+
+        // Tesseral terms make the acceleration depend on Earth's rotation
+        let later = gravity.acceleration(t + 6.0 * 3600.0, &r, &Vector3::zeros(), GM_EARTH);
+        assert!((acc - later).norm() > 1e-7);
+
+        // The dominant J2 part is close to the inertial J2 formula
+        let j2 = j2_perturbation(&r, GM_EARTH, J2_EARTH, R_EARTH);
+        assert!((acc - j2).norm() / j2.norm() < 0.02);
+    }
+
+    #[test]
+    fn test_interpolated_earth_rotation() {
+        use crate::coordinates::earth_orientation::gcrs_to_itrs_matrix;
+
+        let field = Arc::new(GravityField::egm2008_degree4());
+        let gravity = SphericalHarmonicGravity::earth(field, 4, 4).unwrap();
+
+        // Midway between nodes, where the interpolation error peaks
+        let t = Epoch::from_gregorian_utc(2025, 6, 1, 0, 30, 0, 0).to_tdb_seconds_since_j2000();
+        let epoch = Epoch::from_tdb_seconds_since_j2000(t);
+        let exact = gcrs_to_itrs_matrix(&epoch, &EarthOrientation::at_or_fallback(&epoch));
+        let interpolated = gravity.body_fixed_rotation(t);
+        assert!((interpolated - exact).abs().max() < 5e-11);
+        assert_relative_eq!(
+            interpolated * interpolated.transpose(),
+            Matrix3::identity(),
+            epsilon = 1e-14
+        );
+
+        // A clone starts with an empty cache and gives the same rotation
+        assert_relative_eq!(
+            gravity.clone().body_fixed_rotation(t),
+            interpolated,
+            epsilon = 1e-18
+        );
+    }
+
+    #[test]
+    fn test_reused_harmonics_buffers() {
+        let field = GravityField::egm2008_degree4();
+        let mut buffers = SolidHarmonics::default();
+        let r = Vector3::new(6.8e6, 1.0e6, 0.5e6);
+        let first = field.acceleration_with(&r, 4, 4, &mut buffers);
+
+        // A lower truncation in the same buffers, then the full field again
+        let low = field.acceleration_with(&(r * 1.1), 2, 0, &mut buffers);
+        assert_relative_eq!(low, field.acceleration(&(r * 1.1), 2, 0), epsilon = 1e-18);
+        let again = field.acceleration_with(&r, 4, 4, &mut buffers);
+        assert_eq!(first, again);
+        assert_eq!(first, field.acceleration(&r, 4, 4));
+    }
+
+    #[test]
+    fn test_iau_frame_for_moon() {
+        let mut field = GravityField::new("moon", 4.9028e12, 1_738_000.0, 2);
+        field.set_coefficients(2, 0, -9.09e-5, 0.0);
+        field.set_coefficients(2, 2, 3.47e-5, 0.0);
+        let gravity = SphericalHarmonicGravity::new(
+            Arc::new(field),
+            2,
+            2,
+            GravityFieldFrame::Iau(Body::Moon),
+        )
+        .unwrap();
+
+        let r = Vector3::new(1.8e6, 0.3e6, 0.2e6);
+        let acc = gravity.acceleration(0.0, &r, &Vector3::zeros(), 4.9028e12);
+        assert!(acc.norm() > 0.0 && acc.norm() < 1e-3);
+        assert_eq!(gravity.name(), "Spherical Harmonic Gravity");
+
+        // No IAU rotation model for the Earth-Moon barycenter
+        let result = SphericalHarmonicGravity::new(
+            gravity.field.clone(),
+            2,
+            2,
+            GravityFieldFrame::Iau(Body::EarthMoonBarycenter),
+        );
+        assert!(matches!(result, Err(PoliastroError::UnknownFrame { .. })));
+    }
+}
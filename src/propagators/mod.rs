@@ -3,13 +3,24 @@
 //! This module provides various orbit propagation methods:
 //! - Keplerian (two-body) propagator for unperturbed motion
 //! - Perturbation models (J2, drag, SRP, third-body)
+//! - Atmospheric density models (exponential, Harris-Priester, Jacchia 1971)
 //! - High-performance static perturbations (zero-allocation)
+//! - Spherical harmonic gravity fields of arbitrary degree and order
 //! - State transition matrix (STM) propagation for orbit determination
-//! - Numerical integrators (RK4, Dormand-Prince available in core::numerical)
+//! - Orbital events (apsides, nodes, altitude, eclipses) detected during
+//!   numerical propagation
+//! - A stateful numerical propagator combining a perturbation set, an
+//!   integrator and an epoch-tagged state
+//! - Numerical integrators (RK4, Dormand-Prince available in core::numerical,
+//!   event detection in core::events)
 
+pub mod atmosphere;
+pub mod gravity_field;
 pub mod keplerian;
+pub mod orbit_events;
 pub mod perturbations;
 pub mod perturbations_static; // High-performance zero-allocation perturbations
+pub mod propagator;
 pub mod stm;
 
 // Re-export commonly used functions
@@ -24,6 +35,37 @@ pub use perturbations::{
     j2_perturbation,
     propagate_j2_rk4,
     propagate_j2_dopri5,
+    propagate_ephemeris,
+    DynamicParameter,
+};
+
+pub use atmosphere::{
+    AtmosphereModel,
+    AtmospherePoint,
+    ExponentialAtmosphere,
+    HarrisPriester,
+    Jacchia71,
+    SolarActivity,
+};
+
+pub use orbit_events::{
+    altitude_event,
+    apoapsis_event,
+    ascending_node_event,
+    descending_node_event,
+    eclipse_event,
+    periapsis_event,
+    propagate_with_events,
+    reentry_event,
+};
+
+pub use propagator::{Integrator, NumericalPropagator};
+
+pub use gravity_field::{
+    GravityField,
+    GravityFieldError,
+    GravityFieldFrame,
+    SphericalHarmonicGravity,
 };
 
 pub use stm::{
@@ -32,6 +74,8 @@ pub use stm::{
     propagate_stm_rk4,
     propagate_stm_dopri5,
     propagate_stm_j2_rk4,
+    propagate_stm_perturbed,
+    VariationalState,
 };
 
 pub use perturbations_static::{
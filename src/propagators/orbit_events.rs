@@ -0,0 +1,409 @@
+//! Orbital events for numerical propagation
+//!
+//! Ready-made [`Event`]s on the Cartesian state y = [r, v] (m, m/s) used by
+//! [`propagate_with_events`] and the [`core::events`](crate::core::events)
+//! integrators:
+//! - [`periapsis_event`] / [`apoapsis_event`]: zero crossings of r·v
+//! - [`ascending_node_event`] / [`descending_node_event`]: crossings of the
+//!   equatorial plane (z = 0)
+//! - [`altitude_event`]: crossings of a spherical altitude threshold, and
+//!   [`reentry_event`] stopping the propagation below it
+//! - [`eclipse_event`]: shadow cone entry and exit with the Sun from the
+//!   ephemeris subsystem
+//!
+//! Every event records its crossings by default; use
+//! [`Event::with_action`] to stop or reset the propagation instead.
+//!
+//! # Example
+//! ```ignore
+//! use astrora::propagators::orbit_events::{apoapsis_event, propagate_with_events, reentry_event};
+//! use astrora::propagators::perturbations::{DragPerturbation, PerturbationSet};
+//!
+//! let mut perturbations = PerturbationSet::new();
+//! perturbations.add(DragPerturbation::earth(0.02));
+//!
+//! let events = [apoapsis_event(), reentry_event(100e3)];
+//! let result = propagate_with_events(
+//!     &r0, &v0, t0, 30.0 * 86400.0, GM_EARTH, &perturbations, &events, "dop853", None,
+//! )?;
+//! for apoapsis in result.occurrences("apoapsis") {
+//!     println!("apoapsis at t = {} s", apoapsis.t);
+//! }
+//! ```
+
+use nalgebra::DVector;
+
+use crate::core::constants::R_EARTH;
+use crate::core::error::{PoliastroError, PoliastroResult};
+use crate::core::events::{
+    dop853_integrate_with_events, dopri5_integrate_with_events, Event, EventAction, EventDirection,
+    EventIntegration,
+};
+use crate::core::linalg::Vector3;
+use crate::core::time::Epoch;
+use crate::propagators::perturbations::{sun_position_simple, Perturbation};
+use crate::satellite::eclipse::{shadow_cone_margin, sun_position, EclipseState};
+
+fn position(y: &DVector<f64>) -> Vector3 {
+    Vector3::new(y[0], y[1], y[2])
+}
+
+fn velocity(y: &DVector<f64>) -> Vector3 {
+    Vector3::new(y[3], y[4], y[5])
+}
+
+/// Periapsis passages: r·v crossing zero from below
+pub fn periapsis_event<'a>() -> Event<'a> {
+    Event::new("periapsis", |_t, y| position(y).dot(&velocity(y)))
+        .with_direction(EventDirection::Increasing)
+}
+
+/// Apoapsis passages: r·v crossing zero from above
+pub fn apoapsis_event<'a>() -> Event<'a> {
+    Event::new("apoapsis", |_t, y| position(y).dot(&velocity(y)))
+        .with_direction(EventDirection::Decreasing)
+}
+
+/// Ascending node crossings: z crossing zero from below
+pub fn ascending_node_event<'a>() -> Event<'a> {
+    Event::new("ascending_node", |_t, y| y[2]).with_direction(EventDirection::Increasing)
+}
+
+/// Descending node crossings: z crossing zero from above
+pub fn descending_node_event<'a>() -> Event<'a> {
+    Event::new("descending_node", |_t, y| y[2]).with_direction(EventDirection::Decreasing)
+}
+
+/// Crossings of `altitude` (m) above a sphere of radius `body_radius` (m)
+///
+/// Increasing occurrences are ascents through the altitude, decreasing ones
+/// descents.
+pub fn altitude_event<'a>(altitude: f64, body_radius: f64) -> Event<'a> {
+    let threshold = body_radius + altitude;
+    Event::new("altitude", move |_t, y| position(y).norm() - threshold)
+}
+
+/// Stop when descending through `altitude` (m) above the Earth's equatorial
+/// radius
+pub fn reentry_event<'a>(altitude: f64) -> Event<'a> {
+    let mut event = altitude_event(altitude, R_EARTH)
+        .with_direction(EventDirection::Decreasing)
+        .with_action(EventAction::Stop);
+    event.name = "reentry".to_string();
+    event
+}
+
+/// Entries into and exits from the Earth's shadow
+///
+/// `cone` selects the umbra (`EclipseState::Umbra`) or the penumbra
+/// (`EclipseState::Penumbra`, i.e. any loss of full sunlight), see
+/// [`shadow_cone_margin`]. Decreasing occurrences are entries, increasing
+/// ones exits. `EclipseState::Sunlit` gives the same boundary as the
+/// penumbra seen from the lit side, named "sunlit": increasing occurrences
+/// are entries into full sunlight, decreasing ones exits. The propagation time is taken as TDB seconds since J2000 for
+/// the Sun position (installed ephemeris, or the analytic fallback).
+///
+/// A low orbit spends a few minutes per revolution near the shadow boundary,
+/// so the event is checked at least every 60 s.
+pub fn eclipse_event<'a>(cone: EclipseState) -> Event<'a> {
+    let (name, sign) = match cone {
+        EclipseState::Umbra => ("umbra", 1.0),
+        EclipseState::Penumbra => ("penumbra", 1.0),
+        EclipseState::Sunlit => ("sunlit", -1.0),
+    };
+    Event::new(name, move |t, y| {
+        let r_sun = sun_position(&Epoch::from_tdb_seconds_since_j2000(t))
+            .unwrap_or_else(|_| sun_position_simple(t));
+        sign * shadow_cone_margin(&position(y), &r_sun, cone)
+    })
+    .with_max_check_interval(60.0)
+}
+
+/// Propagate an orbit with trait-based perturbations, detecting events
+///
+/// Like [`propagate_with_perturbations`](crate::propagators::perturbations::propagate_with_perturbations),
+/// with the integration time running from `t0` (TDB seconds since J2000, as
+/// passed to the perturbations and events) to `t0 + dt`, and stopping early
+/// at a terminal event.
+///
+/// # Arguments
+/// * `r0` - Initial position (m)
+/// * `v0` - Initial velocity (m/s)
+/// * `t0` - Initial time (TDB seconds since J2000)
+/// * `dt` - Propagation time (seconds, may be negative)
+/// * `mu` - Central body gravitational parameter (m³/s²)
+/// * `perturbations` - Perturbation set or any type implementing Perturbation
+/// * `events` - Events on the state [r, v]
+/// * `method` - Integration method: "dopri5" or "dop853"
+/// * `tol` - Integrator tolerance (None = 1e-10)
+///
+/// # Returns
+/// Final time and state [r, v], with the detected events
+///
+/// # Errors
+/// Returns an error for an unknown method or if the integration fails
+#[allow(clippy::too_many_arguments)]
+pub fn propagate_with_events<P: Perturbation + ?Sized>(
+    r0: &Vector3,
+    v0: &Vector3,
+    t0: f64,
+    dt: f64,
+    mu: f64,
+    perturbations: &P,
+    events: &[Event],
+    method: &str,
+    tol: Option<f64>,
+) -> PoliastroResult<EventIntegration> {
+    let accel_func = |t: f64, state: &DVector<f64>| -> DVector<f64> {
+        let r = position(state);
+        let v = velocity(state);
+        let r_mag = r.norm();
+        let a = -mu / (r_mag * r_mag * r_mag) * r + perturbations.acceleration(t, &r, &v, mu);
+        DVector::from_vec(vec![v.x, v.y, v.z, a.x, a.y, a.z])
+    };
+
+    let state = DVector::from_vec(vec![r0.x, r0.y, r0.z, v0.x, v0.y, v0.z]);
+    let tolerance = tol.unwrap_or(1e-10);
+    let h0 = (dt.abs() / 10.0).min(60.0);
+    // Long propagations take many steps
+    let max_steps = Some(10_000_000);
+
+    match method.to_lowercase().as_str() {
+        "dopri5" => dopri5_integrate_with_events(
+            accel_func,
+            t0,
+            &state,
+            t0 + dt,
+            h0,
+            tolerance,
+            max_steps,
+            events,
+        ),
+        "dop853" => dop853_integrate_with_events(
+            accel_func,
+            t0,
+            &state,
+            t0 + dt,
+            h0,
+            tolerance,
+            max_steps,
+            events,
+        ),
+        _ => Err(PoliastroError::invalid_state(format!(
+            "Unknown integration method: {method}. Use 'dopri5' or 'dop853'"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::GM_EARTH;
+    use crate::propagators::perturbations::{J2Perturbation, PerturbationSet};
+    use approx::assert_relative_eq;
+    use std::f64::consts::PI;
+
+    /// Inclined elliptical orbit starting at periapsis on the ascending node
+    fn elliptical_orbit() -> (Vector3, Vector3, f64) {
+        let (rp, ra) = (R_EARTH + 500e3, R_EARTH + 2000e3);
+        let a = 0.5 * (rp + ra);
+        let vp = (GM_EARTH * (2.0 / rp - 1.0 / a)).sqrt();
+        let inclination = 0.9_f64;
+        let period = 2.0 * PI * (a.powi(3) / GM_EARTH).sqrt();
+        (
+            Vector3::new(rp, 0.0, 0.0),
+            Vector3::new(0.0, vp * inclination.cos(), vp * inclination.sin()),
+            period,
+        )
+    }
+
+    #[test]
+    fn test_apsides_and_nodes_two_body() {
+        let (r0, v0, period) = elliptical_orbit();
+        let events = [
+            periapsis_event(),
+            apoapsis_event(),
+            ascending_node_event(),
+            descending_node_event(),
+        ];
+        let result = propagate_with_events(
+            &r0,
+            &v0,
+            0.0,
+            2.2 * period,
+            GM_EARTH,
+            &PerturbationSet::new(),
+            &events,
+            "dop853",
+            None,
+        )
+        .unwrap();
+
+        let times = |name| result.occurrences(name).map(|e| e.t).collect::<Vec<_>>();
+        let periapsides = times("periapsis");
+        let apoapsides = times("apoapsis");
+        assert_eq!(periapsides.len(), 2);
+        assert_eq!(apoapsides.len(), 2);
+        assert_relative_eq!(apoapsides[0], 0.5 * period, epsilon = 1e-3);
+        assert_relative_eq!(periapsides[0], period, epsilon = 1e-3);
+        assert_relative_eq!(periapsides[1], 2.0 * period, epsilon = 1e-3);
+
+        // Line of apsides is the line of nodes: nodes coincide with apsides
+        assert_relative_eq!(times("descending_node")[0], 0.5 * period, epsilon = 1e-3);
+        assert_relative_eq!(times("ascending_node")[0], period, epsilon = 1e-3);
+
+        let apoapsis = result.occurrences("apoapsis").next().unwrap();
+        assert_relative_eq!(
+            position(&apoapsis.y).norm(),
+            R_EARTH + 2000e3,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_j2_nodal_period() {
+        // J2 shortens the nodal period of a prograde orbit relative to the
+        // Keplerian period
+        let (r0, v0, period) = elliptical_orbit();
+        let mut perturbations = PerturbationSet::new();
+        perturbations.add(J2Perturbation::earth());
+        let result = propagate_with_events(
+            &r0,
+            &v0,
+            0.0,
+            1.2 * period,
+            GM_EARTH,
+            &perturbations,
+            &[ascending_node_event()],
+            "dopri5",
+            None,
+        )
+        .unwrap();
+
+        let node = &result.events[0];
+        assert!(node.y[2].abs() < 1e-3);
+        assert!((node.t - period).abs() < 0.01 * period);
+        assert!(node.t != period);
+    }
+
+    #[test]
+    fn test_altitude_stop() {
+        let (r0, v0, period) = elliptical_orbit();
+        let events = [reentry_event(1000e3)];
+        let result = propagate_with_events(
+            &r0,
+            &v0,
+            0.0,
+            period,
+            GM_EARTH,
+            &PerturbationSet::new(),
+            &events,
+            "dop853",
+            None,
+        )
+        .unwrap();
+
+        assert!(result.stopped);
+        assert_eq!(result.events[0].name, "reentry");
+        assert!(result.t > 0.5 * period && result.t < period);
+        assert_relative_eq!(
+            position(&result.y).norm(),
+            R_EARTH + 1000e3,
+            max_relative = 1e-9
+        );
+
+        // Ascent through the same altitude is only recorded by altitude_event
+        let result = propagate_with_events(
+            &r0,
+            &v0,
+            0.0,
+            period,
+            GM_EARTH,
+            &PerturbationSet::new(),
+            &[altitude_event(1000e3, R_EARTH)],
+            "dop853",
+            None,
+        )
+        .unwrap();
+        assert!(!result.stopped);
+        let increasing: Vec<bool> = result.events.iter().map(|e| e.increasing).collect();
+        assert_eq!(increasing, vec![true, false]);
+    }
+
+    #[test]
+    fn test_eclipse_entry_and_exit() {
+        // Circular LEO in the ecliptic-ish equatorial plane at an equinox:
+        // one umbra pass per revolution, inside the penumbra pass
+        let epoch = Epoch::from_gregorian_utc(2024, 3, 20, 12, 0, 0, 0);
+        let t0 = epoch.to_tdb_seconds_since_j2000();
+        let radius = R_EARTH + 500e3;
+        let speed = (GM_EARTH / radius).sqrt();
+        let period = 2.0 * PI * (radius.powi(3) / GM_EARTH).sqrt();
+
+        let events = [
+            eclipse_event(EclipseState::Umbra),
+            eclipse_event(EclipseState::Penumbra),
+            eclipse_event(EclipseState::Sunlit),
+        ];
+        let result = propagate_with_events(
+            &Vector3::new(radius, 0.0, 0.0),
+            &Vector3::new(0.0, speed, 0.0),
+            t0,
+            period,
+            GM_EARTH,
+            &PerturbationSet::new(),
+            &events,
+            "dopri5",
+            None,
+        )
+        .unwrap();
+
+        let umbra: Vec<_> = result.occurrences("umbra").collect();
+        let penumbra: Vec<_> = result.occurrences("penumbra").collect();
+        assert_eq!(umbra.len(), 2);
+        assert_eq!(penumbra.len(), 2);
+
+        let (entry, exit) = (&umbra[0], &umbra[1]);
+        assert!(!entry.increasing && exit.increasing);
+        assert!(penumbra[0].t < entry.t && exit.t < penumbra[1].t);
+
+        // Leaving full sunlight is entering the penumbra
+        let sunlit: Vec<_> = result.occurrences("sunlit").collect();
+        assert_eq!(sunlit.len(), 2);
+        for (lit, shadow) in sunlit.iter().zip(&penumbra) {
+            assert_eq!(lit.increasing, !shadow.increasing);
+            assert!((lit.t - shadow.t).abs() < 1e-6);
+        }
+
+        // About 35 minutes of umbra at 500 km
+        let duration = exit.t - entry.t;
+        assert!(
+            duration > 30.0 * 60.0 && duration < 40.0 * 60.0,
+            "{}",
+            duration
+        );
+
+        let sun = sun_position(&Epoch::from_tdb_seconds_since_j2000(entry.t)).unwrap();
+        assert_eq!(
+            crate::satellite::eclipse::compute_eclipse_state(&position(&entry.y), &sun),
+            EclipseState::Umbra
+        );
+    }
+
+    #[test]
+    fn test_unknown_method() {
+        let (r0, v0, _) = elliptical_orbit();
+        let result = propagate_with_events(
+            &r0,
+            &v0,
+            0.0,
+            60.0,
+            GM_EARTH,
+            &PerturbationSet::new(),
+            &[],
+            "rk4",
+            None,
+        );
+        assert!(result.is_err());
+    }
+}
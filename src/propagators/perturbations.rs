@@ -14,8 +14,14 @@
 //! - Montenbruck, O. "Satellite Orbits" (Section 3.3.2)
 
 use crate::core::error::{PoliastroError, PoliastroResult};
-use crate::core::linalg::Vector3;
+use crate::core::linalg::{Matrix3, Vector3};
+use crate::core::numerical::Ephemeris;
+use crate::core::time::Epoch;
+use crate::ephemeris::{installed_ephemeris, AnalyticEphemeris, Body, EphemerisSource};
 use std::f64::consts::PI;
+use std::sync::Arc;
+
+use super::atmosphere::{velocity_relative_to_atmosphere, AtmosphereModel};
 
 /// J2 oblateness perturbation acceleration
 ///
@@ -2832,6 +2838,16 @@ pub trait Perturbation: Send + Sync {
     /// - The `mu` parameter is the central body's GM, not the perturbing body
     fn acceleration(&self, t: f64, r: &Vector3, v: &Vector3, mu: f64) -> Vector3;
 
+    /// Compute the perturbation acceleration, or the error that prevents it
+    ///
+    /// Perturbations whose inputs can be unavailable (an ephemeris outside its
+    /// coverage) report it here; [`Self::acceleration`] returns NaN instead.
+    ///
+    /// Default implementation wraps [`Self::acceleration`].
+    fn try_acceleration(&self, t: f64, r: &Vector3, v: &Vector3, mu: f64) -> PoliastroResult<Vector3> {
+        Ok(self.acceleration(t, r, v, mu))
+    }
+
     /// Get a human-readable name for this perturbation
     ///
     /// Used for logging, debugging, and user feedback.
@@ -2846,6 +2862,139 @@ pub trait Perturbation: Send + Sync {
     fn is_time_dependent(&self) -> bool {
         false
     }
+
+    /// Partial derivatives of the acceleration with respect to position and
+    /// velocity, (∂a/∂r, ∂a/∂v)
+    ///
+    /// Used by the variational equations of the state transition matrix
+    /// ([`crate::propagators::stm::propagate_stm_perturbed`]). The default
+    /// implementation takes central finite differences of
+    /// [`Self::acceleration`] ([`finite_difference_partials`]); perturbations
+    /// with closed-form partials override it.
+    fn partials(&self, t: f64, r: &Vector3, v: &Vector3, mu: f64) -> (Matrix3, Matrix3) {
+        finite_difference_partials(self, t, r, v, mu)
+    }
+
+    /// Partial derivative of the acceleration with respect to a force model
+    /// parameter, or `None` if the perturbation does not depend on it
+    ///
+    /// Default implementation returns `None`.
+    fn parameter_partial(
+        &self,
+        _parameter: DynamicParameter,
+        _t: f64,
+        _r: &Vector3,
+        _v: &Vector3,
+        _mu: f64,
+    ) -> Option<Vector3> {
+        None
+    }
+
+    /// Check that the perturbation can be evaluated from `t0` to `tf`
+    /// (seconds, as passed to [`Self::acceleration`]) before integrating
+    ///
+    /// Default implementation accepts every span.
+    fn check_span(&self, _t0: f64, _tf: f64) -> PoliastroResult<()> {
+        Ok(())
+    }
+}
+
+/// Force model parameter whose sensitivity can be propagated alongside the
+/// state transition matrix
+///
+/// The sensitivity to the drag coefficient follows from the one to the
+/// ballistic coefficient as ∂a/∂C_d = −(B/C_d)·∂a/∂B.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DynamicParameter {
+    /// Ballistic coefficient B = m/(C_d × A) of atmospheric drag (kg/m²)
+    BallisticCoefficient,
+    /// Reflectivity coefficient C_r of solar radiation pressure
+    Reflectivity,
+}
+
+impl DynamicParameter {
+    /// Parameter from its name: "ballistic_coefficient" (or "B") or
+    /// "reflectivity" (or "C_r")
+    ///
+    /// # Errors
+    /// Returns an error for an unknown name
+    pub fn from_name(name: &str) -> PoliastroResult<Self> {
+        match name.to_lowercase().as_str() {
+            "ballistic_coefficient" | "b" => Ok(Self::BallisticCoefficient),
+            "reflectivity" | "c_r" | "cr" => Ok(Self::Reflectivity),
+            _ => Err(PoliastroError::invalid_state(format!(
+                "Unknown force model parameter: {name}. Use 'ballistic_coefficient' or 'reflectivity'"
+            ))),
+        }
+    }
+
+    /// Name of the parameter
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::BallisticCoefficient => "ballistic_coefficient",
+            Self::Reflectivity => "reflectivity",
+        }
+    }
+}
+
+/// Partial derivatives (∂a/∂r, ∂a/∂v) of a perturbation by central finite
+/// differences
+///
+/// The steps are 10⁻⁶ of the position and velocity magnitudes (at least
+/// 10⁻⁶ m and 10⁻⁶ m/s), which keeps both truncation and round-off errors
+/// near 10⁻¹² relative for orbital perturbations.
+pub fn finite_difference_partials<P: Perturbation + ?Sized>(
+    perturbation: &P,
+    t: f64,
+    r: &Vector3,
+    v: &Vector3,
+    mu: f64,
+) -> (Matrix3, Matrix3) {
+    let h_r = 1e-6 * r.norm().max(1.0);
+    let h_v = 1e-6 * v.norm().max(1.0);
+    let mut a_r = Matrix3::zeros();
+    let mut a_v = Matrix3::zeros();
+    for j in 0..3 {
+        let dr = Vector3::ith(j, h_r);
+        let diff = perturbation.acceleration(t, &(r + dr), v, mu)
+            - perturbation.acceleration(t, &(r - dr), v, mu);
+        a_r.set_column(j, &(diff / (2.0 * h_r)));
+
+        let dv = Vector3::ith(j, h_v);
+        let diff = perturbation.acceleration(t, r, &(v + dv), mu)
+            - perturbation.acceleration(t, r, &(v - dv), mu);
+        a_v.set_column(j, &(diff / (2.0 * h_v)));
+    }
+    (a_r, a_v)
+}
+
+/// Partial derivatives ∂a/∂r of the J2 acceleration
+///
+/// With a_i = k·r_i·f_i/r⁵, k = (3/2)·J2·μ·R² and f_i = 5z²/r² − c_i
+/// (c = 1, 1, 3):
+///
+/// ∂a_i/∂r_j = k·[δ_ij·f_i/r⁵ + r_i·(∂f_i/∂r_j)/r⁵ − 5·r_i·r_j·f_i/r⁷]
+///
+/// # Arguments
+/// * `r` - Position vector (m)
+/// * `mu` - Standard gravitational parameter (m³/s²)
+/// * `j2` - Oblateness coefficient (dimensionless)
+/// * `R` - Body equatorial radius (m)
+#[allow(non_snake_case)]
+pub fn j2_partials(r: &Vector3, mu: f64, j2: f64, R: f64) -> Matrix3 {
+    let k = 1.5 * j2 * mu * R * R;
+    let r2 = r.norm_squared();
+    let r5 = r2 * r2 * r2.sqrt();
+    let z = r.z;
+
+    // ∂(5z²/r²)/∂r_j
+    let df = Vector3::new(0.0, 0.0, 10.0 * z / r2) - r * (10.0 * z * z / (r2 * r2));
+
+    Matrix3::from_fn(|i, j| {
+        let f = 5.0 * z * z / r2 - if i == 2 { 3.0 } else { 1.0 };
+        let delta = if i == j { f } else { 0.0 };
+        k / r5 * (delta + r[i] * df[j] - 5.0 * r[i] * r[j] * f / r2)
+    })
 }
 
 //==============================================================================
@@ -2905,12 +3054,22 @@ impl Perturbation for J2Perturbation {
     fn is_time_dependent(&self) -> bool {
         false
     }
+
+    fn partials(&self, _t: f64, r: &Vector3, _v: &Vector3, mu: f64) -> (Matrix3, Matrix3) {
+        (j2_partials(r, mu, self.j2, self.radius), Matrix3::zeros())
+    }
 }
 
-/// Atmospheric drag perturbation (exponential atmosphere model)
+/// Atmospheric drag perturbation
 ///
-/// Models atmospheric drag using a simple exponential density model.
-/// Suitable for altitudes between 100-1000 km.
+/// Models atmospheric drag using a simple exponential density model by
+/// default. Suitable for altitudes between 100-1000 km.
+///
+/// A thermospheric model (e.g. [`Jacchia71`](super::atmosphere::Jacchia71))
+/// can be selected with [`DragPerturbation::with_atmosphere`]. The density is
+/// then evaluated at the epoch `t` (TDB seconds since J2000) with `r` in the
+/// GCRS, and drag acts on the velocity relative to the co-rotating
+/// atmosphere.
 ///
 /// # Parameters
 /// - `R`: Body radius (m)
@@ -2919,19 +3078,24 @@ impl Perturbation for J2Perturbation {
 /// - `B`: Ballistic coefficient m/(C_d × A) in kg/m²
 ///
 /// # Limitations
-/// - Simple exponential model (constant scale height)
-/// - Non-rotating atmosphere assumption
-/// - No solar/geomagnetic activity effects
+/// - Exponential model: constant scale height, non-rotating atmosphere and
+///   no solar/geomagnetic activity effects
 ///
 /// # Example
 /// ```ignore
+/// use std::sync::Arc;
+/// use astrora::propagators::atmosphere::{Jacchia71, SolarActivity};
 /// use astrora::propagators::perturbations::DragPerturbation;
 /// use astrora::core::constants::{R_EARTH, RHO0_EARTH, H0_EARTH};
 ///
 /// // ISS-like spacecraft: 100 kg/m²
 /// let drag = DragPerturbation::new(R_EARTH, RHO0_EARTH, H0_EARTH, 100.0);
+///
+/// // Same spacecraft in a Jacchia 1971 atmosphere at high solar activity
+/// let jacchia = Jacchia71::new(SolarActivity::new(220.0, 200.0, 20.0));
+/// let drag = DragPerturbation::with_atmosphere(Arc::new(jacchia), 100.0);
 /// ```
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct DragPerturbation {
     /// Body radius (m)
     pub radius: f64,
@@ -2941,6 +3105,8 @@ pub struct DragPerturbation {
     pub scale_height: f64,
     /// Ballistic coefficient (kg/m²)
     pub ballistic_coeff: f64,
+    /// Atmosphere model replacing the exponential model, if any
+    atmosphere: Option<Arc<dyn AtmosphereModel>>,
 }
 
 impl DragPerturbation {
@@ -2957,6 +3123,19 @@ impl DragPerturbation {
             rho0,
             scale_height,
             ballistic_coeff,
+            atmosphere: None,
+        }
+    }
+
+    /// Create an Earth drag perturbation using an atmosphere model
+    ///
+    /// # Arguments
+    /// * `atmosphere` - Density model, evaluated in the GCRS
+    /// * `ballistic_coeff` - Ballistic coefficient B = m/(C_d × A) in kg/m²
+    pub fn with_atmosphere(atmosphere: Arc<dyn AtmosphereModel>, ballistic_coeff: f64) -> Self {
+        Self {
+            atmosphere: Some(atmosphere),
+            ..Self::earth(ballistic_coeff)
         }
     }
 
@@ -2971,18 +3150,30 @@ impl DragPerturbation {
         use crate::core::constants::{H0_EARTH, R_EARTH, RHO0_EARTH};
         Self::new(R_EARTH, RHO0_EARTH, H0_EARTH, ballistic_coeff)
     }
+
+    /// Atmosphere model set with [`DragPerturbation::with_atmosphere`], or
+    /// `None` for the exponential model
+    pub fn atmosphere(&self) -> Option<&dyn AtmosphereModel> {
+        self.atmosphere.as_deref()
+    }
 }
 
 impl Perturbation for DragPerturbation {
-    fn acceleration(&self, _t: f64, r: &Vector3, v: &Vector3, _mu: f64) -> Vector3 {
-        drag_acceleration(
-            r,
-            v,
-            self.radius,
-            self.rho0,
-            self.scale_height,
-            self.ballistic_coeff,
-        )
+    fn acceleration(&self, t: f64, r: &Vector3, v: &Vector3, _mu: f64) -> Vector3 {
+        let Some(atmosphere) = &self.atmosphere else {
+            return drag_acceleration(
+                r,
+                v,
+                self.radius,
+                self.rho0,
+                self.scale_height,
+                self.ballistic_coeff,
+            );
+        };
+
+        let rho = atmosphere.density(&Epoch::from_tdb_seconds_since_j2000(t), r);
+        let v_rel = velocity_relative_to_atmosphere(r, v);
+        v_rel * (-0.5 * rho * v_rel.norm() / self.ballistic_coeff)
     }
 
     fn name(&self) -> &str {
@@ -2990,24 +3181,59 @@ impl Perturbation for DragPerturbation {
     }
 
     fn is_time_dependent(&self) -> bool {
-        false
+        self.atmosphere.is_some()
+    }
+
+    fn partials(&self, t: f64, r: &Vector3, v: &Vector3, mu: f64) -> (Matrix3, Matrix3) {
+        let v_mag = v.norm();
+        if self.atmosphere.is_some() || v_mag < 1e-10 {
+            return finite_difference_partials(self, t, r, v, mu);
+        }
+
+        // Exponential model: a = −½·ρ(|r|)·|v|·v/B with ∂ρ/∂r = −ρ·r̂/H
+        let a = self.acceleration(t, r, v, mu);
+        let rho = exponential_density(r.norm() - self.radius, self.rho0, self.scale_height);
+        let a_r = a * r.normalize().transpose() * (-1.0 / self.scale_height);
+        let a_v = (Matrix3::identity() * v_mag + v * v.transpose() / v_mag)
+            * (-0.5 * rho / self.ballistic_coeff);
+        (a_r, a_v)
+    }
+
+    fn parameter_partial(
+        &self,
+        parameter: DynamicParameter,
+        t: f64,
+        r: &Vector3,
+        v: &Vector3,
+        mu: f64,
+    ) -> Option<Vector3> {
+        (parameter == DynamicParameter::BallisticCoefficient)
+            .then(|| -self.acceleration(t, r, v, mu) / self.ballistic_coeff)
     }
 }
 
 /// Third-body gravitational perturbation
 ///
 /// Models the gravitational perturbation from a third body (e.g., Sun, Moon).
-/// Can use either a simple circular ephemeris or a custom position function.
+/// Can use a simple circular ephemeris, the ephemeris subsystem
+/// ([`crate::ephemeris`]) or a custom position function.
 ///
 /// # Variants
 /// - `Sun`: Simplified Sun ephemeris (1 AU circular orbit)
 /// - `Moon`: Simplified Moon ephemeris (384,400 km circular orbit)
+/// - `Ephemeris`: Any body from one ephemeris source, fixed at construction
+///   (the installed source, or the built-in analytic ephemeris), with an
+///   optional opt-in fallback for epochs the source does not cover
 /// - `Custom`: User-provided position function and gravitational parameter
 ///
+/// The time argument `t` is interpreted as TDB seconds since J2000 when
+/// querying the ephemeris subsystem.
+///
 /// # Example
 /// ```ignore
 /// use astrora::propagators::perturbations::ThirdBodyPerturbation;
-/// use astrora::core::constants::{GM_SUN, GM_MOON};
+/// use astrora::core::constants::{GM_JUPITER, GM_SUN, GM_MOON};
+/// use astrora::ephemeris::Body;
 ///
 /// // Use built-in Sun perturbation
 /// let sun = ThirdBodyPerturbation::sun();
@@ -3015,6 +3241,9 @@ impl Perturbation for DragPerturbation {
 /// // Use built-in Moon perturbation
 /// let moon = ThirdBodyPerturbation::moon();
 ///
+/// // Jupiter from the installed ephemeris (geocentric)
+/// let jupiter = ThirdBodyPerturbation::ephemeris(Body::Jupiter, GM_JUPITER)?;
+///
 /// // Or create a custom third body
 /// let jupiter = ThirdBodyPerturbation::custom(
 ///     GM_JUPITER,
@@ -3027,6 +3256,21 @@ pub enum ThirdBodyPerturbation {
     Sun,
     /// Moon perturbation using simplified circular ephemeris
     Moon,
+    /// Third body positioned by the ephemeris subsystem
+    Ephemeris {
+        /// Perturbing body
+        body: Body,
+        /// Central body of the propagation (origin of the position vectors)
+        center: Body,
+        /// Gravitational parameter of third body (m³/s²)
+        mu: f64,
+        /// Name of the perturbation (for identification)
+        body_name: String,
+        /// Source of the third body positions
+        source: Arc<dyn EphemerisSource>,
+        /// Source used at epochs `source` does not cover (explicit opt-in)
+        fallback: Option<Arc<dyn EphemerisSource>>,
+    },
     /// Custom third body with user-provided position function
     Custom {
         /// Gravitational parameter of third body (m³/s²)
@@ -3039,14 +3283,126 @@ pub enum ThirdBodyPerturbation {
 }
 
 impl ThirdBodyPerturbation {
-    /// Create Sun perturbation (simplified circular ephemeris)
+    /// Create Sun perturbation
+    ///
+    /// Positions come from the ephemeris installed at construction (e.g. a
+    /// JPL SPK kernel) when it provides the Sun, otherwise from the simplified
+    /// circular ephemeris. The source is fixed here, so a propagation never
+    /// switches between the two; outside the coverage of the installed
+    /// ephemeris, [`Perturbation::check_span`] rejects the span.
     pub fn sun() -> Self {
-        Self::Sun
+        Self::installed_or(Body::Sun, crate::core::constants::GM_SUN, Self::Sun)
     }
 
-    /// Create Moon perturbation (simplified circular ephemeris)
+    /// Create Moon perturbation
+    ///
+    /// Positions come from the installed ephemeris or the simplified circular
+    /// ephemeris, as for [`ThirdBodyPerturbation::sun`].
     pub fn moon() -> Self {
-        Self::Moon
+        Self::installed_or(Body::Moon, crate::core::constants::GM_MOON, Self::Moon)
+    }
+
+    /// `body` about the Earth from the installed ephemeris, or `simplified`
+    /// when none is installed or it lacks the body
+    fn installed_or(body: Body, mu: f64, simplified: Self) -> Self {
+        installed_ephemeris()
+            .and_then(|source| Self::from_source(body, Body::Earth, mu, source).ok())
+            .unwrap_or(simplified)
+    }
+
+    /// Create a third body perturbation from the ephemeris subsystem, for
+    /// orbits about the Earth
+    ///
+    /// # Arguments
+    /// * `body` - Perturbing body
+    /// * `mu` - Gravitational parameter of third body (m³/s²)
+    ///
+    /// # Errors
+    /// See [`ThirdBodyPerturbation::ephemeris_about`]
+    pub fn ephemeris(body: Body, mu: f64) -> PoliastroResult<Self> {
+        Self::ephemeris_about(body, Body::Earth, mu)
+    }
+
+    /// Create a third body perturbation from the ephemeris subsystem, for
+    /// orbits about `center`
+    ///
+    /// Positions come from the ephemeris installed at construction, or the
+    /// built-in analytic ephemeris when none is installed.
+    ///
+    /// # Arguments
+    /// * `body` - Perturbing body
+    /// * `center` - Central body of the propagation
+    /// * `mu` - Gravitational parameter of third body (m³/s²)
+    ///
+    /// # Errors
+    /// See [`ThirdBodyPerturbation::from_source`]
+    pub fn ephemeris_about(body: Body, center: Body, mu: f64) -> PoliastroResult<Self> {
+        let source = installed_ephemeris().unwrap_or_else(|| Arc::new(AnalyticEphemeris));
+        Self::from_source(body, center, mu, source)
+    }
+
+    /// Create a third body perturbation positioned by `source`, for orbits
+    /// about `center`
+    ///
+    /// Propagations outside the coverage of `source` are rejected by
+    /// [`Perturbation::check_span`] unless a fallback is enabled with
+    /// [`ThirdBodyPerturbation::with_analytic_fallback`].
+    ///
+    /// # Arguments
+    /// * `body` - Perturbing body
+    /// * `center` - Central body of the propagation
+    /// * `mu` - Gravitational parameter of third body (m³/s²)
+    /// * `source` - Ephemeris providing `body` relative to `center`
+    ///
+    /// # Errors
+    /// `InvalidParameter` if `source` does not provide `body` relative to
+    /// `center`.
+    pub fn from_source(
+        body: Body,
+        center: Body,
+        mu: f64,
+        source: Arc<dyn EphemerisSource>,
+    ) -> PoliastroResult<Self> {
+        let t = source.time_span(body, center).map_or(0.0, |(start, _)| start);
+        source
+            .position(body, center, &Epoch::from_tdb_seconds_since_j2000(t))
+            .map_err(|e| PoliastroError::invalid_parameter("body", body.naif_id() as f64, e.to_string()))?;
+        Ok(Self::Ephemeris {
+            body,
+            center,
+            mu,
+            body_name: format!("Third-Body ({})", body),
+            source,
+            fallback: None,
+        })
+    }
+
+    /// Use the built-in analytic ephemeris at epochs the source does not cover
+    ///
+    /// The two sources differ, so a propagation that crosses the end of the
+    /// coverage sees a jump in the third body position. Other variants are
+    /// returned unchanged.
+    ///
+    /// # Errors
+    /// `InvalidParameter` if the analytic ephemeris does not provide the body
+    /// relative to the center.
+    pub fn with_analytic_fallback(self) -> PoliastroResult<Self> {
+        match self {
+            Self::Ephemeris { body, center, mu, body_name, source, .. } => {
+                AnalyticEphemeris
+                    .position(body, center, &Epoch::from_tdb_seconds_since_j2000(0.0))
+                    .map_err(|e| PoliastroError::invalid_parameter("body", body.naif_id() as f64, e.to_string()))?;
+                Ok(Self::Ephemeris {
+                    body,
+                    center,
+                    mu,
+                    body_name,
+                    source,
+                    fallback: Some(Arc::new(AnalyticEphemeris)),
+                })
+            }
+            other => Ok(other),
+        }
     }
 
     /// Create custom third body perturbation
@@ -3069,38 +3425,93 @@ impl ThirdBodyPerturbation {
         match self {
             Self::Sun => GM_SUN,
             Self::Moon => GM_MOON,
-            Self::Custom { mu, .. } => *mu,
+            Self::Ephemeris { mu, .. } | Self::Custom { mu, .. } => *mu,
         }
     }
 
-    /// Get the position of the third body at time t
-    fn get_position(&self, t: f64) -> Vector3 {
+    /// Position of the third body relative to the central body at time `t`
+    /// (TDB seconds since J2000)
+    ///
+    /// # Errors
+    /// For an `Ephemeris` body, the error of the ephemeris lookup at an
+    /// epoch its source (and fallback, if any) does not cover.
+    pub fn position(&self, t: f64) -> PoliastroResult<Vector3> {
         match self {
-            Self::Sun => sun_position_simple(t),
-            Self::Moon => moon_position_simple(t),
-            Self::Custom { position_func, .. } => position_func(t),
+            Self::Sun => Ok(sun_position_simple(t)),
+            Self::Moon => Ok(moon_position_simple(t)),
+            Self::Ephemeris { body, center, source, fallback, .. } => {
+                let epoch = Epoch::from_tdb_seconds_since_j2000(t);
+                source
+                    .position(*body, *center, &epoch)
+                    .or_else(|e| match fallback {
+                        Some(fallback) => fallback.position(*body, *center, &epoch),
+                        None => Err(e),
+                    })
+                    .map_err(PoliastroError::from)
+            }
+            Self::Custom { position_func, .. } => Ok(position_func(t)),
         }
     }
 }
 
 impl Perturbation for ThirdBodyPerturbation {
-    fn acceleration(&self, t: f64, r: &Vector3, _v: &Vector3, _mu: f64) -> Vector3 {
-        let r_third = self.get_position(t);
-        let mu_third = self.get_mu();
-        third_body_perturbation(r, &r_third, mu_third)
+    /// NaN at an epoch [`ThirdBodyPerturbation::position`] rejects, which
+    /// [`Perturbation::check_span`] rules out before an integration
+    fn acceleration(&self, t: f64, r: &Vector3, v: &Vector3, mu: f64) -> Vector3 {
+        self.try_acceleration(t, r, v, mu)
+            .unwrap_or_else(|_| Vector3::repeat(f64::NAN))
+    }
+
+    fn try_acceleration(&self, t: f64, r: &Vector3, _v: &Vector3, _mu: f64) -> PoliastroResult<Vector3> {
+        let r_third = self.position(t)?;
+        Ok(third_body_perturbation(r, &r_third, self.get_mu()))
     }
 
     fn name(&self) -> &str {
         match self {
             Self::Sun => "Third-Body (Sun)",
             Self::Moon => "Third-Body (Moon)",
-            Self::Custom { body_name, .. } => body_name,
+            Self::Ephemeris { body_name, .. } | Self::Custom { body_name, .. } => body_name,
         }
     }
 
     fn is_time_dependent(&self) -> bool {
         true // Third body positions change with time
     }
+
+    fn check_span(&self, t0: f64, tf: f64) -> PoliastroResult<()> {
+        let Self::Ephemeris { body, center, source, fallback: None, .. } = self else {
+            return Ok(());
+        };
+        match source.time_span(*body, *center) {
+            Some((start, end)) if t0.min(tf) < start || t0.max(tf) > end => {
+                let t = if t0.min(tf) < start { t0.min(tf) } else { t0.max(tf) };
+                Err(PoliastroError::out_of_range(
+                    format!("propagation time for {} ephemeris '{}'", body, source.name()),
+                    t,
+                    start,
+                    end,
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn partials(&self, t: f64, r: &Vector3, _v: &Vector3, _mu: f64) -> (Matrix3, Matrix3) {
+        // ∂a/∂r = μ₃·(3·d·dᵀ/|d|⁵ − I/|d|³) with d = r₃ − r
+        let Ok(r_third) = self.position(t) else {
+            return (Matrix3::repeat(f64::NAN), Matrix3::zeros());
+        };
+        let d = r_third - r;
+        let d_mag = d.norm();
+        if d_mag < 1e-10 {
+            return (Matrix3::zeros(), Matrix3::zeros());
+        }
+        let d3 = d_mag * d_mag * d_mag;
+        let a_r = (d * d.transpose() * (3.0 / (d3 * d_mag * d_mag)) - Matrix3::identity() / d3)
+            * self.get_mu();
+        (a_r, Matrix3::zeros())
+    }
 }
 
 impl std::fmt::Debug for ThirdBodyPerturbation {
@@ -3108,6 +3519,14 @@ impl std::fmt::Debug for ThirdBodyPerturbation {
         match self {
             Self::Sun => write!(f, "ThirdBodyPerturbation::Sun"),
             Self::Moon => write!(f, "ThirdBodyPerturbation::Moon"),
+            Self::Ephemeris { body, center, mu, source, fallback, .. } => f
+                .debug_struct("ThirdBodyPerturbation::Ephemeris")
+                .field("body", body)
+                .field("center", center)
+                .field("mu", mu)
+                .field("source", &source.name())
+                .field("fallback", &fallback.as_ref().map(|fallback| fallback.name()))
+                .finish(),
             Self::Custom { mu, body_name, .. } => {
                 f.debug_struct("ThirdBodyPerturbation::Custom")
                     .field("mu", mu)
@@ -3201,6 +3620,18 @@ impl Perturbation for SolarRadiationPressure {
     fn is_time_dependent(&self) -> bool {
         true // Sun position changes with time
     }
+
+    fn parameter_partial(
+        &self,
+        parameter: DynamicParameter,
+        t: f64,
+        r: &Vector3,
+        v: &Vector3,
+        mu: f64,
+    ) -> Option<Vector3> {
+        (parameter == DynamicParameter::Reflectivity)
+            .then(|| self.acceleration(t, r, v, mu) / self.reflectivity)
+    }
 }
 
 /// Collection of perturbations that can be applied together
@@ -3222,7 +3653,7 @@ impl Perturbation for SolarRadiationPressure {
 /// perts.add(ThirdBodyPerturbation::moon());
 ///
 /// // Use with propagator
-/// let (r, v) = propagate_with_perturbations(&r0, &v0, dt, GM_EARTH, &perts)?;
+/// let (r, v) = propagate_with_perturbations(&r0, &v0, t0, dt, GM_EARTH, &perts, "dopri5", None, None)?;
 /// ```
 #[derive(Default)]
 pub struct PerturbationSet {
@@ -3289,6 +3720,12 @@ impl Perturbation for PerturbationSet {
         self.total_acceleration(t, r, v, mu)
     }
 
+    fn try_acceleration(&self, t: f64, r: &Vector3, v: &Vector3, mu: f64) -> PoliastroResult<Vector3> {
+        self.perturbations
+            .iter()
+            .try_fold(Vector3::zeros(), |total, p| Ok(total + p.try_acceleration(t, r, v, mu)?))
+    }
+
     fn name(&self) -> &str {
         "Combined Perturbations"
     }
@@ -3296,6 +3733,34 @@ impl Perturbation for PerturbationSet {
     fn is_time_dependent(&self) -> bool {
         self.is_time_dependent()
     }
+
+    fn check_span(&self, t0: f64, tf: f64) -> PoliastroResult<()> {
+        self.perturbations.iter().try_for_each(|p| p.check_span(t0, tf))
+    }
+
+    fn partials(&self, t: f64, r: &Vector3, v: &Vector3, mu: f64) -> (Matrix3, Matrix3) {
+        let mut total = (Matrix3::zeros(), Matrix3::zeros());
+        for pert in &self.perturbations {
+            let (a_r, a_v) = pert.partials(t, r, v, mu);
+            total.0 += a_r;
+            total.1 += a_v;
+        }
+        total
+    }
+
+    fn parameter_partial(
+        &self,
+        parameter: DynamicParameter,
+        t: f64,
+        r: &Vector3,
+        v: &Vector3,
+        mu: f64,
+    ) -> Option<Vector3> {
+        self.perturbations
+            .iter()
+            .filter_map(|p| p.parameter_partial(parameter, t, r, v, mu))
+            .reduce(|total, partial| total + partial)
+    }
 }
 
 impl std::fmt::Debug for PerturbationSet {
@@ -3316,6 +3781,7 @@ impl std::fmt::Debug for PerturbationSet {
 /// # Arguments
 /// * `r0` - Initial position (m)
 /// * `v0` - Initial velocity (m/s)
+/// * `t0` - Initial time (TDB seconds since J2000, as passed to the perturbations)
 /// * `dt` - Time step (seconds)
 /// * `mu` - Central body gravitational parameter (m³/s²)
 /// * `perturbations` - Perturbation set or any type implementing Perturbation
@@ -3339,12 +3805,14 @@ impl std::fmt::Debug for PerturbationSet {
 /// perts.add(DragPerturbation::earth(100.0));
 ///
 /// let (r, v) = propagate_with_perturbations(
-///     &r0, &v0, 3600.0, GM_EARTH, &perts, "dopri5", None, None
+///     &r0, &v0, 0.0, 3600.0, GM_EARTH, &perts, "dopri5", None, None
 /// )?;
 /// ```
+#[allow(clippy::too_many_arguments)]
 pub fn propagate_with_perturbations<P: Perturbation + ?Sized>(
     r0: &Vector3,
     v0: &Vector3,
+    t0: f64,
     dt: f64,
     mu: f64,
     perturbations: &P,
@@ -3354,6 +3822,8 @@ pub fn propagate_with_perturbations<P: Perturbation + ?Sized>(
 ) -> PoliastroResult<(Vector3, Vector3)> {
     use crate::core::numerical::{dopri5_integrate, rk4_step};
 
+    perturbations.check_span(t0, t0 + dt)?;
+
     // Create acceleration function combining two-body + perturbations
     let accel_func = |t: f64, state: &nalgebra::DVector<f64>| -> nalgebra::DVector<f64> {
         let r = Vector3::new(state[0], state[1], state[2]);
@@ -3381,7 +3851,7 @@ pub fn propagate_with_perturbations<P: Perturbation + ?Sized>(
         "rk4" => {
             let steps = n_steps.unwrap_or(100);
             let h = dt / steps as f64;
-            let mut t = 0.0;
+            let mut t = t0;
 
             // Integrate using multiple RK4 steps
             for _ in 0..steps {
@@ -3393,7 +3863,7 @@ pub fn propagate_with_perturbations<P: Perturbation + ?Sized>(
         "dopri5" => {
             let tolerance = tol.unwrap_or(1e-8);
             let h0 = dt.abs() / 10.0; // Initial step size guess
-            dopri5_integrate(accel_func, 0.0, &state, dt, h0, tolerance, None)?
+            dopri5_integrate(accel_func, t0, &state, t0 + dt, h0, tolerance, None)?
         }
         _ => {
             return Err(PoliastroError::invalid_state(format!(
@@ -3409,6 +3879,68 @@ pub fn propagate_with_perturbations<P: Perturbation + ?Sized>(
     Ok((r_final, v_final))
 }
 
+/// Propagate orbit with trait-based perturbations, keeping the trajectory
+///
+/// Integrates from `t0` to `t0 + dt` like [`propagate_with_perturbations`]
+/// and returns an [`Ephemeris`] of the state [r, v] built from the dense
+/// output of every step, which can be sampled at any time in the span in one
+/// pass instead of restarting the integration for each sample.
+///
+/// # Arguments
+/// * `r0` - Initial position (m)
+/// * `v0` - Initial velocity (m/s)
+/// * `t0` - Initial time (TDB seconds since J2000, as passed to the perturbations)
+/// * `dt` - Propagation time (seconds, may be negative)
+/// * `mu` - Central body gravitational parameter (m³/s²)
+/// * `perturbations` - Perturbation set or any type implementing Perturbation
+/// * `method` - Integration method: "dopri5" or "dop853"
+/// * `tol` - Integrator tolerance (None = 1e-10)
+///
+/// # Example
+/// ```ignore
+/// let ephemeris = propagate_ephemeris(
+///     &r0, &v0, 0.0, 86400.0, GM_EARTH, &J2Perturbation::earth(), "dop853", None
+/// )?;
+/// let state = ephemeris.evaluate(3600.0)?; // [x, y, z, vx, vy, vz]
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn propagate_ephemeris<P: Perturbation + ?Sized>(
+    r0: &Vector3,
+    v0: &Vector3,
+    t0: f64,
+    dt: f64,
+    mu: f64,
+    perturbations: &P,
+    method: &str,
+    tol: Option<f64>,
+) -> PoliastroResult<Ephemeris> {
+    use crate::core::numerical::{dop853_ephemeris, dopri5_ephemeris};
+
+    perturbations.check_span(t0, t0 + dt)?;
+
+    let accel_func = |t: f64, state: &nalgebra::DVector<f64>| -> nalgebra::DVector<f64> {
+        let r = Vector3::new(state[0], state[1], state[2]);
+        let v = Vector3::new(state[3], state[4], state[5]);
+        let r_mag = r.norm();
+        let a = -mu / (r_mag * r_mag * r_mag) * r + perturbations.acceleration(t, &r, &v, mu);
+        nalgebra::DVector::from_vec(vec![v.x, v.y, v.z, a.x, a.y, a.z])
+    };
+
+    let state = nalgebra::DVector::from_vec(vec![r0.x, r0.y, r0.z, v0.x, v0.y, v0.z]);
+    let tolerance = tol.unwrap_or(1e-10);
+    let h0 = (dt.abs() / 10.0).min(60.0);
+    // Long propagations take many steps
+    let max_steps = Some(10_000_000);
+
+    match method.to_lowercase().as_str() {
+        "dopri5" => dopri5_ephemeris(accel_func, t0, &state, t0 + dt, h0, tolerance, max_steps),
+        "dop853" => dop853_ephemeris(accel_func, t0, &state, t0 + dt, h0, tolerance, max_steps),
+        _ => Err(PoliastroError::invalid_state(format!(
+            "Unknown integration method: {method}. Use 'dopri5' or 'dop853'"
+        ))),
+    }
+}
+
 //==============================================================================
 // TESTS FOR TRAIT-BASED PERTURBATION FRAMEWORK
 //==============================================================================
@@ -3416,7 +3948,7 @@ pub fn propagate_with_perturbations<P: Perturbation + ?Sized>(
 #[cfg(test)]
 mod perturbation_trait_tests {
     use super::*;
-    use crate::core::constants::{GM_EARTH, J2_EARTH, R_EARTH};
+    use crate::core::constants::{GM_EARTH, GM_SUN, J2_EARTH, R_EARTH};
     use approx::assert_relative_eq;
 
     #[test]
@@ -3481,6 +4013,36 @@ mod perturbation_trait_tests {
         assert_relative_eq!(drag_pert.ballistic_coeff, 100.0, epsilon = 1e-15);
     }
 
+    #[test]
+    fn test_drag_with_atmosphere_model() {
+        use crate::propagators::atmosphere::{Jacchia71, SolarActivity};
+
+        let quiet = DragPerturbation::with_atmosphere(
+            Arc::new(Jacchia71::new(SolarActivity::new(70.0, 70.0, 4.0))),
+            100.0,
+        );
+        let active = DragPerturbation::with_atmosphere(
+            Arc::new(Jacchia71::new(SolarActivity::new(250.0, 250.0, 4.0))),
+            100.0,
+        );
+        assert!(quiet.is_time_dependent());
+        assert_eq!(quiet.atmosphere().map(|model| model.name()), Some("Jacchia 1971"));
+        assert!(DragPerturbation::earth(100.0).atmosphere().is_none());
+
+        let t = Epoch::from_gregorian_utc(2024, 6, 1, 0, 0, 0, 0).to_tdb_seconds_since_j2000();
+        let r = Vector3::new(6778e3, 0.0, 0.0); // 400 km altitude
+        let v = Vector3::new(0.0, 7670.0, 0.0);
+
+        let a = quiet.acceleration(t, &r, &v, GM_EARTH);
+        let v_rel = velocity_relative_to_atmosphere(&r, &v);
+        // Opposes the velocity relative to the co-rotating atmosphere
+        assert_relative_eq!(a.normalize(), -v_rel.normalize(), epsilon = 1e-12);
+        assert!(a.norm() > 1e-9 && a.norm() < 1e-6);
+
+        // Solar activity increases drag
+        assert!(active.acceleration(t, &r, &v, GM_EARTH).norm() > 3.0 * a.norm());
+    }
+
     #[test]
     fn test_thirdbody_sun_perturbation() {
         let sun_pert = ThirdBodyPerturbation::sun();
@@ -3523,6 +4085,126 @@ mod perturbation_trait_tests {
         assert!(moon_pert.is_time_dependent());
     }
 
+    #[test]
+    fn test_thirdbody_ephemeris_perturbation() {
+        use crate::core::constants::GM_JUPITER;
+
+        let jupiter = ThirdBodyPerturbation::ephemeris(Body::Jupiter, GM_JUPITER).unwrap();
+
+        let r = Vector3::new(42164e3, 0.0, 0.0); // GEO
+        let v = Vector3::new(0.0, 3075.0, 0.0);
+        let t = 86400.0 * 365.25;
+
+        let a = jupiter.acceleration(t, &r, &v, GM_EARTH);
+
+        // Should match the direct function call with the ephemeris position
+        let epoch = Epoch::from_tdb_seconds_since_j2000(t);
+        let r_jupiter = crate::ephemeris::ephemeris_position(Body::Jupiter, Body::Earth, &epoch).unwrap();
+        let a_direct = third_body_perturbation(&r, &r_jupiter, GM_JUPITER);
+        assert_relative_eq!(a, a_direct, epsilon = 1e-18);
+
+        // Jupiter's tidal acceleration at GEO is of order 1e-11 m/s²
+        assert!(a.norm() > 1e-12 && a.norm() < 1e-9);
+
+        assert_eq!(jupiter.name(), "Third-Body (Jupiter)");
+        assert!(jupiter.is_time_dependent());
+
+        // Bodies the source does not provide are rejected up front
+        assert!(ThirdBodyPerturbation::ephemeris(Body::Naif(2000001), 6.3e10).is_err());
+    }
+
+    /// Geocentric Pluto and Moon at fixed positions, covering one day either
+    /// side of J2000
+    struct BoundedEphemeris;
+
+    impl EphemerisSource for BoundedEphemeris {
+        fn name(&self) -> &str {
+            "bounded"
+        }
+
+        fn state(
+            &self,
+            target: Body,
+            center: Body,
+            epoch: &Epoch,
+        ) -> Result<(Vector3, Vector3), crate::ephemeris::EphemerisError> {
+            use crate::ephemeris::EphemerisError;
+
+            let position = match (target, center) {
+                (Body::Pluto, Body::Earth) => Vector3::new(5.0e12, 0.0, 0.0),
+                (Body::Moon, Body::Earth) => Vector3::new(0.0, 3.844e8, 0.0),
+                _ => {
+                    return Err(EphemerisError::UnsupportedBody {
+                        body: target,
+                        source_name: "bounded".to_string(),
+                    })
+                }
+            };
+            let tdb_seconds = epoch.to_tdb_seconds_since_j2000();
+            if tdb_seconds.abs() > 86400.0 {
+                return Err(EphemerisError::OutOfRange { body: target, center, tdb_seconds });
+            }
+            Ok((position, Vector3::zeros()))
+        }
+
+        fn time_span(&self, _target: Body, _center: Body) -> Option<(f64, f64)> {
+            Some((-86400.0, 86400.0))
+        }
+    }
+
+    #[test]
+    fn test_thirdbody_ephemeris_coverage() {
+        use crate::core::constants::GM_MOON;
+
+        let r = Vector3::new(7000e3, 0.0, 0.0);
+        let v = Vector3::new(0.0, 7546.0, 0.0);
+
+        // Bodies only the source provides are accepted
+        let pluto = ThirdBodyPerturbation::from_source(Body::Pluto, Body::Earth, 8.7e11, Arc::new(BoundedEphemeris))
+            .unwrap();
+        let a = pluto.acceleration(3600.0, &r, &v, GM_EARTH);
+        let a_direct = third_body_perturbation(&r, &Vector3::new(5.0e12, 0.0, 0.0), 8.7e11);
+        assert_relative_eq!(a, a_direct, epsilon = 1e-24);
+        assert!(ThirdBodyPerturbation::from_source(Body::Mars, Body::Earth, 4.3e13, Arc::new(BoundedEphemeris))
+            .is_err());
+
+        // Propagations past the coverage are rejected instead of switching sources
+        assert!(pluto.check_span(0.0, 3600.0).is_ok());
+        assert!(matches!(
+            pluto.check_span(0.0, 2.0 * 86400.0),
+            Err(PoliastroError::OutOfRange { max, .. }) if max == 86400.0
+        ));
+        assert!(pluto.position(86400.0).is_ok());
+        assert!(matches!(pluto.position(2.0 * 86400.0), Err(PoliastroError::ComputationError { .. })));
+        // Force evaluations outside the coverage report the error, or NaN
+        // where the signature cannot, rather than panicking
+        assert!(pluto.try_acceleration(2.0 * 86400.0, &r, &v, GM_EARTH).is_err());
+        assert!(pluto.acceleration(2.0 * 86400.0, &r, &v, GM_EARTH).iter().all(|a| a.is_nan()));
+        assert!(pluto.partials(2.0 * 86400.0, &r, &v, GM_EARTH).0.iter().all(|a| a.is_nan()));
+        let mut set = PerturbationSet::new();
+        set.add(pluto.clone());
+        assert_relative_eq!(set.try_acceleration(3600.0, &r, &v, GM_EARTH).unwrap(), a_direct, epsilon = 1e-24);
+        assert!(set.try_acceleration(2.0 * 86400.0, &r, &v, GM_EARTH).is_err());
+        assert!(propagate_with_perturbations(&r, &v, 0.0, 2.0 * 86400.0, GM_EARTH, &set, "rk4", None, None).is_err());
+
+        // The analytic fallback is opt-in, and only for bodies it provides
+        assert!(pluto.with_analytic_fallback().is_err());
+        let moon = ThirdBodyPerturbation::from_source(Body::Moon, Body::Earth, GM_MOON, Arc::new(BoundedEphemeris))
+            .unwrap()
+            .with_analytic_fallback()
+            .unwrap();
+        assert!(moon.check_span(0.0, 2.0 * 86400.0).is_ok());
+        let t = 2.0 * 86400.0;
+        let r_moon = AnalyticEphemeris
+            .position(Body::Moon, Body::Earth, &Epoch::from_tdb_seconds_since_j2000(t))
+            .unwrap();
+        assert_relative_eq!(
+            moon.acceleration(t, &r, &v, GM_EARTH),
+            third_body_perturbation(&r, &r_moon, GM_MOON),
+            epsilon = 1e-18
+        );
+    }
+
     #[test]
     fn test_thirdbody_custom_perturbation() {
         fn custom_body_pos(_t: f64) -> Vector3 {
@@ -3666,7 +4348,7 @@ mod perturbation_trait_tests {
         let dt = 3600.0;
 
         let result = propagate_with_perturbations(
-            &r0, &v0, dt, GM_EARTH, &perts, "rk4", Some(100), None
+            &r0, &v0, 0.0, dt, GM_EARTH, &perts, "rk4", Some(100), None
         );
 
         assert!(result.is_ok());
@@ -3694,7 +4376,7 @@ mod perturbation_trait_tests {
         let dt = 600.0; // 10 minutes
 
         let result = propagate_with_perturbations(
-            &r0, &v0, dt, GM_EARTH, &perts, "dopri5", None, Some(1e-8)
+            &r0, &v0, 0.0, dt, GM_EARTH, &perts, "dopri5", None, Some(1e-8)
         );
 
         assert!(result.is_ok());
@@ -3713,6 +4395,32 @@ mod perturbation_trait_tests {
         assert!(vel_diff > 0.1); // Velocity changed by at least 0.1 m/s
     }
 
+    #[test]
+    fn test_propagate_with_perturbations_epoch() {
+        use crate::core::constants::GM_SUN;
+
+        // The perturbations see the time from t0, not from J2000
+        let sun = ThirdBodyPerturbation::custom(GM_SUN, sun_position_simple, "Sun");
+        let r0 = Vector3::new(42164e3, 0.0, 0.0); // GEO
+        let v0 = Vector3::new(0.0, 3075.0, 0.0);
+        let t0 = 0.25 * 365.25 * 86400.0;
+        let dt = 86400.0;
+
+        let ephemeris = propagate_ephemeris(&r0, &v0, t0, dt, GM_EARTH, &sun, "dopri5", Some(1e-10)).unwrap();
+        let state = ephemeris.evaluate(t0 + dt).unwrap();
+        let r_ref = Vector3::new(state[0], state[1], state[2]);
+        let (r, _) = propagate_with_perturbations(&r0, &v0, t0, dt, GM_EARTH, &sun, "dopri5", None, Some(1e-10))
+            .unwrap();
+        assert_relative_eq!(r, r_ref, epsilon = 1e-3);
+        let (r, _) = propagate_with_perturbations(&r0, &v0, t0, dt, GM_EARTH, &sun, "rk4", Some(2000), None).unwrap();
+        assert_relative_eq!(r, r_ref, epsilon = 1e-1);
+
+        // A quarter year from J2000 the Sun lies 90° away, so its tidal pull differs
+        let (r_j2000, _) =
+            propagate_with_perturbations(&r0, &v0, 0.0, dt, GM_EARTH, &sun, "dopri5", None, Some(1e-10)).unwrap();
+        assert!((r_j2000 - r).norm() > 100.0);
+    }
+
     #[test]
     fn test_propagate_with_perturbations_invalid_method() {
         let perts = PerturbationSet::new();
@@ -3721,9 +4429,129 @@ mod perturbation_trait_tests {
         let v0 = Vector3::new(0.0, 7546.0, 0.0);
 
         let result = propagate_with_perturbations(
-            &r0, &v0, 600.0, GM_EARTH, &perts, "invalid", None, None
+            &r0, &v0, 0.0, 600.0, GM_EARTH, &perts, "invalid", None, None
         );
 
         assert!(result.is_err());
     }
+    #[test]
+    fn test_propagate_ephemeris_j2() {
+        let mut perts = PerturbationSet::new();
+        perts.add(J2Perturbation::earth());
+
+        let r0 = Vector3::new(7000e3, 0.0, 0.0);
+        let v0 = Vector3::new(0.0, 6000.0, 4000.0);
+        let ephemeris =
+            propagate_ephemeris(&r0, &v0, 0.0, 7200.0, GM_EARTH, &perts, "dop853", Some(1e-12))
+                .unwrap();
+
+        // Samples agree with restarting the propagation at each time
+        for t in [0.0, 123.4, 2500.0, 5555.5, 7200.0] {
+            let state = ephemeris.evaluate(t).unwrap();
+            let (r, v) = if t == 0.0 {
+                (r0, v0)
+            } else {
+                propagate_j2_dop853(&r0, &v0, t, GM_EARTH, J2_EARTH, R_EARTH, Some(1e-12)).unwrap()
+            };
+            assert_relative_eq!(Vector3::new(state[0], state[1], state[2]), r, epsilon = 1e-2);
+            assert_relative_eq!(Vector3::new(state[3], state[4], state[5]), v, epsilon = 1e-5);
+        }
+
+        assert!(ephemeris.evaluate(7300.0).is_err());
+        assert!(
+            propagate_ephemeris(&r0, &v0, 0.0, 600.0, GM_EARTH, &perts, "rk4", None).is_err()
+        );
+    }
+
+    fn assert_partials_match(p: &dyn Perturbation, r: &Vector3, v: &Vector3, tol: f64) {
+        let t = 0.0;
+        let (a_r, a_v) = p.partials(t, r, v, GM_EARTH);
+        let (fd_r, fd_v) = finite_difference_partials(p, t, r, v, GM_EARTH);
+        let scale_r = fd_r.abs().max().max(1e-30);
+        let scale_v = fd_v.abs().max().max(1e-30);
+        assert_relative_eq!(a_r / scale_r, fd_r / scale_r, epsilon = tol);
+        assert_relative_eq!(a_v / scale_v, fd_v / scale_v, epsilon = tol);
+    }
+
+    #[test]
+    fn test_analytic_partials_match_finite_differences() {
+        let r = Vector3::new(6500e3, 1200e3, 2100e3);
+        let v = Vector3::new(-1500.0, 6200.0, 3900.0);
+
+        assert_partials_match(&J2Perturbation::earth(), &r, &v, 1e-6);
+        assert_partials_match(&DragPerturbation::earth(50.0), &r, &v, 1e-6);
+        // The differences of the large direct and indirect Sun terms cancel
+        // to a few digits
+        let sun = |_| Vector3::new(1.4e11, 3e10, 1e10);
+        assert_partials_match(
+            &ThirdBodyPerturbation::custom(GM_SUN, sun, "Sun"),
+            &r,
+            &v,
+            1e-4,
+        );
+
+        // Drag partials are dominated by the velocity and density gradient
+        let (a_r, a_v) = DragPerturbation::earth(50.0).partials(0.0, &r, &v, GM_EARTH);
+        assert!(a_r.norm() > 0.0 && a_v.norm() > 0.0);
+        let (_, j2_v) = J2Perturbation::earth().partials(0.0, &r, &v, GM_EARTH);
+        assert_eq!(j2_v, Matrix3::zeros());
+    }
+
+    #[test]
+    fn test_perturbation_set_partials_and_parameters() {
+        let r = Vector3::new(6700e3, 0.0, 500e3);
+        let v = Vector3::new(0.0, 7000.0, 2000.0);
+        let t = 1e7;
+
+        let j2 = J2Perturbation::earth();
+        let drag = DragPerturbation::earth(50.0);
+        let srp = SolarRadiationPressure::earth(0.02, 1.5);
+        let mut perts = PerturbationSet::new();
+        perts.add(j2);
+        perts.add(drag.clone());
+        perts.add(srp);
+
+        let (a_r, a_v) = perts.partials(t, &r, &v, GM_EARTH);
+        let (j2_r, _) = j2.partials(t, &r, &v, GM_EARTH);
+        let (drag_r, drag_v) = drag.partials(t, &r, &v, GM_EARTH);
+        let (srp_r, srp_v) = srp.partials(t, &r, &v, GM_EARTH);
+        assert_relative_eq!(a_r, j2_r + drag_r + srp_r, epsilon = 1e-20);
+        assert_relative_eq!(a_v, drag_v + srp_v, epsilon = 1e-20);
+
+        // Parameter partials of linear (C_r) and inverse (B) dependencies
+        let da_db = perts
+            .parameter_partial(DynamicParameter::BallisticCoefficient, t, &r, &v, GM_EARTH)
+            .unwrap();
+        let h = 1e-3;
+        let fd = (DragPerturbation::earth(50.0 + h).acceleration(t, &r, &v, GM_EARTH)
+            - DragPerturbation::earth(50.0 - h).acceleration(t, &r, &v, GM_EARTH))
+            / (2.0 * h);
+        assert_relative_eq!(da_db, fd, max_relative = 1e-6);
+
+        let da_dcr = perts
+            .parameter_partial(DynamicParameter::Reflectivity, t, &r, &v, GM_EARTH)
+            .unwrap();
+        assert_relative_eq!(
+            da_dcr * 1.5,
+            srp.acceleration(t, &r, &v, GM_EARTH),
+            epsilon = 1e-20
+        );
+
+        assert!(j2
+            .parameter_partial(DynamicParameter::Reflectivity, t, &r, &v, GM_EARTH)
+            .is_none());
+        assert!(PerturbationSet::new()
+            .parameter_partial(DynamicParameter::BallisticCoefficient, t, &r, &v, GM_EARTH)
+            .is_none());
+
+        assert_eq!(
+            DynamicParameter::from_name("B").unwrap(),
+            DynamicParameter::BallisticCoefficient
+        );
+        assert_eq!(
+            DynamicParameter::from_name(DynamicParameter::Reflectivity.name()).unwrap(),
+            DynamicParameter::Reflectivity
+        );
+        assert!(DynamicParameter::from_name("mass").is_err());
+    }
 }
@@ -0,0 +1,1587 @@
+//! Stateful numerical orbit propagator
+//!
+//! [`NumericalPropagator`] bundles everything the `propagate_*` free
+//! functions take piecemeal: the central body, a [`PerturbationSet`] force
+//! model, the integrator and its settings, and a state tied to an [`Epoch`].
+//! Each propagation starts from the current state and leaves the propagator
+//! at the target epoch, so a trajectory can be advanced leg by leg.
+//!
+//! The time passed to the perturbations is TDB seconds since J2000, so
+//! time-dependent forces (third bodies, SRP, drag with space weather) see the
+//! true epoch.
+//!
+//! Besides the Runge-Kutta methods, the multistep integrators of
+//! [`crate::core::multistep`] (fixed-step Gauss-Jackson and variable
+//! step and order Adams-Bashforth-Moulton) are available. Their history of
+//! past steps is kept from one propagation to the next, so advancing leg by
+//! leg costs no more than a single propagation; changing the state restarts
+//! them, so an impulsive maneuver is modelled by propagating to the burn,
+//! [`NumericalPropagator::apply_impulse`], and propagating on.
+//!
+//! # Example
+//! ```rust,ignore
+//! use astrora_core::core::constants::GM_EARTH;
+//! use astrora_core::propagators::perturbations::{DragPerturbation, J2Perturbation};
+//! use astrora_core::propagators::propagator::{Integrator, NumericalPropagator};
+//!
+//! let mut propagator = NumericalPropagator::new(r0, v0, epoch, GM_EARTH)
+//!     .with_integrator(Integrator::Dopri5)
+//!     .with_tolerance(1e-9);
+//! propagator.add_perturbation(J2Perturbation::earth());
+//! propagator.add_perturbation(DragPerturbation::earth(100.0));
+//!
+//! let (r, v) = propagator.propagate_to(epoch + one_day)?;
+//! let samples = propagator.propagate_many(&hourly_epochs)?;
+//! ```
+
+use std::sync::Arc;
+
+use nalgebra::DVector;
+use numpy::{PyArray1, PyArray2, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::coordinates::frames::state_from_arrays;
+use crate::core::constants::{GM_MOON, GM_SUN};
+use crate::core::error::{PoliastroError, PoliastroResult};
+use crate::core::linalg::Vector3;
+use crate::core::multistep::{
+    AdamsBashforthMoulton, GaussJackson, DEFAULT_ABM_ORDER, MAX_ABM_ORDER,
+};
+use crate::core::numerical::{
+    dop853_integrate, dop853_interpolant, dop853_trial, dopri5_interpolant, dopri5_trial, rk4_step,
+    Dop853Coefficients, DopriCoefficients, Ephemeris, TrialStep,
+};
+use crate::core::time::Epoch;
+use crate::ephemeris::Body;
+use crate::propagators::gravity_field::{GravityField, SphericalHarmonicGravity};
+use crate::propagators::perturbations::{
+    DragPerturbation, DynamicParameter, J2Perturbation, Perturbation, PerturbationSet,
+    SolarRadiationPressure, ThirdBodyPerturbation,
+};
+use crate::propagators::stm::{propagate_stm_perturbed, VariationalState};
+
+/// Default error tolerance of the adaptive integrators
+pub const DEFAULT_TOLERANCE: f64 = 1e-10;
+
+/// Default step size (s): the fixed step of RK4 and Gauss-Jackson, and the
+/// largest initial step of the adaptive integrators
+pub const DEFAULT_STEP_SIZE: f64 = 60.0;
+
+/// Default maximum number of integration steps per propagation
+pub const DEFAULT_MAX_STEPS: usize = 10_000_000;
+
+/// Fraction of a fixed step within which a grid point is taken as the end of
+/// a propagation
+const GRID_TOLERANCE: f64 = 1e-6;
+
+/// Largest gap (s) between the end of one propagation and the start of the
+/// next for the multistep history to carry over (epochs have nanosecond
+/// resolution)
+const RESUME_TOLERANCE: f64 = 1e-6;
+
+/// Integration method of a [`NumericalPropagator`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Integrator {
+    /// Classic 4th order Runge-Kutta with a fixed step
+    Rk4,
+    /// Dormand-Prince 5(4) with adaptive steps
+    Dopri5,
+    /// Dormand-Prince 8(5,3) with adaptive steps
+    #[default]
+    Dop853,
+    /// Adams-Bashforth-Moulton predictor-corrector with adaptive steps and
+    /// orders up to the given maximum
+    AdamsBashforthMoulton { order: usize },
+    /// 8th order Gauss-Jackson with a fixed step
+    GaussJackson,
+}
+
+impl Integrator {
+    /// Integrator from its name: "rk4", "dopri5", "dop853",
+    /// "gauss_jackson" (or "gj"), or "abm" followed by an optional maximum
+    /// order (e.g. "abm10"; 8 by default)
+    ///
+    /// # Errors
+    /// Returns an error for an unknown name or an unsupported ABM order
+    pub fn from_name(name: &str) -> PoliastroResult<Self> {
+        let lower = name.to_lowercase();
+        match lower.as_str() {
+            "rk4" => Ok(Self::Rk4),
+            "dopri5" => Ok(Self::Dopri5),
+            "dop853" => Ok(Self::Dop853),
+            "gauss_jackson" | "gj" => Ok(Self::GaussJackson),
+            "abm" => Ok(Self::AdamsBashforthMoulton {
+                order: DEFAULT_ABM_ORDER,
+            }),
+            _ => match lower.strip_prefix("abm").map(str::parse::<usize>) {
+                Some(Ok(order)) if (1..=MAX_ABM_ORDER).contains(&order) => {
+                    Ok(Self::AdamsBashforthMoulton { order })
+                }
+                Some(Ok(order)) => Err(PoliastroError::out_of_range(
+                    "order",
+                    order as f64,
+                    1.0,
+                    MAX_ABM_ORDER as f64,
+                )),
+                _ => Err(PoliastroError::invalid_state(format!(
+                    "Unknown integration method: {name}. Use 'rk4', 'dopri5', 'dop853', \
+                     'gauss_jackson' or 'abm'"
+                ))),
+            },
+        }
+    }
+
+    /// Name of the integrator
+    pub fn name(&self) -> String {
+        match self {
+            Self::Rk4 => "rk4".to_string(),
+            Self::Dopri5 => "dopri5".to_string(),
+            Self::Dop853 => "dop853".to_string(),
+            Self::AdamsBashforthMoulton { order } => format!("abm{order}"),
+            Self::GaussJackson => "gauss_jackson".to_string(),
+        }
+    }
+
+    /// Whether the integrator controls its step size
+    pub fn is_adaptive(&self) -> bool {
+        matches!(
+            self,
+            Self::Dopri5 | Self::Dop853 | Self::AdamsBashforthMoulton { .. }
+        )
+    }
+
+    /// Whether the integrator provides dense output for an [`Ephemeris`]
+    fn has_dense_output(&self) -> bool {
+        matches!(self, Self::Dopri5 | Self::Dop853)
+    }
+
+    /// Description used in error messages
+    fn operation(&self) -> &'static str {
+        match self {
+            Self::Rk4 => "RK4 integration",
+            Self::Dopri5 => "DOPRI5 integration",
+            Self::Dop853 => "DOP853 integration",
+            Self::AdamsBashforthMoulton { .. } => "Adams-Bashforth-Moulton integration",
+            Self::GaussJackson => "Gauss-Jackson integration",
+        }
+    }
+}
+
+/// Numerical orbit propagator with a pluggable force model
+///
+/// Two-body gravity of the central body plus the perturbations in its
+/// [`PerturbationSet`], integrated in an inertial frame centred on the body.
+#[pyclass(module = "astrora._core")]
+#[derive(Debug)]
+pub struct NumericalPropagator {
+    mu: f64,
+    perturbations: PerturbationSet,
+    integrator: Integrator,
+    tolerance: f64,
+    step_size: f64,
+    min_step_size: f64,
+    max_step_size: f64,
+    max_steps: usize,
+    epoch: Epoch,
+    position: Vector3,
+    velocity: Vector3,
+    /// Multistep integration the next propagation may continue
+    multistep: Option<MultistepRun>,
+}
+
+/// Multistep integrator state kept between propagations
+#[derive(Debug, Clone)]
+enum Multistep {
+    AdamsBashforthMoulton(AdamsBashforthMoulton),
+    GaussJackson(GaussJackson),
+}
+
+/// A multistep integration and the state it left the propagator in
+#[derive(Debug, Clone)]
+struct MultistepRun {
+    integrator: Multistep,
+    /// Integrator, tolerance, step size and step limits it was started with
+    settings: (Integrator, f64, f64, f64, f64),
+    direction: f64,
+    t: f64,
+    y: DVector<f64>,
+}
+
+impl NumericalPropagator {
+    /// Create a two-body propagator from a state at `epoch`
+    ///
+    /// Uses DOP853 with the default tolerance and step size; add
+    /// perturbations with [`Self::add_perturbation`] or
+    /// [`Self::with_perturbations`].
+    ///
+    /// # Arguments
+    /// * `position` - Position (m)
+    /// * `velocity` - Velocity (m/s)
+    /// * `epoch` - Epoch of the state
+    /// * `mu` - Central body gravitational parameter (m³/s²)
+    pub fn new(position: Vector3, velocity: Vector3, epoch: Epoch, mu: f64) -> Self {
+        Self {
+            mu,
+            perturbations: PerturbationSet::new(),
+            integrator: Integrator::default(),
+            tolerance: DEFAULT_TOLERANCE,
+            step_size: DEFAULT_STEP_SIZE,
+            min_step_size: 0.0,
+            max_step_size: f64::INFINITY,
+            max_steps: DEFAULT_MAX_STEPS,
+            epoch,
+            position,
+            velocity,
+            multistep: None,
+        }
+    }
+
+    /// Use the given force model (replacing any perturbations added so far)
+    pub fn with_perturbations(mut self, perturbations: PerturbationSet) -> Self {
+        self.perturbations = perturbations;
+        self.multistep = None;
+        self
+    }
+
+    /// Use the given integrator
+    pub fn with_integrator(mut self, integrator: Integrator) -> Self {
+        self.integrator = integrator;
+        self
+    }
+
+    /// Set the error tolerance of the adaptive integrators
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the step size (s): the fixed step of RK4 and Gauss-Jackson, or
+    /// the largest initial step of the adaptive integrators
+    pub fn with_step_size(mut self, step_size: f64) -> Self {
+        self.step_size = step_size;
+        self
+    }
+
+    /// Keep the steps of the adaptive integrators (DOPRI5, DOP853 and ABM)
+    /// between `min_step_size` and `max_step_size` (s)
+    ///
+    /// A propagation fails if the tolerance calls for a step below the
+    /// minimum; only the last step before the target may be shorter.
+    pub fn with_step_limits(mut self, min_step_size: f64, max_step_size: f64) -> Self {
+        self.min_step_size = min_step_size;
+        self.max_step_size = max_step_size;
+        self
+    }
+
+    /// Set the maximum number of steps per propagation
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Add a perturbation to the force model
+    pub fn add_perturbation<P: Perturbation + 'static>(&mut self, perturbation: P) {
+        self.perturbations.add(perturbation);
+        self.multistep = None;
+    }
+
+    /// The force model
+    pub fn perturbations(&self) -> &PerturbationSet {
+        &self.perturbations
+    }
+
+    /// Central body gravitational parameter (m³/s²)
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// The integrator
+    pub fn integrator(&self) -> Integrator {
+        self.integrator
+    }
+
+    /// Error tolerance of the adaptive integrators
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Step size (s)
+    pub fn step_size(&self) -> f64 {
+        self.step_size
+    }
+
+    /// Smallest and largest steps of the adaptive integrators (s)
+    pub fn step_limits(&self) -> (f64, f64) {
+        (self.min_step_size, self.max_step_size)
+    }
+
+    /// Maximum number of steps per propagation
+    pub fn max_steps(&self) -> usize {
+        self.max_steps
+    }
+
+    /// Epoch of the current state
+    pub fn epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Current position (m)
+    pub fn position(&self) -> Vector3 {
+        self.position
+    }
+
+    /// Current velocity (m/s)
+    pub fn velocity(&self) -> Vector3 {
+        self.velocity
+    }
+
+    /// Reset the state, e.g. after a maneuver or an orbit determination update
+    pub fn set_state(&mut self, position: Vector3, velocity: Vector3, epoch: Epoch) {
+        self.position = position;
+        self.velocity = velocity;
+        self.epoch = epoch;
+    }
+
+    /// Apply an impulsive maneuver at the current epoch
+    ///
+    /// The next propagation starts afresh from the new velocity; the
+    /// multistep integrators discard their history and rebuild it with
+    /// Runge-Kutta start-up steps rather than differencing across the
+    /// discontinuity.
+    pub fn apply_impulse(&mut self, delta_v: Vector3) {
+        self.velocity += delta_v;
+    }
+
+    /// Propagate to `epoch` (before or after the current one)
+    ///
+    /// # Returns
+    /// Position and velocity at `epoch`, which become the current state
+    ///
+    /// # Errors
+    /// Returns an error if the integration fails or exceeds the step limit
+    pub fn propagate_to(&mut self, epoch: Epoch) -> PoliastroResult<(Vector3, Vector3)> {
+        self.propagate_to_with_callback(epoch, |_, _, _| true)
+    }
+
+    /// Propagate by `dt` seconds (may be negative)
+    ///
+    /// # Errors
+    /// Returns an error if the integration fails or exceeds the step limit
+    pub fn propagate_by(&mut self, dt: f64) -> PoliastroResult<(Vector3, Vector3)> {
+        let target = Epoch::from_tdb_seconds_since_j2000(self.t() + dt);
+        self.propagate_to(target)
+    }
+
+    /// Propagate to `epoch`, calling `callback` after every accepted step
+    ///
+    /// The callback receives the epoch, position and velocity at the end of
+    /// the step and returns whether to continue; returning `false` stops the
+    /// propagation there, leaving the propagator at that step.
+    ///
+    /// # Returns
+    /// Position and velocity where the propagation ended
+    ///
+    /// # Errors
+    /// Returns an error if the integration fails or exceeds the step limit
+    pub fn propagate_to_with_callback<C>(
+        &mut self,
+        epoch: Epoch,
+        mut callback: C,
+    ) -> PoliastroResult<(Vector3, Vector3)>
+    where
+        C: FnMut(Epoch, &Vector3, &Vector3) -> bool,
+    {
+        let (t0, tf) = (self.t(), epoch.to_tdb_seconds_since_j2000());
+        // Report the requested epoch itself at the end of the propagation
+        let epoch_at = |t: f64| {
+            if t == tf {
+                epoch
+            } else {
+                Epoch::from_tdb_seconds_since_j2000(t)
+            }
+        };
+        let previous = self.multistep.take();
+        let (t, y, run) = self.integrate(t0, tf, previous, |t, y| {
+            let (r, v) = split_state(y);
+            callback(epoch_at(t), &r, &v)
+        })?;
+
+        let (r, v) = split_state(&y);
+        self.set_state(r, v, epoch_at(t));
+        self.multistep = run;
+        Ok((r, v))
+    }
+
+    /// Propagate to each of `epochs` in one pass
+    ///
+    /// The Runge-Kutta adaptive integrators integrate once over the span of
+    /// `epochs` (see [`Self::ephemeris_to`]) and interpolate, instead of
+    /// restarting for every sample; the other integrators propagate from
+    /// sample to sample, the multistep ones continuing their history. Either
+    /// way the propagator is left at the last of `epochs`.
+    ///
+    /// # Returns
+    /// Position and velocity at each epoch
+    ///
+    /// # Errors
+    /// Returns an error if the integration fails or exceeds the step limit
+    pub fn propagate_many(&mut self, epochs: &[Epoch]) -> PoliastroResult<Vec<(Vector3, Vector3)>> {
+        let Some(&last) = epochs.last() else {
+            return Ok(Vec::new());
+        };
+
+        if !self.integrator.has_dense_output() {
+            return epochs
+                .iter()
+                .map(|&epoch| self.propagate_to(epoch))
+                .collect();
+        }
+
+        let t0 = self.t();
+        let times: Vec<f64> = epochs
+            .iter()
+            .map(Epoch::to_tdb_seconds_since_j2000)
+            .collect();
+        let t_min = times.iter().copied().fold(t0, f64::min);
+        let t_max = times.iter().copied().fold(t0, f64::max);
+        let backward = (t_min < t0)
+            .then(|| self.ephemeris_between(t0, t_min))
+            .transpose()?;
+        let forward = (t_max > t0)
+            .then(|| self.ephemeris_between(t0, t_max))
+            .transpose()?;
+
+        let mut states = Vec::with_capacity(times.len());
+        for &t in &times {
+            let ephemeris = if t > t0 {
+                forward.as_ref()
+            } else {
+                backward.as_ref()
+            };
+            states.push(match ephemeris {
+                Some(ephemeris) => split_state(&ephemeris.evaluate(t)?),
+                None => (self.position, self.velocity),
+            });
+        }
+
+        let (r, v) = states[states.len() - 1];
+        self.set_state(r, v, last);
+        Ok(states)
+    }
+
+    /// Propagate to `epoch` together with the state transition matrix and
+    /// the sensitivities to `parameters`
+    ///
+    /// The variational equations are integrated with DOP853 at the
+    /// propagator's tolerance, whatever the selected integrator (see
+    /// [`propagate_stm_perturbed`]).
+    ///
+    /// # Errors
+    /// Returns an error if no perturbation depends on one of `parameters`,
+    /// or if the integration fails
+    pub fn propagate_with_stm(
+        &mut self,
+        epoch: Epoch,
+        parameters: &[DynamicParameter],
+    ) -> PoliastroResult<VariationalState> {
+        let t0 = self.t();
+        let dt = epoch.to_tdb_seconds_since_j2000() - t0;
+        let state = propagate_stm_perturbed(
+            &self.position,
+            &self.velocity,
+            t0,
+            dt,
+            self.mu,
+            &self.perturbations,
+            parameters,
+            Some(self.tolerance),
+        )?;
+        self.set_state(state.position, state.velocity, epoch);
+        Ok(state)
+    }
+
+    /// Ephemeris of the state [r, v] from the current epoch to `epoch`,
+    /// without moving the propagator
+    ///
+    /// Times in the ephemeris are TDB seconds since J2000.
+    ///
+    /// # Errors
+    /// Returns an error for integrators other than DOPRI5 and DOP853, if
+    /// `epoch` is the current epoch, or if the integration fails
+    pub fn ephemeris_to(&self, epoch: Epoch) -> PoliastroResult<Ephemeris> {
+        self.ephemeris_between(self.t(), epoch.to_tdb_seconds_since_j2000())
+    }
+
+    fn t(&self) -> f64 {
+        self.epoch.to_tdb_seconds_since_j2000()
+    }
+
+    fn state(&self) -> DVector<f64> {
+        let (r, v) = (self.position, self.velocity);
+        DVector::from_vec(vec![r.x, r.y, r.z, v.x, v.y, v.z])
+    }
+
+    /// Total acceleration at (r, v)
+    fn acceleration(&self, t: f64, r: &Vector3, v: &Vector3) -> Vector3 {
+        let r_mag = r.norm();
+        -self.mu / (r_mag * r_mag * r_mag) * r + self.perturbations.acceleration(t, r, v, self.mu)
+    }
+
+    /// Time derivative of the state [r, v]
+    fn derivative(&self, t: f64, y: &DVector<f64>) -> DVector<f64> {
+        let (r, v) = split_state(y);
+        let a = self.acceleration(t, &r, &v);
+        DVector::from_vec(vec![v.x, v.y, v.z, a.x, a.y, a.z])
+    }
+
+    fn initial_step(&self, dt: f64) -> f64 {
+        (dt.abs() / 10.0)
+            .min(self.step_size)
+            .min(self.max_step_size)
+    }
+
+    fn ephemeris_between(&self, t0: f64, tf: f64) -> PoliastroResult<Ephemeris> {
+        if !self.integrator.has_dense_output() {
+            return Err(PoliastroError::invalid_state(
+                "An ephemeris needs an adaptive integrator ('dopri5' or 'dop853')",
+            ));
+        }
+        if tf == t0 {
+            return Err(PoliastroError::invalid_parameter(
+                "tf",
+                tf,
+                "must differ from t0 for an ephemeris",
+            ));
+        }
+        self.perturbations.check_span(t0, tf)?;
+
+        let f = |t: f64, y: &DVector<f64>| self.derivative(t, y);
+        let coefficients = Dop853Coefficients::new();
+        let mut segments = Vec::new();
+        self.adaptive_loop(t0, tf, |step| {
+            segments.push(match self.integrator {
+                Integrator::Dopri5 => {
+                    dopri5_interpolant(step.t, step.y, step.h, &step.trial.y, &step.trial.k)
+                }
+                _ => dop853_interpolant(
+                    &f,
+                    step.t,
+                    step.y,
+                    step.h,
+                    &step.trial.y,
+                    &step.trial.k,
+                    &coefficients,
+                ),
+            });
+            true
+        })?;
+        Ephemeris::from_segments(segments)
+    }
+
+    /// Integrate the current state from `t0` to `tf` with DOPRI5 or DOP853,
+    /// under the tolerance and step limits, until `on_step` returns false
+    ///
+    /// Returns the final time and state. A step ending within a few units in
+    /// the last place of `tf` ends the integration at `tf`.
+    fn adaptive_loop<C>(
+        &self,
+        t0: f64,
+        tf: f64,
+        mut on_step: C,
+    ) -> PoliastroResult<(f64, DVector<f64>)>
+    where
+        C: FnMut(&AcceptedStep) -> bool,
+    {
+        let f = |t: f64, y: &DVector<f64>| self.derivative(t, y);
+        let (dopri5, dop853) = (DopriCoefficients::new(), Dop853Coefficients::new());
+        let trial = |t: f64, y: &DVector<f64>, h: f64| match self.integrator {
+            Integrator::Dopri5 => dopri5_trial(&f, t, y, h, self.tolerance, &dopri5),
+            _ => dop853_trial(&f, t, y, h, self.tolerance, &dop853),
+        };
+        let direction = (tf - t0).signum();
+        let end_tolerance = 4.0 * f64::EPSILON * tf.abs().max(t0.abs()).max(1.0);
+        let mut t = t0;
+        let mut y = self.state();
+        let mut h = self.initial_step(tf - t0);
+
+        for _step in 0..self.max_steps {
+            let remaining = tf - t;
+            if remaining.abs() <= end_tolerance {
+                return Ok((tf, y));
+            }
+
+            // The last step lands on tf rather than short of it or past it
+            let last = h >= remaining.abs() - end_tolerance;
+            if !last && h < self.min_step_size.max(end_tolerance) {
+                return Err(PoliastroError::NumericalInstability {
+                    operation: self.integrator.operation().to_string(),
+                    details: format!("Step size became too small at t = {t} (h = {h})"),
+                });
+            }
+            let h_signed = if last { remaining } else { h * direction };
+
+            let step = trial(t, &y, h_signed);
+            if step.error <= self.tolerance {
+                let t_end = if last { tf } else { t + h_signed };
+                let accepted = AcceptedStep {
+                    t,
+                    y: &y,
+                    h: h_signed,
+                    t_end,
+                    trial: &step,
+                };
+                let go_on = on_step(&accepted);
+                t = t_end;
+                y = step.y;
+                if !go_on || last {
+                    return Ok((t, y));
+                }
+            }
+            h = step.h_next.abs().min(self.max_step_size);
+        }
+
+        Err(PoliastroError::convergence_failure(
+            self.integrator.operation(),
+            self.max_steps,
+            self.tolerance,
+        ))
+    }
+
+    /// Integrate the current state from `t0` to `tf`, calling `on_step` after
+    /// every accepted step until it returns false
+    ///
+    /// The multistep integration of the previous propagation, `previous`, is
+    /// continued if it ended at the current state with the same settings.
+    /// Returns the final time and state, with the multistep integration the
+    /// next propagation may continue.
+    #[allow(clippy::type_complexity)]
+    fn integrate<C>(
+        &self,
+        t0: f64,
+        tf: f64,
+        previous: Option<MultistepRun>,
+        mut on_step: C,
+    ) -> PoliastroResult<(f64, DVector<f64>, Option<MultistepRun>)>
+    where
+        C: FnMut(f64, &DVector<f64>) -> bool,
+    {
+        let f = |t: f64, y: &DVector<f64>| self.derivative(t, y);
+        let mut t = t0;
+        let mut y = self.state();
+        if tf == t0 {
+            return Ok((t, y, previous));
+        }
+        self.perturbations.check_span(t0, tf)?;
+
+        let operation = self.integrator.operation();
+        let direction = (tf - t0).signum();
+        let settings = (
+            self.integrator,
+            self.tolerance,
+            self.step_size,
+            self.min_step_size,
+            self.max_step_size,
+        );
+        let resumed = previous
+            .filter(|run| {
+                run.settings == settings
+                    && run.direction == direction
+                    && (run.t - t0).abs() <= RESUME_TOLERANCE
+                    && run.y == y
+            })
+            .map(|run| run.integrator);
+        let run = |integrator, t, y: &DVector<f64>| MultistepRun {
+            integrator,
+            settings,
+            direction,
+            t,
+            y: y.clone(),
+        };
+
+        match self.integrator {
+            Integrator::AdamsBashforthMoulton { order } => {
+                let mut abm = match resumed {
+                    Some(Multistep::AdamsBashforthMoulton(abm)) => abm,
+                    _ => {
+                        let h0 = self.initial_step(tf - t0) * direction;
+                        AdamsBashforthMoulton::new(&f, t0, &y, h0, self.tolerance, order)?
+                            .with_step_limits(self.min_step_size, self.max_step_size)
+                    }
+                };
+                for _step in 0..self.max_steps {
+                    abm.step_toward(&f, tf)?;
+                    let (t, y) = (abm.t(), abm.state().clone());
+                    if !on_step(t, &y) || t == tf {
+                        let run = run(Multistep::AdamsBashforthMoulton(abm), t, &y);
+                        return Ok((t, y, Some(run)));
+                    }
+                }
+                return Err(PoliastroError::convergence_failure(
+                    operation,
+                    self.max_steps,
+                    self.tolerance,
+                ));
+            }
+            Integrator::GaussJackson => {
+                let a = |t: f64, r: &DVector<f64>, v: &DVector<f64>| {
+                    let r = Vector3::new(r[0], r[1], r[2]);
+                    let v = Vector3::new(v[0], v[1], v[2]);
+                    DVector::from_column_slice(self.acceleration(t, &r, &v).as_slice())
+                };
+                let h = self.step_size * direction;
+                let mut gj = match resumed {
+                    Some(Multistep::GaussJackson(gj)) => gj,
+                    _ => {
+                        let (r0, v0) = (y.rows(0, 3).into_owned(), y.rows(3, 3).into_owned());
+                        GaussJackson::new(&a, t0, &r0, &v0, h)?
+                    }
+                };
+                let grid_state = |gj: &GaussJackson| {
+                    let (r, v) = (gj.position(), gj.velocity());
+                    DVector::from_iterator(6, r.iter().chain(v.iter()).copied())
+                };
+
+                // Steps on the grid as long as they do not pass tf
+                let mut steps = 0;
+                while (tf - gj.t()) / h > 1.0 - GRID_TOLERANCE {
+                    if steps == self.max_steps {
+                        return Err(PoliastroError::convergence_failure(
+                            operation,
+                            self.max_steps,
+                            self.step_size,
+                        ));
+                    }
+                    gj.step(&a)?;
+                    steps += 1;
+                    let t = if ((tf - gj.t()) / h).abs() <= GRID_TOLERANCE {
+                        tf
+                    } else {
+                        gj.t()
+                    };
+                    let y = grid_state(&gj);
+                    if !on_step(t, &y) || t == tf {
+                        let run = run(Multistep::GaussJackson(gj), t, &y);
+                        return Ok((t, y, Some(run)));
+                    }
+                }
+
+                // The rest of the way with DOP853, keeping the grid for the
+                // next propagation
+                let t_grid = gj.t();
+                let y = dop853_integrate(
+                    f,
+                    t_grid,
+                    &grid_state(&gj),
+                    tf,
+                    tf - t_grid,
+                    self.tolerance,
+                    Some(self.max_steps),
+                )?;
+                on_step(tf, &y);
+                let run = run(Multistep::GaussJackson(gj), tf, &y);
+                return Ok((tf, y, Some(run)));
+            }
+            Integrator::Rk4 => {
+                let n_steps = ((tf - t0).abs() / self.step_size - GRID_TOLERANCE)
+                    .ceil()
+                    .max(1.0) as usize;
+                if n_steps > self.max_steps {
+                    return Err(PoliastroError::convergence_failure(
+                        operation,
+                        self.max_steps,
+                        self.step_size,
+                    ));
+                }
+                let h = (tf - t0) / n_steps as f64;
+                let (t, y) = fixed_step_loop((t0, tf, n_steps), on_step, || {
+                    y = rk4_step(f, t, &y, h);
+                    t += h;
+                    Ok(y.clone())
+                })?;
+                return Ok((t, y, None));
+            }
+            Integrator::Dopri5 | Integrator::Dop853 => {}
+        }
+
+        let (t, y) = self.adaptive_loop(t0, tf, |step| on_step(step.t_end, &step.trial.y))?;
+        Ok((t, y, None))
+    }
+}
+
+/// An accepted step of DOPRI5 or DOP853
+struct AcceptedStep<'a> {
+    /// Start time and state
+    t: f64,
+    y: &'a DVector<f64>,
+    /// Step size (negative backward)
+    h: f64,
+    /// End time, exactly tf for the last step
+    t_end: f64,
+    trial: &'a TrialStep,
+}
+
+/// Take `n_steps` equal steps from `t0` to `tf`, calling `on_step` after
+/// every step until it returns false
+///
+/// `step` advances the integrator by one step and returns the new state.
+fn fixed_step_loop<S, C>(
+    (t0, tf, n_steps): (f64, f64, usize),
+    mut on_step: C,
+    mut step: S,
+) -> PoliastroResult<(f64, DVector<f64>)>
+where
+    S: FnMut() -> PoliastroResult<DVector<f64>>,
+    C: FnMut(f64, &DVector<f64>) -> bool,
+{
+    let h = (tf - t0) / n_steps as f64;
+    let mut t = t0;
+    let mut y = DVector::zeros(0);
+    for i in 1..=n_steps {
+        y = step()?;
+        t = if i == n_steps { tf } else { t0 + i as f64 * h };
+        if !on_step(t, &y) {
+            break;
+        }
+    }
+    Ok((t, y))
+}
+
+/// Position and velocity of the state [r, v]
+fn split_state(y: &DVector<f64>) -> (Vector3, Vector3) {
+    (
+        Vector3::new(y[0], y[1], y[2]),
+        Vector3::new(y[3], y[4], y[5]),
+    )
+}
+
+fn to_pyarray<'py>(py: Python<'py>, v: &Vector3) -> Bound<'py, PyArray1<f64>> {
+    PyArray1::from_slice_bound(py, v.as_slice())
+}
+
+pub(crate) type PyState<'py> = (Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>);
+
+type PyVariationalState<'py> = (
+    Bound<'py, PyArray1<f64>>,
+    Bound<'py, PyArray1<f64>>,
+    Bound<'py, PyArray2<f64>>,
+    Bound<'py, PyArray2<f64>>,
+);
+
+#[pymethods]
+impl NumericalPropagator {
+    /// Create a numerical propagator
+    ///
+    /// # Arguments
+    /// - `position`: Position vector in meters [x, y, z]
+    /// - `velocity`: Velocity vector in m/s [vx, vy, vz]
+    /// - `epoch`: Epoch of the state
+    /// - `mu`: Central body gravitational parameter (m³/s²)
+    /// - `integrator`: "dop853" (default), "dopri5", "rk4", "gauss_jackson",
+    ///   or "abm" with an optional maximum order (e.g. "abm10")
+    /// - `tol`: Error tolerance of the adaptive integrators (default: 1e-10)
+    /// - `step_size`: Fixed step of RK4 and Gauss-Jackson, or largest
+    ///   initial adaptive step, in seconds (default: 60)
+    /// - `max_steps`: Maximum number of steps per propagation
+    /// - `min_step_size`, `max_step_size`: Limits on the steps of the
+    ///   adaptive integrators, in seconds (default: none)
+    #[new]
+    #[pyo3(signature = (
+        position,
+        velocity,
+        epoch,
+        mu,
+        integrator="dop853",
+        tol=DEFAULT_TOLERANCE,
+        step_size=DEFAULT_STEP_SIZE,
+        max_steps=DEFAULT_MAX_STEPS,
+        min_step_size=0.0,
+        max_step_size=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn py_new(
+        position: PyReadonlyArray1<f64>,
+        velocity: PyReadonlyArray1<f64>,
+        epoch: Epoch,
+        mu: f64,
+        integrator: &str,
+        tol: f64,
+        step_size: f64,
+        max_steps: usize,
+        min_step_size: f64,
+        max_step_size: Option<f64>,
+    ) -> PyResult<Self> {
+        let (position, velocity) = state_from_arrays(&position, &velocity)?;
+        if step_size <= 0.0 {
+            return Err(PoliastroError::invalid_parameter(
+                "step_size",
+                step_size,
+                "must be positive",
+            )
+            .into());
+        }
+        let max_step_size = max_step_size.unwrap_or(f64::INFINITY);
+        if max_step_size.is_nan() || max_step_size <= 0.0 {
+            return Err(PoliastroError::invalid_parameter(
+                "max_step_size",
+                max_step_size,
+                "must be positive",
+            )
+            .into());
+        }
+        if !(0.0..=max_step_size).contains(&min_step_size) {
+            return Err(PoliastroError::invalid_parameter(
+                "min_step_size",
+                min_step_size,
+                "must be between 0 and max_step_size",
+            )
+            .into());
+        }
+        Ok(Self::new(position, velocity, epoch, mu)
+            .with_integrator(Integrator::from_name(integrator)?)
+            .with_tolerance(tol)
+            .with_step_size(step_size)
+            .with_step_limits(min_step_size, max_step_size)
+            .with_max_steps(max_steps))
+    }
+
+    /// Add J2 oblateness
+    ///
+    /// # Arguments
+    /// - `j2`: Oblateness coefficient
+    /// - `R`: Body equatorial radius (m)
+    #[pyo3(name = "add_j2")]
+    #[allow(non_snake_case)]
+    pub fn py_add_j2(&mut self, j2: f64, R: f64) {
+        self.add_perturbation(J2Perturbation::new(j2, R));
+    }
+
+    /// Add a spherical harmonic gravity field (Earth-fixed)
+    ///
+    /// # Arguments
+    /// - `field`: Gravity field coefficients
+    /// - `degree`, `order`: Truncation of the expansion
+    #[pyo3(name = "add_gravity_field")]
+    pub fn py_add_gravity_field(
+        &mut self,
+        field: &GravityField,
+        degree: usize,
+        order: usize,
+    ) -> PyResult<()> {
+        let gravity = SphericalHarmonicGravity::earth(Arc::new(field.clone()), degree, order)?;
+        self.add_perturbation(gravity);
+        Ok(())
+    }
+
+    /// Add atmospheric drag (Earth)
+    ///
+    /// # Arguments
+    /// - `B`: Ballistic coefficient m/(C_d × A) in kg/m²
+    /// - `model`: "exponential" (default), "harris_priester" or "jacchia71"
+    /// - `f107`, `f107_avg`, `ap`: Fixed solar activity for Jacchia 1971;
+    ///   indices not given come from the loaded space weather
+    #[pyo3(
+        name = "add_drag",
+        signature = (B, model="exponential", f107=None, f107_avg=None, ap=None)
+    )]
+    #[allow(non_snake_case)]
+    pub fn py_add_drag(
+        &mut self,
+        B: f64,
+        model: &str,
+        f107: Option<f64>,
+        f107_avg: Option<f64>,
+        ap: Option<f64>,
+    ) -> PyResult<()> {
+        let activity = crate::activity_overrides(&self.epoch, f107, f107_avg, ap);
+        let atmosphere = crate::atmosphere_model(model, activity)?;
+        self.add_perturbation(DragPerturbation::with_atmosphere(Arc::from(atmosphere), B));
+        Ok(())
+    }
+
+    /// Add the gravity of a third body, positioned by the ephemeris subsystem
+    ///
+    /// # Arguments
+    /// - `body`: NAIF ID of the perturbing body (e.g. 10 Sun, 301 Moon)
+    /// - `mu`: Gravitational parameter of the body (m³/s²); defaults are
+    ///   known for the Sun and the Moon
+    ///
+    /// - `analytic_fallback`: Use the built-in analytic ephemeris at epochs
+    ///   the installed ephemeris does not cover; otherwise propagating past
+    ///   its coverage raises ValueError
+    ///
+    /// Raises ValueError for bodies the ephemeris (and, with
+    /// `analytic_fallback`, the analytic ephemeris) does not provide.
+    #[pyo3(name = "add_third_body", signature = (body, mu=None, analytic_fallback=false))]
+    pub fn py_add_third_body(
+        &mut self,
+        body: i32,
+        mu: Option<f64>,
+        analytic_fallback: bool,
+    ) -> PyResult<()> {
+        let body = Body::from_naif_id(body);
+        let mu = match (mu, body) {
+            (Some(mu), _) => mu,
+            (None, Body::Sun) => GM_SUN,
+            (None, Body::Moon) => GM_MOON,
+            (None, _) => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "mu is required for third bodies other than the Sun and the Moon",
+                ))
+            }
+        };
+        let mut perturbation = ThirdBodyPerturbation::ephemeris(body, mu)?;
+        if analytic_fallback {
+            perturbation = perturbation.with_analytic_fallback()?;
+        }
+        self.add_perturbation(perturbation);
+        Ok(())
+    }
+
+    /// Add solar radiation pressure (cannonball model with Earth shadow)
+    ///
+    /// # Arguments
+    /// - `area_mass_ratio`: Area-to-mass ratio (m²/kg)
+    /// - `C_r`: Reflectivity coefficient (1.0 to 2.0)
+    /// - `R`: Radius of the shadowing body (m)
+    #[pyo3(name = "add_srp")]
+    #[allow(non_snake_case)]
+    pub fn py_add_srp(&mut self, area_mass_ratio: f64, C_r: f64, R: f64) {
+        self.add_perturbation(SolarRadiationPressure::new(area_mass_ratio, C_r, R));
+    }
+
+    /// Names of the perturbations in the force model
+    #[getter]
+    pub fn get_perturbations(&self) -> Vec<String> {
+        self.perturbations
+            .names()
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Name of the integrator
+    #[getter]
+    pub fn get_integrator(&self) -> String {
+        self.integrator.name()
+    }
+
+    /// Central body gravitational parameter (m³/s²)
+    #[getter]
+    pub fn get_mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Epoch of the current state
+    #[getter]
+    pub fn get_epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Current position (m)
+    #[getter]
+    pub fn get_position<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        to_pyarray(py, &self.position)
+    }
+
+    /// Current velocity (m/s)
+    #[getter]
+    pub fn get_velocity<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        to_pyarray(py, &self.velocity)
+    }
+
+    /// Reset the state
+    #[pyo3(name = "set_state")]
+    pub fn py_set_state(
+        &mut self,
+        position: PyReadonlyArray1<f64>,
+        velocity: PyReadonlyArray1<f64>,
+        epoch: Epoch,
+    ) -> PyResult<()> {
+        let (position, velocity) = state_from_arrays(&position, &velocity)?;
+        self.set_state(position, velocity, epoch);
+        Ok(())
+    }
+
+    /// Apply an impulsive maneuver `delta_v` (m/s) at the current epoch
+    #[pyo3(name = "apply_impulse")]
+    pub fn py_apply_impulse(&mut self, delta_v: [f64; 3]) {
+        self.apply_impulse(Vector3::from(delta_v));
+    }
+
+    /// Propagate to `epoch`, returning (position, velocity) there
+    ///
+    /// `callback(epoch, position, velocity)`, if given, is called after every
+    /// accepted step; returning False stops the propagation at that step.
+    #[pyo3(name = "propagate_to", signature = (epoch, callback=None))]
+    pub fn py_propagate_to<'py>(
+        &mut self,
+        py: Python<'py>,
+        epoch: Epoch,
+        callback: Option<PyObject>,
+    ) -> PyResult<PyState<'py>> {
+        let (r, v) = match callback {
+            None => self.propagate_to(epoch)?,
+            Some(callback) => {
+                let mut error = None;
+                let state = self.propagate_to_with_callback(epoch, |epoch, r, v| {
+                    let result = callback
+                        .call1(py, (epoch, to_pyarray(py, r), to_pyarray(py, v)))
+                        .and_then(|ret| ret.extract::<Option<bool>>(py));
+                    match result {
+                        Ok(proceed) => proceed.unwrap_or(true),
+                        Err(err) => {
+                            error = Some(err);
+                            false
+                        }
+                    }
+                })?;
+                if let Some(err) = error {
+                    return Err(err);
+                }
+                state
+            }
+        };
+        Ok((to_pyarray(py, &r), to_pyarray(py, &v)))
+    }
+
+    /// Propagate by `dt` seconds, returning (position, velocity)
+    #[pyo3(name = "propagate_by")]
+    pub fn py_propagate_by<'py>(&mut self, py: Python<'py>, dt: f64) -> PyResult<PyState<'py>> {
+        let (r, v) = self.propagate_by(dt)?;
+        Ok((to_pyarray(py, &r), to_pyarray(py, &v)))
+    }
+
+    /// Propagate to each of `epochs` in one pass
+    ///
+    /// Returns an array of shape (len(epochs), 6) of [x, y, z, vx, vy, vz];
+    /// the propagator is left at the last epoch.
+    #[pyo3(name = "propagate_many")]
+    pub fn py_propagate_many<'py>(
+        &mut self,
+        py: Python<'py>,
+        epochs: Vec<Epoch>,
+    ) -> PyResult<Bound<'py, PyArray2<f64>>> {
+        let states = self.propagate_many(&epochs)?;
+        let mut result = ndarray::Array2::zeros((states.len(), 6));
+        for (i, (r, v)) in states.iter().enumerate() {
+            for j in 0..3 {
+                result[[i, j]] = r[j];
+                result[[i, j + 3]] = v[j];
+            }
+        }
+        Ok(PyArray2::from_owned_array_bound(py, result))
+    }
+
+    /// Propagate to `epoch` with the state transition matrix
+    ///
+    /// `parameters` names force model parameters ("ballistic_coefficient",
+    /// "reflectivity") to compute sensitivities for. Returns (position,
+    /// velocity, stm, sensitivity) with the 6×6 STM and the 6×len(parameters)
+    /// sensitivity matrix.
+    #[pyo3(name = "propagate_with_stm", signature = (epoch, parameters=Vec::new()))]
+    pub fn py_propagate_with_stm<'py>(
+        &mut self,
+        py: Python<'py>,
+        epoch: Epoch,
+        parameters: Vec<String>,
+    ) -> PyResult<PyVariationalState<'py>> {
+        let parameters = parameters
+            .iter()
+            .map(|name| DynamicParameter::from_name(name))
+            .collect::<PoliastroResult<Vec<_>>>()?;
+        let state = self.propagate_with_stm(epoch, &parameters)?;
+        let stm = ndarray::Array2::from_shape_fn((6, 6), |(i, j)| state.stm[(i, j)]);
+        let sensitivity = ndarray::Array2::from_shape_fn((6, parameters.len()), |(i, j)| {
+            state.sensitivity[(i, j)]
+        });
+        Ok((
+            to_pyarray(py, &state.position),
+            to_pyarray(py, &state.velocity),
+            PyArray2::from_owned_array_bound(py, stm),
+            PyArray2::from_owned_array_bound(py, sensitivity),
+        ))
+    }
+
+    /// Ephemeris of [x, y, z, vx, vy, vz] from the current epoch to `epoch`
+    /// (times in TDB seconds since J2000), without moving the propagator
+    #[pyo3(name = "ephemeris_to")]
+    pub fn py_ephemeris_to(&self, epoch: Epoch) -> PoliastroResult<Ephemeris> {
+        self.ephemeris_to(epoch)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "NumericalPropagator(integrator='{}', perturbations={:?}, epoch={})",
+            self.integrator.name(),
+            self.perturbations.names(),
+            self.epoch.to_iso_string()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::{GM_EARTH, J2_EARTH, R_EARTH};
+    use crate::core::time::Duration;
+    use crate::propagators::perturbations::{propagate_j2_dop853, propagate_with_perturbations};
+    use approx::assert_relative_eq;
+
+    fn leo() -> (Vector3, Vector3, Epoch) {
+        (
+            Vector3::new(7000e3, 0.0, 0.0),
+            Vector3::new(0.0, 6000.0, 4000.0),
+            Epoch::from_gregorian_utc(2024, 3, 1, 12, 0, 0, 0),
+        )
+    }
+
+    fn j2_propagator(integrator: Integrator) -> NumericalPropagator {
+        let (r0, v0, epoch) = leo();
+        let mut propagator = NumericalPropagator::new(r0, v0, epoch, GM_EARTH)
+            .with_integrator(integrator)
+            .with_tolerance(1e-12)
+            .with_step_size(10.0);
+        propagator.add_perturbation(J2Perturbation::new(J2_EARTH, R_EARTH));
+        propagator
+    }
+
+    #[test]
+    fn test_integrator_names() {
+        for integrator in [
+            Integrator::Rk4,
+            Integrator::Dopri5,
+            Integrator::Dop853,
+            Integrator::GaussJackson,
+            Integrator::AdamsBashforthMoulton { order: 12 },
+        ] {
+            assert_eq!(
+                Integrator::from_name(&integrator.name()).unwrap(),
+                integrator
+            );
+        }
+        assert_eq!(Integrator::from_name("DOP853").unwrap(), Integrator::Dop853);
+        assert!(Integrator::from_name("euler").is_err());
+        assert!(!Integrator::Rk4.is_adaptive());
+
+        assert_eq!(
+            Integrator::from_name("gj").unwrap(),
+            Integrator::GaussJackson
+        );
+        assert_eq!(
+            Integrator::from_name("abm").unwrap(),
+            Integrator::AdamsBashforthMoulton { order: 8 }
+        );
+        assert!(!Integrator::GaussJackson.is_adaptive());
+        assert!(Integrator::AdamsBashforthMoulton { order: 8 }.is_adaptive());
+        assert!(Integrator::from_name("abm13").is_err());
+        assert!(Integrator::from_name("abmx").is_err());
+    }
+
+    #[test]
+    fn test_propagate_to_matches_free_functions() {
+        let (r0, v0, epoch) = leo();
+        let target = epoch.add_duration(Duration::from_seconds(3600.0));
+        let (r_ref, v_ref) =
+            propagate_j2_dop853(&r0, &v0, 3600.0, GM_EARTH, J2_EARTH, R_EARTH, Some(1e-12))
+                .unwrap();
+
+        for integrator in [Integrator::Rk4, Integrator::Dopri5, Integrator::Dop853] {
+            let mut propagator = j2_propagator(integrator);
+            let (r, v) = propagator.propagate_to(target).unwrap();
+            assert_relative_eq!(r, r_ref, epsilon = 0.1);
+            assert_relative_eq!(v, v_ref, epsilon = 1e-4);
+            assert_eq!(propagator.epoch(), target);
+            assert_eq!(propagator.position(), r);
+        }
+    }
+
+    #[test]
+    fn test_multistep_integrators() {
+        let (_, _, epoch) = leo();
+        let target = epoch.add_duration(Duration::from_seconds(86400.0));
+        let (r_ref, v_ref) = j2_propagator(Integrator::Dop853)
+            .propagate_to(target)
+            .unwrap();
+
+        for integrator in [
+            Integrator::GaussJackson,
+            Integrator::AdamsBashforthMoulton { order: 10 },
+        ] {
+            let mut steps = 0;
+            let mut propagator = j2_propagator(integrator).with_step_size(30.0);
+            let (r, v) = propagator
+                .propagate_to_with_callback(target, |_, _, _| {
+                    steps += 1;
+                    true
+                })
+                .unwrap();
+            // Gauss-Jackson covers 86400 s in 30 s steps (the TDB span may
+            // round up by one); ABM picks its own steps
+            if integrator == Integrator::GaussJackson {
+                assert!((2880..=2881).contains(&steps));
+            }
+            assert_relative_eq!(r, r_ref, epsilon = 0.1);
+            assert_relative_eq!(v, v_ref, epsilon = 1e-4);
+            assert_eq!(propagator.epoch(), target);
+            assert!(propagator.ephemeris_to(epoch).is_err());
+        }
+    }
+
+    #[test]
+    fn test_multistep_continues_across_propagations() {
+        let (_, _, epoch) = leo();
+        let target = epoch.add_duration(Duration::from_seconds(7200.0));
+
+        for integrator in [
+            Integrator::GaussJackson,
+            Integrator::AdamsBashforthMoulton { order: 10 },
+        ] {
+            let (r_ref, v_ref) = j2_propagator(integrator)
+                .with_step_size(30.0)
+                .propagate_to(target)
+                .unwrap();
+
+            // Legs that do not fall on the Gauss-Jackson grid
+            let mut propagator = j2_propagator(integrator).with_step_size(30.0);
+            let epochs: Vec<Epoch> = (1..=71)
+                .map(|k| epoch.add_duration(Duration::from_seconds(100.0 * k as f64 - 7.0)))
+                .chain([target])
+                .collect();
+            propagator.propagate_to(epochs[0]).unwrap();
+            let started = propagator.multistep.clone().unwrap();
+            for &leg_end in &epochs[1..] {
+                propagator.propagate_to(leg_end).unwrap();
+            }
+            let run = propagator.multistep.as_ref().unwrap();
+            match (&started.integrator, &run.integrator) {
+                (Multistep::GaussJackson(started), Multistep::GaussJackson(gj)) => {
+                    // Still on the grid it started
+                    let n = (gj.t() - started.t()) / gj.step_size();
+                    assert!(n > 200.0);
+                    assert_relative_eq!(n, n.round(), epsilon = 1e-6);
+                }
+                (Multistep::AdamsBashforthMoulton(_), Multistep::AdamsBashforthMoulton(abm)) => {
+                    assert!(abm.order() > 4);
+                }
+                _ => panic!("multistep integrator changed"),
+            }
+            let (r, v) = (propagator.position(), propagator.velocity());
+            assert_relative_eq!(r, r_ref, epsilon = 0.1);
+            assert_relative_eq!(v, v_ref, epsilon = 1e-4);
+
+            // Changing the force model restarts
+            propagator.add_perturbation(J2Perturbation::new(0.0, R_EARTH));
+            assert!(propagator.multistep.is_none());
+        }
+    }
+
+    #[test]
+    fn test_step_limits() {
+        let (_, _, epoch) = leo();
+        let target = epoch.add_duration(Duration::from_seconds(3600.0));
+        let (r_ref, _) = j2_propagator(Integrator::Dop853)
+            .propagate_to(target)
+            .unwrap();
+
+        for integrator in [
+            Integrator::Dopri5,
+            Integrator::Dop853,
+            Integrator::AdamsBashforthMoulton { order: 10 },
+        ] {
+            let mut times = vec![epoch.to_tdb_seconds_since_j2000()];
+            let (r, _) = j2_propagator(integrator)
+                .with_step_size(120.0)
+                .with_step_limits(0.0, 20.0)
+                .propagate_to_with_callback(target, |epoch, _, _| {
+                    times.push(epoch.to_tdb_seconds_since_j2000());
+                    true
+                })
+                .unwrap();
+            assert!(times
+                .windows(2)
+                .all(|pair| pair[1] - pair[0] <= 20.0 + 1e-6));
+            assert_relative_eq!(r, r_ref, epsilon = 0.1);
+
+            // A tolerance the minimum step cannot meet
+            let result = j2_propagator(integrator)
+                .with_tolerance(1e-14)
+                .with_step_limits(300.0, 600.0)
+                .propagate_to(target);
+            assert!(matches!(
+                result,
+                Err(PoliastroError::NumericalInstability { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_impulse_restarts_multistep() {
+        let (_, _, epoch) = leo();
+        let burn = epoch.add_duration(Duration::from_seconds(2700.0));
+        let target = burn.add_duration(Duration::from_seconds(2700.0));
+        let delta_v = Vector3::new(0.0, 30.0, -10.0);
+
+        let mut states = Vec::new();
+        for integrator in [Integrator::Dop853, Integrator::GaussJackson] {
+            let mut propagator = j2_propagator(integrator).with_step_size(30.0);
+            let (_, v_burn) = propagator.propagate_to(burn).unwrap();
+            propagator.apply_impulse(delta_v);
+            assert_eq!(propagator.velocity(), v_burn + delta_v);
+            states.push(propagator.propagate_to(target).unwrap());
+        }
+        assert_relative_eq!(states[0].0, states[1].0, epsilon = 0.1);
+        assert_relative_eq!(states[0].1, states[1].1, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_propagate_with_stm() {
+        let (r0, v0, epoch) = leo();
+        let target = epoch.add_duration(Duration::from_seconds(1800.0));
+        let propagator_from = |r: Vector3| {
+            let mut propagator =
+                NumericalPropagator::new(r, v0, epoch, GM_EARTH).with_tolerance(1e-12);
+            propagator.add_perturbation(J2Perturbation::new(J2_EARTH, R_EARTH));
+            propagator.add_perturbation(DragPerturbation::earth(50.0));
+            propagator
+        };
+
+        let mut propagator = propagator_from(r0);
+        let state = propagator
+            .propagate_with_stm(target, &[DynamicParameter::BallisticCoefficient])
+            .unwrap();
+        assert_eq!(propagator.epoch(), target);
+        assert_eq!(propagator.position(), state.position);
+        assert_eq!(state.sensitivity.shape(), (6, 1));
+
+        // A small initial offset maps through the STM, up to second-order
+        // terms of a few mm
+        let dr0 = Vector3::new(10.0, -5.0, 3.0);
+        let (r_offset, _) = propagator_from(r0 + dr0).propagate_to(target).unwrap();
+        let predicted = state.stm.fixed_view::<3, 3>(0, 0) * dr0;
+        assert_relative_eq!(r_offset - state.position, predicted, epsilon = 1e-2);
+
+        assert!(propagator
+            .propagate_with_stm(epoch, &[DynamicParameter::Reflectivity])
+            .is_err());
+    }
+
+    #[test]
+    fn test_propagate_legs_and_back() {
+        let (r0, v0, epoch) = leo();
+        let mut propagator = j2_propagator(Integrator::Dop853);
+
+        // Two legs agree with one
+        propagator.propagate_by(1000.0).unwrap();
+        let (r_legs, _) = propagator.propagate_by(2000.0).unwrap();
+        let (r_one, _) = j2_propagator(Integrator::Dop853)
+            .propagate_by(3000.0)
+            .unwrap();
+        assert_relative_eq!(r_legs, r_one, epsilon = 1e-2);
+
+        // And back to the start
+        let (r, v) = propagator.propagate_to(epoch).unwrap();
+        assert_relative_eq!(r, r0, epsilon = 1e-2);
+        assert_relative_eq!(v, v0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_propagate_many() {
+        let (_, _, epoch) = leo();
+        let epochs: Vec<Epoch> = [600.0, -300.0, 0.0, 2400.0, 1800.0]
+            .iter()
+            .map(|&dt| epoch.add_duration(Duration::from_seconds(dt)))
+            .collect();
+
+        for integrator in [Integrator::Rk4, Integrator::Dop853] {
+            let mut propagator = j2_propagator(integrator);
+            let states = propagator.propagate_many(&epochs).unwrap();
+            assert_eq!(states.len(), epochs.len());
+            for (&sample, (r, v)) in epochs.iter().zip(&states) {
+                let (r_ref, v_ref) = j2_propagator(Integrator::Dop853)
+                    .propagate_to(sample)
+                    .unwrap();
+                assert_relative_eq!(*r, r_ref, epsilon = 0.1);
+                assert_relative_eq!(*v, v_ref, epsilon = 1e-4);
+            }
+            assert_eq!(propagator.epoch(), epochs[4]);
+        }
+
+        let mut propagator = j2_propagator(Integrator::Dop853);
+        assert!(propagator.propagate_many(&[]).unwrap().is_empty());
+        assert_eq!(propagator.epoch(), epoch);
+    }
+
+    #[test]
+    fn test_step_callback() {
+        let (_, _, epoch) = leo();
+        let mut propagator = j2_propagator(Integrator::Dopri5);
+
+        // Every step is reported, the last one at the target
+        let mut steps = Vec::new();
+        let target = epoch.add_duration(Duration::from_seconds(1200.0));
+        propagator
+            .propagate_to_with_callback(target, |epoch, r, _| {
+                steps.push((epoch, r.norm()));
+                true
+            })
+            .unwrap();
+        assert!(steps.len() > 1);
+        assert_eq!(steps[steps.len() - 1].0, target);
+
+        // Stop after the third step
+        let mut count = 0;
+        let (r, _) = propagator
+            .propagate_to_with_callback(
+                target.add_duration(Duration::from_seconds(3600.0)),
+                |_, _, _| {
+                    count += 1;
+                    count < 3
+                },
+            )
+            .unwrap();
+        assert_eq!(count, 3);
+        assert!(
+            propagator.epoch().to_tdb_seconds_since_j2000()
+                < target.to_tdb_seconds_since_j2000() + 3600.0
+        );
+        assert_eq!(propagator.position(), r);
+    }
+
+    #[test]
+    fn test_matches_perturbation_set_propagation() {
+        let (r0, v0, epoch) = leo();
+        let mut perturbations = PerturbationSet::new();
+        perturbations.add(J2Perturbation::earth());
+        let (r_ref, _) = propagate_with_perturbations(
+            &r0,
+            &v0,
+            epoch.to_tdb_seconds_since_j2000(),
+            5400.0,
+            GM_EARTH,
+            &perturbations,
+            "dopri5",
+            None,
+            Some(1e-12),
+        )
+        .unwrap();
+
+        let mut perturbations = PerturbationSet::new();
+        perturbations.add(J2Perturbation::earth());
+        let mut propagator = NumericalPropagator::new(r0, v0, epoch, GM_EARTH)
+            .with_perturbations(perturbations)
+            .with_tolerance(1e-12);
+        let (r, _) = propagator.propagate_by(5400.0).unwrap();
+        assert_relative_eq!(r, r_ref, epsilon = 1e-2);
+
+        let ephemeris = propagator.ephemeris_to(epoch).unwrap();
+        assert_relative_eq!(
+            ephemeris.t_end(),
+            epoch.to_tdb_seconds_since_j2000(),
+            epsilon = 1e-6
+        );
+        assert!(propagator
+            .with_integrator(Integrator::Rk4)
+            .ephemeris_to(epoch)
+            .is_err());
+    }
+}
@@ -18,13 +18,20 @@
 //!
 //! The STM relates state perturbations: δx(t) ≈ Φ(t,t₀)·δx(t₀)
 //!
+//! For an arbitrary force model, [`propagate_stm_perturbed`] builds A(t) from
+//! the [`Perturbation::partials`] of each perturbation and can also carry
+//! the sensitivity matrix S = ∂x/∂p to force model parameters p (drag
+//! ballistic coefficient, SRP reflectivity), which obeys
+//! dS/dt = A(t)·S + ∂f/∂p with S(t₀) = 0.
+//!
 //! # References
 //! - Vallado, D. A. "Fundamentals of Astrodynamics and Applications", 5th Ed., p. 748
 //! - Tapley, Schutz, Born "Statistical Orbit Determination" (2004), Ch. 3
 //! - nyx-space STM implementation: <https://nyxspace.com/nyxspace/MathSpec/optimization/stm/>
 
-use crate::core::error::PoliastroResult;
+use crate::core::error::{PoliastroError, PoliastroResult};
 use crate::core::linalg::Vector3;
+use crate::propagators::perturbations::{j2_partials, DynamicParameter, Perturbation};
 use nalgebra as na;
 
 /// Compute the Jacobian matrix for two-body orbital dynamics
@@ -136,101 +143,15 @@ pub fn jacobian_two_body(r: &Vector3, mu: f64) -> na::Matrix6<f64> {
 /// let A = jacobian_j2(&r, GM_EARTH, J2_EARTH, R_EARTH);
 /// ```
 pub fn jacobian_j2(r: &Vector3, mu: f64, j2: f64, R: f64) -> na::Matrix6<f64> {
-    // Start with two-body Jacobian
+    // Start with two-body Jacobian and add the J2 contribution to the
+    // lower-left 3×3 block
     let mut jacobian = jacobian_two_body(r, mu);
-
-    // Add J2 contribution to lower-left 3×3 block
-    let x = r.x;
-    let y = r.y;
-    let z = r.z;
-
-    let r_mag = r.norm();
-    let r2 = r_mag * r_mag;
-    let r4 = r2 * r2;
-    let r6 = r4 * r2;
-    let r8 = r6 * r2;
-
-    let z2 = z * z;
-
-    // Common coefficient k = (3/2) * J2 * μ * R²
-    let k = 1.5 * j2 * mu * R * R;
-
-    // The J2 acceleration components are:
-    // ax = k/r⁴ · (x/r) · (5z²/r² - 1)
-    // ay = k/r⁴ · (y/r) · (5z²/r² - 1)
-    // az = k/r⁴ · (z/r) · (5z²/r² - 3)
-
-    // We need ∂a_J2/∂r
-    // This involves computing partial derivatives of each acceleration component
-    // with respect to x, y, z
-
-    // These are complex expressions. For implementation, we use symbolic differentiation results.
-    // The partial derivatives are:
-
-    let z2_r2 = z2 / r2;
-    let factor_xy = 5.0 * z2_r2 - 1.0;
-    let factor_z = 5.0 * z2_r2 - 3.0;
-
-    // ∂(a_J2)/∂x
-    let dax_dx = k * (
-        (factor_xy / r4) * (1.0/r_mag - x*x/r_mag/r2)
-        + (x / r_mag / r4) * (10.0*z2*(-x)/r4 - x*factor_xy*4.0/r2/r_mag)
-    );
-
-    let dax_dy = k * (
-        (factor_xy / r4) * (-x*y/r_mag/r2)
-        + (x / r_mag / r4) * (10.0*z2*(-y)/r4 - y*factor_xy*4.0/r2/r_mag)
-    );
-
-    let dax_dz = k * (
-        (factor_xy / r4) * (-x*z/r_mag/r2)
-        + (x / r_mag / r4) * (10.0*z2*(-z)/r4 + 10.0*z/r2 - z*factor_xy*4.0/r2/r_mag)
-    );
-
-    // ∂(a_J2)/∂y
-    let day_dx = k * (
-        (factor_xy / r4) * (-y*x/r_mag/r2)
-        + (y / r_mag / r4) * (10.0*z2*(-x)/r4 - x*factor_xy*4.0/r2/r_mag)
-    );
-
-    let day_dy = k * (
-        (factor_xy / r4) * (1.0/r_mag - y*y/r_mag/r2)
-        + (y / r_mag / r4) * (10.0*z2*(-y)/r4 - y*factor_xy*4.0/r2/r_mag)
-    );
-
-    let day_dz = k * (
-        (factor_xy / r4) * (-y*z/r_mag/r2)
-        + (y / r_mag / r4) * (10.0*z2*(-z)/r4 + 10.0*z/r2 - z*factor_xy*4.0/r2/r_mag)
-    );
-
-    // ∂(a_J2)/∂z
-    let daz_dx = k * (
-        (factor_z / r4) * (-z*x/r_mag/r2)
-        + (z / r_mag / r4) * (10.0*z2*(-x)/r4 - x*factor_z*4.0/r2/r_mag)
-    );
-
-    let daz_dy = k * (
-        (factor_z / r4) * (-z*y/r_mag/r2)
-        + (z / r_mag / r4) * (10.0*z2*(-y)/r4 - y*factor_z*4.0/r2/r_mag)
-    );
-
-    let daz_dz = k * (
-        (factor_z / r4) * (1.0/r_mag - z*z/r_mag/r2)
-        + (z / r_mag / r4) * (10.0*z2*(-z)/r4 + 10.0*z/r2 - z*factor_z*4.0/r2/r_mag)
-    );
-
-    // Add J2 contributions to the lower-left block (rows 3-5, cols 0-2)
-    jacobian[(3, 0)] += dax_dx;
-    jacobian[(3, 1)] += dax_dy;
-    jacobian[(3, 2)] += dax_dz;
-
-    jacobian[(4, 0)] += day_dx;
-    jacobian[(4, 1)] += day_dy;
-    jacobian[(4, 2)] += day_dz;
-
-    jacobian[(5, 0)] += daz_dx;
-    jacobian[(5, 1)] += daz_dy;
-    jacobian[(5, 2)] += daz_dz;
+    let partials = j2_partials(r, mu, j2, R);
+    for i in 0..3 {
+        for j in 0..3 {
+            jacobian[(3 + i, j)] += partials[(i, j)];
+        }
+    }
 
     jacobian
 }
@@ -573,6 +494,193 @@ pub fn propagate_stm_j2_rk4(
     Ok((r_final, v_final, stm_final))
 }
 
+/// State, state transition matrix and parameter sensitivities at the end of
+/// a variational propagation
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariationalState {
+    /// Final position (m)
+    pub position: Vector3,
+    /// Final velocity (m/s)
+    pub velocity: Vector3,
+    /// State transition matrix Φ(t, t₀) = ∂x(t)/∂x(t₀)
+    pub stm: na::Matrix6<f64>,
+    /// Sensitivity matrix ∂x(t)/∂p (6 × number of parameters), one column
+    /// per requested [`DynamicParameter`]
+    pub sensitivity: na::DMatrix<f64>,
+}
+
+/// Propagate state, STM and parameter sensitivities for any set of
+/// perturbations using adaptive DOP853 integration
+///
+/// Integrates the variational equations
+///
+/// dΦ/dt = A(t)·Φ,  dS/dt = A(t)·S + ∂f/∂p
+///
+/// alongside the state, where A is the two-body Jacobian plus the
+/// [`Perturbation::partials`] of the perturbations (analytic where the
+/// perturbation provides them, finite differences otherwise) and ∂f/∂p =
+/// [0; ∂a/∂p] comes from [`Perturbation::parameter_partial`].
+///
+/// # Augmented State Vector
+/// - Elements 0-5: [x, y, z, vx, vy, vz] (orbital state)
+/// - Elements 6-41: Φ (6×6 STM, flattened row-major)
+/// - Elements 42-: S (6×p sensitivity matrix, flattened row-major)
+///
+/// # Arguments
+/// * `r0` - Initial position vector (m)
+/// * `v0` - Initial velocity vector (m/s)
+/// * `t0` - Initial time passed to the perturbations (TDB seconds since J2000)
+/// * `dt` - Propagation time (seconds, may be negative)
+/// * `mu` - Standard gravitational parameter (m³/s²)
+/// * `perturbations` - Force model (e.g. a `PerturbationSet`)
+/// * `parameters` - Force model parameters to compute sensitivities for
+/// * `tol` - Error tolerance (default: 1e-10)
+///
+/// # Returns
+/// The final [`VariationalState`]
+///
+/// # Errors
+/// Returns an error if none of the perturbations depends on one of
+/// `parameters`, if a perturbation cannot be evaluated over the span
+/// ([`Perturbation::check_span`]), or if the integration fails
+///
+/// # Example
+/// ```ignore
+/// use astrora::propagators::perturbations::{
+///     DragPerturbation, DynamicParameter, J2Perturbation, PerturbationSet,
+/// };
+/// use astrora::propagators::stm::propagate_stm_perturbed;
+///
+/// let mut perturbations = PerturbationSet::new();
+/// perturbations.add(J2Perturbation::earth());
+/// perturbations.add(DragPerturbation::earth(50.0));
+///
+/// let state = propagate_stm_perturbed(
+///     &r0, &v0, t0, 3600.0, GM_EARTH, &perturbations,
+///     &[DynamicParameter::BallisticCoefficient], None,
+/// )?;
+/// let dr_dB = state.sensitivity.fixed_view::<3, 1>(0, 0);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn propagate_stm_perturbed<P: Perturbation + ?Sized>(
+    r0: &Vector3,
+    v0: &Vector3,
+    t0: f64,
+    dt: f64,
+    mu: f64,
+    perturbations: &P,
+    parameters: &[DynamicParameter],
+    tol: Option<f64>,
+) -> PoliastroResult<VariationalState> {
+    use crate::core::numerical::dop853_integrate;
+
+    let tol = tol.unwrap_or(1e-10);
+    let n_params = parameters.len();
+    let n = 42 + 6 * n_params;
+
+    for &parameter in parameters {
+        if perturbations
+            .parameter_partial(parameter, t0, r0, v0, mu)
+            .is_none()
+        {
+            return Err(PoliastroError::invalid_state(format!(
+                "No perturbation depends on the {} parameter",
+                parameter.name()
+            )));
+        }
+    }
+    perturbations.check_span(t0, t0 + dt)?;
+
+    let dynamics = |t: f64, augmented: &na::DVector<f64>| -> na::DVector<f64> {
+        let r = Vector3::new(augmented[0], augmented[1], augmented[2]);
+        let v = Vector3::new(augmented[3], augmented[4], augmented[5]);
+
+        // Jacobian: two-body plus the perturbation partials
+        let (a_r, a_v) = perturbations.partials(t, &r, &v, mu);
+        let mut jacobian = jacobian_two_body(&r, mu);
+        for i in 0..3 {
+            for j in 0..3 {
+                jacobian[(3 + i, j)] += a_r[(i, j)];
+                jacobian[(3 + i, 3 + j)] += a_v[(i, j)];
+            }
+        }
+
+        let r_mag = r.norm();
+        let a = -mu / (r_mag * r_mag * r_mag) * r + perturbations.acceleration(t, &r, &v, mu);
+
+        let mut result = na::DVector::zeros(n);
+        result[0] = v.x;
+        result[1] = v.y;
+        result[2] = v.z;
+        result[3] = a.x;
+        result[4] = a.y;
+        result[5] = a.z;
+
+        // dΦ/dt = A·Φ and dS/dt = A·S + ∂f/∂p, as one product A·[Φ | S]
+        let mut phi_s = na::DMatrix::zeros(6, 6 + n_params);
+        for i in 0..6 {
+            for j in 0..6 {
+                phi_s[(i, j)] = augmented[6 + i * 6 + j];
+            }
+            for k in 0..n_params {
+                phi_s[(i, 6 + k)] = augmented[42 + i * n_params + k];
+            }
+        }
+        let mut derivative = jacobian * phi_s;
+        for (k, &parameter) in parameters.iter().enumerate() {
+            let da_dp = perturbations
+                .parameter_partial(parameter, t, &r, &v, mu)
+                .unwrap_or_else(Vector3::zeros);
+            for i in 0..3 {
+                derivative[(3 + i, 6 + k)] += da_dp[i];
+            }
+        }
+
+        for i in 0..6 {
+            for j in 0..6 {
+                result[6 + i * 6 + j] = derivative[(i, j)];
+            }
+            for k in 0..n_params {
+                result[42 + i * n_params + k] = derivative[(i, 6 + k)];
+            }
+        }
+
+        result
+    };
+
+    // Initial augmented state: [r0, v0, I₆ₓ₆, 0₆ₓₚ]
+    let mut augmented0 = na::DVector::zeros(n);
+    augmented0[0] = r0.x;
+    augmented0[1] = r0.y;
+    augmented0[2] = r0.z;
+    augmented0[3] = v0.x;
+    augmented0[4] = v0.y;
+    augmented0[5] = v0.z;
+
+    for i in 0..6 {
+        augmented0[6 + i * 6 + i] = 1.0;
+    }
+
+    let augmented_final = if dt == 0.0 {
+        augmented0
+    } else {
+        let h0 = (dt.abs() / 10.0).min(60.0);
+        dop853_integrate(dynamics, t0, &augmented0, t0 + dt, h0, tol, None)?
+    };
+
+    let position = Vector3::new(augmented_final[0], augmented_final[1], augmented_final[2]);
+    let velocity = Vector3::new(augmented_final[3], augmented_final[4], augmented_final[5]);
+    let stm = na::Matrix6::from_row_slice(&augmented_final.as_slice()[6..42]);
+    let sensitivity = na::DMatrix::from_row_slice(6, n_params, &augmented_final.as_slice()[42..]);
+
+    Ok(VariationalState {
+        position,
+        velocity,
+        stm,
+        sensitivity,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -746,4 +854,195 @@ mod tests {
         let det = stm.determinant();
         assert_relative_eq!(det.abs(), 1.0, epsilon = 0.2);
     }
+
+    #[test]
+    fn test_jacobian_j2_matches_finite_differences() {
+        use crate::core::constants::{J2_EARTH, R_EARTH};
+        use crate::propagators::perturbations::j2_perturbation;
+
+        let r = Vector3::new(6500e3, 1200e3, 2100e3);
+        let A = jacobian_j2(&r, GM_EARTH, J2_EARTH, R_EARTH);
+        let acceleration = |r: &Vector3| {
+            -GM_EARTH / r.norm().powi(3) * r + j2_perturbation(r, GM_EARTH, J2_EARTH, R_EARTH)
+        };
+
+        let h = 1.0;
+        for j in 0..3 {
+            let dr = Vector3::ith(j, h);
+            let column = (acceleration(&(r + dr)) - acceleration(&(r - dr))) / (2.0 * h);
+            for i in 0..3 {
+                assert_relative_eq!(A[(3 + i, j)], column[i], max_relative = 1e-8);
+            }
+        }
+    }
+
+    mod perturbed {
+        use super::*;
+        use crate::core::constants::R_EARTH;
+        use crate::propagators::perturbations::{
+            sun_position_simple, DragPerturbation, J2Perturbation, PerturbationSet,
+            SolarRadiationPressure,
+        };
+
+        fn force_model(ballistic_coeff: f64, reflectivity: f64) -> PerturbationSet {
+            let mut perts = PerturbationSet::new();
+            perts.add(J2Perturbation::earth());
+            // Exponential atmosphere with a thermospheric scale height, so
+            // that drag is significant at 400 km
+            perts.add(DragPerturbation::new(R_EARTH, 1e-8, 60e3, ballistic_coeff));
+            perts.add(SolarRadiationPressure::earth(0.2, reflectivity));
+            perts
+        }
+
+        fn propagate(r0: &Vector3, v0: &Vector3, perts: &PerturbationSet) -> (Vector3, Vector3) {
+            let state =
+                propagate_stm_perturbed(r0, v0, T0, DT, GM_EARTH, perts, &[], Some(1e-13)).unwrap();
+            (state.position, state.velocity)
+        }
+
+        const T0: f64 = 1e7;
+        const DT: f64 = 4000.0;
+
+        #[test]
+        fn test_two_body_matches_dopri5() {
+            let r0 = Vector3::new(7000e3, 0.0, 0.0);
+            let v0 = Vector3::new(0.0, 7546.0, 0.0);
+            let (r, v, stm) = propagate_stm_dopri5(&r0, &v0, 600.0, GM_EARTH, Some(1e-12)).unwrap();
+            let state = propagate_stm_perturbed(
+                &r0,
+                &v0,
+                0.0,
+                600.0,
+                GM_EARTH,
+                &PerturbationSet::new(),
+                &[],
+                Some(1e-12),
+            )
+            .unwrap();
+
+            assert_relative_eq!(state.position, r, epsilon = 1e-3);
+            assert_relative_eq!(state.velocity, v, epsilon = 1e-6);
+            assert_relative_eq!(state.stm, stm, epsilon = 1e-6);
+            assert_eq!(state.sensitivity.shape(), (6, 0));
+        }
+
+        #[test]
+        fn test_stm_and_sensitivities_match_finite_differences() {
+            // Dawn-dusk orbit, so that SRP stays continuous (no eclipses)
+            let sun = sun_position_simple(T0).normalize();
+            let r_hat = sun.cross(&Vector3::z()).normalize();
+            let r0 = 6750e3 * r_hat;
+            let v0 = (GM_EARTH / 6750e3).sqrt() * sun.cross(&r_hat);
+            let (b, cr) = (20.0, 1.5);
+            let perts = force_model(b, cr);
+            let parameters = [
+                DynamicParameter::BallisticCoefficient,
+                DynamicParameter::Reflectivity,
+            ];
+            let state = propagate_stm_perturbed(
+                &r0,
+                &v0,
+                T0,
+                DT,
+                GM_EARTH,
+                &perts,
+                &parameters,
+                Some(1e-13),
+            )
+            .unwrap();
+
+            let to6 = |(r, v): (Vector3, Vector3)| na::Vector6::new(r.x, r.y, r.z, v.x, v.y, v.z);
+            let check = |column: na::Vector6<f64>,
+                         plus: na::Vector6<f64>,
+                         minus: na::Vector6<f64>,
+                         h: f64,
+                         tol: f64| {
+                let fd = (plus - minus) / (2.0 * h);
+                let scale = fd.abs().max();
+                assert_relative_eq!(column / scale, fd / scale, epsilon = tol);
+            };
+
+            // Φ columns for position and velocity offsets
+            for j in 0..6 {
+                let h = if j < 3 { 10.0 } else { 0.01 };
+                let offset = |sign: f64| {
+                    let mut dx = na::Vector6::zeros();
+                    dx[j] = sign * h;
+                    let r = r0 + dx.fixed_rows::<3>(0);
+                    let v = v0 + dx.fixed_rows::<3>(3);
+                    to6(propagate(&r, &v, &perts))
+                };
+                check(
+                    state.stm.column(j).into_owned(),
+                    offset(1.0),
+                    offset(-1.0),
+                    h,
+                    1e-4,
+                );
+            }
+
+            // Sensitivities to B and C_r
+            let h = 0.1;
+            let plus = to6(propagate(&r0, &v0, &force_model(b + h, cr)));
+            let minus = to6(propagate(&r0, &v0, &force_model(b - h, cr)));
+            check(
+                state.sensitivity.column(0).fixed_rows::<6>(0).into_owned(),
+                plus,
+                minus,
+                h,
+                1e-4,
+            );
+
+            let h = 0.2;
+            let plus = to6(propagate(&r0, &v0, &force_model(b, cr + h)));
+            let minus = to6(propagate(&r0, &v0, &force_model(b, cr - h)));
+            // SRP moves the orbit by well under a metre, close to the
+            // integration noise of the finite differences
+            check(
+                state.sensitivity.column(1).fixed_rows::<6>(0).into_owned(),
+                plus,
+                minus,
+                h,
+                1e-3,
+            );
+
+            // Backward propagation inverts the STM
+            let back = propagate_stm_perturbed(
+                &state.position,
+                &state.velocity,
+                T0 + DT,
+                -DT,
+                GM_EARTH,
+                &perts,
+                &[],
+                Some(1e-13),
+            )
+            .unwrap();
+            assert_relative_eq!(back.position, r0, epsilon = 1e-2);
+            assert_relative_eq!(
+                back.stm * state.stm,
+                na::Matrix6::identity(),
+                epsilon = 1e-6
+            );
+        }
+
+        #[test]
+        fn test_unknown_parameter() {
+            let r0 = Vector3::new(7000e3, 0.0, 0.0);
+            let v0 = Vector3::new(0.0, 7546.0, 0.0);
+            let mut perts = PerturbationSet::new();
+            perts.add(J2Perturbation::earth());
+            assert!(propagate_stm_perturbed(
+                &r0,
+                &v0,
+                0.0,
+                600.0,
+                GM_EARTH,
+                &perts,
+                &[DynamicParameter::Reflectivity],
+                None,
+            )
+            .is_err());
+        }
+    }
 }
@@ -31,6 +31,9 @@
 //! - Chan's method: Infinite series for special cases
 //! - Modern methods: Monte Carlo or advanced analytical techniques
 //!
+//! Conjunction Data Messages (CCSDS 508.0) are read and written by
+//! [`crate::ccsds::cdm`].
+//!
 //! # Limitations
 //!
 //! This module provides **basic** conjunction analysis suitable for:
@@ -284,6 +287,107 @@ pub fn closest_approach_distance(
     Ok(result.miss_distance)
 }
 
+/// Compute TCA and miss distance for two trajectories given as functions of time
+///
+/// For motion that is not two-body, such as interpolated ephemerides or
+/// numerically propagated orbits. The distance is sampled every `step`
+/// seconds over [t_start, t_end], and the closest sample is refined by golden
+/// section search over its two neighbouring intervals.
+///
+/// # Arguments
+///
+/// * `trajectory1` - Position (m) and velocity (m/s) of object 1 at time t (s)
+/// * `trajectory2` - Position (m) and velocity (m/s) of object 2 at time t (s),
+///   in the same frame as object 1
+/// * `t_start`, `t_end` - Search window (seconds)
+/// * `step` - Sampling step (seconds); a small fraction of the orbital period,
+///   so that no close approach falls between samples unnoticed
+/// * `collision_threshold` - Distance threshold for collision risk (meters)
+///
+/// # Returns
+///
+/// `ConjunctionResult` with the TCA in the time argument of the trajectories
+///
+/// # Example
+///
+/// ```ignore
+/// use astrora::satellite::conjunction::compute_conjunction_between;
+///
+/// // Two objects in straight-line motion
+/// let result = compute_conjunction_between(
+///     |t| (r1 + v1 * t, v1),
+///     |t| (r2 + v2 * t, v2),
+///     0.0, 3600.0, 60.0, 5000.0,
+/// )?;
+/// ```
+pub fn compute_conjunction_between<F, G>(
+    trajectory1: F,
+    trajectory2: G,
+    t_start: f64,
+    t_end: f64,
+    step: f64,
+    collision_threshold: f64,
+) -> PoliastroResult<ConjunctionResult>
+where
+    F: Fn(f64) -> (Vector3, Vector3),
+    G: Fn(f64) -> (Vector3, Vector3),
+{
+    if t_end <= t_start {
+        return Err(PoliastroError::invalid_parameter(
+            "t_end",
+            t_end,
+            "must be after t_start",
+        ));
+    }
+
+    if step <= 0.0 {
+        return Err(PoliastroError::invalid_parameter("step", step, "must be positive"));
+    }
+
+    if collision_threshold <= 0.0 {
+        return Err(PoliastroError::invalid_parameter(
+            "collision_threshold",
+            collision_threshold,
+            "must be positive",
+        ));
+    }
+
+    let distance_at_time = |t: f64| -> f64 { (trajectory1(t).0 - trajectory2(t).0).norm() };
+
+    // Coarse sampling, always including the end of the window
+    let samples = ((t_end - t_start) / step).ceil() as usize;
+    let times: Vec<f64> = (0..=samples)
+        .map(|k| (t_start + k as f64 * step).min(t_end))
+        .collect();
+    let closest = (0..times.len())
+        .min_by(|&i, &j| distance_at_time(times[i]).total_cmp(&distance_at_time(times[j])))
+        .unwrap_or(0);
+
+    // Refine between the neighbouring samples
+    let a = times[closest.saturating_sub(1)];
+    let b = times[(closest + 1).min(times.len() - 1)];
+    let tca = if b > a {
+        golden_section_search(distance_at_time, a, b, 1e-6) // 1 µs precision
+    } else {
+        a
+    };
+
+    let (r1_tca, v1_tca) = trajectory1(tca);
+    let (r2_tca, v2_tca) = trajectory2(tca);
+
+    let relative_position = r1_tca - r2_tca;
+    let relative_velocity = v1_tca - v2_tca;
+    let miss_distance = relative_position.norm();
+
+    Ok(ConjunctionResult {
+        tca,
+        miss_distance,
+        relative_position,
+        relative_velocity,
+        collision_risk: miss_distance < collision_threshold,
+    })
+}
+
 /// Propagate Keplerian orbit (two-body problem)
 ///
 /// Simple analytical propagation using f and g functions.
@@ -463,6 +567,34 @@ mod tests {
         assert_relative_eq!(v1.norm(), v0.norm(), epsilon = 1.0);
     }
 
+    #[test]
+    fn test_conjunction_between_straight_lines() {
+        // Two objects crossing 200 m apart at t = 1234.5 s
+        let tca = 1234.5;
+        let object1 = |t: f64| {
+            let v = Vector3::new(7000.0, 0.0, 0.0);
+            (v * (t - tca), v)
+        };
+        let object2 = |t: f64| {
+            let v = Vector3::new(0.0, 7000.0, 0.0);
+            (Vector3::new(0.0, 0.0, 200.0) + v * (t - tca), v)
+        };
+
+        let result = compute_conjunction_between(object1, object2, 0.0, 3600.0, 60.0, 1000.0).unwrap();
+        assert_relative_eq!(result.tca, tca, epsilon = 1e-2);
+        assert_relative_eq!(result.miss_distance, 200.0, epsilon = 1e-3);
+        assert_relative_eq!(result.relative_velocity.norm(), 7000.0 * 2.0_f64.sqrt(), epsilon = 1e-9);
+        assert!(result.collision_risk);
+
+        // The closest point of a window that ends before the encounter is its end
+        let early = compute_conjunction_between(object1, object2, 0.0, 1000.0, 60.0, 1000.0).unwrap();
+        assert_relative_eq!(early.tca, 1000.0, epsilon = 1e-2);
+        assert!(!early.collision_risk);
+
+        assert!(compute_conjunction_between(object1, object2, 10.0, 0.0, 60.0, 1000.0).is_err());
+        assert!(compute_conjunction_between(object1, object2, 0.0, 10.0, 0.0, 1000.0).is_err());
+    }
+
     #[test]
     fn test_golden_section_search() {
         // Test with simple quadratic function
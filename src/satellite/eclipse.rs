@@ -104,7 +104,9 @@
 use nalgebra::Vector3;
 use std::f64::consts::PI;
 
+use crate::core::time::Epoch;
 use crate::core::PoliastroResult;
+use crate::ephemeris::{ephemeris_position, Body};
 
 /// Eclipse state for a satellite
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -218,6 +220,66 @@ pub fn compute_eclipse_state(r_sat: &Vector3<f64>, r_sun: &Vector3<f64>) -> Ecli
     }
 }
 
+/// Angular margin of a satellite outside a shadow cone (rad)
+///
+/// Continuous version of the test in [`compute_eclipse_state`]: the angle
+/// between the satellite and the shadow axis minus the cone half-angle plus
+/// the satellite's angular radius of the Earth. Negative inside the cone,
+/// so its zero crossings are shadow entries (decreasing) and exits
+/// (increasing).
+///
+/// # Arguments
+///
+/// * `r_sat` - Satellite position vector in ECI frame (m)
+/// * `r_sun` - Sun position vector from Earth in ECI frame (m)
+/// * `cone` - `Umbra` for the umbral cone; `Penumbra` or `Sunlit` for the
+///   penumbral cone (the boundary of full sunlight)
+pub fn shadow_cone_margin(r_sat: &Vector3<f64>, r_sun: &Vector3<f64>, cone: EclipseState) -> f64 {
+    use eclipse_constants::*;
+
+    let sun_distance = r_sun.magnitude();
+    let cone_angle = match cone {
+        EclipseState::Umbra => ((SUN_RADIUS - EARTH_RADIUS) / sun_distance).atan(),
+        EclipseState::Penumbra | EclipseState::Sunlit => {
+            ((SUN_RADIUS + EARTH_RADIUS) / sun_distance).atan()
+        }
+    };
+    let sat_angle = (EARTH_RADIUS / r_sat.magnitude()).asin();
+
+    let cos_theta = (-r_sun.normalize()).dot(&r_sat.normalize()).clamp(-1.0, 1.0);
+    cos_theta.acos() - (cone_angle + sat_angle)
+}
+
+/// Geocentric Sun position at an epoch
+///
+/// Uses the installed ephemeris (e.g. a JPL SPK kernel), or the built-in
+/// analytic ephemeris when none is installed.
+///
+/// # Returns
+///
+/// Sun position vector from Earth in GCRS axes (m)
+pub fn sun_position(epoch: &Epoch) -> PoliastroResult<Vector3<f64>> {
+    ephemeris_position(Body::Sun, Body::Earth, epoch)
+}
+
+/// Determine eclipse state for a satellite position at an epoch
+///
+/// Same as [`compute_eclipse_state`], with the Sun position taken from the
+/// ephemeris subsystem (see [`sun_position`]).
+///
+/// # Arguments
+///
+/// * `r_sat` - Satellite position vector in GCRS (m)
+/// * `epoch` - Epoch of the satellite position
+///
+/// # Errors
+///
+/// Returns an error if the installed ephemeris does not cover `epoch`.
+pub fn eclipse_state_at(r_sat: &Vector3<f64>, epoch: &Epoch) -> PoliastroResult<EclipseState> {
+    let r_sun = sun_position(epoch)?;
+    Ok(compute_eclipse_state(r_sat, &r_sun))
+}
+
 /// Calculate solar beta angle for a satellite orbit
 ///
 /// The beta angle (β) is the angle between the orbital plane and the Sun vector.
@@ -507,6 +569,20 @@ mod tests {
     use super::*;
     use approx::assert_relative_eq;
 
+    #[test]
+    fn test_shadow_cone_margin_matches_state() {
+        let r_sun = Vector3::new(1.496e11, 0.0, 0.0);
+        for angle in [0.0_f64, 2.0, 2.9, 3.0, 3.05, 3.1, 3.14] {
+            let r_sat = Vector3::new(7000e3 * angle.cos(), 7000e3 * angle.sin(), 0.0);
+            let state = compute_eclipse_state(&r_sat, &r_sun);
+            let umbra = shadow_cone_margin(&r_sat, &r_sun, EclipseState::Umbra);
+            let penumbra = shadow_cone_margin(&r_sat, &r_sun, EclipseState::Penumbra);
+            assert_eq!(umbra < 0.0, state == EclipseState::Umbra);
+            assert_eq!(penumbra < 0.0, state != EclipseState::Sunlit);
+            assert!(umbra > penumbra);
+        }
+    }
+
     #[test]
     fn test_sunlit_satellite_same_side_as_sun() {
         // Satellite and sun on same side of Earth
@@ -528,6 +604,20 @@ mod tests {
         assert_eq!(state, EclipseState::Umbra);
     }
 
+    #[test]
+    fn test_eclipse_state_at_epoch() {
+        // Near the March equinox the Sun is close to +X in GCRS
+        let epoch = Epoch::from_gregorian_utc(2024, 3, 20, 3, 6, 0, 0);
+        let r_sun = sun_position(&epoch).unwrap();
+        assert!(r_sun.x > 0.99 * r_sun.norm());
+        assert_relative_eq!(r_sun.norm(), 1.496e11, max_relative = 0.02);
+
+        let r_day = Vector3::new(7000e3, 0.0, 0.0);
+        let r_night = Vector3::new(-7000e3, 0.0, 0.0);
+        assert_eq!(eclipse_state_at(&r_day, &epoch).unwrap(), EclipseState::Sunlit);
+        assert_eq!(eclipse_state_at(&r_night, &epoch).unwrap(), EclipseState::Umbra);
+    }
+
     #[test]
     fn test_beta_angle_zero_maximum_eclipse() {
         // Orbit plane contains the Sun (β = 0)
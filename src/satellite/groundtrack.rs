@@ -92,12 +92,16 @@
 
 use std::f64::consts::PI;
 
+use crate::core::constants;
+use crate::ephemeris::Body;
+
 /// WGS84 Earth ellipsoid parameters
 const WGS84_A: f64 = 6378.137;           // Semi-major axis (km)
+#[cfg(test)]
 const WGS84_B: f64 = 6356.752314245;     // Semi-minor axis (km)
 const WGS84_F: f64 = 1.0 / 298.257223563; // Flattening
+#[cfg(test)]
 const WGS84_E2: f64 = WGS84_F * (2.0 - WGS84_F); // First eccentricity squared
-const WGS84_EP2: f64 = (WGS84_A * WGS84_A - WGS84_B * WGS84_B) / (WGS84_B * WGS84_B); // Second eccentricity squared
 
 /// Convergence tolerance for iterative geodetic conversion (radians)
 /// This corresponds to approximately 1 mm on Earth's surface
@@ -142,6 +146,105 @@ impl GeodeticCoordinates {
     }
 }
 
+/// Reference ellipsoid of revolution (oblate spheroid)
+///
+/// Used for geodetic conversions on Earth and other bodies. A flattening of
+/// zero describes a sphere, for which geodetic and planetocentric latitude
+/// coincide.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipsoid {
+    /// Equatorial radius (km)
+    pub equatorial_radius: f64,
+    /// Flattening f = (a - b) / a
+    pub flattening: f64,
+}
+
+impl Ellipsoid {
+    /// WGS84 Earth ellipsoid
+    pub const WGS84: Ellipsoid = Ellipsoid {
+        equatorial_radius: WGS84_A,
+        flattening: WGS84_F,
+    };
+
+    /// Create an ellipsoid from its equatorial radius (km) and flattening
+    pub fn new(equatorial_radius: f64, flattening: f64) -> Self {
+        Self {
+            equatorial_radius,
+            flattening,
+        }
+    }
+
+    /// Create an ellipsoid from its equatorial and polar radii (km)
+    pub fn from_radii(equatorial_radius: f64, polar_radius: f64) -> Self {
+        Self::new(
+            equatorial_radius,
+            (equatorial_radius - polar_radius) / equatorial_radius,
+        )
+    }
+
+    /// Reference ellipsoid of `body`, from the radii in [`crate::core::constants`]
+    ///
+    /// Planets may be given as their system barycenter or the planet itself
+    /// (e.g. NAIF 4 or 499). The Galilean satellites, Titan and Triton are
+    /// treated as spheres of their mean radius. Returns `None` for bodies
+    /// without tabulated radii.
+    pub fn for_body(body: Body) -> Option<Self> {
+        let (a, b) = match body {
+            Body::Earth => return Some(Self::WGS84),
+            Body::Sun => (constants::R_SUN, constants::R_SUN),
+            Body::Mercury | Body::Naif(199) => (constants::R_MERCURY, constants::R_POLAR_MERCURY),
+            Body::Venus | Body::Naif(299) => (constants::R_VENUS, constants::R_POLAR_VENUS),
+            Body::Moon => (constants::R_MOON, constants::R_POLAR_MOON),
+            Body::Mars | Body::Naif(499) => (constants::R_MARS, constants::R_POLAR_MARS),
+            Body::Jupiter | Body::Naif(599) => (constants::R_JUPITER, constants::R_POLAR_JUPITER),
+            Body::Saturn | Body::Naif(699) => (constants::R_SATURN, constants::R_POLAR_SATURN),
+            Body::Uranus | Body::Naif(799) => (constants::R_URANUS, constants::R_POLAR_URANUS),
+            Body::Neptune | Body::Naif(899) => (constants::R_NEPTUNE, constants::R_POLAR_NEPTUNE),
+            Body::Pluto | Body::Naif(999) => (constants::R_PLUTO, constants::R_POLAR_PLUTO),
+            Body::Naif(501) => (constants::R_IO, constants::R_IO),
+            Body::Naif(502) => (constants::R_EUROPA, constants::R_EUROPA),
+            Body::Naif(503) => (constants::R_GANYMEDE, constants::R_GANYMEDE),
+            Body::Naif(504) => (constants::R_CALLISTO, constants::R_CALLISTO),
+            Body::Naif(606) => (constants::R_TITAN, constants::R_TITAN),
+            Body::Naif(801) => (constants::R_TRITON, constants::R_TRITON),
+            _ => return None,
+        };
+        Some(Self::from_radii(a / 1000.0, b / 1000.0))
+    }
+
+    /// Polar radius b = a(1 - f) (km)
+    pub fn polar_radius(&self) -> f64 {
+        self.equatorial_radius * (1.0 - self.flattening)
+    }
+
+    /// First eccentricity squared e² = f(2 - f)
+    pub fn eccentricity_squared(&self) -> f64 {
+        self.flattening * (2.0 - self.flattening)
+    }
+
+    /// Convert geodetic coordinates on this ellipsoid to body-fixed Cartesian
+    /// coordinates (km)
+    pub fn geodetic_to_cartesian(&self, latitude: f64, longitude: f64, altitude: f64) -> [f64; 3] {
+        let e2 = self.eccentricity_squared();
+        let (sin_lat, cos_lat) = latitude.sin_cos();
+        let (sin_lon, cos_lon) = longitude.sin_cos();
+
+        let n = self.equatorial_radius / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+
+        [
+            (n + altitude) * cos_lat * cos_lon,
+            (n + altitude) * cos_lat * sin_lon,
+            (n * (1.0 - e2) + altitude) * sin_lat,
+        ]
+    }
+}
+
+impl Default for Ellipsoid {
+    fn default() -> Self {
+        Self::WGS84
+    }
+}
+
 /// Convert ECEF Cartesian coordinates to geodetic coordinates (WGS84)
 ///
 /// Uses an iterative algorithm to compute geodetic latitude, longitude, and altitude
@@ -183,9 +286,33 @@ impl GeodeticCoordinates {
 ///          geodetic.altitude);
 /// ```
 pub fn ecef_to_geodetic(ecef: &[f64; 3]) -> GeodeticCoordinates {
-    let x = ecef[0];
-    let y = ecef[1];
-    let z = ecef[2];
+    ecef_to_geodetic_on(ecef, &Ellipsoid::WGS84)
+}
+
+/// Convert body-fixed Cartesian coordinates to geodetic coordinates on an
+/// arbitrary reference ellipsoid
+///
+/// Same algorithm as [`ecef_to_geodetic`], for use with other bodies, e.g.
+/// with `Ellipsoid::for_body(Body::Mars)` for positions in the Mars
+/// body-fixed frame.
+///
+/// # Arguments
+///
+/// * `position` - Body-fixed position [x, y, z] in km
+/// * `ellipsoid` - Reference ellipsoid of the body
+///
+/// # Returns
+///
+/// `GeodeticCoordinates` with latitude, longitude (radians), and altitude
+/// above the ellipsoid (km)
+pub fn ecef_to_geodetic_on(position: &[f64; 3], ellipsoid: &Ellipsoid) -> GeodeticCoordinates {
+    let a = ellipsoid.equatorial_radius;
+    let b = ellipsoid.polar_radius();
+    let e2 = ellipsoid.eccentricity_squared();
+
+    let x = position[0];
+    let y = position[1];
+    let z = position[2];
 
     // Longitude is computed directly
     let longitude = y.atan2(x);
@@ -196,12 +323,12 @@ pub fn ecef_to_geodetic(ecef: &[f64; 3]) -> GeodeticCoordinates {
     // Handle special case: point on polar axis
     if p < 1e-10 {
         let latitude = if z >= 0.0 { PI / 2.0 } else { -PI / 2.0 };
-        let altitude = z.abs() - WGS84_B;
+        let altitude = z.abs() - b;
         return GeodeticCoordinates::new(latitude, longitude, altitude);
     }
 
     // Initial latitude estimate (Bowring's formula for first approximation)
-    let mut latitude = (z / ((1.0 - WGS84_E2) * p)).atan();
+    let mut latitude = (z / ((1.0 - e2) * p)).atan();
 
     // Iterative refinement of latitude and altitude
     let mut altitude = 0.0;
@@ -210,17 +337,17 @@ pub fn ecef_to_geodetic(ecef: &[f64; 3]) -> GeodeticCoordinates {
         let cos_lat = latitude.cos();
 
         // Radius of curvature in the prime vertical
-        let n = WGS84_A / (1.0 - WGS84_E2 * sin_lat * sin_lat).sqrt();
+        let n = a / (1.0 - e2 * sin_lat * sin_lat).sqrt();
 
         // Altitude above ellipsoid
         let h_new = if cos_lat.abs() > 1e-10 {
             p / cos_lat - n
         } else {
-            z / sin_lat - n * (1.0 - WGS84_E2)
+            z / sin_lat - n * (1.0 - e2)
         };
 
         // Improved latitude estimate
-        let lat_new = (z / ((1.0 - WGS84_E2 * n / (n + h_new)) * p)).atan();
+        let lat_new = (z / ((1.0 - e2 * n / (n + h_new)) * p)).atan();
 
         // Check convergence
         let lat_change = (lat_new - latitude).abs();
@@ -623,4 +750,55 @@ mod tests {
 
         assert!(range_800 > range_400);
     }
+
+    #[test]
+    fn test_wgs84_ellipsoid_matches_constants() {
+        let wgs84 = Ellipsoid::for_body(Body::Earth).unwrap();
+        assert_eq!(wgs84, Ellipsoid::WGS84);
+        assert_relative_eq!(wgs84.polar_radius(), WGS84_B, epsilon = 1e-6);
+        assert_relative_eq!(wgs84.eccentricity_squared(), WGS84_E2, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_ellipsoid_for_body() {
+        let mars = Ellipsoid::for_body(Body::Mars).unwrap();
+        assert_relative_eq!(mars.equatorial_radius, 3396.2, epsilon = 1e-9);
+        assert_relative_eq!(mars.polar_radius(), 3376.2, epsilon = 1e-9);
+        assert_eq!(Ellipsoid::for_body(Body::Naif(499)), Some(mars));
+
+        // Satellites without tabulated polar radii are spheres
+        let io = Ellipsoid::for_body(Body::Naif(501)).unwrap();
+        assert_eq!(io.flattening, 0.0);
+
+        assert!(Ellipsoid::for_body(Body::Naif(-82)).is_none());
+    }
+
+    #[test]
+    fn test_geodetic_roundtrip_on_mars() {
+        let mars = Ellipsoid::for_body(Body::Mars).unwrap();
+        let latitude = -14.57_f64.to_radians();
+        let longitude = 175.47_f64.to_radians();
+
+        let position = mars.geodetic_to_cartesian(latitude, longitude, 3.5);
+        let geodetic = ecef_to_geodetic_on(&position, &mars);
+
+        assert_relative_eq!(geodetic.latitude, latitude, epsilon = 1e-11);
+        assert_relative_eq!(geodetic.longitude, longitude, epsilon = 1e-11);
+        assert_relative_eq!(geodetic.altitude, 3.5, epsilon = 1e-6);
+
+        // North pole of Mars
+        let pole = ecef_to_geodetic_on(&[0.0, 0.0, 3386.2], &mars);
+        assert_relative_eq!(pole.latitude, PI / 2.0, epsilon = 1e-12);
+        assert_relative_eq!(pole.altitude, 10.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_geodetic_on_sphere_is_geocentric() {
+        let sphere = Ellipsoid::new(1737.4, 0.0);
+        let geodetic = ecef_to_geodetic_on(&[1000.0, 1000.0, 1000.0], &sphere);
+
+        assert_relative_eq!(geodetic.latitude, (1.0 / 2.0_f64.sqrt()).atan(), epsilon = 1e-12);
+        assert_relative_eq!(geodetic.longitude, PI / 4.0, epsilon = 1e-12);
+        assert_relative_eq!(geodetic.altitude, 3.0_f64.sqrt() * 1000.0 - 1737.4, epsilon = 1e-9);
+    }
 }
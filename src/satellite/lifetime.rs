@@ -14,7 +14,8 @@
 //! ```
 //!
 //! Where:
-//! - ρ: atmospheric density (exponential model: ρ = ρ₀ * exp(-(h - h₀)/H))
+//! - ρ: atmospheric density (exponential model: ρ = ρ₀ * exp(-(h - h₀)/H), or any
+//!   [`AtmosphereModel`] via [`estimate_lifetime_with_atmosphere`])
 //! - v: velocity relative to atmosphere
 //! - Cd: drag coefficient (~2.2 for satellites)
 //! - A: cross-sectional area
@@ -41,7 +42,11 @@
 
 use crate::core::error::{PoliastroError, PoliastroResult};
 use crate::core::linalg::Vector3;
-use crate::propagators::perturbations::{drag_acceleration, j2_perturbation};
+use crate::core::time::Epoch;
+use crate::propagators::atmosphere::{
+    velocity_relative_to_atmosphere, AtmosphereModel, ExponentialAtmosphere,
+};
+use crate::propagators::perturbations::j2_perturbation;
 use crate::core::constants::{GM_EARTH, J2_EARTH, R_EARTH};
 
 /// Default terminal altitude for reentry (Karman line) in meters
@@ -60,6 +65,9 @@ pub const DEFAULT_SCALE_HEIGHT: f64 = 8500.0;
 /// Typical drag coefficient for satellites
 pub const TYPICAL_DRAG_COEFFICIENT: f64 = 2.2;
 
+/// Initial step of the decay propagation with an atmosphere model (s)
+pub const DEFAULT_INITIAL_TIME_STEP: f64 = 60.0;
+
 /// Estimate satellite lifetime with atmospheric drag
 ///
 /// Propagates the orbit forward in time using RK4 integration with drag and J2 perturbations
@@ -72,7 +80,8 @@ pub const TYPICAL_DRAG_COEFFICIENT: f64 = 2.2;
 /// * `ballistic_coeff` - Ballistic coefficient Cd*A/m in m²/kg (typical: 0.001-0.1)
 /// * `terminal_altitude` - Altitude below which satellite is considered reentered (m, default: 100 km)
 /// * `max_time` - Maximum propagation time in seconds (to prevent infinite loops)
-/// * `time_step` - Integration time step in seconds (adaptive internally)
+/// * `initial_time_step` - First integration step in seconds (later steps
+///   adapt to the altitude)
 ///
 /// # Returns
 ///
@@ -103,7 +112,7 @@ pub const TYPICAL_DRAG_COEFFICIENT: f64 = 2.2;
 ///     B,
 ///     100e3,  // 100 km terminal altitude
 ///     365.25 * 86400.0, // Max 1 year
-///     600.0   // 10-minute time steps
+///     600.0   // 10-minute first step
 /// ).unwrap() / 86400.0;
 ///
 /// println!("Estimated lifetime: {:.1} days", lifetime_days);
@@ -111,9 +120,12 @@ pub const TYPICAL_DRAG_COEFFICIENT: f64 = 2.2;
 ///
 /// # Notes
 ///
-/// - Uses exponential atmospheric model (suitable for LEO, not high accuracy)
+/// - Uses exponential atmospheric model (suitable for LEO, not high accuracy);
+///   see [`estimate_lifetime_with_atmosphere`] for solar-activity-driven models
 /// - Includes J2 perturbation for realistic orbit evolution
-/// - Time step is adaptive: larger steps at higher altitudes
+/// - Drag acts on the inertial velocity; [`estimate_lifetime_with_atmosphere`]
+///   accounts for the co-rotating atmosphere
+/// - Time step is adaptive after the first one: larger steps at higher altitudes
 /// - For very low ballistic coefficients, may take hours to compute
 /// - Does not account for solar activity variations (assumes nominal conditions)
 pub fn estimate_lifetime(
@@ -123,6 +135,95 @@ pub fn estimate_lifetime(
     terminal_altitude: f64,
     max_time: f64,
     initial_time_step: f64,
+) -> PoliastroResult<f64> {
+    let atmosphere =
+        ExponentialAtmosphere::new(R_EARTH + DEFAULT_H0, DEFAULT_RHO0, DEFAULT_SCALE_HEIGHT);
+    integrate_lifetime(
+        r0,
+        v0,
+        &Epoch::j2000(),
+        ballistic_coeff,
+        &atmosphere,
+        terminal_altitude,
+        max_time,
+        initial_time_step,
+        false,
+    )
+}
+
+/// Estimate satellite lifetime with a selectable atmosphere model
+///
+/// Same propagation as [`estimate_lifetime`], with the density taken from
+/// `atmosphere` along the trajectory, so that models such as
+/// [`Jacchia71`](crate::propagators::atmosphere::Jacchia71) account for
+/// solar activity, the diurnal bulge and the satellite's geodetic position.
+///
+/// # Arguments
+///
+/// * `r0` - Initial GCRS position vector [x, y, z] in meters
+/// * `v0` - Initial GCRS velocity vector [vx, vy, vz] in m/s
+/// * `epoch` - Epoch of the initial state
+/// * `ballistic_coeff` - Ballistic coefficient Cd*A/m in m²/kg
+/// * `atmosphere` - Atmosphere model
+/// * `terminal_altitude` - Altitude below which satellite is considered reentered (m)
+/// * `max_time` - Maximum propagation time in seconds
+///
+/// # Returns
+///
+/// Estimated lifetime in seconds
+///
+/// # Example
+///
+/// ```ignore
+/// use astrora::propagators::atmosphere::{Jacchia71, SolarActivity};
+/// use astrora::satellite::lifetime::estimate_lifetime_with_atmosphere;
+///
+/// let atmosphere = Jacchia71::new(SolarActivity::new(180.0, 170.0, 12.0));
+/// let lifetime = estimate_lifetime_with_atmosphere(
+///     &r0, &v0, &epoch, 0.02, &atmosphere, 100e3, 365.25 * 86400.0,
+/// )?;
+/// ```
+///
+/// # Notes
+///
+/// - Drag acts on the velocity relative to the co-rotating atmosphere
+pub fn estimate_lifetime_with_atmosphere(
+    r0: &Vector3,
+    v0: &Vector3,
+    epoch: &Epoch,
+    ballistic_coeff: f64,
+    atmosphere: &dyn AtmosphereModel,
+    terminal_altitude: f64,
+    max_time: f64,
+) -> PoliastroResult<f64> {
+    integrate_lifetime(
+        r0,
+        v0,
+        epoch,
+        ballistic_coeff,
+        atmosphere,
+        terminal_altitude,
+        max_time,
+        DEFAULT_INITIAL_TIME_STEP,
+        true,
+    )
+}
+
+/// RK4 propagation with drag and J2 until the terminal altitude
+///
+/// With `co_rotating`, drag acts on the velocity relative to the atmosphere
+/// co-rotating with the Earth, otherwise on the inertial velocity.
+#[allow(clippy::too_many_arguments)]
+fn integrate_lifetime(
+    r0: &Vector3,
+    v0: &Vector3,
+    epoch: &Epoch,
+    ballistic_coeff: f64,
+    atmosphere: &dyn AtmosphereModel,
+    terminal_altitude: f64,
+    max_time: f64,
+    initial_time_step: f64,
+    co_rotating: bool,
 ) -> PoliastroResult<f64> {
     // Validate inputs
     if ballistic_coeff <= 0.0 {
@@ -168,11 +269,7 @@ pub fn estimate_lifetime(
     let mut v = *v0;
     let mut time = 0.0;
     let mut dt = initial_time_step;
-
-    // Atmospheric parameters
-    let rho0 = DEFAULT_RHO0;
-    let h_ref = DEFAULT_H0;
-    let H = DEFAULT_SCALE_HEIGHT;
+    let t0 = epoch.to_tdb_seconds_since_j2000();
 
     // Adaptive time stepping based on altitude
     // Higher altitudes = slower decay = can use larger time steps
@@ -195,70 +292,62 @@ pub fn estimate_lifetime(
         }
     };
 
+    // Gravity + J2 + drag (B = Cd*A/m, so the drag scales with B)
+    let acceleration = |t: f64, r: &Vector3, v: &Vector3| -> Vector3 {
+        let r_mag = r.norm();
+        let rho = atmosphere.density(&Epoch::from_tdb_seconds_since_j2000(t0 + t), r);
+        let v_rel = if co_rotating {
+            velocity_relative_to_atmosphere(r, v)
+        } else {
+            *v
+        };
+        let a_drag = v_rel.scale(-0.5 * rho * v_rel.norm() * ballistic_coeff);
+        let a_j2 = j2_perturbation(r, GM_EARTH, J2_EARTH, R_EARTH);
+        r.scale(-GM_EARTH / (r_mag * r_mag * r_mag)) + a_drag + a_j2
+    };
+
     // Propagate until terminal altitude or max time
     while time < max_time {
         // Current altitude
-        let r_mag = r.norm();
-        let altitude = r_mag - R_EARTH;
+        let altitude = r.norm() - R_EARTH;
 
         // Check if we've reached terminal altitude
         if altitude < terminal_altitude {
             return Ok(time);
         }
 
-        // Adaptive time step based on altitude
-        dt = get_adaptive_dt(altitude).min(max_time - time);
+        dt = dt.min(max_time - time);
 
         // RK4 integration step with drag + J2
         // k1 = f(t, y)
-        let a_drag1 = drag_acceleration(&r, &v, R_EARTH, rho0, h_ref, ballistic_coeff);
-        let a_j2_1 = j2_perturbation(&r, GM_EARTH, J2_EARTH, R_EARTH);
-        let a_gravity1 = r.scale(-GM_EARTH / (r_mag * r_mag * r_mag));
-        let a1 = a_gravity1 + a_drag1 + a_j2_1;
-
         let k1_r = v;
-        let k1_v = a1;
+        let k1_v = acceleration(time, &r, &v);
 
         // k2 = f(t + dt/2, y + k1*dt/2)
         let r2 = r + k1_r.scale(dt / 2.0);
         let v2 = v + k1_v.scale(dt / 2.0);
-        let r2_mag = r2.norm();
-        let a_drag2 = drag_acceleration(&r2, &v2, R_EARTH, rho0, h_ref, ballistic_coeff);
-        let a_j2_2 = j2_perturbation(&r2, GM_EARTH, J2_EARTH, R_EARTH);
-        let a_gravity2 = r2.scale(-GM_EARTH / (r2_mag * r2_mag * r2_mag));
-        let a2 = a_gravity2 + a_drag2 + a_j2_2;
-
         let k2_r = v2;
-        let k2_v = a2;
+        let k2_v = acceleration(time + dt / 2.0, &r2, &v2);
 
         // k3 = f(t + dt/2, y + k2*dt/2)
         let r3 = r + k2_r.scale(dt / 2.0);
         let v3 = v + k2_v.scale(dt / 2.0);
-        let r3_mag = r3.norm();
-        let a_drag3 = drag_acceleration(&r3, &v3, R_EARTH, rho0, h_ref, ballistic_coeff);
-        let a_j2_3 = j2_perturbation(&r3, GM_EARTH, J2_EARTH, R_EARTH);
-        let a_gravity3 = r3.scale(-GM_EARTH / (r3_mag * r3_mag * r3_mag));
-        let a3 = a_gravity3 + a_drag3 + a_j2_3;
-
         let k3_r = v3;
-        let k3_v = a3;
+        let k3_v = acceleration(time + dt / 2.0, &r3, &v3);
 
         // k4 = f(t + dt, y + k3*dt)
         let r4 = r + k3_r.scale(dt);
         let v4 = v + k3_v.scale(dt);
-        let r4_mag = r4.norm();
-        let a_drag4 = drag_acceleration(&r4, &v4, R_EARTH, rho0, h_ref, ballistic_coeff);
-        let a_j2_4 = j2_perturbation(&r4, GM_EARTH, J2_EARTH, R_EARTH);
-        let a_gravity4 = r4.scale(-GM_EARTH / (r4_mag * r4_mag * r4_mag));
-        let a4 = a_gravity4 + a_drag4 + a_j2_4;
-
         let k4_r = v4;
-        let k4_v = a4;
+        let k4_v = acceleration(time + dt, &r4, &v4);
 
         // Update state: y_{n+1} = y_n + (dt/6) * (k1 + 2*k2 + 2*k3 + k4)
         r += (k1_r + k2_r.scale(2.0) + k3_r.scale(2.0) + k4_r).scale(dt / 6.0);
         v += (k1_v + k2_v.scale(2.0) + k3_v.scale(2.0) + k4_v).scale(dt / 6.0);
         time += dt;
+
+        // Adaptive time step based on altitude after the initial step
+        dt = get_adaptive_dt(r.norm() - R_EARTH);
     }
 
     // If we got here, satellite didn't decay within max_time
@@ -329,6 +418,74 @@ mod tests {
     use super::*;
     use approx::assert_relative_eq;
 
+    fn circular_state(altitude: f64) -> (Vector3, Vector3) {
+        let r = R_EARTH + altitude;
+        (Vector3::new(r, 0.0, 0.0), Vector3::new(0.0, (GM_EARTH / r).sqrt(), 0.0))
+    }
+
+    #[test]
+    fn test_lifetime_with_atmosphere_solar_activity() {
+        use crate::propagators::atmosphere::{Jacchia71, SolarActivity};
+
+        let (r0, v0) = circular_state(150_000.0);
+        let epoch = Epoch::from_gregorian_utc(2024, 6, 1, 0, 0, 0, 0);
+        let lifetime = |activity| {
+            estimate_lifetime_with_atmosphere(
+                &r0,
+                &v0,
+                &epoch,
+                0.02,
+                &Jacchia71::new(activity),
+                120_000.0,
+                10.0 * 86400.0,
+            )
+            .unwrap()
+        };
+
+        let quiet = lifetime(SolarActivity::new(70.0, 70.0, 4.0));
+        let active = lifetime(SolarActivity::new(250.0, 250.0, 40.0));
+        assert!(quiet > 0.0 && quiet < 2.0 * 86400.0, "quiet lifetime {} s", quiet);
+        assert!(active < quiet);
+    }
+
+    #[test]
+    fn test_lifetime_with_atmosphere_co_rotation() {
+        // Same exponential atmosphere: the prograde orbit moves slower relative to
+        // the co-rotating atmosphere and so decays later
+        let (r0, v0) = circular_state(150_000.0);
+        let inertial = estimate_lifetime(&r0, &v0, 0.02, 120_000.0, 10.0 * 86400.0, 60.0).unwrap();
+        let atmosphere =
+            ExponentialAtmosphere::new(R_EARTH + DEFAULT_H0, DEFAULT_RHO0, DEFAULT_SCALE_HEIGHT);
+        let co_rotating = estimate_lifetime_with_atmosphere(
+            &r0,
+            &v0,
+            &Epoch::j2000(),
+            0.02,
+            &atmosphere,
+            120_000.0,
+            10.0 * 86400.0,
+        )
+        .unwrap();
+        assert!(co_rotating > inertial, "{} s vs {} s", co_rotating, inertial);
+    }
+
+    #[test]
+    fn test_lifetime_with_atmosphere_validation() {
+        use crate::propagators::atmosphere::HarrisPriester;
+
+        let (r0, v0) = circular_state(400_000.0);
+        let result = estimate_lifetime_with_atmosphere(
+            &r0,
+            &v0,
+            &Epoch::j2000(),
+            0.0,
+            &HarrisPriester::default(),
+            100_000.0,
+            86400.0,
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_decay_rate_higher_altitude_slower() {
         // Higher altitude should have slower decay
@@ -379,7 +536,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore] // Slow test requiring significant integration time
     fn test_lifetime_estimation_basic() {
         // Test basic lifetime estimation for a satellite that will decay quickly
 
@@ -412,7 +568,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore] // Slow test requiring significant integration time
     fn test_lifetime_higher_orbit_longer() {
         // Higher initial altitude should give longer lifetime
 
@@ -637,7 +792,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore] // Fast test with extreme parameters for quick decay
     fn test_lifetime_extreme_parameters() {
         // Test with extreme parameters for very fast decay
         // Very low orbit with huge ballistic coefficient
@@ -665,6 +819,21 @@ mod tests {
         assert!(time < 3600.0); // Less than 1 hour
     }
 
+    #[test]
+    fn test_lifetime_initial_time_step() {
+        // The first step has the given size, the following ones follow the
+        // altitude (10 s below 150 km)
+        let r0 = Vector3::new(R_EARTH + 110_000.0, 0.0, 0.0);
+        let v0 = Vector3::new(0.0, (GM_EARTH / r0.norm()).sqrt(), 0.0);
+
+        for initial_time_step in [1.0, 3.0] {
+            let lifetime = estimate_lifetime(&r0, &v0, 1.0, 100_000.0, 3600.0, initial_time_step).unwrap();
+            let later_steps = (lifetime - initial_time_step) / 10.0;
+            assert!(later_steps >= 1.0);
+            assert_relative_eq!(later_steps, later_steps.round(), epsilon = 1e-9);
+        }
+    }
+
     #[test]
     fn test_lifetime_max_time_exceeded() {
         // Test that we get an error when max_time is too short
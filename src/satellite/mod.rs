@@ -14,7 +14,8 @@
 //! # Key Features
 //!
 //! - **TLE Parsing**: Support for 2-line and 3-line element formats
-//! - **OMM Support**: Modern JSON format for orbital elements
+//! - **OMM Support**: CelesTrak/Space-Track JSON and CCSDS KVN and XML, with metadata and covariance
+//! - **Export**: TLE text and OMM in JSON, CCSDS KVN and XML from parsed elements
 //! - **High Accuracy**: Sub-meter position errors (<0.2m after 3.5 years)
 //! - **Performance**: ~7% faster than C++ reference implementation
 //! - **Automatic Mode Selection**: Automatically uses SGP4 or SDP4 based on orbit period
@@ -63,8 +64,11 @@ pub mod lifetime;
 pub mod conjunction;
 
 pub use sgp4_wrapper::{propagate_from_elements, propagate_batch, SatelliteState, Sgp4Error};
-pub use tle::parse_tle;
-pub use omm::parse_omm;
+pub use tle::{parse_tle, format_tle};
+pub use omm::{
+    parse_omm, parse_omm_message, parse_omm_messages, write_omm, write_omm_kvn, write_omm_xml,
+    Omm, OmmMetadata,
+};
 pub use visibility::{
     Observer, TopocentricCoordinates, SatellitePass,
     compute_azimuth_elevation, compute_azimuth_elevation_rate,
@@ -72,8 +76,8 @@ pub use visibility::{
     find_next_pass, find_all_passes,
 };
 pub use groundtrack::{
-    GeodeticCoordinates, GroundTrackPoint,
-    ecef_to_geodetic, sub_satellite_point, compute_ground_track,
+    Ellipsoid, GeodeticCoordinates, GroundTrackPoint,
+    ecef_to_geodetic, ecef_to_geodetic_on, sub_satellite_point, compute_ground_track,
     calculate_swath_width, maximum_ground_range,
 };
 pub use coverage::{
@@ -87,10 +91,10 @@ pub use eclipse::{
     sun_synchronous_inclination, eclipse_duration,
 };
 pub use lifetime::{
-    estimate_lifetime, estimate_decay_rate,
+    estimate_lifetime, estimate_lifetime_with_atmosphere, estimate_decay_rate,
     DEFAULT_TERMINAL_ALTITUDE, TYPICAL_DRAG_COEFFICIENT,
 };
 pub use conjunction::{
     ConjunctionResult,
-    compute_conjunction, check_collision, closest_approach_distance,
+    compute_conjunction, compute_conjunction_between, check_collision, closest_approach_distance,
 };
@@ -1,7 +1,9 @@
-//! OMM (Orbit Mean-Elements Message) Parsing
+//! OMM (Orbit Mean-Elements Message) Parsing and Writing
 //!
-//! This module handles parsing of OMM data in JSON format, which is the
-//! modern successor to the TLE format.
+//! This module handles OMM data, the modern successor to the TLE format:
+//! parsing CelesTrak/Space-Track JSON and the CCSDS KVN and XML encodings
+//! (with their header, metadata and covariance), and writing elements back
+//! in all three.
 //!
 //! # OMM Format
 //!
@@ -60,15 +62,26 @@
 //! - <https://public.ccsds.org/Pubs/502x0b2c1.pdf>
 //! - <https://www.space-track.org/documentation#/OMM>
 
-use sgp4::Elements;
+use sgp4::{Classification, Elements};
+use serde_json::Value;
+use crate::ccsds::kvn::{self, KvnLine, KvnWriter};
+use crate::ccsds::xml::{XmlElement, XmlWriter};
+use crate::ccsds::{self, Encoding, Fields, CARTESIAN_COVARIANCE, KM2_TO_M2};
+use crate::core::constants::GM_EARTH;
+use crate::core::covariance::Covariance6;
+use crate::core::time::Epoch;
+use crate::core::{PoliastroError, PoliastroResult};
 use crate::satellite::sgp4_wrapper::Sgp4Error;
-use serde_json;
+use crate::satellite::tle::classification_code;
 
-/// Parse an OMM JSON string
+/// Parse an OMM in JSON, KVN or XML
+///
+/// The encoding is detected from the first character of the message. Use
+/// [`parse_omm_message`] to keep the header, metadata and covariance.
 ///
 /// # Arguments
 ///
-/// * `json_string` - OMM data in JSON format
+/// * `omm` - OMM data in JSON, CCSDS KVN or CCSDS XML format
 ///
 /// # Returns
 ///
@@ -76,7 +89,9 @@ use serde_json;
 ///
 /// # Errors
 ///
-/// - `OmmParsingFailed`: If JSON format is invalid or contains invalid data
+/// - `OmmParsingFailed`: If the message is malformed or contains invalid data
+/// - `InvalidElements`: If the metadata are not those of SGP4 elements (see
+///   [`OmmMetadata::check_sgp4`])
 ///
 /// # Example
 ///
@@ -96,16 +111,17 @@ use serde_json;
 ///
 /// let elements = parse_omm(omm_json)?;
 /// ```
-pub fn parse_omm(json_string: &str) -> Result<Elements, Sgp4Error> {
-    // Parse JSON into Elements using serde
-    // The sgp4::Elements struct implements Deserialize when serde feature is enabled
-    serde_json::from_str(json_string)
-        .map_err(|e| Sgp4Error::OmmParsingFailed(e.to_string()))
+pub fn parse_omm(omm: &str) -> Result<Elements, Sgp4Error> {
+    let message = parse_omm_message(omm)?;
+    message.metadata.check_sgp4()?;
+    Ok(message.elements)
 }
 
-/// Parse multiple OMM objects from a JSON array
+/// Parse multiple OMMs
 ///
-/// Some Space-Track queries return arrays of OMM objects.
+/// Some Space-Track queries return arrays of OMM objects. KVN files may hold
+/// several messages one after the other (each starting with
+/// `CCSDS_OMM_VERS`), and XML files several `<omm>` elements in an `<ndm>`.
 ///
 /// # Example
 ///
@@ -117,25 +133,550 @@ pub fn parse_omm(json_string: &str) -> Result<Elements, Sgp4Error> {
 ///
 /// let elements_vec = parse_omm_batch(omm_array)?;
 /// ```
-pub fn parse_omm_batch(json_array: &str) -> Result<Vec<Elements>, Sgp4Error> {
-    use serde_json::Value;
-
-    // Parse as JSON array
-    let array: Vec<Value> = serde_json::from_str(json_array)
-        .map_err(|e| Sgp4Error::OmmParsingFailed(format!("Invalid JSON array: {e}")))?;
-
-    // Parse each object
-    array
-        .iter()
-        .enumerate()
-        .map(|(i, obj)| {
-            let obj_str = serde_json::to_string(obj)
-                .map_err(|e| Sgp4Error::OmmParsingFailed(format!("Object {i} serialize error: {e}")))?;
-            parse_omm(&obj_str)
+///
+/// # Errors
+///
+/// As [`parse_omm`], for any of the messages.
+pub fn parse_omm_batch(omms: &str) -> Result<Vec<Elements>, Sgp4Error> {
+    parse_omm_messages(omms)?
+        .into_iter()
+        .map(|message| {
+            message.metadata.check_sgp4()?;
+            Ok(message.elements)
         })
         .collect()
 }
 
+/// Parse one OMM in JSON, KVN or XML, with its metadata and covariance
+///
+/// KVN and XML messages must carry the mandatory CCSDS header and metadata
+/// keywords. JSON objects need only the elements; metadata keywords missing
+/// from them take the defaults of [`OmmMetadata::new`], with an empty
+/// originator and creation date.
+///
+/// # Errors
+///
+/// - `OmmParsingFailed`: If the message is malformed, or holds more than one OMM
+pub fn parse_omm_message(omm: &str) -> Result<Omm, Sgp4Error> {
+    let mut messages = parse_omm_messages(omm)?;
+    match messages.len() {
+        1 => Ok(messages.remove(0)),
+        count => Err(Sgp4Error::OmmParsingFailed(format!("expected one OMM, found {count}"))),
+    }
+}
+
+/// Parse all OMMs of a JSON array, KVN file or XML `<ndm>` document
+///
+/// # Errors
+///
+/// - `OmmParsingFailed`: If a message is malformed
+pub fn parse_omm_messages(omms: &str) -> Result<Vec<Omm>, Sgp4Error> {
+    match Encoding::detect(omms) {
+        Encoding::Json => {
+            let value: Value = serde_json::from_str(omms)
+                .map_err(|e| Sgp4Error::OmmParsingFailed(e.to_string()))?;
+            match value {
+                Value::Array(objects) => objects
+                    .iter()
+                    .enumerate()
+                    .map(|(i, object)| {
+                        omm_from_json(object)
+                            .map_err(|e| Sgp4Error::OmmParsingFailed(format!("Object {i}: {e}")))
+                    })
+                    .collect(),
+                object => Ok(vec![omm_from_json(&object)?]),
+            }
+        }
+        Encoding::Kvn => omms_from_kvn(omms).map_err(parsing_failed),
+        Encoding::Xml => omms_from_xml(omms).map_err(parsing_failed),
+    }
+}
+
+/// Version of CCSDS 502.0 written in KVN and XML messages
+pub const CCSDS_OMM_VERSION: &str = "2.0";
+
+/// Header and metadata of a KVN or XML OMM
+///
+/// JSON OMMs carry only the elements; the CCSDS encodings also need the
+/// originator, creation date and the frame the mean elements refer to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OmmMetadata {
+    /// Agency or operator creating the message (ORIGINATOR)
+    pub originator: String,
+    /// Creation time, UTC (CREATION_DATE)
+    pub creation_date: String,
+    /// Central body (CENTER_NAME)
+    pub center_name: String,
+    /// Reference frame of the elements (REF_FRAME)
+    pub ref_frame: String,
+    /// Time system of the epoch (TIME_SYSTEM)
+    pub time_system: String,
+    /// Theory the mean elements belong to (MEAN_ELEMENT_THEORY)
+    pub mean_element_theory: String,
+}
+
+impl OmmMetadata {
+    /// Metadata for SGP4 mean elements about the Earth (TEME, UTC) from
+    /// `originator`, created now
+    pub fn new(originator: impl Into<String>) -> Self {
+        let (year, month, day, hour, minute, second, _) = Epoch::now().to_gregorian_utc();
+        Self {
+            creation_date: format!(
+                "{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}"
+            ),
+            ..Self::sgp4(originator.into())
+        }
+    }
+
+    /// Check that the message holds SGP4 mean elements: about the Earth, in
+    /// TEME, with a UTC epoch and an SGP/SGP4 MEAN_ELEMENT_THEORY
+    ///
+    /// SGP4 reads the epoch as UTC and returns TEME states, so a TAI or TT
+    /// epoch would shift the results by 37 to 69 s without this check.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidElements`: Naming the first keyword that does not match
+    pub fn check_sgp4(&self) -> Result<(), Sgp4Error> {
+        let expected = [
+            ("CENTER_NAME", &self.center_name, "EARTH"),
+            ("REF_FRAME", &self.ref_frame, "TEME"),
+            ("TIME_SYSTEM", &self.time_system, "UTC"),
+        ];
+        for (key, value, required) in expected {
+            if !value.eq_ignore_ascii_case(required) {
+                return Err(Sgp4Error::InvalidElements(format!(
+                    "{key} = {value}: SGP4 elements require {key} = {required}"
+                )));
+            }
+        }
+        if !is_sgp4_theory(&self.mean_element_theory) {
+            return Err(Sgp4Error::InvalidElements(format!(
+                "MEAN_ELEMENT_THEORY = {}: only SGP/SGP4 elements can be propagated with SGP4",
+                self.mean_element_theory
+            )));
+        }
+        Ok(())
+    }
+
+    fn sgp4(originator: String) -> Self {
+        Self {
+            originator,
+            creation_date: String::new(),
+            center_name: "EARTH".to_string(),
+            ref_frame: "TEME".to_string(),
+            time_system: "UTC".to_string(),
+            mean_element_theory: "SGP4".to_string(),
+        }
+    }
+}
+
+/// A complete OMM: the elements with the header, metadata, comments and
+/// optional covariance of the CCSDS encodings
+#[derive(Debug, Clone, PartialEq)]
+pub struct Omm {
+    /// Header and metadata
+    pub metadata: OmmMetadata,
+    /// Mean elements and TLE parameters
+    pub elements: Elements,
+    /// Covariance of the Cartesian state at the epoch, in m and m/s
+    pub covariance: Option<Covariance6>,
+    /// Frame of the covariance (COV_REF_FRAME) when it is not REF_FRAME
+    pub covariance_frame: Option<String>,
+    /// COMMENT lines of all blocks, in message order
+    pub comments: Vec<String>,
+}
+
+impl Omm {
+    /// OMM of `elements` with `metadata` and no covariance
+    pub fn new(elements: Elements, metadata: OmmMetadata) -> Self {
+        Self {
+            metadata,
+            elements,
+            covariance: None,
+            covariance_frame: None,
+            comments: Vec::new(),
+        }
+    }
+
+    /// Write the message in CCSDS KVN
+    ///
+    /// Comments are written in the header, so they survive a round trip but
+    /// not their original blocks.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidElements`: If an element is not finite
+    pub fn to_kvn(&self) -> Result<String, Sgp4Error> {
+        check_finite(&self.elements)?;
+        let mut kvn = KvnWriter::new();
+        kvn.field("CCSDS_OMM_VERS", CCSDS_OMM_VERSION);
+        kvn.comments(&self.comments);
+        kvn.field("CREATION_DATE", &self.metadata.creation_date);
+        kvn.field("ORIGINATOR", &self.metadata.originator);
+        for block in [
+            metadata_fields(&self.elements, &self.metadata),
+            mean_element_fields(&self.elements),
+            tle_fields(&self.elements),
+            self.covariance_fields(),
+        ] {
+            if !block.is_empty() {
+                kvn.blank();
+            }
+            for (key, value, _) in block {
+                kvn.field(key, value);
+            }
+        }
+        Ok(kvn.finish())
+    }
+
+    /// Write the message in CCSDS NDM/XML, with units attributes as in the
+    /// CCSDS schema
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidElements`: If an element is not finite
+    pub fn to_xml(&self) -> Result<String, Sgp4Error> {
+        check_finite(&self.elements)?;
+        let mut xml = XmlWriter::new("omm", CCSDS_OMM_VERSION);
+        xml.open("header");
+        xml.comments(&self.comments);
+        xml.leaf("CREATION_DATE", &self.metadata.creation_date, None);
+        xml.leaf("ORIGINATOR", &self.metadata.originator, None);
+        xml.close("header");
+        xml.open("body");
+        xml.open("segment");
+        xml.block("metadata", metadata_fields(&self.elements, &self.metadata));
+        xml.open("data");
+        xml.block("meanElements", mean_element_fields(&self.elements));
+        xml.block("tleParameters", tle_fields(&self.elements));
+        let covariance = self.covariance_fields();
+        if !covariance.is_empty() {
+            xml.block("covarianceMatrix", covariance);
+        }
+        xml.close("data");
+        xml.close("segment");
+        xml.close("body");
+        Ok(xml.finish("omm"))
+    }
+
+    fn covariance_fields(&self) -> Vec<OmmField> {
+        let Some(covariance) = &self.covariance else {
+            return Vec::new();
+        };
+        let mut fields = Vec::new();
+        if let Some(frame) = &self.covariance_frame {
+            fields.push(("COV_REF_FRAME", frame.clone(), None));
+        }
+        let values = ccsds::lower_triangle(covariance, KM2_TO_M2);
+        for (index, (key, value)) in CARTESIAN_COVARIANCE.iter().zip(values).enumerate() {
+            fields.push((*key, ccsds::format_number(value), Some(ccsds::cartesian_covariance_units(index))));
+        }
+        fields
+    }
+}
+
+/// Write elements as an OMM JSON object
+///
+/// The inverse of [`parse_omm`], with the CelesTrak/Space-Track keys. Numbers
+/// are written in their shortest exact form, so parsing the result gives back
+/// identical elements.
+///
+/// # Errors
+///
+/// - `InvalidElements`: If an element is not finite
+pub fn write_omm(elements: &Elements) -> Result<String, Sgp4Error> {
+    check_finite(elements)?;
+    serde_json::to_string_pretty(elements)
+        .map_err(|e| Sgp4Error::InvalidElements(e.to_string()))
+}
+
+/// Write several element sets as a JSON array of OMM objects, the inverse of
+/// [`parse_omm_batch`]
+///
+/// # Errors
+///
+/// - `InvalidElements`: If an element is not finite
+pub fn write_omm_batch(elements: &[Elements]) -> Result<String, Sgp4Error> {
+    for element_set in elements {
+        check_finite(element_set)?;
+    }
+    serde_json::to_string_pretty(elements)
+        .map_err(|e| Sgp4Error::InvalidElements(e.to_string()))
+}
+
+/// Write elements as a CCSDS 502.0 OMM in KVN (`KEY = value` lines)
+///
+/// The data keys and values are those of [`write_omm`]. OBJECT_NAME and
+/// OBJECT_ID are left out when the elements have none. See [`Omm::to_kvn`]
+/// for messages with comments and covariance.
+///
+/// # Example
+///
+/// ```text
+/// CCSDS_OMM_VERS = 2.0
+/// CREATION_DATE = 2024-03-01T12:00:00
+/// ORIGINATOR = ASTRORA
+///
+/// OBJECT_NAME = ISS (ZARYA)
+/// OBJECT_ID = 1998-067A
+/// CENTER_NAME = EARTH
+/// ...
+/// ```
+///
+/// # Errors
+///
+/// - `InvalidElements`: If an element is not finite
+pub fn write_omm_kvn(elements: &Elements, metadata: &OmmMetadata) -> Result<String, Sgp4Error> {
+    Omm::new(elements.clone(), metadata.clone()).to_kvn()
+}
+
+/// Write elements as a CCSDS 502.0 OMM in NDM/XML
+///
+/// The same content as [`write_omm_kvn`], with units attributes as in the
+/// CCSDS schema.
+///
+/// # Errors
+///
+/// - `InvalidElements`: If an element is not finite
+pub fn write_omm_xml(elements: &Elements, metadata: &OmmMetadata) -> Result<String, Sgp4Error> {
+    Omm::new(elements.clone(), metadata.clone()).to_xml()
+}
+
+/// OMM keyword, value and units
+type OmmField = (&'static str, String, Option<&'static str>);
+
+fn metadata_fields(elements: &Elements, metadata: &OmmMetadata) -> Vec<OmmField> {
+    let mut fields = Vec::new();
+    if let Some(name) = &elements.object_name {
+        fields.push(("OBJECT_NAME", name.clone(), None));
+    }
+    if let Some(id) = &elements.international_designator {
+        fields.push(("OBJECT_ID", id.clone(), None));
+    }
+    fields.extend([
+        ("CENTER_NAME", metadata.center_name.clone(), None),
+        ("REF_FRAME", metadata.ref_frame.clone(), None),
+        ("TIME_SYSTEM", metadata.time_system.clone(), None),
+        ("MEAN_ELEMENT_THEORY", metadata.mean_element_theory.clone(), None),
+    ]);
+    fields
+}
+
+fn mean_element_fields(elements: &Elements) -> Vec<OmmField> {
+    vec![
+        ("EPOCH", ccsds::format_datetime(&elements.datetime), None),
+        ("MEAN_MOTION", elements.mean_motion.to_string(), Some("rev/day")),
+        ("ECCENTRICITY", elements.eccentricity.to_string(), None),
+        ("INCLINATION", elements.inclination.to_string(), Some("deg")),
+        ("RA_OF_ASC_NODE", elements.right_ascension.to_string(), Some("deg")),
+        ("ARG_OF_PERICENTER", elements.argument_of_perigee.to_string(), Some("deg")),
+        ("MEAN_ANOMALY", elements.mean_anomaly.to_string(), Some("deg")),
+    ]
+}
+
+fn tle_fields(elements: &Elements) -> Vec<OmmField> {
+    vec![
+        ("EPHEMERIS_TYPE", elements.ephemeris_type.to_string(), None),
+        ("CLASSIFICATION_TYPE", classification_code(&elements.classification).to_string(), None),
+        ("NORAD_CAT_ID", elements.norad_id.to_string(), None),
+        ("ELEMENT_SET_NO", elements.element_set_number.to_string(), None),
+        ("REV_AT_EPOCH", elements.revolution_number.to_string(), None),
+        ("BSTAR", elements.drag_term.to_string(), Some("1/ER")),
+        ("MEAN_MOTION_DOT", elements.mean_motion_dot.to_string(), Some("rev/day**2")),
+        ("MEAN_MOTION_DDOT", elements.mean_motion_ddot.to_string(), Some("rev/day**3")),
+    ]
+}
+
+fn check_finite(elements: &Elements) -> Result<(), Sgp4Error> {
+    let values = [
+        ("MEAN_MOTION", elements.mean_motion),
+        ("ECCENTRICITY", elements.eccentricity),
+        ("INCLINATION", elements.inclination),
+        ("RA_OF_ASC_NODE", elements.right_ascension),
+        ("ARG_OF_PERICENTER", elements.argument_of_perigee),
+        ("MEAN_ANOMALY", elements.mean_anomaly),
+        ("BSTAR", elements.drag_term),
+        ("MEAN_MOTION_DOT", elements.mean_motion_dot),
+        ("MEAN_MOTION_DDOT", elements.mean_motion_ddot),
+    ];
+    match values.iter().find(|(_, value)| !value.is_finite()) {
+        Some((key, value)) => Err(Sgp4Error::InvalidElements(format!("{key} is {value}"))),
+        None => Ok(()),
+    }
+}
+
+fn parsing_failed(error: PoliastroError) -> Sgp4Error {
+    match error {
+        PoliastroError::InvalidMessage { reason, .. } => Sgp4Error::OmmParsingFailed(reason),
+        other => Sgp4Error::OmmParsingFailed(other.to_string()),
+    }
+}
+
+/// KVN messages, each starting at its CCSDS_OMM_VERS line
+fn omms_from_kvn(text: &str) -> PoliastroResult<Vec<Omm>> {
+    let mut messages: Vec<Fields> = Vec::new();
+    for (number, line) in kvn::lines(text, "OMM")? {
+        if matches!(&line, KvnLine::Field { key, .. } if key == "CCSDS_OMM_VERS") {
+            messages.push(Fields::new("OMM"));
+        }
+        let fields = messages.last_mut().ok_or_else(|| {
+            PoliastroError::invalid_message("OMM", format!("line {number}: expected CCSDS_OMM_VERS"))
+        })?;
+        match line {
+            KvnLine::Field { key, value } => fields.push(key, value),
+            KvnLine::Comment(comment) => fields.comments.push(comment),
+            _ => {
+                return Err(PoliastroError::invalid_message(
+                    "OMM",
+                    format!("line {number}: expected KEYWORD = value"),
+                ))
+            }
+        }
+    }
+    messages.iter().map(omm_from_fields).collect()
+}
+
+/// An `<omm>` document, or the `<omm>` elements of an `<ndm>`
+fn omms_from_xml(text: &str) -> PoliastroResult<Vec<Omm>> {
+    let root = XmlElement::parse(text, "OMM")?;
+    let messages: Vec<&XmlElement> = match root.name.as_str() {
+        "omm" => vec![&root],
+        "ndm" => root.children_named("omm").collect(),
+        other => {
+            return Err(PoliastroError::invalid_message(
+                "OMM",
+                format!("unexpected root element <{other}>"),
+            ))
+        }
+    };
+    messages
+        .into_iter()
+        .map(|omm| {
+            let mut fields = omm.flat_fields("OMM");
+            if let Some(version) = omm.attribute("version") {
+                fields.push("CCSDS_OMM_VERS", version);
+            }
+            omm_from_fields(&fields)
+        })
+        .collect()
+}
+
+/// A JSON object: elements as the sgp4 crate reads them, and whichever
+/// header and metadata keywords are present
+fn omm_from_json(object: &Value) -> Result<Omm, Sgp4Error> {
+    let elements: Elements = serde_json::from_value(object.clone())
+        .map_err(|e| Sgp4Error::OmmParsingFailed(e.to_string()))?;
+    let mut fields = Fields::new("OMM");
+    if let Value::Object(entries) = object {
+        for (key, value) in entries {
+            match value {
+                Value::String(text) if key == "COMMENT" => fields.comments.push(text.clone()),
+                Value::String(text) => fields.push(key.clone(), text.clone()),
+                Value::Number(number) => fields.push(key.clone(), number.to_string()),
+                _ => {}
+            }
+        }
+    }
+    metadata_from_fields(&fields, false)
+        .and_then(|metadata| complete_omm(&fields, metadata, elements))
+        .map_err(parsing_failed)
+}
+
+/// A KVN or XML message, which must have the CCSDS header and metadata
+fn omm_from_fields(fields: &Fields) -> PoliastroResult<Omm> {
+    let metadata = metadata_from_fields(fields, true)?;
+    let elements = elements_from_fields(fields, &metadata.mean_element_theory)?;
+    complete_omm(fields, metadata, elements)
+}
+
+/// Header and metadata; `strict` requires the mandatory CCSDS keywords
+/// instead of defaulting them
+fn metadata_from_fields(fields: &Fields, strict: bool) -> PoliastroResult<OmmMetadata> {
+    let defaults = OmmMetadata::sgp4(String::new());
+    let text = |key: &str, default: &str| -> PoliastroResult<String> {
+        match fields.get(key) {
+            Some(value) => Ok(value.to_string()),
+            None if !strict => Ok(default.to_string()),
+            None => Err(fields.error(format!("missing {key}"))),
+        }
+    };
+    Ok(OmmMetadata {
+        originator: text("ORIGINATOR", &defaults.originator)?,
+        creation_date: text("CREATION_DATE", &defaults.creation_date)?,
+        center_name: text("CENTER_NAME", &defaults.center_name)?,
+        ref_frame: text("REF_FRAME", &defaults.ref_frame)?,
+        time_system: text("TIME_SYSTEM", &defaults.time_system)?,
+        mean_element_theory: text("MEAN_ELEMENT_THEORY", &defaults.mean_element_theory)?,
+    })
+}
+
+/// Mean elements and TLE parameters
+///
+/// SEMI_MAJOR_AXIS (km) may replace MEAN_MOTION, with GM (km³/s²)
+/// defaulting to the Earth's. The TLE parameters are optional, except BSTAR
+/// and the mean motion derivatives for SGP4 elements.
+fn elements_from_fields(fields: &Fields, theory: &str) -> PoliastroResult<Elements> {
+    let mean_motion = match fields.parse::<f64>("MEAN_MOTION")? {
+        Some(mean_motion) => mean_motion,
+        None => {
+            let a: f64 = fields
+                .parse("SEMI_MAJOR_AXIS")?
+                .ok_or_else(|| fields.error("missing MEAN_MOTION or SEMI_MAJOR_AXIS"))?;
+            let gm = fields.parse::<f64>("GM")?.unwrap_or(GM_EARTH * 1e-9);
+            (gm / a.powi(3)).sqrt() * 86400.0 / std::f64::consts::TAU
+        }
+    };
+    let sgp4 = is_sgp4_theory(theory);
+    let tle_parameter = |key: &str| -> PoliastroResult<f64> {
+        if sgp4 {
+            fields.require_parse(key)
+        } else {
+            Ok(fields.parse(key)?.unwrap_or(0.0))
+        }
+    };
+    let classification = match fields.get("CLASSIFICATION_TYPE").unwrap_or("U") {
+        "U" => Classification::Unclassified,
+        "C" => Classification::Classified,
+        "S" => Classification::Secret,
+        other => return Err(fields.error(format!("invalid CLASSIFICATION_TYPE '{other}'"))),
+    };
+    Ok(Elements {
+        object_name: fields.get("OBJECT_NAME").map(str::to_string),
+        international_designator: fields.get("OBJECT_ID").map(str::to_string),
+        norad_id: fields.parse("NORAD_CAT_ID")?.unwrap_or(0),
+        classification,
+        datetime: fields.datetime("EPOCH")?,
+        mean_motion_dot: tle_parameter("MEAN_MOTION_DOT")?,
+        mean_motion_ddot: tle_parameter("MEAN_MOTION_DDOT")?,
+        drag_term: tle_parameter("BSTAR")?,
+        element_set_number: fields.parse("ELEMENT_SET_NO")?.unwrap_or(0),
+        inclination: fields.require_parse("INCLINATION")?,
+        right_ascension: fields.require_parse("RA_OF_ASC_NODE")?,
+        eccentricity: fields.require_parse("ECCENTRICITY")?,
+        argument_of_perigee: fields.require_parse("ARG_OF_PERICENTER")?,
+        mean_anomaly: fields.require_parse("MEAN_ANOMALY")?,
+        mean_motion,
+        revolution_number: fields.parse("REV_AT_EPOCH")?.unwrap_or(0),
+        ephemeris_type: fields.parse("EPHEMERIS_TYPE")?.unwrap_or(0),
+    })
+}
+
+/// Whether a MEAN_ELEMENT_THEORY names SGP4 mean elements
+fn is_sgp4_theory(theory: &str) -> bool {
+    matches!(theory.to_ascii_uppercase().as_str(), "SGP4" | "SGP" | "SGP/SGP4")
+}
+
+fn complete_omm(fields: &Fields, metadata: OmmMetadata, elements: Elements) -> PoliastroResult<Omm> {
+    Ok(Omm {
+        metadata,
+        elements,
+        covariance: fields.covariance(&CARTESIAN_COVARIANCE, KM2_TO_M2)?,
+        covariance_frame: fields.get("COV_REF_FRAME").map(str::to_string),
+        comments: fields.comments.clone(),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -230,4 +771,269 @@ mod tests {
         let elements = parse_omm(required_fields_omm).unwrap();
         assert_eq!(elements.norad_id, 25544);
     }
+
+    const CREATED: &str = "2024-03-01T12:00:00";
+
+    fn metadata() -> OmmMetadata {
+        OmmMetadata {
+            creation_date: CREATED.to_string(),
+            ..OmmMetadata::new("ASTRORA")
+        }
+    }
+
+    #[test]
+    fn test_write_omm_round_trip() {
+        let elements = parse_omm(ISS_OMM).unwrap();
+        assert_eq!(parse_omm(&write_omm(&elements).unwrap()).unwrap(), elements);
+
+        let batch = write_omm_batch(&[elements.clone(), elements.clone()]).unwrap();
+        assert_eq!(parse_omm_batch(&batch).unwrap(), vec![elements.clone(), elements]);
+
+        // TLE → OMM → TLE reproduces the text
+        let tle = "ISS (ZARYA)
+1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927
+2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let elements = crate::satellite::tle::parse_tle(tle).unwrap();
+        let omm = write_omm(&elements).unwrap();
+        assert_eq!(crate::satellite::tle::format_tle(&parse_omm(&omm).unwrap()).unwrap(), tle);
+    }
+
+    #[test]
+    fn test_write_omm_kvn() {
+        let elements = parse_omm(ISS_OMM).unwrap();
+        let kvn = write_omm_kvn(&elements, &metadata()).unwrap();
+        let lines: Vec<&str> = kvn.lines().collect();
+        assert_eq!(lines[0], "CCSDS_OMM_VERS = 2.0");
+        assert_eq!(lines[1], format!("CREATION_DATE = {CREATED}"));
+        assert!(lines.contains(&"REF_FRAME = TEME"));
+        assert!(lines.contains(&"EPOCH = 2008-09-20T12:25:40.104"));
+
+        assert_eq!(parse_omm(&kvn).unwrap(), elements);
+        assert_eq!(parse_omm_message(&kvn).unwrap().metadata, metadata());
+    }
+
+    #[test]
+    fn test_write_omm_xml() {
+        let mut elements = parse_omm(ISS_OMM).unwrap();
+        elements.object_name = Some("A&B <TEST>".to_string());
+        let xml = write_omm_xml(&elements, &metadata()).unwrap();
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<omm "));
+        assert!(xml.contains("<OBJECT_NAME>A&amp;B &lt;TEST&gt;</OBJECT_NAME>"));
+        assert!(xml.contains("<INCLINATION units=\"deg\">51.6416</INCLINATION>"));
+        assert!(xml.trim_end().ends_with("</omm>"));
+
+        assert_eq!(parse_omm(&xml).unwrap(), elements);
+        assert_eq!(parse_omm_message(&xml).unwrap().metadata, metadata());
+    }
+
+    #[test]
+    fn test_write_omm_rejects_non_finite() {
+        let mut elements = parse_omm(ISS_OMM).unwrap();
+        elements.drag_term = f64::NAN;
+        assert!(matches!(write_omm(&elements), Err(Sgp4Error::InvalidElements(_))));
+        assert!(write_omm_kvn(&elements, &metadata()).is_err());
+        assert!(write_omm_xml(&elements, &metadata()).is_err());
+    }
+
+    /// CCSDS 502.0-B-2 example OMM, with covariance
+    const GOES_KVN: &str = "CCSDS_OMM_VERS = 2.0
+COMMENT this is a comment
+CREATION_DATE = 2007-065T16:00:00
+ORIGINATOR = NOAA/USA
+
+OBJECT_NAME = GOES 9
+OBJECT_ID = 1995-025A
+CENTER_NAME = EARTH
+REF_FRAME = TEME
+TIME_SYSTEM = UTC
+MEAN_ELEMENT_THEORY = SGP/SGP4
+
+EPOCH = 2007-064T10:34:41.4264
+MEAN_MOTION = 1.00273272 [rev/day]
+ECCENTRICITY = 0.0005013
+INCLINATION = 3.0539 [deg]
+RA_OF_ASC_NODE = 81.7939 [deg]
+ARG_OF_PERICENTER = 249.2363 [deg]
+MEAN_ANOMALY = 150.1602 [deg]
+GM = 398600.8 [km**3/s**2]
+
+EPHEMERIS_TYPE = 0
+CLASSIFICATION_TYPE = U
+NORAD_CAT_ID = 23581
+ELEMENT_SET_NO = 0925
+REV_AT_EPOCH = 4316
+BSTAR = 0.0001 [1/ER]
+MEAN_MOTION_DOT = -0.00000113 [rev/day**2]
+MEAN_MOTION_DDOT = 0.0 [rev/day**3]
+
+COV_REF_FRAME = TEME
+CX_X = 3.331349476038534e-04 [km**2]
+CY_X = 4.618927349220216e-04 [km**2]
+CY_Y = 6.782421679971363e-04 [km**2]
+CZ_X = -3.070007847730449e-04 [km**2]
+CZ_Y = -4.221234189514228e-04 [km**2]
+CZ_Z = 3.231931992380369e-04 [km**2]
+CX_DOT_X = -3.349365033922630e-07 [km**2/s]
+CX_DOT_Y = -4.686084221046758e-07 [km**2/s]
+CX_DOT_Z = 2.484949578400095e-07 [km**2/s]
+CX_DOT_X_DOT = 4.296022805587290e-10 [km**2/s**2]
+CY_DOT_X = -2.211832501084875e-07 [km**2/s]
+CY_DOT_Y = -2.864186892102733e-07 [km**2/s]
+CY_DOT_Z = 1.798098699846038e-07 [km**2/s]
+CY_DOT_X_DOT = 2.608899201686016e-10 [km**2/s**2]
+CY_DOT_Y_DOT = 1.767514756338532e-10 [km**2/s**2]
+CZ_DOT_X = -3.041346050686871e-07 [km**2/s]
+CZ_DOT_Y = -4.989496988610662e-07 [km**2/s]
+CZ_DOT_Z = 3.540310904497689e-07 [km**2/s]
+CZ_DOT_X_DOT = 1.869263192954590e-10 [km**2/s**2]
+CZ_DOT_Y_DOT = 1.008862586240695e-10 [km**2/s**2]
+CZ_DOT_Z_DOT = 6.224444338635500e-10 [km**2/s**2]
+";
+
+    fn assert_same_omm(a: &Omm, b: &Omm) {
+        assert_eq!(a.metadata, b.metadata);
+        assert_eq!(a.elements, b.elements);
+        assert_eq!(a.comments, b.comments);
+        assert_eq!(a.covariance_frame, b.covariance_frame);
+        let (pa, pb) = (a.covariance.unwrap().matrix(), b.covariance.unwrap().matrix());
+        assert!((pa - pb).amax() <= 1e-15 * pa.amax());
+    }
+
+    #[test]
+    fn test_parse_omm_kvn() {
+        let omm = parse_omm_message(GOES_KVN).unwrap();
+        assert_eq!(omm.metadata.originator, "NOAA/USA");
+        assert_eq!(omm.metadata.creation_date, "2007-065T16:00:00");
+        assert_eq!(omm.metadata.mean_element_theory, "SGP/SGP4");
+        assert_eq!(omm.metadata.ref_frame, "TEME");
+        assert_eq!(omm.comments, vec!["this is a comment".to_string()]);
+
+        let elements = &omm.elements;
+        assert_eq!(elements.object_name.as_deref(), Some("GOES 9"));
+        assert_eq!(elements.international_designator.as_deref(), Some("1995-025A"));
+        assert_eq!(elements.norad_id, 23581);
+        assert_eq!(elements.element_set_number, 925);
+        assert_eq!(elements.revolution_number, 4316);
+        assert_eq!(elements.drag_term, 0.0001);
+        assert_eq!(elements.mean_motion_dot, -0.00000113);
+        assert_eq!(ccsds::format_datetime(&elements.datetime), "2007-03-05T10:34:41.426400");
+
+        let covariance = omm.covariance.unwrap().matrix();
+        assert_eq!(omm.covariance_frame.as_deref(), Some("TEME"));
+        assert!((covariance[(0, 0)] - 333.1349476038534).abs() < 1e-9);
+        assert!((covariance[(1, 0)] - covariance[(0, 1)]).abs() < 1e-15);
+        assert!((covariance[(5, 5)] - 6.2244443386355e-4).abs() < 1e-15);
+
+        // The same elements as the equivalent JSON
+        assert!(crate::satellite::propagate_from_elements(elements, 60.0).is_ok());
+        assert_eq!(parse_omm(GOES_KVN).unwrap(), omm.elements);
+    }
+
+    #[test]
+    fn test_omm_kvn_xml_round_trip() {
+        let omm = parse_omm_message(GOES_KVN).unwrap();
+
+        let kvn = omm.to_kvn().unwrap();
+        assert!(kvn.contains("\nCOV_REF_FRAME = TEME\nCX_X = 0.0003331349476038534\n"));
+        assert_same_omm(&parse_omm_message(&kvn).unwrap(), &omm);
+
+        let xml = omm.to_xml().unwrap();
+        assert!(xml.contains("<COMMENT>this is a comment</COMMENT>"));
+        assert!(xml.contains("<CZ_DOT_Z_DOT units=\"km**2/s**2\">"));
+        assert_same_omm(&parse_omm_message(&xml).unwrap(), &omm);
+
+        // Several messages in one KVN file or NDM document
+        let kvn_batch = format!("{kvn}\n{GOES_KVN}");
+        assert_eq!(parse_omm_messages(&kvn_batch).unwrap().len(), 2);
+        let body = xml.split_once("?>\n").unwrap().1;
+        let ndm = format!("<?xml version=\"1.0\"?>\n<ndm>\n{body}{body}</ndm>\n");
+        let messages = parse_omm_messages(&ndm).unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_same_omm(&messages[1], &omm);
+        assert_eq!(parse_omm_batch(&ndm).unwrap(), vec![omm.elements.clone(), omm.elements]);
+        assert!(matches!(parse_omm_message(&ndm), Err(Sgp4Error::OmmParsingFailed(_))));
+    }
+
+    #[test]
+    fn test_parse_omm_json_metadata() {
+        let space_track = ISS_OMM.replacen(
+            '{',
+            r#"{
+            "CCSDS_OMM_VERS": "2.0",
+            "COMMENT": "GENERATED VIA SPACE-TRACK.ORG API",
+            "CREATION_DATE": "2024-03-01T06:26:18",
+            "ORIGINATOR": "18 SPCS",
+            "CENTER_NAME": "EARTH",
+            "REF_FRAME": "TEME",
+            "TIME_SYSTEM": "UTC",
+            "MEAN_ELEMENT_THEORY": "SGP4","#,
+            1,
+        );
+        let omm = parse_omm_message(&space_track).unwrap();
+        assert_eq!(omm.metadata.originator, "18 SPCS");
+        assert_eq!(omm.metadata.creation_date, "2024-03-01T06:26:18");
+        assert_eq!(omm.comments, vec!["GENERATED VIA SPACE-TRACK.ORG API".to_string()]);
+        assert_eq!(omm.elements, parse_omm(ISS_OMM).unwrap());
+        assert!(omm.covariance.is_none());
+
+        // CelesTrak objects have no metadata: SGP4 defaults
+        let omm = parse_omm_message(ISS_OMM).unwrap();
+        assert_eq!(omm.metadata.originator, "");
+        assert_eq!(omm.metadata.ref_frame, "TEME");
+    }
+
+    #[test]
+    fn test_parse_omm_semi_major_axis() {
+        // Non-SGP4 elements may give the semi-major axis and omit TLE parameters
+        let kvn = GOES_KVN
+            .replace("MEAN_ELEMENT_THEORY = SGP/SGP4", "MEAN_ELEMENT_THEORY = DSST")
+            .replace("MEAN_MOTION = 1.00273272 [rev/day]", "SEMI_MAJOR_AXIS = 42164.0 [km]")
+            .replace("BSTAR = 0.0001 [1/ER]\n", "");
+        let elements = parse_omm_message(&kvn).unwrap().elements;
+        let expected = (398600.8_f64 / 42164.0_f64.powi(3)).sqrt() * 86400.0 / std::f64::consts::TAU;
+        assert!((elements.mean_motion - expected).abs() < 1e-12);
+        assert_eq!(elements.drag_term, 0.0);
+    }
+
+    #[test]
+    fn test_sgp4_paths_require_sgp4_metadata() {
+        let cases = [
+            GOES_KVN.replace("TIME_SYSTEM = UTC", "TIME_SYSTEM = TAI"),
+            GOES_KVN.replace("TIME_SYSTEM = UTC", "TIME_SYSTEM = TT"),
+            GOES_KVN.replace("REF_FRAME = TEME", "REF_FRAME = GCRF"),
+            GOES_KVN.replace("CENTER_NAME = EARTH", "CENTER_NAME = MOON"),
+            GOES_KVN.replace("MEAN_ELEMENT_THEORY = SGP/SGP4", "MEAN_ELEMENT_THEORY = SGP4-XP"),
+            ISS_OMM.replacen('{', r#"{ "TIME_SYSTEM": "TAI","#, 1),
+        ];
+        for omm in &cases {
+            // The message itself is valid, it only cannot feed SGP4
+            assert!(parse_omm_message(omm).is_ok(), "{omm}");
+            assert!(matches!(parse_omm(omm), Err(Sgp4Error::InvalidElements(_))), "{omm}");
+            assert!(parse_omm_batch(omm).is_err(), "{omm}");
+        }
+        let lower_case = GOES_KVN.replace("TIME_SYSTEM = UTC", "TIME_SYSTEM = utc");
+        assert!(parse_omm(&lower_case).is_ok());
+    }
+
+    #[test]
+    fn test_parse_omm_kvn_errors() {
+        let cases = [
+            GOES_KVN.replace("ORIGINATOR = NOAA/USA\n", ""),
+            GOES_KVN.replace("BSTAR = 0.0001 [1/ER]\n", ""),
+            GOES_KVN.replace("2007-064T10:34:41.4264", "2007-064 10:34"),
+            GOES_KVN.replace("INCLINATION = 3.0539", "INCLINATION = three"),
+            GOES_KVN.replace("CLASSIFICATION_TYPE = U", "CLASSIFICATION_TYPE = X"),
+            GOES_KVN.replace("CZ_DOT_Z_DOT", "CZ_DOT_Z_DOTT"),
+            GOES_KVN.replacen("CCSDS_OMM_VERS = 2.0\n", "", 1),
+            format!("{GOES_KVN}META_START\n"),
+            "<cdm></cdm>".to_string(),
+        ];
+        for text in &cases {
+            assert!(matches!(parse_omm(text), Err(Sgp4Error::OmmParsingFailed(_))), "{text}");
+        }
+        let Err(Sgp4Error::OmmParsingFailed(reason)) = parse_omm(&cases[1]) else {
+            unreachable!()
+        };
+        assert_eq!(reason, "missing BSTAR");
+    }
 }
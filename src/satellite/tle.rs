@@ -1,6 +1,7 @@
-//! TLE (Two-Line Element) Parsing
+//! TLE (Two-Line Element) Parsing and Formatting
 //!
-//! This module handles parsing of TLE data in both 2-line and 3-line formats.
+//! This module handles parsing of TLE data in both 2-line and 3-line formats,
+//! and formatting elements back into TLE text with [`format_tle`].
 //!
 //! # TLE Format
 //!
@@ -53,7 +54,8 @@
 //! - Spacetrack Report #3 (Hoots & Roehrich, 1980)
 //! - CelesTrak TLE format specification
 
-use sgp4::Elements;
+use sgp4::chrono::{Datelike, NaiveDateTime, Timelike};
+use sgp4::{Classification, Elements};
 use crate::satellite::sgp4_wrapper::Sgp4Error;
 
 /// Parse a TLE string (2-line or 3-line format)
@@ -149,18 +151,222 @@ pub fn validate_checksum(line: &str) -> bool {
     let checksum_char = line.chars().nth(68).unwrap_or('0');
     let expected_checksum = checksum_char.to_digit(10).unwrap_or(0);
 
-    // Calculate actual checksum
-    let mut sum = 0;
-    for c in line[..68].chars() {
-        if c.is_ascii_digit() {
-            sum += c.to_digit(10).unwrap();
-        } else if c == '-' {
-            sum += 1;
+    tle_checksum(line) == expected_checksum
+}
+
+/// Format orbital elements as a TLE
+///
+/// The inverse of [`parse_tle`]: writes the 3-line format when the elements
+/// carry an object name and the 2-line format otherwise. Values are rounded to
+/// their column widths, the implied-decimal fields (second derivative of mean
+/// motion and B*) are written as a 5-digit mantissa and a signed exponent, and
+/// each line ends with its checksum, so formatting parsed TLE text reproduces
+/// it exactly.
+///
+/// # Arguments
+///
+/// * `elements` - Orbital elements, e.g. from [`parse_tle`] or `parse_omm`
+///
+/// # Returns
+///
+/// TLE text with lines separated by `\n`
+///
+/// # Errors
+///
+/// - `InvalidElements`: If a value does not fit its field (catalog number
+///   above 339999, epoch outside 1957-2056, eccentricity not in [0, 1),
+///   |ṅ/2| ≥ 1 rev/day², exponent above 9, ...)
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let elements = parse_tle(tle)?;
+/// assert_eq!(format_tle(&elements)?, tle);
+/// ```
+pub fn format_tle(elements: &Elements) -> Result<String, Sgp4Error> {
+    let (line1, line2) = format_tle_lines(elements)?;
+    Ok(match &elements.object_name {
+        Some(name) => format!("{name}\n{line1}\n{line2}"),
+        None => format!("{line1}\n{line2}"),
+    })
+}
+
+/// Format the two element lines of a TLE, each 69 columns with its checksum
+///
+/// # Errors
+///
+/// - `InvalidElements`: If a value does not fit its field (see [`format_tle`])
+pub fn format_tle_lines(elements: &Elements) -> Result<(String, String), Sgp4Error> {
+    let catalog_number = format_catalog_number(elements.norad_id)?;
+    let classification = classification_code(&elements.classification);
+    if elements.ephemeris_type > 9 {
+        return Err(invalid_field("ephemeris type", elements.ephemeris_type));
+    }
+
+    let line1 = format!(
+        "1 {catalog_number}{classification} {} {} {} {} {} {} {:>4}",
+        format_designator(elements.international_designator.as_deref())?,
+        format_epoch(&elements.datetime)?,
+        format_mean_motion_dot(elements.mean_motion_dot)?,
+        format_exponential(elements.mean_motion_ddot, "second derivative of mean motion")?,
+        format_exponential(elements.drag_term, "B*")?,
+        elements.ephemeris_type,
+        elements.element_set_number % 10_000,
+    );
+    let line2 = format!(
+        "2 {catalog_number} {} {} {} {} {} {}{:>5}",
+        format_fixed(elements.inclination, 8, 4, "inclination")?,
+        format_fixed(elements.right_ascension, 8, 4, "right ascension of ascending node")?,
+        format_eccentricity(elements.eccentricity)?,
+        format_fixed(elements.argument_of_perigee, 8, 4, "argument of perigee")?,
+        format_fixed(elements.mean_anomaly, 8, 4, "mean anomaly")?,
+        format_fixed(elements.mean_motion, 11, 8, "mean motion")?,
+        elements.revolution_number % 100_000,
+    );
+
+    Ok((with_checksum(line1), with_checksum(line2)))
+}
+
+/// TLE checksum of a line: the sum of its digits, with minus signs counted
+/// as 1, modulo 10
+///
+/// Only the first 68 columns are summed, so the checksum column of a
+/// complete line is ignored.
+pub fn tle_checksum(line: &str) -> u32 {
+    line.chars()
+        .take(68)
+        .map(|c| match c {
+            '-' => 1,
+            c => c.to_digit(10).unwrap_or(0),
+        })
+        .sum::<u32>()
+        % 10
+}
+
+/// One-letter classification code: U, C or S
+pub(crate) fn classification_code(classification: &Classification) -> char {
+    match classification {
+        Classification::Unclassified => 'U',
+        Classification::Classified => 'C',
+        Classification::Secret => 'S',
+    }
+}
+
+fn with_checksum(line: String) -> String {
+    let checksum = tle_checksum(&line);
+    format!("{line}{checksum}")
+}
+
+fn invalid_field(field: &str, value: impl std::fmt::Display) -> Sgp4Error {
+    Sgp4Error::InvalidElements(format!("{field} {value} does not fit a TLE field"))
+}
+
+/// Catalog number in 5 columns, in Alpha-5 above 99999 (A0000 = 100000,
+/// skipping I and O)
+fn format_catalog_number(norad_id: u64) -> Result<String, Sgp4Error> {
+    const ALPHA5: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ";
+    match norad_id {
+        0..=99_999 => Ok(format!("{norad_id:05}")),
+        100_000..=339_999 => Ok(format!(
+            "{}{:04}",
+            ALPHA5[(norad_id / 10_000 - 10) as usize] as char,
+            norad_id % 10_000
+        )),
+        _ => Err(invalid_field("catalog number", norad_id)),
+    }
+}
+
+/// International designator "1998-067A" as "98067A  ", or blank
+fn format_designator(designator: Option<&str>) -> Result<String, Sgp4Error> {
+    let Some(designator) = designator else {
+        return Ok(" ".repeat(8));
+    };
+    match designator.split_once('-') {
+        Some((year, piece))
+            if year.len() == 4
+                && year.bytes().all(|b| b.is_ascii_digit())
+                && (1..=6).contains(&piece.len())
+                && piece.is_ascii() =>
+        {
+            Ok(format!("{}{piece:<6}", &year[2..]))
+        }
+        _ => Err(invalid_field("international designator", designator)),
+    }
+}
+
+/// Epoch as two-digit year and day of year with 8 decimals ("08264.51782528")
+fn format_epoch(datetime: &NaiveDateTime) -> Result<String, Sgp4Error> {
+    let seconds = datetime.num_seconds_from_midnight() as f64 + datetime.nanosecond() as f64 * 1e-9;
+    let mut ticks = (seconds / 86_400.0 * 1e8).round() as u64;
+    let mut date = datetime.date();
+    if ticks >= 100_000_000 {
+        ticks -= 100_000_000;
+        date = date.succ_opt().ok_or_else(|| invalid_field("epoch", datetime))?;
+    }
+    if !(1957..=2056).contains(&date.year()) {
+        return Err(invalid_field("epoch", datetime));
+    }
+    Ok(format!("{:02}{:03}.{ticks:08}", date.year() % 100, date.ordinal()))
+}
+
+/// First derivative of mean motion as " .NNNNNNNN" or "-.NNNNNNNN"
+fn format_mean_motion_dot(value: f64) -> Result<String, Sgp4Error> {
+    let digits = format!("{:.8}", value.abs());
+    match digits.strip_prefix('0') {
+        Some(fraction) if value.is_finite() => {
+            let negative = value < 0.0 && fraction.bytes().any(|b| matches!(b, b'1'..=b'9'));
+            Ok(format!("{}{fraction}", if negative { '-' } else { ' ' }))
         }
-        // Letters, blanks, periods, plus signs count as 0
+        _ => Err(invalid_field("first derivative of mean motion", value)),
+    }
+}
+
+/// Implied-decimal exponential field ±NNNNN±E, meaning ±0.NNNNN × 10^±E
+///
+/// Values below 10⁻¹⁰ are written as zero, " 00000-0".
+fn format_exponential(value: f64, field: &str) -> Result<String, Sgp4Error> {
+    if !value.is_finite() {
+        return Err(invalid_field(field, value));
+    }
+    if value.abs() < 1e-10 {
+        return Ok(" 00000-0".to_string());
+    }
+    let mut exponent = value.abs().log10().floor() as i32 + 1;
+    let mut mantissa = (value.abs() / 10f64.powi(exponent) * 1e5).round() as u32;
+    if mantissa >= 100_000 {
+        mantissa /= 10;
+        exponent += 1;
     }
+    if exponent > 9 {
+        return Err(invalid_field(field, value));
+    }
+    if exponent < -9 {
+        return Ok(" 00000-0".to_string());
+    }
+    Ok(format!(
+        "{}{mantissa:05}{}{}",
+        if value < 0.0 { '-' } else { ' ' },
+        if exponent < 0 { '-' } else { '+' },
+        exponent.abs()
+    ))
+}
 
-    (sum % 10) == expected_checksum
+/// Eccentricity with an implied leading decimal point ("0006703")
+fn format_eccentricity(eccentricity: f64) -> Result<String, Sgp4Error> {
+    let digits = (eccentricity * 1e7).round();
+    if !(0.0..1e7).contains(&digits) {
+        return Err(invalid_field("eccentricity", eccentricity));
+    }
+    Ok(format!("{:07}", digits as u32))
+}
+
+/// Right-justified fixed-point value of exactly `width` columns
+fn format_fixed(value: f64, width: usize, precision: usize, field: &str) -> Result<String, Sgp4Error> {
+    let formatted = format!("{value:width$.precision$}");
+    if formatted.len() != width || !value.is_finite() {
+        return Err(invalid_field(field, value));
+    }
+    Ok(formatted)
 }
 
 #[cfg(test)]
@@ -241,4 +447,83 @@ mod tests {
         assert!((elements.inclination - 51.6416).abs() < 0.001);
         assert!((elements.eccentricity - 0.0006703).abs() < 0.0000001);
     }
+
+    /// Vallado et al. (2006) verification set: high eccentricity, deep
+    /// space, non-zero second derivative and blank-padded counters
+    const VERIFICATION_TLES: [&str; 5] = [
+        "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753
+2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667",
+        "1 06251U 62025E   06176.82412014  .00008885  00000-0  12808-3 0  3985
+2 06251  58.0579  54.0425 0030035 139.1568 221.1854 15.56387291  6774",
+        "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813
+2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656",
+        "1 28129U 03058A   06175.57071136 -.00000104  00000-0  10000-3 0   459
+2 28129  54.7298 324.8098 0048506 266.2640  93.1663  2.00562768 18443",
+        "1 28350U 04020A   06167.21788666  .16154492  76267-5  18678-3 0  8894
+2 28350  64.9977 345.6130 0024870 260.7578  99.9590 16.47856722116490",
+    ];
+
+    #[test]
+    fn test_format_tle_round_trip() {
+        for tle in [ISS_TLE_2LINE, ISS_TLE_3LINE].iter().chain(VERIFICATION_TLES.iter()) {
+            let elements = parse_tle(tle).unwrap();
+            let formatted = format_tle(&elements).unwrap();
+            assert_eq!(formatted, *tle);
+            assert_eq!(parse_tle(&formatted).unwrap(), elements);
+            for line in formatted.lines().skip(usize::from(elements.object_name.is_some())) {
+                assert_eq!(line.len(), 69);
+                assert!(validate_checksum(line));
+            }
+        }
+    }
+
+    #[test]
+    fn test_format_tle_fields() {
+        let mut elements = parse_tle(ISS_TLE_2LINE).unwrap();
+
+        // Alpha-5 catalog numbers
+        elements.norad_id = 339_999;
+        let (line1, line2) = format_tle_lines(&elements).unwrap();
+        assert_eq!(&line1[2..7], "Z9999");
+        assert_eq!(&line2[2..7], "Z9999");
+        elements.norad_id = 180_042;
+        assert_eq!(parse_tle(&format_tle(&elements).unwrap()).unwrap().norad_id, 180_042);
+
+        // Mantissa carry and positive exponents
+        elements.drag_term = 0.999996;
+        elements.mean_motion_ddot = 1.5;
+        let (line1, _) = format_tle_lines(&elements).unwrap();
+        assert_eq!(&line1[44..52], " 15000+1");
+        assert_eq!(&line1[53..61], " 10000+1");
+        let parsed = parse_tle(&format_tle(&elements).unwrap()).unwrap();
+        assert_eq!(parsed.mean_motion_ddot, 1.5);
+
+        // Epoch rounding into the next day
+        let datetime = sgp4::chrono::NaiveDate::from_ymd_opt(2023, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 999_999_999)
+            .unwrap();
+        elements.datetime = datetime;
+        let (line1, _) = format_tle_lines(&elements).unwrap();
+        assert_eq!(&line1[18..32], "24001.00000000");
+
+        assert_eq!(tle_checksum("1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  292"), 7);
+    }
+
+    #[test]
+    fn test_format_tle_invalid() {
+        let elements = parse_tle(ISS_TLE_3LINE).unwrap();
+        let invalid = [
+            Elements { norad_id: 340_000, ..elements.clone() },
+            Elements { eccentricity: 1.0, ..elements.clone() },
+            Elements { mean_motion_dot: -1.2, ..elements.clone() },
+            Elements { drag_term: 2e9, ..elements.clone() },
+            Elements { mean_motion: 123.0, ..elements.clone() },
+            Elements { international_designator: Some("ISS".to_string()), ..elements.clone() },
+            Elements { ephemeris_type: 12, ..elements.clone() },
+        ];
+        for elements in invalid {
+            assert!(matches!(format_tle(&elements), Err(Sgp4Error::InvalidElements(_))));
+        }
+    }
 }
@@ -77,23 +77,25 @@
 use nalgebra::{Vector3, Matrix3};
 use std::f64::consts::PI;
 
-/// WGS84 Earth ellipsoid parameters
-const WGS84_A: f64 = 6378.137;           // Semi-major axis (km)
-const WGS84_F: f64 = 1.0 / 298.257223563; // Flattening
-const WGS84_E2: f64 = WGS84_F * (2.0 - WGS84_F); // Eccentricity squared
+use super::groundtrack::Ellipsoid;
 
 /// Small threshold for numerical singularity detection (same as Vallado)
 const SMALL: f64 = 1e-8;
 
-/// Observer location on Earth's surface
+/// Observer location on the surface of Earth or another body
+///
+/// Positions passed to the visibility functions are in the body-fixed frame
+/// of the observer's body (ECEF for Earth).
 #[derive(Debug, Clone, Copy)]
 pub struct Observer {
     /// Geodetic latitude (radians, -π/2 to π/2)
     pub latitude: f64,
     /// Geodetic longitude (radians, -π to π)
     pub longitude: f64,
-    /// Altitude above WGS84 ellipsoid (km)
+    /// Altitude above the reference ellipsoid (km)
     pub altitude: f64,
+    /// Reference ellipsoid (WGS84 unless set with [`Observer::with_ellipsoid`])
+    ellipsoid: Ellipsoid,
 }
 
 impl Observer {
@@ -104,12 +106,32 @@ impl Observer {
     /// - `longitude`: Geodetic longitude in radians (-π to π)
     /// - `altitude`: Altitude above WGS84 ellipsoid in km
     pub fn new(latitude: f64, longitude: f64, altitude: f64) -> Self {
-        Observer { latitude, longitude, altitude }
+        Observer {
+            latitude,
+            longitude,
+            altitude,
+            ellipsoid: Ellipsoid::WGS84,
+        }
+    }
+
+    /// Place the observer on another reference ellipsoid, the altitude
+    /// then being above that ellipsoid
+    ///
+    /// Use with [`Ellipsoid::for_body`] for observers on other bodies, e.g. a
+    /// lunar lander or a Mars rover.
+    pub fn with_ellipsoid(mut self, ellipsoid: Ellipsoid) -> Self {
+        self.ellipsoid = ellipsoid;
+        self
+    }
+
+    /// Reference ellipsoid of the observer's body
+    pub fn ellipsoid(&self) -> &Ellipsoid {
+        &self.ellipsoid
     }
 
     /// Convert observer geodetic coordinates to ECEF position vector (km)
     ///
-    /// Uses WGS84 ellipsoid model with flattening.
+    /// Uses the observer's reference ellipsoid (WGS84 by default).
     ///
     /// # Algorithm
     /// 1. Compute radius of curvature in prime vertical: N = a / sqrt(1 - e²sin²φ)
@@ -121,17 +143,9 @@ impl Observer {
     /// # Returns
     /// ECEF position vector [x, y, z] in km
     pub fn to_ecef(&self) -> Vector3<f64> {
-        let sin_lat = self.latitude.sin();
-        let cos_lat = self.latitude.cos();
-        let sin_lon = self.longitude.sin();
-        let cos_lon = self.longitude.cos();
-
-        // Radius of curvature in the prime vertical
-        let n = WGS84_A / (1.0 - WGS84_E2 * sin_lat * sin_lat).sqrt();
-
-        let x = (n + self.altitude) * cos_lat * cos_lon;
-        let y = (n + self.altitude) * cos_lat * sin_lon;
-        let z = (n * (1.0 - WGS84_E2) + self.altitude) * sin_lat;
+        let [x, y, z] = self
+            .ellipsoid
+            .geodetic_to_cartesian(self.latitude, self.longitude, self.altitude);
 
         Vector3::new(x, y, z)
     }
@@ -704,7 +718,7 @@ mod tests {
         let ecef = obs.to_ecef();
 
         // Should be on equator at semi-major axis
-        assert_relative_eq!(ecef[0], WGS84_A, epsilon = 1e-6);
+        assert_relative_eq!(ecef[0], Ellipsoid::WGS84.equatorial_radius, epsilon = 1e-6);
         assert_relative_eq!(ecef[1], 0.0, epsilon = 1e-6);
         assert_relative_eq!(ecef[2], 0.0, epsilon = 1e-6);
     }
@@ -1306,4 +1320,29 @@ mod tests {
         assert!(pass.max_elevation_time >= pass.rise_time);
         assert!(pass.max_elevation_time <= pass.set_time);
     }
+
+    #[test]
+    fn test_lunar_observer() {
+        use crate::ephemeris::Body;
+
+        let moon = Ellipsoid::for_body(Body::Moon).unwrap();
+        let obs = Observer::new(0.0, 0.0, 0.0).with_ellipsoid(moon);
+        assert_eq!(*obs.ellipsoid(), moon);
+        assert_eq!(*Observer::new(0.0, 0.0, 0.0).ellipsoid(), Ellipsoid::WGS84);
+
+        let ecef = obs.to_ecef();
+        assert_relative_eq!(ecef[0], moon.equatorial_radius, epsilon = 1e-9);
+
+        // Orbiter 100 km overhead is at the zenith
+        let sat = [moon.equatorial_radius + 100.0, 0.0, 0.0];
+        let topo = compute_azimuth_elevation(&sat, &obs);
+        assert_relative_eq!(topo.elevation, PI / 2.0, epsilon = 1e-9);
+        assert_relative_eq!(topo.range, 100.0, epsilon = 1e-9);
+
+        // An orbiter 100 km above a point 30° away is below the lunar horizon
+        let angle = 30.0_f64.to_radians();
+        let r = moon.equatorial_radius + 100.0;
+        let sat = [r * angle.cos(), r * angle.sin(), 0.0];
+        assert!(!has_line_of_sight(&sat, &obs));
+    }
 }
@@ -0,0 +1,84 @@
+"""
+Tests for CCSDS APM and AEM reading and writing.
+
+Quaternion attitudes written to attitude data messages must come back
+unchanged in both encodings, whatever the direction of the rotation.
+"""
+
+import math
+
+import pytest
+from astrora._core import Duration, Epoch, parse_aem, parse_apm, write_aem, write_apm
+
+EPOCH = Epoch(2024, 3, 1, 12)
+
+
+def z_rotation(angle):
+    """[q1, q2, q3, qc] of a rotation of `angle` rad about Z"""
+    return [0.0, 0.0, math.sin(angle / 2), math.cos(angle / 2)]
+
+
+class TestApm:
+    @pytest.mark.parametrize("encoding", ["kvn", "xml"])
+    def test_round_trip(self, encoding):
+        quaternion = z_rotation(0.3)
+        text = write_apm(
+            EPOCH,
+            quaternion,
+            "SAT",
+            "2024-001A",
+            quaternion_rate=[0.0, 0.0, 0.01, -0.001],
+            encoding=encoding,
+        )
+        apm = parse_apm(text)
+        assert apm["object_name"] == "SAT"
+        assert apm["epoch"] == EPOCH
+        assert apm["q_frame_a"] == "EME2000"
+        assert apm["q_dir"] == "A2B"
+        assert apm["quaternion"] == pytest.approx(quaternion)
+        assert apm["quaternion_rate"] == pytest.approx([0.0, 0.0, 0.01, -0.001])
+
+    def test_direction(self):
+        apm = parse_apm(write_apm(EPOCH, z_rotation(0.3), "SAT", "2024-001A", q_dir="B2A"))
+        assert apm["q_dir"] == "B2A"
+        # The rotation from A to B is the inverse of the message quaternion
+        assert apm["rotation"] == pytest.approx(z_rotation(-0.3))
+
+    def test_invalid_input(self):
+        with pytest.raises(ValueError):
+            write_apm(EPOCH, [0.0, 0.0, 0.0, 2.0], "SAT", "2024-001A")
+        with pytest.raises(ValueError):
+            write_apm(EPOCH, z_rotation(0.3), "SAT", "2024-001A", q_dir="AB")
+
+
+class TestAem:
+    @pytest.mark.parametrize("encoding", ["kvn", "xml"])
+    def test_round_trip(self, encoding):
+        epochs = [EPOCH + Duration(60.0 * k) for k in range(5)]
+        quaternions = [z_rotation(0.1 * k) for k in range(5)]
+        text = write_aem(epochs, quaternions, "SAT", "2024-001A", encoding=encoding)
+        aem = parse_aem(text)
+        segment = aem["segments"][0]
+        assert segment["object_name"] == "SAT"
+        assert segment["ref_frame_b"] == "SC_BODY_1"
+        assert segment["attitude_type"] == "QUATERNION"
+        assert segment["start_time"] == epochs[0]
+        assert segment["stop_time"] == epochs[-1]
+        assert segment["epochs"] == epochs
+        for read, written in zip(segment["quaternions"], quaternions):
+            assert read == pytest.approx(written)
+
+    def test_kvn_layout(self):
+        text = write_aem([EPOCH], [z_rotation(0.0)], "SAT", "2024-001A", time_system="TAI")
+        assert text.startswith("CCSDS_AEM_VERS = 1.0\n")
+        assert "QUATERNION_TYPE = LAST" in text
+        assert "TIME_SYSTEM = TAI" in text
+
+    def test_invalid_input(self):
+        epochs = [EPOCH, EPOCH + Duration(60.0)]
+        with pytest.raises(ValueError):
+            write_aem(epochs, [z_rotation(0.0)], "SAT", "2024-001A")
+        with pytest.raises(ValueError):
+            write_aem(list(reversed(epochs)), [z_rotation(0.0)] * 2, "SAT", "2024-001A")
+        with pytest.raises(ValueError):
+            parse_aem("CCSDS_AEM_VERS = 1.0\nORIGINATOR = X\n")
@@ -0,0 +1,68 @@
+"""
+Tests for atmospheric density models (exponential, Harris-Priester,
+Jacchia 1971) and solar-activity-driven lifetime estimation
+"""
+
+import numpy as np
+import pytest
+from astrora._core import (
+    Epoch,
+    atmospheric_density,
+    estimate_satellite_lifetime,
+    exponential_density,
+)
+
+R_EARTH = 6378137.0
+
+
+@pytest.fixture
+def epoch():
+    return Epoch(2024, 6, 1, 0, 0, 0, 0)
+
+
+def position(altitude_km):
+    return np.array([R_EARTH + altitude_km * 1e3, 0.0, 0.0])
+
+
+class TestAtmosphericDensity:
+    def test_exponential_matches_free_function(self, epoch):
+        rho = atmospheric_density(position(50.0), epoch, model="exponential")
+        assert rho == pytest.approx(exponential_density(50e3, 1.225, 8500.0))
+
+    def test_jacchia_magnitude(self, epoch):
+        rho = atmospheric_density(position(400.0), epoch)
+        assert 1e-13 < rho < 1e-10
+
+    def test_jacchia_solar_activity(self, epoch):
+        quiet = atmospheric_density(position(400.0), epoch, f107=70.0, f107_avg=70.0, ap=4.0)
+        active = atmospheric_density(position(400.0), epoch, f107=250.0, f107_avg=250.0, ap=4.0)
+        assert active > 3.0 * quiet
+
+    def test_harris_priester_range(self, epoch):
+        assert atmospheric_density(position(400.0), epoch, model="harris_priester") > 0.0
+        assert atmospheric_density(position(1200.0), epoch, model="harris_priester") == 0.0
+
+    def test_unknown_model(self, epoch):
+        with pytest.raises(ValueError):
+            atmospheric_density(position(400.0), epoch, model="msis")
+
+
+class TestLifetimeWithAtmosphere:
+    def circular(self, altitude_km):
+        r = R_EARTH / 1e3 + altitude_km
+        return [r, 0.0, 0.0], [0.0, np.sqrt(398600.4418 / r), 0.0]
+
+    def test_solar_activity_shortens_lifetime(self, epoch):
+        r, v = self.circular(150.0)
+        quiet = estimate_satellite_lifetime(
+            r, v, 0.02, 120.0, 10.0, atmosphere="jacchia71", epoch=epoch, f107=70.0, f107_avg=70.0, ap=4.0
+        )
+        active = estimate_satellite_lifetime(
+            r, v, 0.02, 120.0, 10.0, atmosphere="jacchia71", epoch=epoch, f107=250.0, f107_avg=250.0, ap=40.0
+        )
+        assert 0.0 < active < quiet
+
+    def test_epoch_required(self):
+        r, v = self.circular(150.0)
+        with pytest.raises(ValueError):
+            estimate_satellite_lifetime(r, v, 0.02, 120.0, 10.0, atmosphere="jacchia71")
@@ -0,0 +1,113 @@
+"""
+Tests for measurement models and batch least-squares orbit determination
+"""
+
+import numpy as np
+import pytest
+from astrora._core import (
+    BatchLeastSquares,
+    Covariance6,
+    Duration,
+    Epoch,
+    GroundStation,
+    Measurement,
+    NumericalPropagator,
+    constants,
+)
+from numpy.testing import assert_allclose
+
+R0 = np.array([6878e3, 0.0, 0.0])
+V0 = np.array([0.0, 4500.0, 6000.0])
+STATIONS = [
+    GroundStation("Kourou", 5.25, -52.8, 0.0),
+    GroundStation("Svalbard", 78.2, 15.4, 0.5),
+]
+
+
+@pytest.fixture
+def epoch():
+    return Epoch(2024, 3, 1, 12, 0, 0, 0)
+
+
+def make_propagator(epoch, r=R0, v=V0):
+    prop = NumericalPropagator(r, v, epoch, constants.GM_EARTH)
+    prop.add_j2(constants.J2_EARTH, constants.R_EARTH)
+    return prop
+
+
+def simulate(epoch, kinds, sigmas, rng=None):
+    truth = make_propagator(epoch)
+    measurements = []
+    for i, minute in enumerate(range(-30, 90, 2)):
+        t = epoch + Duration(60.0 * minute)
+        r, v = truth.propagate_to(t)
+        for kind, sigma in zip(kinds, sigmas):
+            station = None if kind == "gnss" else STATIONS[i % 2]
+            dim = {"range": 1, "range_rate": 1, "az_el": 2, "ra_dec": 2, "gnss": 3}[kind]
+            template = Measurement(t, kind, [0.0] * dim, [sigma] * dim, station)
+            values = template.predict(r, v)
+            if rng is not None:
+                values = values + rng.normal(0.0, sigma, dim)
+            measurements.append(Measurement(t, kind, list(values), [sigma] * dim, station))
+    return measurements
+
+
+class TestMeasurement:
+    def test_properties(self, epoch):
+        m = Measurement(epoch, "az_el", [1.0, 0.5], [1e-4, 1e-4], STATIONS[0])
+        assert m.kind == "az_el"
+        assert m.station == "Kourou"
+        assert_allclose(m.values, [1.0, 0.5])
+
+    def test_validation(self, epoch):
+        with pytest.raises(ValueError):
+            Measurement(epoch, "range", [1.0], [1.0])
+        with pytest.raises(ValueError):
+            Measurement(epoch, "range", [1.0, 2.0], [1.0, 1.0], STATIONS[0])
+        with pytest.raises(ValueError):
+            Measurement(epoch, "lidar", [1.0], [1.0], STATIONS[0])
+
+    def test_station_state(self, epoch):
+        r, v = STATIONS[0].gcrs_state(epoch)
+        assert np.linalg.norm(r) == pytest.approx(6378e3, rel=1e-2)
+        assert np.linalg.norm(v) == pytest.approx(465.0, rel=1e-2)
+
+
+class TestBatchLeastSquares:
+    def test_recovers_truth(self, epoch):
+        measurements = simulate(epoch, ["range", "range_rate"], [1.0, 1e-3])
+        guess = make_propagator(epoch, R0 + [2e3, -1.5e3, 1e3], V0 + [-1.0, 2.0, 0.5])
+        solution = BatchLeastSquares().solve(guess, measurements)
+
+        assert solution.converged
+        assert_allclose(solution.position, R0, atol=1e-2)
+        assert_allclose(solution.velocity, V0, atol=1e-5)
+        assert len(solution.residuals) == len(measurements)
+        assert solution.rms_history[0] > solution.rms
+        assert_allclose(guess.position, solution.position)
+
+    def test_noisy_mixed_measurements(self, epoch):
+        rng = np.random.default_rng(1)
+        measurements = simulate(epoch, ["az_el", "ra_dec", "gnss"], [1e-4, 1e-4, 10.0], rng)
+        guess = make_propagator(epoch, R0 + [1e3, 0.0, 0.0], V0)
+        solution = BatchLeastSquares(max_iterations=10).solve(guess, measurements)
+
+        assert solution.converged
+        assert 0.8 < solution.rms < 1.2
+        sigma = solution.covariance.std_devs
+        error = np.concatenate([solution.position - R0, solution.velocity - V0])
+        assert np.all(np.abs(error) < 5.0 * sigma)
+        stations = {r.station for r in solution.residuals}
+        assert stations == {"Kourou", "Svalbard", None}
+
+    def test_a_priori(self, epoch):
+        measurements = simulate(epoch, ["range"], [1.0])
+        guess = make_propagator(epoch, R0 + [2e3, 0.0, 0.0], V0)
+        tight = Covariance6.from_std_devs(np.array([1e-3] * 3 + [1e-6] * 3))
+        solution = BatchLeastSquares(a_priori_covariance=tight).solve(guess, measurements[:3])
+        assert_allclose(solution.position, R0 + [2e3, 0.0, 0.0], atol=1.0)
+
+    def test_too_few_measurements(self, epoch):
+        measurements = simulate(epoch, ["range"], [1.0])
+        with pytest.raises(ValueError):
+            BatchLeastSquares().solve(make_propagator(epoch), measurements[:2])
@@ -0,0 +1,108 @@
+"""
+Tests for body-centered inertial and body-fixed frames of other bodies
+(IAU rotation models) and geodetic/visibility conversions on other bodies
+"""
+
+import numpy as np
+import pytest
+from astrora._core import (
+    GCRS,
+    BodyCenteredInertial,
+    BodyFixed,
+    Epoch,
+    compute_azimuth_elevation,
+    ecef_to_geodetic,
+)
+from numpy.testing import assert_allclose
+
+MOON = 301
+MARS = 499
+R_MARS = 3_396_200.0
+R_MOON = 1_738_100.0
+
+
+@pytest.fixture
+def epoch():
+    return Epoch(2026, 5, 1, 12, 0, 0, 0)
+
+
+class TestBodyCenteredInertial:
+    def test_construction(self, epoch):
+        frame = BodyCenteredInertial(MARS, np.array([4e6, 0.0, 0.0]), np.array([0.0, 3400.0, 0.0]), epoch)
+
+        assert frame.body == MARS
+        assert_allclose(frame.position, [4e6, 0.0, 0.0])
+        assert_allclose(frame.velocity, [0.0, 3400.0, 0.0])
+        assert "BodyCenteredInertial" in repr(frame)
+
+    def test_gcrs_roundtrip(self, epoch):
+        gcrs = GCRS(np.array([7e6, 0.0, 0.0]), np.array([0.0, 7500.0, 0.0]), epoch)
+
+        selenocentric = BodyCenteredInertial.from_gcrs(gcrs, MOON)
+        assert 3.4e8 < np.linalg.norm(selenocentric.position) < 4.2e8
+
+        back = selenocentric.to_gcrs()
+        assert_allclose(back.position, gcrs.position, atol=1e-6)
+        assert_allclose(back.velocity, gcrs.velocity, atol=1e-9)
+
+    def test_body_fixed_roundtrip(self, epoch):
+        inertial = BodyCenteredInertial(
+            MOON, np.array([1.2e6, -1.5e6, 0.8e6]), np.array([900.0, 700.0, -1100.0]), epoch
+        )
+
+        fixed = inertial.to_body_fixed()
+        assert_allclose(np.linalg.norm(fixed.position), np.linalg.norm(inertial.position))
+
+        back = fixed.to_inertial()
+        assert_allclose(back.position, inertial.position, atol=1e-6)
+        assert_allclose(back.velocity, inertial.velocity, atol=1e-9)
+
+    def test_body_without_rotation_model(self, epoch):
+        frame = BodyCenteredInertial(-82, np.array([1e6, 0.0, 0.0]), np.zeros(3), epoch)
+        with pytest.raises(RuntimeError):
+            frame.to_body_fixed()
+
+
+class TestBodyFixed:
+    def test_surface_point_rotates_with_mars(self, epoch):
+        lander = BodyFixed.from_geodetic(MARS, 0.0, 0.0, 0.0, epoch)
+        assert_allclose(np.linalg.norm(lander.position), R_MARS)
+
+        inertial = lander.to_inertial()
+        expected = 2.0 * np.pi * R_MARS / 88642.66
+        assert_allclose(np.linalg.norm(inertial.velocity), expected, rtol=1e-6)
+
+    def test_geodetic_roundtrip(self, epoch):
+        lander = BodyFixed.from_geodetic(MARS, 18.4, 77.5, -2.6, epoch)
+        geodetic = lander.geodetic()
+
+        assert_allclose(geodetic["latitude_deg"], 18.4, atol=1e-9)
+        assert_allclose(geodetic["longitude_deg"], 77.5, atol=1e-9)
+        assert_allclose(geodetic["altitude_km"], -2.6, atol=1e-6)
+
+    def test_unknown_body(self, epoch):
+        with pytest.raises(ValueError):
+            BodyFixed.from_geodetic(-82, 0.0, 0.0, 0.0, epoch)
+
+
+class TestOtherBodyGeodesy:
+    def test_ecef_to_geodetic_on_moon(self):
+        geodetic = ecef_to_geodetic(np.array([0.0, R_MOON / 1000.0 + 100.0, 0.0]), body=MOON)
+
+        assert_allclose(geodetic["latitude_deg"], 0.0, atol=1e-9)
+        assert_allclose(geodetic["longitude_deg"], 90.0, atol=1e-9)
+        assert_allclose(geodetic["altitude_km"], 100.0, atol=1e-6)
+
+    def test_ecef_to_geodetic_defaults_to_wgs84(self):
+        geodetic = ecef_to_geodetic(np.array([6378.137, 0.0, 0.0]))
+        assert_allclose(geodetic["altitude_km"], 0.0, atol=1e-6)
+
+    def test_lunar_observer_zenith(self):
+        result = compute_azimuth_elevation([R_MOON / 1000.0 + 100.0, 0.0, 0.0], 0.0, 0.0, 0.0, body=MOON)
+
+        assert_allclose(result["elevation_deg"], 90.0, atol=1e-6)
+        assert_allclose(result["range_km"], 100.0, atol=1e-6)
+
+    def test_unknown_body(self):
+        with pytest.raises(ValueError):
+            ecef_to_geodetic(np.array([1000.0, 0.0, 0.0]), body=-82)
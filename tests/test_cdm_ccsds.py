@@ -0,0 +1,85 @@
+"""
+Tests for CCSDS CDM reading and writing.
+
+A CDM written from two states must carry the miss distance and relative
+state of those states, and read back the same in both encodings.
+"""
+
+import math
+
+import numpy as np
+import pytest
+from astrora._core import CartesianState, CdmObject, Covariance6, Epoch, parse_cdm, write_cdm
+
+TCA = Epoch(2024, 6, 1, 3, 4, 5)
+
+
+def diagonal_covariance():
+    return Covariance6(np.diag([100.0, 400.0, 25.0, 1e-4, 4e-4, 1e-4]))
+
+
+def objects(frame="GCRS"):
+    state1 = CartesianState([7000e3, 0.0, 0.0], [0.0, 7500.0, 0.0])
+    # 100 m above, 200 m across the track and crossing at 1 km/s
+    state2 = CartesianState([7000.1e3, 0.0, 200.0], [0.0, 7500.0, 1000.0])
+    covariance = diagonal_covariance()
+    return (
+        CdmObject("12345", "SAT A", "2020-001A", state1, covariance, frame=frame),
+        CdmObject("30337", "DEBRIS", "1999-025AA", state2, covariance, frame=frame),
+    )
+
+
+class TestCdm:
+    @pytest.mark.parametrize("encoding", ["kvn", "xml"])
+    def test_round_trip(self, encoding):
+        sat, debris = objects()
+        text = write_cdm(
+            TCA,
+            sat,
+            debris,
+            "SCREEN-1",
+            originator="TEST",
+            collision_probability=1.5e-4,
+            collision_probability_method="FOSTER-1992",
+            encoding=encoding,
+        )
+        cdm = parse_cdm(text)
+        assert cdm["originator"] == "TEST"
+        assert cdm["message_id"] == "SCREEN-1"
+        assert cdm["tca"] == TCA
+        assert cdm["miss_distance"] == pytest.approx(math.hypot(100.0, 200.0))
+        assert cdm["relative_speed"] == pytest.approx(1000.0)
+        assert cdm["relative_position"] == pytest.approx([100.0, 0.0, 200.0], abs=1e-9)
+        assert cdm["relative_velocity"] == pytest.approx([0.0, 0.0, 1000.0], abs=1e-9)
+        assert cdm["collision_probability"] == 1.5e-4
+        assert cdm["collision_probability_method"] == "FOSTER-1992"
+
+        object2 = cdm["object2"]
+        assert object2.object_name == "DEBRIS"
+        assert object2.ref_frame == "GCRF"
+        assert list(object2.state.position) == pytest.approx([7000.1e3, 0.0, 200.0])
+
+    def test_kvn_layout(self):
+        sat, debris = objects(frame="J2000")
+        text = write_cdm(TCA, sat, debris, "SCREEN-2")
+        assert text.startswith("CCSDS_CDM_VERS = 1.0\n")
+        assert "OBJECT = OBJECT1" in text and "OBJECT = OBJECT2" in text
+        assert "REF_FRAME = EME2000" in text
+        assert "CR_R = 100" in text
+
+    def test_inertial_covariance(self):
+        sat, _ = objects()
+        # The RTN frame of this state is aligned with the inertial axes
+        np.testing.assert_allclose(sat.inertial_covariance().matrix, sat.covariance.matrix, atol=1e-9)
+
+    def test_invalid_input(self):
+        sat, _ = objects()
+        _, debris = objects(frame="ITRS")
+        with pytest.raises(ValueError):
+            write_cdm(TCA, sat, debris, "MIXED")
+        with pytest.raises(ValueError):
+            objects(frame="TEME")
+        with pytest.raises(ValueError):
+            write_cdm(TCA, sat, sat, "BAD", collision_probability=2.0)
+        with pytest.raises(ValueError):
+            parse_cdm("CCSDS_CDM_VERS = 1.0\nORIGINATOR = X\n")
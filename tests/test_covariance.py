@@ -0,0 +1,108 @@
+"""
+Tests for Covariance6: STM/unscented propagation and frame/element conversions
+"""
+
+import numpy as np
+import pytest
+from astrora._core import (
+    Covariance6,
+    Duration,
+    Epoch,
+    NumericalPropagator,
+    OrbitalElements,
+    constants,
+    coe_to_rv,
+)
+from numpy.testing import assert_allclose
+
+ELEMENTS = OrbitalElements(7000e3, 0.01, 0.9, 0.4, 0.3, 1.0)
+
+
+@pytest.fixture
+def state():
+    r, v = coe_to_rv(ELEMENTS, constants.GM_EARTH)
+    return np.asarray(r), np.asarray(v)
+
+
+@pytest.fixture
+def covariance():
+    return Covariance6.from_std_devs(np.array([100.0, 200.0, 50.0, 0.1, 0.2, 0.05]))
+
+
+class TestConstruction:
+    def test_matrix_and_std_devs(self, covariance):
+        assert covariance.matrix.shape == (6, 6)
+        assert_allclose(covariance.std_devs, [100.0, 200.0, 50.0, 0.1, 0.2, 0.05])
+        assert_allclose(covariance.correlation(), np.eye(6))
+
+    def test_rejects_asymmetric(self):
+        matrix = np.eye(6)
+        matrix[0, 1] = 0.5
+        with pytest.raises(ValueError):
+            Covariance6(matrix)
+
+    def test_rejects_wrong_shape(self):
+        with pytest.raises(ValueError):
+            Covariance6(np.eye(3))
+
+
+class TestFrames:
+    @pytest.mark.parametrize("frame", ["ric", "ntw"])
+    def test_roundtrip(self, covariance, state, frame):
+        r, v = state
+        local = getattr(covariance, f"to_{frame}")(r, v)
+        back = getattr(local, f"from_{frame}")(r, v)
+        assert_allclose(back.matrix, covariance.matrix, atol=1e-9)
+        assert np.trace(local.matrix) == pytest.approx(np.trace(covariance.matrix))
+
+    def test_radial_uncertainty_in_ric(self, state):
+        r, v = state
+        radial = r / np.linalg.norm(r)
+        matrix = np.zeros((6, 6))
+        matrix[:3, :3] = 1e4 * np.outer(radial, radial)
+        ric = Covariance6(matrix).to_ric(r, v)
+        assert ric.matrix[0, 0] == pytest.approx(1e4)
+        assert ric.matrix[1, 1] == pytest.approx(0.0, abs=1e-6)
+
+
+class TestElements:
+    def test_keplerian_roundtrip(self, covariance, state):
+        r, v = state
+        keplerian = covariance.to_keplerian(r, v, constants.GM_EARTH)
+        back = keplerian.from_keplerian(ELEMENTS, constants.GM_EARTH)
+        assert_allclose(back.matrix, covariance.matrix, rtol=1e-6, atol=1e-6)
+
+    def test_equinoctial_singularity_free(self):
+        r = np.array([7000e3, 0.0, 0.0])
+        v = np.array([0.0, np.sqrt(constants.GM_EARTH / 7000e3), 0.0])
+        covariance = Covariance6.from_std_devs(np.ones(6))
+        with pytest.raises(ArithmeticError):
+            covariance.to_keplerian(r, v, constants.GM_EARTH)
+        assert covariance.to_equinoctial(r, v, constants.GM_EARTH).matrix.shape == (6, 6)
+
+
+class TestPropagation:
+    def test_unscented_matches_linear(self, state):
+        r, v = state
+        epoch = Epoch(2024, 3, 1, 12, 0, 0, 0)
+        target = epoch + Duration(1800.0)
+        covariance = Covariance6.from_std_devs(np.array([10.0] * 3 + [0.01] * 3))
+
+        def propagate(x):
+            prop = NumericalPropagator(x[:3], x[3:], epoch, constants.GM_EARTH)
+            r1, v1 = prop.propagate_to(target)
+            return np.concatenate([r1, v1])
+
+        mean, unscented = covariance.propagate_unscented(np.concatenate([r, v]), propagate)
+        _, _, stm, _ = NumericalPropagator(r, v, epoch, constants.GM_EARTH).propagate_with_stm(target)
+        linear = covariance.propagate_linear(stm)
+
+        assert_allclose(mean, propagate(np.concatenate([r, v])), rtol=1e-6)
+        assert_allclose(unscented.std_devs, linear.std_devs, rtol=1e-3)
+
+    def test_function_errors_propagate(self, covariance, state):
+        def fail(x):
+            raise RuntimeError("boom")
+
+        with pytest.raises(RuntimeError):
+            covariance.propagate_unscented(np.zeros(6), fail)
@@ -0,0 +1,74 @@
+"""
+Tests for the solar system ephemeris functions (built-in analytic ephemeris
+and SPK kernel loading)
+"""
+
+import numpy as np
+import pytest
+from astrora._core import (
+    GCRS,
+    Epoch,
+    clear_ephemeris,
+    ephemeris_loaded,
+    ephemeris_state,
+    load_spk,
+)
+from numpy.testing import assert_allclose
+
+AU = 1.495978707e11
+
+
+class TestAnalyticEphemeris:
+    """Queries answered by the built-in ephemeris (no kernel loaded)"""
+
+    def test_no_kernel_loaded_by_default(self):
+        clear_ephemeris()
+        assert not ephemeris_loaded()
+
+    def test_earth_barycentric_state(self):
+        r, v = ephemeris_state(399, 0, Epoch.j2000_epoch())
+
+        assert r.shape == (3,)
+        assert v.shape == (3,)
+        assert_allclose(np.linalg.norm(r) / AU, 0.983, atol=0.02)
+        assert 29000.0 < np.linalg.norm(v) < 31000.0
+
+    def test_moon_geocentric_distance(self):
+        r, _ = ephemeris_state(301, 399, Epoch(2024, 6, 1, 0, 0, 0, 0))
+        assert 356e6 < np.linalg.norm(r) < 407e6
+
+    def test_reverse_query_is_negated(self):
+        epoch = Epoch(2024, 6, 1, 0, 0, 0, 0)
+        r_sun, v_sun = ephemeris_state(10, 399, epoch)
+        r_earth, v_earth = ephemeris_state(399, 10, epoch)
+
+        assert_allclose(r_sun, -r_earth, rtol=1e-9)
+        assert_allclose(v_sun, -v_earth, rtol=1e-9)
+
+    def test_matches_gcrs_to_icrs_translation(self):
+        epoch = Epoch(2024, 1, 1, 0, 0, 0, 0)
+        r_earth, v_earth = ephemeris_state(399, 0, epoch)
+
+        icrs = GCRS(np.zeros(3), np.zeros(3), epoch).to_icrs()
+        assert_allclose(icrs.position, r_earth, atol=1e-3)
+        assert_allclose(icrs.velocity, v_earth, atol=1e-9)
+
+    def test_unsupported_body(self):
+        with pytest.raises(RuntimeError):
+            ephemeris_state(-82, 399, Epoch.j2000_epoch())
+
+
+class TestLoadSpk:
+    """SPK kernel loading errors"""
+
+    def test_missing_file(self, tmp_path):
+        with pytest.raises(RuntimeError):
+            load_spk(str(tmp_path / "missing.bsp"))
+        assert not ephemeris_loaded()
+
+    def test_invalid_file(self, tmp_path):
+        path = tmp_path / "invalid.bsp"
+        path.write_bytes(b"\0" * 2048)
+        with pytest.raises(RuntimeError):
+            load_spk(str(path))
+        assert not ephemeris_loaded()
@@ -0,0 +1,68 @@
+"""
+Tests for spherical harmonic gravity fields (ICGEM/EGM readers)
+"""
+
+import numpy as np
+import pytest
+from astrora._core import GravityField, j2_perturbation
+from numpy.testing import assert_allclose
+
+EGM_TEXT = """\
+    2    0   -0.484165143790815D-03    0.000000000000000D+00    0.7481239490D-11    0.0000000000D+00
+    2    1   -0.206615509074176D-09    0.138441389137979D-08    0.7063781502D-11    0.7348347201D-11
+    2    2    0.243938357328313D-05   -0.140027370385934D-05    0.7230231722D-11    0.7425816951D-11
+"""
+
+GFC_TEXT = """\
+modelname             TEST_GFC
+earth_gravity_constant 0.3986004415E+15
+radius                0.63781363E+07
+max_degree            2
+norm                  fully_normalized
+end_of_head ==================================================================
+gfc    2    0   -0.484165143790815D-03  0.0D+00
+gfc    2    2    0.243938357328313D-05 -0.140027370385934D-05
+"""
+
+
+class TestGravityField:
+    def test_bundled_egm2008(self):
+        field = GravityField.egm2008_degree4()
+        assert field.max_degree == 4
+        assert field.gm == pytest.approx(3.986004415e14)
+        c20, s20 = field.coefficients(2, 0)
+        assert c20 == pytest.approx(-4.84165143790815e-4)
+        assert s20 == 0.0
+        assert field.coefficients(10, 3) == (0.0, 0.0)
+
+    def test_read_egm(self, tmp_path):
+        path = tmp_path / "egm.txt"
+        path.write_text(EGM_TEXT)
+        field = GravityField.from_egm(str(path))
+        assert field.max_degree == 2
+        assert field.coefficients(2, 1)[1] == pytest.approx(1.38441389137979e-9)
+
+    def test_read_gfc(self, tmp_path):
+        path = tmp_path / "test.gfc"
+        path.write_text(GFC_TEXT)
+        field = GravityField.from_gfc(str(path))
+        assert field.name == "TEST_GFC"
+        assert field.radius == pytest.approx(6378136.3)
+        assert field.coefficients(2, 2)[0] == pytest.approx(2.43938357328313e-6)
+
+    def test_missing_file(self):
+        with pytest.raises(RuntimeError):
+            GravityField.from_gfc("/nonexistent/field.gfc")
+
+    def test_zonal_acceleration_matches_j2(self):
+        field = GravityField.egm2008_degree4()
+        r = np.array([7.0e6, 0.0, 1.0e6])
+        acc = field.acceleration(r, 2, 0)
+        j2 = -field.coefficients(2, 0)[0] * np.sqrt(5.0)
+        expected = j2_perturbation(r, field.gm, j2, field.radius)
+        assert_allclose(acc, expected, rtol=1e-10)
+
+    def test_degree_too_high(self):
+        field = GravityField.egm2008_degree4()
+        with pytest.raises(ValueError):
+            field.acceleration(np.array([7.0e6, 0.0, 0.0]), 8, 8)
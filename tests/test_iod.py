@@ -0,0 +1,109 @@
+"""
+Tests for initial orbit determination
+"""
+
+import numpy as np
+import pytest
+from astrora._core import (
+    Duration,
+    Epoch,
+    GroundStation,
+    Measurement,
+    NumericalPropagator,
+    constants,
+    iod_gauss,
+    iod_gibbs,
+    iod_gooding,
+    iod_herrick_gibbs,
+    iod_lambert,
+    iod_laplace,
+)
+
+R0 = np.array([6878e3, 0.0, 0.0])
+V0 = np.array([0.0, 4500.0, 6000.0])
+
+
+@pytest.fixture
+def epoch():
+    return Epoch(2024, 3, 1, 12, 0, 0, 0)
+
+
+def truth(epoch, offsets):
+    prop = NumericalPropagator(R0, V0, epoch, constants.GM_EARTH)
+    states = []
+    for dt in offsets:
+        t = epoch + Duration(dt)
+        r, v = prop.propagate_to(t)
+        states.append((t, r, v))
+    return states
+
+
+def station_under(epoch):
+    """Station a few degrees off the sub-satellite point at `epoch`"""
+    probe = GroundStation("probe", 0.0, 0.0, 0.0)
+    r_station, _ = probe.gcrs_state(epoch)
+    # The probe's right ascension is the Earth rotation angle, which turns
+    # the satellite's right ascension into an Earth-fixed longitude
+    lon_offset = np.degrees(np.arctan2(R0[1], R0[0]) - np.arctan2(r_station[1], r_station[0]))
+    lat = np.degrees(np.arcsin(R0[2] / np.linalg.norm(R0)))
+    return GroundStation("Tracker", lat + 3.0, lon_offset - 5.0, 0.0)
+
+
+def sightings(epoch, offsets):
+    station = station_under(epoch)
+    measurements = []
+    for t, r, v in truth(epoch, offsets):
+        template = Measurement(t, "ra_dec", [0.0, 0.0], [1e-5, 1e-5], station)
+        values = template.predict(r, v)
+        measurements.append(Measurement(t, "ra_dec", list(values), [1e-5, 1e-5], station))
+    return measurements
+
+
+class TestPositionMethods:
+    def test_gibbs(self, epoch):
+        states = truth(epoch, [0.0, 900.0, 1800.0])
+        solution = iod_gibbs([s[0] for s in states], np.array([s[1] for s in states]), constants.GM_EARTH)
+        assert solution.method == "gibbs"
+        assert solution.coplanarity < 1e-9
+        np.testing.assert_allclose(solution.state.velocity, states[1][2], atol=1e-3)
+
+    def test_herrick_gibbs(self, epoch):
+        states = truth(epoch, [0.0, 60.0, 120.0])
+        solution = iod_herrick_gibbs(
+            [s[0] for s in states], np.array([s[1] for s in states]), constants.GM_EARTH
+        )
+        np.testing.assert_allclose(solution.state.velocity, states[1][2], atol=1e-2)
+
+    def test_lambert(self, epoch):
+        (t1, r1, v1), (t2, r2, _) = truth(epoch, [0.0, 600.0])
+        solution = iod_lambert(t1, r1, t2, r2, constants.GM_EARTH)
+        assert solution.method == "lambert"
+        np.testing.assert_allclose(solution.state.velocity, v1, atol=1e-3)
+
+    def test_shape_validation(self, epoch):
+        with pytest.raises(ValueError):
+            iod_gibbs([epoch, epoch], np.zeros((3, 3)), constants.GM_EARTH)
+
+
+class TestAnglesOnly:
+    def test_gauss_and_gooding(self, epoch):
+        observations = sightings(epoch, [-300.0, 0.0, 300.0])
+        _, r, v = truth(epoch, [0.0])[0]
+        for method in (iod_gauss, iod_gooding):
+            best = method(observations, constants.GM_EARTH)[0]
+            assert best.residual_rms < 1e-8
+            np.testing.assert_allclose(best.state.position, r, atol=10.0)
+            np.testing.assert_allclose(best.state.velocity, v, atol=1e-2)
+
+    def test_laplace(self, epoch):
+        observations = sightings(epoch, [-10.0, 0.0, 10.0])
+        _, r, _ = truth(epoch, [0.0])[0]
+        best = iod_laplace(observations, constants.GM_EARTH)[0]
+        assert best.method == "laplace"
+        assert np.linalg.norm(best.state.position - r) < 5e3
+
+    def test_requires_ra_dec(self, epoch):
+        station = station_under(epoch)
+        ranges = [Measurement(epoch + Duration(60.0 * i), "range", [1e6], [1.0], station) for i in range(3)]
+        with pytest.raises(ValueError):
+            iod_gauss(ranges, constants.GM_EARTH)
@@ -0,0 +1,113 @@
+"""
+Tests for the extended and unscented Kalman filters
+"""
+
+import numpy as np
+import pytest
+from astrora._core import (
+    Covariance6,
+    Duration,
+    Epoch,
+    GroundStation,
+    KalmanFilter,
+    Measurement,
+    NumericalPropagator,
+    constants,
+)
+
+R0 = np.array([6878e3, 0.0, 0.0])
+V0 = np.array([0.0, 4500.0, 6000.0])
+STATIONS = [
+    GroundStation("Kourou", 5.25, -52.8, 0.0),
+    GroundStation("Svalbard", 78.2, 15.4, 0.5),
+]
+
+
+@pytest.fixture
+def epoch():
+    return Epoch(2024, 3, 1, 12, 0, 0, 0)
+
+
+def make_propagator(epoch, r=R0, v=V0):
+    prop = NumericalPropagator(r, v, epoch, constants.GM_EARTH)
+    prop.add_j2(constants.J2_EARTH, constants.R_EARTH)
+    return prop
+
+
+def simulate(epoch, rng):
+    truth = make_propagator(epoch)
+    measurements = []
+    for step in range(1, 81):
+        t = epoch + Duration(30.0 * step)
+        r, v = truth.propagate_to(t)
+        station = STATIONS[int(step > 40)]
+        for kind, sigma in [("range", 5.0), ("range_rate", 5e-3)]:
+            template = Measurement(t, kind, [0.0], [sigma], station)
+            value = template.predict(r, v)[0] + rng.normal(0.0, sigma)
+            measurements.append(Measurement(t, kind, [value], [sigma], station))
+    return measurements
+
+
+def initial_covariance():
+    return Covariance6.from_std_devs(np.array([1e3, 1e3, 1e3, 1.0, 1.0, 1.0]))
+
+
+def guess(epoch):
+    return make_propagator(epoch, R0 + [500.0, -300.0, 200.0], V0 + [0.3, -0.2, 0.1])
+
+
+@pytest.mark.parametrize("kind", ["ekf", "ukf"])
+def test_filter_and_smoother(epoch, kind):
+    measurements = simulate(epoch, np.random.default_rng(3))
+    result = KalmanFilter(kind, snc_sigma=1e-6).run(
+        guess(epoch), initial_covariance(), measurements
+    )
+    assert result.filtered_states.shape == (len(measurements), 6)
+    assert result.smoothed_states.shape == (len(measurements), 6)
+
+    truth = make_propagator(epoch)
+    r_last, _ = truth.propagate_to(result.filtered[-1].epoch)
+    last = result.filtered[-1]
+    sigma = np.sqrt(np.trace(last.covariance.matrix[:3, :3]))
+    assert np.linalg.norm(last.position - r_last) < 4.0 * sigma
+    assert last.acceleration is None
+
+    first_filtered = np.trace(result.filtered[0].covariance.matrix[:3, :3])
+    first_smoothed = np.trace(result.smoothed[0].covariance.matrix[:3, :3])
+    assert first_smoothed < first_filtered
+
+
+def test_editing_and_passes(epoch):
+    measurements = simulate(epoch, np.random.default_rng(5))
+    bad = measurements[60]
+    measurements[60] = Measurement(
+        bad.epoch, bad.kind, list(bad.values + 1e4), list(bad.sigmas), STATIONS[0]
+    )
+    result = KalmanFilter(editing_sigma=5.0).run(
+        guess(epoch), initial_covariance(), measurements
+    )
+    rejected = [i for i, r in enumerate(result.residuals) if not r.accepted]
+    assert rejected == [60]
+    assert [p.station for p in result.passes] == ["Kourou", "Svalbard"]
+    assert result.passes[0].rejected == 1
+    assert result.passes[0].max_normalized > 5.0
+
+
+def test_dmc(epoch):
+    measurements = simulate(epoch, np.random.default_rng(7))
+    result = KalmanFilter(dmc_sigma=1e-6, dmc_tau=600.0).run(
+        guess(epoch), initial_covariance(), measurements
+    )
+    assert result.filtered[-1].acceleration.shape == (3,)
+
+
+def test_invalid_arguments(epoch):
+    with pytest.raises(ValueError):
+        KalmanFilter("particle")
+    with pytest.raises(ValueError):
+        KalmanFilter(snc_sigma=1e-6, dmc_sigma=1e-6, dmc_tau=60.0)
+    with pytest.raises(ValueError):
+        KalmanFilter(dmc_sigma=1e-6, dmc_tau=-1.0)
+    late = make_propagator(epoch + Duration(3600.0))
+    with pytest.raises(ValueError):
+        KalmanFilter().run(late, initial_covariance(), simulate(epoch, np.random.default_rng(1)))
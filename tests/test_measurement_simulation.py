@@ -0,0 +1,93 @@
+"""
+Tests for simulated ground-station measurements
+"""
+
+import numpy as np
+import pytest
+from astrora._core import (
+    Duration,
+    Epoch,
+    GroundStation,
+    MeasurementSimulator,
+    NumericalPropagator,
+    constants,
+    two_way_doppler,
+)
+
+R0 = np.array([6878e3, 0.0, 0.0])
+V0 = np.array([0.0, 4500.0, 6000.0])
+
+
+@pytest.fixture
+def epoch():
+    return Epoch(2024, 3, 1, 12, 0, 0, 0)
+
+
+@pytest.fixture
+def ephemeris(epoch):
+    prop = NumericalPropagator(R0, V0, epoch, constants.GM_EARTH)
+    prop.propagate_to(epoch + Duration(-60.0))
+    return prop.ephemeris_to(epoch + Duration(3600.0))
+
+
+@pytest.fixture
+def station(epoch):
+    # The probe's right ascension is the Earth rotation angle, which turns
+    # the satellite's right ascension into an Earth-fixed longitude
+    r_probe, _ = GroundStation("probe", 0.0, 0.0, 0.0).gcrs_state(epoch)
+    lon = np.degrees(np.arctan2(R0[1], R0[0]) - np.arctan2(r_probe[1], r_probe[0]))
+    return GroundStation("Tracker", 3.0, lon - 5.0, 0.2)
+
+
+def epochs(epoch, step, count):
+    return [epoch + Duration(step * i) for i in range(count)]
+
+
+def make_simulator(**kwargs):
+    sim = MeasurementSimulator(**kwargs)
+    sim.add_measurement("range", 5.0)
+    sim.add_measurement("range_rate", 1e-3)
+    sim.add_measurement("az_el", 1e-5)
+    sim.add_measurement("ra_dec", 1e-5)
+    return sim
+
+
+class TestMeasurementSimulator:
+    def test_geometric_matches_predict(self, epoch, ephemeris, station):
+        sim = make_simulator(light_time=False, aberration=False, noise=False)
+        sim.add_station(station, min_elevation_deg=-90.0)
+        measurements = sim.simulate(ephemeris, epochs(epoch, 60.0, 10))
+        assert len(measurements) == 40
+        for m in measurements:
+            t = m.epoch.tdb_seconds
+            state = ephemeris.evaluate(t)
+            predicted = m.predict(state[:3], state[3:])
+            np.testing.assert_allclose(m.values, predicted, rtol=1e-9, atol=1e-6)
+
+    def test_mask_and_troposphere(self, epoch, ephemeris, station):
+        sim = make_simulator(noise=False)
+        sim.add_station(station, mask_deg=np.array([[0.0, 10.0], [180.0, 20.0]]), troposphere=True)
+        measurements = sim.simulate(ephemeris, epochs(epoch, 30.0, 60))
+        assert measurements
+        for m in measurements:
+            if m.kind == "az_el":
+                az, el = np.degrees(m.values)
+                assert el >= 10.0 + 10.0 * min(az, 360.0 - az) / 180.0 - 1e-9
+
+    def test_noise_is_seeded(self, epoch, ephemeris, station):
+        runs = []
+        for _ in range(2):
+            sim = make_simulator(seed=3)
+            sim.add_station(station, biases={"range": [12.0]})
+            runs.append(sim.simulate(ephemeris, epochs(epoch, 60.0, 10)))
+        for a, b in zip(*runs):
+            np.testing.assert_array_equal(a.values, b.values)
+
+    def test_invalid_mask(self, station):
+        sim = make_simulator()
+        with pytest.raises(ValueError):
+            sim.add_station(station, mask_deg=np.array([[90.0, 5.0], [10.0, 5.0]]))
+
+    def test_two_way_doppler(self):
+        shift = two_way_doppler(1000.0, 8.4e9)
+        assert shift == pytest.approx(-2.0 * 8.4e9 * 1000.0 / constants.C)
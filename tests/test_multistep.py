@@ -0,0 +1,66 @@
+"""
+Tests for the Gauss-Jackson and Adams-Bashforth-Moulton integrators of
+NumericalPropagator
+"""
+
+import numpy as np
+import pytest
+from astrora._core import Duration, Epoch, NumericalPropagator, constants
+from numpy.testing import assert_allclose
+
+R0 = np.array([7000e3, 0.0, 0.0])
+V0 = np.array([0.0, 6000.0, 4000.0])
+
+
+@pytest.fixture
+def epoch():
+    return Epoch(2024, 3, 1, 12, 0, 0, 0)
+
+
+def j2_propagator(epoch, integrator, step_size=30.0):
+    prop = NumericalPropagator(
+        R0, V0, epoch, constants.GM_EARTH, integrator=integrator, tol=1e-12, step_size=step_size
+    )
+    prop.add_j2(constants.J2_EARTH, constants.R_EARTH)
+    return prop
+
+
+class TestMultistepIntegrators:
+    @pytest.mark.parametrize(
+        "name,expected",
+        [("gauss_jackson", "gauss_jackson"), ("gj", "gauss_jackson"), ("abm", "abm8"), ("ABM10", "abm10")],
+    )
+    def test_names(self, epoch, name, expected):
+        assert j2_propagator(epoch, name).integrator == expected
+
+    def test_invalid_order(self, epoch):
+        with pytest.raises(ValueError):
+            j2_propagator(epoch, "abm13")
+
+    @pytest.mark.parametrize("integrator", ["gauss_jackson", "abm10"])
+    def test_matches_dop853(self, epoch, integrator):
+        target = epoch + Duration(86400.0)
+        r_ref, v_ref = j2_propagator(epoch, "dop853", step_size=10.0).propagate_to(target)
+        r, v = j2_propagator(epoch, integrator).propagate_to(target)
+        assert_allclose(r, r_ref, atol=0.1)
+        assert_allclose(v, v_ref, atol=1e-4)
+
+    def test_no_ephemeris(self, epoch):
+        with pytest.raises(ValueError):
+            j2_propagator(epoch, "gauss_jackson").ephemeris_to(epoch + Duration(3600.0))
+
+    def test_impulse(self, epoch):
+        burn = epoch + Duration(2700.0)
+        target = burn + Duration(2700.0)
+        dv = np.array([0.0, 30.0, -10.0])
+
+        states = []
+        for integrator in ["dop853", "gauss_jackson"]:
+            prop = j2_propagator(epoch, integrator)
+            _, v_burn = prop.propagate_to(burn)
+            prop.apply_impulse(dv)
+            assert_allclose(prop.velocity, v_burn + dv)
+            states.append(prop.propagate_to(target))
+
+        assert_allclose(states[0][0], states[1][0], atol=0.1)
+        assert_allclose(states[0][1], states[1][1], atol=1e-4)
@@ -0,0 +1,157 @@
+"""
+Tests for the NumericalPropagator class
+"""
+
+import numpy as np
+import pytest
+from astrora._core import (
+    Duration,
+    Epoch,
+    GravityField,
+    NumericalPropagator,
+    constants,
+    propagate_j2_dop853,
+    propagate_j2_rk4,
+    propagate_thirdbody_dopri5,
+)
+from numpy.testing import assert_allclose
+
+R0 = np.array([7000e3, 0.0, 0.0])
+V0 = np.array([0.0, 6000.0, 4000.0])
+
+
+@pytest.fixture
+def epoch():
+    return Epoch(2024, 3, 1, 12, 0, 0, 0)
+
+
+@pytest.fixture
+def propagator(epoch):
+    prop = NumericalPropagator(R0, V0, epoch, constants.GM_EARTH, tol=1e-12, step_size=10.0)
+    prop.add_j2(constants.J2_EARTH, constants.R_EARTH)
+    return prop
+
+
+class TestNumericalPropagator:
+    def test_construction(self, propagator, epoch):
+        assert propagator.integrator == "dop853"
+        assert propagator.perturbations == ["J2 Oblateness"]
+        assert propagator.epoch == epoch
+        assert_allclose(propagator.position, R0)
+        assert_allclose(propagator.velocity, V0)
+
+    def test_unknown_integrator(self, epoch):
+        with pytest.raises(ValueError):
+            NumericalPropagator(R0, V0, epoch, constants.GM_EARTH, integrator="euler")
+
+    @pytest.mark.parametrize("integrator", ["rk4", "dopri5", "dop853"])
+    def test_propagate_to_matches_j2_function(self, epoch, integrator):
+        prop = NumericalPropagator(
+            R0, V0, epoch, constants.GM_EARTH, integrator=integrator, tol=1e-12, step_size=10.0
+        )
+        prop.add_j2(constants.J2_EARTH, constants.R_EARTH)
+        target = epoch + Duration(3600.0)
+        r, v = prop.propagate_to(target)
+        r_ref, v_ref = propagate_j2_dop853(
+            R0, V0, 3600.0, constants.GM_EARTH, constants.J2_EARTH, constants.R_EARTH, tol=1e-12
+        )
+        assert_allclose(r, r_ref, atol=0.1)
+        assert_allclose(v, v_ref, atol=1e-4)
+        assert prop.epoch == target
+
+    def test_stateful_legs(self, propagator, epoch):
+        propagator.propagate_by(1000.0)
+        r_legs, _ = propagator.propagate_by(2000.0)
+        single = NumericalPropagator(R0, V0, epoch, constants.GM_EARTH, tol=1e-12, step_size=10.0)
+        single.add_j2(constants.J2_EARTH, constants.R_EARTH)
+        r_one, _ = single.propagate_by(3000.0)
+        assert_allclose(r_legs, r_one, atol=1e-2)
+        r_back, _ = propagator.propagate_to(epoch)
+        assert_allclose(r_back, R0, atol=1e-2)
+
+    def test_propagate_many(self, propagator, epoch):
+        offsets = [0.0, 600.0, 1200.0, 3600.0]
+        epochs = [epoch + Duration(dt) for dt in offsets]
+        states = propagator.propagate_many(epochs)
+        assert states.shape == (4, 6)
+        assert_allclose(states[0], np.concatenate([R0, V0]))
+        assert propagator.epoch == epochs[-1]
+        assert_allclose(propagator.position, states[-1, :3])
+
+    def test_callback(self, propagator, epoch):
+        seen = []
+
+        def on_step(step_epoch, r, v):
+            seen.append(np.linalg.norm(r))
+
+        propagator.propagate_to(epoch + Duration(1200.0), callback=on_step)
+        assert len(seen) > 1
+
+        calls = []
+        propagator.propagate_to(
+            epoch + Duration(7200.0), callback=lambda *args: calls.append(1) or len(calls) < 2
+        )
+        assert len(calls) == 2
+
+    def test_callback_error_propagates(self, propagator, epoch):
+        def boom(*args):
+            raise RuntimeError("stop")
+
+        with pytest.raises(RuntimeError, match="stop"):
+            propagator.propagate_to(epoch + Duration(600.0), callback=boom)
+
+    def test_force_model(self, epoch):
+        prop = NumericalPropagator(R0, V0, epoch, constants.GM_EARTH)
+        prop.add_gravity_field(GravityField.egm2008_degree4(), 4, 4)
+        prop.add_drag(100.0)
+        prop.add_third_body(10)
+        prop.add_third_body(301)
+        prop.add_srp(0.01, 1.3, constants.R_EARTH)
+        assert len(prop.perturbations) == 5
+        with pytest.raises(ValueError):
+            prop.add_third_body(599)
+        with pytest.raises(ValueError):
+            prop.add_third_body(999, mu=8.7e11)
+        r, _ = prop.propagate_by(600.0)
+        assert 6000e3 < np.linalg.norm(r) < 8000e3
+
+    def test_ephemeris_to(self, propagator, epoch):
+        eph = propagator.ephemeris_to(epoch + Duration(3600.0))
+        assert len(eph) > 0
+        assert propagator.epoch == epoch
+
+    def test_step_limits(self, epoch):
+        steps = []
+        prop = NumericalPropagator(
+            R0, V0, epoch, constants.GM_EARTH, step_size=120.0, max_step_size=20.0
+        )
+        prop.propagate_to(epoch + Duration(600.0), callback=lambda *args: steps.append(1))
+        assert len(steps) >= 30
+        with pytest.raises(ValueError):
+            NumericalPropagator(
+                R0, V0, epoch, constants.GM_EARTH, min_step_size=30.0, max_step_size=20.0
+            )
+
+
+class TestDeprecatedFunctions:
+    def test_j2_rk4(self, epoch):
+        with pytest.warns(DeprecationWarning, match="propagate_j2_rk4"):
+            r, v = propagate_j2_rk4(
+                R0, V0, 3600.0, constants.GM_EARTH, constants.J2_EARTH, constants.R_EARTH, n_steps=360
+            )
+        prop = NumericalPropagator(
+            R0, V0, epoch, constants.GM_EARTH, integrator="rk4", step_size=10.0
+        )
+        prop.add_j2(constants.J2_EARTH, constants.R_EARTH)
+        r_ref, v_ref = prop.propagate_by(3600.0)
+        assert_allclose(r, r_ref, atol=1e-6)
+        assert_allclose(v, v_ref, atol=1e-9)
+
+    def test_thirdbody_dopri5(self, epoch):
+        with pytest.warns(DeprecationWarning, match="propagate_thirdbody_dopri5"):
+            r, _ = propagate_thirdbody_dopri5(
+                R0, V0, 3600.0, constants.GM_EARTH, 0.0, False, False, tol=1e-12
+            )
+        prop = NumericalPropagator(R0, V0, epoch, constants.GM_EARTH, tol=1e-12)
+        r_ref, _ = prop.propagate_by(3600.0)
+        assert_allclose(r, r_ref, atol=1e-3)
@@ -0,0 +1,132 @@
+"""
+Tests for CCSDS OEM reading, writing and interpolation.
+
+States written to an OEM must come back unchanged in both encodings, and the
+interpolating ephemeris must reproduce the orbit between the states.
+"""
+
+import math
+
+import pytest
+from astrora._core import (
+    CartesianState,
+    Duration,
+    Epoch,
+    OemEphemeris,
+    parse_oem,
+    write_oem,
+)
+
+GM_EARTH = 3.986004418e14
+RADIUS = 7000e3
+RATE = math.sqrt(GM_EARTH / RADIUS**3)
+START = Epoch(2024, 3, 1, 12)
+
+
+def circular_state(t, radius=RADIUS, polar=False):
+    """Circular orbit, equatorial or polar, at t seconds from START"""
+    rate = math.sqrt(GM_EARTH / radius**3)
+    c, s = math.cos(rate * t), math.sin(rate * t)
+    if polar:
+        return CartesianState([radius * c, 0.0, radius * s], [-radius * rate * s, 0.0, radius * rate * c])
+    return CartesianState([radius * c, radius * s, 0.0], [-radius * rate * s, radius * rate * c, 0.0])
+
+
+def circular_oem(step=60.0, count=121, radius=RADIUS, polar=False, **options):
+    """Epochs, states and OEM text of a circular orbit sampled every `step` s"""
+    times = [k * step for k in range(count)]
+    epochs = [START + Duration(t) for t in times]
+    states = [circular_state(t, radius, polar) for t in times]
+    return epochs, states, write_oem(epochs, states, "SAT", "2024-001A", **options)
+
+
+class TestOemMessages:
+    @pytest.mark.parametrize("encoding", ["kvn", "xml"])
+    def test_round_trip(self, encoding):
+        epochs, states, text = circular_oem(count=5, frame="J2000", encoding=encoding, originator="TEST")
+        oem = parse_oem(text)
+        assert oem["originator"] == "TEST"
+        (segment,) = oem["segments"]
+        assert segment["object_name"] == "SAT"
+        assert segment["ref_frame"] == "EME2000"
+        assert segment["frame"] == "J2000"
+        assert segment["interpolation"] == "LAGRANGE"
+        assert segment["interpolation_degree"] == 4
+        assert len(segment["states"]) == 5
+        for epoch, state, parsed_epoch, parsed in zip(epochs, states, segment["epochs"], segment["states"]):
+            assert parsed_epoch == epoch
+            for a, b in zip(parsed.position, state.position):
+                assert a == pytest.approx(b, abs=1e-6)
+        assert segment["accelerations"] == [None] * 5
+        assert segment["covariances"] == []
+
+    def test_kvn_layout(self):
+        _, _, text = circular_oem(count=3, time_system="TAI", interpolation="hermite", interpolation_degree=3)
+        assert text.startswith("CCSDS_OEM_VERS = 2.0\n")
+        assert "META_START" in text and "META_STOP" in text
+        assert "TIME_SYSTEM = TAI" in text
+        assert "INTERPOLATION = HERMITE" in text
+        assert "INTERPOLATION_DEGREE = 2" in text
+
+    def test_invalid_input(self):
+        epochs, states, _ = circular_oem(count=3)
+        with pytest.raises(ValueError):
+            write_oem(epochs[::-1], states, "SAT", "2024-001A")
+        with pytest.raises(ValueError):
+            write_oem(epochs[:2], states, "SAT", "2024-001A")
+        with pytest.raises(ValueError):
+            write_oem(epochs, states, "SAT", "2024-001A", frame="GALACTIC")
+        with pytest.raises(ValueError):
+            write_oem(epochs, states, "SAT", "2024-001A", encoding="json")
+        with pytest.raises(ValueError):
+            parse_oem("CCSDS_OEM_VERS = 2.0\nORIGINATOR = X\n")
+
+
+class TestOemEphemeris:
+    def test_interpolation(self):
+        _, _, text = circular_oem()
+        ephemeris = OemEphemeris.from_oem(text)
+        assert ephemeris.frame == "GCRS"
+        assert ephemeris.start == START
+        assert len(ephemeris) == 1
+        for t in [0.0, 30.0, 3615.0, 7200.0]:
+            state = ephemeris.state(START + Duration(t))
+            exact = circular_state(t)
+            error = math.dist(state.position, exact.position)
+            assert error < 1e-3
+
+    def test_out_of_range(self):
+        _, _, text = circular_oem(count=11)
+        ephemeris = OemEphemeris.from_oem(text)
+        with pytest.raises(ValueError):
+            ephemeris.state(START + Duration(601.0))
+
+    def test_frame_transformation(self):
+        _, _, text = circular_oem(count=11)
+        ephemeris = OemEphemeris.from_oem(text)
+        epoch = START + Duration(120.0)
+        gcrs = ephemeris.state(epoch)
+        itrs = ephemeris.state(epoch, frame="ITRS")
+        assert math.hypot(*itrs.position) == pytest.approx(math.hypot(*gcrs.position), abs=1e-3)
+
+    def test_passes(self):
+        _, _, text = circular_oem(count=181)
+        ephemeris = OemEphemeris.from_oem(text)
+        passes = ephemeris.find_passes(0.0, 0.0, 0.0, START, START + Duration(10800.0))
+        assert passes
+        assert all(p["max_elevation_deg"] > 80.0 for p in passes)
+
+    def test_conjunction(self):
+        period = 2.0 * math.pi / RATE
+        step = period / 120.0
+        _, _, equatorial = circular_oem(step=step, count=121)
+        _, _, polar = circular_oem(step=step, count=121, radius=RADIUS + 500.0, polar=True)
+        result = OemEphemeris.from_oem(equatorial).conjunction(
+            OemEphemeris.from_oem(polar),
+            START + Duration(600.0),
+            START + Duration(period - 600.0),
+            step=60.0,
+            collision_threshold=1000.0,
+        )
+        assert result["miss_distance"] == pytest.approx(500.0, abs=0.1)
+        assert result["collision_risk"]
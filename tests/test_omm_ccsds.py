@@ -0,0 +1,115 @@
+"""
+Tests for CCSDS OMM parsing in KVN and XML.
+
+The same message must give the same elements in every encoding, and the
+header, metadata and covariance must be kept.
+"""
+
+import pytest
+from astrora._core import omm_to_tle, parse_omm, py_propagate_omm, tle_to_omm
+
+ISS_TLE = """ISS (ZARYA)
+1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927
+2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537"""
+
+GOES_KVN = """CCSDS_OMM_VERS = 2.0
+COMMENT this is a comment
+CREATION_DATE = 2007-065T16:00:00
+ORIGINATOR = NOAA/USA
+OBJECT_NAME = GOES 9
+OBJECT_ID = 1995-025A
+CENTER_NAME = EARTH
+REF_FRAME = TEME
+TIME_SYSTEM = UTC
+MEAN_ELEMENT_THEORY = SGP/SGP4
+EPOCH = 2007-064T10:34:41.4264
+MEAN_MOTION = 1.00273272 [rev/day]
+ECCENTRICITY = 0.0005013
+INCLINATION = 3.0539 [deg]
+RA_OF_ASC_NODE = 81.7939 [deg]
+ARG_OF_PERICENTER = 249.2363 [deg]
+MEAN_ANOMALY = 150.1602 [deg]
+EPHEMERIS_TYPE = 0
+CLASSIFICATION_TYPE = U
+NORAD_CAT_ID = 23581
+ELEMENT_SET_NO = 0925
+REV_AT_EPOCH = 4316
+BSTAR = 0.0001 [1/ER]
+MEAN_MOTION_DOT = -0.00000113 [rev/day**2]
+MEAN_MOTION_DDOT = 0.0 [rev/day**3]
+COV_REF_FRAME = TEME
+"""
+
+COVARIANCE_KEYS = [
+    "CX_X", "CY_X", "CY_Y", "CZ_X", "CZ_Y", "CZ_Z",
+    "CX_DOT_X", "CX_DOT_Y", "CX_DOT_Z", "CX_DOT_X_DOT",
+    "CY_DOT_X", "CY_DOT_Y", "CY_DOT_Z", "CY_DOT_X_DOT", "CY_DOT_Y_DOT",
+    "CZ_DOT_X", "CZ_DOT_Y", "CZ_DOT_Z", "CZ_DOT_X_DOT", "CZ_DOT_Y_DOT", "CZ_DOT_Z_DOT",
+]
+
+
+def goes_with_covariance():
+    # Diagonal covariance: 1 km² in position, 1e-6 km²/s² in velocity
+    lines = []
+    for key in COVARIANCE_KEYS:
+        diagonal = key in ("CX_X", "CY_Y", "CZ_Z")
+        diagonal_dot = key in ("CX_DOT_X_DOT", "CY_DOT_Y_DOT", "CZ_DOT_Z_DOT")
+        value = 1.0 if diagonal else 1e-6 if diagonal_dot else 0.0
+        lines.append(f"{key} = {value}")
+    return GOES_KVN + "\n".join(lines) + "\n"
+
+
+class TestKvn:
+    def test_metadata(self):
+        omm = parse_omm(GOES_KVN)
+        assert omm["originator"] == "NOAA/USA"
+        assert omm["creation_date"] == "2007-065T16:00:00"
+        assert omm["mean_element_theory"] == "SGP/SGP4"
+        assert omm["time_system"] == "UTC"
+        assert omm["comments"] == ["this is a comment"]
+        assert omm["epoch"].startswith("2007-03-05T10:34:41.4264")
+        assert omm["norad_cat_id"] == 23581
+        assert omm["covariance"] is None
+
+    def test_covariance(self):
+        omm = parse_omm(goes_with_covariance())
+        std = omm["covariance"].std_devs
+        assert std[0] == pytest.approx(1000.0)
+        assert std[3] == pytest.approx(1.0)
+        assert omm["cov_ref_frame"] == "TEME"
+
+    def test_missing_metadata(self):
+        with pytest.raises(RuntimeError, match="ORIGINATOR"):
+            parse_omm(GOES_KVN.replace("ORIGINATOR = NOAA/USA\n", ""))
+
+    @pytest.mark.parametrize(
+        "line, replacement",
+        [
+            ("TIME_SYSTEM = UTC", "TIME_SYSTEM = TAI"),
+            ("REF_FRAME = TEME", "REF_FRAME = GCRF"),
+            ("MEAN_ELEMENT_THEORY = SGP/SGP4", "MEAN_ELEMENT_THEORY = DSST"),
+        ],
+    )
+    def test_sgp4_requires_sgp4_metadata(self, line, replacement):
+        kvn = GOES_KVN.replace(line, replacement)
+        assert parse_omm(kvn)["object_name"] == "GOES 9"
+        with pytest.raises(RuntimeError, match=replacement.split(" = ")[0]):
+            py_propagate_omm(kvn, 30.0)
+        with pytest.raises(RuntimeError):
+            omm_to_tle(kvn)
+
+
+class TestEncodings:
+    @pytest.mark.parametrize("encoding", ["json", "kvn", "xml"])
+    def test_same_elements(self, encoding):
+        omm = tle_to_omm(ISS_TLE, encoding=encoding)
+        assert omm_to_tle(omm) == ISS_TLE
+        state = py_propagate_omm(omm, 30.0)
+        reference = py_propagate_omm(tle_to_omm(ISS_TLE), 30.0)
+        assert state["position"] == pytest.approx(reference["position"], abs=1e-9)
+
+    def test_xml_keeps_originator(self):
+        omm = parse_omm(tle_to_omm(ISS_TLE, encoding="xml", originator="OPS"))
+        assert omm["originator"] == "OPS"
+        assert omm["object_name"] == "ISS (ZARYA)"
+        assert omm["ref_frame"] == "TEME"
@@ -0,0 +1,94 @@
+"""
+Tests for CCSDS OPM reading and writing.
+
+A state and its planned maneuvers written to an OPM must come back unchanged
+in both encodings, with the transfer burns and propellant they imply.
+"""
+
+import math
+
+import pytest
+from astrora._core import (
+    CartesianState,
+    Duration,
+    Epoch,
+    OpmManeuver,
+    bielliptic_maneuvers,
+    hohmann_maneuvers,
+    hohmann_transfer,
+    parse_opm,
+    write_opm,
+)
+
+GM_EARTH = 3.986004418e14
+R_LEO = 6778e3
+R_GEO = 42164e3
+EPOCH = Epoch(2024, 3, 1, 12)
+
+
+def leo_state():
+    return CartesianState([R_LEO, 0.0, 0.0], [0.0, math.sqrt(GM_EARTH / R_LEO), 0.0])
+
+
+class TestOpm:
+    @pytest.mark.parametrize("encoding", ["kvn", "xml"])
+    def test_round_trip(self, encoding):
+        burns = hohmann_maneuvers(R_LEO, R_GEO, GM_EARTH, EPOCH)
+        text = write_opm(
+            EPOCH,
+            leo_state(),
+            "SAT",
+            "2024-001A",
+            burns,
+            mu=GM_EARTH,
+            mass=1200.0,
+            specific_impulse=310.0,
+            encoding=encoding,
+        )
+        opm = parse_opm(text)
+        assert opm["object_name"] == "SAT"
+        assert opm["frame"] == "GCRS"
+        assert opm["epoch"] == EPOCH
+        assert list(opm["state"].position) == pytest.approx([R_LEO, 0.0, 0.0])
+        assert opm["keplerian"].a == pytest.approx(R_LEO)
+        assert opm["gm"] == pytest.approx(GM_EARTH)
+        assert opm["mass"] == pytest.approx(1200.0)
+
+        maneuvers = opm["maneuvers"]
+        assert len(maneuvers) == 2
+        for read, written in zip(maneuvers, burns):
+            assert read.epoch_ignition == written.epoch_ignition
+            assert read.ref_frame == "RTN"
+            assert read.delta_v == pytest.approx(written.delta_v, abs=1e-9)
+            assert read.delta_mass < 0.0
+
+    def test_hohmann_burns(self):
+        transfer = hohmann_transfer(R_LEO, R_GEO, GM_EARTH)
+        first, second = hohmann_maneuvers(R_LEO, R_GEO, GM_EARTH, EPOCH)
+        assert first.epoch_ignition == EPOCH
+        assert second.epoch_ignition == EPOCH + Duration(transfer["transfer_time"])
+        # Along-track burns, in the RTN frame
+        assert first.delta_v[1] == pytest.approx(transfer["delta_v1"])
+        assert second.delta_v[1] == pytest.approx(transfer["delta_v2"])
+        assert first.inertial_delta_v(leo_state()) == pytest.approx([0.0, transfer["delta_v1"], 0.0])
+
+    def test_bielliptic_burns(self):
+        burns = bielliptic_maneuvers(R_LEO, 20 * R_LEO, 30 * R_LEO, GM_EARTH, EPOCH)
+        assert len(burns) == 3
+        assert burns[0].delta_v[1] > 0.0
+        assert burns[2].delta_v[1] < 0.0
+
+    def test_manual_maneuver(self):
+        maneuver = OpmManeuver(EPOCH + Duration(600.0), [0.0, 1.0, 0.0], duration=30.0)
+        text = write_opm(EPOCH, leo_state(), "SAT", "2024-001A", [maneuver], frame="J2000")
+        assert "REF_FRAME = EME2000" in text
+        assert "MAN_DURATION = 30" in text
+        assert parse_opm(text)["maneuvers"][0].duration == pytest.approx(30.0)
+
+    def test_invalid_input(self):
+        with pytest.raises(ValueError):
+            write_opm(EPOCH, leo_state(), "SAT", "2024-001A", encoding="json")
+        with pytest.raises(ValueError):
+            write_opm(EPOCH, leo_state(), "SAT", "2024-001A", specific_impulse=300.0)
+        with pytest.raises(ValueError):
+            parse_opm("CCSDS_OPM_VERS = 2.0\nORIGINATOR = X\n")
@@ -0,0 +1,69 @@
+"""
+Tests for dense-output ephemerides from the adaptive integrators
+"""
+
+import numpy as np
+import pytest
+from astrora._core import Ephemeris, constants, propagate_ephemeris, propagate_j2_dop853
+
+R0 = np.array([7000e3, 0.0, 0.0])
+V0 = np.array([0.0, 6000.0, 4000.0])
+
+
+@pytest.fixture
+def ephemeris():
+    return propagate_ephemeris(
+        R0,
+        V0,
+        7200.0,
+        constants.GM_EARTH,
+        j2=constants.J2_EARTH,
+        R=constants.R_EARTH,
+        tol=1e-12,
+    )
+
+
+class TestPropagateEphemeris:
+    def test_span(self, ephemeris):
+        assert isinstance(ephemeris, Ephemeris)
+        assert ephemeris.t_start == 0.0
+        assert ephemeris.t_end == pytest.approx(7200.0)
+        assert ephemeris.dimension == 6
+        steps = ephemeris.step_times()
+        assert len(steps) == len(ephemeris) + 1
+        assert np.all(np.diff(steps) > 0.0)
+
+    def test_evaluate_matches_restarted_propagation(self, ephemeris):
+        for t in [123.4, 2500.0, 5555.5]:
+            r, v = propagate_j2_dop853(
+                R0, V0, t, constants.GM_EARTH, constants.J2_EARTH, constants.R_EARTH, tol=1e-12
+            )
+            state = ephemeris.evaluate(t)
+            np.testing.assert_allclose(state[:3], r, atol=1e-2)
+            np.testing.assert_allclose(state[3:], v, atol=1e-5)
+
+    def test_evaluate_many(self, ephemeris):
+        times = np.linspace(0.0, 7200.0, 721)
+        states = ephemeris.evaluate_many(times)
+        assert states.shape == (721, 6)
+        np.testing.assert_allclose(states[0], np.concatenate([R0, V0]))
+        np.testing.assert_allclose(states[100], ephemeris.evaluate(times[100]))
+
+    def test_out_of_range(self, ephemeris):
+        with pytest.raises(ValueError):
+            ephemeris.evaluate(7300.0)
+        with pytest.raises(ValueError):
+            ephemeris.evaluate_many(np.array([0.0, -1.0]))
+
+    def test_two_body_backward(self):
+        eph = propagate_ephemeris(R0, V0, -3600.0, constants.GM_EARTH, t0=1000.0, method="dopri5")
+        assert eph.t_end == pytest.approx(-2600.0)
+        energy = [
+            0.5 * np.dot(s[3:], s[3:]) - constants.GM_EARTH / np.linalg.norm(s[:3])
+            for s in eph.evaluate_many(np.linspace(1000.0, -2600.0, 50))
+        ]
+        np.testing.assert_allclose(energy, energy[0], rtol=1e-6)
+
+    def test_j2_requires_radius(self):
+        with pytest.raises(ValueError):
+            propagate_ephemeris(R0, V0, 600.0, constants.GM_EARTH, j2=constants.J2_EARTH)
@@ -0,0 +1,72 @@
+"""
+Tests for space weather (F10.7 / Ap / Kp) loading and lookup
+"""
+
+import numpy as np
+import pytest
+from astrora._core import (
+    Epoch,
+    atmospheric_density,
+    clear_space_weather,
+    load_space_weather,
+    set_space_weather_fallback,
+    space_weather,
+    space_weather_loaded,
+)
+
+CSV = """\
+DATE,BSRN,ND,KP1,KP2,KP3,KP4,KP5,KP6,KP7,KP8,KP_SUM,AP1,AP2,AP3,AP4,AP5,AP6,AP7,AP8,AP_AVG,CP,C9,ISN,F10.7_OBS,F10.7_ADJ,F10.7_DATA_TYPE,F10.7_OBS_CENTER81,F10.7_OBS_LAST81,F10.7_ADJ_CENTER81,F10.7_ADJ_LAST81
+2024-05-10,2606,2,33,37,40,50,70,83,87,90,490,18,22,27,48,132,236,300,400,173,2.1,8,187,230.6,227.9,OBS,178.2,171.5,176.1,169.8
+2024-05-11,2606,3,87,80,63,57,50,37,30,27,431,300,207,67,56,48,22,15,12,91,1.8,7,204,211.3,208.9,OBS,177.9,172.0,175.8,170.2
+"""
+
+
+@pytest.fixture
+def sw_file(tmp_path):
+    path = tmp_path / "SW-All.csv"
+    path.write_text(CSV)
+    yield str(path)
+    clear_space_weather()
+    set_space_weather_fallback(150.0, 150.0, 15.0)
+
+
+class TestSpaceWeather:
+    def test_load(self, sw_file):
+        start, end = load_space_weather(sw_file)
+        assert (start, end) == (60440.0, 60441.0)
+        assert space_weather_loaded()
+        clear_space_weather()
+        assert not space_weather_loaded()
+
+    def test_lookup(self, sw_file):
+        load_space_weather(sw_file)
+        sw = space_weather(Epoch(2024, 5, 11, 1, 0, 0, 0))
+        # Flux of the previous UTC day
+        assert sw["f107"] == pytest.approx(230.6)
+        assert sw["f107_avg"] == pytest.approx(177.9)
+        assert sw["ap"] == pytest.approx(300.0)
+        assert sw["kp"] == pytest.approx(8.0 + 2.0 / 3.0)
+
+    def test_fallback_beyond_file(self, sw_file):
+        load_space_weather(sw_file)
+        set_space_weather_fallback(120.0, 110.0, 7.0)
+        sw = space_weather(Epoch(2030, 1, 1, 0, 0, 0, 0))
+        assert (sw["f107"], sw["f107_avg"], sw["ap"]) == (120.0, 110.0, 7.0)
+
+    def test_unknown_format(self, sw_file):
+        with pytest.raises(ValueError):
+            load_space_weather(sw_file, format="xml")
+
+    def test_missing_file(self):
+        with pytest.raises(RuntimeError):
+            load_space_weather("/nonexistent/SW-All.csv")
+
+    def test_density_follows_storm(self, sw_file):
+        r = np.array([6378137.0 + 400e3, 0.0, 0.0])
+        epoch = Epoch(2024, 5, 11, 1, 0, 0, 0)
+        quiet = atmospheric_density(r, epoch)
+        load_space_weather(sw_file)
+        storm = atmospheric_density(r, epoch)
+        assert storm > 2.0 * quiet
+        # Explicit indices override the loaded values
+        assert atmospheric_density(r, epoch, f107=150.0, f107_avg=150.0, ap=15.0) == pytest.approx(quiet)
@@ -0,0 +1,73 @@
+"""
+Tests for TLE and OMM export.
+
+Converting a TLE to an OMM and back must reproduce the original text, and
+the OMM encodings must carry the same mean elements.
+"""
+
+import json
+import xml.etree.ElementTree as ET
+
+import pytest
+from astrora._core import omm_to_tle, py_propagate_omm, py_propagate_tle, tle_to_omm
+
+ISS_TLE_2LINE = """1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927
+2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537"""
+
+ISS_TLE_3LINE = """ISS (ZARYA)
+1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927
+2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537"""
+
+
+class TestRoundTrip:
+    """TLE -> OMM -> TLE reproduces the input."""
+
+    @pytest.mark.parametrize("tle", [ISS_TLE_2LINE, ISS_TLE_3LINE])
+    def test_tle_round_trip(self, tle):
+        assert omm_to_tle(tle_to_omm(tle)) == tle
+
+    def test_checksums(self):
+        for line in omm_to_tle(tle_to_omm(ISS_TLE_2LINE)).splitlines():
+            total = sum(int(c) if c.isdigit() else c == "-" for c in line[:68])
+            assert total % 10 == int(line[68])
+
+    def test_same_state(self):
+        omm = tle_to_omm(ISS_TLE_3LINE)
+        a = py_propagate_tle(ISS_TLE_3LINE, 90.0)
+        b = py_propagate_omm(omm, 90.0)
+        assert a["position"] == pytest.approx(b["position"], abs=1e-9)
+
+
+class TestEncodings:
+    """KVN and XML carry the same elements as JSON."""
+
+    def test_json(self):
+        omm = json.loads(tle_to_omm(ISS_TLE_3LINE))
+        assert omm["OBJECT_NAME"] == "ISS (ZARYA)"
+        assert omm["NORAD_CAT_ID"] == 25544
+        assert omm["MEAN_MOTION"] == pytest.approx(15.72125391)
+
+    def test_kvn(self):
+        kvn = tle_to_omm(ISS_TLE_3LINE, encoding="kvn", originator="TEST")
+        values = dict(
+            (key.strip(), value.strip())
+            for key, _, value in (line.partition("=") for line in kvn.splitlines() if "=" in line)
+        )
+        assert values["CCSDS_OMM_VERS"] == "2.0"
+        assert values["ORIGINATOR"] == "TEST"
+        assert values["REF_FRAME"] == "TEME"
+        assert values["OBJECT_ID"] == "1998-067A"
+        assert float(values["ECCENTRICITY"]) == pytest.approx(0.0006703)
+
+    def test_xml(self):
+        xml = tle_to_omm(ISS_TLE_3LINE, encoding="xml")
+        root = ET.fromstring(xml)
+        assert root.tag == "omm"
+        assert root.find("body/segment/metadata/OBJECT_NAME").text == "ISS (ZARYA)"
+        mean = root.find("body/segment/data/meanElements")
+        assert float(mean.find("INCLINATION").text) == pytest.approx(51.6416)
+        assert mean.find("MEAN_MOTION").get("units") == "rev/day"
+
+    def test_unknown_encoding(self):
+        with pytest.raises(ValueError):
+            tle_to_omm(ISS_TLE_2LINE, encoding="yaml")
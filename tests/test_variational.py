@@ -0,0 +1,55 @@
+"""
+Tests for STM and parameter sensitivity propagation with NumericalPropagator
+"""
+
+import numpy as np
+import pytest
+from astrora._core import Duration, Epoch, NumericalPropagator, constants
+from numpy.testing import assert_allclose
+
+R0 = np.array([7000e3, 0.0, 0.0])
+V0 = np.array([0.0, 6000.0, 4000.0])
+
+
+@pytest.fixture
+def epoch():
+    return Epoch(2024, 3, 1, 12, 0, 0, 0)
+
+
+def make_propagator(epoch, position=R0):
+    prop = NumericalPropagator(position, V0, epoch, constants.GM_EARTH, tol=1e-12)
+    prop.add_j2(constants.J2_EARTH, constants.R_EARTH)
+    prop.add_drag(50.0)
+    return prop
+
+
+class TestPropagateWithStm:
+    def test_shapes_and_state(self, epoch):
+        target = epoch + Duration(1800.0)
+        prop = make_propagator(epoch)
+        r, v, stm, sensitivity = prop.propagate_with_stm(target, ["ballistic_coefficient"])
+
+        assert stm.shape == (6, 6)
+        assert sensitivity.shape == (6, 1)
+        assert prop.epoch == target
+        assert_allclose(prop.position, r)
+
+        r_ref, v_ref = make_propagator(epoch).propagate_to(target)
+        assert_allclose(r, r_ref, atol=1e-2)
+        assert_allclose(v, v_ref, atol=1e-5)
+
+    def test_stm_maps_offsets(self, epoch):
+        target = epoch + Duration(1800.0)
+        r, _, stm, sensitivity = make_propagator(epoch).propagate_with_stm(target)
+        assert sensitivity.shape == (6, 0)
+
+        dr0 = np.array([10.0, -5.0, 3.0])
+        r_offset, _ = make_propagator(epoch, R0 + dr0).propagate_to(target)
+        assert_allclose(r_offset - r, stm[:3, :3] @ dr0, atol=1e-2)
+
+    def test_unknown_parameter(self, epoch):
+        prop = make_propagator(epoch)
+        with pytest.raises(ValueError):
+            prop.propagate_with_stm(epoch + Duration(60.0), ["mass"])
+        with pytest.raises(ValueError):
+            prop.propagate_with_stm(epoch + Duration(60.0), ["reflectivity"])